@@ -1,4 +1,8 @@
-//! Keyed hashing with domain separation (blake3).
+//! Keyed hashing: domain-separated blake3 for our own digests, HMAC-SHA256 for signatures
+//! that third parties verify.
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
 
 /// Compute a keyed blake3 hash of `input`, hex-encoded (64 chars).
 ///
@@ -14,9 +18,19 @@ pub fn hash_hex(key_material: &[u8], context: &str, input: &[u8]) -> String {
     hasher.finalize().to_hex().to_string()
 }
 
+/// Compute HMAC-SHA256 of `input` under `key`, hex-encoded (64 chars).
+///
+/// For signatures checked outside this codebase (e.g. webhook receivers), where blake3 is not
+/// a reasonable thing to ask of the other side. HMAC takes keys of any length.
+pub fn hmac_sha256_hex(key: &[u8], input: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(input);
+    hex::encode(mac.finalize().into_bytes())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::hash_hex;
+    use super::{hash_hex, hmac_sha256_hex};
 
     #[test]
     fn deterministic_and_hex() {
@@ -36,4 +50,13 @@ mod tests {
             hash_hex(b"key2", "ctx", b"code")
         );
     }
+
+    #[test]
+    fn hmac_sha256_matches_rfc_4231() {
+        // RFC 4231, test case 2.
+        assert_eq!(
+            hmac_sha256_hex(b"Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
 }
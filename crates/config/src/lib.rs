@@ -5,5 +5,5 @@ mod server_config;
 mod worker_config;
 
 pub use db_url::redact_database_url;
-pub use server_config::ServerConfig;
+pub use server_config::{OidcProviderConfig, ServerConfig};
 pub use worker_config::WorkerConfig;
@@ -4,6 +4,25 @@ use std::env;
 use std::sync::LazyLock;
 use tracing::warn;
 
+/// Provider slugs `OIDC_PROVIDERS` may enable. Each maps to an `oauth_provider` value;
+/// `sso` is the one slot for a self-hosted IdP (Keycloak, Authentik, ...).
+const OIDC_PROVIDER_SLUGS: &[&str] = &["discord", "microsoft", "sso"];
+
+/// One OpenID Connect provider, read from `OIDC_<SLUG>_*`.
+#[derive(Debug, Clone)]
+pub struct OidcProviderConfig {
+    pub slug: String,
+    // Issuer URL; `{issuer}/.well-known/openid-configuration` must name exactly this issuer.
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>, // default: "openid email profile"
+    // Accept an email whose `email_verified` claim is absent (IdPs that own the addresses,
+    // e.g. a single Azure AD tenant). An explicit `false` is always rejected.
+    pub trust_email: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
     pub is_dev: bool,
@@ -18,6 +37,7 @@ pub struct ServerConfig {
     pub auth_email_change_token_expire_time: i64, // minutes
     pub auth_account_deletion_token_expire_time: i64, // minutes
     pub auth_device_verify_token_expire_time: i64, // minutes (new-device login verification)
+    pub auth_magic_link_token_expire_time: i64, // minutes (passwordless sign-in link)
     pub oauth_pending_signup_ttl_minutes: i64, // OAuth pending signup TTL (minutes)
 
     // Google
@@ -30,6 +50,9 @@ pub struct ServerConfig {
     pub github_client_secret: String,
     pub github_redirect_uri: String,
 
+    // OpenID Connect providers enabled by `OIDC_PROVIDERS` (comma-separated slugs)
+    pub oidc_providers: Vec<OidcProviderConfig>,
+
     // Cloudflare R2 (shared credentials)
     pub r2_endpoint: String,
     pub r2_region: String,
@@ -38,6 +61,8 @@ pub struct ServerConfig {
     // R2 Assets (public bucket - images, sitemap)
     pub r2_assets_public_domain: String,
     pub r2_assets_bucket_name: String,
+    // R2 Revisions (private bucket - zstd-compressed board post/comment revision bodies)
+    pub r2_revisions_bucket_name: String,
 
     // Cloudflare Turnstile
     pub turnstile_secret_key: String,
@@ -89,6 +114,33 @@ pub struct ServerConfig {
     // spoof its rate-limit IP key.
     pub internal_proxy_secret: Option<String>,
 
+    // Redis-backed per-route rate limiting (see `middleware::rate_limit`). Disable only when an
+    // upstream gateway already enforces equivalent limits, or in load/e2e test stacks.
+    pub rate_limit_enabled: bool,
+
+    // Accept plain-HTTP webhook receiver URLs (default: false). HTTPS is otherwise required;
+    // enable only in dev/test stacks whose receivers have no TLS.
+    pub webhook_allow_http: bool,
+
+    // Board reaction kinds accepted by the react endpoints (comma-separated, default: "upvote").
+    // Stored reactions of a kind later removed from the list keep counting and can still be
+    // taken back; only new reactions are rejected.
+    pub board_reaction_kinds: Vec<String>,
+
+    // Web Push VAPID public key (base64url, uncompressed P-256 point) handed to browsers as
+    // `applicationServerKey`. Must pair with the worker's `VAPID_PRIVATE_KEY`; unset disables
+    // push subscriptions.
+    pub vapid_public_key: Option<String>,
+
+    // WebAuthn relying party for passkeys. `webauthn_rp_id` is the registrable domain
+    // (e.g. "example.com") and `webauthn_rp_origin` the web origin users sign in from;
+    // extra origins admit native apps (e.g. `android:apk-key-hash:...`). Unset RP id or
+    // origin disables passkeys.
+    pub webauthn_rp_id: Option<String>,
+    pub webauthn_rp_origin: Option<String>,
+    pub webauthn_rp_name: String,
+    pub webauthn_extra_origins: Vec<String>,
+
     // Stability Layer (protect DB pool from overload)
     pub stability_concurrency_limit: usize, // Max concurrent requests (default: 500)
     pub stability_buffer_size: usize,       // Request queue size (default: 1024)
@@ -185,6 +237,25 @@ static CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| {
         }
     };
 
+    let board_reaction_kinds: Vec<String> = env::var("BOARD_REACTION_KINDS")
+        .unwrap_or_else(|_| "upvote".to_string())
+        .split(',')
+        .filter_map(|s| {
+            let kind = s.trim();
+            if kind.is_empty() {
+                None
+            } else if kind.len() > 32 {
+                warn!(
+                    "Reaction kind '{}' in BOARD_REACTION_KINDS exceeds 32 characters; ignored.",
+                    kind
+                );
+                None
+            } else {
+                Some(kind.to_string())
+            }
+        })
+        .collect();
+
     // Required string vars
     let totp_secret = require!("TOTP_SECRET");
     let totp_encryption_key = require!("TOTP_ENCRYPTION_KEY");
@@ -200,11 +271,57 @@ static CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| {
     let r2_secret_access_key = require!("R2_SECRET_ACCESS_KEY");
     let r2_assets_public_domain = require!("R2_ASSETS_PUBLIC_DOMAIN");
     let r2_assets_bucket_name = require!("R2_ASSETS_BUCKET_NAME");
+    let r2_revisions_bucket_name = require!("R2_REVISIONS_BUCKET_NAME");
     let turnstile_secret_key = require!("TURNSTILE_SECRET_KEY");
     let database_url = require!("DATABASE_URL");
     let server_host = require!("HOST");
     let server_port = require!("PORT");
 
+    // OpenID Connect providers: each enabled slug requires its issuer and client settings.
+    let mut oidc_providers: Vec<OidcProviderConfig> = Vec::new();
+    for slug in env::var("OIDC_PROVIDERS")
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_ascii_lowercase())
+        .filter(|s| !s.is_empty())
+    {
+        if !OIDC_PROVIDER_SLUGS.contains(&slug.as_str()) {
+            errors.push(format!(
+                "  - OIDC_PROVIDERS (unknown provider '{}', expected one of: {})",
+                slug,
+                OIDC_PROVIDER_SLUGS.join(", ")
+            ));
+            continue;
+        }
+        if oidc_providers.iter().any(|p| p.slug == slug) {
+            continue;
+        }
+
+        let prefix = format!("OIDC_{}", slug.to_ascii_uppercase());
+        let scopes: Vec<String> = env::var(format!("{prefix}_SCOPES"))
+            .unwrap_or_else(|_| "openid email profile".to_string())
+            .split([' ', ','])
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect();
+        if !scopes.iter().any(|s| s == "openid") {
+            errors.push(format!("  - {prefix}_SCOPES (must include 'openid')"));
+        }
+
+        oidc_providers.push(OidcProviderConfig {
+            issuer: require!(format!("{prefix}_ISSUER")),
+            client_id: require!(format!("{prefix}_CLIENT_ID")),
+            client_secret: require!(format!("{prefix}_CLIENT_SECRET")),
+            redirect_uri: require!(format!("{prefix}_REDIRECT_URI")),
+            scopes,
+            trust_email: env::var(format!("{prefix}_TRUST_EMAIL"))
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            slug,
+        });
+    }
+
     // Required parsed vars
     let auth_session_max_lifetime_hours = require_parse!("AUTH_SESSION_MAX_LIFETIME_HOURS", i64);
     let auth_session_sliding_ttl_hours = require_parse!("AUTH_SESSION_SLIDING_TTL_HOURS", i64);
@@ -257,6 +374,11 @@ static CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| {
             .and_then(|v| v.parse::<i64>().ok())
             .unwrap_or(30)
             .max(0), // Default 30 minutes
+        auth_magic_link_token_expire_time: env::var("AUTH_MAGIC_LINK_TOKEN_EXPIRE_TIME")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .unwrap_or(15)
+            .max(0), // Default 15 minutes
         oauth_pending_signup_ttl_minutes: env::var("OAUTH_PENDING_SIGNUP_TTL_MINUTES")
             .ok()
             .and_then(|v| v.parse::<i64>().ok())
@@ -273,6 +395,8 @@ static CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| {
         github_client_secret,
         github_redirect_uri,
 
+        oidc_providers,
+
         // Cloudflare R2 (shared credentials)
         r2_endpoint,
         r2_region,
@@ -281,6 +405,7 @@ static CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| {
         // R2 Assets (public bucket)
         r2_assets_public_domain,
         r2_assets_bucket_name,
+        r2_revisions_bucket_name,
 
         // Cloudflare Turnstile
         turnstile_secret_key,
@@ -344,6 +469,36 @@ static CONFIG: LazyLock<ServerConfig> = LazyLock::new(|| {
             .ok()
             .filter(|s| !s.is_empty()),
 
+        rate_limit_enabled: env::var("RATE_LIMIT_ENABLED")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(true),
+
+        webhook_allow_http: env::var("WEBHOOK_ALLOW_HTTP")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(false),
+
+        board_reaction_kinds,
+
+        vapid_public_key: env::var("VAPID_PUBLIC_KEY").ok().filter(|k| !k.is_empty()),
+
+        webauthn_rp_id: env::var("WEBAUTHN_RP_ID").ok().filter(|v| !v.is_empty()),
+        webauthn_rp_origin: env::var("WEBAUTHN_RP_ORIGIN")
+            .ok()
+            .filter(|v| !v.is_empty()),
+        webauthn_rp_name: env::var("WEBAUTHN_RP_NAME")
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| "AxumKit".to_string()),
+        webauthn_extra_origins: env::var("WEBAUTHN_EXTRA_ORIGINS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(str::to_string)
+            .collect(),
+
         // Stability Layer
         stability_concurrency_limit: env::var("STABILITY_CONCURRENCY_LIMIT")
             .ok()
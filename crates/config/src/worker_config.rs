@@ -40,6 +40,17 @@ pub struct WorkerConfig {
     pub frontend_path_confirm_email_change: String,
     pub frontend_path_confirm_account_deletion: String,
     pub frontend_path_verify_device: String,
+    pub frontend_path_magic_link: String,
+    pub frontend_path_notifications: String,
+    pub frontend_path_unsubscribe_email: String,
+    // Public base URL of the API, for the one-click `List-Unsubscribe-Post` target.
+    // Unset: notification emails only link to the frontend unsubscribe page.
+    pub public_api_url: Option<String>,
+
+    // Web Push (VAPID). The private key is base64url (32-byte P-256 scalar); unset disables
+    // push delivery. The subject is the contact push services see (default: mailto sender).
+    pub vapid_private_key: Option<String>,
+    pub vapid_subject: String,
 
     // Database endpoint used by worker jobs. Full connection URL — see the note on
     // `ServerConfig::database_url` for why this is a URL and not assembled parts.
@@ -102,6 +113,11 @@ static CONFIG: LazyLock<WorkerConfig> = LazyLock::new(|| {
         );
     }
 
+    let vapid_subject = env::var("VAPID_SUBJECT")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| format!("mailto:{emails_from_email}"));
+
     WorkerConfig {
         // SMTP
         smtp_host,
@@ -150,6 +166,20 @@ static CONFIG: LazyLock<WorkerConfig> = LazyLock::new(|| {
         frontend_path_confirm_email_change,
         frontend_path_confirm_account_deletion,
         frontend_path_verify_device,
+        frontend_path_magic_link: env::var("FRONTEND_PATH_MAGIC_LINK")
+            .unwrap_or_else(|_| "/account/magic-link".into()),
+        frontend_path_notifications: env::var("FRONTEND_PATH_NOTIFICATIONS")
+            .unwrap_or_else(|_| "/notifications".into()),
+        frontend_path_unsubscribe_email: env::var("FRONTEND_PATH_UNSUBSCRIBE_EMAIL")
+            .unwrap_or_else(|_| "/notifications/unsubscribe".into()),
+        public_api_url: env::var("PUBLIC_API_URL")
+            .ok()
+            .map(|url| url.trim_end_matches('/').to_string())
+            .filter(|url| !url.is_empty()),
+
+        // Web Push
+        vapid_private_key: env::var("VAPID_PRIVATE_KEY").ok().filter(|k| !k.is_empty()),
+        vapid_subject,
 
         // Database
         database_url,
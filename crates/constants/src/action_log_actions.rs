@@ -18,6 +18,28 @@ pub enum ActionLogAction {
     #[serde(rename = "post:delete")]
     PostDelete,
 
+    // ==================== Comment Actions ====================
+    /// Comment created
+    #[serde(rename = "comment:create")]
+    CommentCreate,
+    /// Comment edited
+    #[serde(rename = "comment:edit")]
+    CommentEdit,
+    /// Comment deleted
+    #[serde(rename = "comment:delete")]
+    CommentDelete,
+
+    // ==================== Board Actions ====================
+    /// Board created
+    #[serde(rename = "board:create")]
+    BoardCreate,
+    /// Board settings edited
+    #[serde(rename = "board:edit")]
+    BoardEdit,
+    /// Board deleted
+    #[serde(rename = "board:delete")]
+    BoardDelete,
+
     // ==================== User Actions ====================
     /// User created
     #[serde(rename = "user:create")]
@@ -54,6 +76,14 @@ impl ActionLogAction {
             ActionLogAction::PostCreate => "post:create",
             ActionLogAction::PostEdit => "post:edit",
             ActionLogAction::PostDelete => "post:delete",
+            // Comment
+            ActionLogAction::CommentCreate => "comment:create",
+            ActionLogAction::CommentEdit => "comment:edit",
+            ActionLogAction::CommentDelete => "comment:delete",
+            // Board
+            ActionLogAction::BoardCreate => "board:create",
+            ActionLogAction::BoardEdit => "board:edit",
+            ActionLogAction::BoardDelete => "board:delete",
             // User
             ActionLogAction::UserCreate => "user:create",
             ActionLogAction::UserEdit => "user:edit",
@@ -83,6 +113,14 @@ impl FromStr for ActionLogAction {
             "post:create" => Ok(ActionLogAction::PostCreate),
             "post:edit" => Ok(ActionLogAction::PostEdit),
             "post:delete" => Ok(ActionLogAction::PostDelete),
+            // Comment
+            "comment:create" => Ok(ActionLogAction::CommentCreate),
+            "comment:edit" => Ok(ActionLogAction::CommentEdit),
+            "comment:delete" => Ok(ActionLogAction::CommentDelete),
+            // Board
+            "board:create" => Ok(ActionLogAction::BoardCreate),
+            "board:edit" => Ok(ActionLogAction::BoardEdit),
+            "board:delete" => Ok(ActionLogAction::BoardDelete),
             // User
             "user:create" => Ok(ActionLogAction::UserCreate),
             "user:edit" => Ok(ActionLogAction::UserEdit),
@@ -1,4 +1,5 @@
-//! Authentication-related Redis cache keys (OAuth, email, password, TOTP, device).
+//! Authentication-related Redis cache keys (OAuth, email, password, TOTP, passkey, device, magic link,
+//! login throttling).
 //!
 //! Token-derived keys take the **hashed** token id (see `utils::crypto::token::hash_token`), never
 //! the raw token, so the raw token never lives at rest in Redis: a store snapshot yields only
@@ -60,6 +61,18 @@ pub fn totp_used_code_key(user_id: &str, code: &str) -> String {
     format!("{}{}:{}", TOTP_USED_CODE_PREFIX, user_id, code)
 }
 
+/// Passkey ceremony TTL in seconds (5 minutes, the WebAuthn default timeout)
+pub const PASSKEY_CEREMONY_TTL_SECONDS: u64 = 300;
+
+/// Pending WebAuthn ceremony state prefix (registration or authentication challenge).
+/// Format: "passkey_ceremony:{blake3(ceremony_id)}"
+pub const PASSKEY_CEREMONY_PREFIX: &str = "passkey_ceremony:";
+
+/// Build passkey ceremony key. Callers pass the hashed ceremony id, never the raw id.
+pub fn passkey_ceremony_key(ceremony_id_hash: &str) -> String {
+    format!("{}{}", PASSKEY_CEREMONY_PREFIX, ceremony_id_hash)
+}
+
 /// Email verification token prefix.
 /// Format: "email_verification:{blake3(token)}"
 pub const EMAIL_VERIFICATION_PREFIX: &str = "email_verification:";
@@ -80,6 +93,10 @@ pub const ACCOUNT_DELETION_PREFIX: &str = "account_deletion:";
 /// Format: "device_verify:{blake3(token)}"
 pub const DEVICE_VERIFY_PREFIX: &str = "device_verify:";
 
+/// Magic-link sign-in token prefix.
+/// Format: "magic_link:{blake3(token)}"
+pub const MAGIC_LINK_PREFIX: &str = "magic_link:";
+
 /// Build email verification key. Callers pass the hashed token id, never the raw token.
 pub fn email_verification_key(token_id: &str) -> String {
     format!("{}{}", EMAIL_VERIFICATION_PREFIX, token_id)
@@ -124,3 +141,26 @@ pub fn email_signup_email_key(email: &str) -> String {
 pub fn email_signup_handle_key(handle: &str) -> String {
     format!("{}{}", EMAIL_SIGNUP_HANDLE_PREFIX, handle)
 }
+
+/// Build magic-link sign-in key. Callers pass the hashed token id, never the raw token.
+pub fn magic_link_key(token_id: &str) -> String {
+    format!("{}{}", MAGIC_LINK_PREFIX, token_id)
+}
+
+/// Failed-login counter prefix (INCR, TTL = failure window).
+/// Format: "login_failures:account:{blake3(normalized email)}" or "login_failures:ip:{ip}"
+pub const LOGIN_FAILURES_PREFIX: &str = "login_failures:";
+
+/// Login block prefix (progressive delay or lock; the key's TTL is the remaining block time).
+/// Format: "login_block:account:{blake3(normalized email)}" or "login_block:ip:{ip}"
+pub const LOGIN_BLOCK_PREFIX: &str = "login_block:";
+
+/// Build failed-login counter key for a throttling subject (`account:{hash}` or `ip:{ip}`).
+pub fn login_failures_key(subject: &str) -> String {
+    format!("{}{}", LOGIN_FAILURES_PREFIX, subject)
+}
+
+/// Build login block key for a throttling subject (`account:{hash}` or `ip:{ip}`).
+pub fn login_block_key(subject: &str) -> String {
+    format!("{}{}", LOGIN_BLOCK_PREFIX, subject)
+}
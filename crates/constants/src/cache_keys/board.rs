@@ -1,4 +1,4 @@
-//! Board-related Redis cache keys (view-count dedup + buffered deltas)
+//! Board-related Redis cache keys (view-count dedup + buffered deltas, rendered content)
 
 /// Per-viewer view dedup window TTL in seconds (6 hours).
 /// A given viewer (account or IP) counts at most once per post per window.
@@ -18,3 +18,16 @@ pub const BOARD_POST_VIEW_PENDING_KEY: &str = "board:post:view:pending";
 pub fn board_post_view_dedup_key(post_id: &str, viewer: &str) -> String {
     format!("{}{}:{}", BOARD_POST_VIEW_DEDUP_PREFIX, post_id, viewer)
 }
+
+/// Rendered post content TTL in seconds (24 hours). Entries are also dropped
+/// whenever the post's content changes.
+pub const BOARD_POST_RENDERED_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+/// Rendered post content key prefix (zstd-compressed JSON).
+/// Format: "board:post:rendered:{post_id}"
+pub const BOARD_POST_RENDERED_PREFIX: &str = "board:post:rendered:";
+
+/// Build the rendered post content key.
+pub fn board_post_rendered_key(post_id: &str) -> String {
+    format!("{}{}", BOARD_POST_RENDERED_PREFIX, post_id)
+}
@@ -3,6 +3,8 @@
 
 pub mod auth;
 pub mod board;
+pub mod rate_limit;
 
 pub use auth::*;
 pub use board::*;
+pub use rate_limit::*;
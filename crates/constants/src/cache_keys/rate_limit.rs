@@ -0,0 +1,11 @@
+//! Rate-limit Redis keys (sliding-window request logs, stored in redis_session).
+
+/// Rate-limit window key prefix.
+/// Format: "rate_limit:{policy}:{subject}" where subject is "u:{user_id}" for
+/// authenticated callers or "ip:{ip}" otherwise.
+pub const RATE_LIMIT_PREFIX: &str = "rate_limit:";
+
+/// Build the rate-limit window key for one policy and subject.
+pub fn rate_limit_key(policy: &str, subject: &str) -> String {
+    format!("{}{}:{}", RATE_LIMIT_PREFIX, policy, subject)
+}
@@ -4,22 +4,28 @@ pub mod moderation_actions;
 pub mod notification_actions;
 pub mod permissions;
 pub mod storage_keys;
+pub mod token_scopes;
 
 pub use action_log_actions::{
     action_log_action_to_string, string_to_action_log_action, ActionLogAction,
 };
 pub use cache_keys::board::{
-    BOARD_POST_VIEW_DEDUP_PREFIX, BOARD_POST_VIEW_DEDUP_TTL_SECONDS, BOARD_POST_VIEW_PENDING_KEY,
+    BOARD_POST_RENDERED_PREFIX, BOARD_POST_RENDERED_TTL_SECONDS, BOARD_POST_VIEW_DEDUP_PREFIX,
+    BOARD_POST_VIEW_DEDUP_TTL_SECONDS, BOARD_POST_VIEW_PENDING_KEY,
 };
 pub use cache_keys::{
-    account_deletion_key, board_post_view_dedup_key, device_verify_key, email_change_key,
-    email_signup_email_key, email_signup_handle_key, email_verification_key,
-    oauth_one_tap_nonce_key, oauth_pending_key, oauth_pending_lock_key, oauth_state_key,
-    password_reset_key, totp_used_code_key, ACCOUNT_DELETION_PREFIX, DEVICE_VERIFY_PREFIX,
-    EMAIL_CHANGE_PREFIX, EMAIL_SIGNUP_EMAIL_PREFIX, EMAIL_SIGNUP_HANDLE_PREFIX,
-    EMAIL_VERIFICATION_PREFIX, OAUTH_ONE_TAP_NONCE_PREFIX, OAUTH_ONE_TAP_NONCE_TTL_SECONDS,
-    OAUTH_PENDING_LOCK_PREFIX, OAUTH_PENDING_PREFIX, OAUTH_STATE_PREFIX, OAUTH_STATE_TTL_SECONDS,
-    PASSWORD_RESET_PREFIX, TOTP_USED_CODE_PREFIX, TOTP_USED_CODE_TTL_SECONDS,
+    account_deletion_key, board_post_rendered_key, board_post_view_dedup_key, device_verify_key,
+    email_change_key, email_signup_email_key, email_signup_handle_key, email_verification_key,
+    login_block_key, login_failures_key, magic_link_key, oauth_one_tap_nonce_key,
+    oauth_pending_key, oauth_pending_lock_key, oauth_state_key, passkey_ceremony_key,
+    password_reset_key, rate_limit_key, totp_used_code_key, ACCOUNT_DELETION_PREFIX,
+    DEVICE_VERIFY_PREFIX, EMAIL_CHANGE_PREFIX, EMAIL_SIGNUP_EMAIL_PREFIX,
+    EMAIL_SIGNUP_HANDLE_PREFIX, EMAIL_VERIFICATION_PREFIX, LOGIN_BLOCK_PREFIX,
+    LOGIN_FAILURES_PREFIX, MAGIC_LINK_PREFIX, OAUTH_ONE_TAP_NONCE_PREFIX,
+    OAUTH_ONE_TAP_NONCE_TTL_SECONDS, OAUTH_PENDING_LOCK_PREFIX, OAUTH_PENDING_PREFIX,
+    OAUTH_STATE_PREFIX, OAUTH_STATE_TTL_SECONDS, PASSKEY_CEREMONY_PREFIX,
+    PASSKEY_CEREMONY_TTL_SECONDS, PASSWORD_RESET_PREFIX, RATE_LIMIT_PREFIX, TOTP_USED_CODE_PREFIX,
+    TOTP_USED_CODE_TTL_SECONDS,
 };
 pub use moderation_actions::{
     moderation_action_to_string, string_to_moderation_action, ModerationAction,
@@ -29,5 +35,7 @@ pub use notification_actions::{
 };
 pub use permissions::{permission_to_string, string_to_permission, Permission};
 pub use storage_keys::{
-    user_image_key, BANNER_IMAGE_MAX_SIZE, PROFILE_IMAGE_MAX_SIZE, USER_IMAGES_PREFIX,
+    board_revision_key, user_image_key, BANNER_IMAGE_MAX_SIZE, BOARD_REVISIONS_PREFIX,
+    PROFILE_IMAGE_MAX_SIZE, USER_IMAGES_PREFIX,
 };
+pub use token_scopes::TokenScope;
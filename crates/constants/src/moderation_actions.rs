@@ -9,6 +9,8 @@ pub enum ModerationAction {
     UserBan,
     #[serde(rename = "user:unban")]
     UserUnban,
+    #[serde(rename = "user:unlock")]
+    UserUnlock,
     #[serde(rename = "user:grant_role")]
     UserGrantRole,
     #[serde(rename = "user:revoke_role")]
@@ -27,6 +29,22 @@ pub enum ModerationAction {
     BoardUnlock,
     #[serde(rename = "board:reorder_pins")]
     BoardReorderPins,
+    #[serde(rename = "board:restore_revision")]
+    BoardRestoreRevision,
+    #[serde(rename = "board:hide_post")]
+    BoardHidePost,
+    #[serde(rename = "board:unhide_post")]
+    BoardUnhidePost,
+
+    // Board comment
+    #[serde(rename = "board:hide_comment")]
+    BoardHideComment,
+    #[serde(rename = "board:unhide_comment")]
+    BoardUnhideComment,
+
+    // Reports
+    #[serde(rename = "report:dismiss")]
+    ReportDismiss,
 
     // ACL
     #[serde(rename = "group:create")]
@@ -39,6 +57,22 @@ pub enum ModerationAction {
     GroupMemberRemove,
     #[serde(rename = "group:permissions_replace")]
     GroupPermissionsReplace,
+
+    // Notifications
+    #[serde(rename = "notification:announce")]
+    NotificationAnnounce,
+    #[serde(rename = "notification:retract_announcement")]
+    NotificationRetractAnnouncement,
+
+    // Webhooks
+    #[serde(rename = "webhook:create")]
+    WebhookCreate,
+    #[serde(rename = "webhook:update")]
+    WebhookUpdate,
+    #[serde(rename = "webhook:delete")]
+    WebhookDelete,
+    #[serde(rename = "webhook:rotate_secret")]
+    WebhookRotateSecret,
 }
 
 impl ModerationAction {
@@ -46,6 +80,7 @@ impl ModerationAction {
         match self {
             ModerationAction::UserBan => "user:ban",
             ModerationAction::UserUnban => "user:unban",
+            ModerationAction::UserUnlock => "user:unlock",
             ModerationAction::UserGrantRole => "user:grant_role",
             ModerationAction::UserRevokeRole => "user:revoke_role",
             ModerationAction::SearchReindex => "search:reindex",
@@ -54,11 +89,25 @@ impl ModerationAction {
             ModerationAction::BoardLock => "board:lock",
             ModerationAction::BoardUnlock => "board:unlock",
             ModerationAction::BoardReorderPins => "board:reorder_pins",
+            ModerationAction::BoardRestoreRevision => "board:restore_revision",
+            ModerationAction::BoardHidePost => "board:hide_post",
+            ModerationAction::BoardUnhidePost => "board:unhide_post",
+            ModerationAction::BoardHideComment => "board:hide_comment",
+            ModerationAction::BoardUnhideComment => "board:unhide_comment",
+            ModerationAction::ReportDismiss => "report:dismiss",
             ModerationAction::GroupCreate => "group:create",
             ModerationAction::GroupDelete => "group:delete",
             ModerationAction::GroupMemberAdd => "group:member_add",
             ModerationAction::GroupMemberRemove => "group:member_remove",
             ModerationAction::GroupPermissionsReplace => "group:permissions_replace",
+            ModerationAction::NotificationAnnounce => "notification:announce",
+            ModerationAction::NotificationRetractAnnouncement => {
+                "notification:retract_announcement"
+            }
+            ModerationAction::WebhookCreate => "webhook:create",
+            ModerationAction::WebhookUpdate => "webhook:update",
+            ModerationAction::WebhookDelete => "webhook:delete",
+            ModerationAction::WebhookRotateSecret => "webhook:rotate_secret",
         }
     }
 }
@@ -76,6 +125,7 @@ impl FromStr for ModerationAction {
         match s {
             "user:ban" => Ok(ModerationAction::UserBan),
             "user:unban" => Ok(ModerationAction::UserUnban),
+            "user:unlock" => Ok(ModerationAction::UserUnlock),
             "user:grant_role" => Ok(ModerationAction::UserGrantRole),
             "user:revoke_role" => Ok(ModerationAction::UserRevokeRole),
             "search:reindex" => Ok(ModerationAction::SearchReindex),
@@ -84,11 +134,25 @@ impl FromStr for ModerationAction {
             "board:lock" => Ok(ModerationAction::BoardLock),
             "board:unlock" => Ok(ModerationAction::BoardUnlock),
             "board:reorder_pins" => Ok(ModerationAction::BoardReorderPins),
+            "board:restore_revision" => Ok(ModerationAction::BoardRestoreRevision),
+            "board:hide_post" => Ok(ModerationAction::BoardHidePost),
+            "board:unhide_post" => Ok(ModerationAction::BoardUnhidePost),
+            "board:hide_comment" => Ok(ModerationAction::BoardHideComment),
+            "board:unhide_comment" => Ok(ModerationAction::BoardUnhideComment),
+            "report:dismiss" => Ok(ModerationAction::ReportDismiss),
             "group:create" => Ok(ModerationAction::GroupCreate),
             "group:delete" => Ok(ModerationAction::GroupDelete),
             "group:member_add" => Ok(ModerationAction::GroupMemberAdd),
             "group:member_remove" => Ok(ModerationAction::GroupMemberRemove),
             "group:permissions_replace" => Ok(ModerationAction::GroupPermissionsReplace),
+            "notification:announce" => Ok(ModerationAction::NotificationAnnounce),
+            "notification:retract_announcement" => {
+                Ok(ModerationAction::NotificationRetractAnnouncement)
+            }
+            "webhook:create" => Ok(ModerationAction::WebhookCreate),
+            "webhook:update" => Ok(ModerationAction::WebhookUpdate),
+            "webhook:delete" => Ok(ModerationAction::WebhookDelete),
+            "webhook:rotate_secret" => Ok(ModerationAction::WebhookRotateSecret),
             _ => Err(format!("Unknown moderation action: {}", s)),
         }
     }
@@ -13,3 +13,12 @@ pub const BANNER_IMAGE_MAX_SIZE: usize = 8 * 1024 * 1024;
 pub fn user_image_key(hash: &str, extension: &str) -> String {
     format!("{}/{}.{}", USER_IMAGES_PREFIX, hash, extension)
 }
+
+/// Prefix for board post/comment revision bodies (private revisions bucket)
+pub const BOARD_REVISIONS_PREFIX: &str = "board-revisions";
+
+/// Generate storage key for a revision body, grouped under its post so every
+/// revision of a thread shares one prefix
+pub fn board_revision_key(post_id: &str, revision_id: &str) -> String {
+    format!("{}/{}/{}.zst", BOARD_REVISIONS_PREFIX, post_id, revision_id)
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Declares the [`TokenScope`] enum and every representation derived from it.
+///
+/// Same shape as `define_permissions!`: one entry keeps the variant, serde rename,
+/// `as_str` form, `ALL` catalog and `FromStr` parser in lockstep.
+macro_rules! define_token_scopes {
+    ($($(#[$meta:meta])* $variant:ident => $code:literal),+ $(,)?) => {
+        /// Scope a personal access token can be granted.
+        ///
+        /// A scope names an API area a token may act on as its owner; routes outside
+        /// every scope (account, credentials, sessions, admin) accept only interactive
+        /// sessions. The token still acts with the owner's role and permissions.
+        ///
+        /// Stored as TEXT in `personal_access_tokens.scopes` (not a Postgres enum), so
+        /// new scopes need no migration. A stored scope that no longer parses grants
+        /// nothing (fail-closed).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Hash)]
+        pub enum TokenScope {
+            $(
+                $(#[$meta])*
+                #[serde(rename = $code)]
+                $variant,
+            )+
+        }
+
+        impl TokenScope {
+            /// Returns the canonical string representation.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(TokenScope::$variant => $code,)+
+                }
+            }
+
+            /// Every defined scope.
+            pub const ALL: &'static [TokenScope] = &[
+                $(TokenScope::$variant,)+
+            ];
+        }
+
+        impl FromStr for TokenScope {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($code => Ok(TokenScope::$variant),)+
+                    _ => Err(format!("Unknown token scope: {}", s)),
+                }
+            }
+        }
+    };
+}
+
+define_token_scopes! {
+    /// Read the owner's profile (`GET /v0/user/me`).
+    ProfileRead => "profile:read",
+    /// Update the owner's profile fields.
+    ProfileWrite => "profile:write",
+    /// Create, edit and delete posts and comments as the owner.
+    BoardWrite => "board:write",
+    /// Read the owner's notifications and unread count.
+    NotificationsRead => "notifications:read",
+    /// Mark the owner's notifications read or delete them.
+    NotificationsWrite => "notifications:write",
+}
+
+impl fmt::Display for TokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenScope;
+
+    #[test]
+    fn representations_stay_in_lockstep() {
+        for scope in TokenScope::ALL {
+            let code = scope.as_str();
+            assert_eq!(code.parse::<TokenScope>().as_ref(), Ok(scope));
+            let json = serde_json::to_string(scope).unwrap();
+            assert_eq!(json, format!("\"{code}\""));
+            assert_eq!(serde_json::from_str::<TokenScope>(&json).unwrap(), *scope);
+        }
+    }
+}
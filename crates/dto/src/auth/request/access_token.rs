@@ -0,0 +1,28 @@
+use constants::TokenScope;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Request body for minting a personal access token.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for minting a personal access token.")]
+pub struct CreateAccessTokenRequest {
+    /// Label shown in the token list (e.g. "CI deploy bot")
+    #[schema(example = "CI deploy bot")]
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Name must be between 1 and 64 characters."
+    ))]
+    pub name: String,
+    /// Scopes the token is limited to; at least one
+    #[validate(length(
+        min = 1,
+        max = 32,
+        message = "Scopes must list between 1 and 32 entries."
+    ))]
+    pub scopes: Vec<TokenScope>,
+    /// Lifetime in days; omit for a token that never expires
+    #[validate(range(min = 1, max = 365, message = "Expiry must be between 1 and 365 days."))]
+    pub expires_in_days: Option<i64>,
+}
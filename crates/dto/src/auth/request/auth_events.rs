@@ -0,0 +1,17 @@
+use crate::pagination::CursorDirection;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+/// Query for the authenticated user's login history, newest first.
+pub struct ListAuthEventsRequest {
+    /// Cursor ID for pagination. None means get latest.
+    pub cursor_id: Option<Uuid>,
+    /// Cursor direction (default: Older when cursor_id is provided)
+    pub cursor_direction: Option<CursorDirection>,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100."))]
+    pub limit: u64,
+}
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Request body for requesting a magic sign-in link.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for requesting a magic sign-in link.")]
+pub struct MagicLinkRequest {
+    /// Email address to send the sign-in link to
+    #[schema(example = "user@example.com")]
+    #[validate(
+        email,
+        length(max = 254, message = "Email must not exceed 254 characters.")
+    )]
+    pub email: String,
+}
+
+/// Request body for signing in with the emailed magic-link token.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for signing in with a magic link.")]
+pub struct MagicLinkVerifyRequest {
+    /// The single-use token delivered to the account's email address.
+    #[validate(length(min = 1, message = "Token must not be empty."))]
+    pub token: String,
+    /// Whether to stay signed in (30 days if checked; expires when the browser closes if not)
+    #[serde(default)]
+    #[schema(example = false)]
+    pub remember_me: bool,
+}
@@ -1,9 +1,15 @@
+pub mod access_token;
+pub mod auth_events;
 pub mod change_email;
 pub mod change_password;
 pub mod complete_signup;
 pub mod confirm_email_change;
 pub mod forgot_password;
 pub mod login;
+pub mod magic_link;
+pub mod passkey_login;
+pub mod passkey_register;
+pub mod passkey_second_factor;
 pub mod resend_verification_email;
 pub mod reset_password;
 pub mod set_initial_password;
@@ -14,12 +20,18 @@ pub mod totp_verify;
 pub mod verify_device;
 pub mod verify_email;
 
+pub use access_token::CreateAccessTokenRequest;
+pub use auth_events::ListAuthEventsRequest;
 pub use change_email::ChangeEmailRequest;
 pub use change_password::ChangePasswordRequest;
 pub use complete_signup::CompleteSignupRequest;
 pub use confirm_email_change::ConfirmEmailChangeRequest;
 pub use forgot_password::ForgotPasswordRequest;
 pub use login::LoginRequest;
+pub use magic_link::{MagicLinkRequest, MagicLinkVerifyRequest};
+pub use passkey_login::PasskeyLoginRequest;
+pub use passkey_register::PasskeyRegisterRequest;
+pub use passkey_second_factor::{PasskeySecondFactorOptionsRequest, PasskeySecondFactorRequest};
 pub use resend_verification_email::ResendVerificationEmailRequest;
 pub use reset_password::ResetPasswordRequest;
 pub use set_initial_password::SetInitialPasswordRequest;
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+use webauthn_rs_proto::PublicKeyCredential;
+
+/// Request body for finishing a passwordless passkey login.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for finishing a passwordless passkey login.")]
+pub struct PasskeyLoginRequest {
+    /// Ceremony id returned by POST /v0/auth/passkeys/login/options
+    #[validate(length(min = 1, message = "Ceremony id must not be empty."))]
+    pub ceremony_id: String,
+    /// `navigator.credentials.get()` result, serialized as JSON (`PublicKeyCredential.toJSON()`)
+    #[schema(value_type = Object)]
+    pub credential: PublicKeyCredential,
+    /// Whether to stay signed in (30 days if checked; expires when the browser closes if not)
+    #[serde(default)]
+    #[schema(example = false)]
+    pub remember_me: bool,
+}
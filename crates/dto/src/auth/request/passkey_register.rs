@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+use webauthn_rs_proto::RegisterPublicKeyCredential;
+
+/// Request body for finishing a passkey registration.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for finishing a passkey registration.")]
+pub struct PasskeyRegisterRequest {
+    /// Ceremony id returned by POST /v0/auth/passkeys/register/options
+    #[validate(length(min = 1, message = "Ceremony id must not be empty."))]
+    pub ceremony_id: String,
+    /// Label shown in the passkey list (e.g. "MacBook Touch ID")
+    #[schema(example = "MacBook Touch ID")]
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Name must be between 1 and 64 characters."
+    ))]
+    pub name: String,
+    /// `navigator.credentials.create()` result, serialized as JSON (`PublicKeyCredential.toJSON()`)
+    #[schema(value_type = Object)]
+    pub credential: RegisterPublicKeyCredential,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+use webauthn_rs_proto::PublicKeyCredential;
+
+/// Request body for starting a passkey second factor.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for starting a passkey second factor after password login.")]
+pub struct PasskeySecondFactorOptionsRequest {
+    /// Temporary token received at login
+    #[validate(length(min = 1, message = "Temporary token must not be empty."))]
+    pub temp_token: String,
+}
+
+/// Request body for finishing login with a passkey instead of a TOTP code.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for finishing login with a passkey second factor.")]
+pub struct PasskeySecondFactorRequest {
+    /// Temporary token received at login
+    #[validate(length(min = 1, message = "Temporary token must not be empty."))]
+    pub temp_token: String,
+    /// Ceremony id returned by POST /v0/auth/passkeys/second-factor/options
+    #[validate(length(min = 1, message = "Ceremony id must not be empty."))]
+    pub ceremony_id: String,
+    /// `navigator.credentials.get()` result, serialized as JSON (`PublicKeyCredential.toJSON()`)
+    #[schema(value_type = Object)]
+    pub credential: PublicKeyCredential,
+}
@@ -0,0 +1,68 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use constants::TokenScope;
+use entity::personal_access_tokens::Model as AccessTokenModel;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One personal access token (the token itself is never returned after creation).
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "A personal access token of the authenticated account.")]
+pub struct AccessTokenResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<TokenScope>,
+    pub created_at: DateTime<Utc>,
+    /// `null` if the token never expires.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Last request authenticated with this token; `null` if never used.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<AccessTokenModel> for AccessTokenResponse {
+    fn from(model: AccessTokenModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            // Scopes that no longer parse grant nothing, so they are not listed either.
+            scopes: model.scopes.iter().filter_map(|s| s.parse().ok()).collect(),
+            created_at: model.created_at,
+            expires_at: model.expires_at,
+            last_used_at: model.last_used_at,
+        }
+    }
+}
+
+/// A newly minted token, including the raw value shown this one time.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(
+    description = "A newly minted personal access token. `token` is shown only in this response."
+)]
+pub struct AccessTokenCreatedResponse {
+    /// Send as `Authorization: Bearer <token>`
+    pub token: String,
+    #[serde(flatten)]
+    pub info: AccessTokenResponse,
+}
+
+impl IntoResponse for AccessTokenCreatedResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::CREATED, Json(self)).into_response()
+    }
+}
+
+/// The authenticated account's personal access tokens, newest first.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "Personal access tokens of the authenticated account, newest first.")]
+pub struct AccessTokenListResponse {
+    pub data: Vec<AccessTokenResponse>,
+}
+
+impl IntoResponse for AccessTokenListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,56 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use entity::auth_events::Model as AuthEventModel;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One entry of the authenticated user's login history.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "An authentication event recorded for the authenticated account.")]
+pub struct AuthEventResponse {
+    pub id: Uuid,
+    /// e.g. `login_success`, `login_failed`, `password_changed`, `totp_enabled`
+    pub event_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// Event-specific details (e.g. the sign-in method or a token id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<JsonValue>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuthEventModel> for AuthEventResponse {
+    fn from(model: AuthEventModel) -> Self {
+        Self {
+            id: model.id,
+            event_type: model.event_type,
+            ip_address: model.ip.map(|ip| ip.ip().to_string()),
+            user_agent: model.user_agent,
+            metadata: model.metadata,
+            created_at: model.created_at,
+        }
+    }
+}
+
+/// A page of the authenticated user's login history.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(
+    description = "A page of the authenticated account's authentication events, newest first."
+)]
+pub struct AuthEventListResponse {
+    pub data: Vec<AuthEventResponse>,
+    pub has_newer: bool,
+    pub has_older: bool,
+}
+
+impl IntoResponse for AuthEventListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
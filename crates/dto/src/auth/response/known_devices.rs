@@ -0,0 +1,37 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One device trusted to sign in without new-device verification.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "A device the authenticated account has verified.")]
+pub struct KnownDeviceResponse {
+    pub id: Uuid,
+    /// User-Agent when the device was verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// IP address of the last sign-in from this device
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_ip: Option<String>,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    /// True if this request presented the device's token
+    pub is_current: bool,
+}
+
+/// The authenticated account's known devices, most recently seen first.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "Devices the authenticated account has verified, most recently seen first.")]
+pub struct KnownDeviceListResponse {
+    pub data: Vec<KnownDeviceResponse>,
+}
+
+impl IntoResponse for KnownDeviceListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
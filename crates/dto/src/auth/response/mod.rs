@@ -1,9 +1,14 @@
+pub mod access_token;
 pub mod app_device_verify;
+pub mod auth_events;
 pub mod cookie;
 pub mod device_cookie;
 pub mod device_verification_required;
+pub mod known_devices;
 pub mod login;
 pub mod logout;
+pub mod passkey;
+pub mod passkey_options;
 pub mod session_token;
 pub mod sessions;
 pub mod totp_backup_codes;
@@ -12,14 +17,21 @@ pub mod totp_required;
 pub mod totp_setup;
 pub mod totp_status;
 
+pub use access_token::{AccessTokenCreatedResponse, AccessTokenListResponse, AccessTokenResponse};
 pub use app_device_verify::AppDeviceVerifyResponse;
+pub use auth_events::{AuthEventListResponse, AuthEventResponse};
 pub use cookie::session_cookie_name;
 pub use device_cookie::{DEVICE_TOKEN_HEADER, build_device_cookie, device_cookie_name};
 pub use device_verification_required::DeviceVerificationRequiredResponse;
+pub use known_devices::{KnownDeviceListResponse, KnownDeviceResponse};
 pub use login::create_login_response;
 pub use logout::create_logout_response;
+pub use passkey::{PasskeyListResponse, PasskeyResponse};
+pub use passkey_options::{
+    PasskeyAuthenticationOptionsResponse, PasskeyRegistrationOptionsResponse,
+};
 pub use session_token::SessionTokenResponse;
-pub use sessions::{ListSessionsResponse, SessionInfo};
+pub use sessions::{ListSessionsResponse, RevokeOtherSessionsResponse, SessionInfo};
 pub use totp_backup_codes::TotpBackupCodesResponse;
 pub use totp_enable::TotpEnableResponse;
 pub use totp_required::TotpRequiredResponse;
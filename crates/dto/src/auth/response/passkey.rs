@@ -0,0 +1,49 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use entity::user_passkeys::Model as UserPasskeyModel;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One registered passkey (the credential itself is never returned).
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "A passkey registered to the authenticated account.")]
+pub struct PasskeyResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    /// Last successful login with this passkey; `null` if never used.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<UserPasskeyModel> for PasskeyResponse {
+    fn from(model: UserPasskeyModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            created_at: model.created_at,
+            last_used_at: model.last_used_at,
+        }
+    }
+}
+
+impl IntoResponse for PasskeyResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::CREATED, Json(self)).into_response()
+    }
+}
+
+/// The authenticated account's passkeys, newest first.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "Passkeys registered to the authenticated account, newest first.")]
+pub struct PasskeyListResponse {
+    pub data: Vec<PasskeyResponse>,
+}
+
+impl IntoResponse for PasskeyListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,40 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use webauthn_rs_proto::{CreationChallengeResponse, RequestChallengeResponse};
+
+/// Options for `navigator.credentials.create()`, plus the id of the pending ceremony.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "WebAuthn registration options and the ceremony id to finish it with.")]
+pub struct PasskeyRegistrationOptionsResponse {
+    /// Single-use ceremony id; send it back with the authenticator's response (expires in 5 minutes)
+    pub ceremony_id: String,
+    /// `CredentialCreationOptions` (`{ publicKey: ... }`), binary fields base64url-encoded
+    #[schema(value_type = Object)]
+    pub options: CreationChallengeResponse,
+}
+
+impl IntoResponse for PasskeyRegistrationOptionsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
+
+/// Options for `navigator.credentials.get()`, plus the id of the pending ceremony.
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "WebAuthn authentication options and the ceremony id to finish it with.")]
+pub struct PasskeyAuthenticationOptionsResponse {
+    /// Single-use ceremony id; send it back with the authenticator's response (expires in 5 minutes)
+    pub ceremony_id: String,
+    /// `CredentialRequestOptions` (`{ publicKey: ... }`), binary fields base64url-encoded
+    #[schema(value_type = Object)]
+    pub options: RequestChallengeResponse,
+}
+
+impl IntoResponse for PasskeyAuthenticationOptionsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -39,3 +39,16 @@ impl IntoResponse for ListSessionsResponse {
         Json(self).into_response()
     }
 }
+
+/// Result of signing out every other session
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(description = "Number of sessions revoked by signing out everywhere else.")]
+pub struct RevokeOtherSessionsResponse {
+    pub revoked: u64,
+}
+
+impl IntoResponse for RevokeOtherSessionsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
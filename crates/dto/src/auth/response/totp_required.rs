@@ -10,6 +10,9 @@ use utoipa::ToSchema;
 pub struct TotpRequiredResponse {
     /// Temporary token for TOTP verification
     pub temp_token: String,
+    /// Whether the account has a passkey that can be used instead of a TOTP code
+    /// (POST /v0/auth/passkeys/second-factor)
+    pub passkey_available: bool,
 }
 
 impl IntoResponse for TotpRequiredResponse {
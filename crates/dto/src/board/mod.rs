@@ -2,17 +2,25 @@ pub mod request;
 pub mod response;
 
 pub use request::{
-    BoardPostModerationRequest, BoardPostReorderPinsRequest, CreateBoardCommentRequest,
-    CreateBoardPostRequest, CreateBoardRequest, DeleteBoardCommentRequest, DeleteBoardPostRequest,
-    DeleteBoardRequest, GetBoardBySlugRequest, GetBoardCommentsRequest, GetBoardPermissionsRequest,
-    GetBoardPostRequest, GetBoardPostsRequest, GetBoardRequest, GetBoardsRequest,
-    ParseBoardRequest, UpdateBoardCommentRequest, UpdateBoardPostRequest, UpdateBoardRequest,
+    BoardCommentModerationRequest, BoardCommentReactionRequest, BoardPostModerationRequest,
+    BoardPostReactionRequest, BoardPostReorderPinsRequest, BoardPostSort, BoardPostTopWindow,
+    BoardPostWatchRequest, CreateBoardCommentRequest, CreateBoardPostRequest, CreateBoardRequest,
+    DeleteBoardCommentRequest, DeleteBoardPostRequest, DeleteBoardRequest, GetBoardBySlugRequest,
+    GetBoardCommentRevisionsRequest, GetBoardCommentsRequest, GetBoardPermissionsRequest,
+    GetBoardPostRequest, GetBoardPostRevisionsRequest, GetBoardPostsRequest, GetBoardRequest,
+    GetBoardRevisionDiffRequest, GetBoardRevisionRequest, GetBoardsRequest, ParseBoardRequest,
+    RestoreBoardRevisionRequest, UpdateBoardCommentRequest, UpdateBoardPostRequest,
+    UpdateBoardRequest,
 };
 
 pub use response::{
-    BoardCommentListResponse, BoardCommentResponse, BoardListResponse, BoardPermissionsResponse,
-    BoardPostListResponse, BoardPostModerationResponse, BoardPostReorderPinsResponse,
-    BoardPostResponse, BoardResponse, CreateBoardCommentResponse, CreateBoardPostResponse,
-    CreateBoardResponse, DeleteBoardCommentResponse, DeleteBoardPostResponse, DeleteBoardResponse,
-    UpdateBoardCommentResponse, UpdateBoardPostResponse, UpdateBoardResponse,
+    BoardCommentListResponse, BoardCommentModerationResponse, BoardCommentReactionResponse,
+    BoardCommentResponse, BoardListResponse, BoardPermissionsResponse, BoardPostListResponse,
+    BoardPostModerationResponse, BoardPostReactionResponse, BoardPostReorderPinsResponse,
+    BoardPostResponse, BoardPostWatchResponse, BoardResponse, BoardRevisionDiffResponse,
+    BoardRevisionListResponse, BoardRevisionResponse, BoardRevisionSummaryResponse,
+    CreateBoardCommentResponse, CreateBoardPostResponse, CreateBoardResponse,
+    DeleteBoardCommentResponse, DeleteBoardPostResponse, DeleteBoardResponse, ParseBoardResponse,
+    RestoreBoardRevisionResponse, UpdateBoardCommentResponse, UpdateBoardPostResponse,
+    UpdateBoardResponse,
 };
@@ -1,9 +1,13 @@
 pub mod create;
 pub mod delete;
 pub mod get_list;
+pub mod moderation;
+pub mod reaction;
 pub mod update;
 
 pub use create::CreateBoardCommentRequest;
 pub use delete::DeleteBoardCommentRequest;
 pub use get_list::GetBoardCommentsRequest;
+pub use moderation::BoardCommentModerationRequest;
+pub use reaction::BoardCommentReactionRequest;
 pub use update::UpdateBoardCommentRequest;
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for adding or removing the caller's reaction on a board comment.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct BoardCommentReactionRequest {
+    pub comment_id: Uuid,
+    /// One of the server's configured reaction kinds (e.g. `upvote`).
+    #[validate(length(
+        min = 1,
+        max = 32,
+        message = "Reaction kind must be between 1 and 32 characters."
+    ))]
+    pub kind: String,
+}
@@ -3,18 +3,24 @@ pub mod comment;
 pub mod parse;
 pub mod permissions;
 pub mod post;
+pub mod revision;
 
 pub use board::{
     CreateBoardRequest, DeleteBoardRequest, GetBoardBySlugRequest, GetBoardRequest,
     GetBoardsRequest, UpdateBoardRequest,
 };
 pub use comment::{
-    CreateBoardCommentRequest, DeleteBoardCommentRequest, GetBoardCommentsRequest,
-    UpdateBoardCommentRequest,
+    BoardCommentModerationRequest, BoardCommentReactionRequest, CreateBoardCommentRequest,
+    DeleteBoardCommentRequest, GetBoardCommentsRequest, UpdateBoardCommentRequest,
 };
 pub use parse::ParseBoardRequest;
 pub use permissions::GetBoardPermissionsRequest;
 pub use post::{
-    BoardPostModerationRequest, BoardPostReorderPinsRequest, CreateBoardPostRequest,
+    BoardPostModerationRequest, BoardPostReactionRequest, BoardPostReorderPinsRequest,
+    BoardPostSort, BoardPostTopWindow, BoardPostWatchRequest, CreateBoardPostRequest,
     DeleteBoardPostRequest, GetBoardPostRequest, GetBoardPostsRequest, UpdateBoardPostRequest,
 };
+pub use revision::{
+    GetBoardCommentRevisionsRequest, GetBoardPostRevisionsRequest, GetBoardRevisionDiffRequest,
+    GetBoardRevisionRequest, RestoreBoardRevisionRequest,
+};
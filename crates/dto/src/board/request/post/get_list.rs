@@ -1,14 +1,56 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::pagination::CursorDirection;
+
+/// Ordering of a board's unpinned posts. Pins are always served separately, above
+/// every page, whatever the sort.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardPostSort {
+    /// Newest first.
+    #[default]
+    New,
+    /// Most reactions first (ties broken by views) among posts created within `window`.
+    Top,
+    /// Highest time-decayed score first; the score is refreshed by a worker cron.
+    Hot,
+    /// Most comments first.
+    MostCommented,
+}
+
+/// Time window for [`BoardPostSort::Top`], counted back from now.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardPostTopWindow {
+    Day,
+    #[default]
+    Week,
+    Month,
+    Year,
+    All,
+}
+
 #[derive(Debug, Deserialize, ToSchema, Validate, IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct GetBoardPostsRequest {
     pub board_id: Uuid,
-    #[validate(range(min = 1, message = "Page must be greater than 0."))]
-    pub page: u32,
-    #[validate(range(min = 1, max = 50, message = "Page size must be between 1 and 50."))]
-    pub page_size: u32,
+    /// Cursor keyed by a post id: pass the first (`Newer`) or last (`Older`) post
+    /// of the previous page. The listing resumes from that post's position in the
+    /// current `sort`, `(created_at, id)` for `new`. Omit to fetch the first page.
+    pub cursor_id: Option<Uuid>,
+    /// Pagination direction relative to the cursor. `Older` reads further down the
+    /// listing, `Newer` back up toward its top; either way the page is returned in
+    /// listing order. Defaults to `Older` when a cursor is set; ignored otherwise.
+    pub cursor_direction: Option<CursorDirection>,
+    #[validate(range(min = 1, max = 50, message = "Limit must be between 1 and 50."))]
+    pub limit: u64,
+    /// Defaults to `new`.
+    #[serde(default)]
+    pub sort: BoardPostSort,
+    /// Only used by `sort=top`; defaults to `week`.
+    #[serde(default)]
+    pub window: BoardPostTopWindow,
 }
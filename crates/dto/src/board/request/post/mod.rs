@@ -3,13 +3,17 @@ pub mod delete;
 pub mod get;
 pub mod get_list;
 pub mod moderation;
+pub mod reaction;
 pub mod reorder_pins;
 pub mod update;
+pub mod watch;
 
 pub use create::CreateBoardPostRequest;
 pub use delete::DeleteBoardPostRequest;
 pub use get::GetBoardPostRequest;
-pub use get_list::GetBoardPostsRequest;
+pub use get_list::{BoardPostSort, BoardPostTopWindow, GetBoardPostsRequest};
 pub use moderation::BoardPostModerationRequest;
+pub use reaction::BoardPostReactionRequest;
 pub use reorder_pins::BoardPostReorderPinsRequest;
 pub use update::UpdateBoardPostRequest;
+pub use watch::BoardPostWatchRequest;
@@ -4,7 +4,7 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
-/// Shared request body for board post moderation actions (pin/unpin/lock/unlock).
+/// Shared request body for board post moderation actions (pin/unpin/lock/unlock/hide/unhide).
 /// Each action records a moderation-log entry, so a reason is required.
 #[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct BoardPostModerationRequest {
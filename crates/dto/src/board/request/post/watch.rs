@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for watching or unwatching a board post.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct BoardPostWatchRequest {
+    pub post_id: Uuid,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetBoardRevisionDiffRequest {
+    /// The older side of the diff.
+    pub from_revision_id: Uuid,
+    /// The newer side of the diff. Omit to diff against the current body. Both
+    /// revisions must belong to the same post body or the same comment.
+    pub to_revision_id: Option<Uuid>,
+}
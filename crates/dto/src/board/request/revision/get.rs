@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetBoardRevisionRequest {
+    pub revision_id: Uuid,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetBoardPostRevisionsRequest {
+    pub post_id: Uuid,
+    /// Cursor keyed by a revision id (UUIDv7, time-ordered). The list reads
+    /// newest-first; omit to start from the most recent revision, or pass the last
+    /// id of the previous page to continue toward older ones.
+    pub cursor_id: Option<Uuid>,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100."))]
+    pub limit: u64,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetBoardCommentRevisionsRequest {
+    pub comment_id: Uuid,
+    /// Cursor keyed by a revision id (UUIDv7, time-ordered). The list reads
+    /// newest-first; omit to start from the most recent revision, or pass the last
+    /// id of the previous page to continue toward older ones.
+    pub cursor_id: Option<Uuid>,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100."))]
+    pub limit: u64,
+}
@@ -0,0 +1,9 @@
+pub mod diff;
+pub mod get;
+pub mod get_list;
+pub mod restore;
+
+pub use diff::GetBoardRevisionDiffRequest;
+pub use get::GetBoardRevisionRequest;
+pub use get_list::{GetBoardCommentRevisionsRequest, GetBoardPostRevisionsRequest};
+pub use restore::RestoreBoardRevisionRequest;
@@ -0,0 +1,19 @@
+use crate::validator::string_validator::validate_not_blank;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Restore a post or comment to an earlier revision. Recorded in the moderation
+/// log, so a reason is required.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+pub struct RestoreBoardRevisionRequest {
+    pub revision_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
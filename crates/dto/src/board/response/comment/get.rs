@@ -4,6 +4,7 @@ use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -15,15 +16,27 @@ pub struct BoardCommentResponse {
     pub parent_comment_id: Option<Uuid>,
     pub author_actor_id: Uuid,
     pub author: Option<ActorResponse>,
-    /// Raw sevenmark markup (used to populate the edit form).
+    /// Raw Markdown source (used to populate the edit form).
     pub content: String,
     pub reply_count: i32,
+    /// Total reactions across all kinds.
+    pub reaction_count: i32,
+    /// Reaction tally per kind (e.g. `{"upvote": 3}`); kinds with no reactions are omitted.
+    pub reaction_counts: BTreeMap<String, i32>,
+    /// The caller's own reaction kinds on this comment; empty for anonymous callers.
+    pub viewer_reactions: Vec<String>,
     /// Whether the caller can edit this comment (author or moderator).
     pub can_edit: bool,
     /// Whether the caller can delete this comment (author or moderator).
     pub can_delete: bool,
     pub created_at: DateTime<Utc>,
     pub edited_at: Option<DateTime<Utc>>,
+    /// Whether a moderator has hidden this comment. Non-moderators get a
+    /// tombstone: `content` comes back empty, while replies stay in place.
+    pub is_hidden: bool,
+    pub hidden_at: Option<DateTime<Utc>>,
+    /// Moderator-supplied reason for hiding; only returned to moderators.
+    pub hidden_reason: Option<String>,
 }
 
 impl IntoResponse for BoardCommentResponse {
@@ -2,10 +2,14 @@ pub mod create;
 pub mod delete;
 pub mod get;
 pub mod get_list;
+pub mod moderation;
+pub mod reaction;
 pub mod update;
 
 pub use create::CreateBoardCommentResponse;
 pub use delete::DeleteBoardCommentResponse;
 pub use get::BoardCommentResponse;
 pub use get_list::BoardCommentListResponse;
+pub use moderation::BoardCommentModerationResponse;
+pub use reaction::BoardCommentReactionResponse;
 pub use update::UpdateBoardCommentResponse;
@@ -0,0 +1,19 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Result of a board comment moderation action, echoing the comment's resulting state.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BoardCommentModerationResponse {
+    pub comment_id: Uuid,
+    pub is_hidden: bool,
+}
+
+impl IntoResponse for BoardCommentModerationResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -1,17 +1,26 @@
 pub mod board;
 pub mod comment;
+pub mod parse;
 pub mod permissions;
 pub mod post;
+pub mod revision;
 
 pub use board::{
     BoardListResponse, BoardResponse, CreateBoardResponse, DeleteBoardResponse, UpdateBoardResponse,
 };
 pub use comment::{
-    BoardCommentListResponse, BoardCommentResponse, CreateBoardCommentResponse,
-    DeleteBoardCommentResponse, UpdateBoardCommentResponse,
+    BoardCommentListResponse, BoardCommentModerationResponse, BoardCommentReactionResponse,
+    BoardCommentResponse, CreateBoardCommentResponse, DeleteBoardCommentResponse,
+    UpdateBoardCommentResponse,
 };
+pub use parse::ParseBoardResponse;
 pub use permissions::BoardPermissionsResponse;
 pub use post::{
-    BoardPostListResponse, BoardPostModerationResponse, BoardPostReorderPinsResponse,
-    BoardPostResponse, CreateBoardPostResponse, DeleteBoardPostResponse, UpdateBoardPostResponse,
+    BoardPostListResponse, BoardPostModerationResponse, BoardPostReactionResponse,
+    BoardPostReorderPinsResponse, BoardPostResponse, BoardPostWatchResponse,
+    CreateBoardPostResponse, DeleteBoardPostResponse, UpdateBoardPostResponse,
+};
+pub use revision::{
+    BoardRevisionDiffResponse, BoardRevisionListResponse, BoardRevisionResponse,
+    BoardRevisionSummaryResponse, RestoreBoardRevisionResponse,
 };
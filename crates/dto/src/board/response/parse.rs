@@ -0,0 +1,18 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Preview of board markup, rendered exactly as a saved post or comment would be.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ParseBoardResponse {
+    /// Sanitized HTML.
+    pub rendered_content: String,
+}
+
+impl IntoResponse for ParseBoardResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -12,13 +12,12 @@ pub struct BoardPostListResponse {
     /// pagination: this is the complete set on every page, and none of them
     /// appear in `posts`.
     pub pinned: Vec<BoardPostResponse>,
-    /// One page of the board's unpinned posts, newest first.
+    /// One page of the board's unpinned posts, in listing order.
     pub posts: Vec<BoardPostResponse>,
-    pub current_page: u32,
-    pub page_size: u32,
-    /// Whether another page of `posts` exists. Pins are not paged and never
-    /// affect this.
-    pub has_more: bool,
+    /// Whether posts exist above / below this page. Pins are not paged and never
+    /// affect these.
+    pub has_newer: bool,
+    pub has_older: bool,
 }
 
 impl IntoResponse for BoardPostListResponse {
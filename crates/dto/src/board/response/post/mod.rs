@@ -3,13 +3,17 @@ pub mod delete;
 pub mod get;
 pub mod get_list;
 pub mod moderation;
+pub mod reaction;
 pub mod reorder_pins;
 pub mod update;
+pub mod watch;
 
 pub use create::CreateBoardPostResponse;
 pub use delete::DeleteBoardPostResponse;
 pub use get::BoardPostResponse;
 pub use get_list::BoardPostListResponse;
 pub use moderation::BoardPostModerationResponse;
+pub use reaction::BoardPostReactionResponse;
 pub use reorder_pins::BoardPostReorderPinsResponse;
 pub use update::UpdateBoardPostResponse;
+pub use watch::BoardPostWatchResponse;
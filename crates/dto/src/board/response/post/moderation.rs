@@ -11,6 +11,7 @@ pub struct BoardPostModerationResponse {
     pub post_id: Uuid,
     pub is_pinned: bool,
     pub is_locked: bool,
+    pub is_hidden: bool,
 }
 
 impl IntoResponse for BoardPostModerationResponse {
@@ -0,0 +1,22 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Result of adding or removing a reaction, echoing the post's resulting tallies.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BoardPostReactionResponse {
+    pub post_id: Uuid,
+    pub reaction_count: i32,
+    pub reaction_counts: BTreeMap<String, i32>,
+    pub viewer_reactions: Vec<String>,
+}
+
+impl IntoResponse for BoardPostReactionResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
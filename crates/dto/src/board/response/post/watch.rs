@@ -0,0 +1,19 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Result of watching or unwatching a post: whether the caller now watches it.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BoardPostWatchResponse {
+    pub post_id: Uuid,
+    pub watching: bool,
+}
+
+impl IntoResponse for BoardPostWatchResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
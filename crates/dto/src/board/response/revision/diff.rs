@@ -0,0 +1,27 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Line diff between two bodies of the same post or comment.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardRevisionDiffResponse {
+    pub from_revision_id: Uuid,
+    /// `None` when the newer side is the current body.
+    pub to_revision_id: Option<Uuid>,
+    /// Post titles on each side; `None` for comments.
+    pub from_title: Option<String>,
+    pub to_title: Option<String>,
+    /// Unified diff of the bodies (3 lines of context).
+    pub diff: String,
+    pub additions: u32,
+    pub deletions: u32,
+}
+
+impl IntoResponse for BoardRevisionDiffResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
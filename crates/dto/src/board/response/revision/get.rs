@@ -0,0 +1,40 @@
+use crate::actor::ActorResponse;
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Metadata of one superseded body of a post or comment.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardRevisionSummaryResponse {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    /// `None` for a revision of the post body; the comment's id otherwise.
+    pub comment_id: Option<Uuid>,
+    /// Post title before the edit; always `None` for comment revisions.
+    pub title: Option<String>,
+    /// Uncompressed body length in bytes.
+    pub content_length: i32,
+    /// Actor whose edit (or restore) replaced this body.
+    pub actor_id: Uuid,
+    pub actor: Option<ActorResponse>,
+    /// When this body was replaced.
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BoardRevisionResponse {
+    #[serde(flatten)]
+    pub revision: BoardRevisionSummaryResponse,
+    /// Raw markup of the body as it was before the edit.
+    pub content: String,
+}
+
+impl IntoResponse for BoardRevisionResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,21 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::BoardRevisionSummaryResponse;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardRevisionListResponse {
+    /// Newest first.
+    pub data: Vec<BoardRevisionSummaryResponse>,
+    /// Whether older revisions exist past the last item.
+    pub has_older: bool,
+}
+
+impl IntoResponse for BoardRevisionListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
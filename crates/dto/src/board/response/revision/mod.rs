@@ -0,0 +1,9 @@
+pub mod diff;
+pub mod get;
+pub mod get_list;
+pub mod restore;
+
+pub use diff::BoardRevisionDiffResponse;
+pub use get::{BoardRevisionResponse, BoardRevisionSummaryResponse};
+pub use get_list::BoardRevisionListResponse;
+pub use restore::RestoreBoardRevisionResponse;
@@ -0,0 +1,23 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RestoreBoardRevisionResponse {
+    pub post_id: Uuid,
+    pub comment_id: Option<Uuid>,
+    /// The revision whose body is now current.
+    pub restored_revision_id: Uuid,
+    /// New revision holding the body the restore replaced, so a restore can itself
+    /// be undone.
+    pub superseded_revision_id: Uuid,
+}
+
+impl IntoResponse for RestoreBoardRevisionResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
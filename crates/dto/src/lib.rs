@@ -11,6 +11,8 @@ pub mod moderation;
 pub mod notification;
 pub mod oauth;
 pub mod pagination;
+pub mod report;
 pub mod search;
 pub mod user;
 pub mod validator;
+pub mod webhooks;
@@ -2,12 +2,15 @@ pub mod request;
 pub mod response;
 
 pub use request::{
-    DeleteNotificationRequest, GetNotificationsRequest, MarkNotificationAsReadRequest,
+    CreateSystemAnnouncementRequest, DeleteNotificationRequest, GetNotificationsRequest,
+    MarkNotificationAsReadRequest, PushSubscriptionKeys, RetractSystemAnnouncementRequest,
+    SubscribePushRequest, UnsubscribeNotificationEmailRequest, UnsubscribePushRequest,
     UpdateActionPreferenceRequest, UpdateActionPreferencesBulkRequest,
     UpdateNotificationPreferenceRequest,
 };
 pub use response::{
     NotificationActionPreferenceListResponse, NotificationActionPreferenceResponse,
     NotificationListResponse, NotificationPreferenceResponse, NotificationResponse,
-    UnreadCountResponse,
+    PushSubscriptionListResponse, PushSubscriptionResponse, SystemAnnouncementResponse,
+    UnreadCountResponse, VapidPublicKeyResponse,
 };
@@ -0,0 +1,55 @@
+use crate::validator::string_validator::validate_not_blank;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+/// Request payload for broadcasting a system announcement to every active user.
+pub struct CreateSystemAnnouncementRequest {
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Title must be between 1 and 200 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub title: String,
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Body must be between 1 and 5000 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub body: String,
+    /// Where the announcement points (e.g. a blog post or a policy page)
+    #[validate(url(message = "Link must be a valid URL."))]
+    #[validate(length(max = 2048, message = "Link must be at most 2048 characters."))]
+    pub link: Option<String>,
+    /// Deliver even to users who turned `system_announcement` off (default false).
+    /// For notices users must not miss, such as policy changes.
+    #[serde(default)]
+    pub mandatory: bool,
+    /// Recorded on the `notification:announce` moderation log entry
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Reason must be between 1 and 1000 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+/// Request payload for retracting a system announcement.
+pub struct RetractSystemAnnouncementRequest {
+    /// The announcement id returned when it was created
+    pub id: Uuid,
+    /// Recorded on the `notification:retract_announcement` moderation log entry
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Reason must be between 1 and 1000 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
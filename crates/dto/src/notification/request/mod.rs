@@ -1,9 +1,15 @@
 pub mod action_preference;
+pub mod announcement;
 pub mod notification;
 pub mod preference;
+pub mod push;
+pub mod unsubscribe;
 
 pub use action_preference::{UpdateActionPreferenceRequest, UpdateActionPreferencesBulkRequest};
+pub use announcement::{CreateSystemAnnouncementRequest, RetractSystemAnnouncementRequest};
 pub use notification::{
     DeleteNotificationRequest, GetNotificationsRequest, MarkNotificationAsReadRequest,
 };
 pub use preference::UpdateNotificationPreferenceRequest;
+pub use push::{PushSubscriptionKeys, SubscribePushRequest, UnsubscribePushRequest};
+pub use unsubscribe::UnsubscribeNotificationEmailRequest;
@@ -1,3 +1,4 @@
+use entity::common::NotificationDigestFrequency;
 use serde::Deserialize;
 use utoipa::ToSchema;
 use validator::Validate;
@@ -7,4 +8,6 @@ use validator::Validate;
 pub struct UpdateNotificationPreferenceRequest {
     pub email_enabled: Option<bool>,
     pub push_enabled: Option<bool>,
+    /// How often to email a digest of unread notifications (`Off` to stop).
+    pub digest_frequency: Option<NotificationDigestFrequency>,
 }
@@ -0,0 +1,41 @@
+use crate::validator::push_validator::{
+    validate_push_auth, validate_push_endpoint, validate_push_p256dh,
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+/// Request payload registering this device for Web Push. Same shape as the
+/// browser's `PushSubscription.toJSON()` (`expirationTime` is ignored).
+pub struct SubscribePushRequest {
+    /// The push service URL the browser returned (HTTPS)
+    #[validate(url(message = "Endpoint must be a valid URL."))]
+    #[validate(length(max = 2048, message = "Endpoint must be at most 2048 characters."))]
+    #[validate(custom(function = "validate_push_endpoint"))]
+    pub endpoint: String,
+    #[validate(nested)]
+    pub keys: PushSubscriptionKeys,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+/// The subscription's encryption keys, base64url.
+pub struct PushSubscriptionKeys {
+    /// The browser's P-256 public key (uncompressed point)
+    #[validate(custom(function = "validate_push_p256dh"))]
+    pub p256dh: String,
+    /// The 16-byte authentication secret
+    #[validate(custom(function = "validate_push_auth"))]
+    pub auth: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+/// Request payload removing this device's Web Push subscription.
+pub struct UnsubscribePushRequest {
+    #[validate(length(
+        min = 1,
+        max = 2048,
+        message = "Endpoint must be between 1 and 2048 characters."
+    ))]
+    pub endpoint: String,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+/// Query for the one-click notification email unsubscribe.
+pub struct UnsubscribeNotificationEmailRequest {
+    /// Unsubscribe token from the notification email's link.
+    pub token: Uuid,
+}
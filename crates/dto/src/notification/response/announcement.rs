@@ -0,0 +1,25 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for a newly queued system announcement.
+pub struct SystemAnnouncementResponse {
+    /// The announcement's `notification_events` id, used to retract it
+    pub id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub link: Option<String>,
+    pub mandatory: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl IntoResponse for SystemAnnouncementResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::CREATED, Json(self)).into_response()
+    }
+}
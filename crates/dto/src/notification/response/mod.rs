@@ -1,9 +1,13 @@
 pub mod action_preference;
+pub mod announcement;
 pub mod notification;
 pub mod preference;
+pub mod push;
 
 pub use action_preference::{
     NotificationActionPreferenceListResponse, NotificationActionPreferenceResponse,
 };
+pub use announcement::SystemAnnouncementResponse;
 pub use notification::{NotificationListResponse, NotificationResponse, UnreadCountResponse};
 pub use preference::NotificationPreferenceResponse;
+pub use push::{PushSubscriptionListResponse, PushSubscriptionResponse, VapidPublicKeyResponse};
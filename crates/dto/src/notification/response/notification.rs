@@ -25,6 +25,13 @@ pub struct NotificationResponse {
     pub is_read: bool,
     pub created_at: DateTime<Utc>,
     pub read_at: Option<DateTime<Utc>>,
+    /// Unread notifications with the same action and target collapsed into this
+    /// one; 1 for a read or ungrouped notification. `id` is the newest member.
+    pub group_size: u64,
+    /// Distinct actors across the group ("Alice and 4 others" is 5).
+    pub actor_count: u64,
+    /// The group's most recent distinct actors, newest first (at most 3).
+    pub recent_actors: Vec<ActorResponse>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -2,6 +2,7 @@ use axum::Json;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use chrono::{DateTime, Utc};
+use entity::common::NotificationDigestFrequency;
 use serde::Serialize;
 use utoipa::ToSchema;
 
@@ -10,6 +11,7 @@ use utoipa::ToSchema;
 pub struct NotificationPreferenceResponse {
     pub email_enabled: bool,
     pub push_enabled: bool,
+    pub digest_frequency: NotificationDigestFrequency,
     pub updated_at: DateTime<Utc>,
 }
 
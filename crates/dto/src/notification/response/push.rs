@@ -0,0 +1,47 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for one registered Web Push device.
+pub struct PushSubscriptionResponse {
+    pub id: Uuid,
+    pub endpoint: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl IntoResponse for PushSubscriptionResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload listing the caller's Web Push devices, newest first.
+pub struct PushSubscriptionListResponse {
+    pub data: Vec<PushSubscriptionResponse>,
+}
+
+impl IntoResponse for PushSubscriptionListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload carrying the server's VAPID public key.
+pub struct VapidPublicKeyResponse {
+    /// Pass as `applicationServerKey` to `PushManager.subscribe()` (base64url).
+    /// `null` when Web Push is not configured on this server.
+    pub public_key: Option<String>,
+}
+
+impl IntoResponse for VapidPublicKeyResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -27,3 +27,16 @@ pub struct GithubLinkRequest {
     #[validate(length(min = 1, message = "State is required"))]
     pub state: String,
 }
+
+/// OpenID Connect link request
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for linking an OpenID Connect identity to the current user.")]
+pub struct OidcLinkRequest {
+    /// Authorization code from the provider's callback
+    #[validate(length(min = 1, message = "Authorization code is required"))]
+    pub code: String,
+
+    /// State parameter for CSRF protection
+    #[validate(length(min = 1, message = "State is required"))]
+    pub state: String,
+}
@@ -2,10 +2,12 @@ pub mod authorize;
 pub mod github;
 pub mod google;
 pub mod link;
+pub mod oidc;
 pub mod unlink;
 
 pub use authorize::{OAuthAuthorizeFlow, OAuthAuthorizeQuery};
 pub use github::{GithubLoginRequest, GithubTokenRequest};
 pub use google::{GoogleLoginRequest, GoogleOneTapLoginRequest, GoogleTokenRequest};
-pub use link::{GithubLinkRequest, GoogleLinkRequest};
+pub use link::{GithubLinkRequest, GoogleLinkRequest, OidcLinkRequest};
+pub use oidc::{OidcLoginRequest, OidcTokenRequest};
 pub use unlink::UnlinkOAuthRequest;
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// OpenID Connect sign-in request
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+#[schema(description = "Request body for signing in with an OpenID Connect provider.")]
+pub struct OidcLoginRequest {
+    /// Authorization code from the provider's callback
+    #[validate(length(min = 1, message = "Authorization code is required"))]
+    pub code: String,
+
+    /// State parameter for CSRF protection
+    #[validate(length(min = 1, message = "State is required"))]
+    pub state: String,
+}
+
+/// Native-app OpenID Connect sign-in request (provider-token flow).
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+#[schema(
+    description = "Request body for native-app OpenID Connect sign-in with a provider ID token."
+)]
+pub struct OidcTokenRequest {
+    /// ID token the app obtained from the provider with our client id.
+    // ID tokens are compact JWTs; cap to bound parse/DoS cost (as for Google).
+    #[validate(length(min = 1, max = 8192, message = "ID token is required"))]
+    pub id_token: String,
+}
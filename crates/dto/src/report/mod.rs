@@ -0,0 +1,7 @@
+pub mod request;
+pub mod response;
+
+pub use request::{
+    CreateReportRequest, DismissReportRequest, ListReportsRequest, ResolveReportRequest,
+};
+pub use response::{ListReportsResponse, ReportListItem, ReportResponse};
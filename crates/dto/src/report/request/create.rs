@@ -0,0 +1,17 @@
+use entity::common::{ReportReason, ReportTargetType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+/// Request payload for reporting a board post, comment or user profile.
+pub struct CreateReportRequest {
+    pub target_type: ReportTargetType,
+    /// Post, comment or user id depending on `target_type`
+    pub target_id: Uuid,
+    pub reason: ReportReason,
+    /// Free-text details for the moderators
+    #[validate(length(max = 2000, message = "Description must be at most 2000 characters."))]
+    pub description: Option<String>,
+}
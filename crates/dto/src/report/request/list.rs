@@ -0,0 +1,21 @@
+use crate::pagination::CursorDirection;
+use entity::common::{ReportReason, ReportStatus, ReportTargetType};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListReportsRequest {
+    pub cursor_id: Option<Uuid>,
+    pub cursor_direction: Option<CursorDirection>,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100."))]
+    pub limit: u64,
+    /// Defaults to every status; pass `Open` for the working queue
+    pub status: Option<ReportStatus>,
+    pub target_type: Option<ReportTargetType>,
+    pub target_id: Option<Uuid>,
+    pub reporter_id: Option<Uuid>,
+    pub reason: Option<ReportReason>,
+}
@@ -0,0 +1,7 @@
+pub mod create;
+pub mod list;
+pub mod moderation;
+
+pub use create::CreateReportRequest;
+pub use list::ListReportsRequest;
+pub use moderation::{DismissReportRequest, ResolveReportRequest};
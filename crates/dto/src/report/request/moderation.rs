@@ -0,0 +1,30 @@
+use crate::validator::string_validator::validate_not_blank;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+/// Request payload for resolving a report with a moderation action already taken.
+pub struct ResolveReportRequest {
+    pub report_id: Uuid,
+    /// The moderation log entry of the action taken on the reported target
+    /// (e.g. the hide or ban); it must act on the same target.
+    pub moderation_log_id: Uuid,
+    #[validate(length(max = 1000, message = "Note must be at most 1000 characters."))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+/// Request payload for dismissing a report without action.
+pub struct DismissReportRequest {
+    pub report_id: Uuid,
+    /// Recorded on the dismissal's moderation log entry
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Reason must be between 1 and 1000 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
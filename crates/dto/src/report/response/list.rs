@@ -0,0 +1,45 @@
+use axum::{Json, response::IntoResponse};
+use chrono::{DateTime, Utc};
+use entity::common::{ReportReason, ReportStatus, ReportTargetType};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One row of the moderator report queue.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReportListItem {
+    pub id: Uuid,
+    /// NULL once the reporter's account is deleted
+    pub reporter_id: Option<Uuid>,
+    pub target_type: ReportTargetType,
+    pub target_id: Uuid,
+    pub reason: ReportReason,
+    pub description: Option<String>,
+    pub status: ReportStatus,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolution_note: Option<String>,
+    pub moderation_log_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    /// Open reports on the same target, from all reporters
+    pub target_open_reports: i64,
+    /// All reports ever filed on the same target
+    pub target_total_reports: i64,
+    /// All reports ever filed by this reporter
+    pub reporter_total_reports: i64,
+    /// Reports by this reporter that moderators dismissed
+    pub reporter_dismissed_reports: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListReportsResponse {
+    pub data: Vec<ReportListItem>,
+    pub has_newer: bool,
+    pub has_older: bool,
+}
+
+impl IntoResponse for ListReportsResponse {
+    fn into_response(self) -> axum::response::Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod list;
+pub mod report;
+
+pub use list::{ListReportsResponse, ReportListItem};
+pub use report::ReportResponse;
@@ -0,0 +1,46 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use entity::common::{ReportReason, ReportStatus, ReportTargetType};
+use entity::reports::Model as ReportModel;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A single report and its current state.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportResponse {
+    pub id: Uuid,
+    pub target_type: ReportTargetType,
+    pub target_id: Uuid,
+    pub reason: ReportReason,
+    pub description: Option<String>,
+    pub status: ReportStatus,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Moderation log entry of the action taken (or the dismissal)
+    pub moderation_log_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportModel> for ReportResponse {
+    fn from(model: ReportModel) -> Self {
+        Self {
+            id: model.id,
+            target_type: model.target_type,
+            target_id: model.target_id,
+            reason: model.reason,
+            description: model.description,
+            status: model.status,
+            resolved_at: model.resolved_at,
+            moderation_log_id: model.moderation_log_id,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl IntoResponse for ReportResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
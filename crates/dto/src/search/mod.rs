@@ -1,5 +1,12 @@
 pub mod request;
 pub mod response;
 
-pub use request::{SearchUsersRequest, SortOrder};
-pub use response::{SearchUsersResponse, UserSearchItem};
+pub use request::{
+    GetSearchReindexStatusRequest, SearchCommentsRequest, SearchPostsRequest, SearchReindexTarget,
+    SearchUsersRequest, SortOrder, StartSearchReindexRequest,
+};
+pub use response::{
+    CommentSearchItem, PostSearchItem, SearchCommentsResponse, SearchPostsResponse,
+    SearchReindexStatus, SearchReindexStatusResponse, SearchUsersResponse,
+    StartSearchReindexResponse, UserSearchItem,
+};
@@ -1,5 +1,11 @@
+pub mod comments;
 pub mod common;
+pub mod posts;
+pub mod reindex;
 pub mod users;
 
+pub use comments::SearchCommentsRequest;
 pub use common::SortOrder;
+pub use posts::SearchPostsRequest;
+pub use reindex::{GetSearchReindexStatusRequest, SearchReindexTarget, StartSearchReindexRequest};
 pub use users::SearchUsersRequest;
@@ -0,0 +1,40 @@
+use crate::validator::string_validator::validate_not_blank;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Search index a reindex run rebuilds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum SearchReindexTarget {
+    Users,
+    Posts,
+    Comments,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
+/// Request payload for starting a full search reindex.
+pub struct StartSearchReindexRequest {
+    pub target: SearchReindexTarget,
+    /// Items per batch (default 1,000).
+    #[validate(range(
+        min = 1,
+        max = 10000,
+        message = "Batch size must be between 1 and 10000."
+    ))]
+    pub batch_size: Option<u32>,
+    /// Recorded on the run's `search:reindex` moderation log entry
+    #[validate(length(
+        min = 1,
+        max = 1000,
+        message = "Reason must be between 1 and 1000 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetSearchReindexStatusRequest {
+    pub reindex_id: Uuid,
+}
@@ -1,3 +1,9 @@
+pub mod comments;
+pub mod posts;
+pub mod reindex;
 pub mod users;
 
+pub use comments::{CommentSearchItem, SearchCommentsResponse};
+pub use posts::{PostSearchItem, SearchPostsResponse};
+pub use reindex::{SearchReindexStatus, SearchReindexStatusResponse, StartSearchReindexResponse};
 pub use users::{SearchUsersResponse, UserSearchItem};
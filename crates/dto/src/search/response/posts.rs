@@ -0,0 +1,32 @@
+use axum::{Json, http::StatusCode, response::IntoResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PostSearchItem {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub title: String,
+    /// Excerpt of the content around the matched terms.
+    pub snippet: String,
+    pub created_at: DateTime<Utc>,
+    /// Only ever `true` for moderators; hidden posts are filtered out for everyone else.
+    pub is_hidden: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchPostsResponse {
+    pub posts: Vec<PostSearchItem>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_hits: u64,
+    pub total_pages: u32,
+}
+
+impl IntoResponse for SearchPostsResponse {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,51 @@
+use crate::search::request::SearchReindexTarget;
+use axum::{Json, http::StatusCode, response::IntoResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StartSearchReindexResponse {
+    /// Poll `GET /v0/search/reindex` with this id for progress.
+    pub reindex_id: Uuid,
+}
+
+impl IntoResponse for StartSearchReindexResponse {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::ACCEPTED, Json(self)).into_response()
+    }
+}
+
+/// State of a reindex run as last recorded by the worker.
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+pub enum SearchReindexStatus {
+    /// Queued, between batches, or retrying a batch that errored (see `error`).
+    Running,
+    /// The rebuilt index was swapped in.
+    Completed,
+    /// A batch exhausted its retries and was dead-lettered; the run has stopped.
+    Failed,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchReindexStatusResponse {
+    pub reindex_id: Uuid,
+    pub target: SearchReindexTarget,
+    pub status: SearchReindexStatus,
+    /// Last batch that ran (0 until the first one has).
+    pub batch_number: u32,
+    pub processed_count: u64,
+    /// Item count taken when the first batch ran.
+    pub total_count: Option<u64>,
+    /// Error of the last failed batch attempt.
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl IntoResponse for SearchReindexStatusResponse {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
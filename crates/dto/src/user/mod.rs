@@ -4,10 +4,11 @@ pub mod response;
 pub use request::{
     BanUserRequest, CheckHandleAvailablePath, ConfirmAccountDeletionRequest, CreateUserRequest,
     DeleteMyAccountRequest, GetUserProfileByIdRequest, GetUserProfileRequest, GrantRoleRequest,
-    RevokeRoleRequest, UnbanUserRequest, UpdateMyProfileRequest, UploadUserImageRequest,
+    RevokeRoleRequest, UnbanUserRequest, UnlockUserRequest, UpdateMyProfileRequest,
+    UploadUserImageRequest,
 };
 pub use response::{
     BanUserResponse, CheckHandleAvailableResponse, CreateUserResponse, GrantRoleResponse,
-    PublicUserProfile, RevokeRoleResponse, UnbanUserResponse, UploadUserImageResponse,
-    UserBriefResponse, UserResponse,
+    PublicUserProfile, RevokeRoleResponse, UnbanUserResponse, UnlockUserResponse,
+    UploadUserImageResponse, UserBriefResponse, UserResponse,
 };
@@ -8,6 +8,7 @@ pub mod get_user_profile_by_id;
 pub mod grant_role;
 pub mod revoke_role;
 pub mod unban_user;
+pub mod unlock_user;
 pub mod update_my_profile;
 pub mod upload_user_image;
 
@@ -21,5 +22,6 @@ pub use get_user_profile_by_id::GetUserProfileByIdRequest;
 pub use grant_role::GrantRoleRequest;
 pub use revoke_role::RevokeRoleRequest;
 pub use unban_user::UnbanUserRequest;
+pub use unlock_user::UnlockUserRequest;
 pub use update_my_profile::UpdateMyProfileRequest;
 pub use upload_user_image::UploadUserImageRequest;
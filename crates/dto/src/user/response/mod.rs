@@ -5,6 +5,7 @@ pub mod grant_role;
 pub mod public_user_profile;
 pub mod revoke_role;
 pub mod unban_user;
+pub mod unlock_user;
 pub mod upload_user_image;
 pub mod user_brief;
 pub mod user_profile;
@@ -16,6 +17,7 @@ pub use grant_role::GrantRoleResponse;
 pub use public_user_profile::PublicUserProfile;
 pub use revoke_role::RevokeRoleResponse;
 pub use unban_user::UnbanUserResponse;
+pub use unlock_user::UnlockUserResponse;
 pub use upload_user_image::UploadUserImageResponse;
 pub use user_brief::UserBriefResponse;
 pub use user_profile::UserResponse;
@@ -0,0 +1,20 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+/// Response payload for unlock user response.
+pub struct UnlockUserResponse {
+    pub user_id: Uuid,
+}
+
+impl IntoResponse for UnlockUserResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
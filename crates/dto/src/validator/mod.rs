@@ -2,5 +2,8 @@ pub mod datetime_validator;
 pub mod json_validator;
 pub mod multipart_validator;
 pub mod path_validator;
+pub mod push_validator;
 pub mod query_validator;
 pub mod string_validator;
+pub mod url_validator;
+pub mod webhook_validator;
@@ -0,0 +1,68 @@
+use super::url_validator::is_internal_host;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use url::{Host, Url};
+use validator::ValidationError;
+
+/// Validates a Web Push endpoint: push services are only ever reached over HTTPS
+/// at a public host name, so IP literals and local names are rejected.
+pub fn validate_push_endpoint(endpoint: &str) -> Result<(), ValidationError> {
+    let url = Url::parse(endpoint).map_err(|_| ValidationError::new("invalid_push_endpoint"))?;
+    if url.scheme() != "https" {
+        return Err(ValidationError::new("push_endpoint_must_be_https"));
+    }
+    if !matches!(url.host(), Some(Host::Domain(_))) || is_internal_host(&url) {
+        return Err(ValidationError::new("push_endpoint_host_not_allowed"));
+    }
+    Ok(())
+}
+
+/// Validates a subscription's `p256dh` key: a base64url uncompressed P-256 point
+/// (65 bytes, leading 0x04).
+pub fn validate_push_p256dh(key: &str) -> Result<(), ValidationError> {
+    match decode_base64url(key) {
+        Some(bytes) if bytes.len() == 65 && bytes[0] == 0x04 => Ok(()),
+        _ => Err(ValidationError::new("invalid_push_p256dh")),
+    }
+}
+
+/// Validates a subscription's `auth` secret: 16 bytes, base64url.
+pub fn validate_push_auth(secret: &str) -> Result<(), ValidationError> {
+    match decode_base64url(secret) {
+        Some(bytes) if bytes.len() == 16 => Ok(()),
+        _ => Err(ValidationError::new("invalid_push_auth")),
+    }
+}
+
+/// Browsers emit unpadded base64url; tolerate padding anyway.
+fn decode_base64url(value: &str) -> Option<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(value.trim_end_matches('=')).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_push_endpoint;
+
+    #[test]
+    fn push_endpoint_accepts_push_services() {
+        assert!(validate_push_endpoint("https://fcm.googleapis.com/fcm/send/abc").is_ok());
+        assert!(
+            validate_push_endpoint("https://updates.push.services.mozilla.com/wpush/v2/x").is_ok()
+        );
+    }
+
+    #[test]
+    fn push_endpoint_requires_https() {
+        assert!(validate_push_endpoint("http://fcm.googleapis.com/fcm/send/abc").is_err());
+        assert!(validate_push_endpoint("not a url").is_err());
+    }
+
+    #[test]
+    fn push_endpoint_rejects_internal_hosts() {
+        assert!(validate_push_endpoint("https://localhost/push").is_err());
+        assert!(validate_push_endpoint("https://127.0.0.1/push").is_err());
+        assert!(validate_push_endpoint("https://169.254.169.254/latest").is_err());
+        assert!(validate_push_endpoint("https://93.184.216.34/push").is_err());
+        assert!(validate_push_endpoint("https://[::1]/push").is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use std::net::{IpAddr, Ipv4Addr};
+use url::{Host, Url};
+
+/// Whether `url` targets this machine or a private network: `localhost` names,
+/// and loopback, link-local, private or unspecified IP literals.
+///
+/// Guards URLs the worker calls on a user's behalf (push endpoints, webhook
+/// receivers) so they cannot be aimed at internal services. Names that only
+/// resolve to a private address are not caught here.
+pub fn is_internal_host(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_internal_ip(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => is_internal_ip(IpAddr::V6(ip)),
+        None => true,
+    }
+}
+
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_internal_ipv4(mapped),
+            None => {
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+            }
+        },
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    // 100.64.0.0/10 (carrier-grade NAT) is routed privately like RFC 1918 space.
+    let shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64;
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || shared
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_internal_host;
+    use url::Url;
+
+    fn internal(url: &str) -> bool {
+        is_internal_host(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn flags_loopback_private_and_link_local_hosts() {
+        assert!(internal("http://localhost:8000/"));
+        assert!(internal("http://api.localhost/"));
+        assert!(internal("http://127.0.0.1/"));
+        assert!(internal("http://10.0.0.5/"));
+        assert!(internal("http://172.16.0.1/"));
+        assert!(internal("http://192.168.1.1/"));
+        assert!(internal("http://100.64.0.1/"));
+        assert!(internal("http://169.254.169.254/latest/meta-data"));
+        assert!(internal("http://0.0.0.0/"));
+        assert!(internal("http://[::1]/"));
+        assert!(internal("http://[fd00::1]/"));
+        assert!(internal("http://[fe80::1]/"));
+        assert!(internal("http://[::ffff:127.0.0.1]/"));
+    }
+
+    #[test]
+    fn allows_public_hosts() {
+        assert!(!internal("https://example.com/hook"));
+        assert!(!internal("https://93.184.216.34/hook"));
+        assert!(!internal("https://[2606:4700::1111]/hook"));
+        assert!(!internal("https://localhost.example.com/hook"));
+    }
+}
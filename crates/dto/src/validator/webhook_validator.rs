@@ -0,0 +1,65 @@
+use super::url_validator::is_internal_host;
+use config::ServerConfig;
+use constants::{ActionLogAction, ModerationAction};
+use url::Url;
+use validator::ValidationError;
+
+/// Validates a webhook URL: an HTTPS receiver on a public host. Plain HTTP is
+/// accepted only when `WEBHOOK_ALLOW_HTTP` is set (dev and test stacks).
+pub fn validate_webhook_url(url: &str) -> Result<(), ValidationError> {
+    check_webhook_url(url, ServerConfig::get().webhook_allow_http)
+}
+
+fn check_webhook_url(url: &str, allow_http: bool) -> Result<(), ValidationError> {
+    let url = Url::parse(url).map_err(|_| ValidationError::new("invalid_webhook_url"))?;
+    match url.scheme() {
+        "https" => {}
+        "http" if allow_http => {}
+        _ => return Err(ValidationError::new("webhook_url_must_be_https")),
+    }
+    if is_internal_host(&url) {
+        return Err(ValidationError::new("webhook_url_host_not_allowed"));
+    }
+    Ok(())
+}
+
+/// Validates webhook event codes: each must be a known `ActionLogAction` or
+/// `ModerationAction` code.
+pub fn validate_webhook_events(events: &[String]) -> Result<(), ValidationError> {
+    let known = |event: &String| {
+        event.parse::<ActionLogAction>().is_ok() || event.parse::<ModerationAction>().is_ok()
+    };
+    if !events.iter().all(known) {
+        return Err(ValidationError::new("unknown_webhook_event"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_webhook_url;
+
+    #[test]
+    fn webhook_url_requires_https_unless_allowed() {
+        assert!(check_webhook_url("https://hooks.example.com/in", false).is_ok());
+        assert!(check_webhook_url("http://hooks.example.com/in", false).is_err());
+        assert!(check_webhook_url("http://hooks.example.com/in", true).is_ok());
+        assert!(check_webhook_url("ftp://hooks.example.com/in", true).is_err());
+        assert!(check_webhook_url("http://", true).is_err());
+        assert!(check_webhook_url("https://", false).is_err());
+    }
+
+    #[test]
+    fn webhook_url_rejects_internal_hosts() {
+        for url in [
+            "https://169.254.169.254/latest/meta-data",
+            "http://localhost:8000/hook",
+            "https://127.0.0.1/hook",
+            "https://10.1.2.3/hook",
+            "https://192.168.0.10/hook",
+            "https://[::1]/hook",
+        ] {
+            assert!(check_webhook_url(url, true).is_err(), "accepted {url}");
+        }
+    }
+}
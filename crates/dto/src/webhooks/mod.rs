@@ -0,0 +1,13 @@
+pub mod payload;
+pub mod request;
+pub mod response;
+
+pub use payload::{WebhookEventPayload, WebhookEventSource};
+pub use request::{
+    CreateWebhookEndpointRequest, DeleteWebhookEndpointRequest, ListWebhookDeliveriesRequest,
+    RedeliverWebhookRequest, RotateWebhookSecretRequest, UpdateWebhookEndpointRequest,
+};
+pub use response::{
+    WebhookDeliveryListResponse, WebhookDeliveryResponse, WebhookEndpointListResponse,
+    WebhookEndpointResponse, WebhookEndpointSecretResponse,
+};
@@ -0,0 +1,56 @@
+use crate::action_logs::ActionLogResponse;
+use crate::moderation::ModerationLogListItem;
+use chrono::{DateTime, Utc};
+use entity::action_logs::Model as ActionLogModel;
+use entity::moderation_logs::Model as ModerationLogModel;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// Which log a webhook event comes from.
+pub enum WebhookEventSource {
+    ActionLog,
+    ModerationLog,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+/// JSON body POSTed to a webhook endpoint.
+pub struct WebhookEventPayload {
+    /// The log entry's id; stable across retries and redeliveries, so receivers can
+    /// dedupe on it
+    pub id: Uuid,
+    #[serde(rename = "type")]
+    pub source: WebhookEventSource,
+    /// The `ActionLogAction` / `ModerationAction` code, e.g. `post:create`
+    pub event: String,
+    pub created_at: DateTime<Utc>,
+    /// The log entry as `/v0/action-logs` or `/v0/moderation/logs` return it
+    pub data: JsonValue,
+}
+
+impl From<ActionLogModel> for WebhookEventPayload {
+    fn from(log: ActionLogModel) -> Self {
+        Self {
+            id: log.id,
+            source: WebhookEventSource::ActionLog,
+            event: log.action.clone(),
+            created_at: log.created_at,
+            data: serde_json::to_value(ActionLogResponse::from(log)).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<ModerationLogModel> for WebhookEventPayload {
+    fn from(log: ModerationLogModel) -> Self {
+        Self {
+            id: log.id,
+            source: WebhookEventSource::ModerationLog,
+            event: log.action.clone(),
+            created_at: log.created_at,
+            data: serde_json::to_value(ModerationLogListItem::from(log)).unwrap_or_default(),
+        }
+    }
+}
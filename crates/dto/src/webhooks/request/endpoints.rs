@@ -0,0 +1,87 @@
+use crate::validator::string_validator::validate_not_blank;
+use crate::validator::webhook_validator::{validate_webhook_events, validate_webhook_url};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+/// Request payload for registering a webhook endpoint.
+pub struct CreateWebhookEndpointRequest {
+    /// Receiver URL (http or https)
+    #[validate(url(message = "URL must be a valid URL."))]
+    #[validate(length(max = 2048, message = "URL must be at most 2048 characters."))]
+    #[validate(custom(function = "validate_webhook_url"))]
+    pub url: String,
+    #[validate(length(max = 500, message = "Description must be at most 500 characters."))]
+    pub description: Option<String>,
+    /// `ActionLogAction` / `ModerationAction` codes to deliver, e.g. `post:create`
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Events must list between 1 and 64 codes."
+    ))]
+    #[validate(custom(function = "validate_webhook_events"))]
+    pub events: Vec<String>,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+/// Request payload for changing a webhook endpoint. Omitted fields are kept.
+pub struct UpdateWebhookEndpointRequest {
+    pub endpoint_id: Uuid,
+    #[validate(url(message = "URL must be a valid URL."))]
+    #[validate(length(max = 2048, message = "URL must be at most 2048 characters."))]
+    #[validate(custom(function = "validate_webhook_url"))]
+    pub url: Option<String>,
+    #[validate(length(max = 500, message = "Description must be at most 500 characters."))]
+    pub description: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Events must list between 1 and 64 codes."
+    ))]
+    #[validate(custom(function = "validate_webhook_events"))]
+    pub events: Option<Vec<String>>,
+    /// Paused endpoints get no new deliveries
+    pub is_active: Option<bool>,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+/// Request payload for deleting a webhook endpoint and its delivery log.
+pub struct DeleteWebhookEndpointRequest {
+    pub endpoint_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, Validate)]
+/// Request payload for replacing a webhook endpoint's signing secret.
+pub struct RotateWebhookSecretRequest {
+    pub endpoint_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters."
+    ))]
+    #[validate(custom(function = "validate_not_blank"))]
+    pub reason: String,
+}
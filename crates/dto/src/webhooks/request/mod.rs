@@ -0,0 +1,8 @@
+pub mod deliveries;
+pub mod endpoints;
+
+pub use deliveries::{ListWebhookDeliveriesRequest, RedeliverWebhookRequest};
+pub use endpoints::{
+    CreateWebhookEndpointRequest, DeleteWebhookEndpointRequest, RotateWebhookSecretRequest,
+    UpdateWebhookEndpointRequest,
+};
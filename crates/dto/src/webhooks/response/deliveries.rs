@@ -0,0 +1,63 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use entity::common::WebhookDeliveryStatus;
+use entity::webhook_deliveries::Model as WebhookDeliveryModel;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for one logged webhook delivery.
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub endpoint_id: Uuid,
+    pub event: String,
+    pub log_id: Uuid,
+    /// The body sent to the endpoint
+    pub payload: JsonValue,
+    pub status: WebhookDeliveryStatus,
+    /// Attempts so far, counting retries and redeliveries
+    pub attempts: i32,
+    /// HTTP status of the last attempt, if the endpoint answered
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDeliveryModel> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDeliveryModel) -> Self {
+        Self {
+            id: delivery.id,
+            endpoint_id: delivery.endpoint_id,
+            event: delivery.event,
+            log_id: delivery.log_id,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            delivered_at: delivery.delivered_at,
+            created_at: delivery.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for a page of an endpoint's delivery log.
+pub struct WebhookDeliveryListResponse {
+    pub data: Vec<WebhookDeliveryResponse>,
+    pub has_newer: bool,
+    pub has_older: bool,
+}
+
+impl IntoResponse for WebhookDeliveryListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,71 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use entity::webhook_endpoints::Model as WebhookEndpointModel;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for one webhook endpoint. The signing secret is never listed.
+pub struct WebhookEndpointResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub description: String,
+    pub events: Vec<String>,
+    pub is_active: bool,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookEndpointModel> for WebhookEndpointResponse {
+    fn from(endpoint: WebhookEndpointModel) -> Self {
+        Self {
+            id: endpoint.id,
+            url: endpoint.url,
+            description: endpoint.description,
+            events: endpoint.events,
+            is_active: endpoint.is_active,
+            created_by: endpoint.created_by,
+            created_at: endpoint.created_at,
+            updated_at: endpoint.updated_at,
+        }
+    }
+}
+
+impl IntoResponse for WebhookEndpointResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for a created endpoint or a rotated secret. This is the only
+/// time the secret is shown.
+pub struct WebhookEndpointSecretResponse {
+    pub endpoint: WebhookEndpointResponse,
+    /// HMAC-SHA256 key for the `X-AxumKit-Signature` header
+    pub secret: String,
+}
+
+impl IntoResponse for WebhookEndpointSecretResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Response payload for listing webhook endpoints.
+pub struct WebhookEndpointListResponse {
+    pub endpoints: Vec<WebhookEndpointResponse>,
+}
+
+impl IntoResponse for WebhookEndpointListResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,7 @@
+pub mod deliveries;
+pub mod endpoints;
+
+pub use deliveries::{WebhookDeliveryListResponse, WebhookDeliveryResponse};
+pub use endpoints::{
+    WebhookEndpointListResponse, WebhookEndpointResponse, WebhookEndpointSecretResponse,
+};
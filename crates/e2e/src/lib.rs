@@ -9,6 +9,7 @@
 //!     127.0.0.1 rather than `localhost`: on hosts where `localhost` resolves to ::1
 //!     first, Docker's IPv6 port proxy can time out instead of connecting)
 //!   - `MAILPIT_URL`   mailpit web/REST API base   (default `http://127.0.0.1:18025`)
+//!   - `E2E_OIDC_URL`  mock OpenID Connect IdP     (default `http://127.0.0.1:18080`)
 
 use std::time::Duration;
 
@@ -31,6 +32,11 @@ pub fn mailpit_url() -> String {
     std::env::var("MAILPIT_URL").unwrap_or_else(|_| "http://127.0.0.1:18025".to_string())
 }
 
+/// Mock OpenID Connect IdP base URL (issuers live under `/{issuer_id}`).
+pub fn oidc_url() -> String {
+    std::env::var("E2E_OIDC_URL").unwrap_or_else(|_| "http://127.0.0.1:18080".to_string())
+}
+
 /// Connection string for the disposable test database.
 ///
 /// Default port 55432 matches `docker-compose.test.yml`, which deliberately avoids host
@@ -110,6 +116,41 @@ pub async fn backdate_user(handle: &str, days: i64) {
     db.close().await.expect("close test db connection");
 }
 
+/// Moves a personal access token's `expires_at` into the past via the disposable test
+/// database, so expiry can be tested without waiting out the minimum one-day lifetime
+/// (same bootstrap rationale as [`grant_role`]).
+pub async fn expire_access_token(token_id: Uuid) {
+    let db = test_db().await;
+    entity::personal_access_tokens::ActiveModel {
+        id: Set(token_id),
+        expires_at: Set(Some(chrono::Utc::now() - chrono::Duration::minutes(1))),
+        ..Default::default()
+    }
+    .update(&db)
+    .await
+    .expect("expire access token");
+
+    db.close().await.expect("close test db connection");
+}
+
+/// Turns a webhook delivery back into a claim whose job was never published: `pending`,
+/// with `dispatched_at` an hour old, as if the worker died between claim and publish
+/// (same bootstrap rationale as [`grant_role`]).
+pub async fn strand_webhook_delivery(delivery_id: Uuid) {
+    let db = test_db().await;
+    entity::webhook_deliveries::ActiveModel {
+        id: Set(delivery_id),
+        status: Set(entity::common::WebhookDeliveryStatus::Pending),
+        dispatched_at: Set(Some(chrono::Utc::now() - chrono::Duration::hours(1))),
+        ..Default::default()
+    }
+    .update(&db)
+    .await
+    .expect("strand webhook delivery");
+
+    db.close().await.expect("close test db connection");
+}
+
 /// Returns a unique suffix so concurrent tests never collide on handle/email.
 ///
 /// Uses a v4 (fully random) UUID — NOT v7, whose leading hex is a millisecond timestamp
@@ -151,7 +192,7 @@ impl Default for TestClient {
 impl TestClient {
     /// A new anonymous client (empty cookie jar).
     pub fn new() -> Self {
-        Self::build(None)
+        Self::build(reqwest::header::HeaderMap::new())
     }
 
     /// A client whose requests present `ip` as the client address via the
@@ -159,14 +200,25 @@ impl TestClient {
     /// that header, so tests can express per-actor IPs (e.g. for IP-ban policy tests)
     /// without real network differences. Use TEST-NET addresses (203.0.113.0/24).
     pub fn with_ip(ip: &str) -> Self {
-        Self::build(Some(ip))
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("CF-Connecting-IP", ip.parse().expect("valid IP header"));
+        Self::build(headers)
     }
 
-    fn build(ip: Option<&str>) -> Self {
+    /// A client that authenticates every request with `Authorization: Bearer <token>` (a
+    /// native-app session token or a personal access token) instead of cookies.
+    pub fn with_bearer(token: &str) -> Self {
         let mut headers = reqwest::header::HeaderMap::new();
-        if let Some(ip) = ip {
-            headers.insert("CF-Connecting-IP", ip.parse().expect("valid IP header"));
-        }
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {token}")
+                .parse()
+                .expect("valid bearer header"),
+        );
+        Self::build(headers)
+    }
+
+    fn build(headers: reqwest::header::HeaderMap) -> Self {
         let http = Client::builder()
             .cookie_store(true)
             .default_headers(headers)
@@ -0,0 +1,181 @@
+//! Personal access token e2e tests. Run against the docker test stack.
+//!
+//! Policy references:
+//! - Tokens are minted, listed and revoked only from an interactive session; the raw token is
+//!   returned once (`akpat_` prefix) and stored hashed.
+//! - A token authenticates `Authorization: Bearer` requests on `ScopedSession` routes whose
+//!   scope it holds; other scopes are 403 `access_token:insufficient_scope`, and
+//!   session-only routes (account, credentials, sessions) do not accept it at all.
+//! - Revoked or unknown tokens are 401; expired tokens are 401 `access_token:expired`.
+
+use e2e::TestClient;
+use entity::common::Role;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+/// Mints a token for the signed-in `client`, returning the creation response.
+async fn create_token(client: &TestClient, scopes: &[&str]) -> Value {
+    let resp = client
+        .post_json(
+            "/v0/auth/tokens",
+            &json!({ "name": "e2e automation", "scopes": scopes, "expires_in_days": 30 }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::CREATED).await
+}
+
+#[tokio::test]
+async fn access_token_create_list_use_and_revoke() {
+    let client = TestClient::new();
+    let user = client.signup_and_login().await;
+
+    let created = create_token(&client, &["profile:read", "profile:read"]).await;
+    let token = created["token"].as_str().expect("token");
+    assert!(token.starts_with("akpat_"));
+    assert_eq!(created["scopes"], json!(["profile:read"]));
+    assert!(created["expires_at"].is_string());
+    let token_id = created["id"].as_str().expect("token id").to_string();
+
+    let resp = client.get("/v0/auth/tokens").await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(list["data"].as_array().unwrap().len(), 1);
+    assert!(
+        list["data"][0].get("token").is_none(),
+        "raw token must not be listed"
+    );
+    assert!(list["data"][0]["last_used_at"].is_null());
+
+    let bot = TestClient::with_bearer(token);
+    assert_eq!(bot.me().await["handle"], user.handle.as_str());
+
+    let resp = client.get("/v0/auth/tokens").await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(list["data"][0]["last_used_at"].is_string());
+
+    let resp = client.delete(&format!("/v0/auth/tokens/{token_id}")).await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    let resp = bot.get("/v0/user/me").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = client.delete(&format!("/v0/auth/tokens/{token_id}")).await;
+    let body = TestClient::json_ok(resp, StatusCode::NOT_FOUND).await;
+    assert_eq!(body["code"], "access_token:not_found");
+}
+
+#[tokio::test]
+async fn access_token_is_limited_to_its_scopes() {
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let created = create_token(&client, &["profile:read"]).await;
+    let bot = TestClient::with_bearer(created["token"].as_str().unwrap());
+
+    let resp = bot
+        .patch_json("/v0/user/me", &json!({ "display_name": "Bot" }))
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::FORBIDDEN).await;
+    assert_eq!(body["code"], "access_token:insufficient_scope");
+
+    let resp = bot.get("/v0/notifications/unread/count").await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    // Session-only routes: a token cannot list or mint tokens, or see sessions.
+    let resp = bot.get("/v0/auth/tokens").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    let resp = bot
+        .post_json(
+            "/v0/auth/tokens",
+            &json!({ "name": "escalate", "scopes": ["board:write"] }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    let resp = bot.get("/v0/auth/sessions").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn access_token_with_board_write_posts_as_its_owner() {
+    let admin = TestClient::new();
+    let admin_user = admin.signup_and_login().await;
+    e2e::grant_role(&admin_user.handle, Role::Admin).await;
+    let slug = format!("e2e-{}", &e2e::unique()[..12]);
+    let resp = admin
+        .post_json("/v0/board", &json!({ "slug": slug, "name": "PAT board" }))
+        .await;
+    let board = TestClient::json_ok(resp, StatusCode::CREATED).await;
+
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let created = create_token(&client, &["board:write", "notifications:read"]).await;
+    let bot = TestClient::with_bearer(created["token"].as_str().unwrap());
+
+    let resp = bot
+        .post_json(
+            "/v0/board/post",
+            &json!({ "board_id": board["id"], "title": "from CI", "content": "body" }),
+        )
+        .await;
+    let post = TestClient::json_ok(resp, StatusCode::CREATED).await;
+
+    let resp = bot
+        .post_json("/v0/board/post/delete", &json!({ "post_id": post["id"] }))
+        .await;
+    assert!(
+        resp.status().is_success(),
+        "owner delete: {}",
+        resp.status()
+    );
+
+    let resp = bot.get("/v0/notifications/unread/count").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    // `profile:read` was not granted.
+    let resp = bot.get("/v0/user/me").await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn expired_and_unknown_access_tokens_are_rejected() {
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let created = create_token(&client, &["profile:read"]).await;
+    let token_id: Uuid = created["id"].as_str().unwrap().parse().unwrap();
+    e2e::expire_access_token(token_id).await;
+
+    let resp = TestClient::with_bearer(created["token"].as_str().unwrap())
+        .get("/v0/user/me")
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::UNAUTHORIZED).await;
+    assert_eq!(body["code"], "access_token:expired");
+
+    let resp = TestClient::with_bearer("akpat_not-a-real-token")
+        .get("/v0/user/me")
+        .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn access_token_requests_are_validated() {
+    let client = TestClient::new();
+    client.signup_and_login().await;
+
+    for payload in [
+        json!({ "name": "x", "scopes": [] }),
+        json!({ "name": "x", "scopes": ["admin:everything"] }),
+        json!({ "name": "", "scopes": ["profile:read"] }),
+        json!({ "name": "x", "scopes": ["profile:read"], "expires_in_days": 0 }),
+        json!({ "name": "x", "scopes": ["profile:read"], "expires_in_days": 366 }),
+    ] {
+        let resp = client.post_json("/v0/auth/tokens", &payload).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "payload: {payload}");
+    }
+
+    // No expiry is allowed.
+    let resp = client
+        .post_json(
+            "/v0/auth/tokens",
+            &json!({ "name": "forever", "scopes": ["profile:read"] }),
+        )
+        .await;
+    let created = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    assert!(created["expires_at"].is_null());
+}
@@ -0,0 +1,109 @@
+//! Action log e2e tests. Run against the docker test stack.
+//!
+//! Mutating services write an `action_logs` row inside their own transaction, so
+//! `/v0/action-logs` must reflect a write as soon as the request returns.
+
+use e2e::TestClient;
+use entity::common::Role;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+/// Reads the action codes logged for `resource_id`, newest first.
+async fn actions_for_resource(client: &TestClient, resource_id: &str) -> Vec<String> {
+    let resp = client
+        .get_q(
+            "/v0/action-logs",
+            &[("limit", "100"), ("resource_id", resource_id)],
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    body["data"]
+        .as_array()
+        .expect("data array")
+        .iter()
+        .map(|log| log["action"].as_str().expect("action").to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn post_lifecycle_is_recorded() {
+    let admin = TestClient::new();
+    let admin_user = admin.signup_and_login().await;
+    e2e::grant_role(&admin_user.handle, Role::Admin).await;
+
+    let slug = format!("e2e-{}", &e2e::unique()[..12]);
+    let resp = admin
+        .post_json("/v0/board", &json!({ "slug": slug, "name": "Action Logs" }))
+        .await;
+    let board = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    let board_id = board["id"].as_str().expect("board id").to_string();
+    assert_eq!(
+        actions_for_resource(&admin, &board_id).await,
+        vec!["board:create"]
+    );
+
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let author_id = author.me().await["id"].clone();
+    let resp = author
+        .post_json(
+            "/v0/board/post",
+            &json!({ "board_id": board_id, "title": "logged", "content": "body" }),
+        )
+        .await;
+    let post = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    let post_id = post["id"].as_str().expect("post id").to_string();
+
+    let resp = author
+        .post_json(
+            "/v0/board/post/update",
+            &json!({ "post_id": post_id, "content": "edited body" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let resp = author
+        .post_json("/v0/board/post/delete", &json!({ "post_id": post_id }))
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let anon = TestClient::new();
+    assert_eq!(
+        actions_for_resource(&anon, &post_id).await,
+        vec!["post:delete", "post:edit", "post:create"],
+        "every post mutation should leave an action log"
+    );
+
+    // The log is attributed to the acting user.
+    let resp = anon
+        .get_q(
+            "/v0/action-logs",
+            &[("limit", "1"), ("resource_id", post_id.as_str())],
+        )
+        .await;
+    let body: Value = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["data"][0]["actor_id"], author_id);
+    assert_eq!(body["data"][0]["resource_type"], json!("Post"));
+}
+
+#[tokio::test]
+async fn signup_and_profile_edit_are_recorded() {
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let user_id = client.me().await["id"]
+        .as_str()
+        .expect("user id")
+        .to_string();
+
+    let resp = client
+        .patch_json("/v0/user/me", &json!({ "display_name": "Renamed" }))
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let actions = actions_for_resource(&client, &user_id).await;
+    assert_eq!(actions.first().map(String::as_str), Some("user:edit"));
+    assert!(
+        actions.iter().any(|a| a == "user:create"),
+        "signup should record user:create; got {actions:?}"
+    );
+}
@@ -0,0 +1,175 @@
+//! System announcement e2e tests. Run via `just e2e`.
+//!
+//! Kept out of `notification.rs`: an announcement reaches every user's inbox,
+//! which would upset the exact unread counts asserted by tests running beside it.
+//!
+//! Policy references:
+//! - `/v0/notifications/announcements*` sit behind the router-level `require_admin`
+//!   gate (401 anon, 403 non-admin).
+//! - The worker delivers an announcement to every active user except those who
+//!   turned `system_announcement` off, unless the announcement is mandatory.
+//! - Retracting stops the fan-out and removes the unread copies; read copies stay.
+
+use std::time::Duration;
+
+use e2e::TestClient;
+use entity::common::Role;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+/// Polls the inbox until the system announcement titled `title` arrives. The worker
+/// fans announcements out in batches ordered by user id, so a user who signed up
+/// later than another is served after them.
+async fn wait_for_announcement(client: &TestClient, title: &str) -> Value {
+    for _ in 0..120 {
+        if let Some(found) = announcements(client, title).await.into_iter().next() {
+            return found;
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    panic!("timed out waiting for the `{title}` announcement");
+}
+
+/// Returns every copy of the announcement titled `title` currently in the inbox.
+async fn announcements(client: &TestClient, title: &str) -> Vec<Value> {
+    let resp = client.get("/v0/notifications/list?limit=50").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    body["data"]
+        .as_array()
+        .expect("notification list")
+        .iter()
+        .filter(|n| {
+            n["action"].as_str() == Some("system_announcement")
+                && n["additional_data"]["title"].as_str() == Some(title)
+        })
+        .cloned()
+        .collect()
+}
+
+#[tokio::test]
+async fn system_announcements_reach_every_user_and_can_be_retracted() {
+    let body = json!({
+        "title": "Maintenance",
+        "body": "Down for an hour.",
+        "reason": "e2e: announcement",
+    });
+    let anon = TestClient::new();
+    let resp = anon
+        .post_json("/v0/notifications/announcements", &body)
+        .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let reader = TestClient::new();
+    reader.signup_and_login().await;
+    let resp = reader
+        .post_json("/v0/notifications/announcements", &body)
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let opted_out = TestClient::new();
+    opted_out.signup_and_login().await;
+    let resp = opted_out
+        .post_json(
+            "/v0/notifications/preferences/actions/update",
+            &json!({ "updates": [{ "action": "system_announcement", "enabled": false }] }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let other_reader = TestClient::new();
+    other_reader.signup_and_login().await;
+
+    // Signed up last, so served last: once it has its copy, so does everyone above.
+    let admin = TestClient::new();
+    let admin_user = admin.signup_and_login().await;
+    e2e::grant_role(&admin_user.handle, Role::Admin).await;
+
+    let title = format!("Announcement {}", e2e::unique());
+    let resp = admin
+        .post_json(
+            "/v0/notifications/announcements",
+            &json!({
+                "title": title,
+                "body": "Scheduled maintenance tonight.",
+                "link": "https://example.com/status",
+                "reason": "e2e: announcement",
+            }),
+        )
+        .await;
+    let created = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    let announcement_id = created["id"].as_str().expect("announcement id").to_string();
+
+    let copy = wait_for_announcement(&admin, &title).await;
+    assert_eq!(copy["notification_type"], "System");
+    assert_eq!(
+        copy["additional_data"]["link"],
+        "https://example.com/status"
+    );
+    assert!(copy["actor_id"].is_null());
+
+    let copy = wait_for_announcement(&reader, &title).await;
+    assert_eq!(copy["is_read"].as_bool(), Some(false));
+    let read_copy = wait_for_announcement(&other_reader, &title).await;
+    let resp = other_reader
+        .post_json(
+            "/v0/notifications/mark-as-read",
+            &json!({ "notification_id": read_copy["id"] }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    assert!(
+        announcements(&opted_out, &title).await.is_empty(),
+        "users who turned system_announcement off are skipped"
+    );
+
+    // Retraction is admin-only and removes only unread copies.
+    let retract = json!({ "id": announcement_id, "reason": "e2e: retract" });
+    let resp = reader
+        .post_json("/v0/notifications/announcements/retract", &retract)
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    let resp = admin
+        .post_json(
+            "/v0/notifications/announcements/retract",
+            &json!({ "id": uuid::Uuid::now_v7(), "reason": "e2e: retract" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let resp = admin
+        .post_json("/v0/notifications/announcements/retract", &retract)
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    assert!(announcements(&reader, &title).await.is_empty());
+    assert_eq!(
+        announcements(&other_reader, &title).await.len(),
+        1,
+        "read copies survive the retraction"
+    );
+
+    // Mandatory announcements ignore the opt-out.
+    let title = format!("Policy {}", e2e::unique());
+    let resp = admin
+        .post_json(
+            "/v0/notifications/announcements",
+            &json!({
+                "title": title,
+                "body": "Our terms changed.",
+                "mandatory": true,
+                "reason": "e2e: announcement",
+            }),
+        )
+        .await;
+    let created = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    wait_for_announcement(&opted_out, &title).await;
+
+    // Retract it too, so later tests' fresh users are not handed a copy.
+    let resp = admin
+        .post_json(
+            "/v0/notifications/announcements/retract",
+            &json!({ "id": created["id"], "reason": "e2e: cleanup" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    assert!(announcements(&opted_out, &title).await.is_empty());
+}
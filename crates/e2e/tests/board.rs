@@ -6,6 +6,8 @@
 //! - Post update/delete: content edits are owner-only; `board:moderate` may delete.
 //! - Pin/unpin/lock/unlock and pin reordering are RBAC-gated (`board:pin_post`,
 //!   `board:lock_post`); the `Mod` role holds them implicitly.
+//! - Reactions are held to the write bar (login, not banned); kinds come from
+//!   `BOARD_REACTION_KINDS` (default `upvote`).
 
 use e2e::TestClient;
 use entity::common::Role;
@@ -107,18 +109,28 @@ async fn pin(moderator: &TestClient, post_id: &str) {
     );
 }
 
-/// Reads one page of a board listing as (pinned ids, paged post ids).
+/// One page of a board listing: pinned ids, paged post ids and the cursor flags.
+struct ListPage {
+    pinned: Vec<String>,
+    posts: Vec<String>,
+    has_newer: bool,
+    has_older: bool,
+}
+
+/// Reads one page of a board listing, optionally from a `(cursor_id, direction)` cursor.
 async fn list_page(
     client: &TestClient,
     board_id: &str,
-    page: u32,
-    page_size: u32,
-) -> (Vec<String>, Vec<String>) {
-    let resp = client
-        .get(&format!(
-            "/v0/board/post/list?board_id={board_id}&page={page}&page_size={page_size}"
-        ))
-        .await;
+    cursor: Option<(&str, &str)>,
+    limit: u32,
+) -> ListPage {
+    let limit = limit.to_string();
+    let mut query = vec![("board_id", board_id), ("limit", limit.as_str())];
+    if let Some((cursor_id, direction)) = cursor {
+        query.push(("cursor_id", cursor_id));
+        query.push(("cursor_direction", direction));
+    }
+    let resp = client.get_q("/v0/board/post/list", &query).await;
     let body = TestClient::json_ok(resp, StatusCode::OK).await;
     let ids = |key: &str| -> Vec<String> {
         body[key]
@@ -128,7 +140,12 @@ async fn list_page(
             .map(|post| post["id"].as_str().expect("post id").to_string())
             .collect()
     };
-    (ids("pinned"), ids("posts"))
+    ListPage {
+        pinned: ids("pinned"),
+        posts: ids("posts"),
+        has_newer: body["has_newer"].as_bool().expect("has_newer"),
+        has_older: body["has_older"].as_bool().expect("has_older"),
+    }
 }
 
 #[tokio::test]
@@ -713,10 +730,17 @@ async fn pinned_posts_ride_along_on_every_page() {
     let moderator = board_moderator().await;
     pin(&moderator, &ids[0]).await;
 
-    // Four unpinned posts remain, so page_size=2 yields two full pages.
+    // Four unpinned posts remain, so limit=2 yields two full pages.
     let anon = TestClient::new();
+    let mut cursor: Option<String> = None;
     for page in 1..=2 {
-        let (pinned, posts) = list_page(&anon, &board_id, page, 2).await;
+        let ListPage { pinned, posts, .. } = list_page(
+            &anon,
+            &board_id,
+            cursor.as_deref().map(|id| (id, "Older")),
+            2,
+        )
+        .await;
         assert_eq!(
             pinned,
             vec![ids[0].clone()],
@@ -731,7 +755,62 @@ async fn pinned_posts_ride_along_on_every_page() {
             !posts.contains(&ids[0]),
             "the pinned post must not be duplicated into the paged set on page {page}"
         );
+        cursor = posts.last().cloned();
+    }
+}
+
+/// Keyset pages are anchored on a post, not a row count: a post created between
+/// two requests shows up above the cursor (`has_newer`) instead of shifting the
+/// next page and serving its last post twice, as `OFFSET` did.
+#[tokio::test]
+async fn post_list_cursor_pages_survive_new_posts() {
+    let board_id = create_board_as_admin().await;
+    let author = TestClient::new();
+    author.signup_and_login().await;
+
+    let mut ids = Vec::new();
+    for i in 0..5 {
+        ids.push(create_post(&author, &board_id, &format!("post {i}")).await);
     }
+
+    let anon = TestClient::new();
+    let first = list_page(&anon, &board_id, None, 2).await;
+    assert_eq!(first.posts, vec![ids[4].clone(), ids[3].clone()]);
+    assert!(!first.has_newer);
+    assert!(first.has_older);
+
+    let fresh = create_post(&author, &board_id, "arrived mid-scroll").await;
+
+    let second = list_page(&anon, &board_id, Some((&ids[3], "Older")), 2).await;
+    assert_eq!(second.posts, vec![ids[2].clone(), ids[1].clone()]);
+    assert!(second.has_newer);
+    assert!(second.has_older);
+
+    let last = list_page(&anon, &board_id, Some((&ids[1], "Older")), 2).await;
+    assert_eq!(last.posts, vec![ids[0].clone()]);
+    assert!(!last.has_older);
+
+    // Paging back up returns the page in listing order and now sees the new post.
+    let back = list_page(&anon, &board_id, Some((&ids[2], "Newer")), 2).await;
+    assert_eq!(back.posts, vec![ids[4].clone(), ids[3].clone()]);
+    assert!(back.has_newer);
+    let top = list_page(&anon, &board_id, Some((&ids[4], "Newer")), 2).await;
+    assert_eq!(top.posts, vec![fresh]);
+    assert!(!top.has_newer);
+
+    // A cursor from another board does not resume this listing.
+    let other_board = create_board_as_admin().await;
+    let resp = anon
+        .get_q(
+            "/v0/board/post/list",
+            &[
+                ("board_id", other_board.as_str()),
+                ("limit", "2"),
+                ("cursor_id", ids[3].as_str()),
+            ],
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
 }
 
 /// Pin order is moderator-controlled, not post age: a new pin lands on top, and
@@ -753,7 +832,7 @@ async fn pinned_posts_reorder_to_the_order_sent() {
 
     // Each pin takes the top slot, so display order is the reverse of pin order.
     let anon = TestClient::new();
-    let (pinned, _) = list_page(&anon, &board_id, 1, 10).await;
+    let pinned = list_page(&anon, &board_id, None, 10).await.pinned;
     assert_eq!(
         pinned,
         vec![c.clone(), b.clone(), a.clone()],
@@ -773,7 +852,7 @@ async fn pinned_posts_reorder_to_the_order_sent() {
         resp.text().await.unwrap_or_default()
     );
 
-    let (pinned, _) = list_page(&anon, &board_id, 1, 10).await;
+    let pinned = list_page(&anon, &board_id, None, 10).await.pinned;
     assert_eq!(pinned, vec![a, c, b], "reorder must apply the order sent");
 }
 
@@ -861,6 +940,538 @@ async fn pin_reorder_rejects_a_stale_pin_set() {
 
     // Neither rejection wrote anything: b was pinned last, so it is still on top.
     let anon = TestClient::new();
-    let (pinned, _) = list_page(&anon, &board_id, 1, 10).await;
+    let pinned = list_page(&anon, &board_id, None, 10).await.pinned;
     assert_eq!(pinned, vec![b, a], "a rejected reorder must not write");
 }
+
+/// Lists a post's revisions as (id, title) pairs, newest first.
+async fn post_revisions(client: &TestClient, post_id: &str) -> Vec<(String, Value)> {
+    let resp = client
+        .get_q(
+            "/v0/board/post/revisions",
+            &[("post_id", post_id), ("limit", "50")],
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    body["data"]
+        .as_array()
+        .expect("data array")
+        .iter()
+        .map(|r| {
+            (
+                r["id"].as_str().expect("id").to_string(),
+                r["title"].clone(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn post_edits_are_kept_as_revisions() {
+    let (_board_id, author, post_id) = create_board_and_post().await;
+
+    for (title, content) in [("post", "first edit"), ("renamed", "second edit")] {
+        let resp = author
+            .post_json(
+                "/v0/board/post/update",
+                &json!({ "post_id": post_id, "title": title, "content": content }),
+            )
+            .await;
+        TestClient::json_ok(resp, StatusCode::OK).await;
+    }
+    // Re-saving unchanged content is not an edit and leaves no revision.
+    let resp = author
+        .post_json(
+            "/v0/board/post/update",
+            &json!({ "post_id": post_id, "content": "second edit" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let anon = TestClient::new();
+    let revisions = post_revisions(&anon, &post_id).await;
+    assert_eq!(revisions.len(), 2, "one revision per effective edit");
+    assert_eq!(revisions[0].1, json!("post"), "newest revision first");
+
+    // The oldest revision holds the original body.
+    let oldest = &revisions[1].0;
+    let resp = anon
+        .get_q("/v0/board/revision", &[("revision_id", oldest)])
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["content"], "body");
+    assert_eq!(body["post_id"], json!(post_id));
+
+    // Without `to_revision_id` the diff runs against the live body.
+    let resp = anon
+        .get_q("/v0/board/revision/diff", &[("from_revision_id", oldest)])
+        .await;
+    let diff = TestClient::json_ok(resp, StatusCode::OK).await;
+    let text = diff["diff"].as_str().expect("diff text");
+    assert!(text.contains("-body"), "diff: {text}");
+    assert!(text.contains("+second edit"), "diff: {text}");
+    assert_eq!(diff["to_title"], "renamed");
+    assert_eq!(
+        (diff["additions"].as_u64(), diff["deletions"].as_u64()),
+        (Some(1), Some(1))
+    );
+}
+
+#[tokio::test]
+async fn comment_revisions_cannot_be_diffed_against_post_revisions() {
+    let (_board_id, author, post_id) = create_board_and_post().await;
+    let comment_id = create_comment(&author, &post_id, None, "original comment").await;
+
+    let resp = author
+        .post_json(
+            "/v0/board/comment/update",
+            &json!({ "comment_id": comment_id, "content": "edited comment" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+    let resp = author
+        .post_json(
+            "/v0/board/post/update",
+            &json!({ "post_id": post_id, "content": "edited post" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let resp = author
+        .get_q(
+            "/v0/board/comment/revisions",
+            &[("comment_id", comment_id.as_str()), ("limit", "10")],
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let comment_revisions = body["data"].as_array().expect("data array");
+    assert_eq!(comment_revisions.len(), 1);
+    assert_eq!(comment_revisions[0]["comment_id"], json!(comment_id));
+    let comment_revision = comment_revisions[0]["id"].as_str().expect("id");
+
+    // Post-body history must not include the comment's revision.
+    let post_history = post_revisions(&author, &post_id).await;
+    assert_eq!(post_history.len(), 1);
+
+    let resp = author
+        .get_q(
+            "/v0/board/revision/diff",
+            &[
+                ("from_revision_id", comment_revision),
+                ("to_revision_id", post_history[0].0.as_str()),
+            ],
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: Value = resp.json().await.expect("error body");
+    assert_eq!(body["code"].as_str(), Some("board:revision_mismatch"));
+}
+
+#[tokio::test]
+async fn restoring_a_revision_requires_moderation_and_is_logged() {
+    let (_board_id, author, post_id) = create_board_and_post().await;
+    let resp = author
+        .post_json(
+            "/v0/board/post/update",
+            &json!({ "post_id": post_id, "title": "vandalized", "content": "vandalized" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+    let original = post_revisions(&author, &post_id).await[0].0.clone();
+
+    // Authors edit; they do not restore.
+    let resp = author
+        .post_json(
+            "/v0/board/revision/restore",
+            &json!({ "revision_id": original, "reason": "e2e restore" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let moderator = board_moderator().await;
+    let resp = moderator
+        .post_json(
+            "/v0/board/revision/restore",
+            &json!({ "revision_id": original, "reason": "e2e restore" }),
+        )
+        .await;
+    let restored = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(restored["restored_revision_id"], json!(original));
+
+    let resp = moderator
+        .get_q("/v0/board/post", &[("post_id", post_id.as_str())])
+        .await;
+    let post = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(post["title"], "post");
+    assert_eq!(post["content"], "body");
+
+    // The replaced body is kept, so the restore itself can be undone.
+    let history = post_revisions(&moderator, &post_id).await;
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history[0].0,
+        restored["superseded_revision_id"].as_str().unwrap()
+    );
+    assert_eq!(history[0].1, json!("vandalized"));
+
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/logs",
+            &[("limit", "10"), ("resource_id", post_id.as_str())],
+        )
+        .await;
+    let logs = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(logs["data"][0]["action"], "board:restore_revision");
+    assert_eq!(logs["data"][0]["reason"], "e2e restore");
+}
+
+#[tokio::test]
+async fn post_detail_renders_markup_and_refreshes_after_edit() {
+    let board_id = create_board_as_admin().await;
+    let author = TestClient::new();
+    let user = author.signup_and_login().await;
+    let content = format!(
+        "hi @{} and @nobody_here\n\n```rust\nlet mention = \"@{}\";\n```",
+        user.handle, user.handle
+    );
+    let resp = author
+        .post_json(
+            "/v0/board/post",
+            &json!({ "board_id": board_id, "title": "markup", "content": content }),
+        )
+        .await;
+    let post_id = TestClient::json_ok(resp, StatusCode::CREATED).await["id"]
+        .as_str()
+        .expect("post id")
+        .to_string();
+
+    let anon = TestClient::new();
+    let resp = anon.get_q("/v0/board/post", &[("post_id", &post_id)]).await;
+    let post = TestClient::json_ok(resp, StatusCode::OK).await;
+    let html = post["rendered_content"].as_str().expect("rendered_content");
+    let mention_href = format!(r#"href="/@{}""#, user.handle);
+    assert_eq!(
+        html.matches(&mention_href).count(),
+        1,
+        "only the prose mention is linked: {html}"
+    );
+    assert!(!html.contains("/@nobody_here"), "unknown handle: {html}");
+    assert!(
+        html.contains(r#"class="language-rust""#),
+        "code block: {html}"
+    );
+
+    // Lists never carry rendered bodies.
+    let resp = anon
+        .get_q("/v0/board/post/list", &[("board_id", &board_id)])
+        .await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(list["posts"][0]["rendered_content"].is_null());
+
+    // An edit drops the cached render.
+    let resp = author
+        .post_json(
+            "/v0/board/post/update",
+            &json!({ "post_id": post_id, "content": "now **bold**" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+    let resp = anon.get_q("/v0/board/post", &[("post_id", &post_id)]).await;
+    let post = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(
+        post["rendered_content"].as_str().map(str::trim),
+        Some("<p>now <strong>bold</strong></p>")
+    );
+}
+
+#[tokio::test]
+async fn markup_preview_requires_login_and_is_sanitized() {
+    let payload = json!({ "content": "<script>alert(1)</script>*hi*" });
+
+    let anon = TestClient::new();
+    let resp = anon.post_json("/v0/board/parse", &payload).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let resp = client.post_json("/v0/board/parse", &payload).await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let html = body["rendered_content"].as_str().expect("rendered_content");
+    assert!(!html.contains("<script"), "script survived: {html}");
+    assert!(html.contains("<em>hi</em>"), "markdown rendered: {html}");
+
+    let resp = client
+        .post_json("/v0/board/parse", &json!({ "content": "   " }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn hidden_post_is_a_tombstone_for_non_moderators_until_unhidden() {
+    let (_board_id, author, post_id) = create_board_and_post().await;
+    let reply_parent = create_comment(&author, &post_id, None, "still here").await;
+    let hide = json!({ "post_id": post_id, "reason": "e2e hide" });
+
+    // Authors cannot hide their own post; that is a moderation action.
+    let resp = author.post_json("/v0/board/post/hide", &hide).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let moderator = board_moderator().await;
+    let resp = moderator.post_json("/v0/board/post/hide", &hide).await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["is_hidden"], true);
+
+    let anon = TestClient::new();
+    let resp = anon.get_q("/v0/board/post", &[("post_id", &post_id)]).await;
+    let tombstone = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(tombstone["is_hidden"], true);
+    assert_eq!(tombstone["title"], "");
+    assert_eq!(tombstone["content"], "");
+    assert!(tombstone["rendered_content"].is_null());
+    assert!(tombstone["hidden_reason"].is_null());
+
+    // The thread survives under the tombstone.
+    let resp = anon
+        .get_q("/v0/board/comment/list", &[("post_id", &post_id)])
+        .await;
+    let comments = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(comments["data"][0]["id"], json!(reply_parent));
+    assert_eq!(comments["data"][0]["content"], "still here");
+
+    // History would undo the tombstone, so it is moderator-only while hidden.
+    let resp = anon
+        .get_q("/v0/board/post/revisions", &[("post_id", &post_id)])
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let resp = moderator
+        .get_q("/v0/board/post", &[("post_id", &post_id)])
+        .await;
+    let full = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(full["content"], "body");
+    assert_eq!(full["hidden_reason"], "e2e hide");
+
+    let resp = moderator
+        .post_json(
+            "/v0/board/post/unhide",
+            &json!({ "post_id": post_id, "reason": "e2e unhide" }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["is_hidden"], false);
+
+    let resp = anon.get_q("/v0/board/post", &[("post_id", &post_id)]).await;
+    let restored = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(restored["is_hidden"], false);
+    assert_eq!(restored["content"], "body");
+
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/logs",
+            &[("limit", "10"), ("resource_id", post_id.as_str())],
+        )
+        .await;
+    let logs = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(logs["data"][0]["action"], "board:unhide_post");
+    assert_eq!(logs["data"][1]["action"], "board:hide_post");
+}
+
+#[tokio::test]
+async fn hidden_comment_keeps_its_replies() {
+    let (_board_id, author, post_id) = create_board_and_post().await;
+    let parent = create_comment(&author, &post_id, None, "rude comment").await;
+    create_comment(&author, &post_id, Some(&parent), "a reply").await;
+
+    let moderator = board_moderator().await;
+    let resp = moderator
+        .post_json(
+            "/v0/board/comment/hide",
+            &json!({ "comment_id": parent, "reason": "e2e hide" }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["is_hidden"], true);
+
+    let anon = TestClient::new();
+    let resp = anon
+        .get_q("/v0/board/comment/list", &[("post_id", &post_id)])
+        .await;
+    let comments = TestClient::json_ok(resp, StatusCode::OK).await;
+    let hidden = &comments["data"][0];
+    assert_eq!(hidden["id"], json!(parent));
+    assert_eq!(hidden["is_hidden"], true);
+    assert_eq!(hidden["content"], "");
+    assert_eq!(hidden["reply_count"], 1);
+
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/logs",
+            &[("limit", "10"), ("resource_id", parent.as_str())],
+        )
+        .await;
+    let logs = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(logs["data"][0]["action"], "board:hide_comment");
+}
+
+#[tokio::test]
+async fn reactions_are_counted_once_per_kind_and_echo_the_viewer() {
+    let (board_id, author, post_id) = create_board_and_post().await;
+    let reactor = TestClient::new();
+    reactor.signup_and_login().await;
+    let upvote = json!({ "post_id": post_id, "kind": "upvote" });
+
+    let anon = TestClient::new();
+    let resp = anon.post_json("/v0/board/post/react", &upvote).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = reactor
+        .post_json(
+            "/v0/board/post/react",
+            &json!({ "post_id": post_id, "kind": "e2e-not-a-kind" }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::BAD_REQUEST).await;
+    assert_eq!(body["code"].as_str(), Some("board:reaction_kind_invalid"));
+
+    // Reacting twice with the same kind is a no-op the second time.
+    for _ in 0..2 {
+        let resp = reactor.post_json("/v0/board/post/react", &upvote).await;
+        let body = TestClient::json_ok(resp, StatusCode::OK).await;
+        assert_eq!(body["reaction_count"], 1);
+        assert_eq!(body["reaction_counts"], json!({ "upvote": 1 }));
+        assert_eq!(body["viewer_reactions"], json!(["upvote"]));
+    }
+
+    let resp = author.post_json("/v0/board/post/react", &upvote).await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["reaction_counts"], json!({ "upvote": 2 }));
+
+    let resp = reactor
+        .get_q("/v0/board/post", &[("post_id", &post_id)])
+        .await;
+    let post = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(post["reaction_count"], 2);
+    assert_eq!(post["viewer_reactions"], json!(["upvote"]));
+
+    let resp = anon.get_q("/v0/board/post", &[("post_id", &post_id)]).await;
+    let post = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(post["reaction_count"], 2);
+    assert_eq!(post["viewer_reactions"], json!([]));
+
+    let resp = reactor
+        .get_q("/v0/board/post/list", &[("board_id", &board_id)])
+        .await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(list["posts"][0]["id"], json!(post_id));
+    assert_eq!(list["posts"][0]["viewer_reactions"], json!(["upvote"]));
+
+    // Taking a reaction back twice only decrements once.
+    for _ in 0..2 {
+        let resp = reactor.post_json("/v0/board/post/unreact", &upvote).await;
+        let body = TestClient::json_ok(resp, StatusCode::OK).await;
+        assert_eq!(body["reaction_count"], 1);
+        assert_eq!(body["viewer_reactions"], json!([]));
+    }
+
+    let comment_id = create_comment(&author, &post_id, None, "react to me").await;
+    let resp = reactor
+        .post_json(
+            "/v0/board/comment/react",
+            &json!({ "comment_id": comment_id, "kind": "upvote" }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["reaction_counts"], json!({ "upvote": 1 }));
+
+    let resp = reactor
+        .get_q("/v0/board/comment/list", &[("post_id", &post_id)])
+        .await;
+    let comments = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(comments["data"][0]["reaction_count"], 1);
+    assert_eq!(comments["data"][0]["viewer_reactions"], json!(["upvote"]));
+
+    // Comment reactions do not leak into the post's tally.
+    let resp = anon.get_q("/v0/board/post", &[("post_id", &post_id)]).await;
+    let post = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(post["reaction_counts"], json!({ "upvote": 1 }));
+}
+
+/// Reads the first page of a board listing in `sort` order as (pinned ids, post ids).
+async fn sorted_ids(client: &TestClient, board_id: &str, sort: &str) -> (Vec<Value>, Vec<Value>) {
+    let resp = client
+        .get_q(
+            "/v0/board/post/list",
+            &[("board_id", board_id), ("limit", "20"), ("sort", sort)],
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let ids = |key: &str| -> Vec<Value> {
+        body[key]
+            .as_array()
+            .unwrap_or_else(|| panic!("`{key}` must be an array: {body}"))
+            .iter()
+            .map(|post| post["id"].clone())
+            .collect()
+    };
+    (ids("pinned"), ids("posts"))
+}
+
+#[tokio::test]
+async fn post_list_sorts_by_engagement_and_keeps_pins_first() {
+    let board_id = create_board_as_admin().await;
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let oldest = create_post(&author, &board_id, "oldest").await;
+    let middle = create_post(&author, &board_id, "middle").await;
+    let newest = create_post(&author, &board_id, "newest").await;
+
+    for _ in 0..2 {
+        create_comment(&author, &oldest, None, "busy").await;
+    }
+    create_comment(&author, &middle, None, "less busy").await;
+
+    for (reactor_count, post_id) in [(2, &newest), (1, &middle)] {
+        for _ in 0..reactor_count {
+            let reactor = TestClient::new();
+            reactor.signup_and_login().await;
+            let resp = reactor
+                .post_json(
+                    "/v0/board/post/react",
+                    &json!({ "post_id": post_id, "kind": "upvote" }),
+                )
+                .await;
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+    }
+
+    let (_, posts) = sorted_ids(&author, &board_id, "new").await;
+    assert_eq!(posts, vec![json!(newest), json!(middle), json!(oldest)]);
+
+    let (_, posts) = sorted_ids(&author, &board_id, "most_commented").await;
+    assert_eq!(posts, vec![json!(oldest), json!(middle), json!(newest)]);
+
+    let (_, posts) = sorted_ids(&author, &board_id, "top").await;
+    assert_eq!(posts, vec![json!(newest), json!(middle), json!(oldest)]);
+
+    // `hot` depends on the worker's refresh cadence; only check it lists everything.
+    let (_, posts) = sorted_ids(&author, &board_id, "hot").await;
+    assert_eq!(posts.len(), 3);
+
+    let resp = author
+        .get_q(
+            "/v0/board/post/list",
+            &[
+                ("board_id", board_id.as_str()),
+                ("limit", "20"),
+                ("sort", "loudest"),
+            ],
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // A pin leaves the sorted window and rides above it instead.
+    pin(&board_moderator().await, &oldest).await;
+    let (pinned, posts) = sorted_ids(&author, &board_id, "most_commented").await;
+    assert_eq!(pinned, vec![json!(oldest)]);
+    assert_eq!(posts, vec![json!(middle), json!(newest)]);
+}
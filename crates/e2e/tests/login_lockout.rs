@@ -0,0 +1,174 @@
+//! Login throttling and lockout e2e tests.
+//! Run via `just e2e`.
+//!
+//! Policy references:
+//! - Failed password logins are counted per account (by normalized email, known or not).
+//!   From the third failure each one blocks further attempts for a doubling delay, and
+//!   the tenth locks the account for 15 minutes. A lock is never shortened by the
+//!   delays of attempts that were still in flight when it landed.
+//! - A blocked attempt answers 429 `auth:login_throttled` with `Retry-After`, even with
+//!   the right password, and unknown emails are throttled exactly like real ones.
+//! - `POST /v0/users/unlock` (admin) clears an account's failures early.
+//! - Per-IP counters share the rate limiter switch, which the e2e env turns off.
+
+use e2e::TestClient;
+use entity::common::Role;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+const FAILURES_BEFORE_DELAY: usize = 3;
+/// Longest progressive delay; any block longer than this is the lock.
+const MAX_DELAY_SECONDS: u64 = 60;
+
+/// Sends `count` wrong-password logins for `email`, asserting each is a plain 401.
+async fn fail_logins(email: &str, count: usize) {
+    let client = TestClient::new();
+    for attempt in 1..=count {
+        let resp = client
+            .post_json(
+                "/v0/auth/login",
+                &json!({ "email": email, "password": "definitely-wrong" }),
+            )
+            .await;
+        assert_eq!(
+            resp.status(),
+            StatusCode::UNAUTHORIZED,
+            "failed attempt {attempt} should be a plain credential error"
+        );
+    }
+}
+
+/// Asserts that a login for `email` is throttled, and returns the `Retry-After` seconds and the
+/// error body.
+async fn assert_throttled(email: &str, password: &str) -> (u64, Value) {
+    let resp = TestClient::new()
+        .post_json(
+            "/v0/auth/login",
+            &json!({ "email": email, "password": password }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = resp.headers()["retry-after"]
+        .to_str()
+        .expect("Retry-After is ASCII")
+        .parse()
+        .expect("Retry-After is a number of seconds");
+    assert!(retry_after >= 1, "Retry-After must be positive");
+    (retry_after, resp.json().await.expect("error body is JSON"))
+}
+
+#[tokio::test]
+async fn repeated_failures_throttle_even_the_right_password() {
+    let user = TestClient::new().signup_and_login().await;
+
+    fail_logins(&user.email, FAILURES_BEFORE_DELAY).await;
+
+    let (_, body) = assert_throttled(&user.email, &user.password).await;
+    assert_eq!(body["code"], "auth:login_throttled");
+}
+
+#[tokio::test]
+async fn unknown_emails_are_throttled_like_real_ones() {
+    let email = format!("nobody-{}@test.invalid", &e2e::unique()[..12]);
+
+    fail_logins(&email, FAILURES_BEFORE_DELAY).await;
+
+    let (_, body) = assert_throttled(&email, "definitely-wrong").await;
+    assert_eq!(body["code"], "auth:login_throttled");
+}
+
+#[tokio::test]
+async fn concurrent_failures_cannot_shorten_the_lock() {
+    let user = TestClient::new().signup_and_login().await;
+
+    // Fire a burst at once so every attempt passes the block check before any is counted.
+    // Those still in flight when the tenth failure locks the account fail into a fresh
+    // counter; their short delays must not replace the 15-minute lock.
+    let mut attempts = tokio::task::JoinSet::new();
+    for _ in 0..30 {
+        let email = user.email.clone();
+        attempts.spawn(async move {
+            TestClient::new()
+                .post_json(
+                    "/v0/auth/login",
+                    &json!({ "email": email, "password": "definitely-wrong" }),
+                )
+                .await
+                .status()
+        });
+    }
+    let mut failures = 0;
+    while let Some(status) = attempts.join_next().await {
+        if status.expect("login task") == StatusCode::UNAUTHORIZED {
+            failures += 1;
+        }
+    }
+    assert!(
+        failures >= 10,
+        "only {failures} attempts reached the password check"
+    );
+
+    let (retry_after, body) = assert_throttled(&user.email, &user.password).await;
+    assert_eq!(body["code"], "auth:login_throttled");
+    assert!(
+        retry_after > MAX_DELAY_SECONDS,
+        "the lock was shortened to {retry_after}s"
+    );
+}
+
+#[tokio::test]
+async fn admin_unlock_clears_failed_logins() {
+    let admin = TestClient::new();
+    let admin_user = admin.signup_and_login().await;
+    e2e::grant_role(&admin_user.handle, Role::Admin).await;
+
+    let alice = TestClient::new();
+    let user = alice.signup_and_login().await;
+    let alice_id = alice.me().await["id"].as_str().unwrap().to_string();
+
+    // Nothing to unlock yet.
+    let resp = admin
+        .post_json(
+            "/v0/users/unlock",
+            &json!({ "user_id": alice_id, "reason": "e2e: unlock" }),
+        )
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::BAD_REQUEST,
+        "unlock without failures"
+    );
+
+    fail_logins(&user.email, FAILURES_BEFORE_DELAY).await;
+
+    // Only admins may unlock.
+    let resp = alice
+        .post_json(
+            "/v0/users/unlock",
+            &json!({ "user_id": alice_id, "reason": "e2e: self unlock" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN, "non-admin unlock");
+
+    let resp = admin
+        .post_json(
+            "/v0/users/unlock",
+            &json!({ "user_id": alice_id, "reason": "e2e: unlock" }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["user_id"], alice_id.as_str());
+
+    // The block is gone: the right password gets through to the new-device step.
+    let resp = TestClient::new()
+        .post_json(
+            "/v0/auth/login",
+            &json!({ "email": user.email, "password": user.password }),
+        )
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::ACCEPTED,
+        "an unlocked account signs in again"
+    );
+}
@@ -0,0 +1,156 @@
+//! Passwordless magic-link sign-in e2e tests.
+//! Run via `just e2e`.
+//!
+//! Policy references:
+//! - `POST /v0/auth/magic-link` answers 204 for known and unknown emails alike and only
+//!   emails a link when the account exists (enumeration-safe, like forgot-password).
+//! - The emailed token is single-use. Following it signs in without the new-device
+//!   challenge: control of the mailbox is already the proof. The device signed in from is
+//!   trusted, so a later password login from it is not challenged either.
+//! - TOTP still applies: an enrolled account gets 202 + `temp_token`, and the TOTP step
+//!   then issues the session directly, again without a device challenge.
+
+use e2e::TestClient;
+use reqwest::StatusCode;
+use serde_json::json;
+use totp_rs::TOTP;
+
+/// Requests a magic link for `email` and returns the token from the new email.
+async fn request_magic_link(email: &str) -> String {
+    let before = e2e::latest_message_id(email).await;
+    let resp = TestClient::new()
+        .post_json("/v0/auth/magic-link", &json!({ "email": email }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT, "magic-link request");
+
+    let body = e2e::wait_for_new_message_body(email, before.as_deref()).await;
+    e2e::extract_token(&body).expect("magic-link token in email")
+}
+
+#[tokio::test]
+async fn magic_link_signs_in_a_fresh_browser_without_device_challenge() {
+    let user = TestClient::new().signup_and_login().await;
+    let token = request_magic_link(&user.email).await;
+
+    // A brand-new browser is an unknown device, yet no device challenge is raised.
+    let client = TestClient::new();
+    let resp = client
+        .post_json("/v0/auth/magic-link/verify", &json!({ "token": token }))
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::NO_CONTENT,
+        "magic-link verify should issue the session cookie"
+    );
+    let me = client.me().await;
+    assert_eq!(me["handle"].as_str(), Some(user.handle.as_str()));
+
+    // The token is single-use.
+    let resp = TestClient::new()
+        .post_json("/v0/auth/magic-link/verify", &json!({ "token": token }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "replayed token");
+}
+
+#[tokio::test]
+async fn magic_link_app_variant_returns_session_and_device_tokens() {
+    let user = TestClient::new().signup_and_login().await;
+    let token = request_magic_link(&user.email).await;
+
+    let resp = TestClient::new()
+        .post_json("/v0/app/auth/magic-link/verify", &json!({ "token": token }))
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let session_token = body["token"].as_str().expect("session token");
+    let device_token = body["device_token"].as_str().expect("device token");
+
+    let me = TestClient::with_bearer(session_token).me().await;
+    assert_eq!(me["handle"].as_str(), Some(user.handle.as_str()));
+
+    // The magic-link device is now known: a password login presenting it is not challenged.
+    let resp = reqwest::Client::new()
+        .post(format!("{}/v0/app/auth/login", e2e::base_url()))
+        .header("X-Turnstile-Token", "e2e-test-token")
+        .header("X-Device-Token", device_token)
+        .json(&json!({ "email": user.email, "password": user.password }))
+        .send()
+        .await
+        .expect("app login (magic-link device)");
+    assert_eq!(
+        resp.status(),
+        StatusCode::OK,
+        "a device signed in by magic link must be recognized on password login"
+    );
+}
+
+#[tokio::test]
+async fn magic_link_request_does_not_reveal_unknown_emails() {
+    let email = format!("nobody-{}@test.invalid", &e2e::unique()[..12]);
+    let resp = TestClient::new()
+        .post_json("/v0/auth/magic-link", &json!({ "email": email }))
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::NO_CONTENT,
+        "unknown emails must get the same answer as known ones"
+    );
+
+    let resp = TestClient::new()
+        .post_json(
+            "/v0/auth/magic-link/verify",
+            &json!({ "token": "not-a-real-token" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "unknown token");
+}
+
+#[tokio::test]
+async fn magic_link_still_requires_totp() {
+    let enrolled = TestClient::new();
+    let user = enrolled.signup_and_login().await;
+    let resp = enrolled.post_json("/v0/auth/totp/setup", &json!({})).await;
+    let setup = TestClient::json_ok(resp, StatusCode::OK).await;
+    let totp = TOTP::from_url(setup["qr_code_uri"].as_str().expect("otpauth uri"))
+        .expect("parse otpauth uri");
+    let resp = enrolled
+        .post_json(
+            "/v0/auth/totp/enable",
+            &json!({ "code": totp.generate_current().expect("TOTP code") }),
+        )
+        .await;
+    let enabled = TestClient::json_ok(resp, StatusCode::OK).await;
+    let backup_code = enabled["backup_codes"][0]
+        .as_str()
+        .expect("backup code")
+        .to_string();
+
+    let token = request_magic_link(&user.email).await;
+    let client = TestClient::new();
+    let resp = client
+        .post_json("/v0/auth/magic-link/verify", &json!({ "token": token }))
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::ACCEPTED).await;
+    let temp_token = body["temp_token"].as_str().expect("temp token");
+
+    let resp = client.get("/v0/user/me").await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::UNAUTHORIZED,
+        "no session may exist before the second factor succeeds"
+    );
+
+    // The second factor completes the sign-in; the unknown browser is not challenged.
+    let resp = client
+        .post_json(
+            "/v0/auth/totp/verify",
+            &json!({ "temp_token": temp_token, "code": backup_code }),
+        )
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::NO_CONTENT,
+        "TOTP after a magic link should issue the session directly"
+    );
+    let me = client.me().await;
+    assert_eq!(me["handle"].as_str(), Some(user.handle.as_str()));
+}
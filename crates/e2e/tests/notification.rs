@@ -2,16 +2,27 @@
 //!
 //! Policy references:
 //! - All `/v0/notifications/*` routes require a session (401 anon).
-//! - Commenting on a board post delivers a `board_comment_created` notification to the
-//!   post author — but never to the commenter themselves, and mentioned users get a
-//!   `user_mentioned` notification instead of a duplicate comment alert.
+//! - Commenting on a board post delivers a `board_comment_created` notification to every
+//!   watcher of the post (the author watches from creation) through the worker fan-out —
+//!   but never to the commenter themselves, and mentioned users get a `user_mentioned`
+//!   notification instead of a duplicate comment alert. Unwatching stops the alerts.
 //! - `@handle` mentions in post/comment content deliver `user_mentioned` notifications
 //!   to the mentioned users, skipping the author.
 //! - Inbox rows are strictly per-user: mark-as-read/delete match `WHERE id AND user_id`,
 //!   so foreign ids yield 404 (existence is not revealed) and never mutate.
 //! - Unread count, mark-all-as-read, and delete operate only on the caller's inbox.
+//! - With `email_enabled` on, new notifications are also emailed; the email's unsubscribe
+//!   token turns email (and any digest) off again without a session.
+//! - `/v0/notifications/stream` pushes the caller's new notifications (from the server
+//!   and from the worker fan-out) and unread-count changes as SSE events, and closes
+//!   once the session that opened it is logged out or revoked.
 //! - Notification channel/action preferences require login and are scoped per user;
 //!   disabling an action suppresses new notifications of that action for the recipient.
+//! - Web Push subscriptions are per user and keyed by endpoint: only HTTPS endpoints with
+//!   a P-256 `p256dh` and a 16-byte `auth` are accepted, and unsubscribing someone else's
+//!   endpoint is a 404.
+//! - Unread notifications with the same action and target post are listed and counted as
+//!   one group; marking the group read marks every member read.
 
 use std::time::Duration;
 
@@ -53,9 +64,28 @@ async fn create_comment(client: &TestClient, post_id: &str, content: &str) {
     );
 }
 
+/// Makes `client` watch a post.
+async fn watch_post(client: &TestClient, post_id: &str) {
+    let resp = client
+        .post_json("/v0/board/post/watch", &json!({ "post_id": post_id }))
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["watching"].as_bool(), Some(true));
+}
+
+/// A fresh user watching `post_id`. Fan-out jobs write every recipient of one
+/// comment in a single transaction, so once this watcher has its alert the other
+/// recipients' rows (or their absence) are final too.
+async fn sync_watcher(post_id: &str) -> TestClient {
+    let watcher = TestClient::new();
+    watcher.signup_and_login().await;
+    watch_post(&watcher, post_id).await;
+    watcher
+}
+
 /// Polls the notification inbox until a notification with `action` about `post_id`
-/// appears. Notifications are written best-effort during the triggering request, so
-/// this usually resolves on the first poll; the budget just absorbs slow CI.
+/// appears. Mentions are written during the triggering request and comment alerts by
+/// the worker fan-out shortly after; the budget absorbs slow CI.
 async fn wait_for_post_notification(client: &TestClient, action: &str, post_id: &str) -> Value {
     for _ in 0..120 {
         let resp = client.get("/v0/notifications/list?limit=50").await;
@@ -91,6 +121,97 @@ async fn post_notifications(client: &TestClient, action: &str, post_id: &str) ->
         .collect()
 }
 
+/// Reads `event:`/`data:` frames off an open SSE response.
+struct SseReader {
+    resp: reqwest::Response,
+    buf: String,
+}
+
+impl SseReader {
+    async fn open(client: &TestClient, path: &str) -> Self {
+        let resp = client.get(path).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        Self {
+            resp,
+            buf: String::new(),
+        }
+    }
+
+    /// The next event's name and JSON data, skipping keep-alive comments.
+    async fn next_event(&mut self) -> (String, Value) {
+        loop {
+            if let Some(end) = self.buf.find("\n\n") {
+                let frame: String = self.buf.drain(..end + 2).collect();
+                let mut name = None;
+                let mut data = String::new();
+                for line in frame.lines() {
+                    if let Some(value) = line.strip_prefix("event:") {
+                        name = Some(value.trim().to_string());
+                    } else if let Some(value) = line.strip_prefix("data:") {
+                        data.push_str(value.trim());
+                    }
+                }
+                if let Some(name) = name {
+                    return (name, serde_json::from_str(&data).expect("SSE data is JSON"));
+                }
+                continue;
+            }
+
+            let chunk = tokio::time::timeout(Duration::from_secs(20), self.resp.chunk())
+                .await
+                .expect("timed out waiting for an SSE event")
+                .expect("SSE stream failed")
+                .expect("SSE stream ended");
+            self.buf
+                .push_str(std::str::from_utf8(&chunk).expect("SSE is UTF-8"));
+        }
+    }
+
+    /// Waits for the server to close the stream, failing if a notification arrives first.
+    async fn expect_end(&mut self) {
+        loop {
+            assert!(
+                !self.buf.contains("event: notification")
+                    && !self.buf.contains("event:notification"),
+                "no notification may be delivered after the session ended"
+            );
+            let chunk = tokio::time::timeout(Duration::from_secs(20), self.resp.chunk())
+                .await
+                .expect("timed out waiting for the SSE stream to end")
+                .expect("SSE stream failed");
+            match chunk {
+                Some(chunk) => self
+                    .buf
+                    .push_str(std::str::from_utf8(&chunk).expect("SSE is UTF-8")),
+                None => return,
+            }
+        }
+    }
+
+    /// Skips events until an `unread_count` with `count` arrives.
+    async fn expect_unread_count(&mut self, count: u64) {
+        loop {
+            let (name, data) = self.next_event().await;
+            if name == "unread_count" && data["count"].as_u64() == Some(count) {
+                return;
+            }
+        }
+    }
+
+    /// Skips events until a `notification` with `action` about `post_id` arrives.
+    async fn expect_notification(&mut self, action: &str, post_id: &str) -> Value {
+        loop {
+            let (name, data) = self.next_event().await;
+            if name == "notification"
+                && data["action"].as_str() == Some(action)
+                && data["post_id"].as_str() == Some(post_id)
+            {
+                return data;
+            }
+        }
+    }
+}
+
 #[tokio::test]
 async fn inbox_requires_login() {
     let anon = TestClient::new();
@@ -101,6 +222,9 @@ async fn inbox_requires_login() {
     let resp = anon.get("/v0/notifications/unread/count").await;
     assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
 
+    let resp = anon.get("/v0/notifications/stream").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
     let resp = anon
         .post_json(
             "/v0/notifications/mark-as-read",
@@ -235,6 +359,7 @@ async fn mentions_in_posts_and_comments_notify_mentioned_users() {
     // Alice comments on Bob's post AND mentions him: Bob gets exactly one
     // user_mentioned notification — the mention supersedes the comment alert,
     // never both.
+    let watcher = sync_watcher(&post_id).await;
     create_comment(
         &alice,
         &post_id,
@@ -243,6 +368,7 @@ async fn mentions_in_posts_and_comments_notify_mentioned_users() {
     .await;
 
     wait_for_post_notification(&bob, "user_mentioned", &post_id).await;
+    wait_for_post_notification(&watcher, "board_comment_created", &post_id).await;
     let comment_alerts = post_notifications(&bob, "board_comment_created", &post_id).await;
     assert!(
         comment_alerts.is_empty(),
@@ -355,12 +481,13 @@ async fn action_preference_opt_out_suppresses_new_notifications() {
             && pref["enabled"].as_bool() == Some(false)
     }));
 
+    let watcher = sync_watcher(&post_id).await;
     let dave = TestClient::new();
     dave.signup_and_login().await;
     create_comment(&dave, &post_id, "this should be suppressed\n").await;
 
-    // Notifications are written during the comment request, so once it returned
-    // 201 the (suppressed) fan-out has already run — no need to poll for absence.
+    // Once the sync watcher has its alert, the fan-out for this comment has run.
+    wait_for_post_notification(&watcher, "board_comment_created", &post_id).await;
     let suppressed = post_notifications(&carol, "board_comment_created", &post_id).await;
     assert!(
         suppressed.is_empty(),
@@ -403,6 +530,7 @@ async fn notification_preferences_require_login_and_are_per_user() {
     let body = TestClient::json_ok(resp, StatusCode::OK).await;
     assert_eq!(body["email_enabled"].as_bool(), Some(false));
     assert_eq!(body["push_enabled"].as_bool(), Some(false));
+    assert_eq!(body["digest_frequency"], "Off");
 
     let resp = alice
         .post_json(
@@ -418,6 +546,23 @@ async fn notification_preferences_require_login_and_are_per_user() {
         "omitted channel preferences should keep/default their existing value"
     );
 
+    let resp = alice
+        .post_json(
+            "/v0/notifications/preferences/update",
+            &json!({ "digest_frequency": "Weekly" }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["digest_frequency"], "Weekly");
+    assert_eq!(body["email_enabled"].as_bool(), Some(true));
+    let resp = alice
+        .post_json(
+            "/v0/notifications/preferences/update",
+            &json!({ "digest_frequency": "Hourly" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
     let resp = alice
         .post_json(
             "/v0/notifications/preferences/actions/update",
@@ -463,3 +608,360 @@ async fn notification_preferences_require_login_and_are_per_user() {
         "Alice's action preferences must not leak to Bob"
     );
 }
+
+#[tokio::test]
+async fn watchers_hear_about_new_comments_until_they_unwatch() {
+    let bob = TestClient::new();
+    bob.signup_and_login().await;
+    let board_id = board_id_by_slug(&bob, "general").await;
+    let title = format!("Watch {}", e2e::unique());
+    let post_id = create_post(&bob, &board_id, &title, "a thread worth following\n").await;
+
+    let carol = TestClient::new();
+    carol.signup_and_login().await;
+    watch_post(&carol, &post_id).await;
+    // Watching twice is a no-op, not an error.
+    watch_post(&carol, &post_id).await;
+
+    // Alice comments: both the author (auto-watch) and Carol hear about it; the
+    // commenter does not.
+    let alice = TestClient::new();
+    alice.signup_and_login().await;
+    watch_post(&alice, &post_id).await;
+    create_comment(&alice, &post_id, "first!\n").await;
+    wait_for_post_notification(&carol, "board_comment_created", &post_id).await;
+    wait_for_post_notification(&bob, "board_comment_created", &post_id).await;
+    assert!(
+        post_notifications(&alice, "board_comment_created", &post_id)
+            .await
+            .is_empty(),
+        "the commenter must not be alerted about their own comment"
+    );
+
+    // Carol and the author unwatch; the next comment reaches neither.
+    for client in [&carol, &bob] {
+        let resp = client
+            .post_json("/v0/board/post/unwatch", &json!({ "post_id": post_id }))
+            .await;
+        let body = TestClient::json_ok(resp, StatusCode::OK).await;
+        assert_eq!(body["watching"].as_bool(), Some(false));
+    }
+
+    let watcher = sync_watcher(&post_id).await;
+    create_comment(&alice, &post_id, "second\n").await;
+    wait_for_post_notification(&watcher, "board_comment_created", &post_id).await;
+    for client in [&carol, &bob] {
+        assert_eq!(
+            post_notifications(client, "board_comment_created", &post_id)
+                .await
+                .len(),
+            1,
+            "an unwatched post must stop producing comment alerts"
+        );
+    }
+
+    // Watching requires a session.
+    let anon = TestClient::new();
+    let resp = anon
+        .post_json("/v0/board/post/watch", &json!({ "post_id": post_id }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn notification_stream_pushes_new_notifications_and_unread_counts() {
+    let bob = TestClient::new();
+    let bob_user = bob.signup_and_login().await;
+    let mut stream = SseReader::open(&bob, "/v0/notifications/stream").await;
+    stream.expect_unread_count(0).await;
+
+    // A mention is written by the server during Alice's request.
+    let alice = TestClient::new();
+    alice.signup_and_login().await;
+    let board_id = board_id_by_slug(&alice, "general").await;
+    let title = format!("Stream {}", e2e::unique());
+    let mention_post_id = create_post(
+        &alice,
+        &board_id,
+        &title,
+        &format!("ping @{}\n", bob_user.handle),
+    )
+    .await;
+
+    let notification = stream
+        .expect_notification("user_mentioned", &mention_post_id)
+        .await;
+    assert_eq!(notification["is_read"].as_bool(), Some(false));
+    stream.expect_unread_count(1).await;
+
+    // A comment alert is written by the worker fan-out, possibly on another process.
+    let title = format!("Stream watched {}", e2e::unique());
+    let own_post_id = create_post(&bob, &board_id, &title, "watched by its author\n").await;
+    create_comment(&alice, &own_post_id, "a comment for the author\n").await;
+
+    stream
+        .expect_notification("board_comment_created", &own_post_id)
+        .await;
+    stream.expect_unread_count(2).await;
+
+    // Read-state changes push the new count.
+    let resp = bob
+        .post_json("/v0/notifications/mark-all-as-read", &json!({}))
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    stream.expect_unread_count(0).await;
+}
+
+#[tokio::test]
+async fn notification_stream_ends_after_logout() {
+    let bob = TestClient::new();
+    let bob_user = bob.signup_and_login().await;
+    let mut stream = SseReader::open(&bob, "/v0/notifications/stream").await;
+    stream.expect_unread_count(0).await;
+
+    let resp = bob.post_json("/v0/auth/logout", &json!({})).await;
+    assert!(resp.status().is_success(), "logout: {}", resp.status());
+
+    // The next signal re-checks the session and closes the stream instead of delivering.
+    let alice = TestClient::new();
+    alice.signup_and_login().await;
+    let board_id = board_id_by_slug(&alice, "general").await;
+    let title = format!("Stream logout {}", e2e::unique());
+    create_post(
+        &alice,
+        &board_id,
+        &title,
+        &format!("ping @{}\n", bob_user.handle),
+    )
+    .await;
+
+    stream.expect_end().await;
+}
+
+#[tokio::test]
+async fn notification_emails_follow_email_preference_and_unsubscribe_link() {
+    let bob = TestClient::new();
+    let bob_user = bob.signup_and_login().await;
+    let resp = bob
+        .post_json(
+            "/v0/notifications/preferences/update",
+            &json!({ "email_enabled": true, "digest_frequency": "Daily" }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await;
+    let before = e2e::latest_message_id(&bob_user.email).await;
+
+    let alice = TestClient::new();
+    alice.signup_and_login().await;
+    let board_id = board_id_by_slug(&alice, "general").await;
+    let title = format!("Email {}", e2e::unique());
+    create_post(
+        &alice,
+        &board_id,
+        &title,
+        &format!("ping @{}\n", bob_user.handle),
+    )
+    .await;
+
+    let body = e2e::wait_for_new_message_body(&bob_user.email, before.as_deref()).await;
+    assert!(
+        body.contains(&title),
+        "the notification email should name the post"
+    );
+    let token = e2e::extract_token(&body).expect("unsubscribe token in notification email");
+
+    // The link works without a session, and only with a real token.
+    let anon = TestClient::new();
+    let resp = anon
+        .post_json_q(
+            "/v0/notifications/email/unsubscribe",
+            &[("token", "0190a000-0000-4000-8000-000000000000")],
+            &json!({}),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let resp = anon
+        .post_json_q(
+            "/v0/notifications/email/unsubscribe",
+            &[("token", &token)],
+            &json!({}),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = bob.get("/v0/notifications/preferences").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["email_enabled"].as_bool(), Some(false));
+    assert_eq!(
+        body["digest_frequency"], "Off",
+        "unsubscribing also stops the digest"
+    );
+}
+
+/// Any uncompressed P-256 point works as a subscriber key here: the test stack's worker
+/// has no VAPID private key, so nothing is actually pushed.
+const TEST_P256DH: &str =
+    "BJ7Krro8w2V3NzjZGs7OI-vVZVqZUFwqYMlrkv1_vKjY6QSWSju9KCkdf4pDZz6o0uSyNe4td-S0gCj2CgYUyMA";
+const TEST_AUTH: &str = "c2VjcmV0LWF1dGgtMTZiIQ";
+
+#[tokio::test]
+async fn push_subscriptions_register_list_and_remove_per_user() {
+    let anon = TestClient::new();
+    let resp = anon.get("/v0/notifications/push/vapid-public-key").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let alice = TestClient::new();
+    alice.signup_and_login().await;
+    let bob = TestClient::new();
+    bob.signup_and_login().await;
+
+    let resp = alice.get("/v0/notifications/push/vapid-public-key").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(
+        body["public_key"].as_str().is_some_and(|k| !k.is_empty()),
+        "the test stack configures a VAPID public key"
+    );
+
+    let endpoint = format!("https://push.example.invalid/send/{}", e2e::unique());
+    let subscribe = |endpoint: &str, p256dh: &str, auth: &str| json!({ "endpoint": endpoint, "keys": { "p256dh": p256dh, "auth": auth } });
+
+    // Malformed subscriptions are rejected.
+    for bad in [
+        subscribe("http://push.example.invalid/send/x", TEST_P256DH, TEST_AUTH),
+        subscribe("https://127.0.0.1/send/x", TEST_P256DH, TEST_AUTH),
+        subscribe("https://localhost/send/x", TEST_P256DH, TEST_AUTH),
+        subscribe(&endpoint, "not-a-key", TEST_AUTH),
+        subscribe(&endpoint, TEST_P256DH, "dG9vLXNob3J0"),
+    ] {
+        let resp = alice
+            .post_json("/v0/notifications/push/subscribe", &bad)
+            .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "{bad}");
+    }
+
+    let resp = alice
+        .post_json(
+            "/v0/notifications/push/subscribe",
+            &subscribe(&endpoint, TEST_P256DH, TEST_AUTH),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let subscription_id = body["id"].as_str().expect("subscription id").to_string();
+    assert_eq!(body["endpoint"].as_str(), Some(endpoint.as_str()));
+
+    // Re-subscribing the same endpoint refreshes the row instead of adding one.
+    let resp = alice
+        .post_json(
+            "/v0/notifications/push/subscribe",
+            &subscribe(&endpoint, TEST_P256DH, TEST_AUTH),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["id"].as_str(), Some(subscription_id.as_str()));
+
+    let resp = alice.get("/v0/notifications/push/subscriptions").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let data = body["data"].as_array().expect("data array");
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0]["id"].as_str(), Some(subscription_id.as_str()));
+
+    let resp = bob.get("/v0/notifications/push/subscriptions").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(body["data"].as_array().expect("data array").is_empty());
+
+    // Bob cannot remove Alice's device.
+    let unsubscribe = json!({ "endpoint": endpoint });
+    let resp = bob
+        .post_json("/v0/notifications/push/unsubscribe", &unsubscribe)
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = alice
+        .post_json("/v0/notifications/push/unsubscribe", &unsubscribe)
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = alice
+        .post_json("/v0/notifications/push/unsubscribe", &unsubscribe)
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = alice.get("/v0/notifications/push/subscriptions").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(body["data"].as_array().expect("data array").is_empty());
+}
+
+#[tokio::test]
+async fn unread_comment_alerts_on_a_post_collapse_into_one_group() {
+    let bob = TestClient::new();
+    bob.signup_and_login().await;
+    let board_id = board_id_by_slug(&bob, "general").await;
+    let title = format!("Group {}", e2e::unique());
+    let post_id = create_post(&bob, &board_id, &title, "a busy thread\n").await;
+
+    // Three commenters, one of them twice: four alerts from three actors.
+    let alice = TestClient::new();
+    alice.signup_and_login().await;
+    let carol = TestClient::new();
+    carol.signup_and_login().await;
+    let dave = TestClient::new();
+    dave.signup_and_login().await;
+    for (client, content) in [
+        (&alice, "first\n"),
+        (&carol, "second\n"),
+        (&dave, "third\n"),
+        (&alice, "fourth\n"),
+    ] {
+        create_comment(client, &post_id, content).await;
+    }
+
+    // The unread alerts are listed once, as a group headed by the newest.
+    let mut group = Value::Null;
+    for _ in 0..120 {
+        let found = post_notifications(&bob, "board_comment_created", &post_id).await;
+        if let [head] = found.as_slice()
+            && head["group_size"].as_u64() == Some(4)
+        {
+            group = head.clone();
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    assert_eq!(
+        group["group_size"].as_u64(),
+        Some(4),
+        "all unread comment alerts on the post should collapse into one entry"
+    );
+    assert_eq!(group["is_read"].as_bool(), Some(false));
+    assert_eq!(group["actor_count"].as_u64(), Some(3));
+    let recent_actors = group["recent_actors"].as_array().expect("recent actors");
+    assert_eq!(recent_actors.len(), 3, "recent actors are distinct");
+    assert_eq!(
+        recent_actors[0]["id"], group["actor"]["id"],
+        "the newest actor comes first"
+    );
+
+    let resp = bob.get("/v0/notifications/unread/count").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["count"].as_u64(), Some(1), "a group counts once");
+
+    // Marking the group read marks every member read; they then list individually.
+    let resp = bob
+        .post_json(
+            "/v0/notifications/mark-as-read",
+            &json!({ "notification_id": group["id"] }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = bob.get("/v0/notifications/unread/count").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["count"].as_u64(), Some(0));
+
+    let members = post_notifications(&bob, "board_comment_created", &post_id).await;
+    assert_eq!(members.len(), 4, "read alerts are no longer collapsed");
+    for member in &members {
+        assert_eq!(member["is_read"].as_bool(), Some(true));
+        assert_eq!(member["group_size"].as_u64(), Some(1));
+    }
+}
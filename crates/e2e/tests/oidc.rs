@@ -0,0 +1,327 @@
+//! Generic OpenID Connect provider e2e tests, against the mock IdP in
+//! `docker-compose.test.yml` (`mock-oauth2`, configured as the `sso` provider).
+//! Run via `just e2e`.
+//!
+//! Policy references:
+//! - Authorization URLs carry PKCE (S256) and a nonce; the state is single-use and bound to
+//!   the browser's anonymous context.
+//! - ID tokens are verified against the IdP's discovered JWKS with `iss` and `aud` pinned,
+//!   and the email must be verified by the IdP.
+//! - New identities go through pending signup, like Google/GitHub; the native-app token
+//!   flow accepts an ID token minted for our client id only.
+//! - Provider slugs not enabled in `OIDC_PROVIDERS` are 404.
+
+use e2e::{TestClient, oidc_url, unique};
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_TYPE, HOST, LOCATION};
+use reqwest::redirect::Policy;
+use serde_json::{Value, json};
+use url::Url;
+use url::form_urlencoded::Serializer;
+
+/// Matches `OIDC_SSO_*` in `.envs/.test/server.env`.
+const CLIENT_ID: &str = "axumkit-e2e";
+const CLIENT_SECRET: &str = "axumkit-e2e-secret";
+const REDIRECT_URI: &str = "http://localhost:5173/account/oauth/callback/sso";
+/// Address the server reaches the IdP at. The mock IdP derives `iss` from the request's
+/// host, so tokens the tests fetch themselves must be requested under this name too.
+const IDP_HOST: &str = "mock-oauth2:8080";
+
+/// Talks to the IdP directly; redirects are inspected, not followed.
+fn idp_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .redirect(Policy::none())
+        .build()
+        .expect("failed to build reqwest client")
+}
+
+fn query_param(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+fn form(pairs: &[(&str, &str)]) -> String {
+    let mut serializer = Serializer::new(String::new());
+    for (name, value) in pairs {
+        serializer.append_pair(name, value);
+    }
+    serializer.finish()
+}
+
+/// A fresh IdP identity: (subject, email).
+fn identity() -> (String, String) {
+    let suffix = unique();
+    (
+        format!("sub-{suffix}"),
+        format!("oidc{}@test.invalid", &suffix[..12]),
+    )
+}
+
+fn verified(email: &str) -> Value {
+    json!({ "email": email, "email_verified": true })
+}
+
+/// Signs in at the mock IdP's authorization endpoint as `sub` with extra ID-token `claims`,
+/// and returns the authorization code it redirects back with.
+async fn authorize_at_idp(auth_url: &str, sub: &str, claims: &Value) -> String {
+    let mut url = Url::parse(auth_url).expect("authorization URL");
+    // The server discovered the IdP under its compose hostname; reach it via the host port.
+    let idp = Url::parse(&oidc_url()).expect("E2E_OIDC_URL");
+    url.set_host(idp.host_str()).expect("IdP host");
+    url.set_port(idp.port()).expect("IdP port");
+    let state = query_param(&url, "state").expect("state in authorization URL");
+
+    let resp = idp_client()
+        .post(url)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(form(&[("username", sub), ("claims", &claims.to_string())]))
+        .send()
+        .await
+        .expect("IdP login");
+    assert!(
+        resp.status().is_redirection(),
+        "IdP login should redirect back; got {}",
+        resp.status()
+    );
+
+    let location = resp.headers()[LOCATION].to_str().expect("Location header");
+    let callback = Url::parse(location).expect("callback URL");
+    assert!(callback.as_str().starts_with(REDIRECT_URI));
+    assert_eq!(
+        query_param(&callback, "state").as_deref(),
+        Some(state.as_str())
+    );
+    query_param(&callback, "code").expect("authorization code")
+}
+
+/// Starts a flow on the API and returns (authorization URL, state).
+async fn authorize(client: &TestClient, flow: &str) -> (String, String) {
+    let resp = client
+        .get(&format!("/v0/auth/oauth/sso/authorize?flow={flow}"))
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let auth_url = body["auth_url"].as_str().expect("auth_url").to_string();
+    let state = query_param(&Url::parse(&auth_url).unwrap(), "state").expect("state");
+    (auth_url, state)
+}
+
+/// Runs the browser login flow for `sub` and returns the API response.
+async fn login(client: &TestClient, sub: &str, claims: &Value) -> reqwest::Response {
+    let (auth_url, state) = authorize(client, "login").await;
+    let code = authorize_at_idp(&auth_url, sub, claims).await;
+    client
+        .post_json(
+            "/v0/auth/oauth/sso/login",
+            &json!({ "code": code, "state": state }),
+        )
+        .await
+}
+
+/// Obtains an ID token the way a native app would: its own code flow against the IdP.
+async fn app_id_token(client_id: &str, sub: &str, claims: &Value) -> String {
+    let mut auth_url = Url::parse(&format!("{}/sso/authorize", oidc_url())).unwrap();
+    auth_url
+        .query_pairs_mut()
+        .append_pair("client_id", client_id)
+        .append_pair("response_type", "code")
+        .append_pair("redirect_uri", REDIRECT_URI)
+        .append_pair("scope", "openid email profile")
+        .append_pair("state", "app-state");
+    let code = authorize_at_idp(auth_url.as_str(), sub, claims).await;
+
+    let resp = idp_client()
+        .post(format!("{}/sso/token", oidc_url()))
+        .header(HOST, IDP_HOST)
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(form(&[
+            ("grant_type", "authorization_code"),
+            ("code", &code),
+            ("redirect_uri", REDIRECT_URI),
+            ("client_id", client_id),
+            ("client_secret", CLIENT_SECRET),
+        ]))
+        .send()
+        .await
+        .expect("IdP token request");
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    body["id_token"].as_str().expect("id_token").to_string()
+}
+
+#[tokio::test]
+async fn oidc_login_signs_up_then_signs_in() {
+    let client = TestClient::new();
+    let (sub, email) = identity();
+
+    let (auth_url, _) = authorize(&client, "login").await;
+    let parsed = Url::parse(&auth_url).unwrap();
+    assert_eq!(
+        query_param(&parsed, "code_challenge_method").as_deref(),
+        Some("S256")
+    );
+    assert!(
+        query_param(&parsed, "nonce").is_some(),
+        "nonce must be sent"
+    );
+    assert_eq!(
+        query_param(&parsed, "client_id").as_deref(),
+        Some(CLIENT_ID)
+    );
+
+    // New identity: pending signup, completed with a handle.
+    let resp = login(&client, &sub, &verified(&email)).await;
+    let pending = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(pending["email"], email.as_str());
+    let handle = format!("o{}", &unique()[..12]);
+    let resp = client
+        .post_json(
+            "/v0/auth/complete-signup",
+            &json!({
+                "pending_token": pending["pending_token"],
+                "handle": handle,
+                "display_name": "OIDC User",
+            }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    assert_eq!(client.me().await["handle"], handle.as_str());
+
+    let resp = client.get("/v0/auth/oauth/connections").await;
+    let connections = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(connections["connections"][0]["provider"], "Sso");
+
+    // Known identity: straight to a session from a new browser.
+    let other = TestClient::new();
+    let resp = login(&other, &sub, &verified(&email)).await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    assert_eq!(other.me().await["handle"], handle.as_str());
+}
+
+#[tokio::test]
+async fn oidc_state_is_single_use_and_browser_bound() {
+    let client = TestClient::new();
+    let (sub, email) = identity();
+    let (auth_url, state) = authorize(&client, "login").await;
+    let code = authorize_at_idp(&auth_url, &sub, &verified(&email)).await;
+
+    // Another browser cannot redeem the state; the attempt burns it.
+    let resp = TestClient::new()
+        .post_json(
+            "/v0/auth/oauth/sso/login",
+            &json!({ "code": code, "state": state }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::BAD_REQUEST).await;
+    assert_eq!(body["code"], "oauth:invalid_state");
+
+    let resp = client
+        .post_json(
+            "/v0/auth/oauth/sso/login",
+            &json!({ "code": code, "state": state }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::BAD_REQUEST).await;
+    assert_eq!(body["code"], "oauth:invalid_state");
+}
+
+#[tokio::test]
+async fn oidc_requires_a_verified_email() {
+    let client = TestClient::new();
+    let (sub, email) = identity();
+
+    let resp = login(
+        &client,
+        &sub,
+        &json!({ "email": email, "email_verified": false }),
+    )
+    .await;
+    let body = TestClient::json_ok(resp, StatusCode::BAD_REQUEST).await;
+    assert_eq!(body["code"], "oauth:email_not_verified");
+}
+
+#[tokio::test]
+async fn oidc_link_then_sign_in_then_unlink() {
+    let client = TestClient::new();
+    let user = client.signup_and_login().await;
+    let (sub, _) = identity();
+
+    // The IdP's email need not match the account's: linking is explicit.
+    let (auth_url, state) = authorize(&client, "link").await;
+    let code = authorize_at_idp(&auth_url, &sub, &verified(&identity().1)).await;
+    let resp = client
+        .post_json(
+            "/v0/auth/oauth/sso/link",
+            &json!({ "code": code, "state": state }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    // A login-flow state cannot be used to link, and vice versa.
+    let (auth_url, state) = authorize(&client, "login").await;
+    let code = authorize_at_idp(&auth_url, &sub, &verified(&user.email)).await;
+    let resp = client
+        .post_json(
+            "/v0/auth/oauth/sso/link",
+            &json!({ "code": code, "state": state }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let other = TestClient::new();
+    let resp = login(&other, &sub, &verified(&user.email)).await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    assert_eq!(other.me().await["handle"], user.handle.as_str());
+
+    let resp = client
+        .post_json(
+            "/v0/auth/oauth/connections/unlink",
+            &json!({ "provider": "Sso" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn oidc_app_token_flow_checks_the_audience() {
+    let (sub, email) = identity();
+    let client = TestClient::new();
+
+    let id_token = app_id_token(CLIENT_ID, &sub, &verified(&email)).await;
+    let resp = client
+        .post_json(
+            "/v0/app/auth/oauth/sso/token",
+            &json!({ "id_token": id_token }),
+        )
+        .await;
+    let pending = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(pending["pending_token"].is_string());
+    assert_eq!(pending["email"], email.as_str());
+
+    // A token the IdP minted for a different client is rejected.
+    let foreign = app_id_token("some-other-app", &sub, &verified(&email)).await;
+    let resp = client
+        .post_json(
+            "/v0/app/auth/oauth/sso/token",
+            &json!({ "id_token": foreign }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::BAD_REQUEST).await;
+    assert_eq!(body["code"], "oidc:invalid_id_token");
+}
+
+#[tokio::test]
+async fn unconfigured_oidc_provider_is_not_found() {
+    let client = TestClient::new();
+
+    let resp = client.get("/v0/auth/oauth/discord/authorize").await;
+    let body = TestClient::json_ok(resp, StatusCode::NOT_FOUND).await;
+    assert_eq!(body["code"], "oidc:provider_not_configured");
+
+    let resp = client
+        .post_json(
+            "/v0/app/auth/oauth/keycloak/token",
+            &json!({ "id_token": "a.b.c" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
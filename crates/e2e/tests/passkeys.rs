@@ -0,0 +1,354 @@
+//! Passkey (WebAuthn) e2e tests, driven by a software authenticator.
+//! Run via `just e2e`.
+//!
+//! Policy references:
+//! - Registration requires a session: `register/options` issues creation options and a
+//!   single-use ceremony id, `register` verifies the attestation and stores the passkey.
+//! - Passwordless login uses discoverable credentials: the options carry no allow-list and
+//!   the assertion's user handle identifies the account. Ceremonies are single-use.
+//! - With TOTP enabled, login answers 202 with `passkey_available`, and the passkey can
+//!   stand in for the TOTP code via `second-factor/options` + `second-factor`.
+//! - New-device verification applies to passkey logins exactly as to password logins.
+
+use e2e::TestClient;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+use totp_rs::TOTP;
+use url::Url;
+use webauthn_authenticator_rs::WebauthnAuthenticator;
+use webauthn_authenticator_rs::softpasskey::SoftPasskey;
+use webauthn_rs_proto::{AllowCredentials, CreationChallengeResponse, RequestChallengeResponse};
+
+/// Matches `WEBAUTHN_RP_ORIGIN` in `.envs/.test/server.env`: the authenticator signs this
+/// origin into its client data, as a browser on the frontend would.
+const ORIGIN: &str = "http://localhost:5173";
+
+fn origin() -> Url {
+    Url::parse(ORIGIN).expect("valid origin")
+}
+
+/// A software authenticator plus what a platform authenticator would remember about the
+/// discoverable credential it created (its id and the account's user handle).
+struct Authenticator {
+    inner: WebauthnAuthenticator<SoftPasskey>,
+    credential_id: Option<Vec<u8>>,
+    user_handle: Option<Vec<u8>>,
+}
+
+impl Authenticator {
+    fn new() -> Self {
+        Self {
+            // `true` asserts user verification, as a biometric/PIN-protected passkey would.
+            inner: WebauthnAuthenticator::new(SoftPasskey::new(true)),
+            credential_id: None,
+            user_handle: None,
+        }
+    }
+
+    /// Answers `register/options` and returns the JSON credential to post to `register`.
+    fn register(&mut self, options: &Value) -> Value {
+        let options: CreationChallengeResponse =
+            serde_json::from_value(options.clone()).expect("creation options");
+        self.user_handle = Some(options.public_key.user.id.to_vec());
+        let credential = self
+            .inner
+            .do_registration(origin(), options)
+            .expect("software authenticator registration");
+        self.credential_id = Some(credential.raw_id.to_vec());
+        serde_json::to_value(credential).expect("serialize credential")
+    }
+
+    /// Answers authentication options with an allow-list (second factor).
+    fn assert(&mut self, options: &Value) -> Value {
+        let options: RequestChallengeResponse =
+            serde_json::from_value(options.clone()).expect("request options");
+        let credential = self
+            .inner
+            .do_authentication(origin(), options)
+            .expect("software authenticator assertion");
+        serde_json::to_value(credential).expect("serialize assertion")
+    }
+
+    /// Answers discoverable-login options. The software authenticator keeps no resident
+    /// credentials, so do what a platform authenticator does internally: pick the stored
+    /// credential and return the account's user handle with the assertion.
+    fn assert_discoverable(&mut self, options: &Value) -> Value {
+        let mut options: RequestChallengeResponse =
+            serde_json::from_value(options.clone()).expect("request options");
+        assert!(
+            options.public_key.allow_credentials.is_empty(),
+            "passwordless login options must not reveal the account's credentials"
+        );
+        options.public_key.allow_credentials.push(AllowCredentials {
+            type_: "public-key".to_string(),
+            id: self
+                .credential_id
+                .clone()
+                .expect("registered credential")
+                .into(),
+            transports: None,
+        });
+        let mut credential = self
+            .inner
+            .do_authentication(origin(), options)
+            .expect("software authenticator assertion");
+        credential.response.user_handle = self.user_handle.clone().map(Into::into);
+        serde_json::to_value(credential).expect("serialize assertion")
+    }
+}
+
+/// Registers a passkey for the signed-in `client`.
+async fn register_passkey(client: &TestClient, authenticator: &mut Authenticator) -> Value {
+    let resp = client
+        .post_json("/v0/auth/passkeys/register/options", &json!({}))
+        .await;
+    let started = TestClient::json_ok(resp, StatusCode::OK).await;
+    let credential = authenticator.register(&started["options"]);
+
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/register",
+            &json!({
+                "ceremony_id": started["ceremony_id"],
+                "name": "E2E soft passkey",
+                "credential": credential,
+            }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::CREATED).await
+}
+
+/// Starts a passwordless login and returns (ceremony_id, assertion).
+async fn discoverable_assertion(
+    client: &TestClient,
+    authenticator: &mut Authenticator,
+) -> (Value, Value) {
+    let resp = client
+        .post_json("/v0/auth/passkeys/login/options", &json!({}))
+        .await;
+    let started = TestClient::json_ok(resp, StatusCode::OK).await;
+    let credential = authenticator.assert_discoverable(&started["options"]);
+    (started["ceremony_id"].clone(), credential)
+}
+
+#[tokio::test]
+async fn passkey_register_list_and_remove() {
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let mut authenticator = Authenticator::new();
+
+    let created = register_passkey(&client, &mut authenticator).await;
+    assert_eq!(created["name"], "E2E soft passkey");
+    assert!(created["last_used_at"].is_null());
+    let id = created["id"].as_str().expect("passkey id").to_string();
+
+    let resp = client.get("/v0/auth/passkeys").await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    let data = list["data"].as_array().expect("passkey list");
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0]["id"], id.as_str());
+    assert!(
+        data[0].get("passkey").is_none() && data[0].get("credential_id").is_none(),
+        "credential material must never be returned"
+    );
+
+    // The same authenticator cannot be enrolled twice: its credential is excluded from the
+    // next registration options.
+    let resp = client
+        .post_json("/v0/auth/passkeys/register/options", &json!({}))
+        .await;
+    let started = TestClient::json_ok(resp, StatusCode::OK).await;
+    let excluded = started["options"]["publicKey"]["excludeCredentials"]
+        .as_array()
+        .expect("exclude list");
+    assert_eq!(excluded.len(), 1, "existing passkeys must be excluded");
+
+    let resp = client.delete(&format!("/v0/auth/passkeys/{id}")).await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    let resp = client.delete(&format!("/v0/auth/passkeys/{id}")).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = client.get("/v0/auth/passkeys").await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(list["data"].as_array().unwrap().is_empty());
+
+    // Management needs a session.
+    let resp = TestClient::new().get("/v0/auth/passkeys").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn passwordless_login_with_passkey() {
+    let client = TestClient::new();
+    let user = client.signup_and_login().await;
+    let mut authenticator = Authenticator::new();
+    register_passkey(&client, &mut authenticator).await;
+
+    let resp = client.post_json("/v0/auth/logout", &json!({})).await;
+    assert!(resp.status().is_success());
+    let resp = client.get("/v0/user/me").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    // Same browser (trusted device cookie): the passkey alone signs in.
+    let (ceremony_id, credential) = discoverable_assertion(&client, &mut authenticator).await;
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/login",
+            &json!({ "ceremony_id": ceremony_id, "credential": credential }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    let me = client.me().await;
+    assert_eq!(me["handle"], user.handle.as_str());
+
+    // The ceremony is single-use: replaying the same signed assertion is rejected.
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/login",
+            &json!({ "ceremony_id": ceremony_id, "credential": credential }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::BAD_REQUEST).await;
+    assert_eq!(body["code"], "passkey:ceremony_invalid");
+
+    let resp = client.get("/v0/auth/passkeys").await;
+    let list = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(
+        list["data"][0]["last_used_at"].is_string(),
+        "a successful login stamps last_used_at"
+    );
+
+    // Native app without a device token: the passkey verifies, but the new-device gate
+    // still holds the session back.
+    let app = TestClient::new();
+    let (ceremony_id, credential) = discoverable_assertion(&app, &mut authenticator).await;
+    let resp = app
+        .post_json(
+            "/v0/app/auth/passkeys/login",
+            &json!({ "ceremony_id": ceremony_id, "credential": credential }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn passkey_login_rejects_an_unknown_credential() {
+    let client = TestClient::new();
+    client.signup_and_login().await;
+    let mut authenticator = Authenticator::new();
+    let created = register_passkey(&client, &mut authenticator).await;
+
+    let resp = client
+        .delete(&format!(
+            "/v0/auth/passkeys/{}",
+            created["id"].as_str().unwrap()
+        ))
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    // A removed passkey can no longer sign in.
+    let fresh = TestClient::new();
+    let (ceremony_id, credential) = discoverable_assertion(&fresh, &mut authenticator).await;
+    let resp = fresh
+        .post_json(
+            "/v0/auth/passkeys/login",
+            &json!({ "ceremony_id": ceremony_id, "credential": credential }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::UNAUTHORIZED).await;
+    assert_eq!(body["code"], "passkey:authentication_failed");
+}
+
+#[tokio::test]
+async fn passkey_replaces_totp_code_as_second_factor() {
+    let client = TestClient::new();
+    let user = client.signup_and_login().await;
+
+    let resp = client.post_json("/v0/auth/totp/setup", &json!({})).await;
+    let setup = TestClient::json_ok(resp, StatusCode::OK).await;
+    let totp = TOTP::from_url(setup["qr_code_uri"].as_str().unwrap()).expect("otpauth uri");
+    let code = totp.generate_current().expect("generate TOTP code");
+    let resp = client
+        .post_json("/v0/auth/totp/enable", &json!({ "code": code }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let login = json!({ "email": user.email, "password": user.password });
+
+    // Without a passkey, TOTP is the only second factor offered.
+    let resp = TestClient::new().post_json("/v0/auth/login", &login).await;
+    let body = TestClient::json_ok(resp, StatusCode::ACCEPTED).await;
+    assert_eq!(body["passkey_available"], false);
+
+    let mut authenticator = Authenticator::new();
+    register_passkey(&client, &mut authenticator).await;
+    let resp = client.post_json("/v0/auth/logout", &json!({})).await;
+    assert!(resp.status().is_success());
+
+    let resp = client.post_json("/v0/auth/login", &login).await;
+    let body = TestClient::json_ok(resp, StatusCode::ACCEPTED).await;
+    assert_eq!(body["passkey_available"], true);
+    let temp_token = body["temp_token"].as_str().unwrap().to_string();
+
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/second-factor/options",
+            &json!({ "temp_token": temp_token }),
+        )
+        .await;
+    let started = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(
+        started["options"]["publicKey"]["allowCredentials"]
+            .as_array()
+            .map(Vec::len),
+        Some(1),
+        "second-factor options are limited to the account's passkeys"
+    );
+    let credential = authenticator.assert(&started["options"]);
+
+    // A ceremony is bound to its login: another temp token cannot finish it.
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/second-factor",
+            &json!({
+                "temp_token": "not-the-login-token",
+                "ceremony_id": started["ceremony_id"],
+                "credential": credential,
+            }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // That attempt burned the ceremony; start again and finish properly.
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/second-factor/options",
+            &json!({ "temp_token": temp_token }),
+        )
+        .await;
+    let started = TestClient::json_ok(resp, StatusCode::OK).await;
+    let credential = authenticator.assert(&started["options"]);
+    let resp = client
+        .post_json(
+            "/v0/auth/passkeys/second-factor",
+            &json!({
+                "temp_token": temp_token,
+                "ceremony_id": started["ceremony_id"],
+                "credential": credential,
+            }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    let me = client.me().await;
+    assert_eq!(me["handle"], user.handle.as_str());
+
+    // The temp token was consumed: TOTP cannot reuse it.
+    let code = totp.generate_current().expect("generate TOTP code");
+    let resp = client
+        .post_json(
+            "/v0/auth/totp/verify",
+            &json!({ "temp_token": temp_token, "code": code }),
+        )
+        .await;
+    assert!(!resp.status().is_success());
+}
@@ -0,0 +1,273 @@
+//! Report e2e tests. Run via `just e2e`.
+//!
+//! Policy references:
+//! - `POST /v0/reports` requires login; the target must exist (and be visible),
+//!   self-reports are refused (400) and a reporter holds at most one open report
+//!   per target (409 until it is closed).
+//! - `/v0/moderation/reports*` sit behind the router-level `require_mod` gate.
+//! - Resolving links an existing moderation log entry on the same target;
+//!   dismissing writes its own `report:dismiss` entry.
+
+use e2e::TestClient;
+use entity::common::Role;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+async fn new_moderator() -> TestClient {
+    let client = TestClient::new();
+    let user = client.signup_and_login().await;
+    e2e::grant_role(&user.handle, Role::Mod).await;
+    client
+}
+
+/// Creates a post on the seeded `general` board as `client` and returns its id.
+async fn create_post(client: &TestClient) -> String {
+    let resp = client
+        .get_q("/v0/board/by-slug", &[("slug", "general")])
+        .await;
+    let board = TestClient::json_ok(resp, StatusCode::OK).await;
+    let resp = client
+        .post_json(
+            "/v0/board/post",
+            &json!({
+                "board_id": board["id"],
+                "title": format!("Report {}", e2e::unique()),
+                "content": "reported body",
+            }),
+        )
+        .await;
+    let post = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    post["id"].as_str().expect("post id").to_string()
+}
+
+async fn report_post(client: &TestClient, post_id: &str) -> reqwest::Response {
+    client
+        .post_json(
+            "/v0/reports",
+            &json!({
+                "target_type": "BoardPost",
+                "target_id": post_id,
+                "reason": "Spam",
+                "description": "buy now",
+            }),
+        )
+        .await
+}
+
+/// Open reports on `target_id`, as seen by `moderator`.
+async fn open_reports(moderator: &TestClient, target_id: &str) -> Vec<Value> {
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/reports",
+            &[
+                ("limit", "50"),
+                ("status", "Open"),
+                ("target_id", target_id),
+            ],
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    body["data"].as_array().expect("data array").clone()
+}
+
+/// Id of the newest moderation log entry on `resource_id`.
+async fn latest_log_id(moderator: &TestClient, resource_id: &str) -> String {
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/logs",
+            &[("limit", "1"), ("resource_id", resource_id)],
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    body["data"][0]["id"].as_str().expect("log id").to_string()
+}
+
+#[tokio::test]
+async fn reporting_requires_login_and_refuses_self_and_duplicates() {
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+
+    let anon = TestClient::new();
+    let resp = report_post(&anon, &post_id).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = report_post(&author, &post_id).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "self-report");
+
+    let reporter = TestClient::new();
+    reporter.signup_and_login().await;
+    let resp = report_post(&reporter, &post_id).await;
+    let report = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(report["status"], "Open");
+
+    let resp = report_post(&reporter, &post_id).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT, "second open report");
+
+    let resp = reporter
+        .post_json(
+            "/v0/reports",
+            &json!({
+                "target_type": "BoardPost",
+                "target_id": uuid::Uuid::new_v4(),
+                "reason": "Spam",
+            }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND, "missing target");
+}
+
+#[tokio::test]
+async fn concurrent_duplicate_reports_yield_one_report_and_conflicts() {
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+
+    let reporter = TestClient::new();
+    reporter.signup_and_login().await;
+    let (a, b, c, d) = tokio::join!(
+        report_post(&reporter, &post_id),
+        report_post(&reporter, &post_id),
+        report_post(&reporter, &post_id),
+        report_post(&reporter, &post_id),
+    );
+    let statuses = [a.status(), b.status(), c.status(), d.status()];
+    let created = statuses.iter().filter(|s| **s == StatusCode::OK).count();
+    assert_eq!(created, 1, "exactly one report is filed: {statuses:?}");
+    assert!(
+        statuses
+            .iter()
+            .all(|s| *s == StatusCode::OK || *s == StatusCode::CONFLICT),
+        "the losers see 409, not 500: {statuses:?}"
+    );
+
+    let moderator = new_moderator().await;
+    assert_eq!(open_reports(&moderator, &post_id).await.len(), 1);
+}
+
+#[tokio::test]
+async fn report_queue_is_moderator_only_and_counts_reports() {
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+
+    let first = TestClient::new();
+    first.signup_and_login().await;
+    let second = TestClient::new();
+    second.signup_and_login().await;
+    for reporter in [&first, &second] {
+        let resp = report_post(reporter, &post_id).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    let resp = first.get("/v0/moderation/reports?limit=10").await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN, "plain user queue");
+
+    let moderator = new_moderator().await;
+    let reports = open_reports(&moderator, &post_id).await;
+    assert_eq!(reports.len(), 2);
+    for report in &reports {
+        assert_eq!(report["target_open_reports"], 2);
+        assert_eq!(report["target_total_reports"], 2);
+        assert_eq!(report["reporter_total_reports"], 1);
+    }
+
+    // Paging one at a time walks the same two reports.
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/reports",
+            &[("limit", "1"), ("target_id", post_id.as_str())],
+        )
+        .await;
+    let page = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(page["has_older"], true);
+    assert_eq!(page["has_newer"], false);
+    assert_eq!(page["data"][0]["id"], reports[0]["id"]);
+}
+
+#[tokio::test]
+async fn resolving_links_the_moderation_log_and_dismissing_writes_one() {
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+    let other_post_id = create_post(&author).await;
+
+    let reporter = TestClient::new();
+    reporter.signup_and_login().await;
+    let resp = report_post(&reporter, &post_id).await;
+    let report = TestClient::json_ok(resp, StatusCode::OK).await;
+
+    let moderator = new_moderator().await;
+    for target in [&post_id, &other_post_id] {
+        let resp = moderator
+            .post_json(
+                "/v0/board/post/hide",
+                &json!({ "post_id": target, "reason": "e2e: report" }),
+            )
+            .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+    let other_log_id = latest_log_id(&moderator, &other_post_id).await;
+    let hide_log_id = latest_log_id(&moderator, &post_id).await;
+
+    // The linked action must be on the reported target.
+    let resp = moderator
+        .post_json(
+            "/v0/moderation/reports/resolve",
+            &json!({ "report_id": report["id"], "moderation_log_id": other_log_id }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = moderator
+        .post_json(
+            "/v0/moderation/reports/resolve",
+            &json!({
+                "report_id": report["id"],
+                "moderation_log_id": hide_log_id,
+                "note": "hidden",
+            }),
+        )
+        .await;
+    let resolved = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(resolved["status"], "Resolved");
+    assert_eq!(resolved["moderation_log_id"], json!(hide_log_id));
+
+    let resp = moderator
+        .post_json(
+            "/v0/moderation/reports/dismiss",
+            &json!({ "report_id": report["id"], "reason": "too late" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT, "already closed");
+
+    // Closed reports no longer block a new one; dismiss it this time.
+    let resp = report_post(&reporter, &post_id).await;
+    let again = TestClient::json_ok(resp, StatusCode::OK).await;
+    let resp = moderator
+        .post_json(
+            "/v0/moderation/reports/dismiss",
+            &json!({ "report_id": again["id"], "reason": "already handled" }),
+        )
+        .await;
+    let dismissed = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(dismissed["status"], "Dismissed");
+
+    assert_eq!(
+        dismissed["moderation_log_id"],
+        json!(latest_log_id(&moderator, &post_id).await),
+        "dismissal is the newest log entry on the target"
+    );
+
+    // The reporter's history now shows one dismissal.
+    let resp = moderator
+        .get_q(
+            "/v0/moderation/reports",
+            &[("limit", "1"), ("target_id", post_id.as_str())],
+        )
+        .await;
+    let latest = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(latest["data"][0]["reporter_total_reports"], 2);
+    assert_eq!(latest["data"][0]["reporter_dismissed_reports"], 1);
+    assert_eq!(latest["data"][0]["target_open_reports"], 0);
+}
@@ -7,10 +7,16 @@
 //!   so the changed fields become searchable.
 //! - Account deletion removes the user document from the search index, so a
 //!   deleted account is no longer discoverable.
+//! - Board posts and comments are indexed on create/update/delete and on
+//!   hide/unhide. Hidden hits are filtered out for everyone but moderators, and
+//!   deleting a post drops its comments from the comment index too.
+//! - `/v0/search/reindex` is admin-only. A run reports per-batch progress until
+//!   the rebuilt index is swapped in, and is logged as `search:reindex`.
 
 use std::time::Duration;
 
 use e2e::TestClient;
+use entity::common::Role;
 use reqwest::StatusCode;
 use serde_json::{Value, json};
 
@@ -129,3 +135,180 @@ async fn account_deletion_removes_user_from_search() {
     // The deleted account must disappear from the public search index.
     wait_for_user_search_presence(&user.handle, &user.handle, false).await;
 }
+
+/// Polls `path` (posts or comments search) as `client` until the hit with `id`
+/// is present or absent, returning the hit when present.
+async fn wait_for_board_search_hit(
+    client: &TestClient,
+    path: &str,
+    query: &str,
+    id: &str,
+    expect_present: bool,
+) -> Option<Value> {
+    let key = if path.ends_with("posts") {
+        "posts"
+    } else {
+        "comments"
+    };
+    for _ in 0..240 {
+        let resp = client
+            .get_q(
+                path,
+                &[("query", query), ("page", "1"), ("page_size", "20")],
+            )
+            .await;
+        if resp.status() == StatusCode::OK
+            && let Ok(body) = resp.json::<Value>().await
+        {
+            let hit = body[key]
+                .as_array()
+                .and_then(|hits| hits.iter().find(|h| h["id"].as_str() == Some(id)).cloned());
+            if hit.is_some() == expect_present {
+                return hit;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    panic!(
+        "timed out waiting for {id} to be {} in {path} results for {query:?}",
+        if expect_present { "present" } else { "absent" }
+    );
+}
+
+/// Creates a post on the seeded `general` board and returns its id.
+async fn create_post(client: &TestClient, title: &str, content: &str) -> String {
+    let resp = client
+        .get_q("/v0/board/by-slug", &[("slug", "general")])
+        .await;
+    let board = TestClient::json_ok(resp, StatusCode::OK).await;
+    let resp = client
+        .post_json(
+            "/v0/board/post",
+            &json!({ "board_id": board["id"], "title": title, "content": content }),
+        )
+        .await;
+    let post = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    post["id"].as_str().expect("post id").to_string()
+}
+
+#[tokio::test]
+async fn post_search_hides_moderated_posts_from_non_moderators() {
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let token = format!("ps{}", &e2e::unique()[..10]);
+    let post_id = create_post(&author, &format!("Post about {token}"), "searchable body").await;
+
+    let anon = TestClient::new();
+    let hit = wait_for_board_search_hit(&anon, "/v0/search/posts", &token, &post_id, true)
+        .await
+        .expect("post hit");
+    assert_eq!(hit["is_hidden"], false);
+
+    let moderator = TestClient::new();
+    let mod_user = moderator.signup_and_login().await;
+    e2e::grant_role(&mod_user.handle, Role::Mod).await;
+    let resp = moderator
+        .post_json(
+            "/v0/board/post/hide",
+            &json!({ "post_id": post_id, "reason": "e2e: search" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Gone for the public, still listed (flagged) for moderators.
+    wait_for_board_search_hit(&anon, "/v0/search/posts", &token, &post_id, false).await;
+    let hit = wait_for_board_search_hit(&moderator, "/v0/search/posts", &token, &post_id, true)
+        .await
+        .expect("moderator hit");
+    assert_eq!(hit["is_hidden"], true);
+}
+
+#[tokio::test]
+async fn deleting_a_post_drops_it_and_its_comments_from_search() {
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let token = format!("cs{}", &e2e::unique()[..10]);
+    let post_id = create_post(&author, &format!("Thread {token}"), "thread body").await;
+
+    let resp = author
+        .post_json(
+            "/v0/board/comment",
+            &json!({ "post_id": post_id, "content": format!("reply mentioning {token}") }),
+        )
+        .await;
+    let comment = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    let comment_id = comment["id"].as_str().expect("comment id").to_string();
+
+    let anon = TestClient::new();
+    let hit = wait_for_board_search_hit(&anon, "/v0/search/comments", &token, &comment_id, true)
+        .await
+        .expect("comment hit");
+    assert_eq!(hit["post_id"].as_str(), Some(post_id.as_str()));
+    wait_for_board_search_hit(&anon, "/v0/search/posts", &token, &post_id, true).await;
+
+    let resp = author
+        .post_json("/v0/board/post/delete", &json!({ "post_id": post_id }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    wait_for_board_search_hit(&anon, "/v0/search/posts", &token, &post_id, false).await;
+    wait_for_board_search_hit(&anon, "/v0/search/comments", &token, &comment_id, false).await;
+}
+
+#[tokio::test]
+async fn admin_reindex_reports_progress_and_logs_the_run() {
+    let member = TestClient::new();
+    member.signup_and_login().await;
+    let body = json!({ "target": "Comments", "batch_size": 2, "reason": "e2e: reindex" });
+    let resp = member.post_json("/v0/search/reindex", &body).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN, "non-admin reindex");
+
+    let admin = TestClient::new();
+    let admin_user = admin.signup_and_login().await;
+    e2e::grant_role(&admin_user.handle, Role::Admin).await;
+    let resp = admin.post_json("/v0/search/reindex", &body).await;
+    let started = TestClient::json_ok(resp, StatusCode::ACCEPTED).await;
+    let reindex_id = started["reindex_id"]
+        .as_str()
+        .expect("reindex id")
+        .to_string();
+
+    // Batches run asynchronously; wait for the swap.
+    let mut status = Value::Null;
+    for _ in 0..240 {
+        let resp = admin
+            .get_q("/v0/search/reindex", &[("reindex_id", reindex_id.as_str())])
+            .await;
+        status = TestClient::json_ok(resp, StatusCode::OK).await;
+        if status["status"] == "Completed" {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    assert_eq!(
+        status["status"], "Completed",
+        "reindex never completed: {status}"
+    );
+    assert_eq!(status["target"], "Comments");
+    assert!(status["batch_number"].as_u64().is_some_and(|n| n >= 1));
+    // Other suites may add comments mid-run, so only check both were recorded.
+    assert!(status["processed_count"].as_u64().is_some());
+    assert!(status["total_count"].as_u64().is_some());
+
+    let resp = admin
+        .get_q(
+            "/v0/moderation/logs",
+            &[("limit", "1"), ("resource_id", reindex_id.as_str())],
+        )
+        .await;
+    let logs = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(logs["data"][0]["action"], "search:reindex");
+
+    let resp = admin
+        .get_q(
+            "/v0/search/reindex",
+            &[("reindex_id", uuid::Uuid::new_v4().to_string().as_str())],
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,186 @@
+//! Security center e2e tests. Run via `just e2e`.
+//!
+//! Policy references:
+//! - `/v0/auth/events` pages through the caller's own auth events, newest first.
+//! - `/v0/auth/devices` lists the caller's verified devices; forgetting one (only one's
+//!   own — a foreign id is 404) makes its next login go through new-device verification.
+//! - `POST /v0/auth/sessions/revoke-others` kills every session but the caller's.
+
+use e2e::{SignedUpUser, TestClient};
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+/// Logs `client` in as `user` from an unknown device and completes the emailed challenge,
+/// leaving the client with a session and a trusted device cookie.
+async fn login_on_new_device(client: &TestClient, user: &SignedUpUser) {
+    let before_device_mail = e2e::latest_message_id(&user.email).await;
+    let resp = client
+        .post_json(
+            "/v0/auth/login",
+            &json!({ "email": user.email, "password": user.password }),
+        )
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::ACCEPTED,
+        "unknown device is held"
+    );
+    let body = e2e::wait_for_new_message_body(&user.email, before_device_mail.as_deref()).await;
+    let device_token = e2e::extract_token(&body).expect("device verification token");
+    let resp = client
+        .post_json("/v0/auth/device/verify", &json!({ "token": device_token }))
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT, "device verify");
+}
+
+fn event_types(body: &Value) -> Vec<&str> {
+    body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["event_type"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn login_history_lists_own_events_newest_first() {
+    let alice = TestClient::new();
+    let user = alice.signup_and_login().await;
+
+    let resp = TestClient::new()
+        .post_json(
+            "/v0/auth/login",
+            &json!({ "email": user.email, "password": "definitely-wrong" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    login_on_new_device(&TestClient::new(), &user).await;
+
+    let resp = alice.get_q("/v0/auth/events", &[("limit", "50")]).await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let types = event_types(&body);
+    assert!(types.contains(&"login_success"), "{types:?}");
+    assert!(types.contains(&"new_device_login"), "{types:?}");
+    assert!(types.contains(&"login_failed"), "{types:?}");
+    assert_eq!(body["has_newer"], false);
+    assert_eq!(body["has_older"], false);
+    let created: Vec<&str> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["created_at"].as_str().unwrap())
+        .collect();
+    assert!(
+        created.first() >= created.last(),
+        "newest first: {created:?}"
+    );
+
+    // Paging: one event per page, walking back with the cursor.
+    let resp = alice.get_q("/v0/auth/events", &[("limit", "1")]).await;
+    let first = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(first["data"].as_array().unwrap().len(), 1);
+    assert_eq!(first["has_older"], true);
+    let cursor = first["data"][0]["id"].as_str().unwrap().to_string();
+    let resp = alice
+        .get_q(
+            "/v0/auth/events",
+            &[
+                ("limit", "1"),
+                ("cursor_id", &cursor),
+                ("cursor_direction", "Older"),
+            ],
+        )
+        .await;
+    let second = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(second["has_newer"], true);
+    assert_ne!(second["data"][0]["id"], first["data"][0]["id"]);
+
+    // Another user sees none of Alice's events.
+    let bob = TestClient::new();
+    bob.signup_and_login().await;
+    let resp = bob.get_q("/v0/auth/events", &[("limit", "50")]).await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(!event_types(&body).contains(&"login_failed"));
+}
+
+#[tokio::test]
+async fn forgetting_a_device_forces_reverification() {
+    let alice1 = TestClient::new();
+    let user = alice1.signup_and_login().await;
+    let alice2 = TestClient::new();
+    login_on_new_device(&alice2, &user).await;
+
+    let resp = alice2.get("/v0/auth/devices").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    let devices = body["data"].as_array().unwrap();
+    assert_eq!(devices.len(), 1, "the verified device is listed");
+    assert_eq!(devices[0]["is_current"], true);
+    let device_id = devices[0]["id"].as_str().unwrap().to_string();
+
+    // The signup browser never verified a device, so it is not the current one.
+    let resp = alice1.get("/v0/auth/devices").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["data"][0]["is_current"], false);
+
+    // A trusted device signs in again without a challenge.
+    let resp = alice2
+        .post_json(
+            "/v0/auth/login",
+            &json!({ "email": user.email, "password": user.password }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT, "trusted device");
+
+    let bob = TestClient::new();
+    bob.signup_and_login().await;
+    let resp = bob.delete(&format!("/v0/auth/devices/{device_id}")).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND, "foreign device");
+
+    let resp = alice1
+        .delete(&format!("/v0/auth/devices/{device_id}"))
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    let resp = alice1.get("/v0/auth/devices").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert!(body["data"].as_array().unwrap().is_empty());
+
+    // Same browser, same device cookie: challenged again.
+    let resp = alice2
+        .post_json(
+            "/v0/auth/login",
+            &json!({ "email": user.email, "password": user.password }),
+        )
+        .await;
+    assert_eq!(
+        resp.status(),
+        StatusCode::ACCEPTED,
+        "a forgotten device must be verified again"
+    );
+}
+
+#[tokio::test]
+async fn revoke_other_sessions_keeps_only_the_caller() {
+    let alice1 = TestClient::new();
+    let user = alice1.signup_and_login().await;
+    let alice2 = TestClient::new();
+    login_on_new_device(&alice2, &user).await;
+    let alice3 = TestClient::new();
+    login_on_new_device(&alice3, &user).await;
+
+    let resp = alice1
+        .post_json("/v0/auth/sessions/revoke-others", &json!({}))
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["revoked"], 2);
+
+    for client in [&alice2, &alice3] {
+        let resp = client.get("/v0/user/me").await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED, "revoked session");
+    }
+    let resp = alice1.get("/v0/user/me").await;
+    assert_eq!(resp.status(), StatusCode::OK, "the caller stays signed in");
+
+    let resp = alice1.get("/v0/auth/sessions").await;
+    let body = TestClient::json_ok(resp, StatusCode::OK).await;
+    assert_eq!(body["sessions"].as_array().unwrap().len(), 1);
+}
@@ -0,0 +1,277 @@
+//! Outbound webhook e2e tests. Run via `just e2e`.
+//!
+//! Receivers are the stack's `turnstile-stub`, which answers 200 to any request on
+//! port 80; nothing listens on port 81, so deliveries there fail.
+//!
+//! Policy references:
+//! - `/v0/webhooks*` sit behind the router-level `require_admin` gate (401 anon,
+//!   403 non-admin).
+//! - Registering an endpoint returns its `whsec_` signing secret once; listings never
+//!   include it. Events must be known action or moderation log codes.
+//! - A logged action is delivered to every active endpoint subscribed to its code, and
+//!   each attempt's outcome is recorded in the endpoint's delivery log.
+//! - A logged delivery can be redelivered; an unknown delivery id is a 404.
+//! - A claimed delivery whose job was never published is claimed and sent again.
+
+use std::time::Duration;
+
+use e2e::TestClient;
+use entity::common::Role;
+use reqwest::StatusCode;
+use serde_json::{Value, json};
+
+/// Signs up a user and grants them the admin role.
+async fn admin_client() -> TestClient {
+    let admin = TestClient::new();
+    let user = admin.signup_and_login().await;
+    e2e::grant_role(&user.handle, Role::Admin).await;
+    admin
+}
+
+/// Registers an endpoint subscribed to `events` and returns the response body.
+async fn create_endpoint(admin: &TestClient, url: &str, events: &[&str]) -> Value {
+    let resp = admin
+        .post_json(
+            "/v0/webhooks",
+            &json!({
+                "url": url,
+                "description": "e2e receiver",
+                "events": events,
+                "reason": "e2e: webhook",
+            }),
+        )
+        .await;
+    TestClient::json_ok(resp, StatusCode::OK).await
+}
+
+/// Creates a post on the seeded "general" board and returns its id.
+async fn create_post(client: &TestClient) -> String {
+    let resp = client
+        .get_q("/v0/board/by-slug", &[("slug", "general")])
+        .await;
+    let board = TestClient::json_ok(resp, StatusCode::OK).await;
+    let resp = client
+        .post_json(
+            "/v0/board/post",
+            &json!({
+                "board_id": board["id"],
+                "title": format!("Webhook {}", e2e::unique()),
+                "content": "Hello, receivers.",
+            }),
+        )
+        .await;
+    let body = TestClient::json_ok(resp, StatusCode::CREATED).await;
+    body["id"].as_str().expect("post id").to_string()
+}
+
+/// Polls the endpoint's delivery log until the delivery for `post_id` reaches `status`.
+/// Other suites create posts concurrently, so the log is searched by resource id.
+async fn wait_for_delivery(
+    admin: &TestClient,
+    endpoint_id: &str,
+    post_id: &str,
+    status: &str,
+) -> Value {
+    for _ in 0..120 {
+        let resp = admin
+            .get_q(
+                "/v0/webhooks/deliveries",
+                &[("endpoint_id", endpoint_id), ("limit", "100")],
+            )
+            .await;
+        let body = TestClient::json_ok(resp, StatusCode::OK).await;
+        let found = body["data"]
+            .as_array()
+            .expect("delivery list")
+            .iter()
+            .find(|d| {
+                d["payload"]["data"]["resource_id"].as_str() == Some(post_id)
+                    && d["status"].as_str() == Some(status)
+            })
+            .cloned();
+        if let Some(found) = found {
+            return found;
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    panic!("timed out waiting for a `{status}` delivery of post {post_id}");
+}
+
+/// Deletes an endpoint so later posts from other suites stop queueing deliveries.
+async fn delete_endpoint(admin: &TestClient, endpoint_id: &str) {
+    let resp = admin
+        .post_json(
+            "/v0/webhooks/delete",
+            &json!({ "endpoint_id": endpoint_id, "reason": "e2e: cleanup" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn webhook_routes_require_admin() {
+    let anon = TestClient::new();
+    let resp = anon.get("/v0/webhooks").await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let user = TestClient::new();
+    user.signup_and_login().await;
+    let resp = user.get("/v0/webhooks").await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    let resp = user
+        .post_json(
+            "/v0/webhooks",
+            &json!({
+                "url": "http://turnstile-stub/hook",
+                "events": ["post:create"],
+                "reason": "e2e: webhook",
+            }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn endpoint_registration_validates_url_and_events() {
+    let admin = admin_client().await;
+
+    for body in [
+        json!({ "url": "ftp://turnstile-stub/hook", "events": ["post:create"], "reason": "e2e" }),
+        json!({ "url": "http://", "events": ["post:create"], "reason": "e2e" }),
+        json!({ "url": "http://localhost:8000/hook", "events": ["post:create"], "reason": "e2e" }),
+        json!({ "url": "http://169.254.169.254/latest/meta-data", "events": ["post:create"], "reason": "e2e" }),
+        json!({ "url": "http://turnstile-stub/hook", "events": ["post:explode"], "reason": "e2e" }),
+        json!({ "url": "http://turnstile-stub/hook", "events": [], "reason": "e2e" }),
+    ] {
+        let resp = admin.post_json("/v0/webhooks", &body).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "accepted {body}");
+    }
+
+    let resp = admin
+        .post_json(
+            "/v0/webhooks/deliveries/redeliver",
+            &json!({ "delivery_id": "00000000-0000-7000-8000-000000000000" }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn logged_actions_are_delivered_signed_and_can_be_redelivered() {
+    let admin = admin_client().await;
+
+    let created = create_endpoint(&admin, "http://turnstile-stub/hook", &["post:create"]).await;
+    let secret = created["secret"].as_str().expect("secret");
+    assert!(secret.starts_with("whsec_"), "unexpected secret {secret}");
+    let endpoint_id = created["endpoint"]["id"]
+        .as_str()
+        .expect("endpoint id")
+        .to_string();
+    assert_eq!(created["endpoint"]["is_active"], true);
+
+    let resp = admin.get("/v0/webhooks").await;
+    let listed = TestClient::json_ok(resp, StatusCode::OK).await;
+    let entry = listed["endpoints"]
+        .as_array()
+        .expect("endpoint list")
+        .iter()
+        .find(|e| e["id"].as_str() == Some(&endpoint_id))
+        .expect("endpoint listed");
+    assert!(entry.get("secret").is_none(), "secret leaked in listing");
+
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+
+    let delivery = wait_for_delivery(&admin, &endpoint_id, &post_id, "Succeeded").await;
+    assert_eq!(delivery["event"], "post:create");
+    assert_eq!(delivery["response_status"], 200);
+    assert_eq!(delivery["attempts"], 1);
+    assert_eq!(delivery["payload"]["type"], "action_log");
+    assert_eq!(delivery["payload"]["event"], "post:create");
+    assert_eq!(delivery["payload"]["id"], delivery["log_id"]);
+
+    let resp = admin
+        .post_json(
+            "/v0/webhooks/deliveries/redeliver",
+            &json!({ "delivery_id": delivery["id"] }),
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::ACCEPTED);
+
+    let mut redelivered = false;
+    for _ in 0..120 {
+        let found = wait_for_delivery(&admin, &endpoint_id, &post_id, "Succeeded").await;
+        if found["attempts"].as_i64() == Some(2) {
+            redelivered = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    assert!(redelivered, "redelivery was not attempted");
+
+    delete_endpoint(&admin, &endpoint_id).await;
+    let resp = admin
+        .get_q(
+            "/v0/webhooks/deliveries",
+            &[("endpoint_id", endpoint_id.as_str()), ("limit", "10")],
+        )
+        .await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn failed_deliveries_are_logged_with_the_error() {
+    let admin = admin_client().await;
+
+    let created = create_endpoint(&admin, "http://turnstile-stub:81/hook", &["post:create"]).await;
+    let endpoint_id = created["endpoint"]["id"]
+        .as_str()
+        .expect("endpoint id")
+        .to_string();
+
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+
+    let delivery = wait_for_delivery(&admin, &endpoint_id, &post_id, "Failed").await;
+    assert!(delivery["response_status"].is_null());
+    assert!(
+        delivery["last_error"]
+            .as_str()
+            .is_some_and(|e| !e.is_empty())
+    );
+    assert!(delivery["delivered_at"].is_null());
+
+    delete_endpoint(&admin, &endpoint_id).await;
+}
+
+#[tokio::test]
+async fn stale_claims_are_dispatched_again() {
+    let admin = admin_client().await;
+
+    let created = create_endpoint(&admin, "http://turnstile-stub/hook", &["post:create"]).await;
+    let endpoint_id = created["endpoint"]["id"]
+        .as_str()
+        .expect("endpoint id")
+        .to_string();
+
+    let author = TestClient::new();
+    author.signup_and_login().await;
+    let post_id = create_post(&author).await;
+
+    let delivery = wait_for_delivery(&admin, &endpoint_id, &post_id, "Succeeded").await;
+    let delivery_id: uuid::Uuid = delivery["id"]
+        .as_str()
+        .expect("delivery id")
+        .parse()
+        .expect("delivery id is a UUID");
+    e2e::strand_webhook_delivery(delivery_id).await;
+
+    // Stranded as `pending`, so reaching `Succeeded` again means it was re-sent.
+    let resent = wait_for_delivery(&admin, &endpoint_id, &post_id, "Succeeded").await;
+    assert_eq!(resent["id"], delivery["id"]);
+    assert_eq!(resent["attempts"], 2);
+
+    delete_endpoint(&admin, &endpoint_id).await;
+}
@@ -6,7 +6,8 @@ use uuid::Uuid;
 /// One row per authentication decision (login success/failure, logout, credential/2FA change),
 /// with the actor IP + user-agent. `user_id` is nullable: a failed login on an unknown email has
 /// no user. There is intentionally no FK to `users` so the row survives account deletion for
-/// forensics. Never publicly exposed; intended for a restricted role and ~90-day retention.
+/// forensics. Never publicly exposed: users see only their own rows (login history); intended for a
+/// restricted role and ~90-day retention.
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "auth_events")]
 pub struct Model {
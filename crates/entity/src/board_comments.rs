@@ -21,10 +21,24 @@ pub struct Model {
     pub content: String,
     #[sea_orm(not_null, default_value = "0")]
     pub reply_count: i32,
+    /// Total reactions across all kinds (denormalized from `board_reactions`).
+    #[sea_orm(not_null, default_value = "0")]
+    pub reaction_count: i32,
+    /// Per-kind reaction tally as `{"kind": n}`; kinds at zero are dropped.
+    #[sea_orm(column_type = "JsonBinary", not_null)]
+    pub reaction_counts: Json,
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
     pub created_at: DateTimeUtc,
     #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
     pub edited_at: Option<DateTimeUtc>,
+    /// Set while a moderator has hidden the row; non-moderators see a tombstone.
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub hidden_at: Option<DateTimeUtc>,
+    /// Actor id of the moderator who hid the row.
+    #[sea_orm(nullable)]
+    pub hidden_by: Option<Uuid>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub hidden_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -43,6 +57,13 @@ pub enum Relation {
         on_delete = "Restrict"
     )]
     Actor,
+    #[sea_orm(
+        belongs_to = "ActorsEntity",
+        from = "Column::HiddenBy",
+        to = "super::actors::Column::Id",
+        on_delete = "Restrict"
+    )]
+    HiddenBy,
     #[sea_orm(
         belongs_to = "Entity",
         from = "Column::ParentCommentId",
@@ -0,0 +1,80 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::actors::Entity as ActorsEntity;
+use super::board_comments::Entity as BoardCommentsEntity;
+use super::board_posts::Entity as BoardPostsEntity;
+
+/// Index row for one superseded body of a board post or comment. The body itself
+/// lives zstd-compressed in the revisions bucket under `storage_key`; this table
+/// only orders and attributes the snapshots.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "board_post_revisions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    /// The thread the revision belongs to (for comment revisions, the comment's post).
+    #[sea_orm(not_null)]
+    pub post_id: Uuid,
+    /// NULL = a revision of the post body, Some = a revision of this comment.
+    #[sea_orm(nullable)]
+    pub comment_id: Option<Uuid>,
+    /// Post title as it was before the edit; always NULL for comment revisions.
+    #[sea_orm(column_type = "Text", nullable)]
+    pub title: Option<String>,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub storage_key: String,
+    /// Uncompressed body length in bytes.
+    #[sea_orm(not_null)]
+    pub content_length: i32,
+    /// Actor whose edit (or restore) superseded this body.
+    #[sea_orm(not_null)]
+    pub actor_id: Uuid,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "BoardPostsEntity",
+        from = "Column::PostId",
+        to = "super::board_posts::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Post,
+    #[sea_orm(
+        belongs_to = "BoardCommentsEntity",
+        from = "Column::CommentId",
+        to = "super::board_comments::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Comment,
+    #[sea_orm(
+        belongs_to = "ActorsEntity",
+        from = "Column::ActorId",
+        to = "super::actors::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Actor,
+}
+
+impl Related<BoardPostsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl Related<BoardCommentsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comment.def()
+    }
+}
+
+impl Related<ActorsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Actor.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,52 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::board_posts::Entity as BoardPostsEntity;
+use super::users::Entity as UsersEntity;
+
+/// A user watching a board post: new comments on it are fanned out to every
+/// watcher's inbox by the worker. Authors watch their own posts from creation.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "board_post_watchers")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(not_null)]
+    pub post_id: Uuid,
+    #[sea_orm(not_null)]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "BoardPostsEntity",
+        from = "Column::PostId",
+        to = "super::board_posts::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Post,
+    #[sea_orm(
+        belongs_to = "UsersEntity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<BoardPostsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl Related<UsersEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -4,7 +4,7 @@ use uuid::Uuid;
 use super::actors::Entity as ActorsEntity;
 use super::boards::Entity as BoardsEntity;
 
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "board_posts")]
 pub struct Model {
     #[sea_orm(primary_key)]
@@ -28,10 +28,27 @@ pub struct Model {
     pub view_count: i32,
     #[sea_orm(not_null, default_value = "0")]
     pub comment_count: i32,
+    /// Total reactions across all kinds (denormalized from `board_reactions`).
+    #[sea_orm(not_null, default_value = "0")]
+    pub reaction_count: i32,
+    /// Per-kind reaction tally as `{"kind": n}`; kinds at zero are dropped.
+    #[sea_orm(column_type = "JsonBinary", not_null)]
+    pub reaction_counts: Json,
+    /// Time-decayed ranking score for the `hot` sort, refreshed by a worker cron.
+    #[sea_orm(not_null, default_value = "0")]
+    pub hot_score: f64,
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
     pub created_at: DateTimeUtc,
     #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
     pub edited_at: Option<DateTimeUtc>,
+    /// Set while a moderator has hidden the row; non-moderators see a tombstone.
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub hidden_at: Option<DateTimeUtc>,
+    /// Actor id of the moderator who hid the row.
+    #[sea_orm(nullable)]
+    pub hidden_by: Option<Uuid>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub hidden_reason: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -50,6 +67,13 @@ pub enum Relation {
         on_delete = "Restrict"
     )]
     Actor,
+    #[sea_orm(
+        belongs_to = "ActorsEntity",
+        from = "Column::HiddenBy",
+        to = "super::actors::Column::Id",
+        on_delete = "Restrict"
+    )]
+    HiddenBy,
 }
 
 impl Related<BoardsEntity> for Entity {
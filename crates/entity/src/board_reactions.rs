@@ -0,0 +1,73 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::actors::Entity as ActorsEntity;
+use super::board_comments::Entity as BoardCommentsEntity;
+use super::board_posts::Entity as BoardPostsEntity;
+
+/// One actor's reaction of one kind on a board post or comment. The per-kind
+/// totals are denormalized onto the target's `reaction_counts`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "board_reactions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(not_null)]
+    pub actor_id: Uuid,
+    /// The reacted post (for comment reactions, the comment's post).
+    #[sea_orm(not_null)]
+    pub post_id: Uuid,
+    /// NULL = a reaction on the post, Some = a reaction on this comment.
+    #[sea_orm(nullable)]
+    pub comment_id: Option<Uuid>,
+    /// One of the configured `BOARD_REACTION_KINDS`.
+    #[sea_orm(not_null)]
+    pub kind: String,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "ActorsEntity",
+        from = "Column::ActorId",
+        to = "super::actors::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Actor,
+    #[sea_orm(
+        belongs_to = "BoardPostsEntity",
+        from = "Column::PostId",
+        to = "super::board_posts::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Post,
+    #[sea_orm(
+        belongs_to = "BoardCommentsEntity",
+        from = "Column::CommentId",
+        to = "super::board_comments::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Comment,
+}
+
+impl Related<ActorsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Actor.def()
+    }
+}
+
+impl Related<BoardPostsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl Related<BoardCommentsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
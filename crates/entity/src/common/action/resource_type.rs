@@ -13,4 +13,13 @@ use utoipa::ToSchema;
 pub enum ActionResourceType {
     #[sea_orm(string_value = "user")]
     User,
+    /// Board (board management)
+    #[sea_orm(string_value = "board")]
+    Board,
+    /// Board post
+    #[sea_orm(string_value = "post")]
+    Post,
+    /// Board comment
+    #[sea_orm(string_value = "comment")]
+    Comment,
 }
@@ -3,11 +3,15 @@ mod actor_kind;
 pub mod moderation;
 pub mod notification;
 mod oauth_provider;
+pub mod report;
 mod role;
+pub mod webhook;
 
 pub use action::ActionResourceType;
 pub use actor_kind::ActorKind;
 pub use moderation::ModerationResourceType;
-pub use notification::{NotificationTargetKind, NotificationType};
+pub use notification::{NotificationDigestFrequency, NotificationTargetKind, NotificationType};
 pub use oauth_provider::OAuthProvider;
+pub use report::{ReportReason, ReportStatus, ReportTargetType};
 pub use role::Role;
+pub use webhook::WebhookDeliveryStatus;
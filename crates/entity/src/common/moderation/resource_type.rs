@@ -21,7 +21,10 @@ pub enum ModerationResourceType {
     /// Board (board management actions)
     #[sea_orm(string_value = "board")]
     Board,
-    /// Board post (pin/lock moderation)
+    /// Board post (pin/lock/hide moderation, revision restores)
     #[sea_orm(string_value = "board_post")]
     BoardPost,
+    /// Board comment (hide moderation, revision restores)
+    #[sea_orm(string_value = "board_comment")]
+    BoardComment,
 }
@@ -0,0 +1,24 @@
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// How often a user gets the unread-notification email digest
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "notification_digest_frequency"
+)]
+pub enum NotificationDigestFrequency {
+    /// No digest
+    #[sea_orm(string_value = "off")]
+    Off,
+    /// Every day at the digest hour
+    #[sea_orm(string_value = "daily")]
+    Daily,
+    /// Once a week, on the digest weekday
+    #[sea_orm(string_value = "weekly")]
+    Weekly,
+}
@@ -1,5 +1,7 @@
+mod digest_frequency;
 mod target_kind;
 mod r#type;
 
+pub use digest_frequency::NotificationDigestFrequency;
 pub use target_kind::NotificationTargetKind;
 pub use r#type::NotificationType;
@@ -23,4 +23,7 @@ pub enum OAuthProvider {
     /// Microsoft OAuth
     #[sea_orm(string_value = "microsoft")]
     Microsoft,
+    /// Self-hosted OpenID Connect IdP (Keycloak, Authentik, ...)
+    #[sea_orm(string_value = "sso")]
+    Sso,
 }
@@ -0,0 +1,26 @@
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Reason category picked by the reporter
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "report_reason")]
+pub enum ReportReason {
+    #[sea_orm(string_value = "spam")]
+    Spam,
+    #[sea_orm(string_value = "harassment")]
+    Harassment,
+    #[sea_orm(string_value = "hate_speech")]
+    HateSpeech,
+    #[sea_orm(string_value = "sexual_content")]
+    SexualContent,
+    #[sea_orm(string_value = "violence")]
+    Violence,
+    #[sea_orm(string_value = "misinformation")]
+    Misinformation,
+    /// Anything else; the description should say what
+    #[sea_orm(string_value = "other")]
+    Other,
+}
@@ -0,0 +1,20 @@
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Report lifecycle state
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "report_status")]
+pub enum ReportStatus {
+    /// Waiting in the moderator queue
+    #[sea_orm(string_value = "open")]
+    Open,
+    /// Acted on; linked to the moderation log entry of that action
+    #[sea_orm(string_value = "resolved")]
+    Resolved,
+    /// Closed without action
+    #[sea_orm(string_value = "dismissed")]
+    Dismissed,
+}
@@ -0,0 +1,20 @@
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What a user report points at
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "report_target_type")]
+pub enum ReportTargetType {
+    /// Board post
+    #[sea_orm(string_value = "board_post")]
+    BoardPost,
+    /// Board comment
+    #[sea_orm(string_value = "board_comment")]
+    BoardComment,
+    /// User profile
+    #[sea_orm(string_value = "user")]
+    User,
+}
@@ -0,0 +1,24 @@
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Outcome of a webhook delivery so far
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "webhook_delivery_status"
+)]
+pub enum WebhookDeliveryStatus {
+    /// Not attempted yet
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    /// The endpoint answered 2xx
+    #[sea_orm(string_value = "succeeded")]
+    Succeeded,
+    /// The last attempt failed; retries may still be scheduled
+    #[sea_orm(string_value = "failed")]
+    Failed,
+}
@@ -2,7 +2,10 @@ pub mod action_logs;
 pub mod actors;
 pub mod auth_events;
 pub mod board_comments;
+pub mod board_post_revisions;
+pub mod board_post_watchers;
 pub mod board_posts;
+pub mod board_reactions;
 pub mod boards;
 pub mod common;
 pub mod group_members;
@@ -14,7 +17,13 @@ pub mod notification_action_preferences;
 pub mod notification_deliveries;
 pub mod notification_events;
 pub mod notification_preferences;
+pub mod personal_access_tokens;
+pub mod push_subscriptions;
+pub mod reports;
 pub mod user_bans;
 pub mod user_oauth_connections;
+pub mod user_passkeys;
 pub mod user_roles;
 pub mod users;
+pub mod webhook_deliveries;
+pub mod webhook_endpoints;
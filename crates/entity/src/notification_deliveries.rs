@@ -13,6 +13,10 @@ pub struct Model {
     pub user_id: Uuid,
     #[sea_orm(not_null)]
     pub event_id: Uuid,
+    /// Unread deliveries sharing this key are shown as one grouped notification
+    /// (see `notification_repository::notification_group_key`).
+    #[sea_orm(column_type = "Text", not_null)]
+    pub group_key: String,
     #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
     pub is_read: bool,
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
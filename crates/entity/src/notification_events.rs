@@ -34,6 +34,8 @@ pub struct Model {
     pub additional_data: Option<Json>,
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
     pub created_at: DateTimeUtc,
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub retracted_at: Option<DateTimeUtc>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
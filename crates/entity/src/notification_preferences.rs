@@ -1,3 +1,4 @@
+use crate::common::NotificationDigestFrequency;
 use sea_orm::prelude::*;
 use uuid::Uuid;
 
@@ -14,6 +15,12 @@ pub struct Model {
     pub email_enabled: bool,
     #[sea_orm(column_type = "Boolean", not_null, default_value = "false")]
     pub push_enabled: bool,
+    #[sea_orm(not_null, unique)]
+    pub email_unsubscribe_token: Uuid,
+    #[sea_orm(not_null)]
+    pub digest_frequency: NotificationDigestFrequency,
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub last_digest_sent_at: Option<DateTimeUtc>,
     #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
     pub updated_at: DateTimeUtc,
 }
@@ -0,0 +1,42 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::users::Entity as UsersEntity;
+
+/// A browser's Web Push subscription (one per device). The worker encrypts each
+/// notification for `p256dh`/`auth` and posts it to `endpoint`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "push_subscriptions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(not_null)]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "Text", not_null, unique)]
+    pub endpoint: String,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub p256dh: String,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub auth: String,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "UsersEntity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<UsersEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,74 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::common::{ReportReason, ReportStatus, ReportTargetType};
+use super::moderation_logs::Entity as ModerationLogsEntity;
+use super::users::Entity as UsersEntity;
+
+/// A user report against a board post, comment or user profile. At most one
+/// report per reporter per target can be `open` at a time.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "reports")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    /// NULL once the reporter's account is deleted.
+    #[sea_orm(nullable)]
+    pub reporter_id: Option<Uuid>,
+    #[sea_orm(not_null)]
+    pub target_type: ReportTargetType,
+    /// Post, comment or user id depending on `target_type` (no FK).
+    #[sea_orm(not_null)]
+    pub target_id: Uuid,
+    #[sea_orm(not_null)]
+    pub reason: ReportReason,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub description: Option<String>,
+    #[sea_orm(not_null)]
+    pub status: ReportStatus,
+    /// Moderator (user id) who resolved or dismissed the report.
+    #[sea_orm(nullable)]
+    pub resolved_by: Option<Uuid>,
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub resolved_at: Option<DateTimeUtc>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub resolution_note: Option<String>,
+    /// The moderation log entry recording the action taken (or the dismissal).
+    #[sea_orm(nullable)]
+    pub moderation_log_id: Option<Uuid>,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "UsersEntity",
+        from = "Column::ReporterId",
+        to = "super::users::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Reporter,
+    #[sea_orm(
+        belongs_to = "UsersEntity",
+        from = "Column::ResolvedBy",
+        to = "super::users::Column::Id",
+        on_delete = "SetNull"
+    )]
+    ResolvedBy,
+    #[sea_orm(
+        belongs_to = "ModerationLogsEntity",
+        from = "Column::ModerationLogId",
+        to = "super::moderation_logs::Column::Id",
+        on_delete = "SetNull"
+    )]
+    ModerationLog,
+}
+
+impl Related<ModerationLogsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ModerationLog.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,44 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::users::Entity as UsersEntity;
+
+/// A WebAuthn credential (passkey) registered to a user. `passkey` holds the
+/// serialized credential the server verifies assertions against.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_passkeys")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(not_null)]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "Text", not_null, unique)]
+    pub credential_id: String,
+    #[sea_orm(column_type = "JsonBinary", not_null)]
+    pub passkey: Json,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub name: String,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub last_used_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "UsersEntity",
+        from = "Column::UserId",
+        to = "super::users::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<UsersEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
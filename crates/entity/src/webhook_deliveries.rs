@@ -0,0 +1,54 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::common::WebhookDeliveryStatus;
+use super::webhook_endpoints::Entity as WebhookEndpointsEntity;
+
+/// One event sent (or to be sent) to one webhook endpoint. Rows are written in
+/// the same transaction as the log they report, so this table is also the
+/// outbox the worker dispatches from.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(not_null)]
+    pub endpoint_id: Uuid,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub event: String,
+    #[sea_orm(not_null)]
+    pub log_id: Uuid,
+    #[sea_orm(column_type = "JsonBinary", not_null)]
+    pub payload: Json,
+    pub status: WebhookDeliveryStatus,
+    #[sea_orm(not_null)]
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub dispatched_at: Option<DateTimeUtc>,
+    #[sea_orm(column_type = "TimestampWithTimeZone", nullable)]
+    pub delivered_at: Option<DateTimeUtc>,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "WebhookEndpointsEntity",
+        from = "Column::EndpointId",
+        to = "super::webhook_endpoints::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Endpoint,
+}
+
+impl Related<WebhookEndpointsEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Endpoint.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,49 @@
+use sea_orm::prelude::*;
+use uuid::Uuid;
+
+use super::users::Entity as UsersEntity;
+use super::webhook_deliveries::Entity as WebhookDeliveriesEntity;
+
+/// An admin-registered webhook receiver. Every new action or moderation log whose
+/// code is in `events` gets one signed delivery to `url`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_endpoints")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub url: String,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub description: String,
+    pub events: Vec<String>,
+    #[sea_orm(not_null)]
+    pub is_active: bool,
+    #[sea_orm(column_type = "Text", not_null)]
+    pub secret: String,
+    pub created_by: Option<Uuid>,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub created_at: DateTimeUtc,
+    #[sea_orm(column_type = "TimestampWithTimeZone", not_null)]
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "UsersEntity",
+        from = "Column::CreatedBy",
+        to = "super::users::Column::Id",
+        on_delete = "SetNull"
+    )]
+    Creator,
+    #[sea_orm(has_many = "WebhookDeliveriesEntity")]
+    Deliveries,
+}
+
+impl Related<WebhookDeliveriesEntity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Deliveries.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,5 +1,6 @@
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
 use config::ServerConfig;
 use sea_orm::{DbErr, TransactionError};
@@ -60,6 +61,10 @@ impl From<TransactionError<DbErr>> for Errors {
 pub enum Errors {
     // Auth errors
     InvalidCredentials,
+    /// Too many failed logins for this account or client IP; retry after the given delay.
+    LoginThrottled {
+        retry_after_seconds: u64,
+    },
 
     // User errors
     UserInvalidPassword,
@@ -76,6 +81,7 @@ pub enum Errors {
     UserHandleAlreadyExists,
     UserEmailAlreadyExists,
     UserNotBanned,
+    UserNotLocked,
     UserAlreadyBanned,
     UserDoesNotHaveRole,
     UserAlreadyHasRole,
@@ -109,6 +115,20 @@ pub enum Errors {
     BoardPostLocked,
     BoardPinSetMismatch,
     BoardCommentNotFound,
+    BoardRevisionNotFound,
+    BoardRevisionMismatch,
+    BoardReactionKindInvalid,
+
+    // Report
+    ReportNotFound,
+    ReportAlreadyOpen,
+    ReportAlreadyClosed,
+    ReportCannotReportSelf,
+    ReportModerationLogMismatch,
+
+    // Webhook
+    WebhookEndpointNotFound,
+    WebhookDeliveryNotFound,
 
     // Post
     PostNotFound,
@@ -134,6 +154,10 @@ pub enum Errors {
     GoogleOneTapNonceInvalid,
     GoogleJwksFetchFailed,
     GoogleJwksParseFailed,
+    OidcProviderNotConfigured,
+    OidcInvalidIdToken,
+    OidcDiscoveryFailed,
+    OidcJwksFetchFailed,
 
     // Password errors
     PasswordRequiredForUpdate,
@@ -151,6 +175,7 @@ pub enum Errors {
     TokenInvalidEmailChange,
     TokenInvalidAccountDeletion,
     TokenInvalidDeviceVerify,
+    TokenInvalidMagicLink,
 
     // Email errors
     EmailAlreadyVerified,
@@ -171,6 +196,7 @@ pub enum Errors {
 
     // EventStream errors
     EventStreamPublishFailed,
+    EventStreamSubscribeFailed,
 
     // General errors
     BadRequestError(String),
@@ -198,6 +224,8 @@ pub enum Errors {
     MeiliSearchQueryFailed,
     /// A reindex of the same entity type is already in progress.
     ReindexAlreadyRunning,
+    /// No progress record for the reindex id (unknown or expired).
+    ReindexNotFound,
 
     // TOTP 2FA
     TotpAlreadyEnabled,
@@ -208,17 +236,35 @@ pub enum Errors {
     TotpBackupCodeExhausted,
     TotpSecretGenerationFailed,
     TotpQrGenerationFailed,
+
+    // Passkeys (WebAuthn)
+    PasskeyNotFound,
+    PasskeyAlreadyRegistered,
+    PasskeyLimitReached,
+    PasskeyCeremonyInvalid,
+    PasskeyRegistrationFailed,
+    PasskeyAuthenticationFailed,
+
+    // Personal access tokens
+    AccessTokenNotFound,
+    AccessTokenLimitReached,
+    AccessTokenExpired,
+    AccessTokenInsufficientScope,
 }
 
 domain_error_handlers!(
     user_handler,
     group_handler,
     board_handler,
+    report_handler,
+    webhook_handler,
     oauth_handler,
     session_handler,
     password_handler,
     token_handler,
     totp_handler,
+    passkey_handler,
+    access_token_handler,
     email_handler,
     file_handler,
     worker_handler,
@@ -253,7 +299,16 @@ impl IntoResponse for Errors {
             details,
         };
 
-        (status, Json(body)).into_response()
+        let mut response = (status, Json(body)).into_response();
+        if let Errors::LoginThrottled {
+            retry_after_seconds,
+        } = self
+        {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after_seconds));
+        }
+        response
     }
 }
 
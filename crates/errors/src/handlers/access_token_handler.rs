@@ -0,0 +1,33 @@
+use crate::errors::Errors;
+use crate::protocol::access_token::*;
+use axum::http::StatusCode;
+use tracing::debug;
+
+/// Personal access token domain error logging.
+pub fn log_error(error: &Errors) {
+    match error {
+        Errors::AccessTokenNotFound
+        | Errors::AccessTokenLimitReached
+        | Errors::AccessTokenExpired
+        | Errors::AccessTokenInsufficientScope => {
+            debug!(error = ?error, "Access token client error");
+        }
+        _ => {}
+    }
+}
+
+/// Returns: (StatusCode, error_code, details)
+pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<String>)> {
+    match error {
+        Errors::AccessTokenNotFound => Some((StatusCode::NOT_FOUND, ACCESS_TOKEN_NOT_FOUND, None)),
+        Errors::AccessTokenLimitReached => {
+            Some((StatusCode::CONFLICT, ACCESS_TOKEN_LIMIT_REACHED, None))
+        }
+        Errors::AccessTokenExpired => Some((StatusCode::UNAUTHORIZED, ACCESS_TOKEN_EXPIRED, None)),
+        Errors::AccessTokenInsufficientScope => {
+            Some((StatusCode::FORBIDDEN, ACCESS_TOKEN_INSUFFICIENT_SCOPE, None))
+        }
+
+        _ => None, // Return None for errors from other domains
+    }
+}
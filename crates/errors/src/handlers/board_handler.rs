@@ -5,7 +5,10 @@ use tracing::warn;
 
 pub fn log_error(error: &Errors) {
     match error {
-        Errors::BoardNotFound | Errors::BoardPostNotFound | Errors::BoardCommentNotFound => {
+        Errors::BoardNotFound
+        | Errors::BoardPostNotFound
+        | Errors::BoardCommentNotFound
+        | Errors::BoardRevisionNotFound => {
             warn!(error = ?error, "Board resource not found");
         }
         _ => {}
@@ -24,6 +27,17 @@ pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<
         Errors::BoardCommentNotFound => {
             Some((StatusCode::NOT_FOUND, BOARD_COMMENT_NOT_FOUND, None))
         }
+        Errors::BoardRevisionNotFound => {
+            Some((StatusCode::NOT_FOUND, BOARD_REVISION_NOT_FOUND, None))
+        }
+        // Diffing only makes sense between two bodies of the same post or comment.
+        Errors::BoardRevisionMismatch => {
+            Some((StatusCode::BAD_REQUEST, BOARD_REVISION_MISMATCH, None))
+        }
+        // Not one of the server's configured `BOARD_REACTION_KINDS`.
+        Errors::BoardReactionKindInvalid => {
+            Some((StatusCode::BAD_REQUEST, BOARD_REACTION_KIND_INVALID, None))
+        }
         _ => None,
     }
 }
@@ -5,7 +5,7 @@ use tracing::warn;
 
 /// EventStream domain error logging.
 pub fn log_error(error: &Errors) {
-    if let Errors::EventStreamPublishFailed = error {
+    if let Errors::EventStreamPublishFailed | Errors::EventStreamSubscribeFailed = error {
         warn!(error = ?error, "EventStream error");
     }
 }
@@ -18,6 +18,11 @@ pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<
             EVENTSTREAM_PUBLISH_FAILED,
             None,
         )),
+        Errors::EventStreamSubscribeFailed => Some((
+            StatusCode::SERVICE_UNAVAILABLE,
+            EVENTSTREAM_SUBSCRIBE_FAILED,
+            None,
+        )),
         _ => None,
     }
 }
@@ -21,6 +21,7 @@ pub fn map_response(err: &Errors) -> Option<(StatusCode, &'static str, Option<St
         Errors::ReindexAlreadyRunning => {
             Some((StatusCode::CONFLICT, REINDEX_ALREADY_RUNNING, None))
         }
+        Errors::ReindexNotFound => Some((StatusCode::NOT_FOUND, REINDEX_NOT_FOUND, None)),
         _ => None,
     }
 }
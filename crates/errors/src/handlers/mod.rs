@@ -1,3 +1,4 @@
+pub mod access_token_handler;
 pub mod board_handler;
 pub mod email_handler;
 pub mod eventstream_handler;
@@ -6,12 +7,15 @@ pub mod general_handler;
 pub mod group_handler;
 pub mod meilisearch_handler;
 pub mod oauth_handler;
+pub mod passkey_handler;
 pub mod password_handler;
 pub mod rate_limit_handler;
+pub mod report_handler;
 pub mod session_handler;
 pub mod system_handler;
 pub mod token_handler;
 pub mod totp_handler;
 pub mod turnstile_handler;
 pub mod user_handler;
+pub mod webhook_handler;
 pub mod worker_handler;
@@ -18,7 +18,9 @@ pub fn log_error(error: &Errors) {
         | Errors::OauthTokenExchangeFailed
         | Errors::OauthUserInfoFetchFailed
         | Errors::GoogleJwksFetchFailed
-        | Errors::GoogleJwksParseFailed => {
+        | Errors::GoogleJwksParseFailed
+        | Errors::OidcDiscoveryFailed
+        | Errors::OidcJwksFetchFailed => {
             warn!(error = ?error, "OAuth error");
         }
 
@@ -34,7 +36,9 @@ pub fn log_error(error: &Errors) {
         | Errors::OauthEmailNotVerified
         | Errors::GoogleInvalidIdToken
         | Errors::GoogleOneTapNonceInvalid
-        | Errors::GithubInvalidToken => {
+        | Errors::GithubInvalidToken
+        | Errors::OidcProviderNotConfigured
+        | Errors::OidcInvalidIdToken => {
             debug!(error = ?error, "Client error");
         }
 
@@ -105,6 +109,20 @@ pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<
             GOOGLE_JWKS_PARSE_FAILED,
             None,
         )),
+        Errors::OidcProviderNotConfigured => {
+            Some((StatusCode::NOT_FOUND, OIDC_PROVIDER_NOT_CONFIGURED, None))
+        }
+        Errors::OidcInvalidIdToken => Some((StatusCode::BAD_REQUEST, OIDC_INVALID_ID_TOKEN, None)),
+        Errors::OidcDiscoveryFailed => Some((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            OIDC_DISCOVERY_FAILED,
+            None,
+        )),
+        Errors::OidcJwksFetchFailed => Some((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            OIDC_JWKS_FETCH_FAILED,
+            None,
+        )),
 
         _ => None, // Return None for errors from other domains
     }
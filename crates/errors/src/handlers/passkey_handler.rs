@@ -0,0 +1,43 @@
+use crate::errors::Errors;
+use crate::protocol::passkey::*;
+use axum::http::StatusCode;
+use tracing::debug;
+
+/// Passkey domain error logging.
+pub fn log_error(error: &Errors) {
+    match error {
+        Errors::PasskeyNotFound
+        | Errors::PasskeyAlreadyRegistered
+        | Errors::PasskeyLimitReached
+        | Errors::PasskeyCeremonyInvalid
+        | Errors::PasskeyRegistrationFailed
+        | Errors::PasskeyAuthenticationFailed => {
+            debug!(error = ?error, "Passkey client error");
+        }
+        _ => {}
+    }
+}
+
+/// Returns: (StatusCode, error_code, details)
+pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<String>)> {
+    match error {
+        Errors::PasskeyNotFound => Some((StatusCode::NOT_FOUND, PASSKEY_NOT_FOUND, None)),
+        Errors::PasskeyAlreadyRegistered => {
+            Some((StatusCode::CONFLICT, PASSKEY_ALREADY_REGISTERED, None))
+        }
+        Errors::PasskeyLimitReached => Some((StatusCode::CONFLICT, PASSKEY_LIMIT_REACHED, None)),
+        Errors::PasskeyCeremonyInvalid => {
+            Some((StatusCode::BAD_REQUEST, PASSKEY_CEREMONY_INVALID, None))
+        }
+        Errors::PasskeyRegistrationFailed => {
+            Some((StatusCode::BAD_REQUEST, PASSKEY_REGISTRATION_FAILED, None))
+        }
+        Errors::PasskeyAuthenticationFailed => Some((
+            StatusCode::UNAUTHORIZED,
+            PASSKEY_AUTHENTICATION_FAILED,
+            None,
+        )),
+
+        _ => None, // Return None for errors from other domains
+    }
+}
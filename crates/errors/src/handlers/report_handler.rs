@@ -0,0 +1,39 @@
+use crate::errors::Errors;
+use crate::protocol::report::*;
+use axum::http::StatusCode;
+use tracing::debug;
+
+/// Report domain error logging.
+pub fn log_error(error: &Errors) {
+    match error {
+        Errors::ReportNotFound
+        | Errors::ReportAlreadyOpen
+        | Errors::ReportAlreadyClosed
+        | Errors::ReportCannotReportSelf
+        | Errors::ReportModerationLogMismatch => {
+            debug!(error = ?error, "Client error");
+        }
+        _ => {}
+    }
+}
+
+/// Returns: (StatusCode, error_code, details)
+pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<String>)> {
+    match error {
+        Errors::ReportNotFound => Some((StatusCode::NOT_FOUND, REPORT_NOT_FOUND, None)),
+        // The reporter already has an open report on this target.
+        Errors::ReportAlreadyOpen => Some((StatusCode::CONFLICT, REPORT_ALREADY_OPEN, None)),
+        // Another moderator resolved or dismissed it first.
+        Errors::ReportAlreadyClosed => Some((StatusCode::CONFLICT, REPORT_ALREADY_CLOSED, None)),
+        Errors::ReportCannotReportSelf => {
+            Some((StatusCode::BAD_REQUEST, REPORT_CANNOT_REPORT_SELF, None))
+        }
+        // The linked moderation log must exist and act on the reported target.
+        Errors::ReportModerationLogMismatch => Some((
+            StatusCode::BAD_REQUEST,
+            REPORT_MODERATION_LOG_MISMATCH,
+            None,
+        )),
+        _ => None,
+    }
+}
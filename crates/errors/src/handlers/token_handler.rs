@@ -14,7 +14,8 @@ pub fn log_error(error: &Errors) {
         | Errors::TokenExpiredReset
         | Errors::TokenInvalidEmailChange
         | Errors::TokenInvalidAccountDeletion
-        | Errors::TokenInvalidDeviceVerify => {
+        | Errors::TokenInvalidDeviceVerify
+        | Errors::TokenInvalidMagicLink => {
             debug!(error = ?error, "Client error");
         }
 
@@ -45,6 +46,9 @@ pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<
         Errors::TokenInvalidDeviceVerify => {
             Some((StatusCode::BAD_REQUEST, TOKEN_INVALID_DEVICE_VERIFY, None))
         }
+        Errors::TokenInvalidMagicLink => {
+            Some((StatusCode::BAD_REQUEST, TOKEN_INVALID_MAGIC_LINK, None))
+        }
 
         _ => None, // Return None for errors from other domains
     }
@@ -12,6 +12,11 @@ pub fn log_error(error: &Errors) {
             warn!(error = ?error, "Resource not found");
         }
 
+        // Repeated failed logins
+        Errors::LoginThrottled { .. } => {
+            warn!(error = ?error, "Login throttled");
+        }
+
         // Client-side/business validation errors
         Errors::InvalidCredentials
         | Errors::UserInvalidPassword
@@ -28,6 +33,7 @@ pub fn log_error(error: &Errors) {
         | Errors::UserNoRefreshToken
         | Errors::UserInvalidToken
         | Errors::UserNotBanned
+        | Errors::UserNotLocked
         | Errors::UserAlreadyBanned
         | Errors::UserDoesNotHaveRole
         | Errors::UserAlreadyHasRole
@@ -46,6 +52,9 @@ pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<
         Errors::InvalidCredentials => {
             Some((StatusCode::UNAUTHORIZED, AUTH_INVALID_CREDENTIALS, None))
         }
+        Errors::LoginThrottled { .. } => {
+            Some((StatusCode::TOO_MANY_REQUESTS, AUTH_LOGIN_THROTTLED, None))
+        }
         Errors::UserInvalidPassword => {
             Some((StatusCode::UNAUTHORIZED, USER_INVALID_PASSWORD, None))
         }
@@ -71,6 +80,7 @@ pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<
         Errors::UserNoRefreshToken => Some((StatusCode::UNAUTHORIZED, USER_NO_REFRESH_TOKEN, None)),
         Errors::UserInvalidToken => Some((StatusCode::UNAUTHORIZED, USER_INVALID_TOKEN, None)),
         Errors::UserNotBanned => Some((StatusCode::BAD_REQUEST, USER_NOT_BANNED, None)),
+        Errors::UserNotLocked => Some((StatusCode::BAD_REQUEST, USER_NOT_LOCKED, None)),
         Errors::UserAlreadyBanned => Some((StatusCode::CONFLICT, USER_ALREADY_BANNED, None)),
         Errors::UserDoesNotHaveRole => {
             Some((StatusCode::BAD_REQUEST, USER_DOES_NOT_HAVE_ROLE, None))
@@ -0,0 +1,27 @@
+use crate::errors::Errors;
+use crate::protocol::webhook::*;
+use axum::http::StatusCode;
+use tracing::debug;
+
+/// Webhook domain error logging.
+pub fn log_error(error: &Errors) {
+    match error {
+        Errors::WebhookEndpointNotFound | Errors::WebhookDeliveryNotFound => {
+            debug!(error = ?error, "Client error");
+        }
+        _ => {}
+    }
+}
+
+/// Returns: (StatusCode, error_code, details)
+pub fn map_response(error: &Errors) -> Option<(StatusCode, &'static str, Option<String>)> {
+    match error {
+        Errors::WebhookEndpointNotFound => {
+            Some((StatusCode::NOT_FOUND, WEBHOOK_ENDPOINT_NOT_FOUND, None))
+        }
+        Errors::WebhookDeliveryNotFound => {
+            Some((StatusCode::NOT_FOUND, WEBHOOK_DELIVERY_NOT_FOUND, None))
+        }
+        _ => None,
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod auth {
     pub const AUTH_INVALID_CREDENTIALS: &str = "auth:invalid_credentials";
+    pub const AUTH_LOGIN_THROTTLED: &str = "auth:login_throttled";
 }
 
 pub mod user {
@@ -20,6 +21,7 @@ pub mod user {
     pub const USER_NO_REFRESH_TOKEN: &str = "user:no_refresh_token";
     pub const USER_INVALID_TOKEN: &str = "user:invalid_token";
     pub const USER_NOT_BANNED: &str = "user:not_banned";
+    pub const USER_NOT_LOCKED: &str = "user:not_locked";
     pub const USER_ALREADY_BANNED: &str = "user:already_banned";
     pub const USER_DOES_NOT_HAVE_ROLE: &str = "user:does_not_have_role";
     pub const USER_ALREADY_HAS_ROLE: &str = "user:already_has_role";
@@ -47,6 +49,22 @@ pub mod board {
     pub const BOARD_POST_LOCKED: &str = "board:post_locked";
     pub const BOARD_PIN_SET_MISMATCH: &str = "board:pin_set_mismatch";
     pub const BOARD_COMMENT_NOT_FOUND: &str = "board:comment_not_found";
+    pub const BOARD_REVISION_NOT_FOUND: &str = "board:revision_not_found";
+    pub const BOARD_REVISION_MISMATCH: &str = "board:revision_mismatch";
+    pub const BOARD_REACTION_KIND_INVALID: &str = "board:reaction_kind_invalid";
+}
+
+pub mod report {
+    pub const REPORT_NOT_FOUND: &str = "report:not_found";
+    pub const REPORT_ALREADY_OPEN: &str = "report:already_open";
+    pub const REPORT_ALREADY_CLOSED: &str = "report:already_closed";
+    pub const REPORT_CANNOT_REPORT_SELF: &str = "report:cannot_report_self";
+    pub const REPORT_MODERATION_LOG_MISMATCH: &str = "report:moderation_log_mismatch";
+}
+
+pub mod webhook {
+    pub const WEBHOOK_ENDPOINT_NOT_FOUND: &str = "webhook:endpoint_not_found";
+    pub const WEBHOOK_DELIVERY_NOT_FOUND: &str = "webhook:delivery_not_found";
 }
 
 pub mod post {
@@ -76,6 +94,12 @@ pub mod oauth {
     pub const GOOGLE_JWKS_FETCH_FAILED: &str = "google:jwks_fetch_failed";
     pub const GOOGLE_JWKS_PARSE_FAILED: &str = "google:jwks_parse_failed";
     pub const GITHUB_INVALID_TOKEN: &str = "github:invalid_token";
+
+    // OpenID Connect
+    pub const OIDC_PROVIDER_NOT_CONFIGURED: &str = "oidc:provider_not_configured";
+    pub const OIDC_INVALID_ID_TOKEN: &str = "oidc:invalid_id_token";
+    pub const OIDC_DISCOVERY_FAILED: &str = "oidc:discovery_failed";
+    pub const OIDC_JWKS_FETCH_FAILED: &str = "oidc:jwks_fetch_failed";
 }
 
 pub mod general {
@@ -112,6 +136,7 @@ pub mod token {
     pub const TOKEN_INVALID_EMAIL_CHANGE: &str = "token:invalid_email_change";
     pub const TOKEN_INVALID_ACCOUNT_DELETION: &str = "token:invalid_account_deletion";
     pub const TOKEN_INVALID_DEVICE_VERIFY: &str = "token:invalid_device_verify";
+    pub const TOKEN_INVALID_MAGIC_LINK: &str = "token:invalid_magic_link";
 }
 
 pub mod email {
@@ -142,6 +167,7 @@ pub mod worker {
 
 pub mod eventstream {
     pub const EVENTSTREAM_PUBLISH_FAILED: &str = "eventstream:publish_failed";
+    pub const EVENTSTREAM_SUBSCRIBE_FAILED: &str = "eventstream:subscribe_failed";
 }
 
 pub mod rate_limit {
@@ -157,6 +183,7 @@ pub mod turnstile {
 pub mod meilisearch {
     pub const MEILISEARCH_QUERY_FAILED: &str = "meilisearch:query_failed";
     pub const REINDEX_ALREADY_RUNNING: &str = "reindex:already_running";
+    pub const REINDEX_NOT_FOUND: &str = "reindex:not_found";
 }
 
 pub mod totp {
@@ -169,3 +196,19 @@ pub mod totp {
     pub const TOTP_SECRET_GENERATION_FAILED: &str = "totp:secret_generation_failed";
     pub const TOTP_QR_GENERATION_FAILED: &str = "totp:qr_generation_failed";
 }
+
+pub mod passkey {
+    pub const PASSKEY_NOT_FOUND: &str = "passkey:not_found";
+    pub const PASSKEY_ALREADY_REGISTERED: &str = "passkey:already_registered";
+    pub const PASSKEY_LIMIT_REACHED: &str = "passkey:limit_reached";
+    pub const PASSKEY_CEREMONY_INVALID: &str = "passkey:ceremony_invalid";
+    pub const PASSKEY_REGISTRATION_FAILED: &str = "passkey:registration_failed";
+    pub const PASSKEY_AUTHENTICATION_FAILED: &str = "passkey:authentication_failed";
+}
+
+pub mod access_token {
+    pub const ACCESS_TOKEN_NOT_FOUND: &str = "access_token:not_found";
+    pub const ACCESS_TOKEN_LIMIT_REACHED: &str = "access_token:limit_reached";
+    pub const ACCESS_TOKEN_EXPIRED: &str = "access_token:expired";
+    pub const ACCESS_TOKEN_INSUFFICIENT_SCOPE: &str = "access_token:insufficient_scope";
+}
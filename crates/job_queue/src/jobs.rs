@@ -49,10 +49,31 @@ pub mod email {
             token: String,
             valid_minutes: u64,
         },
+        MagicLink {
+            username: String,
+            token: String,
+            valid_minutes: u64,
+        },
         SecurityAlert {
             username: String,
             event: String,
         },
+        /// A new in-app notification, mirrored by email. The token backs the
+        /// one-click unsubscribe link and `List-Unsubscribe` header.
+        Notification {
+            username: String,
+            summary: String,
+            unsubscribe_token: Uuid,
+        },
+        /// Periodic summary of unread notifications: up to a page of one-line
+        /// `items`, newest first, out of `total`. `period` is "daily" or "weekly".
+        NotificationDigest {
+            username: String,
+            period: String,
+            items: Vec<String>,
+            total: u64,
+            unsubscribe_token: Uuid,
+        },
         Custom {
             html_content: String,
         },
@@ -79,6 +100,44 @@ pub mod index {
             Delete,
         }
     }
+
+    /// Board post index jobs.
+    pub mod post {
+        use super::super::*;
+
+        /// Worker job payload for index post job.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct IndexPostJob {
+            pub post_id: Uuid,
+            pub action: PostIndexAction,
+        }
+
+        /// Enum describing post index action.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub enum PostIndexAction {
+            Index,
+            Delete,
+        }
+    }
+
+    /// Board comment index jobs.
+    pub mod comment {
+        use super::super::*;
+
+        /// Worker job payload for index comment job.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub struct IndexCommentJob {
+            pub comment_id: Uuid,
+            pub action: CommentIndexAction,
+        }
+
+        /// Enum describing comment index action.
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        pub enum CommentIndexAction {
+            Index,
+            Delete,
+        }
+    }
 }
 
 /// OAuth profile image fetch jobs.
@@ -93,6 +152,65 @@ pub mod oauth {
     }
 }
 
+/// Notification fan-out and email jobs.
+pub mod notification {
+    use super::*;
+    use serde_json::Value;
+    use std::net::IpAddr;
+
+    /// Worker job payload fanning a new board comment out to the post's watchers.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct BoardCommentFanOutJob {
+        pub board_id: Uuid,
+        pub post_id: Uuid,
+        pub comment_id: Uuid,
+        /// The commenter's actor, recorded on the notification event.
+        pub actor_id: Uuid,
+        pub actor_ip: Option<IpAddr>,
+        /// Users the server already notified directly (the commenter, mentioned
+        /// users); skipped even if they watch the post.
+        pub skip_user_ids: Vec<Uuid>,
+        /// Stored as the event's `additional_data` (board name/slug, post title).
+        pub additional_data: Value,
+    }
+
+    /// Worker job payload emailing one notification event to its recipients.
+    /// The worker keeps only those with `email_enabled` and throttles each.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct NotificationEmailJob {
+        pub event_id: Uuid,
+        pub user_ids: Vec<Uuid>,
+    }
+
+    /// Worker job payload delivering a system announcement to every active user.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct SystemAnnouncementFanOutJob {
+        pub event_id: Uuid,
+        /// Deliver even to users who turned `system_announcement` off.
+        pub mandatory: bool,
+    }
+
+    /// Worker job payload pushing one notification event to its recipients'
+    /// browsers. The worker keeps only those with `push_enabled`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct NotificationPushJob {
+        pub event_id: Uuid,
+        pub user_ids: Vec<Uuid>,
+    }
+}
+
+/// Outbound webhook jobs.
+pub mod webhook {
+    use super::*;
+
+    /// Worker job payload sending one logged webhook delivery. The payload, target
+    /// and signing secret are read from the row, so the message stays small.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct WebhookDeliveryJob {
+        pub delivery_id: Uuid,
+    }
+}
+
 /// Full-reindex jobs plus their pure constructors.
 pub mod reindex {
     use super::*;
@@ -111,6 +229,59 @@ pub mod reindex {
         pub reindex_id: Uuid,
         /// Current batch number (for logging)
         pub batch_number: u32,
+        /// Items indexed by the batches before this one
+        #[serde(default)]
+        pub processed_count: u64,
+        /// Item count taken when the first batch ran (None until then)
+        #[serde(default)]
+        pub total_count: Option<u64>,
+    }
+
+    /// Which search index a reindex run rebuilds.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum ReindexTarget {
+        Users,
+        Posts,
+        Comments,
+    }
+
+    /// State of a reindex run as last recorded by the worker.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub enum ReindexStatus {
+        /// Queued or between batches.
+        Running,
+        /// The rebuilt index was swapped in.
+        Completed,
+        /// A batch exhausted its retries; the run stopped and the live index
+        /// was left untouched.
+        Failed,
+    }
+
+    /// Progress of one reindex run, kept in the cache Redis under
+    /// [`reindex_progress_key`]. The server writes the initial record when it
+    /// enqueues the first batch; the worker overwrites it after every batch.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReindexProgress {
+        pub reindex_id: Uuid,
+        pub target: ReindexTarget,
+        pub status: ReindexStatus,
+        /// Last batch that ran (0 before the first one).
+        pub batch_number: u32,
+        pub processed_count: u64,
+        pub total_count: Option<u64>,
+        pub error: Option<String>,
+        /// Unix timestamp (seconds).
+        pub started_at: i64,
+        /// Unix timestamp (seconds).
+        pub updated_at: i64,
+    }
+
+    /// How long a progress record outlives its last update (7 days).
+    pub const REINDEX_PROGRESS_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+    /// Redis key holding a reindex run's [`ReindexProgress`].
+    pub fn reindex_progress_key(reindex_id: Uuid) -> String {
+        format!("reindex:progress:{reindex_id}")
     }
 
     /// Job to reindex all users in batches
@@ -128,6 +299,53 @@ pub mod reindex {
                 batch_size: batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
                 reindex_id,
                 batch_number: 1,
+                processed_count: 0,
+                total_count: None,
+            },
+        }
+    }
+
+    /// Job to reindex all board posts in batches
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReindexPostsJob {
+        #[serde(flatten)]
+        pub base: ReindexJobBase,
+    }
+
+    /// Create a new [`ReindexPostsJob`] that starts from the beginning.
+    pub fn create_reindex_posts_job(reindex_id: Uuid, batch_size: Option<u32>) -> ReindexPostsJob {
+        ReindexPostsJob {
+            base: ReindexJobBase {
+                after_id: None,
+                batch_size: batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
+                reindex_id,
+                batch_number: 1,
+                processed_count: 0,
+                total_count: None,
+            },
+        }
+    }
+
+    /// Job to reindex all board comments in batches
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ReindexCommentsJob {
+        #[serde(flatten)]
+        pub base: ReindexJobBase,
+    }
+
+    /// Create a new [`ReindexCommentsJob`] that starts from the beginning.
+    pub fn create_reindex_comments_job(
+        reindex_id: Uuid,
+        batch_size: Option<u32>,
+    ) -> ReindexCommentsJob {
+        ReindexCommentsJob {
+            base: ReindexJobBase {
+                after_id: None,
+                batch_size: batch_size.unwrap_or(DEFAULT_BATCH_SIZE),
+                reindex_id,
+                batch_number: 1,
+                processed_count: 0,
+                total_count: None,
             },
         }
     }
@@ -135,4 +353,6 @@ pub mod reindex {
 
 // Aliases mirroring `worker::jobs`, so server-side imports read the same as the
 // worker's own module names.
+pub use index::comment as comment_index;
+pub use index::post as post_index;
 pub use index::user as user_index;
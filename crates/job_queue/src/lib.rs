@@ -13,7 +13,9 @@
 //! - [`subjects`] holds the JetStream stream names, publish subjects, durable
 //!   consumer names and the dead-letter routing helpers.
 //! - [`streams`] creates the streams (`get_or_create`, safe from both binaries).
+//! - [`realtime`] publishes per-user realtime signals (core NATS, not JetStream).
 
 pub mod jobs;
+pub mod realtime;
 pub mod streams;
 pub mod subjects;
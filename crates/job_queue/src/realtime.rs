@@ -0,0 +1,46 @@
+//! Per-user realtime signals over core NATS.
+//!
+//! Unlike the job streams these are not persisted: each is a hint to whichever
+//! server replica holds that user's notification SSE connection, published on
+//! the user's own subject ([`realtime_user_subject`]) so a replica only receives
+//! traffic for the users it is serving. The data itself stays in Postgres — a
+//! missed signal costs a live update, never a notification.
+//!
+//! [`realtime_user_subject`]: crate::subjects::realtime_user_subject
+
+use crate::subjects::realtime_user_subject;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What changed for the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UserRealtimeEvent {
+    /// A `notification_deliveries` row was created for the user from this event.
+    NotificationCreated { event_id: Uuid },
+    /// Read state or membership of the user's inbox changed (read, deleted).
+    NotificationsChanged,
+}
+
+/// Publish `event` to `user_id`'s realtime subject, best-effort: failures are
+/// logged and dropped, as the change being signalled is already committed.
+pub async fn publish_user_realtime_event(
+    client: &async_nats::Client,
+    user_id: Uuid,
+    event: &UserRealtimeEvent,
+) {
+    let payload = match serde_json::to_vec(event) {
+        Ok(payload) => payload,
+        Err(e) => {
+            tracing::warn!(user_id = %user_id, error = %e, "Failed to serialize user realtime event");
+            return;
+        }
+    };
+
+    if let Err(e) = client
+        .publish(realtime_user_subject(user_id), payload.into())
+        .await
+    {
+        tracing::warn!(user_id = %user_id, error = %e, "Failed to publish user realtime event");
+    }
+}
@@ -4,6 +4,7 @@
 //! them. Stream *creation* (which needs the NATS client) lives in [`crate::streams`].
 
 use std::time::Duration;
+use uuid::Uuid;
 
 // Stream names
 /// JetStream stream name for email jobs.
@@ -12,8 +13,26 @@ pub const EMAIL_STREAM: &str = "axumkit_jobs_email";
 pub const INDEX_USER_STREAM: &str = "axumkit_jobs_index_user";
 /// JetStream stream name for reindex users jobs.
 pub const REINDEX_USERS_STREAM: &str = "axumkit_jobs_reindex_users";
+/// JetStream stream name for index post jobs.
+pub const INDEX_POST_STREAM: &str = "axumkit_jobs_index_post";
+/// JetStream stream name for reindex posts jobs.
+pub const REINDEX_POSTS_STREAM: &str = "axumkit_jobs_reindex_posts";
+/// JetStream stream name for index comment jobs.
+pub const INDEX_COMMENT_STREAM: &str = "axumkit_jobs_index_comment";
+/// JetStream stream name for reindex comments jobs.
+pub const REINDEX_COMMENTS_STREAM: &str = "axumkit_jobs_reindex_comments";
 /// JetStream stream name for OAuth profile image jobs.
 pub const OAUTH_PROFILE_IMAGE_STREAM: &str = "axumkit_jobs_oauth_profile_image";
+/// JetStream stream name for notification fan-out jobs.
+pub const NOTIFICATION_FANOUT_STREAM: &str = "axumkit_jobs_notification_fanout";
+/// JetStream stream name for notification email jobs.
+pub const NOTIFICATION_EMAIL_STREAM: &str = "axumkit_jobs_notification_email";
+/// JetStream stream name for system announcement fan-out jobs.
+pub const NOTIFICATION_ANNOUNCEMENT_STREAM: &str = "axumkit_jobs_notification_announcement";
+/// JetStream stream name for notification Web Push jobs.
+pub const NOTIFICATION_PUSH_STREAM: &str = "axumkit_jobs_notification_push";
+/// JetStream stream name for webhook delivery jobs.
+pub const WEBHOOK_DELIVERY_STREAM: &str = "axumkit_jobs_webhook_delivery";
 
 /// Dead-letter stream: messages that fail permanently (bad payload or max deliveries)
 /// are republished here for inspection/replay instead of being dropped.
@@ -35,13 +54,40 @@ pub const EMAIL_SUBJECT: &str = "axumkit.jobs.email";
 pub const INDEX_USER_SUBJECT: &str = "axumkit.jobs.index.user";
 /// NATS subject used to publish reindex users jobs.
 pub const REINDEX_USERS_SUBJECT: &str = "axumkit.jobs.reindex.users";
+/// NATS subject used to publish index post jobs.
+pub const INDEX_POST_SUBJECT: &str = "axumkit.jobs.index.post";
+/// NATS subject used to publish reindex posts jobs.
+pub const REINDEX_POSTS_SUBJECT: &str = "axumkit.jobs.reindex.posts";
+/// NATS subject used to publish index comment jobs.
+pub const INDEX_COMMENT_SUBJECT: &str = "axumkit.jobs.index.comment";
+/// NATS subject used to publish reindex comments jobs.
+pub const REINDEX_COMMENTS_SUBJECT: &str = "axumkit.jobs.reindex.comments";
 /// NATS subject used to publish OAuth profile image jobs.
 pub const OAUTH_PROFILE_IMAGE_SUBJECT: &str = "axumkit.jobs.oauth.profile_image";
+/// NATS subject used to publish notification fan-out jobs.
+pub const NOTIFICATION_FANOUT_SUBJECT: &str = "axumkit.jobs.notification.fanout";
+/// NATS subject used to publish notification email jobs.
+pub const NOTIFICATION_EMAIL_SUBJECT: &str = "axumkit.jobs.notification.email";
+/// NATS subject used to publish system announcement fan-out jobs.
+pub const NOTIFICATION_ANNOUNCEMENT_SUBJECT: &str = "axumkit.jobs.notification.announcement";
+/// NATS subject used to publish notification Web Push jobs.
+pub const NOTIFICATION_PUSH_SUBJECT: &str = "axumkit.jobs.notification.push";
+/// NATS subject used to publish webhook delivery jobs.
+pub const WEBHOOK_DELIVERY_SUBJECT: &str = "axumkit.jobs.webhook.delivery";
 
 /// Core NATS (non-JetStream) subject for realtime SSE fan-out events. Published
 /// by the API server's eventstream publisher and consumed by its subscriber;
 /// lives here so every NATS subject has one home.
 pub const REALTIME_EVENTS_SUBJECT: &str = "axumkit.realtime.events";
+/// Subject prefix for per-user realtime signals: `axumkit.realtime.users.{user_id}`.
+/// Core NATS like [`REALTIME_EVENTS_SUBJECT`]; each server replica subscribes only
+/// to the users holding a notification stream on it.
+pub const REALTIME_USER_SUBJECT_PREFIX: &str = "axumkit.realtime.users.";
+
+/// Build the realtime subject for one user.
+pub fn realtime_user_subject(user_id: Uuid) -> String {
+    format!("{REALTIME_USER_SUBJECT_PREFIX}{user_id}")
+}
 
 // Consumer names
 /// Durable consumer name for email jobs.
@@ -50,8 +96,26 @@ pub const EMAIL_CONSUMER: &str = "email-consumer";
 pub const INDEX_USER_CONSUMER: &str = "user-index-consumer";
 /// Durable consumer name for reindex users jobs.
 pub const REINDEX_USERS_CONSUMER: &str = "reindex-users-consumer";
+/// Durable consumer name for index post jobs.
+pub const INDEX_POST_CONSUMER: &str = "post-index-consumer";
+/// Durable consumer name for reindex posts jobs.
+pub const REINDEX_POSTS_CONSUMER: &str = "reindex-posts-consumer";
+/// Durable consumer name for index comment jobs.
+pub const INDEX_COMMENT_CONSUMER: &str = "comment-index-consumer";
+/// Durable consumer name for reindex comments jobs.
+pub const REINDEX_COMMENTS_CONSUMER: &str = "reindex-comments-consumer";
 /// Durable consumer name for OAuth profile image jobs.
 pub const OAUTH_PROFILE_IMAGE_CONSUMER: &str = "oauth-profile-image-consumer";
+/// Durable consumer name for notification fan-out jobs.
+pub const NOTIFICATION_FANOUT_CONSUMER: &str = "notification-fanout-consumer";
+/// Durable consumer name for notification email jobs.
+pub const NOTIFICATION_EMAIL_CONSUMER: &str = "notification-email-consumer";
+/// Durable consumer name for system announcement fan-out jobs.
+pub const NOTIFICATION_ANNOUNCEMENT_CONSUMER: &str = "notification-announcement-consumer";
+/// Durable consumer name for notification Web Push jobs.
+pub const NOTIFICATION_PUSH_CONSUMER: &str = "notification-push-consumer";
+/// Durable consumer name for webhook delivery jobs.
+pub const WEBHOOK_DELIVERY_CONSUMER: &str = "webhook-delivery-consumer";
 
 /// Stream and subject pairs, one per work-queue job stream. The worker uses this
 /// to create every stream on startup; keeping it beside the names means a new job
@@ -60,5 +124,17 @@ pub const JOB_STREAMS: &[(&str, &str)] = &[
     (EMAIL_STREAM, EMAIL_SUBJECT),
     (INDEX_USER_STREAM, INDEX_USER_SUBJECT),
     (REINDEX_USERS_STREAM, REINDEX_USERS_SUBJECT),
+    (INDEX_POST_STREAM, INDEX_POST_SUBJECT),
+    (REINDEX_POSTS_STREAM, REINDEX_POSTS_SUBJECT),
+    (INDEX_COMMENT_STREAM, INDEX_COMMENT_SUBJECT),
+    (REINDEX_COMMENTS_STREAM, REINDEX_COMMENTS_SUBJECT),
     (OAUTH_PROFILE_IMAGE_STREAM, OAUTH_PROFILE_IMAGE_SUBJECT),
+    (NOTIFICATION_FANOUT_STREAM, NOTIFICATION_FANOUT_SUBJECT),
+    (NOTIFICATION_EMAIL_STREAM, NOTIFICATION_EMAIL_SUBJECT),
+    (
+        NOTIFICATION_ANNOUNCEMENT_STREAM,
+        NOTIFICATION_ANNOUNCEMENT_SUBJECT,
+    ),
+    (NOTIFICATION_PUSH_STREAM, NOTIFICATION_PUSH_SUBJECT),
+    (WEBHOOK_DELIVERY_STREAM, WEBHOOK_DELIVERY_SUBJECT),
 ];
@@ -7,4 +7,10 @@ pub enum ActionResourceType {
     Table,
     #[sea_orm(iden = "user")]
     User,
+    #[sea_orm(iden = "board")]
+    Board,
+    #[sea_orm(iden = "post")]
+    Post,
+    #[sea_orm(iden = "comment")]
+    Comment,
 }
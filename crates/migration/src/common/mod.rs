@@ -4,7 +4,9 @@ mod actor_kind;
 pub mod moderation;
 pub mod notification;
 mod oauth_provider;
+pub mod report;
 mod role;
+pub mod webhook;
 
 pub use actor_kind::ActorKind;
 pub use oauth_provider::OAuthProvider;
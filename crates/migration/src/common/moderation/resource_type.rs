@@ -15,4 +15,6 @@ pub enum ModerationResourceType {
     Board,
     #[sea_orm(iden = "board_post")]
     BoardPost,
+    #[sea_orm(iden = "board_comment")]
+    BoardComment,
 }
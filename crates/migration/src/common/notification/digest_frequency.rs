@@ -0,0 +1,14 @@
+use sea_orm_migration::prelude::*;
+use strum::EnumIter;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum NotificationDigestFrequency {
+    #[sea_orm(iden = "notification_digest_frequency")]
+    Table,
+    #[sea_orm(iden = "off")]
+    Off,
+    #[sea_orm(iden = "daily")]
+    Daily,
+    #[sea_orm(iden = "weekly")]
+    Weekly,
+}
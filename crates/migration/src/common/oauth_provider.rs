@@ -15,4 +15,6 @@ pub enum OAuthProvider {
     X,
     #[sea_orm(string_value = "microsoft")]
     Microsoft,
+    #[sea_orm(iden = "sso")]
+    Sso,
 }
@@ -0,0 +1,7 @@
+mod reason;
+mod status;
+mod target_type;
+
+pub use reason::ReportReason;
+pub use status::ReportStatus;
+pub use target_type::ReportTargetType;
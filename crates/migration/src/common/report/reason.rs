@@ -0,0 +1,22 @@
+use sea_orm_migration::prelude::*;
+use strum::EnumIter;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum ReportReason {
+    #[sea_orm(iden = "report_reason")]
+    Table,
+    #[sea_orm(iden = "spam")]
+    Spam,
+    #[sea_orm(iden = "harassment")]
+    Harassment,
+    #[sea_orm(iden = "hate_speech")]
+    HateSpeech,
+    #[sea_orm(iden = "sexual_content")]
+    SexualContent,
+    #[sea_orm(iden = "violence")]
+    Violence,
+    #[sea_orm(iden = "misinformation")]
+    Misinformation,
+    #[sea_orm(iden = "other")]
+    Other,
+}
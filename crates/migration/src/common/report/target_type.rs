@@ -0,0 +1,14 @@
+use sea_orm_migration::prelude::*;
+use strum::EnumIter;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum ReportTargetType {
+    #[sea_orm(iden = "report_target_type")]
+    Table,
+    #[sea_orm(iden = "board_post")]
+    BoardPost,
+    #[sea_orm(iden = "board_comment")]
+    BoardComment,
+    #[sea_orm(iden = "user")]
+    User,
+}
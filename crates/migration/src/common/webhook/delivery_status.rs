@@ -0,0 +1,14 @@
+use sea_orm_migration::prelude::*;
+use strum::EnumIter;
+
+#[derive(DeriveIden, EnumIter)]
+pub enum WebhookDeliveryStatus {
+    #[sea_orm(iden = "webhook_delivery_status")]
+    Table,
+    #[sea_orm(iden = "pending")]
+    Pending,
+    #[sea_orm(iden = "succeeded")]
+    Succeeded,
+    #[sea_orm(iden = "failed")]
+    Failed,
+}
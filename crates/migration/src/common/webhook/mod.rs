@@ -0,0 +1,3 @@
+mod delivery_status;
+
+pub use delivery_status::WebhookDeliveryStatus;
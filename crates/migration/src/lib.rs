@@ -19,6 +19,7 @@ mod m20260328_141048_create_board_comments;
 mod m20260328_141049_notification_target_kind_enum;
 mod m20260328_141050_notification_events;
 mod m20260328_141052_notification_deliveries;
+mod m20260328_141055_notification_digest_frequency_enum;
 mod m20260328_141056_notification_preferences;
 mod m20260328_141058_notification_action_preferences;
 mod m20260405_073559_create_moderation_logs;
@@ -27,6 +28,19 @@ mod m20260705_000100_create_known_devices;
 pub(crate) mod m20260710_000101_groups;
 mod m20260710_000102_group_members;
 mod m20260710_000103_group_permissions;
+mod m20261018_000000_create_board_post_revisions;
+mod m20261019_000000_report_target_type_enum;
+mod m20261019_000001_report_reason_enum;
+mod m20261019_000002_report_status_enum;
+mod m20261019_000003_create_reports;
+mod m20261020_000000_create_board_reactions;
+mod m20261021_000000_create_board_post_watchers;
+mod m20261022_000000_create_push_subscriptions;
+mod m20261023_000000_webhook_delivery_status_enum;
+mod m20261023_000001_create_webhook_endpoints;
+mod m20261023_000002_create_webhook_deliveries;
+mod m20261024_000000_create_user_passkeys;
+mod m20261025_000000_create_personal_access_tokens;
 
 pub struct Migrator;
 
@@ -52,6 +66,7 @@ impl MigratorTrait for Migrator {
             Box::new(m20260328_141049_notification_target_kind_enum::Migration),
             Box::new(m20260328_141050_notification_events::Migration),
             Box::new(m20260328_141052_notification_deliveries::Migration),
+            Box::new(m20260328_141055_notification_digest_frequency_enum::Migration),
             Box::new(m20260328_141056_notification_preferences::Migration),
             Box::new(m20260328_141058_notification_action_preferences::Migration),
             Box::new(m20260405_073559_create_moderation_logs::Migration),
@@ -60,6 +75,19 @@ impl MigratorTrait for Migrator {
             Box::new(m20260710_000101_groups::Migration),
             Box::new(m20260710_000102_group_members::Migration),
             Box::new(m20260710_000103_group_permissions::Migration),
+            Box::new(m20261018_000000_create_board_post_revisions::Migration),
+            Box::new(m20261019_000000_report_target_type_enum::Migration),
+            Box::new(m20261019_000001_report_reason_enum::Migration),
+            Box::new(m20261019_000002_report_status_enum::Migration),
+            Box::new(m20261019_000003_create_reports::Migration),
+            Box::new(m20261020_000000_create_board_reactions::Migration),
+            Box::new(m20261021_000000_create_board_post_watchers::Migration),
+            Box::new(m20261022_000000_create_push_subscriptions::Migration),
+            Box::new(m20261023_000000_webhook_delivery_status_enum::Migration),
+            Box::new(m20261023_000001_create_webhook_endpoints::Migration),
+            Box::new(m20261023_000002_create_webhook_deliveries::Migration),
+            Box::new(m20261024_000000_create_user_passkeys::Migration),
+            Box::new(m20261025_000000_create_personal_access_tokens::Migration),
         ]
     }
 }
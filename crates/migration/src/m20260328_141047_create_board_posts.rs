@@ -48,6 +48,28 @@ impl MigrationTrait for Migration {
                             .not_null()
                             .default(0),
                     )
+                    // Denormalized from board_reactions: the total across kinds, and
+                    // the per-kind tally as `{"kind": n}`.
+                    .col(
+                        ColumnDef::new(BoardPosts::ReactionCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(BoardPosts::ReactionCounts)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'{}'::jsonb")),
+                    )
+                    // Time-decayed ranking for `sort=hot`, recomputed by the worker's
+                    // hot-score cron; 0 once a post ages out of the scoring horizon.
+                    .col(
+                        ColumnDef::new(BoardPosts::HotScore)
+                            .double()
+                            .not_null()
+                            .default(0.0),
+                    )
                     .col(
                         ColumnDef::new(BoardPosts::CreatedAt)
                             .timestamp_with_time_zone()
@@ -59,6 +81,15 @@ impl MigrationTrait for Migration {
                             .timestamp_with_time_zone()
                             .null(),
                     )
+                    // Moderator soft-hide: a hidden row stays in place (so replies and
+                    // counters survive) and is served as a tombstone to non-moderators.
+                    .col(
+                        ColumnDef::new(BoardPosts::HiddenAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(BoardPosts::HiddenBy).uuid().null())
+                    .col(ColumnDef::new(BoardPosts::HiddenReason).text().null())
                     .foreign_key(
                         ForeignKey::create()
                             .name("fk_board_posts_board_id")
@@ -73,6 +104,13 @@ impl MigrationTrait for Migration {
                             .to(Actors::Table, Actors::Id)
                             .on_delete(ForeignKeyAction::Restrict),
                     )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_posts_hidden_by")
+                            .from(BoardPosts::Table, BoardPosts::HiddenBy)
+                            .to(Actors::Table, Actors::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
                     .to_owned(),
             )
             .await?;
@@ -94,6 +132,34 @@ impl MigrationTrait for Migration {
             )
             .await?;
 
+        // `sort=hot` pages the unpinned posts by score; the other sorts are cheap
+        // enough as a filtered sort over one board's posts.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_posts_board_hot_score")
+                    .table(BoardPosts::Table)
+                    .col(BoardPosts::BoardId)
+                    .col((BoardPosts::HotScore, IndexOrder::Desc))
+                    .col((BoardPosts::CreatedAt, IndexOrder::Desc))
+                    .col((BoardPosts::Id, IndexOrder::Desc))
+                    .cond_where(Expr::col(BoardPosts::PinnedPosition).is_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Postgres does not auto-index FK columns; index `hidden_by` so the
+        // restrict check on actor deletes stays cheap.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_posts_hidden_by")
+                    .table(BoardPosts::Table)
+                    .col(BoardPosts::HiddenBy)
+                    .to_owned(),
+            )
+            .await?;
+
         manager
             .create_index(
                 Index::create()
@@ -124,6 +190,12 @@ pub enum BoardPosts {
     IsLocked,
     ViewCount,
     CommentCount,
+    ReactionCount,
+    ReactionCounts,
+    HotScore,
     CreatedAt,
     EditedAt,
+    HiddenAt,
+    HiddenBy,
+    HiddenReason,
 }
@@ -32,6 +32,19 @@ impl MigrationTrait for Migration {
                             .not_null()
                             .default(0),
                     )
+                    // Denormalized from board_reactions, as on board_posts.
+                    .col(
+                        ColumnDef::new(BoardComments::ReactionCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(BoardComments::ReactionCounts)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'{}'::jsonb")),
+                    )
                     .col(
                         ColumnDef::new(BoardComments::CreatedAt)
                             .timestamp_with_time_zone()
@@ -43,6 +56,15 @@ impl MigrationTrait for Migration {
                             .timestamp_with_time_zone()
                             .null(),
                     )
+                    // Moderator soft-hide: a hidden row stays in place (so replies and
+                    // counters survive) and is served as a tombstone to non-moderators.
+                    .col(
+                        ColumnDef::new(BoardComments::HiddenAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(BoardComments::HiddenBy).uuid().null())
+                    .col(ColumnDef::new(BoardComments::HiddenReason).text().null())
                     .foreign_key(
                         ForeignKey::create()
                             .name("fk_board_comments_post_id")
@@ -57,6 +79,13 @@ impl MigrationTrait for Migration {
                             .to(Actors::Table, Actors::Id)
                             .on_delete(ForeignKeyAction::Restrict),
                     )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_comments_hidden_by")
+                            .from(BoardComments::Table, BoardComments::HiddenBy)
+                            .to(Actors::Table, Actors::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
                     .foreign_key(
                         ForeignKey::create()
                             .name("fk_board_comments_parent_comment_id")
@@ -83,6 +112,18 @@ impl MigrationTrait for Migration {
             )
             .await?;
 
+        // Postgres does not auto-index FK columns; index `hidden_by` so the
+        // restrict check on actor deletes stays cheap.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_comments_hidden_by")
+                    .table(BoardComments::Table)
+                    .col(BoardComments::HiddenBy)
+                    .to_owned(),
+            )
+            .await?;
+
         manager
             .create_index(
                 Index::create()
@@ -123,6 +164,11 @@ pub enum BoardComments {
     ParentCommentId,
     Content,
     ReplyCount,
+    ReactionCount,
+    ReactionCounts,
     CreatedAt,
     EditedAt,
+    HiddenAt,
+    HiddenBy,
+    HiddenReason,
 }
@@ -111,6 +111,13 @@ impl MigrationTrait for Migration {
                             .not_null()
                             .default(Expr::cust("now()")),
                     )
+                    // Set when an admin retracts a system announcement; the worker
+                    // fan-out stops delivering once it is. NULL otherwise.
+                    .col(
+                        ColumnDef::new(NotificationEvents::RetractedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
                     // Only allow the FK combinations valid for each target_kind.
                     .check(("chk_notification_events_target_shape", target_constraint))
                     // Reject events whose action disagrees with the target shape.
@@ -240,4 +247,5 @@ pub enum NotificationEvents {
     CommentId,
     AdditionalData,
     CreatedAt,
+    RetractedAt,
 }
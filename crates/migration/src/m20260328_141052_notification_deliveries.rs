@@ -30,6 +30,11 @@ impl MigrationTrait for Migration {
                             .uuid()
                             .not_null(),
                     )
+                    .col(
+                        ColumnDef::new(NotificationDeliveries::GroupKey)
+                            .text()
+                            .not_null(),
+                    )
                     .col(
                         ColumnDef::new(NotificationDeliveries::IsRead)
                             .boolean()
@@ -70,6 +75,20 @@ impl MigrationTrait for Migration {
             )
             .await?;
 
+        // One copy of an event per user: lets a retried fan-out skip the
+        // recipients an earlier attempt already served.
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_notification_deliveries_user_event")
+                    .table(NotificationDeliveries::Table)
+                    .col(NotificationDeliveries::UserId)
+                    .col(NotificationDeliveries::EventId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
         manager
             .create_index(
                 Index::create()
@@ -82,6 +101,21 @@ impl MigrationTrait for Migration {
             )
             .await?;
 
+        // Unread groups: finding a group's newest member, counting a user's
+        // groups and marking a whole group read all scan this.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notification_deliveries_user_unread_group")
+                    .table(NotificationDeliveries::Table)
+                    .col(NotificationDeliveries::UserId)
+                    .col(NotificationDeliveries::GroupKey)
+                    .col(NotificationDeliveries::Id)
+                    .and_where(Expr::col(NotificationDeliveries::IsRead).eq(false))
+                    .to_owned(),
+            )
+            .await?;
+
         manager
             .create_index(
                 Index::create()
@@ -131,6 +165,7 @@ pub enum NotificationDeliveries {
     Id,
     UserId,
     EventId,
+    GroupKey,
     IsRead,
     CreatedAt,
     ReadAt,
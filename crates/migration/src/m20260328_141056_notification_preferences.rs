@@ -1,5 +1,7 @@
+use crate::common::notification::NotificationDigestFrequency;
 use crate::m20250825_033639_users::Users;
 use sea_orm_migration::{prelude::*, schema::*};
+use strum::IntoEnumIterator;
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -37,6 +39,33 @@ impl MigrationTrait for Migration {
                             .not_null()
                             .default(false),
                     )
+                    // Capability behind the one-click unsubscribe link in notification
+                    // emails: random, stable for the row, never shown in the API.
+                    .col(
+                        ColumnDef::new(NotificationPreferences::EmailUnsubscribeToken)
+                            .uuid()
+                            .not_null()
+                            .unique_key()
+                            .default(Expr::cust("gen_random_uuid()")),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::DigestFrequency)
+                            .enumeration(
+                                NotificationDigestFrequency::Table,
+                                NotificationDigestFrequency::iter()
+                                    .filter(|p| !matches!(p, NotificationDigestFrequency::Table))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .not_null()
+                            .default("off"),
+                    )
+                    // End of the window the previous digest covered; the next one
+                    // starts here. NULL until the first digest is sent.
+                    .col(
+                        ColumnDef::new(NotificationPreferences::LastDigestSentAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
                     .col(
                         timestamp_with_time_zone(NotificationPreferences::UpdatedAt)
                             .not_null()
@@ -78,5 +107,8 @@ pub enum NotificationPreferences {
     UserId,
     EmailEnabled,
     PushEnabled,
+    EmailUnsubscribeToken,
+    DigestFrequency,
+    LastDigestSentAt,
     UpdatedAt,
 }
@@ -0,0 +1,129 @@
+use crate::m20250825_033643_actors::Actors;
+use crate::m20260328_141047_create_board_posts::BoardPosts;
+use crate::m20260328_141048_create_board_comments::BoardComments;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardPostRevisions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BoardPostRevisions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(ColumnDef::new(BoardPostRevisions::PostId).uuid().not_null())
+                    // NULL = revision of the post body, Some = revision of that comment.
+                    .col(ColumnDef::new(BoardPostRevisions::CommentId).uuid().null())
+                    .col(ColumnDef::new(BoardPostRevisions::Title).text().null())
+                    .col(
+                        ColumnDef::new(BoardPostRevisions::StorageKey)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardPostRevisions::ContentLength)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardPostRevisions::ActorId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardPostRevisions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_post_revisions_post_id")
+                            .from(BoardPostRevisions::Table, BoardPostRevisions::PostId)
+                            .to(BoardPosts::Table, BoardPosts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_post_revisions_comment_id")
+                            .from(BoardPostRevisions::Table, BoardPostRevisions::CommentId)
+                            .to(BoardComments::Table, BoardComments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_post_revisions_actor_id")
+                            .from(BoardPostRevisions::Table, BoardPostRevisions::ActorId)
+                            .to(Actors::Table, Actors::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_post_revisions_post_comment_created")
+                    .table(BoardPostRevisions::Table)
+                    // Serves "revisions of the post body" (comment_id IS NULL) and
+                    // "revisions of a comment" (comment_id = X), newest-first.
+                    .col(BoardPostRevisions::PostId)
+                    .col(BoardPostRevisions::CommentId)
+                    .col(BoardPostRevisions::CreatedAt)
+                    .col(BoardPostRevisions::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Postgres does not auto-index FK columns; keep the comment cascade index-driven.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_post_revisions_comment_id")
+                    .table(BoardPostRevisions::Table)
+                    .col(BoardPostRevisions::CommentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_post_revisions_actor_id")
+                    .table(BoardPostRevisions::Table)
+                    .col(BoardPostRevisions::ActorId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BoardPostRevisions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BoardPostRevisions {
+    Table,
+    Id,
+    PostId,
+    CommentId,
+    Title,
+    StorageKey,
+    ContentLength,
+    ActorId,
+    CreatedAt,
+}
@@ -0,0 +1,182 @@
+use crate::common::report::{ReportReason, ReportStatus, ReportTargetType};
+use crate::m20250825_033639_users::Users;
+use crate::m20260405_073559_create_moderation_logs::ModerationLogs;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reports::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Reports::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    // NULL once the reporter's account is deleted; the report stays in the queue.
+                    .col(ColumnDef::new(Reports::ReporterId).uuid().null())
+                    .col(
+                        ColumnDef::new(Reports::TargetType)
+                            .enumeration(
+                                ReportTargetType::Table,
+                                ReportTargetType::iter()
+                                    .filter(|p| !matches!(p, ReportTargetType::Table))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .not_null(),
+                    )
+                    // Polymorphic (post, comment or user id), so no FK.
+                    .col(ColumnDef::new(Reports::TargetId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Reports::Reason)
+                            .enumeration(
+                                ReportReason::Table,
+                                ReportReason::iter()
+                                    .filter(|p| !matches!(p, ReportReason::Table))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Reports::Description).text().null())
+                    .col(
+                        ColumnDef::new(Reports::Status)
+                            .enumeration(
+                                ReportStatus::Table,
+                                ReportStatus::iter()
+                                    .filter(|p| !matches!(p, ReportStatus::Table))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Reports::ResolvedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(Reports::ResolvedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(Reports::ResolutionNote).text().null())
+                    .col(ColumnDef::new(Reports::ModerationLogId).uuid().null())
+                    .col(
+                        ColumnDef::new(Reports::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_reporter_id")
+                            .from(Reports::Table, Reports::ReporterId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_resolved_by")
+                            .from(Reports::Table, Reports::ResolvedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_moderation_log_id")
+                            .from(Reports::Table, Reports::ModerationLogId)
+                            .to(ModerationLogs::Table, ModerationLogs::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One open report per reporter per target; closed reports don't block a new one.
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_reports_open_reporter_target")
+                    .table(Reports::Table)
+                    .col(Reports::ReporterId)
+                    .col(Reports::TargetType)
+                    .col(Reports::TargetId)
+                    .unique()
+                    .cond_where(Expr::col(Reports::Status).eq("open"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Moderator queue: filter by status, page by id.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_status_id")
+                    .table(Reports::Table)
+                    .col(Reports::Status)
+                    .col(Reports::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_target")
+                    .table(Reports::Table)
+                    .col(Reports::TargetType)
+                    .col(Reports::TargetId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_reporter_id")
+                    .table(Reports::Table)
+                    .col(Reports::ReporterId)
+                    .cond_where(Expr::col(Reports::ReporterId).is_not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_reports_moderation_log_id")
+                    .table(Reports::Table)
+                    .col(Reports::ModerationLogId)
+                    .cond_where(Expr::col(Reports::ModerationLogId).is_not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Reports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Reports {
+    Table,
+    Id,
+    ReporterId,
+    TargetType,
+    TargetId,
+    Reason,
+    Description,
+    Status,
+    ResolvedBy,
+    ResolvedAt,
+    ResolutionNote,
+    ModerationLogId,
+    CreatedAt,
+}
@@ -0,0 +1,123 @@
+use crate::m20250825_033643_actors::Actors;
+use crate::m20260328_141047_create_board_posts::BoardPosts;
+use crate::m20260328_141048_create_board_comments::BoardComments;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardReactions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BoardReactions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(ColumnDef::new(BoardReactions::ActorId).uuid().not_null())
+                    .col(ColumnDef::new(BoardReactions::PostId).uuid().not_null())
+                    // NULL = reaction on the post itself, Some = reaction on that comment.
+                    .col(ColumnDef::new(BoardReactions::CommentId).uuid().null())
+                    // Validated against `BOARD_REACTION_KINDS` on write, so the set of
+                    // kinds can change without a migration.
+                    .col(
+                        ColumnDef::new(BoardReactions::Kind)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardReactions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_reactions_actor_id")
+                            .from(BoardReactions::Table, BoardReactions::ActorId)
+                            .to(Actors::Table, Actors::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_reactions_post_id")
+                            .from(BoardReactions::Table, BoardReactions::PostId)
+                            .to(BoardPosts::Table, BoardPosts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_reactions_comment_id")
+                            .from(BoardReactions::Table, BoardReactions::CommentId)
+                            .to(BoardComments::Table, BoardComments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One reaction of each kind per actor per target. Split in two partial
+        // indexes because a plain unique index treats NULL comment ids as distinct.
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_board_reactions_post")
+                    .table(BoardReactions::Table)
+                    .col(BoardReactions::PostId)
+                    .col(BoardReactions::ActorId)
+                    .col(BoardReactions::Kind)
+                    .unique()
+                    .cond_where(Expr::col(BoardReactions::CommentId).is_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_board_reactions_comment")
+                    .table(BoardReactions::Table)
+                    .col(BoardReactions::CommentId)
+                    .col(BoardReactions::ActorId)
+                    .col(BoardReactions::Kind)
+                    .unique()
+                    .cond_where(Expr::col(BoardReactions::CommentId).is_not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_reactions_actor_id")
+                    .table(BoardReactions::Table)
+                    .col(BoardReactions::ActorId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BoardReactions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BoardReactions {
+    Table,
+    Id,
+    ActorId,
+    PostId,
+    CommentId,
+    Kind,
+    CreatedAt,
+}
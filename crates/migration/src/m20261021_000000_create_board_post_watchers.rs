@@ -0,0 +1,90 @@
+use crate::m20250825_033639_users::Users;
+use crate::m20260328_141047_create_board_posts::BoardPosts;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardPostWatchers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BoardPostWatchers::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(ColumnDef::new(BoardPostWatchers::PostId).uuid().not_null())
+                    // Keyed by user, not actor: watching exists to route notifications,
+                    // and those are delivered per user.
+                    .col(ColumnDef::new(BoardPostWatchers::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(BoardPostWatchers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_post_watchers_post_id")
+                            .from(BoardPostWatchers::Table, BoardPostWatchers::PostId)
+                            .to(BoardPosts::Table, BoardPosts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_post_watchers_user_id")
+                            .from(BoardPostWatchers::Table, BoardPostWatchers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One watch per user per post; also the index the fan-out pages through
+        // (`post_id = ? AND user_id > cursor ORDER BY user_id`).
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_board_post_watchers_post_user")
+                    .table(BoardPostWatchers::Table)
+                    .col(BoardPostWatchers::PostId)
+                    .col(BoardPostWatchers::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_post_watchers_user_id")
+                    .table(BoardPostWatchers::Table)
+                    .col(BoardPostWatchers::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BoardPostWatchers::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BoardPostWatchers {
+    Table,
+    Id,
+    PostId,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,90 @@
+use crate::m20250825_033639_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PushSubscriptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PushSubscriptions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(ColumnDef::new(PushSubscriptions::UserId).uuid().not_null())
+                    // The push service URL; it identifies the browser subscription.
+                    .col(
+                        ColumnDef::new(PushSubscriptions::Endpoint)
+                            .text()
+                            .not_null(),
+                    )
+                    // base64url P-256 public key and auth secret the payload is
+                    // encrypted for (RFC 8291).
+                    .col(ColumnDef::new(PushSubscriptions::P256dh).text().not_null())
+                    .col(ColumnDef::new(PushSubscriptions::Auth).text().not_null())
+                    .col(
+                        ColumnDef::new(PushSubscriptions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_push_subscriptions_user_id")
+                            .from(PushSubscriptions::Table, PushSubscriptions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One row per endpoint: re-subscribing upserts on it.
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_push_subscriptions_endpoint")
+                    .table(PushSubscriptions::Table)
+                    .col(PushSubscriptions::Endpoint)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_push_subscriptions_user_id")
+                    .table(PushSubscriptions::Table)
+                    .col(PushSubscriptions::UserId)
+                    .col(PushSubscriptions::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PushSubscriptions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PushSubscriptions {
+    Table,
+    Id,
+    UserId,
+    Endpoint,
+    P256dh,
+    Auth,
+    CreatedAt,
+}
@@ -0,0 +1,36 @@
+use crate::common::webhook::WebhookDeliveryStatus;
+use crate::extension::postgres::Type;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WebhookDeliveryStatus::Table)
+                    .values(
+                        WebhookDeliveryStatus::iter()
+                            .filter(|p| !matches!(p, WebhookDeliveryStatus::Table))
+                            .collect::<Vec<_>>(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_type(
+                Type::drop()
+                    .if_exists()
+                    .name(WebhookDeliveryStatus::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,89 @@
+use crate::m20250825_033639_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookEndpoints::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(ColumnDef::new(WebhookEndpoints::Url).text().not_null())
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Description)
+                            .text()
+                            .not_null()
+                            .default(""),
+                    )
+                    // Subscribed `ActionLogAction` / `ModerationAction` codes. The
+                    // table stays small, so matching scans it instead of using an index.
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::Events)
+                            .array(ColumnType::Text)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    // HMAC-SHA256 signing secret. The worker signs with it, so it is
+                    // stored as is; the API only returns it on create and rotation.
+                    .col(ColumnDef::new(WebhookEndpoints::Secret).text().not_null())
+                    .col(ColumnDef::new(WebhookEndpoints::CreatedBy).uuid().null())
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEndpoints::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_endpoints_created_by")
+                            .from(WebhookEndpoints::Table, WebhookEndpoints::CreatedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookEndpoints::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum WebhookEndpoints {
+    Table,
+    Id,
+    Url,
+    Description,
+    Events,
+    IsActive,
+    Secret,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,136 @@
+use crate::common::webhook::WebhookDeliveryStatus;
+use crate::m20261023_000001_create_webhook_endpoints::WebhookEndpoints;
+use sea_orm_migration::prelude::*;
+use strum::IntoEnumIterator;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::EndpointId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    // The log code that triggered the delivery, and the log row itself.
+                    .col(ColumnDef::new(WebhookDeliveries::Event).text().not_null())
+                    .col(ColumnDef::new(WebhookDeliveries::LogId).uuid().not_null())
+                    // The exact JSON body sent (and re-sent on redelivery).
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Status)
+                            .enumeration(
+                                WebhookDeliveryStatus::Table,
+                                WebhookDeliveryStatus::iter()
+                                    .filter(|p| !matches!(p, WebhookDeliveryStatus::Table))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::ResponseStatus)
+                            .integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(WebhookDeliveries::LastError).text().null())
+                    // Set when the dispatcher hands the row to the delivery stream;
+                    // NULL rows are still waiting in this outbox.
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::DispatchedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::DeliveredAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookDeliveries::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_endpoint_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::EndpointId)
+                            .to(WebhookEndpoints::Table, WebhookEndpoints::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Delivery log, newest first per endpoint.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_endpoint_id")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::EndpointId)
+                    .col(WebhookDeliveries::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        // The dispatcher's outbox scan only touches undispatched rows.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_undispatched")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::Id)
+                    .and_where(Expr::col(WebhookDeliveries::DispatchedAt).is_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum WebhookDeliveries {
+    Table,
+    Id,
+    EndpointId,
+    Event,
+    LogId,
+    Payload,
+    Status,
+    Attempts,
+    ResponseStatus,
+    LastError,
+    DispatchedAt,
+    DeliveredAt,
+    CreatedAt,
+}
@@ -0,0 +1,95 @@
+use crate::m20250825_033639_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserPasskeys::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserPasskeys::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(ColumnDef::new(UserPasskeys::UserId).uuid().not_null())
+                    // base64url credential id; the authenticator names the credential by it.
+                    .col(ColumnDef::new(UserPasskeys::CredentialId).text().not_null())
+                    // Serialized credential (public key, signature counter, flags).
+                    .col(
+                        ColumnDef::new(UserPasskeys::Passkey)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(UserPasskeys::Name).text().not_null())
+                    .col(
+                        ColumnDef::new(UserPasskeys::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .col(
+                        ColumnDef::new(UserPasskeys::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_passkeys_user_id")
+                            .from(UserPasskeys::Table, UserPasskeys::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A credential can be registered to one account only.
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_passkeys_credential_id")
+                    .table(UserPasskeys::Table)
+                    .col(UserPasskeys::CredentialId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_passkeys_user_id")
+                    .table(UserPasskeys::Table)
+                    .col(UserPasskeys::UserId)
+                    .col(UserPasskeys::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserPasskeys::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum UserPasskeys {
+    Table,
+    Id,
+    UserId,
+    CredentialId,
+    Passkey,
+    Name,
+    CreatedAt,
+    LastUsedAt,
+}
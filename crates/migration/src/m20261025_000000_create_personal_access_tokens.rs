@@ -0,0 +1,109 @@
+use crate::m20250825_033639_users::Users;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PersonalAccessTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .default(Expr::cust("uuidv7()")),
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::UserId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PersonalAccessTokens::Name).text().not_null())
+                    // blake3 hex digest of the raw token; the raw token is shown once.
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::TokenHash)
+                            .text()
+                            .not_null(),
+                    )
+                    // Granted `TokenScope` codes, stored as text like webhook events.
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::Scopes)
+                            .array(ColumnType::Text)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(PersonalAccessTokens::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::cust("now()")),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_personal_access_tokens_user_id")
+                            .from(PersonalAccessTokens::Table, PersonalAccessTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Every authenticated request looks the token up by its hash.
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_personal_access_tokens_token_hash")
+                    .table(PersonalAccessTokens::Table)
+                    .col(PersonalAccessTokens::TokenHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_personal_access_tokens_user_id")
+                    .table(PersonalAccessTokens::Table)
+                    .col(PersonalAccessTokens::UserId)
+                    .col(PersonalAccessTokens::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PersonalAccessTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PersonalAccessTokens {
+    Table,
+    Id,
+    UserId,
+    Name,
+    TokenHash,
+    Scopes,
+    ExpiresAt,
+    LastUsedAt,
+    CreatedAt,
+}
@@ -4,18 +4,23 @@
 //!
 //! Both the API server (direct, single-recipient notifications such as mentions
 //! and comment alerts) and the worker (batched fan-out) go through
-//! [`insert_notification_event_deliveries`], so the event's column set,
+//! [`insert_notification_event_deliveries`] — or its two halves,
+//! [`insert_notification_event`] and [`insert_notification_deliveries`], when
+//! the recipients are written in separate transactions — so the event's column set,
 //! the action encoding and the delivery defaults are defined once instead of
 //! being mirrored field-for-field across the two crates.
 
 use constants::NotificationAction;
 use entity::common::{NotificationTargetKind, NotificationType};
 use entity::notification_deliveries::{
-    ActiveModel as NotificationDeliveryActiveModel, Entity as NotificationDeliveryEntity,
+    ActiveModel as NotificationDeliveryActiveModel, Column as NotificationDeliveryColumn,
+    Entity as NotificationDeliveryEntity,
+};
+use entity::notification_events::{
+    ActiveModel as NotificationEventActiveModel, Model as NotificationEventModel,
 };
-use entity::notification_events::ActiveModel as NotificationEventActiveModel;
 use sea_orm::prelude::{IpNetwork, Json};
-use sea_orm::{ActiveModelTrait, ConnectionTrait, DbErr, EntityTrait, Set};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, DbErr, EntityTrait, Set, TryInsertResult};
 use uuid::Uuid;
 
 /// The event's target: which entity a notification points at. Converted to the
@@ -78,6 +83,19 @@ impl NotificationTarget {
     }
 }
 
+/// The key a delivery is grouped under in the inbox: unread deliveries of one
+/// user sharing it are listed and counted as one notification.
+///
+/// Events about a board post group by action and post, so every comment alert
+/// (or mention) on the same thread collapses together; events without a post
+/// are keyed by their own id and never group.
+pub fn notification_group_key(event: &NotificationEventModel) -> String {
+    match event.post_id {
+        Some(post_id) => format!("{}:post:{post_id}", event.action),
+        None => format!("event:{}", event.id),
+    }
+}
+
 /// Everything needed to write one notification event (shared by all recipients).
 #[derive(Debug, Clone)]
 pub struct NotificationEventInsertSpec {
@@ -89,27 +107,17 @@ pub struct NotificationEventInsertSpec {
     pub additional_data: Json,
 }
 
-/// Insert one `notification_events` row and one `notification_deliveries` row per
-/// recipient, on the given connection. Returns the number of deliveries created
-/// (0 for no recipients).
+/// Insert one `notification_events` row without any deliveries.
 ///
-/// The caller owns the transaction: pass a `&DatabaseTransaction` (or otherwise
-/// atomic connection) and commit it, so the event and every delivery batch
-/// commit together — a partial write would let a job retry duplicate
-/// notifications for already-inserted recipients.
-pub async fn insert_notification_event_deliveries<C: ConnectionTrait>(
+/// Used where recipients are added later in separate batches (system
+/// announcements); most callers want [`insert_notification_event_deliveries`].
+pub async fn insert_notification_event<C: ConnectionTrait>(
     conn: &C,
-    recipients: &[Uuid],
     spec: NotificationEventInsertSpec,
-    batch_size: usize,
-) -> Result<usize, DbErr> {
-    if recipients.is_empty() {
-        return Ok(0);
-    }
-
+) -> Result<NotificationEventModel, DbErr> {
     let columns = spec.target.into_columns();
 
-    let event = NotificationEventActiveModel {
+    NotificationEventActiveModel {
         id: Default::default(),
         actor_id: Set(spec.actor_id),
         actor_ip: Set(spec.actor_ip),
@@ -121,11 +129,28 @@ pub async fn insert_notification_event_deliveries<C: ConnectionTrait>(
         comment_id: Set(columns.comment_id),
         additional_data: Set(Some(spec.additional_data)),
         created_at: Default::default(),
+        retracted_at: Set(None),
     }
     .insert(conn)
-    .await?;
+    .await
+}
+
+/// Insert one unread `notification_deliveries` row per recipient of an existing
+/// event, `batch_size` rows per statement, each carrying the event's
+/// [`notification_group_key`]. Returns the recipients whose row was written.
+///
+/// Deliveries are unique per (user, event): a recipient who already has the
+/// event is skipped rather than failing the batch, so a retried fan-out can
+/// walk the same recipients again and act only on those newly inserted.
+pub async fn insert_notification_deliveries<C: ConnectionTrait>(
+    conn: &C,
+    event: &NotificationEventModel,
+    recipients: &[Uuid],
+    batch_size: usize,
+) -> Result<Vec<Uuid>, DbErr> {
+    let group_key = notification_group_key(event);
+    let mut inserted = Vec::new();
 
-    let mut created = 0usize;
     for batch in recipients.chunks(batch_size.max(1)) {
         let models: Vec<NotificationDeliveryActiveModel> = batch
             .iter()
@@ -133,28 +158,76 @@ pub async fn insert_notification_event_deliveries<C: ConnectionTrait>(
                 id: Default::default(),
                 user_id: Set(*user_id),
                 event_id: Set(event.id),
+                group_key: Set(group_key.clone()),
                 is_read: Set(false),
                 created_at: Default::default(),
                 read_at: Set(None),
             })
             .collect();
 
-        NotificationDeliveryEntity::insert_many(models)
-            .exec(conn)
+        let result = NotificationDeliveryEntity::insert_many(models)
+            .on_conflict_do_nothing_on([
+                NotificationDeliveryColumn::UserId,
+                NotificationDeliveryColumn::EventId,
+            ])
+            .exec_with_returning_many(conn)
             .await?;
+        if let TryInsertResult::Inserted(rows) = result {
+            inserted.extend(rows.into_iter().map(|row| row.user_id));
+        }
+    }
 
-        created += batch.len();
+    Ok(inserted)
+}
+
+/// Insert one `notification_events` row and one `notification_deliveries` row per
+/// recipient, on the given connection. Returns the created event's id, or `None`
+/// when there are no recipients (nothing is written).
+///
+/// The caller owns the transaction: pass a `&DatabaseTransaction` (or otherwise
+/// atomic connection) and commit it, so the event and every delivery batch
+/// commit together — a partial write would let a job retry duplicate
+/// notifications for already-inserted recipients.
+pub async fn insert_notification_event_deliveries<C: ConnectionTrait>(
+    conn: &C,
+    recipients: &[Uuid],
+    spec: NotificationEventInsertSpec,
+    batch_size: usize,
+) -> Result<Option<Uuid>, DbErr> {
+    if recipients.is_empty() {
+        return Ok(None);
     }
 
-    Ok(created)
+    let event = insert_notification_event(conn, spec).await?;
+    insert_notification_deliveries(conn, &event, recipients, batch_size).await?;
+
+    Ok(Some(event.id))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::NotificationTarget;
-    use entity::common::NotificationTargetKind;
+    use super::{NotificationTarget, notification_group_key};
+    use entity::common::{NotificationTargetKind, NotificationType};
+    use entity::notification_events::Model as NotificationEventModel;
     use uuid::Uuid;
 
+    fn event(action: &str, post_id: Option<Uuid>) -> NotificationEventModel {
+        NotificationEventModel {
+            id: Uuid::now_v7(),
+            actor_id: None,
+            actor_ip: None,
+            notification_type: NotificationType::Board,
+            action: action.to_string(),
+            target_kind: NotificationTargetKind::None,
+            board_id: None,
+            post_id,
+            comment_id: None,
+            additional_data: None,
+            created_at: Default::default(),
+            retracted_at: None,
+        }
+    }
+
     #[test]
     fn board_post_target_sets_only_board_columns() {
         let board_id = Uuid::now_v7();
@@ -196,4 +269,35 @@ mod tests {
         assert_eq!(columns.post_id, None);
         assert_eq!(columns.comment_id, None);
     }
+
+    #[test]
+    fn events_about_one_post_share_a_group_per_action() {
+        let post_id = Uuid::now_v7();
+        let first = event("board_comment_created", Some(post_id));
+        let second = event("board_comment_created", Some(post_id));
+
+        assert_eq!(
+            notification_group_key(&first),
+            notification_group_key(&second)
+        );
+        assert_ne!(
+            notification_group_key(&first),
+            notification_group_key(&event("user_mentioned", Some(post_id)))
+        );
+        assert_ne!(
+            notification_group_key(&first),
+            notification_group_key(&event("board_comment_created", Some(Uuid::now_v7())))
+        );
+    }
+
+    #[test]
+    fn events_without_a_post_never_group() {
+        let first = event("system_announcement", None);
+        let second = event("system_announcement", None);
+
+        assert_ne!(
+            notification_group_key(&first),
+            notification_group_key(&second)
+        );
+    }
 }
@@ -5,7 +5,8 @@
 //! action, how an event + deliveries are written) would otherwise live in two
 //! places and drift. This crate is the single source for them:
 //!
-//! - [`deliveries`] — write one event + per-recipient deliveries.
+//! - [`deliveries`] — write one event + per-recipient deliveries, together or
+//!   apart, and the key deliveries are grouped under in the inbox.
 //! - [`preferences`] — filter recipients by their per-action opt-out.
 //!
 //! Every item is re-exported at the crate root.
@@ -14,6 +15,7 @@ pub mod deliveries;
 pub mod preferences;
 
 pub use deliveries::{
-    NotificationEventInsertSpec, NotificationTarget, insert_notification_event_deliveries,
+    NotificationEventInsertSpec, NotificationTarget, insert_notification_deliveries,
+    insert_notification_event, insert_notification_event_deliveries, notification_group_key,
 };
 pub use preferences::filter_recipients_by_action_preference;
@@ -0,0 +1,24 @@
+//! `comments` index contract.
+
+use serde::{Deserialize, Serialize};
+
+/// Meilisearch index uid for board comments.
+pub const COMMENTS_INDEX: &str = "comments";
+
+/// A board comment as stored in the `comments` search index. `board_id` is
+/// denormalized from the parent post so the server can apply the same board
+/// filter as for posts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchComment {
+    pub id: String,
+    pub board_id: String,
+    pub post_id: String,
+    /// Id of the top-level comment of the thread (the comment's own id when it
+    /// is top-level), so deleting a thread can drop its replies too.
+    pub thread_id: String,
+    pub actor_id: String,
+    pub content: String,
+    /// Unix timestamp (seconds), sortable.
+    pub created_at: i64,
+    pub is_hidden: bool,
+}
@@ -6,4 +6,6 @@
 //! enum string encodings are defined exactly once here instead of being
 //! mirrored (and silently drifting) across the two crates.
 
+pub mod comments;
+pub mod posts;
 pub mod users;
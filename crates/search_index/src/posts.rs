@@ -0,0 +1,22 @@
+//! `posts` index contract.
+
+use serde::{Deserialize, Serialize};
+
+/// Meilisearch index uid for board posts.
+pub const POSTS_INDEX: &str = "posts";
+
+/// A board post as stored in the `posts` search index. The worker serializes
+/// this from `board_posts`; the server deserializes it for search and filters
+/// on `board_id`/`is_hidden` (whether the board is disabled is checked against
+/// the DB, not the index).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchPost {
+    pub id: String,
+    pub board_id: String,
+    pub actor_id: String,
+    pub title: String,
+    pub content: String,
+    /// Unix timestamp (seconds), sortable.
+    pub created_at: i64,
+    pub is_hidden: bool,
+}
@@ -1,7 +1,7 @@
 use super::health::openapi::HealthApiDoc;
 use super::v0::routes::openapi::V0ApiDoc;
 use errors::errors::ErrorResponse;
-use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 
 #[derive(OpenApi)]
@@ -37,6 +37,17 @@ impl Modify for SecurityAddon {
                 "session_id_cookie",
                 SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("session_id"))),
             );
+            components.add_security_scheme(
+                "personal_access_token",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .description(Some(
+                            "Personal access token (`akpat_...`); the operation lists the scope it needs",
+                        ))
+                        .build(),
+                ),
+            );
         }
     }
 }
@@ -0,0 +1,34 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::access_token::service_create_access_token;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::request::CreateAccessTokenRequest;
+use dto::auth::response::AccessTokenCreatedResponse;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/tokens",
+    summary = "Create a personal access token",
+    description = "Mints a named token limited to the requested scopes, with an optional expiry. The raw token is returned only in this response; send it as `Authorization: Bearer <token>`. Routes outside every scope (account, credentials, sessions) reject it. The account owner is emailed a security alert.",
+    request_body = CreateAccessTokenRequest,
+    responses(
+        (status = 201, description = "Token created", body = AccessTokenCreatedResponse),
+        (status = 400, description = "Invalid name, scope, or expiry", body = ErrorResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 409, description = "The account already holds the maximum number of tokens", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Access Tokens"
+)]
+pub async fn access_token_create(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<CreateAccessTokenRequest>,
+) -> Result<AccessTokenCreatedResponse, Errors> {
+    service_create_access_token(&state.db, &state.worker, session_context.user_id, payload).await
+}
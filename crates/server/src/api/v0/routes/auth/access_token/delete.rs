@@ -0,0 +1,39 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::access_token::service_delete_access_token;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use errors::errors::{ErrorResponse, Errors};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/v0/auth/tokens/{id}",
+    summary = "Revoke one of the authenticated user's personal access tokens",
+    description = "Deletes the token; requests carrying it are rejected from then on.",
+    params(
+        ("id" = Uuid, Path, description = "Token id")
+    ),
+    responses(
+        (status = 204, description = "The token was revoked"),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 404, description = "Token does not exist or does not belong to the current user", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Access Tokens"
+)]
+pub async fn access_token_delete(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    Path(id): Path<Uuid>,
+) -> Result<Response, Errors> {
+    service_delete_access_token(&state.db, session_context.user_id, id).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
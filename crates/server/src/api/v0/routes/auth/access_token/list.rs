@@ -0,0 +1,28 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::access_token::service_list_access_tokens;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::response::AccessTokenListResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/auth/tokens",
+    summary = "List the authenticated user's personal access tokens",
+    description = "Returns the account's tokens, newest first, with their scopes, expiry and last use. Raw tokens are never returned.",
+    responses(
+        (status = 200, description = "Tokens of the authenticated user", body = AccessTokenListResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Access Tokens"
+)]
+pub async fn access_token_list(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<AccessTokenListResponse, Errors> {
+    service_list_access_tokens(&state.db, session_context.user_id).await
+}
@@ -39,7 +39,13 @@ pub async fn auth_verify_email(
     let user_agent = extract_user_agent(user_agent);
     let ip_address = extract_ip_address(&headers, addr);
 
-    let user_id = service_verify_email(&state.db, &state.redis_session, &payload.token).await?;
+    let user_id = service_verify_email(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &payload.token,
+    )
+    .await?;
 
     spawn_index_user(&state.worker, user_id);
 
@@ -78,7 +84,13 @@ pub async fn auth_verify_email_app(
     let user_agent = extract_user_agent(user_agent);
     let ip_address = extract_ip_address(&headers, addr);
 
-    let user_id = service_verify_email(&state.db, &state.redis_session, &payload.token).await?;
+    let user_id = service_verify_email(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &payload.token,
+    )
+    .await?;
 
     spawn_index_user(&state.worker, user_id);
 
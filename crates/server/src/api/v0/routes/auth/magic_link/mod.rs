@@ -0,0 +1,2 @@
+pub mod request_magic_link;
+pub mod verify_magic_link;
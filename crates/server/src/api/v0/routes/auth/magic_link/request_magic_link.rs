@@ -0,0 +1,39 @@
+use crate::service::auth::magic_link::service_request_magic_link;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use dto::auth::request::MagicLinkRequest;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/magic-link",
+    summary = "Request a magic sign-in link",
+    description = "If the submitted email belongs to an active account, this endpoint issues a one-time sign-in token and queues an email with the link. Complete the sign-in with POST /v0/auth/magic-link/verify (browser) or POST /v0/app/auth/magic-link/verify (native app). It still returns 204 No Content for unknown emails to avoid account enumeration.",
+    request_body = MagicLinkRequest,
+    responses(
+        (status = 204, description = "Sign-in email was queued when the account was eligible"),
+        (status = 400, description = "Malformed JSON payload or validation error", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or Redis error", body = ErrorResponse),
+        (status = 502, description = "Worker service rejected the sign-in email job or returned an invalid response", body = ErrorResponse),
+        (status = 503, description = "Worker service could not be reached", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_request_magic_link(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<MagicLinkRequest>,
+) -> Result<impl IntoResponse, Errors> {
+    service_request_magic_link(
+        &state.db,
+        &state.redis_session,
+        &state.worker,
+        &payload.email,
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
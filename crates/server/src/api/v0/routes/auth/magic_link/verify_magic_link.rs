@@ -0,0 +1,144 @@
+use crate::service::auth::magic_link::{MagicLinkResult, service_verify_magic_link};
+use crate::state::AppState;
+use crate::utils::crypto::token::generate_secure_token;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use crate::utils::extract::extract_user_agent::extract_user_agent;
+use axum::extract::{ConnectInfo, State};
+use axum::http::HeaderMap;
+use axum::response::{IntoResponse, Response};
+use axum_extra::{TypedHeader, headers::UserAgent};
+use dto::auth::request::MagicLinkVerifyRequest;
+use dto::auth::response::{
+    AppDeviceVerifyResponse, DEVICE_TOKEN_HEADER, TotpRequiredResponse, build_device_cookie,
+    create_login_response, device_cookie_name,
+};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+use std::net::SocketAddr;
+use tower_cookies::Cookies;
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/magic-link/verify",
+    summary = "Sign in with a magic link",
+    description = "Consumes the single-use token from the email sent by POST /v0/auth/magic-link. Following the link proves control of the account's mailbox, so new-device verification is not applied; the browser is trusted instead (the long-lived device cookie is set), so later password logins from it are recognized. If the account has TOTP enabled, this endpoint returns 202 Accepted with a temporary token for POST /v0/auth/totp/verify (or, when `passkey_available` is true, POST /v0/auth/passkeys/second-factor) instead of creating a session. Otherwise it creates a session immediately and sets the session cookie.",
+    request_body = MagicLinkVerifyRequest,
+    responses(
+        (status = 204, description = "Sign-in succeeded; session and device cookies were issued"),
+        (status = 202, description = "TOTP verification is required", body = TotpRequiredResponse),
+        (status = 400, description = "Malformed JSON payload, validation error, or an invalid, expired or already-used token", body = ErrorResponse),
+        (status = 404, description = "The account no longer exists", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_verify_magic_link(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<MagicLinkVerifyRequest>,
+) -> Result<Response, Errors> {
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+    // Browser flow: reuse the device cookie, or mint one now so the TOTP step can trust it too.
+    let device_token = cookies
+        .get(&device_cookie_name())
+        .map(|c| c.value().to_string())
+        .unwrap_or_else(generate_secure_token);
+
+    let result = service_verify_magic_link(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &payload.token,
+        payload.remember_me,
+        user_agent,
+        Some(ip_address),
+        Some(device_token.clone()),
+    )
+    .await?;
+
+    // The device becomes trusted on sign-in (after the second factor on the TOTP path); an untrusted
+    // cookie recognizes nothing, so it is safe to set either way.
+    cookies.add(build_device_cookie(device_token));
+
+    match result {
+        MagicLinkResult::SessionCreated {
+            session_id,
+            remember_me,
+            ..
+        } => create_login_response(session_id, remember_me),
+        MagicLinkResult::TotpRequired {
+            temp_token,
+            passkey_available,
+        } => Ok(TotpRequiredResponse {
+            temp_token,
+            passkey_available,
+        }
+        .into_response()),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/app/auth/magic-link/verify",
+    summary = "Sign in with a magic link (native-app client)",
+    description = "Native-app variant of POST /v0/auth/magic-link/verify. On success the opaque session token is returned in the response body — for replay as `Authorization: Bearer <token>` — instead of an HttpOnly cookie, together with a device-recognition token: the one presented in the `X-Device-Token` header, or a new one to store and send on future logins. The TOTP branch is identical to the browser flow (202 + temporary token for POST /v0/app/auth/totp/verify) and trusts the presented `X-Device-Token`, if any, once the second factor succeeds. `remember_me` is ignored.",
+    request_body = MagicLinkVerifyRequest,
+    responses(
+        (status = 200, description = "Sign-in succeeded; session and device tokens are returned in the body", body = AppDeviceVerifyResponse),
+        (status = 202, description = "TOTP verification is required", body = TotpRequiredResponse),
+        (status = 400, description = "Malformed JSON payload, validation error, or an invalid, expired or already-used token", body = ErrorResponse),
+        (status = 404, description = "The account no longer exists", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_verify_magic_link_app(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<MagicLinkVerifyRequest>,
+) -> Result<Response, Errors> {
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+    // App flow: the device-recognition token arrives in the `X-Device-Token` header (no cookie jar).
+    let device_token = headers
+        .get(DEVICE_TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let result = service_verify_magic_link(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &payload.token,
+        payload.remember_me,
+        user_agent,
+        Some(ip_address),
+        device_token,
+    )
+    .await?;
+
+    match result {
+        MagicLinkResult::SessionCreated {
+            session_id,
+            device_token,
+            ..
+        } => {
+            // App client holds both tokens itself → return them in the body (no cookies).
+            Ok(AppDeviceVerifyResponse::new(session_id, device_token).into_response())
+        }
+        MagicLinkResult::TotpRequired {
+            temp_token,
+            passkey_available,
+        } => Ok(TotpRequiredResponse {
+            temp_token,
+            passkey_available,
+        }
+        .into_response()),
+    }
+}
@@ -1,7 +1,11 @@
+pub mod access_token;
 pub mod email;
+pub mod magic_link;
 pub mod oauth;
 pub mod openapi;
+pub mod passkey;
 pub mod password;
 pub mod routes;
+pub mod security;
 pub mod session;
 pub mod totp;
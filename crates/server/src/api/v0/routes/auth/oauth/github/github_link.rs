@@ -37,6 +37,7 @@ pub async fn auth_github_link(
     service_link_github_oauth(
         &state.db,
         &state.redis_session,
+        &state.nats_client,
         &state.http_client,
         session_context.user_id,
         &payload.code,
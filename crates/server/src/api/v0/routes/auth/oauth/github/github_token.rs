@@ -46,6 +46,7 @@ pub async fn auth_github_token_app(
     let result = service_github_token_sign_in(
         &state.db,
         &state.redis_session,
+        &state.nats_client,
         &state.http_client,
         &payload.access_token,
         user_agent,
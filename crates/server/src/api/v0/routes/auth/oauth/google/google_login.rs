@@ -50,6 +50,7 @@ pub async fn auth_google_login(
     let result = service_google_sign_in(
         &state.db,
         &state.redis_session,
+        &state.nats_client,
         &state.http_client,
         &payload.code,
         &payload.state,
@@ -49,6 +49,7 @@ pub async fn auth_google_one_tap_login(
     let result = service_google_one_tap_sign_in(
         &state.db,
         &state.redis_session,
+        &state.nats_client,
         &state.http_client,
         &payload.credential,
         &anonymous.anonymous_user_id,
@@ -1,4 +1,5 @@
 pub mod github;
 pub mod google;
 pub mod list_oauth_connections;
+pub mod oidc;
 pub mod unlink_oauth_connection;
@@ -0,0 +1,4 @@
+pub mod oidc_authorize;
+pub mod oidc_link;
+pub mod oidc_login;
+pub mod oidc_token;
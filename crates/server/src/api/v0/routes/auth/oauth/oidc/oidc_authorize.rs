@@ -0,0 +1,46 @@
+use crate::middleware::anonymous_user::AnonymousUserContext;
+use crate::service::oauth::oidc::{oidc_provider, service_generate_oidc_oauth_url};
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::{Path, State};
+use dto::oauth::request::{OAuthAuthorizeFlow, OAuthAuthorizeQuery};
+use dto::oauth::response::OAuthUrlResponse;
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+/// Generates an OpenID Connect authorization URL for a configured provider.
+#[utoipa::path(
+    get,
+    path = "/v0/auth/oauth/{provider}/authorize",
+    summary = "Create an OpenID Connect authorization URL",
+    description = "Generates an authorization URL for a provider configured in `OIDC_PROVIDERS` (`discord`, `microsoft`, or `sso` for a self-hosted IdP). Endpoints come from the provider's discovery document. The URL carries PKCE (S256) and a nonce; the single-use state record stored in Redis holds both and is bound to the current anonymous browser context and to the requested flow, which defaults to login.",
+    params(
+        ("provider" = String, Path, description = "OIDC provider slug, e.g. `sso`"),
+        OAuthAuthorizeQuery
+    ),
+    responses(
+        (status = 200, description = "Authorization URL generated successfully", body = OAuthUrlResponse),
+        (status = 400, description = "Invalid query parameters", body = ErrorResponse),
+        (status = 404, description = "The provider is not configured on this server", body = ErrorResponse),
+        (status = 500, description = "Unexpected Redis error or the provider's discovery document is unavailable", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_oidc_authorize(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    Extension(anonymous): Extension<AnonymousUserContext>,
+    ValidatedQuery(query): ValidatedQuery<OAuthAuthorizeQuery>,
+) -> Result<OAuthUrlResponse, Errors> {
+    let provider = oidc_provider(&provider)?;
+    let flow = query.flow.unwrap_or(OAuthAuthorizeFlow::Login);
+
+    service_generate_oidc_oauth_url(
+        &state.redis_session,
+        &state.http_client,
+        &provider,
+        &anonymous.anonymous_user_id,
+        flow,
+    )
+    .await
+}
@@ -0,0 +1,58 @@
+use crate::extractors::RequiredSession;
+use crate::middleware::anonymous_user::AnonymousUserContext;
+use crate::service::oauth::oidc::{oidc_provider, service_link_oidc_oauth};
+use crate::state::AppState;
+use axum::Extension;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use dto::oauth::request::OidcLinkRequest;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+/// Links an OpenID Connect provider to the current account.
+#[utoipa::path(
+    post,
+    path = "/v0/auth/oauth/{provider}/link",
+    summary = "Link an OpenID Connect identity to the current user",
+    description = "Exchanges the authorization code, validates the single-use state created by the OIDC authorize endpoint with `flow=link`, verifies the ID token (including its nonce), and stores the provider identity on the authenticated account. The state is bound to the same anonymous browser context that started the link flow.",
+    params(
+        ("provider" = String, Path, description = "OIDC provider slug, e.g. `sso`")
+    ),
+    request_body = OidcLinkRequest,
+    responses(
+        (status = 204, description = "The provider identity was linked to the current user"),
+        (status = 400, description = "Malformed JSON payload, validation error, invalid or expired state or code, invalid ID token, or the provider did not vouch for the email", body = ErrorResponse),
+        (status = 401, description = "Missing, invalid, or expired session cookie", body = ErrorResponse),
+        (status = 404, description = "The provider is not configured on this server", body = ErrorResponse),
+        (status = 409, description = "The identity is already linked to this account or another account", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or Redis error, or the provider's metadata is unavailable", body = ErrorResponse)
+    ),
+    tag = "Auth",
+    security(
+        ("session_id_cookie" = [])
+    )
+)]
+pub async fn auth_oidc_link(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    RequiredSession(session_context): RequiredSession,
+    Extension(anonymous): Extension<AnonymousUserContext>,
+    ValidatedJson(payload): ValidatedJson<OidcLinkRequest>,
+) -> Result<StatusCode, Errors> {
+    let provider = oidc_provider(&provider)?;
+
+    service_link_oidc_oauth(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &state.http_client,
+        &provider,
+        session_context.user_id,
+        &payload.code,
+        &payload.state,
+        &anonymous.anonymous_user_id,
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,71 @@
+use crate::middleware::anonymous_user::AnonymousUserContext;
+use crate::service::oauth::oidc::{oidc_provider, service_oidc_sign_in};
+use crate::state::AppState;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use crate::utils::extract::extract_user_agent::extract_user_agent;
+use axum::Extension;
+use axum::http::HeaderMap;
+use axum::{
+    extract::{ConnectInfo, Path, State},
+    response::Response,
+};
+use axum_extra::{TypedHeader, headers::UserAgent};
+use dto::oauth::request::OidcLoginRequest;
+use dto::oauth::response::{OAuthPendingSignupResponse, OAuthSignInResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+use std::net::SocketAddr;
+
+/// Handles OpenID Connect login.
+///
+/// - Existing user: 204 No Content + Set-Cookie
+/// - New user: 200 OK + pending signup info (complete-signup required)
+#[utoipa::path(
+    post,
+    path = "/v0/auth/oauth/{provider}/login",
+    summary = "Sign in with an OpenID Connect provider",
+    description = "Exchanges the authorization code (with the PKCE verifier from the validated state) and verifies the returned ID token against the provider's JWKS: signature, `iss` pinned to the discovered issuer, `aud` pinned to our client id, `exp`, and the nonce issued with the authorization URL. If the ID token has no email, it is read from the userinfo endpoint. Existing linked accounts receive a session immediately. New identities receive a pending signup token that must be completed via POST /v0/auth/complete-signup.",
+    params(
+        ("provider" = String, Path, description = "OIDC provider slug, e.g. `sso`")
+    ),
+    request_body = OidcLoginRequest,
+    responses(
+        (status = 200, description = "Provider identity was accepted but profile completion is still required", body = OAuthPendingSignupResponse),
+        (status = 204, description = "Provider identity matched an existing account and a session cookie was issued"),
+        (status = 400, description = "Malformed JSON payload, validation error, invalid or expired state or code, invalid ID token, or the provider did not vouch for the email", body = ErrorResponse),
+        (status = 404, description = "The provider is not configured on this server", body = ErrorResponse),
+        (status = 409, description = "A local account already uses the same email address", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or Redis error, or the provider's metadata is unavailable", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_oidc_login(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    Extension(anonymous): Extension<AnonymousUserContext>,
+    ValidatedJson(payload): ValidatedJson<OidcLoginRequest>,
+) -> Result<Response, Errors> {
+    let provider = oidc_provider(&provider)?;
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+
+    let result = service_oidc_sign_in(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &state.http_client,
+        &provider,
+        &payload.code,
+        &payload.state,
+        &anonymous.anonymous_user_id,
+        user_agent,
+        Some(ip_address),
+    )
+    .await?;
+
+    // Convert SignInResult into an HTTP response
+    OAuthSignInResponse::from_result(result).into_response_result()
+}
@@ -0,0 +1,66 @@
+use crate::service::oauth::oidc::{oidc_provider, service_oidc_token_sign_in};
+use crate::state::AppState;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use crate::utils::extract::extract_user_agent::extract_user_agent;
+use axum::{
+    extract::{ConnectInfo, Path, State},
+    http::HeaderMap,
+    response::Response,
+};
+use axum_extra::{TypedHeader, headers::UserAgent};
+use dto::auth::response::SessionTokenResponse;
+use dto::oauth::request::OidcTokenRequest;
+use dto::oauth::response::OAuthSignInResponse;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+use std::net::SocketAddr;
+
+/// Native-app OpenID Connect sign-in with a provider ID token.
+///
+/// - Existing user: 200 OK + session token in the body (`SessionTokenResponse`)
+/// - New user: 200 OK + pending signup payload (complete via POST /v0/app/auth/complete-signup)
+#[utoipa::path(
+    post,
+    path = "/v0/app/auth/oauth/{provider}/token",
+    summary = "Sign in with an OpenID Connect provider from a native app (provider-token flow)",
+    description = "Native-app variant of the OIDC flow (allauth `provider/token` pattern). The app runs its own authorization against the IdP and submits the ID token; the server verifies it against the provider's JWKS (signature, `iss`, `aud` pinned to our client id, `exp`) and requires an email the provider vouches for in the token itself. An existing linked account receives the opaque session token in the body for `Authorization: Bearer` use. A new identity receives a pending signup token (bound only by its own secrecy) that must be completed via POST /v0/app/auth/complete-signup.",
+    params(
+        ("provider" = String, Path, description = "OIDC provider slug, e.g. `sso`")
+    ),
+    request_body = OidcTokenRequest,
+    responses(
+        (status = 200, description = "Existing account signed in; session token returned in the body. A NEW identity instead returns 200 with OAuthPendingSignupResponse (profile completion required).", body = SessionTokenResponse),
+        (status = 400, description = "Malformed JSON payload, validation error, invalid ID token, or the provider did not vouch for the email", body = ErrorResponse),
+        (status = 404, description = "The provider is not configured on this server", body = ErrorResponse),
+        (status = 409, description = "A local account already uses the same email address", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or Redis error, or the provider's metadata is unavailable", body = ErrorResponse)
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_oidc_token_app(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    ValidatedJson(payload): ValidatedJson<OidcTokenRequest>,
+) -> Result<Response, Errors> {
+    let provider = oidc_provider(&provider)?;
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+
+    let result = service_oidc_token_sign_in(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &state.http_client,
+        &provider,
+        &payload.id_token,
+        user_agent,
+        Some(ip_address),
+    )
+    .await?;
+
+    // Existing user → session token in body; new user → pending signup payload (already a body).
+    OAuthSignInResponse::from_result(result).into_app_response_result()
+}
@@ -31,7 +31,13 @@ pub async fn unlink_oauth_connection(
     RequiredSession(session_context): RequiredSession,
     ValidatedJson(payload): ValidatedJson<UnlinkOAuthRequest>,
 ) -> Result<StatusCode, Errors> {
-    service_unlink_oauth(&state.db, session_context.user_id, payload.provider).await?;
+    service_unlink_oauth(
+        &state.db,
+        &state.nats_client,
+        session_context.user_id,
+        payload.provider,
+    )
+    .await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
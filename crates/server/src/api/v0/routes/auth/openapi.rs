@@ -1,18 +1,25 @@
 use dto::auth::request::{
     ChangeEmailRequest, ChangePasswordRequest, CompleteSignupRequest, ConfirmEmailChangeRequest,
-    ForgotPasswordRequest, LoginRequest, ResendVerificationEmailRequest, ResetPasswordRequest,
-    SetInitialPasswordRequest, TotpDisableRequest, TotpEnableRequest,
+    CreateAccessTokenRequest, ForgotPasswordRequest, ListAuthEventsRequest, LoginRequest,
+    MagicLinkRequest, MagicLinkVerifyRequest, PasskeyLoginRequest, PasskeyRegisterRequest,
+    PasskeySecondFactorOptionsRequest, PasskeySecondFactorRequest, ResendVerificationEmailRequest,
+    ResetPasswordRequest, SetInitialPasswordRequest, TotpDisableRequest, TotpEnableRequest,
     TotpRegenerateBackupCodesRequest, TotpVerifyRequest, VerifyDeviceRequest, VerifyEmailRequest,
 };
 use dto::auth::response::{
-    AppDeviceVerifyResponse, DeviceVerificationRequiredResponse, ListSessionsResponse, SessionInfo,
+    AccessTokenCreatedResponse, AccessTokenListResponse, AccessTokenResponse,
+    AppDeviceVerifyResponse, AuthEventListResponse, AuthEventResponse,
+    DeviceVerificationRequiredResponse, KnownDeviceListResponse, KnownDeviceResponse,
+    ListSessionsResponse, PasskeyAuthenticationOptionsResponse, PasskeyListResponse,
+    PasskeyRegistrationOptionsResponse, PasskeyResponse, RevokeOtherSessionsResponse, SessionInfo,
     SessionTokenResponse, TotpBackupCodesResponse, TotpEnableResponse, TotpRequiredResponse,
     TotpSetupResponse, TotpStatusResponse,
 };
 use dto::oauth::request::google::{GoogleOneTapLoginRequest, GoogleTokenRequest};
 use dto::oauth::request::{
     GithubLinkRequest, GithubLoginRequest, GithubTokenRequest, GoogleLinkRequest,
-    GoogleLoginRequest, OAuthAuthorizeFlow, OAuthAuthorizeQuery, UnlinkOAuthRequest,
+    GoogleLoginRequest, OAuthAuthorizeFlow, OAuthAuthorizeQuery, OidcLinkRequest, OidcLoginRequest,
+    OidcTokenRequest, UnlinkOAuthRequest,
 };
 use dto::oauth::response::OAuthPendingSignupResponse;
 use dto::oauth::response::{
@@ -28,11 +35,18 @@ use utoipa::OpenApi;
         super::session::login::auth_login_app,
         super::session::verify_device::auth_verify_device,
         super::session::verify_device::auth_verify_device_app,
+        super::magic_link::request_magic_link::auth_request_magic_link,
+        super::magic_link::verify_magic_link::auth_verify_magic_link,
+        super::magic_link::verify_magic_link::auth_verify_magic_link_app,
         super::totp::verify::totp_verify_app,
         super::email::verify_email::auth_verify_email_app,
         super::session::logout::auth_logout,
         super::session::list_sessions::auth_list_sessions,
         super::session::revoke_session::auth_revoke_session,
+        super::session::revoke_other_sessions::auth_revoke_other_sessions,
+        super::security::list_auth_events::auth_list_events,
+        super::security::list_devices::auth_list_devices,
+        super::security::forget_device::auth_forget_device,
         super::password::forgot_password::auth_forgot_password,
         super::password::reset_password::auth_reset_password,
         super::session::signup::auth_signup,
@@ -40,12 +54,26 @@ use utoipa::OpenApi;
         super::session::complete_signup::auth_complete_signup_app,
         super::oauth::google::google_token::auth_google_token_app,
         super::oauth::github::github_token::auth_github_token_app,
+        super::oauth::oidc::oidc_token::auth_oidc_token_app,
         super::totp::setup::totp_setup,
         super::totp::verify::totp_verify,
         super::totp::enable::totp_enable,
         super::totp::disable::totp_disable,
         super::totp::status::totp_status,
         super::totp::regenerate_backup_codes::totp_regenerate_backup_codes,
+        super::access_token::create::access_token_create,
+        super::access_token::list::access_token_list,
+        super::access_token::delete::access_token_delete,
+        super::passkey::register_options::passkey_register_options,
+        super::passkey::register::passkey_register,
+        super::passkey::list::passkey_list,
+        super::passkey::delete::passkey_delete,
+        super::passkey::login_options::passkey_login_options,
+        super::passkey::login::passkey_login,
+        super::passkey::login::passkey_login_app,
+        super::passkey::second_factor_options::passkey_second_factor_options,
+        super::passkey::second_factor::passkey_second_factor,
+        super::passkey::second_factor::passkey_second_factor_app,
         super::oauth::google::google_authorize::auth_google_authorize,
         super::oauth::google::google_login::auth_google_login,
         super::oauth::google::google_one_tap_login::auth_google_one_tap_login,
@@ -54,6 +82,9 @@ use utoipa::OpenApi;
         super::oauth::github::github_authorize::auth_github_authorize,
         super::oauth::github::github_login::auth_github_login,
         super::oauth::github::github_link::auth_github_link,
+        super::oauth::oidc::oidc_authorize::auth_oidc_authorize,
+        super::oauth::oidc::oidc_login::auth_oidc_login,
+        super::oauth::oidc::oidc_link::auth_oidc_link,
         super::oauth::list_oauth_connections::list_oauth_connections,
         super::oauth::unlink_oauth_connection::unlink_oauth_connection,
         super::email::verify_email::auth_verify_email,
@@ -69,6 +100,8 @@ use utoipa::OpenApi;
             VerifyEmailRequest,
             ResendVerificationEmailRequest,
             ForgotPasswordRequest,
+            MagicLinkRequest,
+            MagicLinkVerifyRequest,
             ResetPasswordRequest,
             CreateUserRequest,
             CreateUserResponse,
@@ -84,6 +117,9 @@ use utoipa::OpenApi;
             GithubTokenRequest,
             GoogleLinkRequest,
             GithubLinkRequest,
+            OidcLoginRequest,
+            OidcLinkRequest,
+            OidcTokenRequest,
             UnlinkOAuthRequest,
             OAuthConnectionResponse,
             OAuthConnectionListResponse,
@@ -96,6 +132,18 @@ use utoipa::OpenApi;
             TotpEnableResponse,
             TotpBackupCodesResponse,
             TotpRequiredResponse,
+            CreateAccessTokenRequest,
+            AccessTokenCreatedResponse,
+            AccessTokenResponse,
+            AccessTokenListResponse,
+            PasskeyRegisterRequest,
+            PasskeyLoginRequest,
+            PasskeySecondFactorOptionsRequest,
+            PasskeySecondFactorRequest,
+            PasskeyRegistrationOptionsResponse,
+            PasskeyAuthenticationOptionsResponse,
+            PasskeyResponse,
+            PasskeyListResponse,
             VerifyDeviceRequest,
             DeviceVerificationRequiredResponse,
             AppDeviceVerifyResponse,
@@ -105,12 +153,21 @@ use utoipa::OpenApi;
             ConfirmEmailChangeRequest,
             SessionInfo,
             ListSessionsResponse,
+            RevokeOtherSessionsResponse,
+            ListAuthEventsRequest,
+            AuthEventResponse,
+            AuthEventListResponse,
+            KnownDeviceResponse,
+            KnownDeviceListResponse,
             SessionTokenResponse,
         )
     ),
     tags(
         (name = "Auth", description = "Authentication, signup, session, and OAuth endpoints"),
-        (name = "Auth - TOTP", description = "Two-factor authentication enrollment and verification endpoints")
+        (name = "Auth - TOTP", description = "Two-factor authentication enrollment and verification endpoints"),
+        (name = "Auth - Passkeys", description = "Passkey (WebAuthn) registration, passwordless login, and second-factor endpoints"),
+        (name = "Auth - Access Tokens", description = "Personal access tokens for scripts and CI jobs"),
+        (name = "Auth - Security", description = "Login history and known-device management for the authenticated account")
     )
 )]
 pub struct AuthApiDoc;
@@ -0,0 +1,39 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::passkey::service_delete_passkey;
+use crate::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use errors::errors::{ErrorResponse, Errors};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/v0/auth/passkeys/{id}",
+    summary = "Remove one of the authenticated user's passkeys",
+    description = "Deletes the passkey so it can no longer sign in or serve as a second factor. The account owner is emailed a security alert.",
+    params(
+        ("id" = Uuid, Path, description = "Passkey id")
+    ),
+    responses(
+        (status = 204, description = "The passkey was removed"),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 404, description = "Passkey does not exist or does not belong to the current user", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_delete(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    Path(id): Path<Uuid>,
+) -> Result<Response, Errors> {
+    service_delete_passkey(&state.db, &state.worker, session_context.user_id, id).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,28 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::passkey::service_list_passkeys;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::response::PasskeyListResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/auth/passkeys",
+    summary = "List the authenticated user's passkeys",
+    description = "Returns the passkeys registered to the account, newest first, with their names and last use. Credential material is never returned.",
+    responses(
+        (status = 200, description = "Passkeys of the authenticated user", body = PasskeyListResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_list(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<PasskeyListResponse, Errors> {
+    service_list_passkeys(&state.db, session_context.user_id).await
+}
@@ -0,0 +1,128 @@
+use crate::service::auth::passkey::{PasskeyLoginResult, service_passkey_login};
+use crate::state::AppState;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use crate::utils::extract::extract_user_agent::extract_user_agent;
+use axum::http::HeaderMap;
+use axum::response::IntoResponse;
+use axum::{
+    extract::{ConnectInfo, State},
+    response::Response,
+};
+use axum_extra::{TypedHeader, headers::UserAgent};
+use dto::auth::request::PasskeyLoginRequest;
+use dto::auth::response::create_login_response;
+use dto::auth::response::{
+    DEVICE_TOKEN_HEADER, DeviceVerificationRequiredResponse, SessionTokenResponse,
+    device_cookie_name,
+};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+use std::net::SocketAddr;
+use tower_cookies::Cookies;
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/passkeys/login",
+    summary = "Finish a passwordless passkey login",
+    description = "Verifies the assertion for the ceremony from POST /v0/auth/passkeys/login/options and issues the session cookie. The account is identified by the credential's user handle. A passkey is user-verified, so accounts with TOTP enabled are not asked for a code. New-device verification applies as in POST /v0/auth/login.",
+    request_body = PasskeyLoginRequest,
+    responses(
+        (status = 204, description = "Login succeeded and a session cookie was issued"),
+        (status = 202, description = "New-device email verification is required", body = DeviceVerificationRequiredResponse),
+        (status = 400, description = "Validation error, unknown or expired ceremony, or passkeys are not configured", body = ErrorResponse),
+        (status = 401, description = "Unknown credential or the assertion did not verify", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_login(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<PasskeyLoginRequest>,
+) -> Result<Response, Errors> {
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+    // Browser flow: present the device cookie (if any) for new-device verification.
+    let device_token = cookies
+        .get(&device_cookie_name())
+        .map(|c| c.value().to_string());
+
+    let result = service_passkey_login(
+        &state.db,
+        &state.redis_session,
+        &state.worker,
+        &state.nats_client,
+        payload,
+        user_agent,
+        Some(ip_address),
+        device_token,
+    )
+    .await?;
+
+    match result {
+        PasskeyLoginResult::SessionCreated {
+            session_id,
+            remember_me,
+        } => create_login_response(session_id, remember_me),
+        PasskeyLoginResult::DeviceVerificationRequired => {
+            Ok(DeviceVerificationRequiredResponse::new().into_response())
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/app/auth/passkeys/login",
+    summary = "Finish a passwordless passkey login (native-app client)",
+    description = "Native-app variant of POST /v0/auth/passkeys/login. Returns the opaque session token in the response body — for replay as `Authorization: Bearer <token>` — instead of a cookie. The app presents its device-recognition token in the `X-Device-Token` header; an unrecognized (or absent) device returns 202 and emails a challenge to complete via POST /v0/app/auth/device/verify. The app's origin must be one of the server's allowed WebAuthn origins.",
+    request_body = PasskeyLoginRequest,
+    responses(
+        (status = 200, description = "Login succeeded; the session token is returned in the body", body = SessionTokenResponse),
+        (status = 202, description = "New-device email verification is required", body = DeviceVerificationRequiredResponse),
+        (status = 400, description = "Validation error, unknown or expired ceremony, or passkeys are not configured", body = ErrorResponse),
+        (status = 401, description = "Unknown credential or the assertion did not verify", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_login_app(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<PasskeyLoginRequest>,
+) -> Result<Response, Errors> {
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+    // App flow: the device-recognition token arrives in the `X-Device-Token` header (no cookie jar).
+    let device_token = headers
+        .get(DEVICE_TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let result = service_passkey_login(
+        &state.db,
+        &state.redis_session,
+        &state.worker,
+        &state.nats_client,
+        payload,
+        user_agent,
+        Some(ip_address),
+        device_token,
+    )
+    .await?;
+
+    match result {
+        PasskeyLoginResult::SessionCreated { session_id, .. } => {
+            Ok(SessionTokenResponse::new(session_id).into_response())
+        }
+        PasskeyLoginResult::DeviceVerificationRequired => {
+            Ok(DeviceVerificationRequiredResponse::new().into_response())
+        }
+    }
+}
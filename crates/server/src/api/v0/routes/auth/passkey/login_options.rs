@@ -0,0 +1,24 @@
+use crate::service::auth::passkey::service_passkey_login_options;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::response::PasskeyAuthenticationOptionsResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/passkeys/login/options",
+    summary = "Start a passwordless passkey login",
+    description = "Returns `navigator.credentials.get()` options for a discoverable-credential (username-less) login and a single-use ceremony id valid for 5 minutes. Shared by browser and native-app clients; finish with POST /v0/auth/passkeys/login or POST /v0/app/auth/passkeys/login.",
+    responses(
+        (status = 200, description = "Authentication options issued", body = PasskeyAuthenticationOptionsResponse),
+        (status = 400, description = "Passkeys are not configured on this server", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
+        (status = 500, description = "Unexpected session store error", body = ErrorResponse)
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_login_options(
+    State(state): State<AppState>,
+) -> Result<PasskeyAuthenticationOptionsResponse, Errors> {
+    service_passkey_login_options(&state.redis_session).await
+}
@@ -0,0 +1,8 @@
+pub mod delete;
+pub mod list;
+pub mod login;
+pub mod login_options;
+pub mod register;
+pub mod register_options;
+pub mod second_factor;
+pub mod second_factor_options;
@@ -0,0 +1,41 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::passkey::service_passkey_register;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::request::PasskeyRegisterRequest;
+use dto::auth::response::PasskeyResponse;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/passkeys/register",
+    summary = "Finish registering a passkey",
+    description = "Verifies the authenticator's response to the registration options and stores the passkey under the given name. The ceremony id is single-use. The account owner is emailed a security alert.",
+    request_body = PasskeyRegisterRequest,
+    responses(
+        (status = 201, description = "Passkey registered", body = PasskeyResponse),
+        (status = 400, description = "Validation error, unknown or expired ceremony, an attestation that does not verify, or passkeys are not configured", body = ErrorResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 409, description = "The credential is already registered, or the account already has the maximum of 10 passkeys", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_register(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<PasskeyRegisterRequest>,
+) -> Result<PasskeyResponse, Errors> {
+    service_passkey_register(
+        &state.db,
+        &state.redis_session,
+        &state.worker,
+        session_context.user_id,
+        payload,
+    )
+    .await
+}
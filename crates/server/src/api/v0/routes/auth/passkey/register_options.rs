@@ -0,0 +1,30 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::passkey::service_passkey_register_options;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::response::PasskeyRegistrationOptionsResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/passkeys/register/options",
+    summary = "Start registering a passkey",
+    description = "Returns `navigator.credentials.create()` options for the authenticated user and a single-use ceremony id valid for 5 minutes. Credentials already registered to the account are excluded. Pass the result to POST /v0/auth/passkeys/register.",
+    responses(
+        (status = 200, description = "Registration options issued", body = PasskeyRegistrationOptionsResponse),
+        (status = 400, description = "Passkeys are not configured on this server", body = ErrorResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 409, description = "The account already has the maximum of 10 passkeys", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_register_options(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<PasskeyRegistrationOptionsResponse, Errors> {
+    service_passkey_register_options(&state.db, &state.redis_session, session_context.user_id).await
+}
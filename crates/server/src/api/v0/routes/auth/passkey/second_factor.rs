@@ -0,0 +1,87 @@
+use crate::service::auth::passkey::{PasskeyLoginResult, service_passkey_second_factor};
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+use dto::auth::request::PasskeySecondFactorRequest;
+use dto::auth::response::DeviceVerificationRequiredResponse;
+use dto::auth::response::SessionTokenResponse;
+use dto::auth::response::create_login_response;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/passkeys/second-factor",
+    summary = "Finish login with a passkey second factor",
+    description = "Alternative to POST /v0/auth/totp/verify. Consumes the temporary token returned by POST /v0/auth/login and the ceremony from POST /v0/auth/passkeys/second-factor/options, verifies the assertion, and issues the session cookie. New-device verification applies as after a TOTP code.",
+    request_body = PasskeySecondFactorRequest,
+    responses(
+        (status = 204, description = "Passkey verification succeeded and a session cookie was issued"),
+        (status = 202, description = "New-device email verification is required", body = DeviceVerificationRequiredResponse),
+        (status = 400, description = "Validation error, invalid or expired temporary token or ceremony, or passkeys are not configured", body = ErrorResponse),
+        (status = 401, description = "The assertion did not verify", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_second_factor(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<PasskeySecondFactorRequest>,
+) -> Result<Response, Errors> {
+    let result = service_passkey_second_factor(
+        &state.db,
+        &state.redis_session,
+        &state.worker,
+        &state.nats_client,
+        payload,
+    )
+    .await?;
+
+    match result {
+        PasskeyLoginResult::SessionCreated {
+            session_id,
+            remember_me,
+        } => create_login_response(session_id, remember_me),
+        PasskeyLoginResult::DeviceVerificationRequired => {
+            Ok(DeviceVerificationRequiredResponse::new().into_response())
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v0/app/auth/passkeys/second-factor",
+    summary = "Finish login with a passkey second factor (native-app client)",
+    description = "Native-app variant of POST /v0/auth/passkeys/second-factor. Consumes the temporary token returned by POST /v0/app/auth/login and returns the opaque session token in the response body — for replay as `Authorization: Bearer <token>` — instead of a cookie.",
+    request_body = PasskeySecondFactorRequest,
+    responses(
+        (status = 200, description = "Passkey verification succeeded; the session token is returned in the body", body = SessionTokenResponse),
+        (status = 202, description = "New-device email verification is required", body = DeviceVerificationRequiredResponse),
+        (status = 400, description = "Validation error, invalid or expired temporary token or ceremony, or passkeys are not configured", body = ErrorResponse),
+        (status = 401, description = "The assertion did not verify", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_second_factor_app(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<PasskeySecondFactorRequest>,
+) -> Result<Response, Errors> {
+    let result = service_passkey_second_factor(
+        &state.db,
+        &state.redis_session,
+        &state.worker,
+        &state.nats_client,
+        payload,
+    )
+    .await?;
+
+    match result {
+        PasskeyLoginResult::SessionCreated { session_id, .. } => {
+            Ok(SessionTokenResponse::new(session_id).into_response())
+        }
+        PasskeyLoginResult::DeviceVerificationRequired => {
+            Ok(DeviceVerificationRequiredResponse::new().into_response())
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::service::auth::passkey::service_passkey_second_factor_options;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::request::PasskeySecondFactorOptionsRequest;
+use dto::auth::response::PasskeyAuthenticationOptionsResponse;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/passkeys/second-factor/options",
+    summary = "Start a passkey second factor",
+    description = "For a login that returned 202 with `passkey_available: true`, returns `navigator.credentials.get()` options limited to the account's passkeys and a ceremony id bound to the temporary token. The temporary token is not consumed, so POST /v0/auth/totp/verify stays available as a fallback.",
+    request_body = PasskeySecondFactorOptionsRequest,
+    responses(
+        (status = 200, description = "Authentication options issued", body = PasskeyAuthenticationOptionsResponse),
+        (status = 400, description = "Validation error, invalid or expired temporary token, or passkeys are not configured", body = ErrorResponse),
+        (status = 404, description = "The account has no passkeys", body = ErrorResponse),
+        (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
+    ),
+    tag = "Auth - Passkeys"
+)]
+pub async fn passkey_second_factor_options(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<PasskeySecondFactorOptionsRequest>,
+) -> Result<PasskeyAuthenticationOptionsResponse, Errors> {
+    service_passkey_second_factor_options(&state.db, &state.redis_session, &payload.temp_token)
+        .await
+}
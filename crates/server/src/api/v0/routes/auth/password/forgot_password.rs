@@ -16,6 +16,7 @@ use errors::errors::{ErrorResponse, Errors};
     responses(
         (status = 204, description = "Password reset email was queued when the account was eligible"),
         (status = 400, description = "Malformed JSON payload or validation error", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
         (status = 500, description = "Unexpected database or Redis error", body = ErrorResponse),
         (status = 502, description = "Worker service rejected the password reset email job or returned an invalid response", body = ErrorResponse),
         (status = 503, description = "Worker service could not be reached", body = ErrorResponse)
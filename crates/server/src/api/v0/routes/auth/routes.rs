@@ -1,15 +1,32 @@
+use super::access_token::create::access_token_create;
+use super::access_token::delete::access_token_delete;
+use super::access_token::list::access_token_list;
 use super::email::change_email::auth_change_email;
 use super::email::confirm_email_change::auth_confirm_email_change;
 use super::email::resend_verification_email::auth_resend_verification_email;
 use super::email::verify_email::{auth_verify_email, auth_verify_email_app};
+use super::magic_link::request_magic_link::auth_request_magic_link;
+use super::magic_link::verify_magic_link::{auth_verify_magic_link, auth_verify_magic_link_app};
+use super::passkey::delete::passkey_delete;
+use super::passkey::list::passkey_list;
+use super::passkey::login::{passkey_login, passkey_login_app};
+use super::passkey::login_options::passkey_login_options;
+use super::passkey::register::passkey_register;
+use super::passkey::register_options::passkey_register_options;
+use super::passkey::second_factor::{passkey_second_factor, passkey_second_factor_app};
+use super::passkey::second_factor_options::passkey_second_factor_options;
 use super::password::change_password::auth_change_password;
 use super::password::forgot_password::auth_forgot_password;
 use super::password::reset_password::auth_reset_password;
 use super::password::set_initial_password::auth_set_initial_password;
+use super::security::forget_device::auth_forget_device;
+use super::security::list_auth_events::auth_list_events;
+use super::security::list_devices::auth_list_devices;
 use super::session::complete_signup::{auth_complete_signup, auth_complete_signup_app};
 use super::session::list_sessions::auth_list_sessions;
 use super::session::login::{auth_login, auth_login_app};
 use super::session::logout::auth_logout;
+use super::session::revoke_other_sessions::auth_revoke_other_sessions;
 use super::session::revoke_session::auth_revoke_session;
 use super::session::signup::auth_signup;
 use super::session::verify_device::{auth_verify_device, auth_verify_device_app};
@@ -30,11 +47,19 @@ use crate::api::v0::routes::auth::oauth::google::google_one_tap_login::auth_goog
 use crate::api::v0::routes::auth::oauth::google::google_one_tap_nonce::auth_google_one_tap_nonce;
 use crate::api::v0::routes::auth::oauth::google::google_token::auth_google_token_app;
 use crate::api::v0::routes::auth::oauth::list_oauth_connections::list_oauth_connections;
+use crate::api::v0::routes::auth::oauth::oidc::oidc_authorize::auth_oidc_authorize;
+use crate::api::v0::routes::auth::oauth::oidc::oidc_link::auth_oidc_link;
+use crate::api::v0::routes::auth::oauth::oidc::oidc_login::auth_oidc_login;
+use crate::api::v0::routes::auth::oauth::oidc::oidc_token::auth_oidc_token_app;
 use crate::api::v0::routes::auth::oauth::unlink_oauth_connection::unlink_oauth_connection;
+use crate::middleware::rate_limit::{
+    FORGOT_PASSWORD_RATE_LIMIT, LOGIN_RATE_LIMIT, MAGIC_LINK_RATE_LIMIT, RateLimitLayer,
+    SIGNUP_RATE_LIMIT,
+};
 use crate::state::AppState;
 use axum::{Router, routing::delete, routing::get, routing::post};
 
-pub fn auth_routes(_state: AppState) -> Router<AppState> {
+pub fn auth_routes(state: AppState) -> Router<AppState> {
     Router::new()
         // Protected routes (authentication via extractors)
         .route("/auth/logout", post(auth_logout))
@@ -44,6 +69,14 @@ pub fn auth_routes(_state: AppState) -> Router<AppState> {
             "/auth/sessions/{management_id}",
             delete(auth_revoke_session),
         )
+        .route(
+            "/auth/sessions/revoke-others",
+            post(auth_revoke_other_sessions),
+        )
+        // Security center: login history and known devices (require session)
+        .route("/auth/events", get(auth_list_events))
+        .route("/auth/devices", get(auth_list_devices))
+        .route("/auth/devices/{id}", delete(auth_forget_device))
         .route("/auth/oauth/connections", get(list_oauth_connections))
         .route(
             "/auth/oauth/connections/unlink",
@@ -55,6 +88,8 @@ pub fn auth_routes(_state: AppState) -> Router<AppState> {
         // OAuth authorize routes (URL generation)
         .route("/auth/oauth/google/authorize", get(auth_google_authorize))
         .route("/auth/oauth/github/authorize", get(auth_github_authorize))
+        // OpenID Connect providers from `OIDC_PROVIDERS` (the static routes above win over `{provider}`)
+        .route("/auth/oauth/{provider}/authorize", get(auth_oidc_authorize))
         // OAuth login routes (code exchange)
         .route("/auth/oauth/google/login", post(auth_google_login))
         .route(
@@ -66,15 +101,30 @@ pub fn auth_routes(_state: AppState) -> Router<AppState> {
             post(auth_google_one_tap_login),
         )
         .route("/auth/oauth/github/login", post(auth_github_login))
+        .route("/auth/oauth/{provider}/login", post(auth_oidc_login))
         // OAuth complete signup (pending token + handle)
         .route("/auth/complete-signup", post(auth_complete_signup))
         // OAuth link routes (link existing account)
         .route("/auth/oauth/google/link", post(auth_google_link))
         .route("/auth/oauth/github/link", post(auth_github_link))
+        .route("/auth/oauth/{provider}/link", post(auth_oidc_link))
         // Email/password signup route
-        .route("/auth/signup", post(auth_signup))
+        .route(
+            "/auth/signup",
+            post(auth_signup).route_layer(RateLimitLayer::new(&state, &SIGNUP_RATE_LIMIT)),
+        )
         // Email/password login route
-        .route("/auth/login", post(auth_login))
+        .route(
+            "/auth/login",
+            post(auth_login).route_layer(RateLimitLayer::new(&state, &LOGIN_RATE_LIMIT)),
+        )
+        // Magic-link sign-in (public; the emailed token is the proof)
+        .route(
+            "/auth/magic-link",
+            post(auth_request_magic_link)
+                .route_layer(RateLimitLayer::new(&state, &MAGIC_LINK_RATE_LIMIT)),
+        )
+        .route("/auth/magic-link/verify", post(auth_verify_magic_link))
         // Email verification routes (public)
         .route("/auth/verify-email", post(auth_verify_email))
         .route(
@@ -86,6 +136,34 @@ pub fn auth_routes(_state: AppState) -> Router<AppState> {
         .route("/auth/totp/enable", post(totp_enable))
         // TOTP verify route (public, for 2FA login)
         .route("/auth/totp/verify", post(totp_verify))
+        // Personal access tokens (require session)
+        .route(
+            "/auth/tokens",
+            get(access_token_list).post(access_token_create),
+        )
+        .route("/auth/tokens/{id}", delete(access_token_delete))
+        // Passkey management (require session)
+        .route("/auth/passkeys", get(passkey_list))
+        .route("/auth/passkeys/{id}", delete(passkey_delete))
+        .route(
+            "/auth/passkeys/register/options",
+            post(passkey_register_options),
+        )
+        .route("/auth/passkeys/register", post(passkey_register))
+        // Passkey login (public): passwordless, or as the second factor instead of a TOTP code
+        .route(
+            "/auth/passkeys/login/options",
+            post(passkey_login_options).route_layer(RateLimitLayer::new(&state, &LOGIN_RATE_LIMIT)),
+        )
+        .route(
+            "/auth/passkeys/login",
+            post(passkey_login).route_layer(RateLimitLayer::new(&state, &LOGIN_RATE_LIMIT)),
+        )
+        .route(
+            "/auth/passkeys/second-factor/options",
+            post(passkey_second_factor_options),
+        )
+        .route("/auth/passkeys/second-factor", post(passkey_second_factor))
         // New-device sign-in confirmation (public; the emailed token is the proof)
         .route("/auth/device/verify", post(auth_verify_device))
         // TOTP backup codes regeneration (require session)
@@ -94,7 +172,11 @@ pub fn auth_routes(_state: AppState) -> Router<AppState> {
             post(totp_regenerate_backup_codes),
         )
         // Password reset routes
-        .route("/auth/forgot-password", post(auth_forgot_password))
+        .route(
+            "/auth/forgot-password",
+            post(auth_forgot_password)
+                .route_layer(RateLimitLayer::new(&state, &FORGOT_PASSWORD_RATE_LIMIT)),
+        )
         .route("/auth/reset-password", post(auth_reset_password))
         // Password and email change routes (require session)
         .route(
@@ -111,13 +193,32 @@ pub fn auth_routes(_state: AppState) -> Router<AppState> {
         // response body for `Authorization: Bearer` use instead of an HttpOnly cookie. Only the
         // session-minting flows without a browser-cookie dependency are mirrored here; OAuth uses a
         // browser-cookie-bound state/nonce and needs a separate provider-token flow for apps.
-        .route("/app/auth/login", post(auth_login_app))
+        .route(
+            "/app/auth/login",
+            post(auth_login_app).route_layer(RateLimitLayer::new(&state, &LOGIN_RATE_LIMIT)),
+        )
         .route("/app/auth/totp/verify", post(totp_verify_app))
+        .route(
+            "/app/auth/passkeys/login",
+            post(passkey_login_app).route_layer(RateLimitLayer::new(&state, &LOGIN_RATE_LIMIT)),
+        )
+        .route(
+            "/app/auth/passkeys/second-factor",
+            post(passkey_second_factor_app),
+        )
         .route("/app/auth/device/verify", post(auth_verify_device_app))
+        .route(
+            "/app/auth/magic-link/verify",
+            post(auth_verify_magic_link_app),
+        )
         .route("/app/auth/verify-email", post(auth_verify_email_app))
         // Native-app OAuth: provider-token flow (app submits a provider id_token/access_token; the
         // server verifies it directly — no redirect/state/anonymous-cookie binding). Google first.
         .route("/app/auth/oauth/google/token", post(auth_google_token_app))
         .route("/app/auth/oauth/github/token", post(auth_github_token_app))
+        .route(
+            "/app/auth/oauth/{provider}/token",
+            post(auth_oidc_token_app),
+        )
         .route("/app/auth/complete-signup", post(auth_complete_signup_app))
 }
@@ -0,0 +1,56 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::device::service_forget_known_device;
+use crate::state::AppState;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use crate::utils::extract::extract_user_agent::extract_user_agent;
+use axum::{
+    extract::{ConnectInfo, Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::{TypedHeader, headers::UserAgent};
+use errors::errors::{ErrorResponse, Errors};
+use std::net::SocketAddr;
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/v0/auth/devices/{id}",
+    summary = "Forget one of the authenticated user's known devices",
+    description = "Removes the device from the trusted list, so its next sign-in requires new-device email verification again. Sessions already open on the device stay active; revoke them through the session endpoints.",
+    params(
+        ("id" = Uuid, Path, description = "Known device id")
+    ),
+    responses(
+        (status = 204, description = "The device was forgotten"),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 404, description = "Device does not exist or does not belong to the current user", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Security"
+)]
+pub async fn auth_forget_device(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    Path(id): Path<Uuid>,
+) -> Result<Response, Errors> {
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+
+    service_forget_known_device(
+        &state.db,
+        session_context.user_id,
+        id,
+        Some(ip_address),
+        user_agent,
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,33 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::list_auth_events::service_list_auth_events;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::auth::request::ListAuthEventsRequest;
+use dto::auth::response::AuthEventListResponse;
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/auth/events",
+    summary = "List the authenticated user's login history",
+    description = "Pages through the account's authentication events, newest first: sign-ins, failed sign-in attempts, logouts, password, email and 2FA changes, and device and session management. Each entry carries the IP address and User-Agent that triggered it.",
+    params(ListAuthEventsRequest),
+    responses(
+        (status = 200, description = "Authentication events of the authenticated user", body = AuthEventListResponse),
+        (status = 400, description = "Invalid query parameters or validation error", body = ErrorResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Security"
+)]
+pub async fn auth_list_events(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    ValidatedQuery(payload): ValidatedQuery<ListAuthEventsRequest>,
+) -> Result<AuthEventListResponse, Errors> {
+    service_list_auth_events(&state.db, session_context.user_id, payload).await
+}
@@ -0,0 +1,43 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::device::service_list_known_devices;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use dto::auth::response::{DEVICE_TOKEN_HEADER, KnownDeviceListResponse, device_cookie_name};
+use errors::errors::{ErrorResponse, Errors};
+use tower_cookies::Cookies;
+
+#[utoipa::path(
+    get,
+    path = "/v0/auth/devices",
+    summary = "List the authenticated user's known devices",
+    description = "Returns the devices that passed new-device verification and can sign in without an email challenge, most recently seen first. The device that made this request (by its device cookie or `X-Device-Token` header) is marked with is_current=true.",
+    responses(
+        (status = 200, description = "Known devices of the authenticated user", body = KnownDeviceListResponse),
+        (status = 401, description = "Missing, invalid, or expired session", body = ErrorResponse),
+        (status = 500, description = "Unexpected database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth - Security"
+)]
+pub async fn auth_list_devices(
+    headers: HeaderMap,
+    cookies: Cookies,
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<KnownDeviceListResponse, Errors> {
+    // Browsers carry the device token in a cookie, native apps in a header.
+    let device_token = cookies
+        .get(&device_cookie_name())
+        .map(|c| c.value().to_string())
+        .or_else(|| {
+            headers
+                .get(DEVICE_TOKEN_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        });
+
+    service_list_known_devices(&state.db, session_context.user_id, device_token.as_deref()).await
+}
@@ -0,0 +1,3 @@
+pub mod forget_device;
+pub mod list_auth_events;
+pub mod list_devices;
@@ -53,6 +53,7 @@ pub async fn auth_complete_signup(
         &state.db,
         &state.redis_session,
         &state.worker,
+        &state.nats_client,
         &payload.pending_token,
         &payload.handle,
         &payload.display_name,
@@ -102,6 +103,7 @@ pub async fn auth_complete_signup_app(
         &state.db,
         &state.redis_session,
         &state.worker,
+        &state.nats_client,
         &payload.pending_token,
         &payload.handle,
         &payload.display_name,
@@ -25,13 +25,14 @@ use tower_cookies::Cookies;
     post,
     path = "/v0/auth/login",
     summary = "Authenticate with email and password",
-    description = "Validates the submitted credentials. If the account has TOTP enabled, this endpoint returns 202 Accepted with a temporary token for POST /v0/auth/totp/verify instead of creating a session. Otherwise it creates a session immediately and sets the session cookie.",
+    description = "Validates the submitted credentials. If the account has TOTP enabled, this endpoint returns 202 Accepted with a temporary token for POST /v0/auth/totp/verify (or, when `passkey_available` is true, POST /v0/auth/passkeys/second-factor) instead of creating a session. Otherwise it creates a session immediately and sets the session cookie.",
     request_body = LoginRequest,
     responses(
         (status = 204, description = "Login succeeded and a session cookie was issued"),
         (status = 202, description = "TOTP or new-device email verification is required", body = TotpRequiredResponse),
         (status = 400, description = "Malformed JSON payload or validation error", body = ErrorResponse),
         (status = 401, description = "Invalid credentials or this account cannot use password login", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
         (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
     ),
     tag = "Auth"
@@ -56,6 +57,7 @@ pub async fn auth_login(
         &state.db,
         &state.redis_session,
         &state.worker,
+        &state.nats_client,
         payload,
         user_agent,
         Some(ip_address),
@@ -71,9 +73,16 @@ pub async fn auth_login(
             // Return a 204 response that sets the cookie
             create_login_response(session_id, remember_me)
         }
-        LoginResult::TotpRequired(temp_token) => {
+        LoginResult::TotpRequired {
+            temp_token,
+            passkey_available,
+        } => {
             // TOTP required: return 202 + temp_token
-            Ok(TotpRequiredResponse { temp_token }.into_response())
+            Ok(TotpRequiredResponse {
+                temp_token,
+                passkey_available,
+            }
+            .into_response())
         }
         LoginResult::DeviceVerificationRequired => {
             // New device: 202 without a session; a verification email has been sent.
@@ -93,6 +102,7 @@ pub async fn auth_login(
         (status = 202, description = "TOTP or new-device email verification is required", body = TotpRequiredResponse),
         (status = 400, description = "Malformed JSON payload or validation error", body = ErrorResponse),
         (status = 401, description = "Invalid credentials or this account cannot use password login", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
         (status = 500, description = "Unexpected database or session store error", body = ErrorResponse)
     ),
     tag = "Auth"
@@ -117,6 +127,7 @@ pub async fn auth_login_app(
         &state.db,
         &state.redis_session,
         &state.worker,
+        &state.nats_client,
         payload,
         user_agent,
         Some(ip_address),
@@ -129,9 +140,14 @@ pub async fn auth_login_app(
             // App client holds the token itself → return it in the body (no cookie).
             Ok(SessionTokenResponse::new(session_id).into_response())
         }
-        LoginResult::TotpRequired(temp_token) => {
-            Ok(TotpRequiredResponse { temp_token }.into_response())
+        LoginResult::TotpRequired {
+            temp_token,
+            passkey_available,
+        } => Ok(TotpRequiredResponse {
+            temp_token,
+            passkey_available,
         }
+        .into_response()),
         LoginResult::DeviceVerificationRequired => {
             // Unrecognized device: session withheld, challenge emailed. The app completes it via
             // POST /v0/app/auth/device/verify (returns the session + device token in the body).
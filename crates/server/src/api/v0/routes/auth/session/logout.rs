@@ -25,7 +25,13 @@ pub async fn auth_logout(
     RequiredSession(session_context): RequiredSession,
 ) -> Result<Response, Errors> {
     // Handle logout
-    service_logout(&state.redis_session, &session_context.session_id).await?;
+    service_logout(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &session_context,
+    )
+    .await?;
 
     // Return a 204 response that clears the cookie
     create_logout_response()
@@ -2,6 +2,7 @@ pub mod complete_signup;
 pub mod list_sessions;
 pub mod login;
 pub mod logout;
+pub mod revoke_other_sessions;
 pub mod revoke_session;
 pub mod signup;
 pub mod verify_device;
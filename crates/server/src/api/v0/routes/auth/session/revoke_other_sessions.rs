@@ -0,0 +1,49 @@
+use crate::extractors::RequiredSession;
+use crate::service::auth::revoke_other_sessions::service_revoke_other_sessions;
+use crate::state::AppState;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use crate::utils::extract::extract_user_agent::extract_user_agent;
+use axum::extract::{ConnectInfo, State};
+use axum::http::HeaderMap;
+use axum_extra::{TypedHeader, headers::UserAgent};
+use dto::auth::response::RevokeOtherSessionsResponse;
+use errors::errors::{ErrorResponse, Errors};
+use std::net::SocketAddr;
+
+#[utoipa::path(
+    post,
+    path = "/v0/auth/sessions/revoke-others",
+    summary = "Sign out all other sessions",
+    description = "Revokes every active session of the current user except the one used by this request (\"sign out everywhere else\") and returns how many were revoked.",
+    responses(
+        (status = 200, description = "Other sessions were revoked", body = RevokeOtherSessionsResponse),
+        (status = 401, description = "Missing, invalid, or expired session cookie", body = ErrorResponse),
+        (status = 500, description = "Unexpected session store error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Auth"
+)]
+pub async fn auth_revoke_other_sessions(
+    user_agent: Option<TypedHeader<UserAgent>>,
+    headers: HeaderMap,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<RevokeOtherSessionsResponse, Errors> {
+    let user_agent = extract_user_agent(user_agent);
+    let ip_address = extract_ip_address(&headers, addr);
+
+    let revoked = service_revoke_other_sessions(
+        &state.db,
+        &state.redis_session,
+        session_context.user_id,
+        &session_context.session_id,
+        Some(ip_address),
+        user_agent,
+    )
+    .await?;
+
+    Ok(RevokeOtherSessionsResponse { revoked })
+}
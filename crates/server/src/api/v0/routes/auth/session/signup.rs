@@ -15,6 +15,7 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 202, description = "Verification email queued and pending signup stored", body = CreateUserResponse),
         (status = 400, description = "Malformed JSON payload or validation error", body = ErrorResponse),
         (status = 409, description = "The email or handle is already in use or reserved by another pending signup", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
         (status = 500, description = "Unexpected database or Redis error", body = ErrorResponse),
         (status = 502, description = "Worker service rejected the verification email job or returned an invalid response", body = ErrorResponse),
         (status = 503, description = "Worker service could not be reached", body = ErrorResponse),
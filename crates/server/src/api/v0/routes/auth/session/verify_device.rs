@@ -30,8 +30,13 @@ pub async fn auth_verify_device(
     cookies: Cookies,
     ValidatedJson(payload): ValidatedJson<VerifyDeviceRequest>,
 ) -> Result<Response, Errors> {
-    let result =
-        confirm_device_verification(&state.db, &state.redis_session, &payload.token).await?;
+    let result = confirm_device_verification(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &payload.token,
+    )
+    .await?;
 
     // Trust this browser going forward: set the long-lived device cookie (the CookieManager layer
     // writes it as a Set-Cookie alongside the session cookie).
@@ -64,8 +69,13 @@ pub async fn auth_verify_device_app(
     State(state): State<AppState>,
     ValidatedJson(payload): ValidatedJson<VerifyDeviceRequest>,
 ) -> Result<Response, Errors> {
-    let result =
-        confirm_device_verification(&state.db, &state.redis_session, &payload.token).await?;
+    let result = confirm_device_verification(
+        &state.db,
+        &state.redis_session,
+        &state.nats_client,
+        &payload.token,
+    )
+    .await?;
 
     // App holds both tokens itself → return them in the body (no cookies). `remember_me` is
     // irrelevant for apps: there is no cookie to persist, only the server-side session lifetime.
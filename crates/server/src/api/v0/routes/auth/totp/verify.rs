@@ -32,6 +32,7 @@ pub async fn totp_verify(
         &state.db,
         &state.redis_session,
         &state.worker,
+        &state.nats_client,
         &payload.temp_token,
         &payload.code,
     )
@@ -70,6 +71,7 @@ pub async fn totp_verify_app(
         &state.db,
         &state.redis_session,
         &state.worker,
+        &state.nats_client,
         &payload.temp_token,
         &payload.code,
     )
@@ -25,5 +25,5 @@ pub async fn create_board(
     RequiredSession(session): RequiredSession,
     ValidatedJson(payload): ValidatedJson<CreateBoardRequest>,
 ) -> Result<CreateBoardResponse, Errors> {
-    service_create_board(&state.db, payload, &session).await
+    service_create_board(&state.db, &state.nats_client, payload, &session).await
 }
@@ -25,5 +25,12 @@ pub async fn delete_board(
     RequiredSession(session): RequiredSession,
     ValidatedJson(payload): ValidatedJson<DeleteBoardRequest>,
 ) -> Result<DeleteBoardResponse, Errors> {
-    service_delete_board(&state.db, payload.board_id, &session).await
+    service_delete_board(
+        &state.db,
+        &state.r2_revisions,
+        &state.nats_client,
+        payload.board_id,
+        &session,
+    )
+    .await
 }
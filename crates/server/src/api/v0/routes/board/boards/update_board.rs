@@ -26,5 +26,5 @@ pub async fn update_board(
     RequiredSession(session): RequiredSession,
     ValidatedJson(payload): ValidatedJson<UpdateBoardRequest>,
 ) -> Result<UpdateBoardResponse, Errors> {
-    service_update_board(&state.db, payload, &session).await
+    service_update_board(&state.db, &state.nats_client, payload, &session).await
 }
@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::board::service_create_board_comment;
 use crate::state::AppState;
 use crate::utils::extract::extract_ip_address::extract_ip_address;
@@ -22,16 +22,28 @@ use std::net::SocketAddr;
         (status = 404, description = "Post or parent comment not found", body = ErrorResponse),
         (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
     ),
+    security(
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["board:write"])
+    ),
     tag = "Board Comments"
 )]
 pub async fn create_comment(
     headers: HeaderMap,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
-    RequiredSession(session): RequiredSession,
+    ScopedSession(session, ..): ScopedSession<scope::BoardWrite>,
     ValidatedJson(payload): ValidatedJson<CreateBoardCommentRequest>,
 ) -> Result<CreateBoardCommentResponse, Errors> {
     let ip_address = extract_ip_address(&headers, addr);
 
-    service_create_board_comment(&state.db, payload, &session, &ip_address).await
+    service_create_board_comment(
+        &state.db,
+        &state.worker,
+        &state.nats_client,
+        payload,
+        &session,
+        &ip_address,
+    )
+    .await
 }
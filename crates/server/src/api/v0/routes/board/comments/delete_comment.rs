@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::board::service_delete_board_comment;
 use crate::state::AppState;
 use axum::extract::State;
@@ -18,12 +18,24 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 404, description = "Comment not found", body = ErrorResponse),
         (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
     ),
+    security(
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["board:write"])
+    ),
     tag = "Board Comments"
 )]
 pub async fn delete_comment(
     State(state): State<AppState>,
-    RequiredSession(session): RequiredSession,
+    ScopedSession(session, ..): ScopedSession<scope::BoardWrite>,
     ValidatedJson(payload): ValidatedJson<DeleteBoardCommentRequest>,
 ) -> Result<DeleteBoardCommentResponse, Errors> {
-    service_delete_board_comment(&state.db, payload.comment_id, &session).await
+    service_delete_board_comment(
+        &state.db,
+        &state.r2_revisions,
+        &state.worker,
+        &state.nats_client,
+        payload.comment_id,
+        &session,
+    )
+    .await
 }
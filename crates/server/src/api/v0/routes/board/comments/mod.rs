@@ -1,4 +1,8 @@
 pub mod create_comment;
 pub mod delete_comment;
 pub mod get_comments;
+pub mod hide_comment;
+pub mod react_comment;
+pub mod unhide_comment;
+pub mod unreact_comment;
 pub mod update_comment;
@@ -0,0 +1,29 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_unhide_board_comment;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardCommentModerationRequest, BoardCommentModerationResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/comment/unhide",
+    summary = "Unhide a board comment",
+    description = "Restores a hidden comment for all viewers. Requires board moderation permission.",
+    request_body = BoardCommentModerationRequest,
+    responses(
+        (status = 200, description = "Comment unhidden successfully", body = BoardCommentModerationResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = ErrorResponse),
+        (status = 404, description = "Comment not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    tag = "Board Comments"
+)]
+pub async fn unhide_comment(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<BoardCommentModerationRequest>,
+) -> Result<BoardCommentModerationResponse, Errors> {
+    service_unhide_board_comment(&state.db, &state.worker, payload, &session).await
+}
@@ -0,0 +1,31 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_unreact_board_comment;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardCommentReactionRequest, BoardCommentReactionResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/comment/unreact",
+    summary = "Remove a reaction from a board comment",
+    description = "Removes the caller's reaction of the given kind from the comment. Removing a reaction that was never added is a no-op. Returns the comment's updated tallies.",
+    request_body = BoardCommentReactionRequest,
+    responses(
+        (status = 200, description = "Reaction removed", body = BoardCommentReactionResponse),
+        (status = 400, description = "Bad request - Invalid JSON or unknown reaction kind", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 403, description = "Forbidden - User is banned", body = ErrorResponse),
+        (status = 404, description = "Comment or board not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    tag = "Board Comments"
+)]
+pub async fn unreact_comment(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<BoardCommentReactionRequest>,
+) -> Result<BoardCommentReactionResponse, Errors> {
+    service_unreact_board_comment(&state.db, payload, &session).await
+}
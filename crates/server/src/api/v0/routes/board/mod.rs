@@ -1,6 +1,8 @@
 pub mod boards;
 pub mod comments;
 pub mod openapi;
+pub mod parse;
 pub mod permissions;
 pub mod posts;
+pub mod revisions;
 pub mod routes;
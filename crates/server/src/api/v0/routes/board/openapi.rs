@@ -1,14 +1,21 @@
 use dto::board::{
-    BoardCommentListResponse, BoardCommentResponse, BoardListResponse, BoardPermissionsResponse,
-    BoardPostListResponse, BoardPostModerationRequest, BoardPostModerationResponse,
-    BoardPostReorderPinsRequest, BoardPostReorderPinsResponse, BoardPostResponse, BoardResponse,
-    CreateBoardCommentRequest, CreateBoardCommentResponse, CreateBoardPostRequest,
-    CreateBoardPostResponse, CreateBoardRequest, CreateBoardResponse, DeleteBoardCommentRequest,
-    DeleteBoardCommentResponse, DeleteBoardPostRequest, DeleteBoardPostResponse,
-    DeleteBoardRequest, DeleteBoardResponse, GetBoardBySlugRequest, GetBoardCommentsRequest,
-    GetBoardPermissionsRequest, GetBoardPostRequest, GetBoardPostsRequest, GetBoardRequest,
-    GetBoardsRequest, UpdateBoardCommentRequest, UpdateBoardCommentResponse,
-    UpdateBoardPostRequest, UpdateBoardPostResponse, UpdateBoardRequest, UpdateBoardResponse,
+    BoardCommentListResponse, BoardCommentModerationRequest, BoardCommentModerationResponse,
+    BoardCommentReactionRequest, BoardCommentReactionResponse, BoardCommentResponse,
+    BoardListResponse, BoardPermissionsResponse, BoardPostListResponse, BoardPostModerationRequest,
+    BoardPostModerationResponse, BoardPostReactionRequest, BoardPostReactionResponse,
+    BoardPostReorderPinsRequest, BoardPostReorderPinsResponse, BoardPostResponse, BoardPostSort,
+    BoardPostTopWindow, BoardPostWatchRequest, BoardPostWatchResponse, BoardResponse,
+    BoardRevisionDiffResponse, BoardRevisionListResponse, BoardRevisionResponse,
+    BoardRevisionSummaryResponse, CreateBoardCommentRequest, CreateBoardCommentResponse,
+    CreateBoardPostRequest, CreateBoardPostResponse, CreateBoardRequest, CreateBoardResponse,
+    DeleteBoardCommentRequest, DeleteBoardCommentResponse, DeleteBoardPostRequest,
+    DeleteBoardPostResponse, DeleteBoardRequest, DeleteBoardResponse, GetBoardBySlugRequest,
+    GetBoardCommentRevisionsRequest, GetBoardCommentsRequest, GetBoardPermissionsRequest,
+    GetBoardPostRequest, GetBoardPostRevisionsRequest, GetBoardPostsRequest, GetBoardRequest,
+    GetBoardRevisionDiffRequest, GetBoardRevisionRequest, GetBoardsRequest, ParseBoardRequest,
+    ParseBoardResponse, RestoreBoardRevisionRequest, RestoreBoardRevisionResponse,
+    UpdateBoardCommentRequest, UpdateBoardCommentResponse, UpdateBoardPostRequest,
+    UpdateBoardPostResponse, UpdateBoardRequest, UpdateBoardResponse,
 };
 use dto::pagination::CursorDirection;
 use utoipa::OpenApi;
@@ -23,6 +30,7 @@ use utoipa::OpenApi;
         super::boards::update_board::update_board,
         super::boards::delete_board::delete_board,
         super::permissions::get_permissions,
+        super::parse::parse_board,
         super::posts::create_post::create_post,
         super::posts::get_post::get_post,
         super::posts::get_posts::get_posts,
@@ -33,10 +41,25 @@ use utoipa::OpenApi;
         super::posts::reorder_pins::reorder_pins,
         super::posts::lock_post::lock_post,
         super::posts::unlock_post::unlock_post,
+        super::posts::hide_post::hide_post,
+        super::posts::unhide_post::unhide_post,
+        super::posts::react_post::react_post,
+        super::posts::unreact_post::unreact_post,
+        super::posts::watch_post::watch_post,
+        super::posts::unwatch_post::unwatch_post,
         super::comments::create_comment::create_comment,
         super::comments::get_comments::get_comments,
         super::comments::update_comment::update_comment,
         super::comments::delete_comment::delete_comment,
+        super::comments::hide_comment::hide_comment,
+        super::comments::unhide_comment::unhide_comment,
+        super::comments::react_comment::react_comment,
+        super::comments::unreact_comment::unreact_comment,
+        super::revisions::get_post_revisions::get_post_revisions,
+        super::revisions::get_comment_revisions::get_comment_revisions,
+        super::revisions::get_revision::get_revision,
+        super::revisions::diff_revisions::diff_revisions,
+        super::revisions::restore_revision::restore_revision,
     ),
     components(
         schemas(
@@ -53,16 +76,24 @@ use utoipa::OpenApi;
             BoardListResponse,
             GetBoardPermissionsRequest,
             BoardPermissionsResponse,
+            ParseBoardRequest,
+            ParseBoardResponse,
             CreateBoardPostRequest,
             CreateBoardPostResponse,
             GetBoardPostRequest,
             GetBoardPostsRequest,
+            BoardPostSort,
+            BoardPostTopWindow,
             UpdateBoardPostRequest,
             UpdateBoardPostResponse,
             DeleteBoardPostRequest,
             DeleteBoardPostResponse,
             BoardPostModerationRequest,
             BoardPostModerationResponse,
+            BoardPostReactionRequest,
+            BoardPostReactionResponse,
+            BoardPostWatchRequest,
+            BoardPostWatchResponse,
             BoardPostReorderPinsRequest,
             BoardPostReorderPinsResponse,
             BoardPostResponse,
@@ -76,13 +107,28 @@ use utoipa::OpenApi;
             DeleteBoardCommentResponse,
             BoardCommentResponse,
             BoardCommentListResponse,
+            BoardCommentModerationRequest,
+            BoardCommentModerationResponse,
+            BoardCommentReactionRequest,
+            BoardCommentReactionResponse,
+            GetBoardPostRevisionsRequest,
+            GetBoardCommentRevisionsRequest,
+            GetBoardRevisionRequest,
+            GetBoardRevisionDiffRequest,
+            RestoreBoardRevisionRequest,
+            BoardRevisionSummaryResponse,
+            BoardRevisionResponse,
+            BoardRevisionListResponse,
+            BoardRevisionDiffResponse,
+            RestoreBoardRevisionResponse,
             CursorDirection,
         )
     ),
     tags(
         (name = "Boards", description = "Board endpoints"),
         (name = "Board Posts", description = "Board post endpoints"),
-        (name = "Board Comments", description = "Board comment endpoints")
+        (name = "Board Comments", description = "Board comment endpoints"),
+        (name = "Board Revisions", description = "Board post and comment revision history endpoints")
     )
 )]
 pub struct BoardApiDoc;
@@ -0,0 +1,29 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_parse_board;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{ParseBoardRequest, ParseBoardResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/parse",
+    summary = "Preview board markup",
+    description = "Renders post or comment Markdown to sanitized HTML exactly as it would be shown once saved, including @mention links. Nothing is stored.",
+    request_body = ParseBoardRequest,
+    responses(
+        (status = 200, description = "Markup rendered successfully", body = ParseBoardResponse),
+        (status = 400, description = "Bad request - Invalid JSON or validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    tag = "Boards"
+)]
+pub async fn parse_board(
+    State(state): State<AppState>,
+    _session: RequiredSession,
+    ValidatedJson(payload): ValidatedJson<ParseBoardRequest>,
+) -> Result<ParseBoardResponse, Errors> {
+    service_parse_board(&state.db, payload).await
+}
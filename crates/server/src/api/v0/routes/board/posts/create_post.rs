@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::board::service_create_board_post;
 use crate::state::AppState;
 use crate::utils::extract::extract_ip_address::extract_ip_address;
@@ -19,18 +19,31 @@ use std::net::SocketAddr;
         (status = 201, description = "Post created successfully", body = CreateBoardPostResponse),
         (status = 400, description = "Bad request - Invalid JSON or validation error", body = ErrorResponse),
         (status = 403, description = "Forbidden - Insufficient permissions", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
         (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
     ),
+    security(
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["board:write"])
+    ),
     tag = "Board Posts"
 )]
 pub async fn create_post(
     headers: HeaderMap,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
-    RequiredSession(session): RequiredSession,
+    ScopedSession(session, ..): ScopedSession<scope::BoardWrite>,
     ValidatedJson(payload): ValidatedJson<CreateBoardPostRequest>,
 ) -> Result<CreateBoardPostResponse, Errors> {
     let ip_address = extract_ip_address(&headers, addr);
 
-    service_create_board_post(&state.db, payload, &session, &ip_address).await
+    service_create_board_post(
+        &state.db,
+        &state.worker,
+        &state.nats_client,
+        payload,
+        &session,
+        &ip_address,
+    )
+    .await
 }
@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::board::service_delete_board_post;
 use crate::state::AppState;
 use axum::extract::State;
@@ -18,12 +18,24 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 404, description = "Post not found", body = ErrorResponse),
         (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
     ),
+    security(
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["board:write"])
+    ),
     tag = "Board Posts"
 )]
 pub async fn delete_post(
     State(state): State<AppState>,
-    RequiredSession(session): RequiredSession,
+    ScopedSession(session, ..): ScopedSession<scope::BoardWrite>,
     ValidatedJson(payload): ValidatedJson<DeleteBoardPostRequest>,
 ) -> Result<DeleteBoardPostResponse, Errors> {
-    service_delete_board_post(&state.db, payload.post_id, &session).await
+    service_delete_board_post(
+        &state.db,
+        &state.r2_revisions,
+        &state.worker,
+        &state.nats_client,
+        payload.post_id,
+        &session,
+    )
+    .await
 }
@@ -10,7 +10,7 @@ use errors::errors::{ErrorResponse, Errors};
     get,
     path = "/v0/board/post/list",
     summary = "List board posts",
-    description = "Returns posts from the requested board, applying filters and pagination parameters.",
+    description = "Returns posts from the requested board with keyset (cursor) pagination: pass the first or last post of a page as `cursor_id` to read above or below it. `sort` orders the unpinned posts (`new`, `top` within `window`, `hot`, `most_commented`); pinned posts are always returned separately, in pin order.",
     params(GetBoardPostsRequest),
     responses(
         (status = 200, description = "Post list retrieved successfully", body = BoardPostListResponse),
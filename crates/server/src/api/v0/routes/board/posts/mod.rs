@@ -2,9 +2,15 @@ pub mod create_post;
 pub mod delete_post;
 pub mod get_post;
 pub mod get_posts;
+pub mod hide_post;
 pub mod lock_post;
 pub mod pin_post;
+pub mod react_post;
 pub mod reorder_pins;
+pub mod unhide_post;
 pub mod unlock_post;
 pub mod unpin_post;
+pub mod unreact_post;
+pub mod unwatch_post;
 pub mod update_post;
+pub mod watch_post;
@@ -0,0 +1,31 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_react_board_post;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardPostReactionRequest, BoardPostReactionResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/post/react",
+    summary = "React to a board post",
+    description = "Adds the caller's reaction of the given kind to the post. Kinds come from the server's BOARD_REACTION_KINDS; reacting twice with the same kind is a no-op. Returns the post's updated tallies.",
+    request_body = BoardPostReactionRequest,
+    responses(
+        (status = 200, description = "Reaction recorded", body = BoardPostReactionResponse),
+        (status = 400, description = "Bad request - Invalid JSON or unknown reaction kind", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 403, description = "Forbidden - User is banned", body = ErrorResponse),
+        (status = 404, description = "Post or board not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    tag = "Board Posts"
+)]
+pub async fn react_post(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<BoardPostReactionRequest>,
+) -> Result<BoardPostReactionResponse, Errors> {
+    service_react_board_post(&state.db, payload, &session).await
+}
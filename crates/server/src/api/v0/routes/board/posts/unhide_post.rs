@@ -0,0 +1,29 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_unhide_board_post;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardPostModerationRequest, BoardPostModerationResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/post/unhide",
+    summary = "Unhide a board post",
+    description = "Restores a hidden post for all viewers. Requires board moderation permission.",
+    request_body = BoardPostModerationRequest,
+    responses(
+        (status = 200, description = "Post unhidden successfully", body = BoardPostModerationResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = ErrorResponse),
+        (status = 404, description = "Post not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    tag = "Board Posts"
+)]
+pub async fn unhide_post(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<BoardPostModerationRequest>,
+) -> Result<BoardPostModerationResponse, Errors> {
+    service_unhide_board_post(&state.db, &state.worker, payload, &session).await
+}
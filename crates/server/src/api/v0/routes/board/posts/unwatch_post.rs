@@ -0,0 +1,31 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_unwatch_board_post;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardPostWatchRequest, BoardPostWatchResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/post/unwatch",
+    summary = "Unwatch a board post",
+    description = "Stops delivering new comments on the post to the caller's inbox, including on their own posts. Unwatching a post that is not watched is a no-op.",
+    request_body = BoardPostWatchRequest,
+    responses(
+        (status = 200, description = "Post unwatched", body = BoardPostWatchResponse),
+        (status = 400, description = "Bad request - Invalid JSON", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Board is not viewable", body = ErrorResponse),
+        (status = 404, description = "Post or board not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    tag = "Board Posts"
+)]
+pub async fn unwatch_post(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<BoardPostWatchRequest>,
+) -> Result<BoardPostWatchResponse, Errors> {
+    service_unwatch_board_post(&state.db, payload, &session).await
+}
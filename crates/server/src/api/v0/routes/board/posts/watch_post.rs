@@ -0,0 +1,31 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_watch_board_post;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardPostWatchRequest, BoardPostWatchResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/post/watch",
+    summary = "Watch a board post",
+    description = "Subscribes the caller to the post: every new comment on it is delivered to their inbox as `board_comment_created` (subject to their notification preferences). Authors watch their own posts automatically; watching twice is a no-op.",
+    request_body = BoardPostWatchRequest,
+    responses(
+        (status = 200, description = "Post watched", body = BoardPostWatchResponse),
+        (status = 400, description = "Bad request - Invalid JSON", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Board is not viewable", body = ErrorResponse),
+        (status = 404, description = "Post or board not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    tag = "Board Posts"
+)]
+pub async fn watch_post(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<BoardPostWatchRequest>,
+) -> Result<BoardPostWatchResponse, Errors> {
+    service_watch_board_post(&state.db, payload, &session).await
+}
@@ -0,0 +1,29 @@
+use crate::extractors::OptionalSession;
+use crate::service::board::service_diff_board_revisions;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardRevisionDiffResponse, GetBoardRevisionDiffRequest};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/board/revision/diff",
+    summary = "Diff two board revisions",
+    description = "Returns a unified line diff between two revisions of the same post or comment, or between a revision and the current body when to_revision_id is omitted.",
+    params(GetBoardRevisionDiffRequest),
+    responses(
+        (status = 200, description = "Diff computed successfully", body = BoardRevisionDiffResponse),
+        (status = 400, description = "Bad request - The revisions belong to different posts or comments", body = ErrorResponse),
+        (status = 404, description = "Revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or storage error", body = ErrorResponse)
+    ),
+    tag = "Board Revisions"
+)]
+pub async fn diff_revisions(
+    State(state): State<AppState>,
+    OptionalSession(session): OptionalSession,
+    ValidatedQuery(payload): ValidatedQuery<GetBoardRevisionDiffRequest>,
+) -> Result<BoardRevisionDiffResponse, Errors> {
+    service_diff_board_revisions(&state.db, &state.r2_revisions, payload, session.as_ref()).await
+}
@@ -0,0 +1,29 @@
+use crate::extractors::OptionalSession;
+use crate::service::board::service_list_board_comment_revisions;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardRevisionListResponse, GetBoardCommentRevisionsRequest};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/board/comment/revisions",
+    summary = "List comment revisions",
+    description = "Returns the superseded bodies of a comment, newest first. Each edit snapshots the body it replaced.",
+    params(GetBoardCommentRevisionsRequest),
+    responses(
+        (status = 200, description = "Revision list retrieved successfully", body = BoardRevisionListResponse),
+        (status = 400, description = "Bad request - Invalid query parameters or validation error", body = ErrorResponse),
+        (status = 404, description = "Comment not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    tag = "Board Revisions"
+)]
+pub async fn get_comment_revisions(
+    State(state): State<AppState>,
+    OptionalSession(session): OptionalSession,
+    ValidatedQuery(payload): ValidatedQuery<GetBoardCommentRevisionsRequest>,
+) -> Result<BoardRevisionListResponse, Errors> {
+    service_list_board_comment_revisions(&state.db, payload, session.as_ref()).await
+}
@@ -0,0 +1,34 @@
+use crate::extractors::OptionalSession;
+use crate::service::board::service_get_board_revision;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{BoardRevisionResponse, GetBoardRevisionRequest};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/board/revision",
+    summary = "Get a board revision",
+    description = "Returns one superseded post or comment body with its metadata.",
+    params(GetBoardRevisionRequest),
+    responses(
+        (status = 200, description = "Revision retrieved successfully", body = BoardRevisionResponse),
+        (status = 404, description = "Revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or storage error", body = ErrorResponse)
+    ),
+    tag = "Board Revisions"
+)]
+pub async fn get_revision(
+    State(state): State<AppState>,
+    OptionalSession(session): OptionalSession,
+    ValidatedQuery(payload): ValidatedQuery<GetBoardRevisionRequest>,
+) -> Result<BoardRevisionResponse, Errors> {
+    service_get_board_revision(
+        &state.db,
+        &state.r2_revisions,
+        payload.revision_id,
+        session.as_ref(),
+    )
+    .await
+}
@@ -0,0 +1,5 @@
+pub mod diff_revisions;
+pub mod get_comment_revisions;
+pub mod get_post_revisions;
+pub mod get_revision;
+pub mod restore_revision;
@@ -0,0 +1,39 @@
+use crate::extractors::RequiredSession;
+use crate::service::board::service_restore_board_revision;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::board::{RestoreBoardRevisionRequest, RestoreBoardRevisionResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/board/revision/restore",
+    summary = "Restore a board revision",
+    description = "Replaces the current post or comment body with an earlier revision. The replaced body is kept as a new revision. Requires board moderation permission and is recorded in the moderation log.",
+    request_body = RestoreBoardRevisionRequest,
+    responses(
+        (status = 200, description = "Revision restored successfully", body = RestoreBoardRevisionResponse),
+        (status = 400, description = "Bad request - Invalid JSON or validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or missing session", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = ErrorResponse),
+        (status = 404, description = "Revision not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or storage error", body = ErrorResponse)
+    ),
+    tag = "Board Revisions"
+)]
+pub async fn restore_revision(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<RestoreBoardRevisionRequest>,
+) -> Result<RestoreBoardRevisionResponse, Errors> {
+    service_restore_board_revision(
+        &state.db,
+        &state.r2_revisions,
+        &state.redis_cache,
+        &state.worker,
+        payload,
+        &session,
+    )
+    .await
+}
@@ -1,3 +1,4 @@
+use crate::middleware::rate_limit::{POST_CREATE_RATE_LIMIT, RateLimitLayer};
 use crate::state::AppState;
 use axum::{
     Router,
@@ -13,25 +14,45 @@ use super::boards::update_board::update_board;
 use super::comments::create_comment::create_comment;
 use super::comments::delete_comment::delete_comment;
 use super::comments::get_comments::get_comments;
+use super::comments::hide_comment::hide_comment;
+use super::comments::react_comment::react_comment;
+use super::comments::unhide_comment::unhide_comment;
+use super::comments::unreact_comment::unreact_comment;
 use super::comments::update_comment::update_comment;
+use super::parse::parse_board;
 use super::permissions::get_permissions;
 use super::posts::create_post::create_post;
 use super::posts::delete_post::delete_post;
 use super::posts::get_post::get_post;
 use super::posts::get_posts::get_posts;
+use super::posts::hide_post::hide_post;
 use super::posts::lock_post::lock_post;
 use super::posts::pin_post::pin_post;
+use super::posts::react_post::react_post;
 use super::posts::reorder_pins::reorder_pins;
+use super::posts::unhide_post::unhide_post;
 use super::posts::unlock_post::unlock_post;
 use super::posts::unpin_post::unpin_post;
+use super::posts::unreact_post::unreact_post;
+use super::posts::unwatch_post::unwatch_post;
 use super::posts::update_post::update_post;
+use super::posts::watch_post::watch_post;
+use super::revisions::diff_revisions::diff_revisions;
+use super::revisions::get_comment_revisions::get_comment_revisions;
+use super::revisions::get_post_revisions::get_post_revisions;
+use super::revisions::get_revision::get_revision;
+use super::revisions::restore_revision::restore_revision;
 
-pub fn board_routes() -> Router<AppState> {
+pub fn board_routes(state: AppState) -> Router<AppState> {
     let protected_routes = Router::new()
         .route("/board", post(create_board))
         .route("/board/update", post(update_board))
         .route("/board/delete", post(delete_board))
-        .route("/board/post", post(create_post))
+        .route("/board/parse", post(parse_board))
+        .route(
+            "/board/post",
+            post(create_post).route_layer(RateLimitLayer::new(&state, &POST_CREATE_RATE_LIMIT)),
+        )
         .route("/board/post/update", post(update_post))
         .route("/board/post/delete", post(delete_post))
         .route("/board/post/pin", post(pin_post))
@@ -39,9 +60,20 @@ pub fn board_routes() -> Router<AppState> {
         .route("/board/post/reorder-pins", post(reorder_pins))
         .route("/board/post/lock", post(lock_post))
         .route("/board/post/unlock", post(unlock_post))
+        .route("/board/post/hide", post(hide_post))
+        .route("/board/post/unhide", post(unhide_post))
+        .route("/board/post/react", post(react_post))
+        .route("/board/post/unreact", post(unreact_post))
+        .route("/board/post/watch", post(watch_post))
+        .route("/board/post/unwatch", post(unwatch_post))
         .route("/board/comment", post(create_comment))
         .route("/board/comment/update", post(update_comment))
-        .route("/board/comment/delete", post(delete_comment));
+        .route("/board/comment/delete", post(delete_comment))
+        .route("/board/comment/hide", post(hide_comment))
+        .route("/board/comment/unhide", post(unhide_comment))
+        .route("/board/comment/react", post(react_comment))
+        .route("/board/comment/unreact", post(unreact_comment))
+        .route("/board/revision/restore", post(restore_revision));
 
     let public_routes = Router::new()
         .route("/board", get(get_board))
@@ -50,7 +82,11 @@ pub fn board_routes() -> Router<AppState> {
         .route("/board/permissions", get(get_permissions))
         .route("/board/post", get(get_post))
         .route("/board/post/list", get(get_posts))
-        .route("/board/comment/list", get(get_comments));
+        .route("/board/comment/list", get(get_comments))
+        .route("/board/post/revisions", get(get_post_revisions))
+        .route("/board/comment/revisions", get(get_comment_revisions))
+        .route("/board/revision", get(get_revision))
+        .route("/board/revision/diff", get(diff_revisions));
 
     protected_routes.merge(public_routes)
 }
@@ -5,7 +5,9 @@ mod groups;
 mod moderation;
 mod notification;
 pub mod openapi;
+mod report;
 pub mod routes;
 mod search;
 mod stream;
 mod user;
+mod webhooks;
@@ -0,0 +1,30 @@
+use crate::extractors::RequiredSession;
+use crate::service::report::service_dismiss_report;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::report::{DismissReportRequest, ReportResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/moderation/reports/dismiss",
+    summary = "Dismiss a report",
+    description = "Closes an open report without action. The dismissal is recorded as a `report:dismiss` moderation log entry, which the report links to.",
+    request_body = DismissReportRequest,
+    responses(
+        (status = 200, description = "Report dismissed successfully", body = ReportResponse),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 404, description = "Report not found", body = ErrorResponse),
+        (status = 409, description = "Conflict - Report is already closed", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    tag = "Moderation"
+)]
+pub async fn dismiss_report(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<DismissReportRequest>,
+) -> Result<ReportResponse, Errors> {
+    service_dismiss_report(&state.db, payload, &session).await
+}
@@ -0,0 +1,24 @@
+use crate::service::report::service_list_reports;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::report::{ListReportsRequest, ListReportsResponse};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::Errors;
+
+#[utoipa::path(
+    get,
+    path = "/v0/moderation/reports",
+    params(ListReportsRequest),
+    responses(
+        (status = 200, description = "Reports retrieved successfully", body = ListReportsResponse),
+        (status = 400, description = "Bad request - Invalid query parameters or validation error"),
+        (status = 500, description = "Internal Server Error - Database error")
+    ),
+    tag = "Moderation"
+)]
+pub async fn list_reports(
+    State(state): State<AppState>,
+    ValidatedQuery(payload): ValidatedQuery<ListReportsRequest>,
+) -> Result<ListReportsResponse, Errors> {
+    service_list_reports(&state.db, payload).await
+}
@@ -1,3 +1,6 @@
+pub mod dismiss_report;
 pub mod list_logs;
+pub mod list_reports;
 pub mod openapi;
+pub mod resolve_report;
 pub mod routes;
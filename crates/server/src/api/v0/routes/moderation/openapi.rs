@@ -1,20 +1,32 @@
 use dto::moderation::{
     ListModerationLogsRequest, ListModerationLogsResponse, ModerationLogListItem,
 };
+use dto::report::{
+    DismissReportRequest, ListReportsRequest, ListReportsResponse, ReportListItem,
+    ResolveReportRequest,
+};
 use utoipa::OpenApi;
 
+use super::dismiss_report::__path_dismiss_report;
 use super::list_logs::__path_list_moderation_logs;
+use super::list_reports::__path_list_reports;
+use super::resolve_report::__path_resolve_report;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(list_moderation_logs),
+    paths(list_moderation_logs, list_reports, resolve_report, dismiss_report),
     components(schemas(
         ListModerationLogsRequest,
         ModerationLogListItem,
         ListModerationLogsResponse,
+        ListReportsRequest,
+        ReportListItem,
+        ListReportsResponse,
+        ResolveReportRequest,
+        DismissReportRequest,
     )),
     tags(
-        (name = "Moderation", description = "Moderation logs")
+        (name = "Moderation", description = "Moderation logs and the report queue")
     )
 )]
 pub struct ModerationOpenApi;
@@ -0,0 +1,30 @@
+use crate::extractors::RequiredSession;
+use crate::service::report::service_resolve_report;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::report::{ReportResponse, ResolveReportRequest};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/moderation/reports/resolve",
+    summary = "Resolve a report",
+    description = "Closes an open report as resolved, linking the moderation log entry of the action taken on the reported target.",
+    request_body = ResolveReportRequest,
+    responses(
+        (status = 200, description = "Report resolved successfully", body = ReportResponse),
+        (status = 400, description = "Bad request - Validation error or moderation log does not act on the target", body = ErrorResponse),
+        (status = 404, description = "Report not found", body = ErrorResponse),
+        (status = 409, description = "Conflict - Report is already closed", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    tag = "Moderation"
+)]
+pub async fn resolve_report(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<ResolveReportRequest>,
+) -> Result<ReportResponse, Errors> {
+    service_resolve_report(&state.db, payload, &session).await
+}
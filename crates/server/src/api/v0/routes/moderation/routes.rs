@@ -1,13 +1,22 @@
 use crate::middleware::require_role::require_mod;
 use crate::state::AppState;
 use axum::middleware::from_fn_with_state;
-use axum::{Router, routing::get};
+use axum::{
+    Router,
+    routing::{get, post},
+};
 
+use super::dismiss_report::dismiss_report;
 use super::list_logs::list_moderation_logs;
+use super::list_reports::list_reports;
+use super::resolve_report::resolve_report;
 
 pub fn moderation_routes(state: AppState) -> Router<AppState> {
     Router::new()
         .route("/moderation/logs", get(list_moderation_logs))
+        .route("/moderation/reports", get(list_reports))
+        .route("/moderation/reports/resolve", post(resolve_report))
+        .route("/moderation/reports/dismiss", post(dismiss_report))
         // Router-boundary gate: every moderation route requires at least the Mod role. Keeping the
         // check here (not in each handler) makes "moderation is privileged" a single, greppable
         // property of the route table.
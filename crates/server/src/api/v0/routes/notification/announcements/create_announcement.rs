@@ -0,0 +1,33 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::announcements::service_create_system_announcement;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::notification::{CreateSystemAnnouncementRequest, SystemAnnouncementResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/announcements",
+    summary = "Broadcast a system announcement",
+    description = "Admin only. Stores the announcement as one `system_announcement` notification event and queues a worker fan-out to every active user's inbox. Users who turned `system_announcement` off are skipped unless `mandatory` is set. Records a `notification:announce` moderation log entry.",
+    request_body = CreateSystemAnnouncementRequest,
+    responses(
+        (status = 201, description = "Announcement stored and fan-out queued", body = SystemAnnouncementResponse),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or worker queue error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn create_system_announcement(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<CreateSystemAnnouncementRequest>,
+) -> Result<SystemAnnouncementResponse, Errors> {
+    service_create_system_announcement(&state.db, &state.worker, &session, payload).await
+}
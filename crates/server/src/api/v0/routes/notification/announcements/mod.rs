@@ -0,0 +1,2 @@
+pub mod create_announcement;
+pub mod retract_announcement;
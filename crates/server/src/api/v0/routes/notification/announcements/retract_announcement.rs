@@ -0,0 +1,36 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::announcements::service_retract_system_announcement;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use dto::notification::RetractSystemAnnouncementRequest;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/announcements/retract",
+    summary = "Retract a system announcement",
+    description = "Admin only. Stops a fan-out still in progress and deletes every unread copy of the announcement; copies already read are kept. Records a `notification:retract_announcement` moderation log entry.",
+    request_body = RetractSystemAnnouncementRequest,
+    responses(
+        (status = 204, description = "Announcement retracted"),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Not Found - No announcement with this id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn retract_system_announcement(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<RetractSystemAnnouncementRequest>,
+) -> Result<StatusCode, Errors> {
+    service_retract_system_announcement(&state.db, &state.nats_client, &session, payload).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::notification::messages::count_unread::service_count_unread_notifications;
 use crate::state::AppState;
 use axum::extract::State;
@@ -16,13 +16,14 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
     ),
     security(
-        ("session_id_cookie" = [])
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["notifications:read"])
     ),
     tag = "Notifications"
 )]
 pub async fn count_unread_notifications(
     State(state): State<AppState>,
-    RequiredSession(session_context): RequiredSession,
+    ScopedSession(session_context, ..): ScopedSession<scope::NotificationsRead>,
 ) -> Result<UnreadCountResponse, Errors> {
     service_count_unread_notifications(&state.db, &session_context).await
 }
@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::notification::messages::get_notifications::service_get_notifications;
 use crate::state::AppState;
 use axum::extract::State;
@@ -19,13 +19,14 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
     ),
     security(
-        ("session_id_cookie" = [])
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["notifications:read"])
     ),
     tag = "Notifications"
 )]
 pub async fn get_notifications(
     State(state): State<AppState>,
-    RequiredSession(session_context): RequiredSession,
+    ScopedSession(session_context, ..): ScopedSession<scope::NotificationsRead>,
     ValidatedQuery(payload): ValidatedQuery<GetNotificationsRequest>,
 ) -> Result<NotificationListResponse, Errors> {
     service_get_notifications(&state.db, &session_context, payload).await
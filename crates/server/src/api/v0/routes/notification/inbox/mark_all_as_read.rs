@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::notification::messages::mark_all_as_read::service_mark_all_notifications_as_read;
 use crate::state::AppState;
 use axum::extract::State;
@@ -16,14 +16,15 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
     ),
     security(
-        ("session_id_cookie" = [])
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["notifications:write"])
     ),
     tag = "Notifications"
 )]
 pub async fn mark_all_notifications_as_read(
     State(state): State<AppState>,
-    RequiredSession(session_context): RequiredSession,
+    ScopedSession(session_context, ..): ScopedSession<scope::NotificationsWrite>,
 ) -> Result<StatusCode, Errors> {
-    service_mark_all_notifications_as_read(&state.db, &session_context).await?;
+    service_mark_all_notifications_as_read(&state.db, &state.nats_client, &session_context).await?;
     Ok(StatusCode::NO_CONTENT)
 }
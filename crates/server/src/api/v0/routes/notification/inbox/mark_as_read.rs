@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::notification::messages::mark_notification_as_read::service_mark_notification_as_read;
 use crate::state::AppState;
 use axum::extract::State;
@@ -21,15 +21,22 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
     ),
     security(
-        ("session_id_cookie" = [])
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["notifications:write"])
     ),
     tag = "Notifications"
 )]
 pub async fn mark_notification_as_read(
     State(state): State<AppState>,
-    RequiredSession(session_context): RequiredSession,
+    ScopedSession(session_context, ..): ScopedSession<scope::NotificationsWrite>,
     ValidatedJson(payload): ValidatedJson<MarkNotificationAsReadRequest>,
 ) -> Result<StatusCode, Errors> {
-    service_mark_notification_as_read(&state.db, &session_context, payload.notification_id).await?;
+    service_mark_notification_as_read(
+        &state.db,
+        &state.nats_client,
+        &session_context,
+        payload.notification_id,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
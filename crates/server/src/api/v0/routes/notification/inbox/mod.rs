@@ -3,3 +3,4 @@ pub mod delete_notification;
 pub mod get_notifications;
 pub mod mark_all_as_read;
 pub mod mark_as_read;
+pub mod stream_notifications;
@@ -0,0 +1,39 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::messages::stream_notifications::service_stream_notifications;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use errors::errors::{ErrorResponse, Errors};
+use futures::Stream;
+use std::convert::Infallible;
+use std::time::Duration;
+
+#[utoipa::path(
+    get,
+    path = "/v0/notifications/stream",
+    summary = "Stream notifications",
+    description = "Server-Sent Events stream for the current authenticated user. Sends an `unread_count` event on connect, then a `notification` event (a `NotificationResponse`) for each new notification and an `unread_count` event (an `UnreadCountResponse`) whenever the count may have changed. The stream ends once the session is logged out, revoked or expires.",
+    responses(
+        (status = 200, description = "SSE stream of notifications and unread counts", content_type = "text/event-stream"),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse),
+        (status = 503, description = "Service Unavailable - Realtime subscription failed", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn stream_notifications(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, Errors> {
+    let stream = service_stream_notifications(
+        state.db.clone(),
+        state.redis_session.clone(),
+        &state.nats_client,
+        &session_context,
+    )
+    .await?;
+    Ok(Sse::new(stream).keep_alive(KeepAlive::new().interval(Duration::from_secs(15))))
+}
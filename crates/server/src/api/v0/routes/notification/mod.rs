@@ -1,4 +1,6 @@
+pub mod announcements;
 pub mod inbox;
 pub mod openapi;
 pub mod preferences;
+pub mod push;
 pub mod routes;
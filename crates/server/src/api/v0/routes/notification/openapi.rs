@@ -1,11 +1,15 @@
 use dto::notification::{
-    DeleteNotificationRequest, GetNotificationsRequest, MarkNotificationAsReadRequest,
-    NotificationActionPreferenceListResponse, NotificationActionPreferenceResponse,
-    NotificationListResponse, NotificationPreferenceResponse, NotificationResponse,
-    UnreadCountResponse, UpdateActionPreferenceRequest, UpdateActionPreferencesBulkRequest,
-    UpdateNotificationPreferenceRequest,
+    CreateSystemAnnouncementRequest, DeleteNotificationRequest, GetNotificationsRequest,
+    MarkNotificationAsReadRequest, NotificationActionPreferenceListResponse,
+    NotificationActionPreferenceResponse, NotificationListResponse, NotificationPreferenceResponse,
+    NotificationResponse, PushSubscriptionKeys, PushSubscriptionListResponse,
+    PushSubscriptionResponse, RetractSystemAnnouncementRequest, SubscribePushRequest,
+    SystemAnnouncementResponse, UnreadCountResponse, UnsubscribeNotificationEmailRequest,
+    UnsubscribePushRequest, UpdateActionPreferenceRequest, UpdateActionPreferencesBulkRequest,
+    UpdateNotificationPreferenceRequest, VapidPublicKeyResponse,
 };
 use dto::pagination::CursorDirection;
+use entity::common::NotificationDigestFrequency;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
@@ -16,10 +20,18 @@ use utoipa::OpenApi;
         super::inbox::mark_as_read::mark_notification_as_read,
         super::inbox::mark_all_as_read::mark_all_notifications_as_read,
         super::inbox::delete_notification::delete_notification,
+        super::inbox::stream_notifications::stream_notifications,
         super::preferences::get_preferences::get_notification_preferences,
         super::preferences::update_preferences::update_notification_preferences,
         super::preferences::get_action_preferences::get_notification_action_preferences,
         super::preferences::update_action_preferences_bulk::update_notification_action_preferences_bulk,
+        super::preferences::unsubscribe_email::unsubscribe_notification_email,
+        super::push::get_vapid_public_key::get_vapid_public_key,
+        super::push::get_push_subscriptions::get_push_subscriptions,
+        super::push::subscribe_push::subscribe_push,
+        super::push::unsubscribe_push::unsubscribe_push,
+        super::announcements::create_announcement::create_system_announcement,
+        super::announcements::retract_announcement::retract_system_announcement,
     ),
     components(
         schemas(
@@ -32,10 +44,21 @@ use utoipa::OpenApi;
             DeleteNotificationRequest,
             NotificationPreferenceResponse,
             UpdateNotificationPreferenceRequest,
+            NotificationDigestFrequency,
             NotificationActionPreferenceListResponse,
             NotificationActionPreferenceResponse,
             UpdateActionPreferencesBulkRequest,
             UpdateActionPreferenceRequest,
+            UnsubscribeNotificationEmailRequest,
+            VapidPublicKeyResponse,
+            PushSubscriptionListResponse,
+            PushSubscriptionResponse,
+            SubscribePushRequest,
+            PushSubscriptionKeys,
+            UnsubscribePushRequest,
+            CreateSystemAnnouncementRequest,
+            RetractSystemAnnouncementRequest,
+            SystemAnnouncementResponse,
         )
     ),
     tags(
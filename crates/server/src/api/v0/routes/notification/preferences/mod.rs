@@ -1,4 +1,5 @@
 pub mod get_action_preferences;
 pub mod get_preferences;
+pub mod unsubscribe_email;
 pub mod update_action_preferences_bulk;
 pub mod update_preferences;
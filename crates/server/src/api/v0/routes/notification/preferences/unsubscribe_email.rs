@@ -0,0 +1,29 @@
+use crate::service::notification::preferences::unsubscribe_email::service_unsubscribe_notification_email;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use dto::notification::UnsubscribeNotificationEmailRequest;
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/email/unsubscribe",
+    summary = "Unsubscribe from notification emails",
+    description = "Turns off email notifications for the account owning the token from a notification email. No session required; this is the RFC 8058 one-click `List-Unsubscribe-Post` target, so any request body is ignored.",
+    params(UnsubscribeNotificationEmailRequest),
+    responses(
+        (status = 204, description = "Notification emails turned off"),
+        (status = 400, description = "Bad request - Missing or malformed token", body = ErrorResponse),
+        (status = 404, description = "Not Found - Unknown unsubscribe token", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    tag = "Notifications"
+)]
+pub async fn unsubscribe_notification_email(
+    State(state): State<AppState>,
+    ValidatedQuery(payload): ValidatedQuery<UnsubscribeNotificationEmailRequest>,
+) -> Result<StatusCode, Errors> {
+    service_unsubscribe_notification_email(&state.db, payload.token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
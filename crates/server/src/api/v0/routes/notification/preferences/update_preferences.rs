@@ -33,6 +33,7 @@ pub async fn update_notification_preferences(
         &session_context,
         payload.email_enabled,
         payload.push_enabled,
+        payload.digest_frequency,
     )
     .await
 }
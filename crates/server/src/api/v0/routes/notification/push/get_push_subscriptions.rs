@@ -0,0 +1,28 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::push::service_get_push_subscriptions;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::notification::PushSubscriptionListResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/notifications/push/subscriptions",
+    summary = "List Web Push devices",
+    description = "Returns the authenticated user's registered Web Push subscriptions, newest first.",
+    responses(
+        (status = 200, description = "Push subscriptions retrieved successfully", body = PushSubscriptionListResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn get_push_subscriptions(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+) -> Result<PushSubscriptionListResponse, Errors> {
+    service_get_push_subscriptions(&state.db, &session_context).await
+}
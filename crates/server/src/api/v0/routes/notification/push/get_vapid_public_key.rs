@@ -0,0 +1,24 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::push::service_get_vapid_public_key;
+use dto::notification::VapidPublicKeyResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/notifications/push/vapid-public-key",
+    summary = "Get the VAPID public key",
+    description = "Returns the server's VAPID public key (base64url) to pass as `applicationServerKey` when subscribing the browser. `public_key` is null when Web Push is not configured.",
+    responses(
+        (status = 200, description = "VAPID public key retrieved successfully", body = VapidPublicKeyResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn get_vapid_public_key(
+    RequiredSession(_session_context): RequiredSession,
+) -> Result<VapidPublicKeyResponse, Errors> {
+    Ok(service_get_vapid_public_key())
+}
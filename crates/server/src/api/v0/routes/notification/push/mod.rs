@@ -0,0 +1,4 @@
+pub mod get_push_subscriptions;
+pub mod get_vapid_public_key;
+pub mod subscribe_push;
+pub mod unsubscribe_push;
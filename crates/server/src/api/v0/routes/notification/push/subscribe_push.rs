@@ -0,0 +1,32 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::push::service_subscribe_push;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::notification::{PushSubscriptionResponse, SubscribePushRequest};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/push/subscribe",
+    summary = "Register a Web Push device",
+    description = "Stores the browser's push subscription (`PushSubscription.toJSON()`) for the authenticated user. Re-registering an endpoint updates its keys and owner. A user keeps at most 10 devices; the oldest is dropped past that. Pushes are only sent while `push_enabled` is on.",
+    request_body = SubscribePushRequest,
+    responses(
+        (status = 200, description = "Push subscription registered", body = PushSubscriptionResponse),
+        (status = 400, description = "Bad request - Validation error, or Web Push is not configured", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn subscribe_push(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<SubscribePushRequest>,
+) -> Result<PushSubscriptionResponse, Errors> {
+    service_subscribe_push(&state.db, &session_context, payload).await
+}
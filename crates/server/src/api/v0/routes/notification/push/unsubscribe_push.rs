@@ -0,0 +1,35 @@
+use crate::extractors::RequiredSession;
+use crate::service::notification::push::service_unsubscribe_push;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use dto::notification::UnsubscribePushRequest;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/notifications/push/unsubscribe",
+    summary = "Remove a Web Push device",
+    description = "Deletes the authenticated user's push subscription for the given endpoint.",
+    request_body = UnsubscribePushRequest,
+    responses(
+        (status = 204, description = "Push subscription removed"),
+        (status = 400, description = "Bad request - Invalid JSON or validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
+        (status = 404, description = "Not Found - No subscription with this endpoint", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Notifications"
+)]
+pub async fn unsubscribe_push(
+    State(state): State<AppState>,
+    RequiredSession(session_context): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<UnsubscribePushRequest>,
+) -> Result<StatusCode, Errors> {
+    service_unsubscribe_push(&state.db, &session_context, payload).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,22 +1,33 @@
+use crate::middleware::require_role::require_admin;
 use crate::state::AppState;
+use axum::middleware::from_fn_with_state;
 use axum::{
     Router,
     routing::{get, post},
 };
 
+use super::announcements::create_announcement::create_system_announcement;
+use super::announcements::retract_announcement::retract_system_announcement;
 use super::inbox::count_unread::count_unread_notifications;
 use super::inbox::delete_notification::delete_notification;
 use super::inbox::get_notifications::get_notifications;
 use super::inbox::mark_all_as_read::mark_all_notifications_as_read;
 use super::inbox::mark_as_read::mark_notification_as_read;
+use super::inbox::stream_notifications::stream_notifications;
 use super::preferences::get_action_preferences::get_notification_action_preferences;
 use super::preferences::get_preferences::get_notification_preferences;
+use super::preferences::unsubscribe_email::unsubscribe_notification_email;
 use super::preferences::update_action_preferences_bulk::update_notification_action_preferences_bulk;
 use super::preferences::update_preferences::update_notification_preferences;
+use super::push::get_push_subscriptions::get_push_subscriptions;
+use super::push::get_vapid_public_key::get_vapid_public_key;
+use super::push::subscribe_push::subscribe_push;
+use super::push::unsubscribe_push::unsubscribe_push;
 
-pub fn notification_routes() -> Router<AppState> {
-    // All notification routes require authentication
-    Router::new()
+pub fn notification_routes(state: AppState) -> Router<AppState> {
+    // All notification routes require authentication, except the email unsubscribe,
+    // which is authorized by its token
+    let user = Router::new()
         .route("/notifications/list", get(get_notifications))
         .route(
             "/notifications/unread/count",
@@ -31,6 +42,7 @@ pub fn notification_routes() -> Router<AppState> {
             post(mark_all_notifications_as_read),
         )
         .route("/notifications/delete", post(delete_notification))
+        .route("/notifications/stream", get(stream_notifications))
         .route(
             "/notifications/preferences",
             get(get_notification_preferences),
@@ -47,4 +59,32 @@ pub fn notification_routes() -> Router<AppState> {
             "/notifications/preferences/actions/update",
             post(update_notification_action_preferences_bulk),
         )
+        .route(
+            "/notifications/email/unsubscribe",
+            post(unsubscribe_notification_email),
+        )
+        .route(
+            "/notifications/push/vapid-public-key",
+            get(get_vapid_public_key),
+        )
+        .route(
+            "/notifications/push/subscriptions",
+            get(get_push_subscriptions),
+        )
+        .route("/notifications/push/subscribe", post(subscribe_push))
+        .route("/notifications/push/unsubscribe", post(unsubscribe_push));
+
+    // Announcements are admin-only, gated at the router boundary.
+    let admin = Router::new()
+        .route(
+            "/notifications/announcements",
+            post(create_system_announcement),
+        )
+        .route(
+            "/notifications/announcements/retract",
+            post(retract_system_announcement),
+        )
+        .route_layer(from_fn_with_state(state, require_admin));
+
+    user.merge(admin)
 }
@@ -4,9 +4,11 @@ use super::board::openapi::BoardApiDoc;
 use super::groups::openapi::GroupsApiDoc;
 use super::moderation::openapi::ModerationOpenApi;
 use super::notification::openapi::NotificationApiDoc;
+use super::report::openapi::ReportApiDoc;
 use super::search::openapi::SearchApiDoc;
 use super::stream::openapi::StreamOpenApi;
 use super::user::openapi::UserApiDoc;
+use super::webhooks::openapi::WebhooksApiDoc;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
@@ -21,9 +23,11 @@ impl V0ApiDoc {
         openapi.merge(SearchApiDoc::openapi());
         openapi.merge(ActionLogsOpenApi::openapi());
         openapi.merge(ModerationOpenApi::openapi());
+        openapi.merge(ReportApiDoc::openapi());
         openapi.merge(StreamOpenApi::openapi());
         openapi.merge(GroupsApiDoc::openapi());
         openapi.merge(NotificationApiDoc::openapi());
+        openapi.merge(WebhooksApiDoc::openapi());
         openapi.merge(BoardApiDoc::openapi());
         openapi
     }
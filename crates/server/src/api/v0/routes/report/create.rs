@@ -0,0 +1,35 @@
+use crate::extractors::RequiredSession;
+use crate::service::report::service_create_report;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::report::{CreateReportRequest, ReportResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/reports",
+    summary = "Report a post, comment or user",
+    description = "Files a report for the moderator queue. A reporter can hold only one open report per target; reporting your own content or profile is rejected.",
+    request_body = CreateReportRequest,
+    responses(
+        (status = 200, description = "Report filed successfully", body = ReportResponse),
+        (status = 400, description = "Bad request - Validation error or self-report", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Target is not visible to the caller", body = ErrorResponse),
+        (status = 404, description = "Target not found", body = ErrorResponse),
+        (status = 409, description = "Conflict - An open report on this target already exists", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or transaction error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Reports"
+)]
+pub async fn create_report(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<CreateReportRequest>,
+) -> Result<ReportResponse, Errors> {
+    service_create_report(&state.db, payload, &session).await
+}
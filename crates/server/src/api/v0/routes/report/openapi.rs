@@ -0,0 +1,23 @@
+use dto::report::{CreateReportRequest, ReportResponse};
+use entity::common::{ReportReason, ReportStatus, ReportTargetType};
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        super::create::create_report,
+    ),
+    components(
+        schemas(
+            ReportTargetType,
+            ReportReason,
+            ReportStatus,
+            CreateReportRequest,
+            ReportResponse,
+        )
+    ),
+    tags(
+        (name = "Reports", description = "User content reports")
+    )
+)]
+pub struct ReportApiDoc;
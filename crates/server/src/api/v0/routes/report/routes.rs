@@ -0,0 +1,9 @@
+use crate::state::AppState;
+use axum::{Router, routing::post};
+
+use super::create::create_report;
+
+pub fn report_routes() -> Router<AppState> {
+    // Any logged-in user may report; the queue itself lives under /moderation.
+    Router::new().route("/reports", post(create_report))
+}
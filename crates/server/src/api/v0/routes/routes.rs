@@ -4,22 +4,26 @@ use super::board::routes::board_routes as BoardRoutes;
 use super::groups::routes::group_routes as GroupRoutes;
 use super::moderation::routes::moderation_routes as ModerationRoutes;
 use super::notification::routes::notification_routes as NotificationRoutes;
+use super::report::routes::report_routes as ReportRoutes;
 use super::search::routes::search_routes as SearchRoutes;
 use super::stream::routes::stream_routes as StreamRoutes;
 use super::user::routes::user_routes as UserRoutes;
+use super::webhooks::routes::webhook_routes as WebhookRoutes;
 use crate::state::AppState;
 use axum::Router;
 
 /// v0 API router
 pub fn v0_routes(state: AppState) -> Router<AppState> {
     Router::new()
-        .merge(UserRoutes())
+        .merge(UserRoutes(state.clone()))
         .merge(AuthRoutes(state.clone()))
-        .merge(SearchRoutes())
+        .merge(SearchRoutes(state.clone()))
         .merge(ActionLogsRoutes())
         .merge(ModerationRoutes(state.clone()))
+        .merge(ReportRoutes())
         .merge(StreamRoutes())
         .merge(GroupRoutes())
-        .merge(NotificationRoutes())
-        .merge(BoardRoutes())
+        .merge(NotificationRoutes(state.clone()))
+        .merge(WebhookRoutes(state.clone()))
+        .merge(BoardRoutes(state))
 }
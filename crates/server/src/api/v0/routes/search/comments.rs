@@ -0,0 +1,34 @@
+use crate::extractors::OptionalSession;
+use crate::service::search::service_search_comments;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::search::{SearchCommentsRequest, SearchCommentsResponse};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::Errors;
+
+#[utoipa::path(
+    get,
+    path = "/v0/search/comments",
+    params(SearchCommentsRequest),
+    responses(
+        (status = 200, description = "Board comment search results", body = SearchCommentsResponse),
+        (status = 400, description = "Bad request - Invalid query parameters or validation error"),
+        (status = 404, description = "Requested board not found or not visible"),
+        (status = 500, description = "Internal Server Error")
+    ),
+    tag = "Search"
+)]
+pub async fn search_comments(
+    State(state): State<AppState>,
+    OptionalSession(session): OptionalSession,
+    ValidatedQuery(payload): ValidatedQuery<SearchCommentsRequest>,
+) -> Result<SearchCommentsResponse, Errors> {
+    let response = service_search_comments(
+        &state.db,
+        &state.meilisearch_client,
+        &payload,
+        session.as_ref(),
+    )
+    .await?;
+    Ok(response)
+}
@@ -1,3 +1,7 @@
+pub mod comments;
 pub mod openapi;
+pub mod posts;
+pub mod reindex_status;
 pub mod routes;
+pub mod start_reindex;
 pub mod users;
@@ -1,10 +1,19 @@
-use dto::search::{SearchUsersRequest, SearchUsersResponse, SortOrder, UserSearchItem};
+use dto::search::{
+    CommentSearchItem, GetSearchReindexStatusRequest, PostSearchItem, SearchCommentsRequest,
+    SearchCommentsResponse, SearchPostsRequest, SearchPostsResponse, SearchReindexStatus,
+    SearchReindexStatusResponse, SearchReindexTarget, SearchUsersRequest, SearchUsersResponse,
+    SortOrder, StartSearchReindexRequest, StartSearchReindexResponse, UserSearchItem,
+};
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         super::users::search_users,
+        super::posts::search_posts,
+        super::comments::search_comments,
+        super::start_reindex::start_reindex,
+        super::reindex_status::reindex_status,
     ),
     components(
         schemas(
@@ -12,10 +21,22 @@ use utoipa::OpenApi;
             SearchUsersRequest,
             SearchUsersResponse,
             UserSearchItem,
+            SearchPostsRequest,
+            SearchPostsResponse,
+            PostSearchItem,
+            SearchCommentsRequest,
+            SearchCommentsResponse,
+            CommentSearchItem,
+            SearchReindexTarget,
+            StartSearchReindexRequest,
+            StartSearchReindexResponse,
+            GetSearchReindexStatusRequest,
+            SearchReindexStatus,
+            SearchReindexStatusResponse,
         )
     ),
     tags(
-        (name = "Search", description = "Search endpoints")
+        (name = "Search", description = "Search endpoints and admin index maintenance")
     )
 )]
 pub struct SearchApiDoc;
@@ -0,0 +1,34 @@
+use crate::extractors::OptionalSession;
+use crate::service::search::service_search_posts;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::search::{SearchPostsRequest, SearchPostsResponse};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::Errors;
+
+#[utoipa::path(
+    get,
+    path = "/v0/search/posts",
+    params(SearchPostsRequest),
+    responses(
+        (status = 200, description = "Board post search results", body = SearchPostsResponse),
+        (status = 400, description = "Bad request - Invalid query parameters or validation error"),
+        (status = 404, description = "Requested board not found or not visible"),
+        (status = 500, description = "Internal Server Error")
+    ),
+    tag = "Search"
+)]
+pub async fn search_posts(
+    State(state): State<AppState>,
+    OptionalSession(session): OptionalSession,
+    ValidatedQuery(payload): ValidatedQuery<SearchPostsRequest>,
+) -> Result<SearchPostsResponse, Errors> {
+    let response = service_search_posts(
+        &state.db,
+        &state.meilisearch_client,
+        &payload,
+        session.as_ref(),
+    )
+    .await?;
+    Ok(response)
+}
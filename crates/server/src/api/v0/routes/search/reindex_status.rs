@@ -0,0 +1,28 @@
+use crate::service::search::service_get_search_reindex_status;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::search::{GetSearchReindexStatusRequest, SearchReindexStatusResponse};
+use dto::validator::query_validator::ValidatedQuery;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/search/reindex",
+    summary = "Get search reindex progress",
+    description = "Admin only. Returns the batch progress the worker last recorded for a reindex run. Records expire 7 days after the last update.",
+    params(GetSearchReindexStatusRequest),
+    responses(
+        (status = 200, description = "Reindex progress", body = SearchReindexStatusResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Unknown or expired reindex id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Redis error", body = ErrorResponse)
+    ),
+    tag = "Search"
+)]
+pub async fn reindex_status(
+    State(state): State<AppState>,
+    ValidatedQuery(payload): ValidatedQuery<GetSearchReindexStatusRequest>,
+) -> Result<SearchReindexStatusResponse, Errors> {
+    service_get_search_reindex_status(&state.redis_cache, payload.reindex_id).await
+}
@@ -1,9 +1,29 @@
+use crate::middleware::require_role::require_admin;
 use crate::state::AppState;
-use axum::{Router, routing::get};
+use axum::middleware::from_fn_with_state;
+use axum::{
+    Router,
+    routing::{get, post},
+};
 
+use super::comments::search_comments;
+use super::posts::search_posts;
+use super::reindex_status::reindex_status;
+use super::start_reindex::start_reindex;
 use super::users::search_users;
 
-pub fn search_routes() -> Router<AppState> {
-    // Public routes (no authentication required)
-    Router::new().route("/search/users", get(search_users))
+pub fn search_routes(state: AppState) -> Router<AppState> {
+    // Public routes (no authentication required); board content is filtered
+    // by what the optional session may view.
+    let public = Router::new()
+        .route("/search/users", get(search_users))
+        .route("/search/posts", get(search_posts))
+        .route("/search/comments", get(search_comments));
+
+    // Index maintenance is admin-only, gated at the router boundary.
+    let admin = Router::new()
+        .route("/search/reindex", post(start_reindex).get(reindex_status))
+        .route_layer(from_fn_with_state(state, require_admin));
+
+    public.merge(admin)
 }
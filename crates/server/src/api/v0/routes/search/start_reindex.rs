@@ -0,0 +1,38 @@
+use crate::extractors::RequiredSession;
+use crate::service::search::service_start_search_reindex;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::search::{StartSearchReindexRequest, StartSearchReindexResponse};
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/search/reindex",
+    summary = "Start a search reindex",
+    description = "Admin only. Rebuilds one search index in batches into a temp index and swaps it in when done. Returns the `reindex_id` to poll for progress and records a `search:reindex` moderation log entry.",
+    request_body = StartSearchReindexRequest,
+    responses(
+        (status = 202, description = "Reindex queued", body = StartSearchReindexResponse),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 409, description = "Conflict - A reindex of this index is still running", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Worker queue or Redis error", body = ErrorResponse)
+    ),
+    tag = "Search"
+)]
+pub async fn start_reindex(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<StartSearchReindexRequest>,
+) -> Result<StartSearchReindexResponse, Errors> {
+    service_start_search_reindex(
+        &state.db,
+        &state.redis_cache,
+        &state.worker,
+        payload,
+        &session,
+    )
+    .await
+}
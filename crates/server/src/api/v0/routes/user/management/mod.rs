@@ -2,3 +2,4 @@ pub mod ban_user;
 pub mod grant_role;
 pub mod revoke_role;
 pub mod unban_user;
+pub mod unlock_user;
@@ -0,0 +1,42 @@
+use crate::extractors::RequiredSession;
+use crate::service::user::management::unlock_user::service_unlock_user;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::user::request::UnlockUserRequest;
+use dto::user::response::UnlockUserResponse;
+use dto::validator::json_validator::ValidatedJson;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/users/unlock",
+    summary = "Unlock a user's sign-in",
+    description = "Lifts a login lockout early: clears the account's failed-login counter and any active delay or lock. Failures counted against the client IP are not affected.",
+    request_body = UnlockUserRequest,
+    responses(
+        (status = 200, description = "User unlocked successfully", body = UnlockUserResponse),
+        (status = 400, description = "Bad request - User has no failed logins on record", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = ErrorResponse),
+        (status = 404, description = "Not Found - User not found", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database or session store error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "User Management"
+)]
+pub async fn unlock_user(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<UnlockUserRequest>,
+) -> Result<UnlockUserResponse, Errors> {
+    service_unlock_user(
+        &state.db,
+        &state.redis_session,
+        payload.user_id,
+        payload.reason,
+        &session,
+    )
+    .await
+}
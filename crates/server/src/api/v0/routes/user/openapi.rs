@@ -3,8 +3,8 @@ use dto::user::{
     BanUserRequest, BanUserResponse, CheckHandleAvailablePath, CheckHandleAvailableResponse,
     ConfirmAccountDeletionRequest, DeleteMyAccountRequest, GetUserProfileByIdRequest,
     GetUserProfileRequest, GrantRoleRequest, GrantRoleResponse, PublicUserProfile,
-    RevokeRoleRequest, RevokeRoleResponse, UnbanUserRequest, UnbanUserResponse,
-    UpdateMyProfileRequest, UploadUserImageResponse, UserResponse,
+    RevokeRoleRequest, RevokeRoleResponse, UnbanUserRequest, UnbanUserResponse, UnlockUserRequest,
+    UnlockUserResponse, UpdateMyProfileRequest, UploadUserImageResponse, UserResponse,
 };
 use utoipa::OpenApi;
 
@@ -24,6 +24,7 @@ use utoipa::OpenApi;
         super::account::check_handle_available::check_handle_available,
         super::management::ban_user::ban_user,
         super::management::unban_user::unban_user,
+        super::management::unlock_user::unlock_user,
         super::management::grant_role::grant_role,
         super::management::revoke_role::revoke_role,
     ),
@@ -44,6 +45,8 @@ use utoipa::OpenApi;
             BanUserResponse,
             UnbanUserRequest,
             UnbanUserResponse,
+            UnlockUserRequest,
+            UnlockUserResponse,
             GrantRoleRequest,
             GrantRoleResponse,
             RevokeRoleRequest,
@@ -25,6 +25,12 @@ pub async fn delete_banner_image(
     State(state): State<AppState>,
     RequiredSession(session_context): RequiredSession,
 ) -> Result<StatusCode, Errors> {
-    service_delete_banner_image(&state.db, &state.r2_assets, &session_context).await?;
+    service_delete_banner_image(
+        &state.db,
+        &state.r2_assets,
+        &state.nats_client,
+        &session_context,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -25,7 +25,13 @@ pub async fn delete_profile_image(
     State(state): State<AppState>,
     RequiredSession(session_context): RequiredSession,
 ) -> Result<StatusCode, Errors> {
-    service_delete_profile_image(&state.db, &state.r2_assets, &state.worker, &session_context)
-        .await?;
+    service_delete_profile_image(
+        &state.db,
+        &state.r2_assets,
+        &state.worker,
+        &state.nats_client,
+        &session_context,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::user::profile::get_my_profile::service_get_my_profile;
 use crate::state::AppState;
 use axum::extract::State;
@@ -16,13 +16,14 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
     ),
     security(
-        ("session_id_cookie" = [])
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["profile:read"])
     ),
     tag = "User",
 )]
 pub async fn get_my_profile(
     State(state): State<AppState>,
-    RequiredSession(session_context): RequiredSession,
+    ScopedSession(session_context, ..): ScopedSession<scope::ProfileRead>,
 ) -> Result<UserResponse, Errors> {
     service_get_my_profile(&state.db, &session_context).await
 }
@@ -1,4 +1,4 @@
-use crate::extractors::RequiredSession;
+use crate::extractors::{ScopedSession, scope};
 use crate::service::user::profile::update_my_profile::service_update_my_profile;
 use crate::state::AppState;
 use axum::extract::State;
@@ -20,14 +20,22 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 500, description = "Internal Server Error - Database or storage error", body = ErrorResponse)
     ),
     security(
-        ("session_id_cookie" = [])
+        ("session_id_cookie" = []),
+        ("personal_access_token" = ["profile:write"])
     ),
     tag = "User",
 )]
 pub async fn update_my_profile(
     State(state): State<AppState>,
-    RequiredSession(session_context): RequiredSession,
+    ScopedSession(session_context, ..): ScopedSession<scope::ProfileWrite>,
     ValidatedJson(payload): ValidatedJson<UpdateMyProfileRequest>,
 ) -> Result<UserResponse, Errors> {
-    service_update_my_profile(&state.db, &state.worker, &session_context, payload).await
+    service_update_my_profile(
+        &state.db,
+        &state.worker,
+        &state.nats_client,
+        &session_context,
+        payload,
+    )
+    .await
 }
@@ -18,6 +18,7 @@ use errors::errors::{ErrorResponse, Errors};
         (status = 400, description = "Bad Request - Invalid image or validation error", body = ErrorResponse),
         (status = 401, description = "Unauthorized - Invalid or expired session", body = ErrorResponse),
         (status = 413, description = "Payload Too Large - Image exceeds size limit", body = ErrorResponse),
+        (status = 429, description = "Too many requests; retry after the `Retry-After` interval", body = ErrorResponse),
         (status = 500, description = "Internal Server Error - Database or storage error", body = ErrorResponse)
     ),
     security(
@@ -34,6 +35,7 @@ pub async fn upload_banner_image(
         &state.db,
         &state.http_client,
         &state.r2_assets,
+        &state.nats_client,
         &session_context,
         payload,
     )
@@ -5,6 +5,7 @@ use super::management::ban_user::ban_user;
 use super::management::grant_role::grant_role;
 use super::management::revoke_role::revoke_role;
 use super::management::unban_user::unban_user;
+use super::management::unlock_user::unlock_user;
 use super::profile::delete_banner_image::delete_banner_image;
 use super::profile::delete_profile_image::delete_profile_image;
 use super::profile::get_my_profile::get_my_profile;
@@ -13,6 +14,7 @@ use super::profile::upload_banner_image::upload_banner_image;
 use super::profile::upload_profile_image::upload_profile_image;
 use super::public::get_user_profile::get_user_profile;
 use super::public::get_user_profile_by_id::get_user_profile_by_id;
+use crate::middleware::rate_limit::{IMAGE_UPLOAD_RATE_LIMIT, RateLimitLayer};
 use crate::state::AppState;
 use axum::{
     Router,
@@ -21,12 +23,14 @@ use axum::{
 };
 use constants::{BANNER_IMAGE_MAX_SIZE, PROFILE_IMAGE_MAX_SIZE};
 
-pub fn user_routes() -> Router<AppState> {
+pub fn user_routes(state: AppState) -> Router<AppState> {
     // Profile image upload route with 4MB limit
     let profile_image_route = Router::new()
         .route(
             "/user/me/profile-image",
-            post(upload_profile_image).delete(delete_profile_image),
+            post(upload_profile_image)
+                .route_layer(RateLimitLayer::new(&state, &IMAGE_UPLOAD_RATE_LIMIT))
+                .delete(delete_profile_image),
         )
         .layer(DefaultBodyLimit::max(PROFILE_IMAGE_MAX_SIZE));
 
@@ -34,7 +38,9 @@ pub fn user_routes() -> Router<AppState> {
     let banner_image_route = Router::new()
         .route(
             "/user/me/banner-image",
-            post(upload_banner_image).delete(delete_banner_image),
+            post(upload_banner_image)
+                .route_layer(RateLimitLayer::new(&state, &IMAGE_UPLOAD_RATE_LIMIT))
+                .delete(delete_banner_image),
         )
         .layer(DefaultBodyLimit::max(BANNER_IMAGE_MAX_SIZE));
 
@@ -49,6 +55,7 @@ pub fn user_routes() -> Router<AppState> {
         // User Management (moderator actions)
         .route("/users/ban", post(ban_user))
         .route("/users/unban", post(unban_user))
+        .route("/users/unlock", post(unlock_user))
         .route("/users/roles/grant", post(grant_role))
         .route("/users/roles/revoke", post(revoke_role));
 
@@ -0,0 +1,33 @@
+use crate::extractors::RequiredSession;
+use crate::service::webhooks::service_create_webhook_endpoint;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::validator::json_validator::ValidatedJson;
+use dto::webhooks::{CreateWebhookEndpointRequest, WebhookEndpointSecretResponse};
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks",
+    summary = "Register a webhook endpoint",
+    description = "Admin only. Registers an endpoint that receives a signed POST for every action or moderation log entry whose code is in `events`. The response carries the signing secret, which is not shown again. Records a `webhook:create` moderation log entry.",
+    request_body = CreateWebhookEndpointRequest,
+    responses(
+        (status = 200, description = "Endpoint registered", body = WebhookEndpointSecretResponse),
+        (status = 400, description = "Bad request - Validation error or unknown event code", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn create_webhook_endpoint(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<CreateWebhookEndpointRequest>,
+) -> Result<WebhookEndpointSecretResponse, Errors> {
+    service_create_webhook_endpoint(&state.db, &session, payload).await
+}
@@ -0,0 +1,36 @@
+use crate::extractors::RequiredSession;
+use crate::service::webhooks::service_delete_webhook_endpoint;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use dto::validator::json_validator::ValidatedJson;
+use dto::webhooks::DeleteWebhookEndpointRequest;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/delete",
+    summary = "Delete a webhook endpoint",
+    description = "Admin only. Deletes the endpoint and its delivery log; deliveries still queued are dropped. Records a `webhook:delete` moderation log entry.",
+    request_body = DeleteWebhookEndpointRequest,
+    responses(
+        (status = 204, description = "Endpoint deleted"),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Not Found - No endpoint with this id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn delete_webhook_endpoint(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<DeleteWebhookEndpointRequest>,
+) -> Result<StatusCode, Errors> {
+    service_delete_webhook_endpoint(&state.db, &session, payload).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,32 @@
+use crate::service::webhooks::service_list_webhook_deliveries;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::validator::query_validator::ValidatedQuery;
+use dto::webhooks::{ListWebhookDeliveriesRequest, WebhookDeliveryListResponse};
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks/deliveries",
+    summary = "List an endpoint's deliveries",
+    description = "Admin only. Pages through the endpoint's delivery log, newest first, with the payload sent and the outcome of the last attempt.",
+    params(ListWebhookDeliveriesRequest),
+    responses(
+        (status = 200, description = "Deliveries retrieved successfully", body = WebhookDeliveryListResponse),
+        (status = 400, description = "Bad request - Invalid query parameters or validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Not Found - No endpoint with this id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    ValidatedQuery(payload): ValidatedQuery<ListWebhookDeliveriesRequest>,
+) -> Result<WebhookDeliveryListResponse, Errors> {
+    service_list_webhook_deliveries(&state.db, payload).await
+}
@@ -0,0 +1,27 @@
+use crate::service::webhooks::service_list_webhook_endpoints;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::webhooks::WebhookEndpointListResponse;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    get,
+    path = "/v0/webhooks",
+    summary = "List webhook endpoints",
+    description = "Admin only. Lists every registered endpoint, newest first. Signing secrets are never included.",
+    responses(
+        (status = 200, description = "Endpoints retrieved successfully", body = WebhookEndpointListResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn list_webhook_endpoints(
+    State(state): State<AppState>,
+) -> Result<WebhookEndpointListResponse, Errors> {
+    service_list_webhook_endpoints(&state.db).await
+}
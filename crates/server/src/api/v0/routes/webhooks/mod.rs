@@ -0,0 +1,9 @@
+pub mod create_endpoint;
+pub mod delete_endpoint;
+pub mod list_deliveries;
+pub mod list_endpoints;
+pub mod openapi;
+pub mod redeliver;
+pub mod rotate_secret;
+pub mod routes;
+pub mod update_endpoint;
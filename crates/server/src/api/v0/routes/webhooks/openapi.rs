@@ -0,0 +1,50 @@
+use dto::webhooks::{
+    CreateWebhookEndpointRequest, DeleteWebhookEndpointRequest, ListWebhookDeliveriesRequest,
+    RedeliverWebhookRequest, RotateWebhookSecretRequest, UpdateWebhookEndpointRequest,
+    WebhookDeliveryListResponse, WebhookDeliveryResponse, WebhookEndpointListResponse,
+    WebhookEndpointResponse, WebhookEndpointSecretResponse, WebhookEventPayload,
+    WebhookEventSource,
+};
+use entity::common::WebhookDeliveryStatus;
+use utoipa::OpenApi;
+
+use super::create_endpoint::__path_create_webhook_endpoint;
+use super::delete_endpoint::__path_delete_webhook_endpoint;
+use super::list_deliveries::__path_list_webhook_deliveries;
+use super::list_endpoints::__path_list_webhook_endpoints;
+use super::redeliver::__path_redeliver_webhook;
+use super::rotate_secret::__path_rotate_webhook_secret;
+use super::update_endpoint::__path_update_webhook_endpoint;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        list_webhook_endpoints,
+        create_webhook_endpoint,
+        update_webhook_endpoint,
+        delete_webhook_endpoint,
+        rotate_webhook_secret,
+        list_webhook_deliveries,
+        redeliver_webhook,
+    ),
+    components(schemas(
+        CreateWebhookEndpointRequest,
+        UpdateWebhookEndpointRequest,
+        DeleteWebhookEndpointRequest,
+        RotateWebhookSecretRequest,
+        ListWebhookDeliveriesRequest,
+        RedeliverWebhookRequest,
+        WebhookEndpointResponse,
+        WebhookEndpointSecretResponse,
+        WebhookEndpointListResponse,
+        WebhookDeliveryResponse,
+        WebhookDeliveryListResponse,
+        WebhookDeliveryStatus,
+        WebhookEventPayload,
+        WebhookEventSource,
+    )),
+    tags(
+        (name = "Webhooks", description = "Outbound signed webhooks for action and moderation log events")
+    )
+)]
+pub struct WebhooksApiDoc;
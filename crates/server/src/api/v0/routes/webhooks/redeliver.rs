@@ -0,0 +1,34 @@
+use crate::service::webhooks::service_redeliver_webhook;
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use dto::validator::json_validator::ValidatedJson;
+use dto::webhooks::RedeliverWebhookRequest;
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/deliveries/redeliver",
+    summary = "Redeliver a webhook",
+    description = "Admin only. Queues a logged delivery to be sent again with its original payload and a fresh signature. Works for any delivery status.",
+    request_body = RedeliverWebhookRequest,
+    responses(
+        (status = 202, description = "Delivery queued"),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Not Found - No delivery with this id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn redeliver_webhook(
+    State(state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<RedeliverWebhookRequest>,
+) -> Result<StatusCode, Errors> {
+    service_redeliver_webhook(&state.db, payload).await?;
+    Ok(StatusCode::ACCEPTED)
+}
@@ -0,0 +1,34 @@
+use crate::extractors::RequiredSession;
+use crate::service::webhooks::service_rotate_webhook_secret;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::validator::json_validator::ValidatedJson;
+use dto::webhooks::{RotateWebhookSecretRequest, WebhookEndpointSecretResponse};
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/rotate-secret",
+    summary = "Rotate a webhook signing secret",
+    description = "Admin only. Replaces the endpoint's signing secret and returns the new one, which is not shown again. Every attempt from then on, including retries of older deliveries, is signed with it. Records a `webhook:rotate_secret` moderation log entry.",
+    request_body = RotateWebhookSecretRequest,
+    responses(
+        (status = 200, description = "Secret rotated", body = WebhookEndpointSecretResponse),
+        (status = 400, description = "Bad request - Validation error", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Not Found - No endpoint with this id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn rotate_webhook_secret(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<RotateWebhookSecretRequest>,
+) -> Result<WebhookEndpointSecretResponse, Errors> {
+    service_rotate_webhook_secret(&state.db, &session, payload).await
+}
@@ -0,0 +1,31 @@
+use crate::middleware::require_role::require_admin;
+use crate::state::AppState;
+use axum::middleware::from_fn_with_state;
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use super::create_endpoint::create_webhook_endpoint;
+use super::delete_endpoint::delete_webhook_endpoint;
+use super::list_deliveries::list_webhook_deliveries;
+use super::list_endpoints::list_webhook_endpoints;
+use super::redeliver::redeliver_webhook;
+use super::rotate_secret::rotate_webhook_secret;
+use super::update_endpoint::update_webhook_endpoint;
+
+pub fn webhook_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/webhooks",
+            get(list_webhook_endpoints).post(create_webhook_endpoint),
+        )
+        .route("/webhooks/update", post(update_webhook_endpoint))
+        .route("/webhooks/delete", post(delete_webhook_endpoint))
+        .route("/webhooks/rotate-secret", post(rotate_webhook_secret))
+        .route("/webhooks/deliveries", get(list_webhook_deliveries))
+        .route("/webhooks/deliveries/redeliver", post(redeliver_webhook))
+        // Endpoints receive every subscribed event and their secrets sign them, so the
+        // whole surface is admin-only.
+        .route_layer(from_fn_with_state(state, require_admin))
+}
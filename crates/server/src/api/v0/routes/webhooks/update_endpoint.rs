@@ -0,0 +1,34 @@
+use crate::extractors::RequiredSession;
+use crate::service::webhooks::service_update_webhook_endpoint;
+use crate::state::AppState;
+use axum::extract::State;
+use dto::validator::json_validator::ValidatedJson;
+use dto::webhooks::{UpdateWebhookEndpointRequest, WebhookEndpointResponse};
+use errors::errors::{ErrorResponse, Errors};
+
+#[utoipa::path(
+    post,
+    path = "/v0/webhooks/update",
+    summary = "Update a webhook endpoint",
+    description = "Admin only. Changes the URL, description, subscribed events or active flag; omitted fields are kept. A paused endpoint receives no new deliveries. Records a `webhook:update` moderation log entry.",
+    request_body = UpdateWebhookEndpointRequest,
+    responses(
+        (status = 200, description = "Endpoint updated", body = WebhookEndpointResponse),
+        (status = 400, description = "Bad request - Validation error or unknown event code", body = ErrorResponse),
+        (status = 401, description = "Unauthorized - Login required", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Admin role required", body = ErrorResponse),
+        (status = 404, description = "Not Found - No endpoint with this id", body = ErrorResponse),
+        (status = 500, description = "Internal Server Error - Database error", body = ErrorResponse)
+    ),
+    security(
+        ("session_id_cookie" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn update_webhook_endpoint(
+    State(state): State<AppState>,
+    RequiredSession(session): RequiredSession,
+    ValidatedJson(payload): ValidatedJson<UpdateWebhookEndpointRequest>,
+) -> Result<WebhookEndpointResponse, Errors> {
+    service_update_webhook_endpoint(&state.db, &session, payload).await
+}
@@ -143,6 +143,32 @@ pub async fn send_device_verification(
     Ok(())
 }
 
+/// Push a magic-link sign-in email job to the worker queue.
+///
+/// The raw token reaches the user only via this email; following the link signs them in.
+pub async fn send_magic_link_email(
+    worker: &WorkerClient,
+    email_to: &str,
+    username: &str,
+    token: &str,
+    valid_minutes: u64,
+) -> Result<(), Errors> {
+    let job = SendEmailJob {
+        to: email_to.to_string(),
+        subject: "Your sign-in link".to_string(),
+        template: EmailTemplate::MagicLink {
+            username: username.to_string(),
+            token: token.to_string(),
+            valid_minutes,
+        },
+    };
+
+    publish_job(worker, EMAIL_SUBJECT, &job).await?;
+
+    info!(template = "magic_link", "Magic link email job queued");
+    Ok(())
+}
+
 /// Push an account deletion confirmation job to the worker queue.
 ///
 /// Used to re-authenticate OAuth-only accounts (no password or TOTP factor) before
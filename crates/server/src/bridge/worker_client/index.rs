@@ -1,8 +1,10 @@
 use super::publish_job;
 use crate::state::WorkerClient;
 use errors::errors::Errors;
+use job_queue::jobs::comment_index::{CommentIndexAction, IndexCommentJob};
+use job_queue::jobs::post_index::{IndexPostJob, PostIndexAction};
 use job_queue::jobs::user_index::{IndexUserJob, UserIndexAction};
-use job_queue::subjects::INDEX_USER_SUBJECT;
+use job_queue::subjects::{INDEX_COMMENT_SUBJECT, INDEX_POST_SUBJECT, INDEX_USER_SUBJECT};
 use tracing::info;
 use uuid::Uuid;
 
@@ -35,3 +37,66 @@ pub async fn delete_user_from_index(worker: &WorkerClient, user_id: Uuid) -> Res
     info!(user_id = %user_id, "User delete job queued");
     Ok(())
 }
+
+/// Push a board post indexing job to the worker queue
+pub async fn index_post(worker: &WorkerClient, post_id: Uuid) -> Result<(), Errors> {
+    info!(post_id = %post_id, "Queuing post index job");
+
+    let job = IndexPostJob {
+        post_id,
+        action: PostIndexAction::Index,
+    };
+
+    publish_job(worker, INDEX_POST_SUBJECT, &job).await?;
+
+    info!(post_id = %post_id, "Post index job queued");
+    Ok(())
+}
+
+/// Push a board post deletion job to the worker queue
+pub async fn delete_post_from_index(worker: &WorkerClient, post_id: Uuid) -> Result<(), Errors> {
+    info!(post_id = %post_id, "Queuing post delete job");
+
+    let job = IndexPostJob {
+        post_id,
+        action: PostIndexAction::Delete,
+    };
+
+    publish_job(worker, INDEX_POST_SUBJECT, &job).await?;
+
+    info!(post_id = %post_id, "Post delete job queued");
+    Ok(())
+}
+
+/// Push a board comment indexing job to the worker queue
+pub async fn index_comment(worker: &WorkerClient, comment_id: Uuid) -> Result<(), Errors> {
+    info!(comment_id = %comment_id, "Queuing comment index job");
+
+    let job = IndexCommentJob {
+        comment_id,
+        action: CommentIndexAction::Index,
+    };
+
+    publish_job(worker, INDEX_COMMENT_SUBJECT, &job).await?;
+
+    info!(comment_id = %comment_id, "Comment index job queued");
+    Ok(())
+}
+
+/// Push a board comment deletion job to the worker queue
+pub async fn delete_comment_from_index(
+    worker: &WorkerClient,
+    comment_id: Uuid,
+) -> Result<(), Errors> {
+    info!(comment_id = %comment_id, "Queuing comment delete job");
+
+    let job = IndexCommentJob {
+        comment_id,
+        action: CommentIndexAction::Delete,
+    };
+
+    publish_job(worker, INDEX_COMMENT_SUBJECT, &job).await?;
+
+    info!(comment_id = %comment_id, "Comment delete job queued");
+    Ok(())
+}
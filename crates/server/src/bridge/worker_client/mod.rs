@@ -1,12 +1,14 @@
 mod cache;
 mod email;
 mod index;
+mod notification;
 mod oauth;
 mod reindex;
 
 // Re-export all functions for backwards compatibility
 pub use email::*;
 pub use index::*;
+pub use notification::*;
 pub use oauth::*;
 pub use reindex::*;
 
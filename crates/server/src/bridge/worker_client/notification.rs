@@ -0,0 +1,66 @@
+use super::publish_job;
+use crate::state::WorkerClient;
+use errors::errors::Errors;
+use job_queue::jobs::notification::{
+    BoardCommentFanOutJob, NotificationEmailJob, NotificationPushJob, SystemAnnouncementFanOutJob,
+};
+use job_queue::subjects::{
+    NOTIFICATION_ANNOUNCEMENT_SUBJECT, NOTIFICATION_EMAIL_SUBJECT, NOTIFICATION_FANOUT_SUBJECT,
+    NOTIFICATION_PUSH_SUBJECT,
+};
+use uuid::Uuid;
+
+/// Queue the watcher fan-out for a new board comment.
+pub async fn fan_out_board_comment(
+    worker: &WorkerClient,
+    job: BoardCommentFanOutJob,
+) -> Result<(), Errors> {
+    publish_job(worker, NOTIFICATION_FANOUT_SUBJECT, &job).await
+}
+
+/// Queue the delivery of a system announcement to every active user.
+pub async fn fan_out_system_announcement(
+    worker: &WorkerClient,
+    event_id: Uuid,
+    mandatory: bool,
+) -> Result<(), Errors> {
+    publish_job(
+        worker,
+        NOTIFICATION_ANNOUNCEMENT_SUBJECT,
+        &SystemAnnouncementFanOutJob {
+            event_id,
+            mandatory,
+        },
+    )
+    .await
+}
+
+/// Queue the email copy of a notification event for its recipients; the worker
+/// keeps those who turned email on.
+pub async fn send_notification_email(
+    worker: &WorkerClient,
+    event_id: Uuid,
+    user_ids: Vec<Uuid>,
+) -> Result<(), Errors> {
+    publish_job(
+        worker,
+        NOTIFICATION_EMAIL_SUBJECT,
+        &NotificationEmailJob { event_id, user_ids },
+    )
+    .await
+}
+
+/// Queue the Web Push copy of a notification event for its recipients; the
+/// worker keeps those who turned push on.
+pub async fn send_notification_push(
+    worker: &WorkerClient,
+    event_id: Uuid,
+    user_ids: Vec<Uuid>,
+) -> Result<(), Errors> {
+    publish_job(
+        worker,
+        NOTIFICATION_PUSH_SUBJECT,
+        &NotificationPushJob { event_id, user_ids },
+    )
+    .await
+}
@@ -1,8 +1,10 @@
 use super::publish_job;
 use crate::state::WorkerClient;
 use errors::errors::Errors;
-use job_queue::jobs::reindex::create_reindex_users_job;
-use job_queue::subjects::REINDEX_USERS_SUBJECT;
+use job_queue::jobs::reindex::{
+    create_reindex_comments_job, create_reindex_posts_job, create_reindex_users_job,
+};
+use job_queue::subjects::{REINDEX_COMMENTS_SUBJECT, REINDEX_POSTS_SUBJECT, REINDEX_USERS_SUBJECT};
 use tracing::info;
 use uuid::Uuid;
 
@@ -25,3 +27,43 @@ pub async fn start_reindex_users(
     info!(reindex_id = %reindex_id, "User reindex job started");
     Ok(reindex_id)
 }
+
+/// Start a full reindex of all board posts
+pub async fn start_reindex_posts(
+    worker: &WorkerClient,
+    batch_size: Option<u32>,
+) -> Result<Uuid, Errors> {
+    let reindex_id = Uuid::now_v7();
+    info!(
+        reindex_id = %reindex_id,
+        batch_size = ?batch_size,
+        "Starting post reindex job"
+    );
+
+    let job = create_reindex_posts_job(reindex_id, batch_size);
+
+    publish_job(worker, REINDEX_POSTS_SUBJECT, &job).await?;
+
+    info!(reindex_id = %reindex_id, "Post reindex job started");
+    Ok(reindex_id)
+}
+
+/// Start a full reindex of all board comments
+pub async fn start_reindex_comments(
+    worker: &WorkerClient,
+    batch_size: Option<u32>,
+) -> Result<Uuid, Errors> {
+    let reindex_id = Uuid::now_v7();
+    info!(
+        reindex_id = %reindex_id,
+        batch_size = ?batch_size,
+        "Starting comment reindex job"
+    );
+
+    let job = create_reindex_comments_job(reindex_id, batch_size);
+
+    publish_job(worker, REINDEX_COMMENTS_SUBJECT, &job).await?;
+
+    info!(reindex_id = %reindex_id, "Comment reindex job started");
+    Ok(reindex_id)
+}
@@ -2,10 +2,12 @@ pub mod database_conn;
 pub mod http_conn;
 pub mod meilisearch_conn;
 pub mod r2_assets_conn;
+pub mod r2_revisions_conn;
 pub mod redis_conn;
 
 pub use database_conn::*;
 pub use http_conn::*;
 pub use meilisearch_conn::*;
 pub use r2_assets_conn::*;
+pub use r2_revisions_conn::*;
 pub use redis_conn::*;
@@ -0,0 +1,28 @@
+use config::ServerConfig;
+use storage::{R2Config, create_r2_client};
+use tracing::info;
+
+pub use storage::R2RevisionClient;
+
+pub async fn establish_r2_revisions_connection()
+-> Result<R2RevisionClient, Box<dyn std::error::Error + Send + Sync>> {
+    let config = ServerConfig::get();
+
+    info!(
+        "Connecting to R2 revisions at: {} (region: {})",
+        config.r2_endpoint, config.r2_region
+    );
+
+    let client = create_r2_client(&R2Config {
+        endpoint: config.r2_endpoint.clone(),
+        region: config.r2_region.clone(),
+        access_key_id: config.r2_access_key_id.clone(),
+        secret_access_key: config.r2_secret_access_key.clone(),
+    })
+    .await;
+
+    let r2_client = R2RevisionClient::new(client, config.r2_revisions_bucket_name.clone());
+
+    info!("Successfully connected to R2 revisions");
+    Ok(r2_client)
+}
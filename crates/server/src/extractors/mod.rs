@@ -1,5 +1,6 @@
+pub mod scope;
 pub mod session;
 pub mod turnstile;
 
-pub use session::{OptionalSession, RequiredSession};
+pub use session::{OptionalSession, RequiredSession, ScopedSession};
 pub use turnstile::TurnstileVerified;
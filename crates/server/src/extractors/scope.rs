@@ -0,0 +1,33 @@
+//! Scope markers for [`ScopedSession`](super::ScopedSession).
+//!
+//! `ScopedSession<scope::BoardWrite>` admits interactive sessions and personal access tokens
+//! granted `board:write`.
+
+use constants::TokenScope;
+
+/// The `TokenScope` a [`ScopedSession`](super::ScopedSession) requires of a personal access token.
+pub trait RequiredScope {
+    const SCOPE: TokenScope;
+}
+
+macro_rules! scope_markers {
+    ($($scope:ident),+ $(,)?) => {
+        $(
+            #[doc = concat!("Requires `TokenScope::", stringify!($scope), "`.")]
+            #[derive(Debug, Clone, Copy)]
+            pub struct $scope;
+
+            impl RequiredScope for $scope {
+                const SCOPE: TokenScope = TokenScope::$scope;
+            }
+        )+
+    };
+}
+
+scope_markers!(
+    ProfileRead,
+    ProfileWrite,
+    BoardWrite,
+    NotificationsRead,
+    NotificationsWrite,
+);
@@ -3,8 +3,11 @@ use axum::http::HeaderMap;
 use axum::http::header::AUTHORIZATION;
 use axum::http::request::Parts;
 use std::convert::Infallible;
+use std::marker::PhantomData;
 use tower_cookies::Cookies;
 
+use super::scope::RequiredScope;
+use crate::service::auth::access_token::{is_access_token, service_resolve_access_token};
 use crate::service::auth::session::SessionService;
 use crate::service::auth::session_types::SessionContext;
 use crate::state::AppState;
@@ -124,6 +127,48 @@ where
     }
 }
 
+/// Session extractor for routes that personal access tokens may also call.
+///
+/// Admits an interactive session (cookie or bearer session token) with full access, or a
+/// personal access token (`Authorization: Bearer akpat_...`) granted the marker's scope;
+/// a token without it is rejected with `Errors::AccessTokenInsufficientScope`. Routes that
+/// keep [`RequiredSession`] never accept personal access tokens.
+///
+/// ```ignore
+/// pub async fn create_post(
+///     ScopedSession(session, ..): ScopedSession<scope::BoardWrite>,
+/// ) { ... }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScopedSession<S>(pub SessionContext, pub PhantomData<S>);
+
+impl<S, St> FromRequestParts<St> for ScopedSession<S>
+where
+    S: RequiredScope,
+    St: Send + Sync,
+    AppState: FromRef<St>,
+{
+    type Rejection = Errors;
+
+    async fn from_request_parts(parts: &mut Parts, state: &St) -> Result<Self, Self::Rejection> {
+        if let Some(token) = bearer_token(&parts.headers)
+            && is_access_token(&token)
+        {
+            let app_state = AppState::from_ref(state);
+            let context = service_resolve_access_token(&app_state.db, &token)
+                .await?
+                .ok_or(Errors::UserUnauthorized)?;
+            if !context.has_scope(S::SCOPE) {
+                return Err(Errors::AccessTokenInsufficientScope);
+            }
+            return Ok(ScopedSession(context, PhantomData));
+        }
+
+        let RequiredSession(context) = RequiredSession::from_request_parts(parts, state).await?;
+        Ok(ScopedSession(context, PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::bearer_token;
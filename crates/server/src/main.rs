@@ -5,7 +5,7 @@ use dto::action_logs::ActionLogResponse;
 use server::api::routes::api_routes;
 use server::connection::{
     MeilisearchClient, create_http_client, establish_connection, establish_r2_assets_connection,
-    establish_redis_connection,
+    establish_r2_revisions_connection, establish_redis_connection,
 };
 use server::eventstream::start_eventstream_subscriber;
 use server::middleware::anonymous_user::anonymous_user_middleware;
@@ -34,6 +34,10 @@ pub async fn run_server() -> anyhow::Result<()> {
         error!("Failed to establish cloudflare_r2 connection: {}", e);
         anyhow::anyhow!("R2 connection failed: {}", e)
     })?;
+    let r2_revisions = establish_r2_revisions_connection().await.map_err(|e| {
+        error!("Failed to establish R2 revisions connection: {}", e);
+        anyhow::anyhow!("R2 revisions connection failed: {}", e)
+    })?;
     let redis_session = establish_redis_connection(
         &ServerConfig::get().redis_session_host,
         &ServerConfig::get().redis_session_port,
@@ -107,6 +111,7 @@ pub async fn run_server() -> anyhow::Result<()> {
     let state = AppState {
         db,
         r2_assets,
+        r2_revisions,
         redis_session,
         redis_cache,
         worker,
@@ -1,5 +1,6 @@
 pub mod anonymous_user;
 pub mod cors;
+pub mod rate_limit;
 pub mod require_role;
 pub mod stability;
 pub mod trace_layer_config;
@@ -0,0 +1,137 @@
+use super::policy::RateLimitPolicy;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use constants::rate_limit_key;
+use errors::errors::Errors;
+use redis::aio::ConnectionManager;
+use std::sync::LazyLock;
+use uuid::Uuid;
+
+static SLIDING_WINDOW_SCRIPT: LazyLock<redis::Script> =
+    LazyLock::new(|| redis::Script::new(include_str!("lua/sliding_window.lua")));
+
+static RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+static RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+static RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+static RATELIMIT_POLICY: HeaderName = HeaderName::from_static("ratelimit-policy");
+
+/// Outcome of one rate-limit check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Seconds until the oldest request in the window expires (at least 1).
+    pub reset_seconds: u64,
+}
+
+impl RateLimitDecision {
+    fn from_script(
+        policy: &RateLimitPolicy,
+        (allowed, remaining, reset_ms): (i64, i64, i64),
+    ) -> Self {
+        Self {
+            allowed: allowed == 1,
+            limit: policy.limit,
+            remaining: remaining.clamp(0, i64::from(policy.limit)) as u32,
+            reset_seconds: (reset_ms.max(0) as u64).div_ceil(1000).max(1),
+        }
+    }
+
+    /// Write the `RateLimit-*` headers, plus `Retry-After` when the request was rejected.
+    pub fn write_headers(&self, policy: &RateLimitPolicy, headers: &mut HeaderMap) {
+        headers.insert(RATELIMIT_LIMIT.clone(), HeaderValue::from(self.limit));
+        headers.insert(
+            RATELIMIT_REMAINING.clone(),
+            HeaderValue::from(self.remaining),
+        );
+        headers.insert(
+            RATELIMIT_RESET.clone(),
+            HeaderValue::from(self.reset_seconds),
+        );
+        if let Ok(value) = HeaderValue::from_str(&policy.header_value()) {
+            headers.insert(RATELIMIT_POLICY.clone(), value);
+        }
+        if !self.allowed {
+            headers.insert(RETRY_AFTER, HeaderValue::from(self.reset_seconds));
+        }
+    }
+}
+
+/// Count one request against `policy` for `subject` and decide whether it may proceed.
+///
+/// The window lives in a Redis sorted set updated atomically by a Lua script, so
+/// concurrent requests across server instances cannot overshoot the limit. Rejected
+/// requests are not recorded, so a client hammering a closed window does not push
+/// its own reset further out.
+pub async fn check_rate_limit(
+    redis_conn: &ConnectionManager,
+    policy: &RateLimitPolicy,
+    subject: &str,
+) -> Result<RateLimitDecision, Errors> {
+    let key = rate_limit_key(policy.name, subject);
+    let mut conn = redis_conn.clone();
+    let result: (i64, i64, i64) = SLIDING_WINDOW_SCRIPT
+        .key(&key)
+        .arg(policy.window_seconds * 1000)
+        .arg(policy.limit)
+        .arg(Uuid::now_v7().to_string())
+        .invoke_async(&mut conn)
+        .await
+        .map_err(|e| {
+            Errors::SysInternalError(format!("Rate limit check failed for key '{}': {}", key, e))
+        })?;
+
+    Ok(RateLimitDecision::from_script(policy, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middleware::rate_limit::policy::RateLimitKey;
+
+    static POLICY: RateLimitPolicy = RateLimitPolicy {
+        name: "test",
+        limit: 10,
+        window_seconds: 60,
+        key: RateLimitKey::Ip,
+    };
+
+    #[test]
+    fn rounds_reset_up_to_whole_seconds() {
+        let decision = RateLimitDecision::from_script(&POLICY, (1, 9, 59_001));
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining, 9);
+        assert_eq!(decision.reset_seconds, 60);
+    }
+
+    #[test]
+    fn reset_is_at_least_one_second() {
+        let decision = RateLimitDecision::from_script(&POLICY, (0, 0, 0));
+        assert!(!decision.allowed);
+        assert_eq!(decision.reset_seconds, 1);
+    }
+
+    #[test]
+    fn allowed_response_has_no_retry_after() {
+        let decision = RateLimitDecision::from_script(&POLICY, (1, 3, 42_000));
+        let mut headers = HeaderMap::new();
+        decision.write_headers(&POLICY, &mut headers);
+
+        assert_eq!(headers["ratelimit-limit"], "10");
+        assert_eq!(headers["ratelimit-remaining"], "3");
+        assert_eq!(headers["ratelimit-reset"], "42");
+        assert_eq!(headers["ratelimit-policy"], "10;w=60");
+        assert!(headers.get(RETRY_AFTER).is_none());
+    }
+
+    #[test]
+    fn rejected_response_carries_retry_after() {
+        let decision = RateLimitDecision::from_script(&POLICY, (0, 0, 12_500));
+        let mut headers = HeaderMap::new();
+        decision.write_headers(&POLICY, &mut headers);
+
+        assert_eq!(headers["ratelimit-remaining"], "0");
+        assert_eq!(headers[RETRY_AFTER], "13");
+    }
+}
@@ -0,0 +1,35 @@
+-- Sliding-window-log rate limiter.
+--
+-- KEYS[1]  window key (sorted set of request timestamps in ms)
+-- ARGV[1]  window length in ms
+-- ARGV[2]  request limit per window
+-- ARGV[3]  unique member for this request
+--
+-- Returns { allowed (1|0), remaining, reset_ms } where reset_ms is the time until
+-- the oldest request in the window expires (i.e. until a slot frees up).
+local key = KEYS[1]
+local window = tonumber(ARGV[1])
+local limit = tonumber(ARGV[2])
+
+-- Use the Redis clock so every server instance shares one time source.
+local t = redis.call('TIME')
+local now = tonumber(t[1]) * 1000 + math.floor(tonumber(t[2]) / 1000)
+
+redis.call('ZREMRANGEBYSCORE', key, '-inf', now - window)
+local count = redis.call('ZCARD', key)
+
+local allowed = 0
+if count < limit then
+    redis.call('ZADD', key, now, ARGV[3])
+    count = count + 1
+    allowed = 1
+end
+redis.call('PEXPIRE', key, window)
+
+local reset = window
+local oldest = redis.call('ZRANGE', key, 0, 0, 'WITHSCORES')
+if oldest[2] then
+    reset = tonumber(oldest[2]) + window - now
+end
+
+return { allowed, limit - count, reset }
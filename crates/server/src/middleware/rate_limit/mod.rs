@@ -0,0 +1,149 @@
+//! Redis-backed per-route rate limiting.
+//!
+//! Attach a policy to a single route's method router so the budget is a visible property of the
+//! route table:
+//!
+//! ```ignore
+//! .route("/auth/login", post(auth_login).route_layer(RateLimitLayer::new(&state, &LOGIN_RATE_LIMIT)))
+//! ```
+//!
+//! Every response carries `RateLimit-Limit` / `RateLimit-Remaining` / `RateLimit-Reset` /
+//! `RateLimit-Policy`; rejected requests get `Errors::RateLimitExceeded` (429) plus `Retry-After`.
+//! The limiter fails open: if Redis is unreachable the request proceeds and a warning is logged,
+//! so a cache outage degrades protection rather than availability.
+
+mod limiter;
+mod policy;
+
+pub use limiter::{RateLimitDecision, check_rate_limit};
+pub use policy::*;
+
+use crate::extractors::session::resolve_session_from_request;
+use crate::state::AppState;
+use crate::utils::extract::extract_ip_address::extract_ip_address;
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, Request};
+use axum::response::{IntoResponse, Response};
+use config::ServerConfig;
+use errors::errors::Errors;
+use futures::future::BoxFuture;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tower_cookies::Cookies;
+use tracing::warn;
+
+/// Tower layer enforcing one [`RateLimitPolicy`].
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    state: AppState,
+    policy: &'static RateLimitPolicy,
+}
+
+impl RateLimitLayer {
+    pub fn new(state: &AppState, policy: &'static RateLimitPolicy) -> Self {
+        Self {
+            state: state.clone(),
+            policy,
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            state: self.state.clone(),
+            policy: self.policy,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    state: AppState,
+    policy: &'static RateLimitPolicy,
+}
+
+impl<S> Service<Request<Body>> for RateLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // Use the instance that was driven to readiness; leave a fresh clone for the next call.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let state = self.state.clone();
+        let policy = self.policy;
+
+        Box::pin(async move {
+            if !ServerConfig::get().rate_limit_enabled {
+                return inner.call(req).await;
+            }
+
+            let subject = rate_limit_subject(
+                &state,
+                policy,
+                req.headers().clone(),
+                req.extensions().get::<Cookies>().cloned(),
+                req.extensions()
+                    .get::<ConnectInfo<SocketAddr>>()
+                    .map(|ConnectInfo(addr)| *addr),
+            )
+            .await;
+
+            match check_rate_limit(&state.redis_session, policy, &subject).await {
+                Ok(decision) if decision.allowed => {
+                    let mut response = inner.call(req).await?;
+                    decision.write_headers(policy, response.headers_mut());
+                    Ok(response)
+                }
+                Ok(decision) => {
+                    let mut response = Errors::RateLimitExceeded.into_response();
+                    decision.write_headers(policy, response.headers_mut());
+                    Ok(response)
+                }
+                Err(e) => {
+                    warn!(policy = policy.name, error = ?e, "Rate limiter unavailable; allowing request");
+                    inner.call(req).await
+                }
+            }
+        })
+    }
+}
+
+/// Resolve the identity a request is counted against: `u:{user_id}` for a valid session on a
+/// [`RateLimitKey::UserOrIp`] policy, otherwise `ip:{client_ip}`.
+async fn rate_limit_subject(
+    state: &AppState,
+    policy: &RateLimitPolicy,
+    headers: HeaderMap,
+    cookies: Option<Cookies>,
+    addr: Option<SocketAddr>,
+) -> String {
+    if policy.key == RateLimitKey::UserOrIp
+        && let Some(cookies) = cookies
+        && let Ok(Some(session)) = resolve_session_from_request(&cookies, &headers, state).await
+    {
+        return format!("u:{}", session.user_id);
+    }
+
+    match addr {
+        Some(addr) => format!("ip:{}", extract_ip_address(&headers, addr)),
+        None => "ip:unknown".to_string(),
+    }
+}
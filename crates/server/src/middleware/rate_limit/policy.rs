@@ -0,0 +1,80 @@
+//! Per-route rate-limit policies.
+//!
+//! Each policy is a sliding-window budget — at most `limit` requests in any
+//! `window_seconds` span — counted against the caller identity chosen by `key`.
+//! The `name` is part of the Redis key, so two routes sharing a policy share one budget.
+
+/// What a policy counts requests against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKey {
+    /// The client IP from `extract_ip_address` (honours the trusted-proxy secret).
+    Ip,
+    /// The session's user id, falling back to the client IP for anonymous callers.
+    UserOrIp,
+}
+
+#[derive(Debug)]
+pub struct RateLimitPolicy {
+    /// Stable identifier used in the Redis key and logs.
+    pub name: &'static str,
+    /// Requests allowed per window.
+    pub limit: u32,
+    /// Sliding window length in seconds.
+    pub window_seconds: u64,
+    pub key: RateLimitKey,
+}
+
+impl RateLimitPolicy {
+    /// `RateLimit-Policy` header value, e.g. `10;w=60`.
+    pub fn header_value(&self) -> String {
+        format!("{};w={}", self.limit, self.window_seconds)
+    }
+}
+
+/// Password login (browser and native-app endpoints share one budget).
+pub static LOGIN_RATE_LIMIT: RateLimitPolicy = RateLimitPolicy {
+    name: "auth:login",
+    limit: 10,
+    window_seconds: 60,
+    key: RateLimitKey::Ip,
+};
+
+/// Email/password signup (each attempt sends a verification email).
+pub static SIGNUP_RATE_LIMIT: RateLimitPolicy = RateLimitPolicy {
+    name: "auth:signup",
+    limit: 5,
+    window_seconds: 60 * 60,
+    key: RateLimitKey::Ip,
+};
+
+/// Forgot-password (each attempt may send a reset email).
+pub static FORGOT_PASSWORD_RATE_LIMIT: RateLimitPolicy = RateLimitPolicy {
+    name: "auth:forgot_password",
+    limit: 5,
+    window_seconds: 60 * 60,
+    key: RateLimitKey::Ip,
+};
+
+/// Magic-link request (each attempt may send a sign-in email).
+pub static MAGIC_LINK_RATE_LIMIT: RateLimitPolicy = RateLimitPolicy {
+    name: "auth:magic_link",
+    limit: 5,
+    window_seconds: 60 * 60,
+    key: RateLimitKey::Ip,
+};
+
+/// Board post creation.
+pub static POST_CREATE_RATE_LIMIT: RateLimitPolicy = RateLimitPolicy {
+    name: "board:post_create",
+    limit: 10,
+    window_seconds: 60,
+    key: RateLimitKey::UserOrIp,
+};
+
+/// Profile and banner image uploads (each runs through the media processor).
+pub static IMAGE_UPLOAD_RATE_LIMIT: RateLimitPolicy = RateLimitPolicy {
+    name: "user:image_upload",
+    limit: 20,
+    window_seconds: 60 * 60,
+    key: RateLimitKey::UserOrIp,
+};
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use entity::personal_access_tokens::{
+    ActiveModel as AccessTokenActiveModel, Model as AccessTokenModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// Stores a newly minted personal access token.
+///
+/// # Related
+/// - `service_create_access_token`
+///
+/// # Errors
+/// - Returns a DB/repository error if the insert fails.
+pub async fn repository_create_access_token<C>(
+    conn: &C,
+    user_id: Uuid,
+    name: String,
+    token_hash: String,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<AccessTokenModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let token = AccessTokenActiveModel {
+        id: Default::default(),
+        user_id: Set(user_id),
+        name: Set(name),
+        token_hash: Set(token_hash),
+        scopes: Set(scopes),
+        expires_at: Set(expires_at),
+        last_used_at: Set(None),
+        created_at: Default::default(),
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(token)
+}
@@ -0,0 +1,32 @@
+use entity::personal_access_tokens::{Column as AccessTokenColumn, Entity as AccessTokenEntity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Deletes (revokes) one of the user's personal access tokens.
+///
+/// # Role
+/// Matches `id + user_id`, so a caller cannot revoke another user's token.
+/// Returns the affected row count.
+///
+/// # Related
+/// - `service_delete_access_token`
+///
+/// # Errors
+/// - Returns a DB/repository error if the delete fails.
+pub async fn repository_delete_access_token<C>(
+    conn: &C,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = AccessTokenEntity::delete_many()
+        .filter(AccessTokenColumn::UserId.eq(user_id))
+        .filter(AccessTokenColumn::Id.eq(id))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
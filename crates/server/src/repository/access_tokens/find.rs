@@ -0,0 +1,52 @@
+use entity::personal_access_tokens::{
+    Column as AccessTokenColumn, Entity as AccessTokenEntity, Model as AccessTokenModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// Lists a user's personal access tokens, newest first.
+///
+/// # Related
+/// - `service_list_access_tokens`
+/// - `service_create_access_token` (per-user limit)
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_access_tokens_by_user_id<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<AccessTokenModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let tokens = AccessTokenEntity::find()
+        .filter(AccessTokenColumn::UserId.eq(user_id))
+        .order_by_desc(AccessTokenColumn::Id)
+        .all(conn)
+        .await?;
+
+    Ok(tokens)
+}
+
+/// Looks up a token by the hash of its raw value.
+///
+/// # Related
+/// - `service_resolve_access_token`
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_access_token_by_hash<C>(
+    conn: &C,
+    token_hash: &str,
+) -> Result<Option<AccessTokenModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let token = AccessTokenEntity::find()
+        .filter(AccessTokenColumn::TokenHash.eq(token_hash))
+        .one(conn)
+        .await?;
+
+    Ok(token)
+}
@@ -0,0 +1,13 @@
+//! Personal access token repository APIs.
+//!
+//! One row per token, looked up by the hash of the raw token.
+
+mod create;
+mod delete;
+mod find;
+mod update;
+
+pub use create::repository_create_access_token;
+pub use delete::repository_delete_access_token;
+pub use find::{repository_find_access_token_by_hash, repository_find_access_tokens_by_user_id};
+pub use update::repository_touch_access_token;
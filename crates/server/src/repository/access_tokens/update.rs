@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use entity::personal_access_tokens::{Column as AccessTokenColumn, Entity as AccessTokenEntity};
+use errors::errors::Errors;
+use sea_orm::prelude::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Stamps `last_used_at` on a token.
+///
+/// # Related
+/// - `service_resolve_access_token`
+///
+/// # Errors
+/// - Returns a DB/repository error if the update fails.
+pub async fn repository_touch_access_token<C>(
+    conn: &C,
+    id: Uuid,
+    used_at: DateTime<Utc>,
+) -> Result<(), Errors>
+where
+    C: ConnectionTrait,
+{
+    AccessTokenEntity::update_many()
+        .col_expr(AccessTokenColumn::LastUsedAt, Expr::value(Some(used_at)))
+        .filter(AccessTokenColumn::Id.eq(id))
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
+use crate::repository::webhooks::repository_enqueue_webhook_deliveries;
 use constants::ActionLogAction;
+use dto::webhooks::WebhookEventPayload;
 use entity::action_logs::{ActiveModel as ActionLogActiveModel, Model as ActionLogModel};
 use entity::common::ActionResourceType;
 use errors::errors::Errors;
@@ -31,5 +33,8 @@ where
 
     let log = log.insert(conn).await?;
 
+    // Queued in the caller's transaction, so webhooks fire only for committed logs.
+    repository_enqueue_webhook_deliveries(conn, WebhookEventPayload::from(log.clone())).await?;
+
     Ok(log)
 }
@@ -11,6 +11,8 @@ use uuid::Uuid;
 /// is `text`, so new event types are additive with no enum migration.
 pub const AUTH_EVENT_LOGIN_SUCCESS: &str = "login_success";
 pub const AUTH_EVENT_LOGIN_FAILED: &str = "login_failed";
+pub const AUTH_EVENT_ACCOUNT_LOCKED: &str = "account_locked";
+pub const AUTH_EVENT_ACCOUNT_UNLOCKED: &str = "account_unlocked";
 pub const AUTH_EVENT_LOGOUT: &str = "logout";
 pub const AUTH_EVENT_PASSWORD_CHANGED: &str = "password_changed";
 pub const AUTH_EVENT_PASSWORD_RESET: &str = "password_reset";
@@ -19,6 +21,12 @@ pub const AUTH_EVENT_EMAIL_CHANGED: &str = "email_changed";
 pub const AUTH_EVENT_TOTP_ENABLED: &str = "totp_enabled";
 pub const AUTH_EVENT_TOTP_DISABLED: &str = "totp_disabled";
 pub const AUTH_EVENT_NEW_DEVICE: &str = "new_device_login";
+pub const AUTH_EVENT_PASSKEY_ADDED: &str = "passkey_added";
+pub const AUTH_EVENT_PASSKEY_REMOVED: &str = "passkey_removed";
+pub const AUTH_EVENT_ACCESS_TOKEN_CREATED: &str = "access_token_created";
+pub const AUTH_EVENT_ACCESS_TOKEN_REVOKED: &str = "access_token_revoked";
+pub const AUTH_EVENT_DEVICE_FORGOTTEN: &str = "device_forgotten";
+pub const AUTH_EVENT_OTHER_SESSIONS_REVOKED: &str = "other_sessions_revoked";
 
 /// Insert one authentication audit event.
 ///
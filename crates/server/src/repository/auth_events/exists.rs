@@ -0,0 +1,44 @@
+use entity::auth_events::{Column as AuthEventColumn, Entity as AuthEventEntity};
+use errors::errors::Errors;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect,
+};
+use uuid::Uuid;
+
+/// Whether the user has an authentication event newer than `cursor_id`.
+pub async fn repository_exists_newer_auth_event<C>(
+    conn: &C,
+    user_id: Uuid,
+    cursor_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let count = AuthEventEntity::find()
+        .filter(AuthEventColumn::UserId.eq(user_id))
+        .filter(AuthEventColumn::Id.gt(cursor_id))
+        .limit(1)
+        .count(conn)
+        .await?;
+
+    Ok(count > 0)
+}
+
+/// Whether the user has an authentication event older than `cursor_id`.
+pub async fn repository_exists_older_auth_event<C>(
+    conn: &C,
+    user_id: Uuid,
+    cursor_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let count = AuthEventEntity::find()
+        .filter(AuthEventColumn::UserId.eq(user_id))
+        .filter(AuthEventColumn::Id.lt(cursor_id))
+        .limit(1)
+        .count(conn)
+        .await?;
+
+    Ok(count > 0)
+}
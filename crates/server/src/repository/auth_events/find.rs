@@ -0,0 +1,47 @@
+use dto::pagination::CursorDirection;
+use entity::auth_events::{
+    Column as AuthEventColumn, Entity as AuthEventEntity, Model as AuthEventModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// Fetches a page of one user's authentication events.
+///
+/// # Role
+/// Newest first without a cursor; `Older` pages descend from the cursor and
+/// `Newer` pages ascend from it (the service restores display order). Served by
+/// the `(user_id, id)` index.
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_user_auth_events<C>(
+    conn: &C,
+    user_id: Uuid,
+    cursor_id: Option<Uuid>,
+    cursor_direction: Option<CursorDirection>,
+    limit: u64,
+) -> Result<Vec<AuthEventModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut query = AuthEventEntity::find().filter(AuthEventColumn::UserId.eq(user_id));
+
+    if let Some(id) = cursor_id {
+        let direction = cursor_direction.unwrap_or(CursorDirection::Older);
+        query = match direction {
+            CursorDirection::Older => query
+                .filter(AuthEventColumn::Id.lt(id))
+                .order_by_desc(AuthEventColumn::Id),
+            CursorDirection::Newer => query
+                .filter(AuthEventColumn::Id.gt(id))
+                .order_by_asc(AuthEventColumn::Id),
+        };
+    } else {
+        query = query.order_by_desc(AuthEventColumn::Id);
+    }
+
+    let events = query.limit(limit).all(conn).await?;
+
+    Ok(events)
+}
@@ -2,8 +2,12 @@
 //!
 //! Durable, private-tier record of authentication decisions (login success/failure, logout,
 //! credential/2FA changes). Recording is best-effort at call sites so a write failure never breaks
-//! the authentication flow.
+//! the authentication flow. Users read back only their own rows (login history).
 
 pub mod create;
+pub mod exists;
+pub mod find;
 
 pub use create::*;
+pub use exists::*;
+pub use find::*;
@@ -1,6 +1,7 @@
 use entity::board_comments::{ActiveModel as CommentActiveModel, Model as CommentModel};
 use errors::errors::Errors;
 use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn repository_create_board_comment<C>(
@@ -20,8 +21,13 @@ where
         parent_comment_id: Set(parent_comment_id),
         content: Set(content),
         reply_count: Set(0),
+        reaction_count: Set(0),
+        reaction_counts: Set(json!({})),
         created_at: Default::default(),
         edited_at: Set(None),
+        hidden_at: Set(None),
+        hidden_by: Set(None),
+        hidden_reason: Set(None),
     };
 
     let comment = new_comment.insert(conn).await?;
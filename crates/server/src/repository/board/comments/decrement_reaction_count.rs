@@ -0,0 +1,38 @@
+use entity::board_comments::{Column as CommentColumn, Entity as CommentEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Decrement a comment's `reaction_count` and its `reaction_counts[kind]` entry.
+pub async fn repository_decrement_comment_reaction_count<C>(
+    conn: &C,
+    comment_id: Uuid,
+    kind: &str,
+) -> Result<(), Errors>
+where
+    C: ConnectionTrait,
+{
+    // Clamped at 0 like the reply counter; a kind that drops to 0 is removed
+    // from the map rather than kept as a zero entry.
+    CommentEntity::update_many()
+        .filter(CommentColumn::Id.eq(comment_id))
+        .col_expr(
+            CommentColumn::ReactionCount,
+            Expr::cust("GREATEST(reaction_count - 1, 0)"),
+        )
+        .col_expr(
+            CommentColumn::ReactionCounts,
+            Expr::cust_with_values(
+                "CASE WHEN COALESCE((reaction_counts ->> $1)::int, 0) <= 1 \
+                 THEN reaction_counts - $1 \
+                 ELSE jsonb_set(reaction_counts, ARRAY[$1], \
+                 to_jsonb((reaction_counts ->> $1)::int - 1)) END",
+                [kind],
+            ),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -20,8 +20,9 @@ where
 
 /// Fetch a single board comment by ID with a row lock (`SELECT ... FOR UPDATE`).
 ///
-/// Used to serialize concurrent deletes of the same comment so the post's
-/// `comment_count` and the thread root's `reply_count` are adjusted exactly once.
+/// Used to serialize concurrent mutations of the same comment: deletes, so the
+/// post's `comment_count` and the thread root's `reply_count` are adjusted exactly
+/// once, and edits, so each revision snapshots the body it actually replaces.
 ///
 /// # Errors
 /// - `Errors::BoardCommentNotFound` if the comment does not exist.
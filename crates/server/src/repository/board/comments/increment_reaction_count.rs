@@ -0,0 +1,34 @@
+use entity::board_comments::{Column as CommentColumn, Entity as CommentEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, ExprTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Increment a comment's `reaction_count` and its `reaction_counts[kind]` entry.
+pub async fn repository_increment_comment_reaction_count<C>(
+    conn: &C,
+    comment_id: Uuid,
+    kind: &str,
+) -> Result<(), Errors>
+where
+    C: ConnectionTrait,
+{
+    CommentEntity::update_many()
+        .filter(CommentColumn::Id.eq(comment_id))
+        .col_expr(
+            CommentColumn::ReactionCount,
+            Expr::col(CommentColumn::ReactionCount).add(1),
+        )
+        .col_expr(
+            CommentColumn::ReactionCounts,
+            Expr::cust_with_values(
+                "jsonb_set(reaction_counts, ARRAY[$1], \
+                 to_jsonb(COALESCE((reaction_counts ->> $1)::int, 0) + 1))",
+                [kind],
+            ),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod create;
+mod decrement_reaction_count;
 mod decrement_reply_count;
 mod delete;
 mod exists_newer;
@@ -6,10 +7,13 @@ mod exists_older;
 mod find_around;
 mod find_by_post_id;
 mod get_by_id;
+mod increment_reaction_count;
 mod increment_reply_count;
+mod set_hidden;
 mod update;
 
 pub use create::*;
+pub use decrement_reaction_count::*;
 pub use decrement_reply_count::*;
 pub use delete::*;
 pub use exists_newer::*;
@@ -17,5 +21,7 @@ pub use exists_older::*;
 pub use find_around::*;
 pub use find_by_post_id::*;
 pub use get_by_id::*;
+pub use increment_reaction_count::*;
 pub use increment_reply_count::*;
+pub use set_hidden::*;
 pub use update::*;
@@ -0,0 +1,31 @@
+use chrono::Utc;
+use entity::board_comments::{
+    ActiveModel as CommentActiveModel, Entity as CommentEntity, Model as CommentModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+/// Hide a board comment (`hidden_by` = moderator actor id) or unhide it (`None`).
+/// Moderation-only (gated in the service layer). Hiding an already hidden comment
+/// restamps it with the new moderator and reason.
+pub async fn repository_set_board_comment_hidden<C>(
+    conn: &C,
+    id: Uuid,
+    hidden_by: Option<Uuid>,
+    reason: Option<String>,
+) -> Result<CommentModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let comment = CommentEntity::find_by_id(id)
+        .one(conn)
+        .await?
+        .ok_or(Errors::BoardCommentNotFound)?;
+
+    let mut active: CommentActiveModel = comment.into();
+    active.hidden_at = Set(hidden_by.map(|_| Utc::now()));
+    active.hidden_by = Set(hidden_by);
+    active.hidden_reason = Set(hidden_by.and(reason));
+    Ok(active.update(conn).await?)
+}
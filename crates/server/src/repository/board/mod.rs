@@ -5,7 +5,10 @@ mod find_list;
 mod get_by_id;
 mod get_by_slug;
 pub mod posts;
+pub mod reactions;
+pub mod revisions;
 mod update;
+pub mod watchers;
 
 pub use create::*;
 pub use delete::*;
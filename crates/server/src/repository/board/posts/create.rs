@@ -1,6 +1,7 @@
 use entity::board_posts::{ActiveModel as PostActiveModel, Model as PostModel};
 use errors::errors::Errors;
 use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use serde_json::json;
 use uuid::Uuid;
 
 pub async fn repository_create_board_post<C>(
@@ -23,8 +24,14 @@ where
         is_locked: Set(false),
         view_count: Set(0),
         comment_count: Set(0),
+        reaction_count: Set(0),
+        reaction_counts: Set(json!({})),
+        hot_score: Set(0.0),
         created_at: Default::default(),
         edited_at: Set(None),
+        hidden_at: Set(None),
+        hidden_by: Set(None),
+        hidden_reason: Set(None),
     };
 
     let post = new_post.insert(conn).await?;
@@ -0,0 +1,38 @@
+use entity::board_posts::{Column as PostColumn, Entity as PostEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Decrement a post's `reaction_count` and its `reaction_counts[kind]` entry.
+pub async fn repository_decrement_post_reaction_count<C>(
+    conn: &C,
+    post_id: Uuid,
+    kind: &str,
+) -> Result<(), Errors>
+where
+    C: ConnectionTrait,
+{
+    // Clamped at 0 like the comment counter; a kind that drops to 0 is removed
+    // from the map rather than kept as a zero entry.
+    PostEntity::update_many()
+        .filter(PostColumn::Id.eq(post_id))
+        .col_expr(
+            PostColumn::ReactionCount,
+            Expr::cust("GREATEST(reaction_count - 1, 0)"),
+        )
+        .col_expr(
+            PostColumn::ReactionCounts,
+            Expr::cust_with_values(
+                "CASE WHEN COALESCE((reaction_counts ->> $1)::int, 0) <= 1 \
+                 THEN reaction_counts - $1 \
+                 ELSE jsonb_set(reaction_counts, ARRAY[$1], \
+                 to_jsonb((reaction_counts ->> $1)::int - 1)) END",
+                [kind],
+            ),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
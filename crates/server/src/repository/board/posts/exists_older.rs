@@ -0,0 +1,29 @@
+use super::keyset::{BoardPostCursor, board_post_listing, past_cursor};
+use crate::repository::common::repository_query_exists;
+use chrono::{DateTime, Utc};
+use dto::board::BoardPostSort;
+use dto::pagination::CursorDirection;
+use errors::errors::Errors;
+use sea_orm::ConnectionTrait;
+use uuid::Uuid;
+
+/// Whether an unpinned post sits below `cursor` in the board's `sort` listing.
+pub async fn repository_exists_older_board_post<C>(
+    conn: &C,
+    board_id: Uuid,
+    sort: BoardPostSort,
+    created_since: Option<DateTime<Utc>>,
+    cursor: BoardPostCursor,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let query = past_cursor(
+        board_post_listing(board_id, created_since),
+        sort,
+        cursor,
+        CursorDirection::Older,
+    );
+
+    repository_query_exists(conn, query).await
+}
@@ -1,34 +1,51 @@
-use entity::board_posts::{Column as PostColumn, Entity as PostEntity, Model as PostModel};
+use super::keyset::{BoardPostCursor, board_post_listing, order_by_sort_key, past_cursor};
+use chrono::{DateTime, Utc};
+use dto::board::BoardPostSort;
+use dto::pagination::CursorDirection;
+use entity::board_posts::Model as PostModel;
 use errors::errors::Errors;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::sea_query::Order;
+use sea_orm::{ConnectionTrait, QuerySelect};
 use uuid::Uuid;
 
-/// List a board's unpinned top-level posts, newest-first.
+/// List a board's unpinned top-level posts in `sort` order, with keyset pagination.
 ///
-/// Pins are excluded rather than sorted to the front: they are read whole by
-/// [`repository_find_pinned_board_posts`] and rendered above every page. Ordering
-/// them first inside this offset window instead — as this query once did — both
-/// stranded them on page 1 and spent that page's slots on them.
+/// Pins are excluded: they are served by [`repository_find_pinned_board_posts`]
+/// and rendered above every page.
+///
+/// `created_since` bounds the listing to recent posts (the `top` window). Every
+/// sort ends on `(created_at, id)`, so the cursor is a strict row comparison on
+/// the full sort key: posts arriving between requests neither shift nor repeat
+/// the pages after it. Without a cursor the first page is returned top-down; a
+/// `Newer` page is read upward (ascending) from the cursor.
 ///
 /// [`repository_find_pinned_board_posts`]: super::repository_find_pinned_board_posts
 pub async fn repository_find_board_posts<C>(
     conn: &C,
     board_id: Uuid,
-    offset: u64,
+    sort: BoardPostSort,
+    created_since: Option<DateTime<Utc>>,
+    cursor: Option<BoardPostCursor>,
+    cursor_direction: Option<CursorDirection>,
     limit: u64,
 ) -> Result<Vec<PostModel>, Errors>
 where
     C: ConnectionTrait,
 {
-    let posts = PostEntity::find()
-        .filter(PostColumn::BoardId.eq(board_id))
-        .filter(PostColumn::PinnedPosition.is_null())
-        .order_by_desc(PostColumn::CreatedAt)
-        .order_by_desc(PostColumn::Id)
-        .offset(offset)
-        .limit(limit)
-        .all(conn)
-        .await?;
+    let mut query = board_post_listing(board_id, created_since);
+
+    if let Some(cursor) = cursor {
+        let direction = cursor_direction.unwrap_or(CursorDirection::Older);
+        query = past_cursor(query, sort, cursor, direction);
+        query = match direction {
+            CursorDirection::Older => order_by_sort_key(query, sort, Order::Desc),
+            CursorDirection::Newer => order_by_sort_key(query, sort, Order::Asc),
+        };
+    } else {
+        query = order_by_sort_key(query, sort, Order::Desc);
+    }
+
+    let posts = query.limit(limit).all(conn).await?;
 
     Ok(posts)
 }
@@ -0,0 +1,34 @@
+use entity::board_posts::{Column as PostColumn, Entity as PostEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, ExprTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Increment a post's `reaction_count` and its `reaction_counts[kind]` entry.
+pub async fn repository_increment_post_reaction_count<C>(
+    conn: &C,
+    post_id: Uuid,
+    kind: &str,
+) -> Result<(), Errors>
+where
+    C: ConnectionTrait,
+{
+    PostEntity::update_many()
+        .filter(PostColumn::Id.eq(post_id))
+        .col_expr(
+            PostColumn::ReactionCount,
+            Expr::col(PostColumn::ReactionCount).add(1),
+        )
+        .col_expr(
+            PostColumn::ReactionCounts,
+            Expr::cust_with_values(
+                "jsonb_set(reaction_counts, ARRAY[$1], \
+                 to_jsonb(COALESCE((reaction_counts ->> $1)::int, 0) + 1))",
+                [kind],
+            ),
+        )
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
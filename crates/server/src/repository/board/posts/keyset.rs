@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use dto::board::BoardPostSort;
+use dto::pagination::CursorDirection;
+use entity::board_posts::{Column as PostColumn, Entity as PostEntity, Model as PostModel};
+use sea_orm::sea_query::{Expr, ExprTrait, Order};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Select, Value};
+use uuid::Uuid;
+
+/// Position of a post in a board listing: every column any sort orders by.
+///
+/// `new` pages on `(created_at, id)` alone; the ranked sorts put their score in
+/// front of that pair, so a cursor taken from the edge of one page resumes the
+/// same order on the next.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardPostCursor {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub reaction_count: i32,
+    pub view_count: i32,
+    pub comment_count: i32,
+    pub hot_score: f64,
+}
+
+impl From<&PostModel> for BoardPostCursor {
+    fn from(post: &PostModel) -> Self {
+        Self {
+            id: post.id,
+            created_at: post.created_at,
+            reaction_count: post.reaction_count,
+            view_count: post.view_count,
+            comment_count: post.comment_count,
+            hot_score: post.hot_score,
+        }
+    }
+}
+
+impl BoardPostCursor {
+    fn value(&self, column: PostColumn) -> Value {
+        match column {
+            PostColumn::ReactionCount => self.reaction_count.into(),
+            PostColumn::ViewCount => self.view_count.into(),
+            PostColumn::CommentCount => self.comment_count.into(),
+            PostColumn::HotScore => self.hot_score.into(),
+            PostColumn::CreatedAt => self.created_at.into(),
+            _ => self.id.into(),
+        }
+    }
+}
+
+/// Sort key of a listing, most significant first. The listing is descending on
+/// every column, which is what lets a single row comparison act as the cursor.
+fn sort_columns(sort: BoardPostSort) -> &'static [PostColumn] {
+    match sort {
+        BoardPostSort::New => &[PostColumn::CreatedAt, PostColumn::Id],
+        BoardPostSort::Top => &[
+            PostColumn::ReactionCount,
+            PostColumn::ViewCount,
+            PostColumn::CreatedAt,
+            PostColumn::Id,
+        ],
+        BoardPostSort::Hot => &[PostColumn::HotScore, PostColumn::CreatedAt, PostColumn::Id],
+        BoardPostSort::MostCommented => &[
+            PostColumn::CommentCount,
+            PostColumn::CreatedAt,
+            PostColumn::Id,
+        ],
+    }
+}
+
+/// A board's unpinned posts, bounded to `created_since` when set (the `top` window).
+pub(super) fn board_post_listing(
+    board_id: Uuid,
+    created_since: Option<DateTime<Utc>>,
+) -> Select<PostEntity> {
+    let mut query = PostEntity::find()
+        .filter(PostColumn::BoardId.eq(board_id))
+        .filter(PostColumn::PinnedPosition.is_null());
+
+    if let Some(since) = created_since {
+        query = query.filter(PostColumn::CreatedAt.gte(since));
+    }
+
+    query
+}
+
+/// Restricts `query` to the posts on the `direction` side of `cursor`:
+/// `(key...) < (cursor key...)` for `Older`, `>` for `Newer`.
+pub(super) fn past_cursor(
+    query: Select<PostEntity>,
+    sort: BoardPostSort,
+    cursor: BoardPostCursor,
+    direction: CursorDirection,
+) -> Select<PostEntity> {
+    let columns = sort_columns(sort);
+    let key = Expr::tuple(columns.iter().map(|column| Expr::col(*column)));
+    let bound = Expr::tuple(
+        columns
+            .iter()
+            .map(|column| Expr::val(cursor.value(*column))),
+    );
+
+    query.filter(match direction {
+        CursorDirection::Older => key.lt(bound),
+        CursorDirection::Newer => key.gt(bound),
+    })
+}
+
+/// Orders `query` by the sort key, all columns in `order`.
+pub(super) fn order_by_sort_key(
+    mut query: Select<PostEntity>,
+    sort: BoardPostSort,
+    order: Order,
+) -> Select<PostEntity> {
+    for column in sort_columns(sort) {
+        query = query.order_by(*column, order.clone());
+    }
+    query
+}
@@ -1,23 +1,35 @@
 mod create;
 mod decrement_comment_count;
+mod decrement_reaction_count;
 mod delete;
+mod exists_newer;
+mod exists_older;
 mod find_by_board_id;
 mod find_pinned_by_board_id;
 mod get_by_id;
 mod increment_comment_count;
+mod increment_reaction_count;
+mod keyset;
 mod reorder_pins;
+mod set_hidden;
 mod set_locked;
 mod set_pinned;
 mod update;
 
 pub use create::*;
 pub use decrement_comment_count::*;
+pub use decrement_reaction_count::*;
 pub use delete::*;
+pub use exists_newer::*;
+pub use exists_older::*;
 pub use find_by_board_id::*;
 pub use find_pinned_by_board_id::*;
 pub use get_by_id::*;
 pub use increment_comment_count::*;
+pub use increment_reaction_count::*;
+pub use keyset::BoardPostCursor;
 pub use reorder_pins::*;
+pub use set_hidden::*;
 pub use set_locked::*;
 pub use set_pinned::*;
 pub use update::*;
@@ -0,0 +1,37 @@
+use entity::board_reactions::{ActiveModel as ReactionActiveModel, Entity as ReactionEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+/// Record `actor_id`'s reaction of `kind` on a post (`comment_id = None`) or on one
+/// of its comments. Idempotent: returns `false` when the reaction already existed,
+/// so the caller only bumps the target's counters for a genuinely new row.
+pub async fn repository_create_board_reaction<C>(
+    conn: &C,
+    actor_id: Uuid,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    kind: &str,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let new_reaction = ReactionActiveModel {
+        id: Default::default(),
+        actor_id: Set(actor_id),
+        post_id: Set(post_id),
+        comment_id: Set(comment_id),
+        kind: Set(kind.to_string()),
+        created_at: Default::default(),
+    };
+
+    // No conflict target: the uniqueness lives in two partial indexes (post vs.
+    // comment reactions), and a bare `ON CONFLICT DO NOTHING` covers both.
+    let inserted = ReactionEntity::insert(new_reaction)
+        .on_conflict(OnConflict::new().do_nothing().to_owned())
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(inserted > 0)
+}
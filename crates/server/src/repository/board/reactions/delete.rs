@@ -0,0 +1,31 @@
+use entity::board_reactions::{Column as ReactionColumn, Entity as ReactionEntity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Remove `actor_id`'s reaction of `kind` on a post (`comment_id = None`) or on one
+/// of its comments. Returns `false` when there was nothing to remove.
+pub async fn repository_delete_board_reaction<C>(
+    conn: &C,
+    actor_id: Uuid,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    kind: &str,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut query = ReactionEntity::delete_many()
+        .filter(ReactionColumn::ActorId.eq(actor_id))
+        .filter(ReactionColumn::PostId.eq(post_id))
+        .filter(ReactionColumn::Kind.eq(kind));
+
+    query = match comment_id {
+        Some(id) => query.filter(ReactionColumn::CommentId.eq(id)),
+        None => query.filter(ReactionColumn::CommentId.is_null()),
+    };
+
+    let result = query.exec(conn).await?;
+
+    Ok(result.rows_affected > 0)
+}
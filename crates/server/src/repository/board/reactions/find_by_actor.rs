@@ -0,0 +1,53 @@
+use entity::board_reactions::{
+    Column as ReactionColumn, Entity as ReactionEntity, Model as ReactionModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// `actor_id`'s own reactions on the given posts (post-level only, not their comments).
+pub async fn repository_find_post_reactions_by_actor<C>(
+    conn: &C,
+    actor_id: Uuid,
+    post_ids: &[Uuid],
+) -> Result<Vec<ReactionModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if post_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let reactions = ReactionEntity::find()
+        .filter(ReactionColumn::ActorId.eq(actor_id))
+        .filter(ReactionColumn::PostId.is_in(post_ids.iter().copied()))
+        .filter(ReactionColumn::CommentId.is_null())
+        .order_by_asc(ReactionColumn::Id)
+        .all(conn)
+        .await?;
+
+    Ok(reactions)
+}
+
+/// `actor_id`'s own reactions on the given comments.
+pub async fn repository_find_comment_reactions_by_actor<C>(
+    conn: &C,
+    actor_id: Uuid,
+    comment_ids: &[Uuid],
+) -> Result<Vec<ReactionModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if comment_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let reactions = ReactionEntity::find()
+        .filter(ReactionColumn::ActorId.eq(actor_id))
+        .filter(ReactionColumn::CommentId.is_in(comment_ids.iter().copied()))
+        .order_by_asc(ReactionColumn::Id)
+        .all(conn)
+        .await?;
+
+    Ok(reactions)
+}
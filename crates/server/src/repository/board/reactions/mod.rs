@@ -0,0 +1,7 @@
+mod create;
+mod delete;
+mod find_by_actor;
+
+pub use create::*;
+pub use delete::*;
+pub use find_by_actor::*;
@@ -0,0 +1,37 @@
+use entity::board_post_revisions::{ActiveModel as RevisionActiveModel, Model as RevisionModel};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// Insert the index row for a revision body already written to the revisions bucket.
+///
+/// `id` is supplied by the caller because the storage key embeds it, and the
+/// object is uploaded before this row is written.
+pub async fn repository_create_board_revision<C>(
+    conn: &C,
+    id: Uuid,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    title: Option<String>,
+    storage_key: String,
+    content_length: i32,
+    actor_id: Uuid,
+) -> Result<RevisionModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let revision = RevisionActiveModel {
+        id: Set(id),
+        post_id: Set(post_id),
+        comment_id: Set(comment_id),
+        title: Set(title),
+        storage_key: Set(storage_key),
+        content_length: Set(content_length),
+        actor_id: Set(actor_id),
+        created_at: Default::default(),
+    };
+
+    let revision = revision.insert(conn).await?;
+
+    Ok(revision)
+}
@@ -0,0 +1,39 @@
+use entity::board_post_revisions::{
+    Column as RevisionColumn, Entity as RevisionEntity, Model as RevisionModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// List the revisions of a post body (`comment_id = None`) or of one comment,
+/// newest first. `cursor_id` pages toward older revisions (ids are uuidv7, so
+/// id order is creation order).
+pub async fn repository_find_board_revisions<C>(
+    conn: &C,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    cursor_id: Option<Uuid>,
+    limit: u64,
+) -> Result<Vec<RevisionModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut query = RevisionEntity::find().filter(RevisionColumn::PostId.eq(post_id));
+
+    query = match comment_id {
+        Some(cid) => query.filter(RevisionColumn::CommentId.eq(cid)),
+        None => query.filter(RevisionColumn::CommentId.is_null()),
+    };
+
+    if let Some(id) = cursor_id {
+        query = query.filter(RevisionColumn::Id.lt(id));
+    }
+
+    let revisions = query
+        .order_by_desc(RevisionColumn::Id)
+        .limit(limit)
+        .all(conn)
+        .await?;
+
+    Ok(revisions)
+}
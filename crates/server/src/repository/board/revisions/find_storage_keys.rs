@@ -0,0 +1,84 @@
+use entity::board_comments::{Column as CommentColumn, Entity as CommentEntity};
+use entity::board_post_revisions::{Column as RevisionColumn, Entity as RevisionEntity};
+use entity::board_posts::{Column as PostColumn, Entity as PostEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Query;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
+
+/// Storage keys of every revision under a post (post body and all its comments).
+///
+/// Read before the post is deleted: the rows cascade away with it, but the
+/// objects in the revisions bucket do not.
+pub async fn repository_find_board_revision_keys_by_post<C>(
+    conn: &C,
+    post_id: Uuid,
+) -> Result<Vec<String>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let keys = RevisionEntity::find()
+        .select_only()
+        .column(RevisionColumn::StorageKey)
+        .filter(RevisionColumn::PostId.eq(post_id))
+        .into_tuple::<String>()
+        .all(conn)
+        .await?;
+
+    Ok(keys)
+}
+
+/// Storage keys of every revision of a comment and of its replies, which cascade
+/// away together with it on delete.
+pub async fn repository_find_board_revision_keys_by_comment_thread<C>(
+    conn: &C,
+    comment_id: Uuid,
+) -> Result<Vec<String>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let reply_ids = Query::select()
+        .column(CommentColumn::Id)
+        .from(CommentEntity)
+        .and_where(CommentColumn::ParentCommentId.eq(comment_id))
+        .to_owned();
+
+    let keys = RevisionEntity::find()
+        .select_only()
+        .column(RevisionColumn::StorageKey)
+        .filter(
+            Condition::any()
+                .add(RevisionColumn::CommentId.eq(comment_id))
+                .add(RevisionColumn::CommentId.in_subquery(reply_ids)),
+        )
+        .into_tuple::<String>()
+        .all(conn)
+        .await?;
+
+    Ok(keys)
+}
+
+/// Storage keys of every revision in a board, whose posts cascade away with it.
+pub async fn repository_find_board_revision_keys_by_board<C>(
+    conn: &C,
+    board_id: Uuid,
+) -> Result<Vec<String>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let post_ids = Query::select()
+        .column(PostColumn::Id)
+        .from(PostEntity)
+        .and_where(PostColumn::BoardId.eq(board_id))
+        .to_owned();
+
+    let keys = RevisionEntity::find()
+        .select_only()
+        .column(RevisionColumn::StorageKey)
+        .filter(RevisionColumn::PostId.in_subquery(post_ids))
+        .into_tuple::<String>()
+        .all(conn)
+        .await?;
+
+    Ok(keys)
+}
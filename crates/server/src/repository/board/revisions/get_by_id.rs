@@ -0,0 +1,19 @@
+use entity::board_post_revisions::{Entity as RevisionEntity, Model as RevisionModel};
+use errors::errors::Errors;
+use sea_orm::{ConnectionTrait, EntityTrait};
+use uuid::Uuid;
+
+pub async fn repository_get_board_revision_by_id<C>(
+    conn: &C,
+    id: Uuid,
+) -> Result<RevisionModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let revision = RevisionEntity::find_by_id(id)
+        .one(conn)
+        .await?
+        .ok_or(Errors::BoardRevisionNotFound)?;
+
+    Ok(revision)
+}
@@ -0,0 +1,9 @@
+mod create;
+mod find_by_target;
+mod find_storage_keys;
+mod get_by_id;
+
+pub use create::*;
+pub use find_by_target::*;
+pub use find_storage_keys::*;
+pub use get_by_id::*;
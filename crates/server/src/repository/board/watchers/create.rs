@@ -0,0 +1,35 @@
+use entity::board_post_watchers::{
+    ActiveModel as WatcherActiveModel, Column as WatcherColumn, Entity as WatcherEntity,
+};
+use errors::errors::Errors;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+/// Make `user_id` watch a post. Idempotent: returns `false` when they already did.
+pub async fn repository_create_board_post_watcher<C>(
+    conn: &C,
+    post_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let new_watcher = WatcherActiveModel {
+        id: Default::default(),
+        post_id: Set(post_id),
+        user_id: Set(user_id),
+        created_at: Default::default(),
+    };
+
+    let inserted = WatcherEntity::insert(new_watcher)
+        .on_conflict(
+            OnConflict::columns([WatcherColumn::PostId, WatcherColumn::UserId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(inserted > 0)
+}
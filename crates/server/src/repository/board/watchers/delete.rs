@@ -0,0 +1,22 @@
+use entity::board_post_watchers::{Column as WatcherColumn, Entity as WatcherEntity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Stop `user_id` watching a post. Returns `false` when they were not watching.
+pub async fn repository_delete_board_post_watcher<C>(
+    conn: &C,
+    post_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = WatcherEntity::delete_many()
+        .filter(WatcherColumn::PostId.eq(post_id))
+        .filter(WatcherColumn::UserId.eq(user_id))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected > 0)
+}
@@ -0,0 +1,21 @@
+use crate::repository::common::repository_query_exists;
+use entity::board_post_watchers::{Column as WatcherColumn, Entity as WatcherEntity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Whether `user_id` is watching a post.
+pub async fn repository_exists_board_post_watcher<C>(
+    conn: &C,
+    post_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let query = WatcherEntity::find()
+        .filter(WatcherColumn::PostId.eq(post_id))
+        .filter(WatcherColumn::UserId.eq(user_id));
+
+    repository_query_exists(conn, query).await
+}
@@ -0,0 +1,7 @@
+mod create;
+mod delete;
+mod exists;
+
+pub use create::*;
+pub use delete::*;
+pub use exists::*;
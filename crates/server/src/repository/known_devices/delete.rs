@@ -0,0 +1,18 @@
+use entity::known_devices::{Column, Entity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Forget one of the user's trusted devices. Matches `id + user_id` and returns the affected row count.
+pub async fn repository_delete_known_device<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<u64, Errors> {
+    let result = Entity::delete_many()
+        .filter(Column::UserId.eq(user_id))
+        .filter(Column::Id.eq(id))
+        .exec(conn)
+        .await?;
+    Ok(result.rows_affected)
+}
@@ -1,6 +1,6 @@
 use entity::known_devices::{Column, Entity, Model};
 use errors::errors::Errors;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
 use uuid::Uuid;
 
 /// Look up a trusted device for `(user_id, device_hash)`.
@@ -15,3 +15,15 @@ pub async fn repository_find_known_device<C: ConnectionTrait>(
         .one(conn)
         .await?)
 }
+
+/// List a user's trusted devices, most recently seen first.
+pub async fn repository_find_known_devices_by_user<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<Model>, Errors> {
+    Ok(Entity::find()
+        .filter(Column::UserId.eq(user_id))
+        .order_by_desc(Column::LastSeen)
+        .all(conn)
+        .await?)
+}
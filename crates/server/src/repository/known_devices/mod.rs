@@ -1,9 +1,11 @@
 //! Trusted-device registry repository (new-device login verification, OWASP ASVS 6.3.5).
 
 pub mod create;
+pub mod delete;
 pub mod find;
 pub mod update;
 
 pub use create::*;
+pub use delete::*;
 pub use find::*;
 pub use update::*;
@@ -1,3 +1,4 @@
+pub mod access_tokens;
 pub mod action_logs;
 pub mod actors;
 pub mod auth_events;
@@ -10,4 +11,7 @@ pub mod known_devices;
 pub mod moderation;
 pub mod notification;
 pub mod oauth;
+pub mod passkeys;
+pub mod report;
 pub mod user;
+pub mod webhooks;
@@ -1,4 +1,6 @@
+use crate::repository::webhooks::repository_enqueue_webhook_deliveries;
 use constants::ModerationAction;
+use dto::webhooks::WebhookEventPayload;
 use entity::common::ModerationResourceType;
 use entity::moderation_logs::{
     ActiveModel as ModerationLogActiveModel, Model as ModerationLogModel,
@@ -33,5 +35,8 @@ where
 
     let log = log.insert(conn).await?;
 
+    // Queued in the caller's transaction, so webhooks fire only for committed logs.
+    repository_enqueue_webhook_deliveries(conn, WebhookEventPayload::from(log.clone())).await?;
+
     Ok(log)
 }
@@ -0,0 +1,16 @@
+use entity::moderation_logs::{Entity as ModerationLogEntity, Model as ModerationLogModel};
+use errors::errors::Errors;
+use sea_orm::{ConnectionTrait, EntityTrait};
+use uuid::Uuid;
+
+pub async fn repository_find_moderation_log_by_id<C>(
+    conn: &C,
+    id: Uuid,
+) -> Result<Option<ModerationLogModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let log = ModerationLogEntity::find_by_id(id).one(conn).await?;
+
+    Ok(log)
+}
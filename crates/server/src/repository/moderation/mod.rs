@@ -1,9 +1,11 @@
 pub mod create;
 pub mod exists;
 mod filter;
+pub mod find_by_id;
 pub mod find_list;
 
 pub use create::repository_create_moderation_log;
 pub use exists::*;
 pub use filter::ModerationLogFilter;
+pub use find_by_id::repository_find_moderation_log_by_id;
 pub use find_list::repository_find_moderation_logs;
@@ -0,0 +1,44 @@
+use constants::NotificationAction;
+use entity::common::NotificationType;
+use entity::notification_events::Model as NotificationEventModel;
+use errors::errors::Errors;
+use notification_repository::{
+    NotificationEventInsertSpec, NotificationTarget, insert_notification_event,
+};
+use sea_orm::ConnectionTrait;
+use serde_json::Value as JsonValue;
+
+/// Creates the `notification_events` row of a system announcement.
+///
+/// # Role
+/// Writes the event alone: it has no actor and no target, and `additional_data`
+/// holds the announcement content. Deliveries are added later by the worker
+/// fan-out.
+///
+/// # Related
+/// - `service_create_system_announcement`
+///
+/// # Errors
+/// - Returns a DB/repository error if the insert fails.
+pub async fn repository_create_system_announcement<C>(
+    conn: &C,
+    additional_data: JsonValue,
+) -> Result<NotificationEventModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let event = insert_notification_event(
+        conn,
+        NotificationEventInsertSpec {
+            actor_id: None,
+            actor_ip: None,
+            notification_type: NotificationType::System,
+            action: NotificationAction::SystemAnnouncement,
+            target: NotificationTarget::None,
+            additional_data,
+        },
+    )
+    .await?;
+
+    Ok(event)
+}
@@ -0,0 +1,36 @@
+use constants::NotificationAction;
+use entity::notification_events::{
+    Column as NotificationEventColumn, Entity as NotificationEventEntity,
+    Model as NotificationEventModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
+use uuid::Uuid;
+
+/// Fetches a system announcement and locks its row (`FOR UPDATE`).
+///
+/// # Role
+/// Only matches `system_announcement` events, so other notification events
+/// cannot be retracted through the announcement API. The lock waits out a
+/// fan-out batch in flight, which holds the row `FOR SHARE` while it writes.
+///
+/// # Related
+/// - `service_retract_system_announcement`
+///
+/// # Errors
+/// - Returns a DB/repository error if the lookup fails.
+pub async fn repository_find_system_announcement_for_update<C>(
+    conn: &C,
+    id: Uuid,
+) -> Result<Option<NotificationEventModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let announcement = NotificationEventEntity::find_by_id(id)
+        .filter(NotificationEventColumn::Action.eq(NotificationAction::SystemAnnouncement.as_str()))
+        .lock_exclusive()
+        .one(conn)
+        .await?;
+
+    Ok(announcement)
+}
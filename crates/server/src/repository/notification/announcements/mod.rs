@@ -0,0 +1,12 @@
+//! System announcement repository APIs.
+//!
+//! An announcement is a `system_announcement` notification event; its deliveries
+//! are written by the worker fan-out, and retracting removes the unread ones.
+
+mod create;
+mod find_for_update;
+mod retract;
+
+pub use create::repository_create_system_announcement;
+pub use find_for_update::repository_find_system_announcement_for_update;
+pub use retract::repository_retract_system_announcement;
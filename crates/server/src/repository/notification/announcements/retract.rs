@@ -0,0 +1,54 @@
+use chrono::Utc;
+use entity::notification_deliveries::{
+    Column as NotificationDeliveryColumn, Entity as NotificationDeliveryEntity,
+};
+use entity::notification_events::{
+    Column as NotificationEventColumn, Entity as NotificationEventEntity,
+};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Retracts a system announcement: stamps `retracted_at` and deletes its unread
+/// deliveries.
+///
+/// # Role
+/// The stamp is kept from the first retraction, and it is what stops the worker
+/// fan-out from writing further batches. Read copies stay in their inboxes.
+/// Returns the users whose copy was removed.
+///
+/// # Related
+/// - `repository_find_system_announcement_for_update` (lock the row first)
+/// - `service_retract_system_announcement`
+///
+/// # Errors
+/// - Returns a DB/repository error if the update or delete fails.
+pub async fn repository_retract_system_announcement<C>(
+    conn: &C,
+    id: Uuid,
+) -> Result<Vec<Uuid>, Errors>
+where
+    C: ConnectionTrait,
+{
+    NotificationEventEntity::update_many()
+        .col_expr(
+            NotificationEventColumn::RetractedAt,
+            Expr::value(Some(Utc::now())),
+        )
+        .filter(NotificationEventColumn::Id.eq(id))
+        .filter(NotificationEventColumn::RetractedAt.is_null())
+        .exec(conn)
+        .await?;
+
+    let removed = NotificationDeliveryEntity::delete_many()
+        .filter(NotificationDeliveryColumn::EventId.eq(id))
+        .filter(NotificationDeliveryColumn::IsRead.eq(false))
+        .exec_with_returning(conn)
+        .await?;
+
+    Ok(removed
+        .into_iter()
+        .map(|delivery| delivery.user_id)
+        .collect())
+}
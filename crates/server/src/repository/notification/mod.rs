@@ -1,12 +1,17 @@
 //! Notification repository layer.
 //!
 //! Provides data access for user notifications and notification preferences
-//! (global preferences + per-action preferences).
+//! (global preferences + per-action preferences), system announcements and Web Push
+//! subscriptions.
 
 pub mod action_preferences;
+pub mod announcements;
 pub mod notification_deliveries;
 pub mod preferences;
+pub mod push_subscriptions;
 
 pub use action_preferences::*;
+pub use announcements::*;
 pub use notification_deliveries::*;
 pub use preferences::*;
+pub use push_subscriptions::*;
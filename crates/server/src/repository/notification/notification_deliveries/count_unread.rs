@@ -2,13 +2,15 @@ use entity::notification_deliveries::{
     Column as UserNotificationColumn, Entity as UserNotificationEntity,
 };
 use errors::errors::Errors;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter};
+use sea_orm::sea_query::{Expr, ExprTrait};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
 use uuid::Uuid;
 
 /// Counts a user's unread notifications.
 ///
 /// # Role
-/// Returns the number of notifications with `is_read = false` for `user_id`.
+/// Returns the number of unread groups for `user_id`: deliveries with
+/// `is_read = false` sharing a `group_key` count once, as they are listed.
 ///
 /// # Callers
 /// - `service_count_unread_notifications`
@@ -22,11 +24,14 @@ pub async fn repository_count_unread_notifications<C>(
 where
     C: ConnectionTrait,
 {
-    let count = UserNotificationEntity::find()
+    let count: Option<i64> = UserNotificationEntity::find()
         .filter(UserNotificationColumn::UserId.eq(user_id))
         .filter(UserNotificationColumn::IsRead.eq(false))
-        .count(conn)
+        .select_only()
+        .expr(Expr::col(UserNotificationColumn::GroupKey).count_distinct())
+        .into_tuple()
+        .one(conn)
         .await?;
 
-    Ok(count)
+    Ok(count.unwrap_or(0) as u64)
 }
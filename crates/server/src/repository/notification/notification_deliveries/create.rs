@@ -18,6 +18,7 @@ use uuid::Uuid;
 ///
 /// Since this is called on paths after the main transaction has committed, it
 /// opens a short internal transaction so the event + delivery inserts are atomic.
+/// Returns the created event's id.
 pub async fn repository_create_notification(
     db: &DatabaseConnection,
     user_id: Uuid,
@@ -27,10 +28,10 @@ pub async fn repository_create_notification(
     action: NotificationAction,
     target: NotificationTarget,
     additional_data: JsonValue,
-) -> Result<(), Errors> {
+) -> Result<Option<Uuid>, Errors> {
     let txn = db.begin().await?;
 
-    insert_notification_event_deliveries(
+    let event_id = insert_notification_event_deliveries(
         &txn,
         &[user_id],
         NotificationEventInsertSpec {
@@ -47,5 +48,5 @@ pub async fn repository_create_notification(
 
     txn.commit().await?;
 
-    Ok(())
+    Ok(event_id)
 }
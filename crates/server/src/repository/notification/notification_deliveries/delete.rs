@@ -2,13 +2,16 @@ use entity::notification_deliveries::{
     Column as UserNotificationColumn, Entity as UserNotificationEntity,
 };
 use errors::errors::Errors;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use sea_orm::sea_query::Query;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
 
-/// Deletes a single notification owned by the user.
+/// Deletes a notification owned by the user.
 ///
 /// # Role
 /// Deletes the notification matching `notification_id + user_id` and returns the affected row count.
+/// An unread notification is listed as its whole group, so the group's other unread members go
+/// with it.
 ///
 /// # Related
 /// - `service_delete_notification`
@@ -23,9 +26,25 @@ pub async fn repository_delete_notification<C>(
 where
     C: ConnectionTrait,
 {
+    let unread_group = Query::select()
+        .column(UserNotificationColumn::GroupKey)
+        .from(UserNotificationEntity)
+        .and_where(UserNotificationColumn::Id.eq(notification_id))
+        .and_where(UserNotificationColumn::UserId.eq(user_id))
+        .and_where(UserNotificationColumn::IsRead.eq(false))
+        .to_owned();
+
     let result = UserNotificationEntity::delete_many()
-        .filter(UserNotificationColumn::Id.eq(notification_id))
         .filter(UserNotificationColumn::UserId.eq(user_id))
+        .filter(
+            Condition::any()
+                .add(UserNotificationColumn::Id.eq(notification_id))
+                .add(
+                    Condition::all()
+                        .add(UserNotificationColumn::IsRead.eq(false))
+                        .add(UserNotificationColumn::GroupKey.in_subquery(unread_group)),
+                ),
+        )
         .exec(conn)
         .await?;
 
@@ -4,7 +4,8 @@ use entity::notification_deliveries::{
     Column as NotificationDeliveryColumn, Entity as NotificationDeliveryEntity,
 };
 use entity::notification_events::Column as NotificationEventColumn;
-use sea_orm::{ColumnTrait, QueryFilter, Select};
+use sea_orm::sea_query::{Alias, Expr, Query};
+use sea_orm::{ColumnTrait, ExprTrait, QueryFilter, Select};
 use uuid::Uuid;
 
 /// Filter options for notification queries
@@ -17,10 +18,18 @@ pub struct NotificationFilter {
     pub post_id: Option<Uuid>,
 }
 
+/// Applies `filter` and collapses each unread group to its newest member, so
+/// every list and cursor-neighbor query sees groups, not their members.
 pub(crate) fn apply_notification_filter(
     mut query: Select<NotificationDeliveryEntity>,
     filter: &NotificationFilter,
 ) -> Select<NotificationDeliveryEntity> {
+    query = query.filter(
+        NotificationDeliveryColumn::IsRead
+            .eq(true)
+            .or(Expr::exists(newer_unread_group_member()).not()),
+    );
+
     if let Some(notification_type) = &filter.notification_type {
         query =
             query.filter(NotificationEventColumn::NotificationType.eq(notification_type.clone()));
@@ -47,3 +56,32 @@ pub(crate) fn apply_notification_filter(
 
     query
 }
+
+/// `SELECT 1` of a newer unread delivery in the outer row's group.
+fn newer_unread_group_member() -> sea_orm::sea_query::SelectStatement {
+    let newer = Alias::new("newer");
+
+    Query::select()
+        .expr(Expr::val(1))
+        .from_as(NotificationDeliveryEntity, newer.clone())
+        .and_where(
+            Expr::col((newer.clone(), NotificationDeliveryColumn::UserId)).equals((
+                NotificationDeliveryEntity,
+                NotificationDeliveryColumn::UserId,
+            )),
+        )
+        .and_where(
+            Expr::col((newer.clone(), NotificationDeliveryColumn::GroupKey)).equals((
+                NotificationDeliveryEntity,
+                NotificationDeliveryColumn::GroupKey,
+            )),
+        )
+        .and_where(Expr::col((newer.clone(), NotificationDeliveryColumn::IsRead)).eq(false))
+        .and_where(
+            Expr::col((newer, NotificationDeliveryColumn::Id)).gt(Expr::col((
+                NotificationDeliveryEntity,
+                NotificationDeliveryColumn::Id,
+            ))),
+        )
+        .to_owned()
+}
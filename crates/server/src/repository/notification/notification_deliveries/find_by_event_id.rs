@@ -0,0 +1,24 @@
+use super::find_by_user_id_cursor::{NotificationQueryResult, notification_select};
+use entity::notification_deliveries::Column as NotificationDeliveryColumn;
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Fetches the user's delivery of one notification event, if the user has one
+/// (it may have been deleted since the event was signalled).
+pub async fn repository_find_notification_by_event_id<C>(
+    conn: &C,
+    user_id: Uuid,
+    event_id: Uuid,
+) -> Result<Option<NotificationQueryResult>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let notification = notification_select(user_id)
+        .filter(NotificationDeliveryColumn::EventId.eq(event_id))
+        .into_model::<NotificationQueryResult>()
+        .one(conn)
+        .await?;
+
+    Ok(notification)
+}
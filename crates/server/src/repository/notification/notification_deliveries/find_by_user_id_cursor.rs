@@ -9,7 +9,7 @@ use errors::errors::Errors;
 use sea_orm::prelude::IpNetwork;
 use sea_orm::{
     ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, JoinType, QueryFilter, QueryOrder,
-    QuerySelect, RelationTrait,
+    QuerySelect, RelationTrait, Select,
 };
 use uuid::Uuid;
 
@@ -24,12 +24,44 @@ pub struct NotificationQueryResult {
     pub post_id: Option<Uuid>,
     pub comment_id: Option<Uuid>,
     pub additional_data: Option<serde_json::Value>,
+    pub group_key: String,
     pub is_read: bool,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub read_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// A user's deliveries joined to their events, projected onto
+/// [`NotificationQueryResult`].
+pub(super) fn notification_select(user_id: Uuid) -> Select<NotificationDeliveryEntity> {
+    NotificationDeliveryEntity::find()
+        .join(
+            JoinType::InnerJoin,
+            entity::notification_deliveries::Relation::Event.def(),
+        )
+        .filter(NotificationDeliveryColumn::UserId.eq(user_id))
+        .select_only()
+        .column_as(NotificationDeliveryColumn::Id, "id")
+        .column_as(NotificationEventColumn::ActorId, "actor_id")
+        .column_as(NotificationEventColumn::ActorIp, "actor_ip")
+        .column_as(
+            NotificationEventColumn::NotificationType,
+            "notification_type",
+        )
+        .column_as(NotificationEventColumn::Action, "action")
+        .column_as(NotificationEventColumn::BoardId, "board_id")
+        .column_as(NotificationEventColumn::PostId, "post_id")
+        .column_as(NotificationEventColumn::CommentId, "comment_id")
+        .column_as(NotificationEventColumn::AdditionalData, "additional_data")
+        .column_as(NotificationDeliveryColumn::GroupKey, "group_key")
+        .column_as(NotificationDeliveryColumn::IsRead, "is_read")
+        .column_as(NotificationDeliveryColumn::CreatedAt, "created_at")
+        .column_as(NotificationDeliveryColumn::ReadAt, "read_at")
+}
+
 /// Fetches a user's notification list using filter/cursor conditions.
+///
+/// An unread group is returned as its newest member only (see
+/// `apply_notification_filter`), so the cursor pages over groups.
 pub async fn repository_find_notifications_by_user_id_cursor<C>(
     conn: &C,
     user_id: Uuid,
@@ -41,31 +73,7 @@ pub async fn repository_find_notifications_by_user_id_cursor<C>(
 where
     C: ConnectionTrait,
 {
-    let mut query = apply_notification_filter(
-        NotificationDeliveryEntity::find()
-            .join(
-                JoinType::InnerJoin,
-                entity::notification_deliveries::Relation::Event.def(),
-            )
-            .filter(NotificationDeliveryColumn::UserId.eq(user_id))
-            .select_only()
-            .column_as(NotificationDeliveryColumn::Id, "id")
-            .column_as(NotificationEventColumn::ActorId, "actor_id")
-            .column_as(NotificationEventColumn::ActorIp, "actor_ip")
-            .column_as(
-                NotificationEventColumn::NotificationType,
-                "notification_type",
-            )
-            .column_as(NotificationEventColumn::Action, "action")
-            .column_as(NotificationEventColumn::BoardId, "board_id")
-            .column_as(NotificationEventColumn::PostId, "post_id")
-            .column_as(NotificationEventColumn::CommentId, "comment_id")
-            .column_as(NotificationEventColumn::AdditionalData, "additional_data")
-            .column_as(NotificationDeliveryColumn::IsRead, "is_read")
-            .column_as(NotificationDeliveryColumn::CreatedAt, "created_at")
-            .column_as(NotificationDeliveryColumn::ReadAt, "read_at"),
-        filter,
-    );
+    let mut query = apply_notification_filter(notification_select(user_id), filter);
 
     if let Some(notification_id) = cursor_notification_id {
         let direction = cursor_direction.unwrap_or(CursorDirection::Older);
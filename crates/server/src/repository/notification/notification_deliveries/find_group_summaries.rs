@@ -0,0 +1,79 @@
+use entity::notification_deliveries::{
+    Column as NotificationDeliveryColumn, Entity as NotificationDeliveryEntity,
+};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, JoinType, QueryFilter, QuerySelect,
+    RelationTrait,
+};
+use uuid::Uuid;
+
+/// Members looked at, newest first, when collecting a group's recent actors.
+/// A long run by one actor can hide older ones; the count stays exact.
+const GROUP_ACTOR_SCAN_LIMIT: usize = 50;
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct NotificationGroupSummary {
+    pub group_key: String,
+    /// Unread deliveries in the group.
+    pub member_count: i64,
+    /// Distinct actors across the group's members.
+    pub actor_count: i64,
+    /// Actor ids of the newest members, newest first, with repeats.
+    pub actor_ids: Option<Vec<Uuid>>,
+}
+
+/// Summarizes a user's unread notification groups.
+///
+/// # Role
+/// For each of `group_keys`, counts the user's unread members and their
+/// distinct actors, and lists the actors of the newest members. Groups with no
+/// unread member are absent from the result.
+///
+/// # Related
+/// - `service_get_notifications` (one call per page)
+/// - `notification_repository::notification_group_key`
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_notification_group_summaries<C>(
+    conn: &C,
+    user_id: Uuid,
+    group_keys: Vec<String>,
+) -> Result<Vec<NotificationGroupSummary>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if group_keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let summaries = NotificationDeliveryEntity::find()
+        .join(
+            JoinType::InnerJoin,
+            entity::notification_deliveries::Relation::Event.def(),
+        )
+        .filter(NotificationDeliveryColumn::UserId.eq(user_id))
+        .filter(NotificationDeliveryColumn::IsRead.eq(false))
+        .filter(NotificationDeliveryColumn::GroupKey.is_in(group_keys))
+        .select_only()
+        .column_as(NotificationDeliveryColumn::GroupKey, "group_key")
+        .expr_as(Expr::cust("COUNT(*)"), "member_count")
+        .expr_as(
+            Expr::cust(r#"COUNT(DISTINCT "notification_events"."actor_id")"#),
+            "actor_count",
+        )
+        .expr_as(
+            Expr::cust(format!(
+                r#"(ARRAY_AGG("notification_events"."actor_id" ORDER BY "notification_deliveries"."id" DESC) FILTER (WHERE "notification_events"."actor_id" IS NOT NULL))[1:{GROUP_ACTOR_SCAN_LIMIT}]"#
+            )),
+            "actor_ids",
+        )
+        .group_by(NotificationDeliveryColumn::GroupKey)
+        .into_model::<NotificationGroupSummary>()
+        .all(conn)
+        .await?;
+
+    Ok(summaries)
+}
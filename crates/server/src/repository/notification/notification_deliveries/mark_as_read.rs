@@ -4,13 +4,16 @@ use entity::notification_deliveries::{
 };
 use errors::errors::Errors;
 use sea_orm::prelude::Expr;
+use sea_orm::sea_query::Query;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
 
-/// Marks a single user notification as read.
+/// Marks a user notification as read, together with the rest of its group.
 ///
 /// # Role
-/// Updates `is_read` and `read_at` in a single UPDATE and returns the affected row count.
+/// Updates `is_read` and `read_at` of every unread delivery sharing the target's
+/// `group_key` in a single UPDATE and returns the affected row count (0 when the
+/// target is not the user's or is already read).
 ///
 /// # Related
 /// - `service_mark_notification_as_read`
@@ -25,16 +28,24 @@ pub async fn repository_mark_notification_as_read<C>(
 where
     C: ConnectionTrait,
 {
-    // Single atomic UPDATE query without SELECT
+    let target_group = Query::select()
+        .column(UserNotificationColumn::GroupKey)
+        .from(UserNotificationEntity)
+        .and_where(UserNotificationColumn::Id.eq(notification_id))
+        .and_where(UserNotificationColumn::UserId.eq(user_id))
+        .and_where(UserNotificationColumn::IsRead.eq(false))
+        .to_owned();
+
+    // Single atomic UPDATE; the group is resolved in a subquery
     let result = UserNotificationEntity::update_many()
         .col_expr(UserNotificationColumn::IsRead, Expr::value(true))
         .col_expr(
             UserNotificationColumn::ReadAt,
             Expr::value(Some(Utc::now())),
         )
-        .filter(UserNotificationColumn::Id.eq(notification_id))
         .filter(UserNotificationColumn::UserId.eq(user_id))
-        .filter(UserNotificationColumn::IsRead.eq(false)) // Optimization: skip if already read
+        .filter(UserNotificationColumn::IsRead.eq(false))
+        .filter(UserNotificationColumn::GroupKey.in_subquery(target_group))
         .exec(conn)
         .await?;
 
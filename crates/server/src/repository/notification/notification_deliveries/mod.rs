@@ -2,13 +2,19 @@
 //!
 //! Handles notification CRUD, read-state transitions, list queries, and
 //! cursor-neighbor existence checks.
+//!
+//! Unread deliveries sharing a `group_key` form one group: lists show only the
+//! group's newest member, the unread count counts groups, and marking read or
+//! deleting any member applies to the whole group.
 
 mod count_unread;
 mod create;
 mod delete;
 pub mod exists;
 mod filter;
+mod find_by_event_id;
 mod find_by_user_id_cursor;
+mod find_group_summaries;
 mod mark_all_as_read;
 mod mark_as_read;
 
@@ -17,9 +23,13 @@ pub use create::repository_create_notification;
 pub use delete::{repository_delete_all_notifications_for_user, repository_delete_notification};
 pub use exists::*;
 pub use filter::NotificationFilter;
+pub use find_by_event_id::repository_find_notification_by_event_id;
 pub use find_by_user_id_cursor::{
     NotificationQueryResult, repository_find_notifications_by_user_id_cursor,
 };
+pub use find_group_summaries::{
+    NotificationGroupSummary, repository_find_notification_group_summaries,
+};
 pub use mark_all_as_read::repository_mark_all_notifications_as_read;
 pub use mark_as_read::repository_mark_notification_as_read;
 pub use notification_repository::NotificationTarget;
@@ -1,4 +1,5 @@
 use chrono::Utc;
+use entity::common::NotificationDigestFrequency;
 use entity::notification_preferences::{
     ActiveModel as NotificationPreferenceActiveModel, Model as NotificationPreferenceModel,
 };
@@ -9,7 +10,7 @@ use uuid::Uuid;
 /// Creates a per-channel notification preference record for a user.
 ///
 /// # Role
-/// Inserts a preference record with the initial email/push settings.
+/// Inserts a preference record with the initial email/push/digest settings.
 ///
 /// # Related
 /// - `service_update_notification_preferences`
@@ -21,6 +22,7 @@ pub async fn repository_create_notification_preferences<C>(
     user_id: Uuid,
     email_enabled: bool,
     push_enabled: bool,
+    digest_frequency: NotificationDigestFrequency,
 ) -> Result<NotificationPreferenceModel, Errors>
 where
     C: ConnectionTrait,
@@ -29,6 +31,7 @@ where
         user_id: Set(user_id),
         email_enabled: Set(email_enabled),
         push_enabled: Set(push_enabled),
+        digest_frequency: Set(digest_frequency),
         updated_at: Set(Utc::now()),
         ..Default::default()
     };
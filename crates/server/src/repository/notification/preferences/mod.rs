@@ -5,9 +5,11 @@
 mod create;
 mod delete;
 mod find_by_user_id;
+mod unsubscribe_email;
 mod update;
 
 pub use create::repository_create_notification_preferences;
 pub use delete::repository_delete_notification_preferences_for_user;
 pub use find_by_user_id::repository_find_notification_preferences_by_user_id;
+pub use unsubscribe_email::repository_disable_email_by_unsubscribe_token;
 pub use update::repository_update_notification_preferences;
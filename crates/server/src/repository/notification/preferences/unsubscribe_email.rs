@@ -0,0 +1,49 @@
+use chrono::Utc;
+use entity::common::NotificationDigestFrequency;
+use entity::notification_preferences::{
+    Column as NotificationPreferenceColumn, Entity as NotificationPreferenceEntity,
+};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Turns notification emails (per-event and digest) off for the preference row
+/// holding `token`.
+///
+/// # Role
+/// Single UPDATE keyed by the unsubscribe token; returns the number of affected rows
+/// (0 when no row holds the token). Already-off rows still match, so repeating the
+/// request is harmless.
+///
+/// # Related
+/// - `service_unsubscribe_notification_email`
+///
+/// # Errors
+/// - Returns a DB/repository error if the update fails.
+pub async fn repository_disable_email_by_unsubscribe_token<C>(
+    conn: &C,
+    token: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = NotificationPreferenceEntity::update_many()
+        .col_expr(
+            NotificationPreferenceColumn::EmailEnabled,
+            Expr::value(false),
+        )
+        .col_expr(
+            NotificationPreferenceColumn::DigestFrequency,
+            Expr::value(NotificationDigestFrequency::Off),
+        )
+        .col_expr(
+            NotificationPreferenceColumn::UpdatedAt,
+            Expr::value(Utc::now()),
+        )
+        .filter(NotificationPreferenceColumn::EmailUnsubscribeToken.eq(token))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
@@ -1,4 +1,5 @@
 use chrono::Utc;
+use entity::common::NotificationDigestFrequency;
 use entity::notification_preferences::{
     ActiveModel as NotificationPreferenceActiveModel, Column as NotificationPreferenceColumn,
     Entity as NotificationPreferenceEntity, Model as NotificationPreferenceModel,
@@ -23,6 +24,7 @@ pub async fn repository_update_notification_preferences<C>(
     user_id: Uuid,
     email_enabled: Option<bool>,
     push_enabled: Option<bool>,
+    digest_frequency: Option<NotificationDigestFrequency>,
 ) -> Result<NotificationPreferenceModel, Errors>
 where
     C: ConnectionTrait,
@@ -45,6 +47,10 @@ where
         active_prefs.push_enabled = Set(push_enabled);
     }
 
+    if let Some(digest_frequency) = digest_frequency {
+        active_prefs.digest_frequency = Set(digest_frequency);
+    }
+
     active_prefs.updated_at = Set(Utc::now());
 
     let updated = active_prefs.update(conn).await?;
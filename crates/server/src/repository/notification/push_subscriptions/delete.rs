@@ -0,0 +1,70 @@
+use entity::push_subscriptions::{
+    Column as PushSubscriptionColumn, Entity as PushSubscriptionEntity,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Deletes the user's subscription for `endpoint`.
+///
+/// # Role
+/// Matches `endpoint + user_id`, so a caller cannot remove another user's
+/// device. Returns the affected row count.
+///
+/// # Related
+/// - `service_unsubscribe_push`
+///
+/// # Errors
+/// - Returns a DB/repository error if the delete fails.
+pub async fn repository_delete_push_subscription<C>(
+    conn: &C,
+    user_id: Uuid,
+    endpoint: &str,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = PushSubscriptionEntity::delete_many()
+        .filter(PushSubscriptionColumn::UserId.eq(user_id))
+        .filter(PushSubscriptionColumn::Endpoint.eq(endpoint))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+/// Deletes subscriptions by id (the per-user cap eviction).
+pub async fn repository_delete_push_subscriptions_by_ids<C>(
+    conn: &C,
+    ids: Vec<Uuid>,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let result = PushSubscriptionEntity::delete_many()
+        .filter(PushSubscriptionColumn::Id.is_in(ids))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+/// Deletes every push subscription of a user (account deletion).
+pub async fn repository_delete_all_push_subscriptions_for_user<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = PushSubscriptionEntity::delete_many()
+        .filter(PushSubscriptionColumn::UserId.eq(user_id))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
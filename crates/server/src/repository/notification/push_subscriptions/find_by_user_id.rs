@@ -0,0 +1,31 @@
+use entity::push_subscriptions::{
+    Column as PushSubscriptionColumn, Entity as PushSubscriptionEntity,
+    Model as PushSubscriptionModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// Lists a user's Web Push subscriptions, newest first.
+///
+/// # Related
+/// - `service_get_push_subscriptions`
+/// - `service_subscribe_push` (evicts past the per-user cap)
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_push_subscriptions_by_user_id<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<PushSubscriptionModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let subscriptions = PushSubscriptionEntity::find()
+        .filter(PushSubscriptionColumn::UserId.eq(user_id))
+        .order_by_desc(PushSubscriptionColumn::Id)
+        .all(conn)
+        .await?;
+
+    Ok(subscriptions)
+}
@@ -0,0 +1,14 @@
+//! Web Push subscription repository APIs.
+//!
+//! One row per browser subscription (device), keyed by its unique endpoint.
+
+mod delete;
+mod find_by_user_id;
+mod upsert;
+
+pub use delete::{
+    repository_delete_all_push_subscriptions_for_user, repository_delete_push_subscription,
+    repository_delete_push_subscriptions_by_ids,
+};
+pub use find_by_user_id::repository_find_push_subscriptions_by_user_id;
+pub use upsert::repository_upsert_push_subscription;
@@ -0,0 +1,55 @@
+use entity::push_subscriptions::{
+    ActiveModel as PushSubscriptionActiveModel, Column as PushSubscriptionColumn,
+    Entity as PushSubscriptionEntity, Model as PushSubscriptionModel,
+};
+use errors::errors::Errors;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ConnectionTrait, EntityTrait, Set};
+use uuid::Uuid;
+
+/// Registers a Web Push subscription for a user, or refreshes it.
+///
+/// # Role
+/// Upserts on the endpoint: a browser re-subscribing (rotated keys) or a device
+/// now signed in as another user replaces the row's owner and keys instead of
+/// adding a second row for the same endpoint.
+///
+/// # Related
+/// - `service_subscribe_push`
+///
+/// # Errors
+/// - Returns a DB/repository error if the upsert fails.
+pub async fn repository_upsert_push_subscription<C>(
+    conn: &C,
+    user_id: Uuid,
+    endpoint: String,
+    p256dh: String,
+    auth: String,
+) -> Result<PushSubscriptionModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let subscription = PushSubscriptionActiveModel {
+        id: Default::default(),
+        user_id: Set(user_id),
+        endpoint: Set(endpoint),
+        p256dh: Set(p256dh),
+        auth: Set(auth),
+        created_at: Default::default(),
+    };
+
+    let subscription = PushSubscriptionEntity::insert(subscription)
+        .on_conflict(
+            OnConflict::column(PushSubscriptionColumn::Endpoint)
+                .update_columns([
+                    PushSubscriptionColumn::UserId,
+                    PushSubscriptionColumn::P256dh,
+                    PushSubscriptionColumn::Auth,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(conn)
+        .await?;
+
+    Ok(subscription)
+}
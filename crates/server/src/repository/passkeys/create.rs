@@ -0,0 +1,37 @@
+use entity::user_passkeys::{ActiveModel as UserPasskeyActiveModel, Model as UserPasskeyModel};
+use errors::errors::Errors;
+use sea_orm::prelude::Json;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// Stores a newly registered passkey.
+///
+/// # Related
+/// - `service_passkey_register`
+///
+/// # Errors
+/// - Returns a DB/repository error if the insert fails (including a duplicate credential id).
+pub async fn repository_create_user_passkey<C>(
+    conn: &C,
+    user_id: Uuid,
+    credential_id: String,
+    passkey: Json,
+    name: String,
+) -> Result<UserPasskeyModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let passkey = UserPasskeyActiveModel {
+        id: Default::default(),
+        user_id: Set(user_id),
+        credential_id: Set(credential_id),
+        passkey: Set(passkey),
+        name: Set(name),
+        created_at: Default::default(),
+        last_used_at: Set(None),
+    }
+    .insert(conn)
+    .await?;
+
+    Ok(passkey)
+}
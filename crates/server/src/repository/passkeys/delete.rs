@@ -0,0 +1,32 @@
+use entity::user_passkeys::{Column as UserPasskeyColumn, Entity as UserPasskeyEntity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Deletes one of the user's passkeys.
+///
+/// # Role
+/// Matches `id + user_id`, so a caller cannot remove another user's passkey.
+/// Returns the affected row count.
+///
+/// # Related
+/// - `service_delete_passkey`
+///
+/// # Errors
+/// - Returns a DB/repository error if the delete fails.
+pub async fn repository_delete_user_passkey<C>(
+    conn: &C,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = UserPasskeyEntity::delete_many()
+        .filter(UserPasskeyColumn::UserId.eq(user_id))
+        .filter(UserPasskeyColumn::Id.eq(id))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
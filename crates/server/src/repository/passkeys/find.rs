@@ -0,0 +1,49 @@
+use entity::user_passkeys::{
+    Column as UserPasskeyColumn, Entity as UserPasskeyEntity, Model as UserPasskeyModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use uuid::Uuid;
+
+/// Lists a user's passkeys, newest first.
+///
+/// # Related
+/// - `service_list_passkeys`
+/// - `service_passkey_second_factor_options` (builds the allow-list)
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_user_passkeys_by_user_id<C>(
+    conn: &C,
+    user_id: Uuid,
+) -> Result<Vec<UserPasskeyModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let passkeys = UserPasskeyEntity::find()
+        .filter(UserPasskeyColumn::UserId.eq(user_id))
+        .order_by_desc(UserPasskeyColumn::Id)
+        .all(conn)
+        .await?;
+
+    Ok(passkeys)
+}
+
+/// Looks up a passkey by its credential id (base64url).
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_user_passkey_by_credential_id<C>(
+    conn: &C,
+    credential_id: &str,
+) -> Result<Option<UserPasskeyModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let passkey = UserPasskeyEntity::find()
+        .filter(UserPasskeyColumn::CredentialId.eq(credential_id))
+        .one(conn)
+        .await?;
+
+    Ok(passkey)
+}
@@ -0,0 +1,15 @@
+//! Passkey (WebAuthn credential) repository APIs.
+//!
+//! One row per registered credential, keyed by its globally unique credential id.
+
+mod create;
+mod delete;
+mod find;
+mod update;
+
+pub use create::repository_create_user_passkey;
+pub use delete::repository_delete_user_passkey;
+pub use find::{
+    repository_find_user_passkey_by_credential_id, repository_find_user_passkeys_by_user_id,
+};
+pub use update::repository_update_user_passkey_usage;
@@ -0,0 +1,34 @@
+use chrono::Utc;
+use entity::user_passkeys::{Column as UserPasskeyColumn, Entity as UserPasskeyEntity};
+use errors::errors::Errors;
+use sea_orm::prelude::{Expr, Json};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Records a successful assertion: stamps `last_used_at` and, when the authenticator's
+/// signature counter moved, stores the updated credential.
+///
+/// # Related
+/// - `service_passkey_login`
+/// - `service_passkey_second_factor`
+///
+/// # Errors
+/// - Returns a DB/repository error if the update fails.
+pub async fn repository_update_user_passkey_usage<C>(
+    conn: &C,
+    id: Uuid,
+    passkey: Option<Json>,
+) -> Result<(), Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut update = UserPasskeyEntity::update_many()
+        .col_expr(UserPasskeyColumn::LastUsedAt, Expr::value(Some(Utc::now())))
+        .filter(UserPasskeyColumn::Id.eq(id));
+    if let Some(passkey) = passkey {
+        update = update.col_expr(UserPasskeyColumn::Passkey, Expr::value(passkey));
+    }
+    update.exec(conn).await?;
+
+    Ok(())
+}
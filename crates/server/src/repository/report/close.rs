@@ -0,0 +1,28 @@
+use chrono::Utc;
+use entity::common::ReportStatus;
+use entity::reports::{ActiveModel as ReportActiveModel, Model as ReportModel};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// Close an open report as `Resolved` or `Dismissed`, linking the moderation log
+/// entry that records the outcome.
+pub async fn repository_close_report<C>(
+    conn: &C,
+    report: ReportModel,
+    status: ReportStatus,
+    resolved_by: Uuid,
+    resolution_note: Option<String>,
+    moderation_log_id: Uuid,
+) -> Result<ReportModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut active: ReportActiveModel = report.into();
+    active.status = Set(status);
+    active.resolved_by = Set(Some(resolved_by));
+    active.resolved_at = Set(Some(Utc::now()));
+    active.resolution_note = Set(resolution_note);
+    active.moderation_log_id = Set(Some(moderation_log_id));
+    Ok(active.update(conn).await?)
+}
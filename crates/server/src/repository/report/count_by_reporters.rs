@@ -0,0 +1,44 @@
+use entity::reports::{Column as ReportColumn, Entity as ReportEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, QueryFilter, QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct ReporterReportCounts {
+    pub reporter_id: Uuid,
+    pub total_count: i64,
+    pub dismissed_count: i64,
+}
+
+/// All-time and dismissed report counts per reporter, for the reporters in
+/// `reporter_ids`. A high dismissed share flags a noisy or abusive reporter.
+pub async fn repository_count_reports_by_reporters<C>(
+    conn: &C,
+    reporter_ids: &[Uuid],
+) -> Result<Vec<ReporterReportCounts>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if reporter_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let counts = ReportEntity::find()
+        .select_only()
+        .column(ReportColumn::ReporterId)
+        .column_as(ReportColumn::Id.count(), "total_count")
+        .column_as(
+            Expr::cust("COUNT(*) FILTER (WHERE status = 'dismissed')"),
+            "dismissed_count",
+        )
+        .filter(ReportColumn::ReporterId.is_in(reporter_ids.to_vec()))
+        .group_by(ReportColumn::ReporterId)
+        .into_model::<ReporterReportCounts>()
+        .all(conn)
+        .await?;
+
+    Ok(counts)
+}
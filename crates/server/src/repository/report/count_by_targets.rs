@@ -0,0 +1,49 @@
+use entity::common::ReportTargetType;
+use entity::reports::{Column as ReportColumn, Entity as ReportEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, QueryFilter, QuerySelect,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct ReportTargetCounts {
+    pub target_type: ReportTargetType,
+    pub target_id: Uuid,
+    pub open_count: i64,
+    pub total_count: i64,
+}
+
+/// Open and all-time report counts per target, for the targets in `target_ids`.
+///
+/// Targets without reports are absent from the result.
+pub async fn repository_count_reports_by_targets<C>(
+    conn: &C,
+    target_ids: &[Uuid],
+) -> Result<Vec<ReportTargetCounts>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if target_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let counts = ReportEntity::find()
+        .select_only()
+        .column(ReportColumn::TargetType)
+        .column(ReportColumn::TargetId)
+        .column_as(
+            Expr::cust("COUNT(*) FILTER (WHERE status = 'open')"),
+            "open_count",
+        )
+        .column_as(ReportColumn::Id.count(), "total_count")
+        .filter(ReportColumn::TargetId.is_in(target_ids.to_vec()))
+        .group_by(ReportColumn::TargetType)
+        .group_by(ReportColumn::TargetId)
+        .into_model::<ReportTargetCounts>()
+        .all(conn)
+        .await?;
+
+    Ok(counts)
+}
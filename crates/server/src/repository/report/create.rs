@@ -0,0 +1,53 @@
+use entity::common::{ReportReason, ReportStatus, ReportTargetType};
+use entity::reports::{ActiveModel as ReportActiveModel, Model as ReportModel};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, DbErr, Set, SqlErr};
+use uuid::Uuid;
+
+/// Map an insert failure to `ReportAlreadyOpen` when it hits
+/// `uq_reports_open_reporter_target` (a concurrent report on the same target
+/// won the race past the service's pre-check), instead of a generic 500.
+fn map_create_report_db_err(err: DbErr) -> Errors {
+    match err.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(detail))
+            if detail.contains("uq_reports_open_reporter_target") =>
+        {
+            Errors::ReportAlreadyOpen
+        }
+        _ => Errors::DatabaseError(err.to_string()),
+    }
+}
+
+pub async fn repository_create_report<C>(
+    conn: &C,
+    reporter_id: Uuid,
+    target_type: ReportTargetType,
+    target_id: Uuid,
+    reason: ReportReason,
+    description: Option<String>,
+) -> Result<ReportModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let report = ReportActiveModel {
+        id: Default::default(),
+        reporter_id: Set(Some(reporter_id)),
+        target_type: Set(target_type),
+        target_id: Set(target_id),
+        reason: Set(reason),
+        description: Set(description),
+        status: Set(ReportStatus::Open),
+        resolved_by: Set(None),
+        resolved_at: Set(None),
+        resolution_note: Set(None),
+        moderation_log_id: Set(None),
+        created_at: Default::default(),
+    };
+
+    let report = report
+        .insert(conn)
+        .await
+        .map_err(map_create_report_db_err)?;
+
+    Ok(report)
+}
@@ -0,0 +1,5 @@
+mod newer;
+mod older;
+
+pub use newer::repository_exists_newer_report;
+pub use older::repository_exists_older_report;
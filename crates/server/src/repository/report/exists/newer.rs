@@ -0,0 +1,22 @@
+use super::super::filter::{ReportFilter, apply_report_filter};
+use crate::repository::common::repository_query_exists;
+use entity::reports::{Column as ReportColumn, Entity as ReportEntity};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+pub async fn repository_exists_newer_report<C>(
+    conn: &C,
+    filter: &ReportFilter,
+    cursor_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let query = apply_report_filter(
+        ReportEntity::find().filter(ReportColumn::Id.gt(cursor_id)),
+        filter,
+    );
+
+    repository_query_exists(conn, query).await
+}
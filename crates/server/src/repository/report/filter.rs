@@ -0,0 +1,40 @@
+use entity::common::{ReportReason, ReportStatus, ReportTargetType};
+use entity::reports::{Column as ReportColumn, Entity as ReportEntity};
+use sea_orm::{ColumnTrait, QueryFilter, Select};
+use uuid::Uuid;
+
+#[derive(Debug, Default, Clone)]
+pub struct ReportFilter {
+    pub status: Option<ReportStatus>,
+    pub target_type: Option<ReportTargetType>,
+    pub target_id: Option<Uuid>,
+    pub reporter_id: Option<Uuid>,
+    pub reason: Option<ReportReason>,
+}
+
+pub(crate) fn apply_report_filter(
+    mut query: Select<ReportEntity>,
+    filter: &ReportFilter,
+) -> Select<ReportEntity> {
+    if let Some(status) = filter.status.clone() {
+        query = query.filter(ReportColumn::Status.eq(status));
+    }
+
+    if let Some(target_type) = filter.target_type.clone() {
+        query = query.filter(ReportColumn::TargetType.eq(target_type));
+    }
+
+    if let Some(target_id) = filter.target_id {
+        query = query.filter(ReportColumn::TargetId.eq(target_id));
+    }
+
+    if let Some(reporter_id) = filter.reporter_id {
+        query = query.filter(ReportColumn::ReporterId.eq(reporter_id));
+    }
+
+    if let Some(reason) = filter.reason.clone() {
+        query = query.filter(ReportColumn::Reason.eq(reason));
+    }
+
+    query
+}
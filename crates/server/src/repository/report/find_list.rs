@@ -0,0 +1,36 @@
+use super::filter::{ReportFilter, apply_report_filter};
+use dto::pagination::CursorDirection;
+use entity::reports::{Column as ReportColumn, Entity as ReportEntity, Model as ReportModel};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+pub async fn repository_find_reports<C>(
+    conn: &C,
+    filter: &ReportFilter,
+    cursor_id: Option<Uuid>,
+    cursor_direction: Option<CursorDirection>,
+    limit: u64,
+) -> Result<Vec<ReportModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut query = apply_report_filter(ReportEntity::find(), filter);
+
+    if let Some(id) = cursor_id {
+        let direction = cursor_direction.unwrap_or(CursorDirection::Older);
+        query = match direction {
+            CursorDirection::Older => query
+                .filter(ReportColumn::Id.lt(id))
+                .order_by_desc(ReportColumn::Id),
+            CursorDirection::Newer => query
+                .filter(ReportColumn::Id.gt(id))
+                .order_by_asc(ReportColumn::Id),
+        };
+    } else {
+        query = query.order_by_desc(ReportColumn::Id);
+    }
+
+    let results = query.limit(limit).all(conn).await?;
+    Ok(results)
+}
@@ -0,0 +1,26 @@
+use entity::common::{ReportStatus, ReportTargetType};
+use entity::reports::{Column as ReportColumn, Entity as ReportEntity, Model as ReportModel};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// The reporter's open report on a target, if any (at most one can exist).
+pub async fn repository_find_open_report<C>(
+    conn: &C,
+    reporter_id: Uuid,
+    target_type: ReportTargetType,
+    target_id: Uuid,
+) -> Result<Option<ReportModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let report = ReportEntity::find()
+        .filter(ReportColumn::ReporterId.eq(reporter_id))
+        .filter(ReportColumn::TargetType.eq(target_type))
+        .filter(ReportColumn::TargetId.eq(target_id))
+        .filter(ReportColumn::Status.eq(ReportStatus::Open))
+        .one(conn)
+        .await?;
+
+    Ok(report)
+}
@@ -0,0 +1,25 @@
+use entity::reports::{Entity as ReportEntity, Model as ReportModel};
+use errors::errors::Errors;
+use sea_orm::{ConnectionTrait, EntityTrait, QuerySelect};
+use uuid::Uuid;
+
+/// Fetch a report by ID with a row lock (`SELECT ... FOR UPDATE`), so two
+/// moderators closing the same report serialize and the second one sees it closed.
+///
+/// # Errors
+/// - `Errors::ReportNotFound` if the report does not exist.
+pub async fn repository_get_report_by_id_for_update<C>(
+    conn: &C,
+    id: Uuid,
+) -> Result<ReportModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let report = ReportEntity::find_by_id(id)
+        .lock_exclusive()
+        .one(conn)
+        .await?
+        .ok_or(Errors::ReportNotFound)?;
+
+    Ok(report)
+}
@@ -0,0 +1,19 @@
+pub mod close;
+pub mod count_by_reporters;
+pub mod count_by_targets;
+pub mod create;
+pub mod exists;
+mod filter;
+pub mod find_list;
+pub mod find_open;
+pub mod get_by_id;
+
+pub use close::repository_close_report;
+pub use count_by_reporters::{ReporterReportCounts, repository_count_reports_by_reporters};
+pub use count_by_targets::{ReportTargetCounts, repository_count_reports_by_targets};
+pub use create::repository_create_report;
+pub use exists::*;
+pub use filter::ReportFilter;
+pub use find_list::repository_find_reports;
+pub use find_open::repository_find_open_report;
+pub use get_by_id::repository_get_report_by_id_for_update;
@@ -0,0 +1,30 @@
+use entity::users::{Column as UserColumn, Entity as UserEntity, Model as UserModel};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+/// Fetches users by a list of handles.
+///
+/// # Role
+/// Returns an empty vector immediately for empty input; otherwise runs an `IN` query.
+/// Handles that match no user are simply absent from the result.
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_users_by_handles<C>(
+    conn: &C,
+    handles: &[String],
+) -> Result<Vec<UserModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    if handles.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let users = UserEntity::find()
+        .filter(UserColumn::Handle.is_in(handles.to_vec()))
+        .all(conn)
+        .await?;
+
+    Ok(users)
+}
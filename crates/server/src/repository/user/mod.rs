@@ -5,6 +5,7 @@
 pub mod create;
 pub mod find_by_email;
 pub mod find_by_handle;
+pub mod find_by_handles;
 pub mod find_by_id;
 pub mod find_by_ids;
 pub mod get_by_email;
@@ -18,6 +19,7 @@ pub mod user_roles;
 pub use create::{repository_create_user, repository_create_user_with_password_hash};
 pub use find_by_email::repository_find_user_by_email;
 pub use find_by_handle::repository_find_user_by_handle;
+pub use find_by_handles::repository_find_users_by_handles;
 pub use find_by_id::repository_find_user_by_id;
 pub use find_by_ids::repository_find_users_by_ids;
 pub use get_by_id::{repository_get_user_by_id, repository_get_user_by_id_for_update};
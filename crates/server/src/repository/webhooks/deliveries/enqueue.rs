@@ -0,0 +1,70 @@
+use dto::webhooks::WebhookEventPayload;
+use entity::common::WebhookDeliveryStatus;
+use entity::webhook_deliveries::{
+    ActiveModel as WebhookDeliveryActiveModel, Entity as WebhookDeliveryEntity,
+};
+use entity::webhook_endpoints::{Column as WebhookEndpointColumn, Entity as WebhookEndpointEntity};
+use errors::errors::Errors;
+use sea_orm::sea_query::{Expr, ExprTrait, PgFunc};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect, Set};
+use uuid::Uuid;
+
+/// Queues an event for every active webhook endpoint subscribed to it.
+///
+/// # Role
+/// Inserts one pending delivery per matching endpoint, carrying the payload as it
+/// will be sent. Called inside the transaction that writes the log, so an event is
+/// queued exactly when its log commits. Returns the number of deliveries queued.
+///
+/// # Related
+/// - `repository_create_action_log`
+/// - `repository_create_moderation_log`
+/// - `worker::jobs::cron::dispatch_webhook_deliveries`
+///
+/// # Errors
+/// - Returns a DB/repository error if the query or insert fails.
+pub async fn repository_enqueue_webhook_deliveries<C>(
+    conn: &C,
+    payload: WebhookEventPayload,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let endpoint_ids: Vec<Uuid> = WebhookEndpointEntity::find()
+        .select_only()
+        .column(WebhookEndpointColumn::Id)
+        .filter(WebhookEndpointColumn::IsActive.eq(true))
+        .filter(
+            Expr::val(payload.event.as_str())
+                .eq(PgFunc::any(Expr::col(WebhookEndpointColumn::Events))),
+        )
+        .into_tuple()
+        .all(conn)
+        .await?;
+
+    if endpoint_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let body = serde_json::to_value(&payload)
+        .map_err(|e| Errors::SysInternalError(format!("webhook payload: {e}")))?;
+    let count = endpoint_ids.len() as u64;
+
+    let deliveries = endpoint_ids
+        .into_iter()
+        .map(|endpoint_id| WebhookDeliveryActiveModel {
+            endpoint_id: Set(endpoint_id),
+            event: Set(payload.event.clone()),
+            log_id: Set(payload.id),
+            payload: Set(body.clone()),
+            status: Set(WebhookDeliveryStatus::Pending),
+            attempts: Set(0),
+            ..Default::default()
+        });
+
+    WebhookDeliveryEntity::insert_many(deliveries)
+        .exec_without_returning(conn)
+        .await?;
+
+    Ok(count)
+}
@@ -0,0 +1,46 @@
+use entity::webhook_deliveries::{
+    Column as WebhookDeliveryColumn, Entity as WebhookDeliveryEntity,
+};
+use errors::errors::Errors;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect,
+};
+use uuid::Uuid;
+
+/// Whether the endpoint has a delivery newer than `cursor_id`.
+pub async fn repository_exists_newer_webhook_delivery<C>(
+    conn: &C,
+    endpoint_id: Uuid,
+    cursor_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let count = WebhookDeliveryEntity::find()
+        .filter(WebhookDeliveryColumn::EndpointId.eq(endpoint_id))
+        .filter(WebhookDeliveryColumn::Id.gt(cursor_id))
+        .limit(1)
+        .count(conn)
+        .await?;
+
+    Ok(count > 0)
+}
+
+/// Whether the endpoint has a delivery older than `cursor_id`.
+pub async fn repository_exists_older_webhook_delivery<C>(
+    conn: &C,
+    endpoint_id: Uuid,
+    cursor_id: Uuid,
+) -> Result<bool, Errors>
+where
+    C: ConnectionTrait,
+{
+    let count = WebhookDeliveryEntity::find()
+        .filter(WebhookDeliveryColumn::EndpointId.eq(endpoint_id))
+        .filter(WebhookDeliveryColumn::Id.lt(cursor_id))
+        .limit(1)
+        .count(conn)
+        .await?;
+
+    Ok(count > 0)
+}
@@ -0,0 +1,65 @@
+use dto::pagination::CursorDirection;
+use entity::webhook_deliveries::{
+    Column as WebhookDeliveryColumn, Entity as WebhookDeliveryEntity, Model as WebhookDeliveryModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// Finds a webhook delivery by id.
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_webhook_delivery_by_id<C>(
+    conn: &C,
+    delivery_id: Uuid,
+) -> Result<Option<WebhookDeliveryModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let delivery = WebhookDeliveryEntity::find_by_id(delivery_id)
+        .one(conn)
+        .await?;
+
+    Ok(delivery)
+}
+
+/// Fetches a page of one endpoint's delivery log.
+///
+/// # Role
+/// Newest first without a cursor; `Older` pages descend from the cursor and
+/// `Newer` pages ascend from it (the service restores display order).
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_webhook_deliveries<C>(
+    conn: &C,
+    endpoint_id: Uuid,
+    cursor_id: Option<Uuid>,
+    cursor_direction: Option<CursorDirection>,
+    limit: u64,
+) -> Result<Vec<WebhookDeliveryModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut query =
+        WebhookDeliveryEntity::find().filter(WebhookDeliveryColumn::EndpointId.eq(endpoint_id));
+
+    if let Some(id) = cursor_id {
+        let direction = cursor_direction.unwrap_or(CursorDirection::Older);
+        query = match direction {
+            CursorDirection::Older => query
+                .filter(WebhookDeliveryColumn::Id.lt(id))
+                .order_by_desc(WebhookDeliveryColumn::Id),
+            CursorDirection::Newer => query
+                .filter(WebhookDeliveryColumn::Id.gt(id))
+                .order_by_asc(WebhookDeliveryColumn::Id),
+        };
+    } else {
+        query = query.order_by_desc(WebhookDeliveryColumn::Id);
+    }
+
+    let deliveries = query.limit(limit).all(conn).await?;
+
+    Ok(deliveries)
+}
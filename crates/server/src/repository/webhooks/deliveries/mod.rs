@@ -0,0 +1,14 @@
+//! Webhook delivery repository APIs.
+//!
+//! One row per (event, endpoint). Rows are written in the transaction of the log
+//! they report; the worker dispatches rows whose `dispatched_at` is still NULL.
+
+mod enqueue;
+pub mod exists;
+mod find;
+mod requeue;
+
+pub use enqueue::repository_enqueue_webhook_deliveries;
+pub use exists::*;
+pub use find::{repository_find_webhook_deliveries, repository_find_webhook_delivery_by_id};
+pub use requeue::repository_requeue_webhook_delivery;
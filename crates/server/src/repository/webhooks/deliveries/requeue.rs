@@ -0,0 +1,43 @@
+use entity::common::WebhookDeliveryStatus;
+use entity::webhook_deliveries::{
+    Column as WebhookDeliveryColumn, Entity as WebhookDeliveryEntity,
+};
+use errors::errors::Errors;
+use sea_orm::prelude::Expr;
+use sea_orm::{ActiveEnum, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Puts a logged delivery back in the outbox.
+///
+/// # Role
+/// Resets the delivery to `pending` and clears `dispatched_at`, so the worker's
+/// dispatcher sends it again with the same payload. Attempt history is kept.
+/// Returns the affected row count.
+///
+/// # Related
+/// - `service_redeliver_webhook`
+///
+/// # Errors
+/// - Returns a DB/repository error if the update fails.
+pub async fn repository_requeue_webhook_delivery<C>(
+    conn: &C,
+    delivery_id: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = WebhookDeliveryEntity::update_many()
+        .col_expr(
+            WebhookDeliveryColumn::Status,
+            WebhookDeliveryStatus::Pending.as_enum(),
+        )
+        .col_expr(
+            WebhookDeliveryColumn::DispatchedAt,
+            Expr::value(Option::<chrono::DateTime<chrono::Utc>>::None),
+        )
+        .filter(WebhookDeliveryColumn::Id.eq(delivery_id))
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
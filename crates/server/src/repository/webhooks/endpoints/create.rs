@@ -0,0 +1,44 @@
+use entity::webhook_endpoints::{
+    ActiveModel as WebhookEndpointActiveModel, Model as WebhookEndpointModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
+use uuid::Uuid;
+
+/// Registers a webhook endpoint.
+///
+/// # Role
+/// Inserts an active endpoint subscribed to `events` with the given signing secret.
+///
+/// # Related
+/// - `service_create_webhook_endpoint`
+///
+/// # Errors
+/// - Returns a DB/repository error if the insert fails.
+pub async fn repository_create_webhook_endpoint<C>(
+    conn: &C,
+    url: String,
+    description: String,
+    events: Vec<String>,
+    secret: String,
+    created_by: Uuid,
+) -> Result<WebhookEndpointModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let endpoint = WebhookEndpointActiveModel {
+        id: Default::default(),
+        url: Set(url),
+        description: Set(description),
+        events: Set(events),
+        is_active: Set(true),
+        secret: Set(secret),
+        created_by: Set(Some(created_by)),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    };
+
+    let endpoint = endpoint.insert(conn).await?;
+
+    Ok(endpoint)
+}
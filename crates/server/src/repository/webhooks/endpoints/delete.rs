@@ -0,0 +1,29 @@
+use entity::webhook_endpoints::Entity as WebhookEndpointEntity;
+use errors::errors::Errors;
+use sea_orm::{ConnectionTrait, EntityTrait};
+use uuid::Uuid;
+
+/// Deletes a webhook endpoint.
+///
+/// # Role
+/// Deletes the endpoint and returns the affected row count; its delivery log
+/// goes with it (`ON DELETE CASCADE`).
+///
+/// # Related
+/// - `service_delete_webhook_endpoint`
+///
+/// # Errors
+/// - Returns a DB/repository error if the deletion fails.
+pub async fn repository_delete_webhook_endpoint<C>(
+    conn: &C,
+    endpoint_id: Uuid,
+) -> Result<u64, Errors>
+where
+    C: ConnectionTrait,
+{
+    let result = WebhookEndpointEntity::delete_by_id(endpoint_id)
+        .exec(conn)
+        .await?;
+
+    Ok(result.rows_affected)
+}
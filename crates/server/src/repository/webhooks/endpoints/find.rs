@@ -0,0 +1,43 @@
+use entity::webhook_endpoints::{
+    Column as WebhookEndpointColumn, Entity as WebhookEndpointEntity, Model as WebhookEndpointModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ConnectionTrait, EntityTrait, QueryOrder};
+use uuid::Uuid;
+
+/// Finds a webhook endpoint by id.
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_webhook_endpoint_by_id<C>(
+    conn: &C,
+    endpoint_id: Uuid,
+) -> Result<Option<WebhookEndpointModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let endpoint = WebhookEndpointEntity::find_by_id(endpoint_id)
+        .one(conn)
+        .await?;
+
+    Ok(endpoint)
+}
+
+/// Lists every webhook endpoint, newest first. Endpoints are few and
+/// admin-managed, so the list is not paginated.
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn repository_find_webhook_endpoints<C>(
+    conn: &C,
+) -> Result<Vec<WebhookEndpointModel>, Errors>
+where
+    C: ConnectionTrait,
+{
+    let endpoints = WebhookEndpointEntity::find()
+        .order_by_desc(WebhookEndpointColumn::Id)
+        .all(conn)
+        .await?;
+
+    Ok(endpoints)
+}
@@ -0,0 +1,11 @@
+//! Webhook endpoint repository APIs.
+
+mod create;
+mod delete;
+mod find;
+mod update;
+
+pub use create::repository_create_webhook_endpoint;
+pub use delete::repository_delete_webhook_endpoint;
+pub use find::{repository_find_webhook_endpoint_by_id, repository_find_webhook_endpoints};
+pub use update::{WebhookEndpointUpdate, repository_update_webhook_endpoint};
@@ -0,0 +1,59 @@
+use chrono::Utc;
+use entity::webhook_endpoints::{
+    ActiveModel as WebhookEndpointActiveModel, Model as WebhookEndpointModel,
+};
+use errors::errors::Errors;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, IntoActiveModel, Set};
+
+/// Fields to change on a webhook endpoint; `None` keeps the current value.
+#[derive(Debug, Default)]
+pub struct WebhookEndpointUpdate {
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+    pub secret: Option<String>,
+}
+
+/// Updates a webhook endpoint.
+///
+/// # Role
+/// Applies the set fields of `update` to `endpoint` and bumps `updated_at`.
+///
+/// # Related
+/// - `service_update_webhook_endpoint`
+/// - `service_rotate_webhook_secret`
+///
+/// # Errors
+/// - Returns a DB/repository error if the update fails.
+pub async fn repository_update_webhook_endpoint<C>(
+    conn: &C,
+    endpoint: WebhookEndpointModel,
+    update: WebhookEndpointUpdate,
+) -> Result<WebhookEndpointModel, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut active: WebhookEndpointActiveModel = endpoint.into_active_model();
+
+    if let Some(url) = update.url {
+        active.url = Set(url);
+    }
+    if let Some(description) = update.description {
+        active.description = Set(description);
+    }
+    if let Some(events) = update.events {
+        active.events = Set(events);
+    }
+    if let Some(is_active) = update.is_active {
+        active.is_active = Set(is_active);
+    }
+    if let Some(secret) = update.secret {
+        active.secret = Set(secret);
+    }
+    active.updated_at = Set(Utc::now());
+
+    let endpoint = active.update(conn).await?;
+
+    Ok(endpoint)
+}
@@ -0,0 +1,10 @@
+//! Webhook repository layer.
+//!
+//! Provides data access for admin-registered webhook endpoints and their
+//! delivery log, which doubles as the outbox the worker dispatches from.
+
+pub mod deliveries;
+pub mod endpoints;
+
+pub use deliveries::*;
+pub use endpoints::*;
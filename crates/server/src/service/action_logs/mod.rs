@@ -1,3 +1,5 @@
 mod get_action_logs;
+mod record;
 
 pub use get_action_logs::service_get_action_logs;
+pub use record::{publish_action_log, record_action_log};
@@ -0,0 +1,59 @@
+//! Action log fan-out to the realtime event stream.
+//!
+//! Mutating services write their `action_logs` row inside their own transaction and call
+//! [`publish_action_log`] after commit. Flows without a transaction of their own (sign-in,
+//! sign-out) use [`record_action_log`], which never breaks the flow it observes.
+
+use crate::eventstream::publish_eventstream_event;
+use crate::repository::action_logs::repository_create_action_log;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
+use entity::action_logs::Model as ActionLogModel;
+use entity::common::ActionResourceType;
+use sea_orm::ConnectionTrait;
+use serde_json::Value as JsonValue;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Publish a committed action log over `REALTIME_EVENTS_SUBJECT`, best-effort: the row is
+/// already durable, so a publish failure only costs live SSE subscribers this one event.
+pub async fn publish_action_log(nats_client: &NatsClient, action_log: &ActionLogModel) {
+    if let Err(e) = publish_eventstream_event(nats_client, action_log).await {
+        warn!(
+            action_log_id = %action_log.id,
+            action = %action_log.action,
+            error = ?e,
+            "Failed to publish action log event"
+        );
+    }
+}
+
+/// Write and publish an action log outside any transaction, best-effort: a write failure is
+/// logged, never propagated.
+pub async fn record_action_log<C>(
+    conn: &C,
+    nats_client: &NatsClient,
+    action: ActionLogAction,
+    actor_id: Option<Uuid>,
+    resource_type: ActionResourceType,
+    resource_id: Option<Uuid>,
+    summary: String,
+    metadata: Option<JsonValue>,
+) where
+    C: ConnectionTrait,
+{
+    match repository_create_action_log(
+        conn,
+        action,
+        actor_id,
+        resource_type,
+        resource_id,
+        summary,
+        metadata,
+    )
+    .await
+    {
+        Ok(action_log) => publish_action_log(nats_client, &action_log).await,
+        Err(e) => warn!(error = ?e, action = %action, "Failed to record action log"),
+    }
+}
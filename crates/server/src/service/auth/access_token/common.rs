@@ -0,0 +1,15 @@
+/// Prefix of every raw personal access token. It tells tokens apart from bearer session tokens
+/// without a lookup and makes leaked tokens easy to recognize in secret scanners.
+pub const ACCESS_TOKEN_PREFIX: &str = "akpat_";
+
+/// Maximum personal access tokens a user can hold.
+pub const MAX_ACCESS_TOKENS_PER_USER: usize = 20;
+
+/// Minimum spacing between `last_used_at` writes for one token, so automation that calls the
+/// API in a tight loop does not turn every request into a DB write.
+pub const LAST_USED_WRITE_INTERVAL_SECONDS: i64 = 60;
+
+/// Whether a bearer token is a personal access token (rather than a session token).
+pub fn is_access_token(token: &str) -> bool {
+    token.starts_with(ACCESS_TOKEN_PREFIX)
+}
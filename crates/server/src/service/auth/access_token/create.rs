@@ -0,0 +1,97 @@
+use super::common::{ACCESS_TOKEN_PREFIX, MAX_ACCESS_TOKENS_PER_USER};
+use crate::bridge::worker_client;
+use crate::repository::access_tokens::{
+    repository_create_access_token, repository_find_access_tokens_by_user_id,
+};
+use crate::repository::auth_events::AUTH_EVENT_ACCESS_TOKEN_CREATED;
+use crate::repository::user::repository_get_user_by_id;
+use crate::service::auth::audit::record_auth_event;
+use crate::state::WorkerClient;
+use crate::utils::crypto::token::{generate_secure_token, hash_token};
+use chrono::{Duration, Utc};
+use dto::auth::request::CreateAccessTokenRequest;
+use dto::auth::response::{AccessTokenCreatedResponse, AccessTokenResponse};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+/// Mints a personal access token for the signed-in user.
+///
+/// # Role
+/// - Generates a prefixed 256-bit token and stores only its hash; the raw token is returned once.
+/// - Stores the requested scopes (deduplicated) and the optional expiry.
+/// - Records an audit event and emails the owner, like other credential changes.
+///
+/// # Errors
+/// - `Errors::AccessTokenLimitReached` if the user already holds the maximum number of tokens
+pub async fn service_create_access_token(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    user_id: Uuid,
+    payload: CreateAccessTokenRequest,
+) -> ServiceResult<AccessTokenCreatedResponse> {
+    let mut scopes: Vec<String> = Vec::with_capacity(payload.scopes.len());
+    for scope in payload.scopes {
+        let code = scope.as_str().to_string();
+        if !scopes.contains(&code) {
+            scopes.push(code);
+        }
+    }
+    let expires_at = payload
+        .expires_in_days
+        .map(|days| Utc::now() + Duration::days(days));
+
+    let token = format!("{}{}", ACCESS_TOKEN_PREFIX, generate_secure_token());
+
+    let txn = db.begin().await?;
+
+    let user = repository_get_user_by_id(&txn, user_id).await?;
+    if repository_find_access_tokens_by_user_id(&txn, user_id)
+        .await?
+        .len()
+        >= MAX_ACCESS_TOKENS_PER_USER
+    {
+        return Err(Errors::AccessTokenLimitReached);
+    }
+
+    let created = repository_create_access_token(
+        &txn,
+        user_id,
+        payload.name,
+        hash_token(&token),
+        scopes,
+        expires_at,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(user_id = %user_id, token_id = %created.id, "Personal access token created");
+
+    record_auth_event(
+        db,
+        Some(user_id),
+        AUTH_EVENT_ACCESS_TOKEN_CREATED,
+        None,
+        None,
+        Some(json!({ "token_id": created.id, "scopes": created.scopes })),
+    )
+    .await;
+    if let Err(e) = worker_client::send_security_alert(
+        worker,
+        &user.email,
+        &user.handle,
+        "A personal access token was created for your account",
+    )
+    .await
+    {
+        tracing::warn!(user_id = %user_id, error = ?e, "Failed to queue access-token-created alert email");
+    }
+
+    Ok(AccessTokenCreatedResponse {
+        token,
+        info: AccessTokenResponse::from(created),
+    })
+}
@@ -0,0 +1,40 @@
+use crate::repository::access_tokens::repository_delete_access_token;
+use crate::repository::auth_events::AUTH_EVENT_ACCESS_TOKEN_REVOKED;
+use crate::service::auth::audit::record_auth_event;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+/// Revokes one of the signed-in user's personal access tokens.
+///
+/// # Role
+/// Matches `id + user_id`, so a caller cannot revoke another user's token. The row is deleted,
+/// so the next request with the token is unauthenticated.
+///
+/// # Errors
+/// - `Errors::AccessTokenNotFound` if the token does not exist or belongs to someone else
+pub async fn service_delete_access_token(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    token_id: Uuid,
+) -> ServiceResult<()> {
+    if repository_delete_access_token(db, user_id, token_id).await? == 0 {
+        return Err(Errors::AccessTokenNotFound);
+    }
+
+    info!(user_id = %user_id, token_id = %token_id, "Personal access token revoked");
+
+    record_auth_event(
+        db,
+        Some(user_id),
+        AUTH_EVENT_ACCESS_TOKEN_REVOKED,
+        None,
+        None,
+        Some(json!({ "token_id": token_id })),
+    )
+    .await;
+
+    Ok(())
+}
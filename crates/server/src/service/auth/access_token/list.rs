@@ -0,0 +1,18 @@
+use crate::repository::access_tokens::repository_find_access_tokens_by_user_id;
+use dto::auth::response::{AccessTokenListResponse, AccessTokenResponse};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Lists the signed-in user's personal access tokens, newest first (expired ones included, so
+/// they can be cleaned up).
+pub async fn service_list_access_tokens(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<AccessTokenListResponse> {
+    let tokens = repository_find_access_tokens_by_user_id(db, user_id).await?;
+
+    Ok(AccessTokenListResponse {
+        data: tokens.into_iter().map(AccessTokenResponse::from).collect(),
+    })
+}
@@ -0,0 +1,16 @@
+//! Personal access token service APIs.
+//!
+//! Users mint named tokens limited to a set of `TokenScope`s for scripts and CI jobs. Tokens are
+//! opaque, stored hashed, and resolved per request by the `ScopedSession` extractor.
+
+mod common;
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod resolve;
+
+pub use common::is_access_token;
+pub use create::service_create_access_token;
+pub use delete::service_delete_access_token;
+pub use list::service_list_access_tokens;
+pub use resolve::service_resolve_access_token;
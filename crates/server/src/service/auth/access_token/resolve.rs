@@ -0,0 +1,70 @@
+use super::common::LAST_USED_WRITE_INTERVAL_SECONDS;
+use crate::repository::access_tokens::{
+    repository_find_access_token_by_hash, repository_touch_access_token,
+};
+use crate::repository::user::repository_find_user_by_id;
+use crate::service::auth::session_types::SessionContext;
+use crate::utils::crypto::token::hash_token;
+use chrono::{Duration, Utc};
+use constants::TokenScope;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+
+/// Resolves a raw personal access token into a `SessionContext` limited to its scopes.
+///
+/// # Role
+/// The personal-access-token counterpart of `SessionService::resolve_session`.
+/// - Looks the token up by hash and checks its expiry.
+/// - Rejects tokens of deleted or soft-deleted users.
+/// - Stamps `last_used_at`, at most once per `LAST_USED_WRITE_INTERVAL_SECONDS` (best-effort).
+///
+/// The context's `session_id` is the token hash and its `management_id` the token id, mirroring
+/// sessions; `scopes` carries the grant.
+///
+/// # Returns
+/// - `Ok(None)` for an unknown token or an inactive owner
+///
+/// # Errors
+/// - `Errors::AccessTokenExpired` for an expired token
+pub async fn service_resolve_access_token(
+    db: &DatabaseConnection,
+    token: &str,
+) -> ServiceResult<Option<SessionContext>> {
+    let token_hash = hash_token(token);
+    let Some(access_token) = repository_find_access_token_by_hash(db, &token_hash).await? else {
+        return Ok(None);
+    };
+
+    let now = Utc::now();
+    if access_token.expires_at.is_some_and(|at| now >= at) {
+        return Err(Errors::AccessTokenExpired);
+    }
+
+    let is_active = repository_find_user_by_id(db, access_token.user_id)
+        .await?
+        .is_some_and(|user| user.deleted_at.is_none());
+    if !is_active {
+        return Ok(None);
+    }
+
+    let stale = access_token
+        .last_used_at
+        .is_none_or(|at| now - at >= Duration::seconds(LAST_USED_WRITE_INTERVAL_SECONDS));
+    if stale && let Err(e) = repository_touch_access_token(db, access_token.id, now).await {
+        tracing::warn!(error = ?e, "Failed to record personal access token use");
+    }
+
+    // A stored scope that no longer parses grants nothing.
+    let scopes = access_token
+        .scopes
+        .iter()
+        .filter_map(|s| s.parse::<TokenScope>().ok())
+        .collect();
+
+    Ok(Some(SessionContext {
+        user_id: access_token.user_id,
+        session_id: token_hash,
+        management_id: access_token.id.to_string(),
+        scopes: Some(scopes),
+    }))
+}
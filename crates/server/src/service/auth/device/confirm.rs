@@ -4,10 +4,14 @@ use crate::repository::known_devices::{
     repository_find_known_device, repository_register_known_device,
 };
 use crate::repository::user::repository_find_user_by_id;
+use crate::service::action_logs::record_action_log;
 use crate::service::auth::audit::{parse_ip, record_auth_event};
 use crate::service::auth::session::SessionService;
+use crate::state::NatsClient;
 use crate::utils::crypto::token::hash_token;
 use crate::utils::redis_cache::get_json_and_delete;
+use constants::ActionLogAction;
+use entity::common::ActionResourceType;
 use errors::errors::Errors;
 use redis::aio::ConnectionManager as RedisClient;
 use sea_orm::DatabaseConnection;
@@ -28,6 +32,7 @@ pub struct DeviceVerifyResult {
 pub async fn confirm_device_verification(
     db: &DatabaseConnection,
     redis: &RedisClient,
+    nats_client: &NatsClient,
     token: &str,
 ) -> Result<DeviceVerifyResult, Errors> {
     // Single-use lookup by the hashed token id.
@@ -93,6 +98,17 @@ pub async fn confirm_device_verification(
         None,
     )
     .await;
+    record_action_log(
+        db,
+        nats_client,
+        ActionLogAction::AuthLogin,
+        Some(user.id),
+        ActionResourceType::User,
+        Some(user.id),
+        "Signed in from a newly verified device".to_string(),
+        None,
+    )
+    .await;
 
     Ok(DeviceVerifyResult {
         session_token,
@@ -0,0 +1,43 @@
+use crate::repository::auth_events::AUTH_EVENT_DEVICE_FORGOTTEN;
+use crate::repository::known_devices::repository_delete_known_device;
+use crate::service::auth::audit::{parse_ip, record_auth_event};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+/// Forgets one of the user's trusted devices.
+///
+/// # Role
+/// Deletes the `known_devices` row, so the next login presenting that device's token is
+/// challenged by email again. Sessions already open on the device are left alone; revoke them
+/// separately. Matches `id + user_id`, so a caller cannot forget another user's device.
+///
+/// # Errors
+/// - `Errors::NotFound` if the device does not exist or belongs to someone else
+pub async fn service_forget_known_device(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    device_id: Uuid,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+) -> ServiceResult<()> {
+    if repository_delete_known_device(db, user_id, device_id).await? == 0 {
+        return Err(Errors::NotFound("Device not found".to_string()));
+    }
+
+    info!(user_id = %user_id, device_id = %device_id, "Known device forgotten");
+
+    record_auth_event(
+        db,
+        Some(user_id),
+        AUTH_EVENT_DEVICE_FORGOTTEN,
+        parse_ip(ip_address.as_deref()),
+        user_agent,
+        Some(json!({ "device_id": device_id })),
+    )
+    .await;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::repository::known_devices::repository_find_known_devices_by_user;
+use crate::utils::crypto::token::hash_token;
+use dto::auth::response::{KnownDeviceListResponse, KnownDeviceResponse};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Lists the user's trusted devices, most recently seen first.
+///
+/// # Role
+/// Only metadata is returned; the device hash never leaves the server. The device whose token
+/// the caller presented (browser cookie or `X-Device-Token`) is marked `is_current`.
+///
+/// # Errors
+/// - Returns DB/repository errors when the query fails.
+pub async fn service_list_known_devices(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    presented_device_token: Option<&str>,
+) -> ServiceResult<KnownDeviceListResponse> {
+    let current_hash = presented_device_token.map(hash_token);
+    let devices = repository_find_known_devices_by_user(db, user_id).await?;
+
+    let data = devices
+        .into_iter()
+        .map(|device| KnownDeviceResponse {
+            is_current: current_hash.as_deref() == Some(device.device_hash.as_str()),
+            id: device.id,
+            user_agent: device.user_agent,
+            last_ip: device.last_ip.map(|ip| ip.ip().to_string()),
+            first_seen: device.first_seen,
+            last_seen: device.last_seen,
+        })
+        .collect();
+
+    Ok(KnownDeviceListResponse { data })
+}
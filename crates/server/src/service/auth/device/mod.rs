@@ -2,12 +2,17 @@
 //!
 //! After credentials are fully verified, a login from an unrecognized device is held and challenged
 //! by email on every channel; only a confirmed device is trusted and remembered — browsers remember
-//! it via the device cookie, native apps via a stored `X-Device-Token`.
+//! it via the device cookie, native apps via a stored `X-Device-Token`. Users can list their trusted devices and forget one,
+//! which sends its next login through the challenge again.
 
 pub mod confirm;
+pub mod forget;
+pub mod list;
 pub mod resolve;
 pub mod types;
 
 pub use confirm::{DeviceVerifyResult, confirm_device_verification};
-pub use resolve::resolve_device_login;
+pub use forget::service_forget_known_device;
+pub use list::service_list_known_devices;
+pub use resolve::{create_login_session, resolve_device_login, trust_login_device};
 pub use types::{DeviceLoginOutcome, DevicePendingData};
@@ -1,15 +1,18 @@
 use super::types::{DeviceLoginOutcome, DevicePendingData};
 use crate::bridge::worker_client;
-use crate::repository::auth_events::AUTH_EVENT_LOGIN_SUCCESS;
+use crate::repository::auth_events::{AUTH_EVENT_LOGIN_SUCCESS, AUTH_EVENT_NEW_DEVICE};
 use crate::repository::known_devices::{
-    repository_find_known_device, repository_touch_known_device,
+    repository_find_known_device, repository_register_known_device, repository_touch_known_device,
 };
+use crate::service::action_logs::record_action_log;
 use crate::service::auth::audit::{parse_ip, record_auth_event};
 use crate::service::auth::session::SessionService;
-use crate::state::WorkerClient;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::crypto::token::{generate_secure_token, hash_token};
 use crate::utils::redis_cache::issue_token_and_store_json_with_ttl;
 use config::ServerConfig;
+use constants::ActionLogAction;
+use entity::common::ActionResourceType;
 use entity::users::Model as UserModel;
 use errors::errors::Errors;
 use redis::aio::ConnectionManager as RedisClient;
@@ -28,6 +31,7 @@ pub async fn resolve_device_login(
     db: &DatabaseConnection,
     redis: &RedisClient,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     user: &UserModel,
     presented_device_token: Option<String>,
     user_agent: Option<String>,
@@ -41,9 +45,15 @@ pub async fn resolve_device_login(
         let device_hash = hash_token(token);
         if let Some(device) = repository_find_known_device(db, user.id, &device_hash).await? {
             repository_touch_known_device(db, device, audit_ip).await?;
-            let session_token =
-                create_session_and_record(redis, db, user, user_agent.clone(), ip_address.clone())
-                    .await?;
+            let session_token = create_login_session(
+                redis,
+                db,
+                nats_client,
+                user,
+                user_agent.clone(),
+                ip_address.clone(),
+            )
+            .await?;
             return Ok(DeviceLoginOutcome::SessionCreated { session_token });
         }
     }
@@ -85,10 +95,14 @@ pub async fn resolve_device_login(
     Ok(DeviceLoginOutcome::VerificationRequired)
 }
 
-/// Create the session and record the successful-login audit event (best-effort).
-async fn create_session_and_record(
+/// Create the session and record the successful-login audit event and action log (best-effort).
+///
+/// Also called directly by flows whose first step already proved control of the mailbox (magic-link
+/// sign-in), where the new-device gate does not apply.
+pub async fn create_login_session(
     redis: &RedisClient,
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     user: &UserModel,
     user_agent: Option<String>,
     ip_address: Option<String>,
@@ -110,6 +124,51 @@ async fn create_session_and_record(
         None,
     )
     .await;
+    record_action_log(
+        db,
+        nats_client,
+        ActionLogAction::AuthLogin,
+        Some(user.id),
+        ActionResourceType::User,
+        Some(user.id),
+        "Signed in".to_string(),
+        None,
+    )
+    .await;
 
     Ok(raw_token)
 }
+
+/// Trust the device behind `device_token` for `user`: refresh its row when it is already known,
+/// otherwise register it and record the new-device audit event.
+///
+/// For flows that skip the new-device gate (magic-link sign-in), so the device they signed in from
+/// is recognized on later logins exactly as if its email challenge had been confirmed.
+pub async fn trust_login_device(
+    db: &DatabaseConnection,
+    user: &UserModel,
+    device_token: &str,
+    user_agent: Option<String>,
+    ip_address: Option<&str>,
+) -> Result<(), Errors> {
+    let device_hash = hash_token(device_token);
+    let device_ip = parse_ip(ip_address);
+
+    if let Some(device) = repository_find_known_device(db, user.id, &device_hash).await? {
+        return repository_touch_known_device(db, device, device_ip).await;
+    }
+
+    repository_register_known_device(db, user.id, device_hash, user_agent.clone(), device_ip)
+        .await?;
+    record_auth_event(
+        db,
+        Some(user.id),
+        AUTH_EVENT_NEW_DEVICE,
+        device_ip,
+        user_agent,
+        None,
+    )
+    .await;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::repository::auth_events::{
+    repository_exists_newer_auth_event, repository_exists_older_auth_event,
+    repository_find_user_auth_events,
+};
+use crate::service::cursor_pagination::{cursor_flags, reverse_if_newer};
+use dto::auth::request::ListAuthEventsRequest;
+use dto::auth::response::{AuthEventListResponse, AuthEventResponse};
+use dto::pagination::CursorDirection;
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Pages through the user's own authentication events, newest first.
+///
+/// # Role
+/// The self-service view of `auth_events`: sign-ins, failed attempts against the account,
+/// credential and 2FA changes. Failed logins on an unknown email carry no `user_id` and never
+/// show up here.
+///
+/// # Related
+/// - `repository_find_user_auth_events`
+/// - `record_auth_event`
+///
+/// # Errors
+/// - Returns DB/repository errors when the query fails.
+pub async fn service_list_auth_events(
+    conn: &DatabaseConnection,
+    user_id: Uuid,
+    payload: ListAuthEventsRequest,
+) -> ServiceResult<AuthEventListResponse> {
+    let is_newer = payload.cursor_direction == Some(CursorDirection::Newer);
+
+    let mut events = repository_find_user_auth_events(
+        conn,
+        user_id,
+        payload.cursor_id,
+        payload.cursor_direction,
+        payload.limit,
+    )
+    .await?;
+
+    let (has_newer, has_older) = cursor_flags(
+        &events,
+        is_newer,
+        |event| event.id,
+        |cursor| repository_exists_newer_auth_event(conn, user_id, cursor),
+        |cursor| repository_exists_older_auth_event(conn, user_id, cursor),
+    )
+    .await?;
+
+    reverse_if_newer(&mut events, is_newer);
+
+    let data = events.into_iter().map(AuthEventResponse::from).collect();
+
+    Ok(AuthEventListResponse {
+        data,
+        has_newer,
+        has_older,
+    })
+}
@@ -1,12 +1,19 @@
-use crate::repository::auth_events::AUTH_EVENT_LOGIN_FAILED;
+use crate::bridge::worker_client;
+use crate::repository::auth_events::{AUTH_EVENT_ACCOUNT_LOCKED, AUTH_EVENT_LOGIN_FAILED};
+use crate::repository::passkeys::repository_find_user_passkeys_by_user_id;
 use crate::repository::user::repository_find_user_by_email;
 use crate::service::auth::audit::{parse_ip, record_auth_event};
 use crate::service::auth::device::{DeviceLoginOutcome, resolve_device_login};
+use crate::service::auth::login_throttle::{
+    LoginFailureOutcome, check_login_throttle, clear_login_failures, record_login_failure,
+};
+use crate::service::auth::passkey::passkeys_enabled;
 use crate::service::auth::totp::TotpTempToken;
-use crate::state::WorkerClient;
+use crate::state::{NatsClient, WorkerClient};
 use dto::auth::request::LoginRequest;
+use entity::users::Model as UserModel;
 use errors::errors::{Errors, ServiceResult};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::utils::crypto::password::{verify_dummy_password, verify_password};
 use redis::aio::ConnectionManager;
@@ -19,8 +26,11 @@ pub enum LoginResult {
         session_id: String,
         remember_me: bool,
     },
-    /// TOTP required: returns a temporary token
-    TotpRequired(String),
+    /// TOTP required: returns a temporary token, and whether a passkey may stand in for the code
+    TotpRequired {
+        temp_token: String,
+        passkey_available: bool,
+    },
     /// New device: session withheld, verification email sent (OWASP ASVS 6.3.5)
     DeviceVerificationRequired,
 }
@@ -28,11 +38,13 @@ pub enum LoginResult {
 /// Handles a login request.
 ///
 /// # Responsibilities
-/// - Verifies the email/password credentials.
+/// - Rejects the attempt while the account or client IP is throttled or locked.
+/// - Verifies the email/password credentials, counting failures toward the throttle.
 /// - If the user has TOTP enabled, issues a temporary token and requires the TOTP step.
 /// - After credentials pass: a new device requires email verification, a trusted device gets a session.
 ///
 /// # Related
+/// - `check_login_throttle` / `record_login_failure`
 /// - `repository_find_user_by_email`
 /// - `verify_password`
 /// - `TotpTempToken::create`
@@ -40,18 +52,22 @@ pub enum LoginResult {
 ///
 /// # Errors
 /// - `Errors::InvalidCredentials` on authentication failure
+/// - `Errors::LoginThrottled` while the account or client IP is blocked
 /// - Returns Redis/storage errors when session/token persistence fails.
 pub async fn service_login(
     db: &DatabaseConnection,
     redis: &ConnectionManager,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     payload: LoginRequest,
     user_agent: Option<String>,
     ip_address: Option<String>,
     presented_device_token: Option<String>,
 ) -> ServiceResult<LoginResult> {
+    // Blocked subjects are rejected before any lookup, identically for known and unknown emails.
+    check_login_throttle(redis, &payload.email, ip_address.as_deref()).await?;
+
     let user = repository_find_user_by_email(db, payload.email.clone()).await?;
-    let audit_ip = parse_ip(ip_address.as_deref());
 
     // Constant-time credential check (account-enumeration defense). Every path runs
     // exactly one Argon2 verification, so a missing / soft-deleted / password-less
@@ -66,36 +82,39 @@ pub async fn service_login(
         Some(password_hash) => {
             if verify_password(&payload.password, password_hash).is_err() {
                 // Wrong password on an existing active account.
-                record_auth_event(
+                fail_login(
                     db,
-                    user.as_ref().map(|u| u.id),
-                    AUTH_EVENT_LOGIN_FAILED,
-                    audit_ip,
+                    redis,
+                    worker,
+                    user.as_ref(),
+                    &payload.email,
+                    ip_address.as_deref(),
                     user_agent.clone(),
-                    None,
                 )
-                .await;
+                .await?;
                 return Err(Errors::InvalidCredentials);
             }
         }
         None => {
             verify_dummy_password(&payload.password);
             // Unknown email, or a soft-deleted / password-less (OAuth-only) account.
-            record_auth_event(
+            fail_login(
                 db,
-                user.as_ref().map(|u| u.id),
-                AUTH_EVENT_LOGIN_FAILED,
-                audit_ip,
+                redis,
+                worker,
+                user.as_ref(),
+                &payload.email,
+                ip_address.as_deref(),
                 user_agent.clone(),
-                None,
             )
-            .await;
+            .await?;
             return Err(Errors::InvalidCredentials);
         }
     }
 
     // Password matched, so an active account with a usable hash is present.
     let user = user.expect("user is present when a password hash matched");
+    clear_login_failures(redis, &payload.email).await?;
 
     // Check whether TOTP is enabled
     if user.totp_enabled_at.is_some() {
@@ -107,11 +126,21 @@ pub async fn service_login(
             ip_address,
             payload.remember_me,
             presented_device_token,
+            false,
         )
         .await?;
 
+        // Offer the passkey second factor only when the account actually has one.
+        let passkey_available = passkeys_enabled()
+            && !repository_find_user_passkeys_by_user_id(db, user.id)
+                .await?
+                .is_empty();
+
         info!(user_id = %user.id, "Login requires TOTP");
-        return Ok(LoginResult::TotpRequired(temp_token.token));
+        return Ok(LoginResult::TotpRequired {
+            temp_token: temp_token.token,
+            passkey_available,
+        });
     }
 
     // No TOTP: new-device check (recognized device creates a session, new device triggers an email challenge).
@@ -119,6 +148,7 @@ pub async fn service_login(
         db,
         redis,
         worker,
+        nats_client,
         &user,
         presented_device_token,
         user_agent,
@@ -141,3 +171,55 @@ pub async fn service_login(
         }
     }
 }
+
+/// Record a failed login: audit event, throttle counters, and, when this failure locks the
+/// account, an audit event and security alert to the owner. The caller's response is the same
+/// either way, so a lock reveals nothing about whether the email exists.
+async fn fail_login(
+    db: &DatabaseConnection,
+    redis: &ConnectionManager,
+    worker: &WorkerClient,
+    user: Option<&UserModel>,
+    email: &str,
+    ip_address: Option<&str>,
+    user_agent: Option<String>,
+) -> ServiceResult<()> {
+    let audit_ip = parse_ip(ip_address);
+    record_auth_event(
+        db,
+        user.map(|u| u.id),
+        AUTH_EVENT_LOGIN_FAILED,
+        audit_ip,
+        user_agent.clone(),
+        None,
+    )
+    .await;
+
+    let outcome = record_login_failure(redis, email, ip_address).await?;
+    if outcome == LoginFailureOutcome::AccountLocked
+        && let Some(user) = user.filter(|u| u.deleted_at.is_none())
+    {
+        warn!(user_id = %user.id, "Account locked after repeated failed logins");
+        record_auth_event(
+            db,
+            Some(user.id),
+            AUTH_EVENT_ACCOUNT_LOCKED,
+            audit_ip,
+            user_agent,
+            None,
+        )
+        .await;
+        if let Err(e) = worker_client::send_security_alert(
+            worker,
+            &user.email,
+            &user.handle,
+            "Sign-in to your account was temporarily locked after repeated failed attempts",
+        )
+        .await
+        {
+            warn!(user_id = %user.id, error = ?e, "Failed to queue account-locked alert email");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+//! Progressive login throttling and temporary lockout.
+//!
+//! Failed password logins are counted in Redis per account (keyed by the hash of the normalized
+//! email, so unknown addresses are counted exactly like real ones) and per client IP. Past a
+//! threshold each further failure blocks the subject for an exponentially growing delay; at the
+//! lock threshold the subject is blocked for [`LOCK_SECONDS`]. A block is only ever extended, never
+//! shortened: attempts already past the check when the lock lands keep failing into a fresh
+//! counter, and their small delays must not replace the lock. `auth_events` stays the audit trail.
+
+use crate::utils::crypto::token::hash_token;
+use crate::utils::email::normalize_email;
+use crate::utils::redis_cache::{
+    delete_key, get_ttl_seconds, incr_with_ttl, key_exists, set_json_with_min_ttl,
+};
+use config::ServerConfig;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+
+/// Failures are forgotten this long after the first one in a run.
+pub const FAILURE_WINDOW_SECONDS: u64 = 15 * 60;
+/// Account failures allowed before each further one adds a delay.
+pub const ACCOUNT_DELAY_AFTER: i64 = 3;
+/// Account failures that lock the account.
+pub const ACCOUNT_LOCK_AFTER: i64 = 10;
+/// IP failures (across all accounts) allowed before each further one adds a delay.
+pub const IP_DELAY_AFTER: i64 = 10;
+/// IP failures that lock the IP.
+pub const IP_LOCK_AFTER: i64 = 50;
+/// Upper bound of a single progressive delay.
+pub const MAX_DELAY_SECONDS: u64 = 60;
+/// How long a lock lasts.
+pub const LOCK_SECONDS: u64 = 15 * 60;
+
+/// What recording a failure did to the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginFailureOutcome {
+    /// Counted; any delay applies to the next attempt.
+    Counted,
+    /// This failure reached the lock threshold and locked the account.
+    AccountLocked,
+}
+
+/// Counter subject for an account, derived from the submitted email whether or not it exists.
+pub fn account_subject(email: &str) -> String {
+    format!("account:{}", hash_token(&normalize_email(email)))
+}
+
+/// Counter subject for a client IP. Per-IP throttling shares the rate limiter's switch: every e2e
+/// client shares one source IP, so `RATE_LIMIT_ENABLED=false` turns it off there.
+fn ip_subject(ip_address: Option<&str>) -> Option<String> {
+    ip_address
+        .filter(|_| ServerConfig::get().rate_limit_enabled)
+        .map(|ip| format!("ip:{ip}"))
+}
+
+/// Delay before the next attempt after `failures` failures: none below `delay_after`, then 2s
+/// doubling per failure, capped at [`MAX_DELAY_SECONDS`].
+pub fn progressive_delay_seconds(failures: i64, delay_after: i64) -> u64 {
+    if failures < delay_after {
+        return 0;
+    }
+    let exponent = (failures - delay_after).min(16) as u32;
+    2u64.pow(exponent + 1).min(MAX_DELAY_SECONDS)
+}
+
+/// Rejects the attempt while the account or the client IP is blocked.
+///
+/// # Errors
+/// - `Errors::LoginThrottled` with the remaining block time (the longer of the two)
+pub async fn check_login_throttle(
+    redis: &RedisClient,
+    email: &str,
+    ip_address: Option<&str>,
+) -> ServiceResult<()> {
+    let mut retry_after =
+        get_ttl_seconds(redis, &constants::login_block_key(&account_subject(email))).await?;
+    if let Some(subject) = ip_subject(ip_address) {
+        let ip_retry_after = get_ttl_seconds(redis, &constants::login_block_key(&subject)).await?;
+        retry_after = retry_after.max(ip_retry_after);
+    }
+
+    match retry_after {
+        Some(seconds) => Err(Errors::LoginThrottled {
+            retry_after_seconds: seconds.max(1),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Counts a failed attempt against the account and the client IP and blocks them as needed.
+pub async fn record_login_failure(
+    redis: &RedisClient,
+    email: &str,
+    ip_address: Option<&str>,
+) -> ServiceResult<LoginFailureOutcome> {
+    let account = account_subject(email);
+    let failures = incr_with_ttl(
+        redis,
+        &constants::login_failures_key(&account),
+        FAILURE_WINDOW_SECONDS,
+    )
+    .await?;
+    let outcome = block_subject(
+        redis,
+        &account,
+        failures,
+        ACCOUNT_DELAY_AFTER,
+        ACCOUNT_LOCK_AFTER,
+    )
+    .await?;
+
+    if let Some(subject) = ip_subject(ip_address) {
+        let failures = incr_with_ttl(
+            redis,
+            &constants::login_failures_key(&subject),
+            FAILURE_WINDOW_SECONDS,
+        )
+        .await?;
+        block_subject(redis, &subject, failures, IP_DELAY_AFTER, IP_LOCK_AFTER).await?;
+    }
+
+    Ok(outcome)
+}
+
+/// Forgets the account's failures after a successful sign-in or an admin unlock.
+///
+/// Returns whether the account had any failures or an active block. The IP counter is left alone,
+/// so one valid account cannot be used to reset an IP that is guessing at others.
+pub async fn clear_login_failures(redis: &RedisClient, email: &str) -> ServiceResult<bool> {
+    let account = account_subject(email);
+    let failures_key = constants::login_failures_key(&account);
+    let block_key = constants::login_block_key(&account);
+
+    let had_state =
+        key_exists(redis, &failures_key).await? || key_exists(redis, &block_key).await?;
+    delete_key(redis, &failures_key).await?;
+    delete_key(redis, &block_key).await?;
+
+    Ok(had_state)
+}
+
+async fn block_subject(
+    redis: &RedisClient,
+    subject: &str,
+    failures: i64,
+    delay_after: i64,
+    lock_after: i64,
+) -> ServiceResult<LoginFailureOutcome> {
+    if failures >= lock_after {
+        // Start a fresh run once the lock expires.
+        set_json_with_min_ttl(
+            redis,
+            &constants::login_block_key(subject),
+            &true,
+            LOCK_SECONDS,
+        )
+        .await?;
+        delete_key(redis, &constants::login_failures_key(subject)).await?;
+        return Ok(LoginFailureOutcome::AccountLocked);
+    }
+
+    let delay = progressive_delay_seconds(failures, delay_after);
+    if delay > 0 {
+        set_json_with_min_ttl(redis, &constants::login_block_key(subject), &true, delay).await?;
+    }
+    Ok(LoginFailureOutcome::Counted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_starts_at_threshold_and_doubles() {
+        assert_eq!(progressive_delay_seconds(0, 3), 0);
+        assert_eq!(progressive_delay_seconds(2, 3), 0);
+        assert_eq!(progressive_delay_seconds(3, 3), 2);
+        assert_eq!(progressive_delay_seconds(4, 3), 4);
+        assert_eq!(progressive_delay_seconds(6, 3), 16);
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        assert_eq!(progressive_delay_seconds(8, 3), MAX_DELAY_SECONDS);
+        assert_eq!(progressive_delay_seconds(1_000, 3), MAX_DELAY_SECONDS);
+    }
+
+    #[test]
+    fn account_subject_ignores_case_and_whitespace() {
+        assert_eq!(
+            account_subject(" User@Example.com "),
+            account_subject("user@example.com")
+        );
+    }
+}
@@ -1,23 +1,48 @@
+use crate::service::action_logs::record_action_log;
 use crate::service::auth::session::SessionService;
+use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use redis::aio::ConnectionManager;
+use sea_orm::DatabaseConnection;
 use tracing::info;
 
 /// Logs out the current session.
 ///
 /// # Responsibilities
-/// Deletes the server-side session matching the session ID.
+/// Deletes the server-side session matching the session ID and records an
+/// `auth:logout` action log (best-effort).
 ///
 /// # Related
 /// - `SessionService::delete_session`
+/// - `record_action_log`
 ///
 /// # Errors
 /// - Returns Redis/storage errors when session deletion fails.
-pub async fn service_logout(redis: &ConnectionManager, session_id: &str) -> ServiceResult<()> {
+pub async fn service_logout(
+    db: &DatabaseConnection,
+    redis: &ConnectionManager,
+    nats_client: &NatsClient,
+    session: &SessionContext,
+) -> ServiceResult<()> {
     // Delete the session (delete_session validates it internally)
-    SessionService::delete_session(redis, session_id).await?;
+    SessionService::delete_session(redis, &session.session_id).await?;
 
-    info!(session_id = %session_id, "Logout");
+    record_action_log(
+        db,
+        nats_client,
+        ActionLogAction::AuthLogout,
+        Some(session.user_id),
+        ActionResourceType::User,
+        Some(session.user_id),
+        "Signed out".to_string(),
+        None,
+    )
+    .await;
+
+    info!(session_id = %session.session_id, "Logout");
 
     Ok(())
 }
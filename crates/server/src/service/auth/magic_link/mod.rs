@@ -0,0 +1,10 @@
+//! Passwordless magic-link sign-in.
+//!
+//! A single-use link is emailed to the account address; following it signs the user in. Control of
+//! the mailbox is the proof, so the new-device gate is skipped, but TOTP still applies as step 2.
+
+pub mod request;
+pub mod verify;
+
+pub use request::{MagicLinkData, service_request_magic_link};
+pub use verify::{MagicLinkResult, service_verify_magic_link};
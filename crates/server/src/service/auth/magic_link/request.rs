@@ -0,0 +1,71 @@
+use crate::bridge::worker_client;
+use crate::repository::user::repository_find_user_by_email;
+use crate::state::WorkerClient;
+use crate::utils::crypto::token::{generate_secure_token, hash_token};
+use crate::utils::redis_cache::issue_token_and_store_json_with_ttl;
+use config::ServerConfig;
+use errors::errors::ServiceResult;
+use redis::aio::ConnectionManager;
+use sea_orm::ConnectionTrait;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+/// Magic-link token data stored in Redis
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MagicLinkData {
+    pub user_id: Uuid,
+}
+
+/// Emails a single-use sign-in link.
+///
+/// Security: always returns success regardless of whether the email exists.
+pub async fn service_request_magic_link<C>(
+    conn: &C,
+    redis_conn: &ConnectionManager,
+    worker: &WorkerClient,
+    email: &str,
+) -> ServiceResult<()>
+where
+    C: ConnectionTrait,
+{
+    let config = ServerConfig::get();
+
+    // 1. Look up the user by email
+    let user = repository_find_user_by_email(conn, email.to_string()).await?;
+
+    // 2. Return silently for unknown or soft-deleted accounts (avoids revealing email existence)
+    let user = match user.filter(|u| u.deleted_at.is_none()) {
+        Some(u) => u,
+        None => {
+            info!("Magic link requested for non-existent email");
+            return Ok(());
+        }
+    };
+
+    // 3. Store the token in Redis under its hash (convert minutes to seconds)
+    let link_data = MagicLinkData { user_id: user.id };
+    let ttl_seconds = (config.auth_magic_link_token_expire_time * 60) as u64;
+    let token = issue_token_and_store_json_with_ttl(
+        redis_conn,
+        generate_secure_token,
+        |token| constants::magic_link_key(&hash_token(token)),
+        &link_data,
+        ttl_seconds,
+    )
+    .await?;
+
+    // 4. Ask the Worker service to send the email
+    worker_client::send_magic_link_email(
+        worker,
+        &user.email,
+        &user.handle,
+        &token,
+        config.auth_magic_link_token_expire_time as u64,
+    )
+    .await?;
+
+    info!("Magic link email sent");
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use super::request::MagicLinkData;
+use crate::repository::passkeys::repository_find_user_passkeys_by_user_id;
+use crate::repository::user::repository_find_user_by_id;
+use crate::service::auth::device::{create_login_session, trust_login_device};
+use crate::service::auth::passkey::passkeys_enabled;
+use crate::service::auth::totp::TotpTempToken;
+use crate::state::NatsClient;
+use crate::utils::crypto::token::{generate_secure_token, hash_token};
+use crate::utils::redis_cache::get_json_and_delete;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use tracing::info;
+
+/// Magic-link sign-in outcome: session created / TOTP required
+pub enum MagicLinkResult {
+    /// No TOTP: returns the session ID and the now-trusted device's token
+    SessionCreated {
+        session_id: String,
+        remember_me: bool,
+        device_token: String,
+    },
+    /// TOTP required: returns a temporary token, and whether a passkey may stand in for the code
+    TotpRequired {
+        temp_token: String,
+        passkey_available: bool,
+    },
+}
+
+/// Completes a magic-link sign-in via the emailed single-use token.
+///
+/// # Responsibilities
+/// - Consumes the token (single-use lookup by its hash).
+/// - If the user has TOTP enabled, issues a temporary token and requires the TOTP step.
+/// - Otherwise mints the session directly. The new-device gate is skipped on both paths: following
+///   the emailed link is already proof of control of the account's mailbox.
+/// - Trusts the device signed in from (the presented device token, or a new one), so later password
+///   logins from it are recognized. On the TOTP path this happens once the second factor succeeds,
+///   and only for a presented token.
+///
+/// # Related
+/// - `service_request_magic_link`
+/// - `TotpTempToken::create`
+/// - `create_login_session`
+/// - `trust_login_device`
+///
+/// # Errors
+/// - `Errors::TokenInvalidMagicLink` for an unknown, expired, or already-used token
+/// - `Errors::UserNotFound` if the account was deleted after the link was sent
+pub async fn service_verify_magic_link(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    nats_client: &NatsClient,
+    token: &str,
+    remember_me: bool,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    device_token: Option<String>,
+) -> ServiceResult<MagicLinkResult> {
+    let key = constants::magic_link_key(&hash_token(token));
+    let link_data: MagicLinkData = get_json_and_delete(
+        redis,
+        &key,
+        || Errors::TokenInvalidMagicLink,
+        |_| Errors::TokenInvalidMagicLink,
+    )
+    .await?;
+
+    let user = repository_find_user_by_id(db, link_data.user_id)
+        .await?
+        .filter(|u| u.deleted_at.is_none())
+        .ok_or(Errors::UserNotFound)?;
+
+    if user.totp_enabled_at.is_some() {
+        // The temp token is marked as device-verified so the second step does not re-run the
+        // new-device gate.
+        let temp_token = TotpTempToken::create(
+            redis,
+            user.id,
+            user_agent,
+            ip_address,
+            remember_me,
+            device_token,
+            true,
+        )
+        .await?;
+
+        let passkey_available = passkeys_enabled()
+            && !repository_find_user_passkeys_by_user_id(db, user.id)
+                .await?
+                .is_empty();
+
+        info!(user_id = %user.id, "Magic link sign-in requires TOTP");
+        return Ok(MagicLinkResult::TotpRequired {
+            temp_token: temp_token.token,
+            passkey_available,
+        });
+    }
+
+    let device_token = device_token.unwrap_or_else(generate_secure_token);
+    trust_login_device(
+        db,
+        &user,
+        &device_token,
+        user_agent.clone(),
+        ip_address.as_deref(),
+    )
+    .await?;
+
+    let session_id =
+        create_login_session(redis, db, nats_client, &user, user_agent, ip_address).await?;
+
+    info!(user_id = %user.id, "Magic link sign-in successful");
+    Ok(MagicLinkResult::SessionCreated {
+        session_id,
+        remember_me,
+        device_token,
+    })
+}
@@ -1,19 +1,26 @@
 //! Authentication service layer.
 //!
-//! Provides signup, login/logout, email/password flows, session lifecycle
-//! management, and optional TOTP authentication flows.
+//! Provides signup, login/logout, email/password and magic-link flows, session lifecycle
+//! management (including login history and known devices), optional TOTP and passkey
+//! authentication flows, and personal access tokens.
 
+pub mod access_token;
 pub mod audit;
 pub mod change_email;
 pub mod change_password;
 pub mod confirm_email_change;
 pub mod device;
 pub mod forgot_password;
+pub mod list_auth_events;
 pub mod list_sessions;
 pub mod login;
+pub mod login_throttle;
 pub mod logout;
+pub mod magic_link;
+pub mod passkey;
 pub mod resend_verification_email;
 pub mod reset_password;
+pub mod revoke_other_sessions;
 pub mod revoke_session;
 pub mod session;
 pub mod session_types;
@@ -0,0 +1,165 @@
+use crate::repository::passkeys::repository_update_user_passkey_usage;
+use crate::service::auth::device::DeviceLoginOutcome;
+use crate::utils::crypto::token::{generate_secure_token, hash_token};
+use crate::utils::redis_cache::{
+    get_optional_json_and_delete, issue_token_and_store_json_with_ttl,
+};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use config::ServerConfig;
+use constants::{PASSKEY_CEREMONY_TTL_SECONDS, passkey_ceremony_key};
+use entity::user_passkeys::Model as UserPasskeyModel;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use uuid::Uuid;
+use webauthn_rs::prelude::{
+    AuthenticationResult, DiscoverableAuthentication, Passkey, PasskeyAuthentication,
+    PasskeyRegistration, Url,
+};
+use webauthn_rs::{Webauthn, WebauthnBuilder};
+
+/// Passkeys one account may register.
+pub const MAX_PASSKEYS_PER_USER: usize = 10;
+
+/// Relying party built once from `WEBAUTHN_RP_*`; `None` when passkeys are not configured.
+static WEBAUTHN: LazyLock<Option<Webauthn>> = LazyLock::new(|| {
+    let config = ServerConfig::get();
+    let rp_id = config.webauthn_rp_id.as_deref()?;
+    let rp_origin = config.webauthn_rp_origin.as_deref()?;
+
+    let build = || -> Result<Webauthn, String> {
+        let origin = Url::parse(rp_origin).map_err(|e| e.to_string())?;
+        let mut builder = WebauthnBuilder::new(rp_id, &origin)
+            .map_err(|e| e.to_string())?
+            .rp_name(&config.webauthn_rp_name);
+        // Native apps assert with their own origin (e.g. `android:apk-key-hash:...`).
+        for extra in &config.webauthn_extra_origins {
+            builder = builder.append_allowed_origin(&Url::parse(extra).map_err(|e| e.to_string())?);
+        }
+        builder.build().map_err(|e| e.to_string())
+    };
+
+    match build() {
+        Ok(webauthn) => Some(webauthn),
+        Err(e) => {
+            tracing::error!(error = %e, "Invalid WebAuthn relying-party configuration; passkeys disabled");
+            None
+        }
+    }
+});
+
+/// Returns the relying party, or a 400 when passkeys are not configured on this server.
+pub fn webauthn() -> ServiceResult<&'static Webauthn> {
+    WEBAUTHN.as_ref().ok_or_else(|| {
+        Errors::BadRequestError("Passkeys are not configured on this server".to_string())
+    })
+}
+
+/// Whether passkeys are configured (used to advertise the TOTP alternative at login).
+pub fn passkeys_enabled() -> bool {
+    WEBAUTHN.is_some()
+}
+
+/// Text form of a credential id, as stored in `user_passkeys.credential_id`.
+pub fn encode_credential_id(credential_id: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(credential_id)
+}
+
+/// Decodes a stored `user_passkeys.passkey` column.
+pub fn decode_passkey(value: &serde_json::Value) -> ServiceResult<Passkey> {
+    serde_json::from_value(value.clone())
+        .map_err(|e| Errors::SysInternalError(format!("Stored passkey is unreadable: {}", e)))
+}
+
+/// Persists the authenticator's new signature counter (when it moved) and stamps `last_used_at`.
+pub async fn record_passkey_use(
+    db: &DatabaseConnection,
+    row: &UserPasskeyModel,
+    mut passkey: Passkey,
+    result: &AuthenticationResult,
+) -> ServiceResult<()> {
+    let passkey_json = match passkey.update_credential(result) {
+        Some(true) => Some(serde_json::to_value(&passkey).map_err(|e| {
+            Errors::SysInternalError(format!("Passkey serialization failed: {}", e))
+        })?),
+        _ => None,
+    };
+
+    repository_update_user_passkey_usage(db, row.id, passkey_json).await
+}
+
+/// Passkey login outcome: session created, or new-device confirmation required.
+pub enum PasskeyLoginResult {
+    /// Trusted device (or app): returns the session ID
+    SessionCreated {
+        session_id: String,
+        remember_me: bool,
+    },
+    /// New device: session deferred, verification email sent (OWASP ASVS 6.3.5)
+    DeviceVerificationRequired,
+}
+
+impl PasskeyLoginResult {
+    pub fn from_outcome(outcome: DeviceLoginOutcome, remember_me: bool) -> Self {
+        match outcome {
+            DeviceLoginOutcome::SessionCreated { session_token } => Self::SessionCreated {
+                session_id: session_token,
+                remember_me,
+            },
+            DeviceLoginOutcome::VerificationRequired => Self::DeviceVerificationRequired,
+        }
+    }
+}
+
+/// Server-side half of an in-flight WebAuthn ceremony (stored in Redis).
+///
+/// The options go to the client; this state stays here and is consumed exactly once by the
+/// matching finish call, so a signed challenge cannot be replayed.
+#[derive(Serialize, Deserialize)]
+pub enum PasskeyCeremony {
+    /// Adding a passkey to a signed-in account.
+    Registration {
+        user_id: Uuid,
+        state: PasskeyRegistration,
+    },
+    /// Passwordless login with a discoverable credential (the user is not known up front).
+    Login { state: DiscoverableAuthentication },
+    /// Second factor after a password, bound to the login's TOTP temp token.
+    SecondFactor {
+        user_id: Uuid,
+        temp_token_hash: String,
+        state: PasskeyAuthentication,
+    },
+}
+
+impl PasskeyCeremony {
+    /// Stores the ceremony and returns its id; the raw id is only ever handed to the client
+    /// (the Redis key holds its hash).
+    pub async fn store(&self, redis: &RedisClient) -> ServiceResult<String> {
+        issue_token_and_store_json_with_ttl(
+            redis,
+            generate_secure_token,
+            |token| passkey_ceremony_key(&hash_token(token)),
+            self,
+            PASSKEY_CEREMONY_TTL_SECONDS,
+        )
+        .await
+    }
+
+    /// Fetches and deletes the ceremony (single-use).
+    ///
+    /// # Errors
+    /// - `Errors::PasskeyCeremonyInvalid` if it is unknown, expired, or already used
+    pub async fn take(redis: &RedisClient, ceremony_id: &str) -> ServiceResult<Self> {
+        get_optional_json_and_delete(
+            redis,
+            &passkey_ceremony_key(&hash_token(ceremony_id)),
+            |e| Errors::SysInternalError(format!("Passkey ceremony deserialization failed: {}", e)),
+        )
+        .await?
+        .ok_or(Errors::PasskeyCeremonyInvalid)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::bridge::worker_client;
+use crate::repository::auth_events::AUTH_EVENT_PASSKEY_REMOVED;
+use crate::repository::passkeys::repository_delete_user_passkey;
+use crate::repository::user::repository_get_user_by_id;
+use crate::service::auth::audit::record_auth_event;
+use crate::state::WorkerClient;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use tracing::info;
+use uuid::Uuid;
+
+/// Removes one of the signed-in user's passkeys.
+///
+/// # Role
+/// Matches `id + user_id`, so a caller cannot remove another user's passkey. Records an audit
+/// event and emails the owner.
+///
+/// # Errors
+/// - `Errors::PasskeyNotFound` if the passkey does not exist or belongs to someone else
+pub async fn service_delete_passkey(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    user_id: Uuid,
+    passkey_id: Uuid,
+) -> ServiceResult<()> {
+    let user = repository_get_user_by_id(db, user_id).await?;
+
+    if repository_delete_user_passkey(db, user_id, passkey_id).await? == 0 {
+        return Err(Errors::PasskeyNotFound);
+    }
+
+    info!(user_id = %user_id, passkey_id = %passkey_id, "Passkey removed");
+
+    record_auth_event(
+        db,
+        Some(user_id),
+        AUTH_EVENT_PASSKEY_REMOVED,
+        None,
+        None,
+        None,
+    )
+    .await;
+    if let Err(e) = worker_client::send_security_alert(
+        worker,
+        &user.email,
+        &user.handle,
+        "A passkey was removed from your account",
+    )
+    .await
+    {
+        tracing::warn!(user_id = %user_id, error = ?e, "Failed to queue passkey-removed alert email");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::repository::passkeys::repository_find_user_passkeys_by_user_id;
+use dto::auth::response::{PasskeyListResponse, PasskeyResponse};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Lists the signed-in user's passkeys, newest first.
+pub async fn service_list_passkeys(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+) -> ServiceResult<PasskeyListResponse> {
+    let passkeys = repository_find_user_passkeys_by_user_id(db, user_id).await?;
+
+    Ok(PasskeyListResponse {
+        data: passkeys.into_iter().map(PasskeyResponse::from).collect(),
+    })
+}
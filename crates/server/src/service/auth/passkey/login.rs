@@ -0,0 +1,105 @@
+use super::common::{
+    PasskeyCeremony, PasskeyLoginResult, decode_passkey, encode_credential_id, record_passkey_use,
+    webauthn,
+};
+use crate::repository::auth_events::AUTH_EVENT_LOGIN_FAILED;
+use crate::repository::passkeys::repository_find_user_passkey_by_credential_id;
+use crate::repository::user::repository_find_user_by_id;
+use crate::service::auth::audit::{parse_ip, record_auth_event};
+use crate::service::auth::device::resolve_device_login;
+use crate::state::{NatsClient, WorkerClient};
+use dto::auth::request::PasskeyLoginRequest;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use tracing::info;
+use webauthn_rs::prelude::DiscoverableKey;
+
+/// Finishes a passwordless passkey login.
+///
+/// # Responsibilities
+/// - Consumes the login ceremony (single-use challenge).
+/// - Resolves the credential from the assertion's user handle + credential id and verifies it.
+/// - A passkey assertion is user-verified, so it satisfies MFA on its own: TOTP is not asked for.
+/// - Applies the same new-device gate as password login.
+///
+/// # Related
+/// - `service_passkey_login_options`
+/// - `resolve_device_login`
+///
+/// # Errors
+/// - `Errors::PasskeyCeremonyInvalid` for an unknown, expired, or already-used ceremony
+/// - `Errors::PasskeyAuthenticationFailed` if the credential is unknown or the assertion fails
+pub async fn service_passkey_login(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
+    payload: PasskeyLoginRequest,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    presented_device_token: Option<String>,
+) -> ServiceResult<PasskeyLoginResult> {
+    let webauthn = webauthn()?;
+
+    let state = match PasskeyCeremony::take(redis, &payload.ceremony_id).await? {
+        PasskeyCeremony::Login { state } => state,
+        _ => return Err(Errors::PasskeyCeremonyInvalid),
+    };
+
+    let (user_id, credential_id) = webauthn
+        .identify_discoverable_authentication(&payload.credential)
+        .map_err(|_| Errors::PasskeyAuthenticationFailed)?;
+    let row =
+        repository_find_user_passkey_by_credential_id(db, &encode_credential_id(credential_id))
+            .await?
+            .filter(|row| row.user_id == user_id)
+            .ok_or(Errors::PasskeyAuthenticationFailed)?;
+    let user = repository_find_user_by_id(db, user_id)
+        .await?
+        .filter(|user| user.deleted_at.is_none())
+        .ok_or(Errors::PasskeyAuthenticationFailed)?;
+
+    let passkey = decode_passkey(&row.passkey)?;
+    let result = match webauthn.finish_discoverable_authentication(
+        &payload.credential,
+        state,
+        &[DiscoverableKey::from(&passkey)],
+    ) {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::debug!(error = %e, "Passkey assertion rejected");
+            record_auth_event(
+                db,
+                Some(user.id),
+                AUTH_EVENT_LOGIN_FAILED,
+                parse_ip(ip_address.as_deref()),
+                user_agent,
+                None,
+            )
+            .await;
+            return Err(Errors::PasskeyAuthenticationFailed);
+        }
+    };
+    record_passkey_use(db, &row, passkey, &result).await?;
+
+    info!(user_id = %user.id, passkey_id = %row.id, "Passkey verified");
+
+    let outcome = resolve_device_login(
+        db,
+        redis,
+        worker,
+        nats_client,
+        &user,
+        presented_device_token,
+        user_agent,
+        ip_address,
+        payload.remember_me,
+    )
+    .await?;
+
+    Ok(PasskeyLoginResult::from_outcome(
+        outcome,
+        payload.remember_me,
+    ))
+}
@@ -0,0 +1,33 @@
+use super::common::{PasskeyCeremony, webauthn};
+use dto::auth::response::PasskeyAuthenticationOptionsResponse;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+
+/// Starts a passwordless passkey login.
+///
+/// # Role
+/// Issues `navigator.credentials.get()` options with an empty allow-list: the authenticator
+/// offers its discoverable credentials for this relying party and the assertion's user handle
+/// identifies the account, so nothing about the user is revealed before they authenticate.
+///
+/// # Related
+/// - `service_passkey_login` (finishes the ceremony)
+///
+/// # Errors
+/// - `Errors::BadRequestError` if passkeys are not configured
+pub async fn service_passkey_login_options(
+    redis: &RedisClient,
+) -> ServiceResult<PasskeyAuthenticationOptionsResponse> {
+    let webauthn = webauthn()?;
+
+    let (options, state) = webauthn.start_discoverable_authentication().map_err(|e| {
+        Errors::SysInternalError(format!("Passkey authentication start failed: {}", e))
+    })?;
+
+    let ceremony_id = PasskeyCeremony::Login { state }.store(redis).await?;
+
+    Ok(PasskeyAuthenticationOptionsResponse {
+        ceremony_id,
+        options,
+    })
+}
@@ -0,0 +1,25 @@
+//! Passkey (WebAuthn) authentication service APIs.
+//!
+//! Covers registration, listing and removal, passwordless login with discoverable credentials,
+//! and passkeys as a second factor alternative to TOTP. Ceremony state lives in Redis between
+//! the options and finish calls (see `PasskeyCeremony`).
+
+mod common;
+pub mod delete;
+pub mod list;
+pub mod login;
+pub mod login_options;
+pub mod register;
+pub mod register_options;
+pub mod second_factor;
+pub mod second_factor_options;
+
+pub use common::{PasskeyLoginResult, passkeys_enabled};
+pub use delete::service_delete_passkey;
+pub use list::service_list_passkeys;
+pub use login::service_passkey_login;
+pub use login_options::service_passkey_login_options;
+pub use register::service_passkey_register;
+pub use register_options::service_passkey_register_options;
+pub use second_factor::service_passkey_second_factor;
+pub use second_factor_options::service_passkey_second_factor_options;
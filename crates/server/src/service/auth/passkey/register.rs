@@ -0,0 +1,107 @@
+use super::common::{MAX_PASSKEYS_PER_USER, PasskeyCeremony, encode_credential_id, webauthn};
+use crate::bridge::worker_client;
+use crate::repository::auth_events::AUTH_EVENT_PASSKEY_ADDED;
+use crate::repository::passkeys::{
+    repository_create_user_passkey, repository_find_user_passkey_by_credential_id,
+    repository_find_user_passkeys_by_user_id,
+};
+use crate::repository::user::repository_get_user_by_id;
+use crate::service::auth::audit::record_auth_event;
+use crate::state::WorkerClient;
+use dto::auth::request::PasskeyRegisterRequest;
+use dto::auth::response::PasskeyResponse;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+use uuid::Uuid;
+
+/// Finishes adding a passkey: verifies the authenticator's attestation and stores the credential.
+///
+/// # Role
+/// - Consumes the registration ceremony, which must belong to the same account.
+/// - Rejects a credential id that is already registered (to any account).
+/// - Records an audit event and emails the owner, like other 2FA changes.
+///
+/// # Related
+/// - `service_passkey_register_options`
+/// - `repository_create_user_passkey`
+///
+/// # Errors
+/// - `Errors::PasskeyCeremonyInvalid` for an unknown, expired, or foreign ceremony
+/// - `Errors::PasskeyRegistrationFailed` if the attestation does not verify
+/// - `Errors::PasskeyAlreadyRegistered` / `Errors::PasskeyLimitReached`
+pub async fn service_passkey_register(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    worker: &WorkerClient,
+    user_id: Uuid,
+    payload: PasskeyRegisterRequest,
+) -> ServiceResult<PasskeyResponse> {
+    let webauthn = webauthn()?;
+
+    let state = match PasskeyCeremony::take(redis, &payload.ceremony_id).await? {
+        PasskeyCeremony::Registration {
+            user_id: owner,
+            state,
+        } if owner == user_id => state,
+        _ => return Err(Errors::PasskeyCeremonyInvalid),
+    };
+
+    let passkey = webauthn
+        .finish_passkey_registration(&payload.credential, &state)
+        .map_err(|e| {
+            tracing::debug!(error = %e, "Passkey registration rejected");
+            Errors::PasskeyRegistrationFailed
+        })?;
+    let credential_id = encode_credential_id(passkey.cred_id().as_ref());
+    let passkey_json = serde_json::to_value(&passkey)
+        .map_err(|e| Errors::SysInternalError(format!("Passkey serialization failed: {}", e)))?;
+
+    let txn = db.begin().await?;
+
+    let user = repository_get_user_by_id(&txn, user_id).await?;
+    if repository_find_user_passkeys_by_user_id(&txn, user_id)
+        .await?
+        .len()
+        >= MAX_PASSKEYS_PER_USER
+    {
+        return Err(Errors::PasskeyLimitReached);
+    }
+    if repository_find_user_passkey_by_credential_id(&txn, &credential_id)
+        .await?
+        .is_some()
+    {
+        return Err(Errors::PasskeyAlreadyRegistered);
+    }
+
+    let created =
+        repository_create_user_passkey(&txn, user_id, credential_id, passkey_json, payload.name)
+            .await?;
+
+    txn.commit().await?;
+
+    info!(user_id = %user_id, passkey_id = %created.id, "Passkey registered");
+
+    record_auth_event(
+        db,
+        Some(user_id),
+        AUTH_EVENT_PASSKEY_ADDED,
+        None,
+        None,
+        None,
+    )
+    .await;
+    if let Err(e) = worker_client::send_security_alert(
+        worker,
+        &user.email,
+        &user.handle,
+        "A passkey was added to your account",
+    )
+    .await
+    {
+        tracing::warn!(user_id = %user_id, error = ?e, "Failed to queue passkey-added alert email");
+    }
+
+    Ok(PasskeyResponse::from(created))
+}
@@ -0,0 +1,55 @@
+use super::common::{MAX_PASSKEYS_PER_USER, PasskeyCeremony, decode_passkey, webauthn};
+use crate::repository::passkeys::repository_find_user_passkeys_by_user_id;
+use crate::repository::user::repository_get_user_by_id;
+use dto::auth::response::PasskeyRegistrationOptionsResponse;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Starts adding a passkey to the signed-in account.
+///
+/// # Role
+/// - Issues `navigator.credentials.create()` options; the user handle is the account id.
+/// - Excludes the user's existing credentials so the same authenticator is not enrolled twice.
+/// - Stores the ceremony state in Redis under a single-use ceremony id.
+///
+/// # Related
+/// - `service_passkey_register` (finishes the ceremony)
+///
+/// # Errors
+/// - `Errors::BadRequestError` if passkeys are not configured
+/// - `Errors::PasskeyLimitReached` if the account already has the maximum number of passkeys
+pub async fn service_passkey_register_options(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    user_id: Uuid,
+) -> ServiceResult<PasskeyRegistrationOptionsResponse> {
+    let webauthn = webauthn()?;
+
+    let user = repository_get_user_by_id(db, user_id).await?;
+    let existing = repository_find_user_passkeys_by_user_id(db, user_id).await?;
+    if existing.len() >= MAX_PASSKEYS_PER_USER {
+        return Err(Errors::PasskeyLimitReached);
+    }
+
+    let exclude = existing
+        .iter()
+        .map(|row| decode_passkey(&row.passkey).map(|passkey| passkey.cred_id().clone()))
+        .collect::<ServiceResult<Vec<_>>>()?;
+
+    let (options, state) = webauthn
+        .start_passkey_registration(user.id, &user.handle, &user.display_name, Some(exclude))
+        .map_err(|e| {
+            Errors::SysInternalError(format!("Passkey registration start failed: {}", e))
+        })?;
+
+    let ceremony_id = PasskeyCeremony::Registration { user_id, state }
+        .store(redis)
+        .await?;
+
+    Ok(PasskeyRegistrationOptionsResponse {
+        ceremony_id,
+        options,
+    })
+}
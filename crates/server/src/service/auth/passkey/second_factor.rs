@@ -0,0 +1,118 @@
+use super::common::{
+    PasskeyCeremony, PasskeyLoginResult, decode_passkey, encode_credential_id, record_passkey_use,
+    webauthn,
+};
+use crate::repository::passkeys::repository_find_user_passkey_by_credential_id;
+use crate::repository::user::repository_get_user_by_id;
+use crate::service::auth::device::{
+    DeviceLoginOutcome, create_login_session, resolve_device_login, trust_login_device,
+};
+use crate::service::auth::totp::TotpTempToken;
+use crate::state::{NatsClient, WorkerClient};
+use crate::utils::crypto::token::hash_token;
+use dto::auth::request::PasskeySecondFactorRequest;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use tracing::info;
+
+/// Finishes login step 2 with a passkey instead of a TOTP code.
+///
+/// # Responsibilities
+/// - Consumes the ceremony and the temp token (both single-use, like a TOTP attempt).
+/// - Verifies the assertion against the user's stored credential and updates its counter.
+/// - Hands off to the new-device gate with the context captured at password login (skipped after
+///   a magic-link first step).
+///
+/// # Related
+/// - `service_passkey_second_factor_options`
+/// - `service_totp_verify` (the TOTP-code equivalent)
+///
+/// # Errors
+/// - `Errors::PasskeyCeremonyInvalid` if the ceremony is unknown, used, or bound to another login
+/// - `Errors::TotpTempTokenInvalid` for an unknown or expired temp token
+/// - `Errors::PasskeyAuthenticationFailed` if the assertion does not verify
+pub async fn service_passkey_second_factor(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
+    payload: PasskeySecondFactorRequest,
+) -> ServiceResult<PasskeyLoginResult> {
+    let webauthn = webauthn()?;
+
+    let (user_id, state) = match PasskeyCeremony::take(redis, &payload.ceremony_id).await? {
+        PasskeyCeremony::SecondFactor {
+            user_id,
+            temp_token_hash,
+            state,
+        } if temp_token_hash == hash_token(&payload.temp_token) => (user_id, state),
+        _ => return Err(Errors::PasskeyCeremonyInvalid),
+    };
+
+    let token_data = TotpTempToken::get_and_delete(redis, &payload.temp_token)
+        .await?
+        .filter(|token_data| token_data.user_id == user_id)
+        .ok_or(Errors::TotpTempTokenInvalid)?;
+
+    let result = webauthn
+        .finish_passkey_authentication(&payload.credential, &state)
+        .map_err(|e| {
+            tracing::debug!(error = %e, "Passkey assertion rejected");
+            Errors::PasskeyAuthenticationFailed
+        })?;
+    let row = repository_find_user_passkey_by_credential_id(
+        db,
+        &encode_credential_id(result.cred_id().as_ref()),
+    )
+    .await?
+    .filter(|row| row.user_id == user_id)
+    .ok_or(Errors::PasskeyAuthenticationFailed)?;
+    record_passkey_use(db, &row, decode_passkey(&row.passkey)?, &result).await?;
+
+    info!(user_id = %user_id, passkey_id = %row.id, "Passkey second factor verified");
+
+    let user = repository_get_user_by_id(db, user_id).await?;
+    // A magic-link first step already proved control of the mailbox, so it skips the device check
+    // and trusts the device it carried instead.
+    let outcome = if token_data.skip_device_check {
+        if let Some(device_token) = token_data.device_token.as_deref() {
+            trust_login_device(
+                db,
+                &user,
+                device_token,
+                token_data.user_agent.clone(),
+                token_data.ip_address.as_deref(),
+            )
+            .await?;
+        }
+        let session_token = create_login_session(
+            redis,
+            db,
+            nats_client,
+            &user,
+            token_data.user_agent,
+            token_data.ip_address,
+        )
+        .await?;
+        DeviceLoginOutcome::SessionCreated { session_token }
+    } else {
+        resolve_device_login(
+            db,
+            redis,
+            worker,
+            nats_client,
+            &user,
+            token_data.device_token,
+            token_data.user_agent,
+            token_data.ip_address,
+            token_data.remember_me,
+        )
+        .await?
+    };
+
+    Ok(PasskeyLoginResult::from_outcome(
+        outcome,
+        token_data.remember_me,
+    ))
+}
@@ -0,0 +1,62 @@
+use super::common::{PasskeyCeremony, decode_passkey, webauthn};
+use crate::repository::passkeys::repository_find_user_passkeys_by_user_id;
+use crate::service::auth::totp::TotpTempToken;
+use crate::utils::crypto::token::hash_token;
+use dto::auth::response::PasskeyAuthenticationOptionsResponse;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+
+/// Starts a passkey second factor for a login that stopped at the TOTP step.
+///
+/// # Role
+/// - Reads (without consuming) the temp token from POST /v0/auth/login, so the user can still
+///   fall back to a TOTP code if the authenticator is unavailable.
+/// - Issues assertion options restricted to that user's registered credentials.
+/// - Binds the ceremony to the temp token, so it cannot finish a different login.
+///
+/// # Related
+/// - `service_passkey_second_factor` (finishes the ceremony)
+///
+/// # Errors
+/// - `Errors::TotpTempTokenInvalid` for an unknown or expired temp token
+/// - `Errors::PasskeyNotFound` if the account has no passkeys
+pub async fn service_passkey_second_factor_options(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    temp_token: &str,
+) -> ServiceResult<PasskeyAuthenticationOptionsResponse> {
+    let webauthn = webauthn()?;
+
+    let token_data = TotpTempToken::get(redis, temp_token)
+        .await?
+        .ok_or(Errors::TotpTempTokenInvalid)?;
+
+    let passkeys = repository_find_user_passkeys_by_user_id(db, token_data.user_id)
+        .await?
+        .iter()
+        .map(|row| decode_passkey(&row.passkey))
+        .collect::<ServiceResult<Vec<_>>>()?;
+    if passkeys.is_empty() {
+        return Err(Errors::PasskeyNotFound);
+    }
+
+    let (options, state) = webauthn
+        .start_passkey_authentication(&passkeys)
+        .map_err(|e| {
+            Errors::SysInternalError(format!("Passkey authentication start failed: {}", e))
+        })?;
+
+    let ceremony_id = PasskeyCeremony::SecondFactor {
+        user_id: token_data.user_id,
+        temp_token_hash: hash_token(temp_token),
+        state,
+    }
+    .store(redis)
+    .await?;
+
+    Ok(PasskeyAuthenticationOptionsResponse {
+        ceremony_id,
+        options,
+    })
+}
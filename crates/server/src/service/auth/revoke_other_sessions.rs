@@ -0,0 +1,47 @@
+use crate::repository::auth_events::AUTH_EVENT_OTHER_SESSIONS_REVOKED;
+use crate::service::auth::audit::{parse_ip, record_auth_event};
+use crate::service::auth::session::SessionService;
+use errors::errors::ServiceResult;
+use redis::aio::ConnectionManager;
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+/// Signs the user out everywhere except the session making the request.
+///
+/// # Role
+/// Deletes every other session in the user's per-user session index and returns how many were
+/// revoked. Recorded as an auth event even when there was nothing to revoke.
+///
+/// # Related
+/// - `SessionService::delete_other_sessions`
+///
+/// # Errors
+/// - `Errors::SysInternalError` on Redis failure
+pub async fn service_revoke_other_sessions(
+    db: &DatabaseConnection,
+    redis: &ConnectionManager,
+    user_id: Uuid,
+    current_session_id: &str,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+) -> ServiceResult<u64> {
+    let revoked =
+        SessionService::delete_other_sessions(redis, &user_id.to_string(), current_session_id)
+            .await?;
+
+    info!(user_id = %user_id, revoked, "Other sessions revoked");
+
+    record_auth_event(
+        db,
+        Some(user_id),
+        AUTH_EVENT_OTHER_SESSIONS_REVOKED,
+        parse_ip(ip_address.as_deref()),
+        user_agent,
+        Some(json!({ "revoked": revoked })),
+    )
+    .await;
+
+    Ok(revoked)
+}
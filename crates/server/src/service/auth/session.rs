@@ -202,6 +202,28 @@ impl SessionService {
         }
     }
 
+    /// Checks that a session still exists for `user_id` and is within its absolute lifetime.
+    ///
+    /// # Role
+    /// A read-only re-check for long-lived responses (the notification SSE stream) that
+    /// authenticated once and must stop after logout or revocation. Unlike
+    /// `resolve_session`, it never refreshes the sliding TTL, so an open stream alone does
+    /// not keep a session alive.
+    ///
+    /// # Errors
+    /// - `Errors::SysInternalError` on deserialization or Redis read failure
+    pub async fn is_session_active(
+        redis: &RedisClient,
+        session_id: &str,
+        user_id: Uuid,
+    ) -> Result<bool, Errors> {
+        let session = Self::get_session(redis, session_id).await?;
+
+        Ok(session.is_some_and(|session| {
+            session.user_id == user_id.to_string() && Utc::now() < session.max_expires_at
+        }))
+    }
+
     /// Validates a session ID and resolves it into a `SessionContext`.
     ///
     /// # Role
@@ -260,6 +282,7 @@ impl SessionService {
             user_id,
             session_id,
             management_id: session.management_id,
+            scopes: None,
         }))
     }
 
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Duration, Utc};
+use constants::TokenScope;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,6 +9,19 @@ pub struct SessionContext {
     pub user_id: Uuid,
     pub session_id: String,
     pub management_id: String,
+    /// `None` for an interactive session (full access); for a personal access token, the
+    /// scopes it was granted.
+    pub scopes: Option<Vec<TokenScope>>,
+}
+
+impl SessionContext {
+    /// Whether the caller may act within `scope`: always for a session, and for a personal
+    /// access token only if it was granted the scope.
+    pub fn has_scope(&self, scope: TokenScope) -> bool {
+        self.scopes
+            .as_ref()
+            .is_none_or(|scopes| scopes.contains(&scope))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,5 +1,5 @@
 use crate::utils::crypto::token::hash_token;
-use crate::utils::redis_cache::{get_optional_json_and_delete, set_json_with_ttl};
+use crate::utils::redis_cache::{get_json, get_optional_json_and_delete, set_json_with_ttl};
 use chrono::{DateTime, Utc};
 use errors::errors::Errors;
 use rand::Rng;
@@ -22,6 +22,11 @@ pub struct TotpTempToken {
     /// `None` when the client presented no device token yet.
     #[serde(default)]
     pub device_token: Option<String>,
+    /// Set when the first step already proved control of the account's mailbox (magic-link
+    /// sign-in), so the new-device gate is skipped once the second factor succeeds and
+    /// `device_token`, if any, is trusted instead.
+    #[serde(default)]
+    pub skip_device_check: bool,
     pub created_at: DateTime<Utc>,
 }
 
@@ -36,6 +41,7 @@ impl TotpTempToken {
         ip_address: Option<String>,
         remember_me: bool,
         device_token: Option<String>,
+        skip_device_check: bool,
     ) -> Self {
         // Generate a cryptographically secure random token (32 bytes = 256 bits)
         let mut bytes = [0u8; 32];
@@ -49,6 +55,7 @@ impl TotpTempToken {
             ip_address,
             remember_me,
             device_token,
+            skip_device_check,
             created_at: Utc::now(),
         }
     }
@@ -70,8 +77,16 @@ impl TotpTempToken {
         ip_address: Option<String>,
         remember_me: bool,
         device_token: Option<String>,
+        skip_device_check: bool,
     ) -> Result<Self, Errors> {
-        let temp_token = Self::new(user_id, user_agent, ip_address, remember_me, device_token);
+        let temp_token = Self::new(
+            user_id,
+            user_agent,
+            ip_address,
+            remember_me,
+            device_token,
+            skip_device_check,
+        );
 
         set_json_with_ttl(
             redis,
@@ -84,6 +99,12 @@ impl TotpTempToken {
         Ok(temp_token)
     }
 
+    /// Fetches the temporary token without consuming it (the passkey second factor peeks at it to
+    /// build its options, leaving TOTP available as a fallback until one of them finishes)
+    pub async fn get(redis: &RedisClient, token: &str) -> Result<Option<Self>, Errors> {
+        get_json(redis, &format!("totp_temp:{}", hash_token(token))).await
+    }
+
     /// Fetches and deletes the temporary token (single-use)
     pub async fn get_and_delete(redis: &RedisClient, token: &str) -> Result<Option<Self>, Errors> {
         // Look up by the hashed token id (raw token never lives at rest).
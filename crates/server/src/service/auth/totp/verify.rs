@@ -2,9 +2,11 @@ use super::common::verify_totp_code;
 use crate::repository::user::{
     UserUpdateParams, repository_get_user_by_id_for_update, repository_update_user,
 };
-use crate::service::auth::device::{DeviceLoginOutcome, resolve_device_login};
+use crate::service::auth::device::{
+    DeviceLoginOutcome, create_login_session, resolve_device_login, trust_login_device,
+};
 use crate::service::auth::totp::TotpTempToken;
-use crate::state::WorkerClient;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::crypto::backup_code::verify_backup_code;
 use errors::errors::{Errors, ServiceResult};
 use redis::aio::ConnectionManager as RedisClient;
@@ -27,6 +29,7 @@ pub async fn service_totp_verify(
     db: &DatabaseConnection,
     redis: &RedisClient,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     temp_token: &str,
     code: &str,
 ) -> ServiceResult<TotpVerifyResult> {
@@ -105,17 +108,43 @@ pub async fn service_totp_verify(
 
     // Device check: decide via the device token from the initial login whether the device is
     // recognized. Recognized → create session; new device → email challenge (browser and app alike).
-    let outcome = resolve_device_login(
-        db,
-        redis,
-        worker,
-        &user,
-        token_data.device_token.clone(),
-        token_data.user_agent.clone(),
-        token_data.ip_address.clone(),
-        token_data.remember_me,
-    )
-    .await?;
+    // A magic-link first step already proved control of the mailbox, so it skips the check and
+    // trusts the device it carried instead.
+    let outcome = if token_data.skip_device_check {
+        if let Some(device_token) = token_data.device_token.as_deref() {
+            trust_login_device(
+                db,
+                &user,
+                device_token,
+                token_data.user_agent.clone(),
+                token_data.ip_address.as_deref(),
+            )
+            .await?;
+        }
+        let session_token = create_login_session(
+            redis,
+            db,
+            nats_client,
+            &user,
+            token_data.user_agent.clone(),
+            token_data.ip_address.clone(),
+        )
+        .await?;
+        DeviceLoginOutcome::SessionCreated { session_token }
+    } else {
+        resolve_device_login(
+            db,
+            redis,
+            worker,
+            nats_client,
+            &user,
+            token_data.device_token.clone(),
+            token_data.user_agent.clone(),
+            token_data.ip_address.clone(),
+            token_data.remember_me,
+        )
+        .await?
+    };
 
     match outcome {
         DeviceLoginOutcome::SessionCreated { session_token } => {
@@ -1,14 +1,20 @@
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::user::{
     repository_create_user_with_password_hash, repository_find_user_by_email,
     repository_find_user_by_handle,
 };
+use crate::service::action_logs::publish_action_log;
+use crate::state::NatsClient;
 use crate::utils::crypto::token::{generate_secure_token, hash_token};
 use crate::utils::email::normalize_email;
 use crate::utils::redis_cache::{delete_key, get_json, get_ttl_seconds, set_json_with_ttl};
+use constants::ActionLogAction;
+use entity::common::ActionResourceType;
 use errors::errors::{Errors, ServiceResult};
 use redis::aio::ConnectionManager;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::sync::LazyLock;
 use tracing::info;
 use uuid::Uuid;
@@ -188,6 +194,7 @@ async fn find_pending_email_signup_by_index(
 pub async fn service_verify_email(
     db: &DatabaseConnection,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     token: &str,
 ) -> ServiceResult<Uuid> {
     let token_key = constants::email_verification_key(&hash_token(token));
@@ -196,7 +203,7 @@ pub async fn service_verify_email(
         .await?
         .ok_or(Errors::TokenInvalidVerification)?;
 
-    let user_id = complete_pending_email_signup(db, signup_data.clone()).await?;
+    let user_id = complete_pending_email_signup(db, nats_client, signup_data.clone()).await?;
 
     // DB commit succeeded — now clean up Redis (best-effort).
     delete_key(redis_conn, &token_key).await.ok();
@@ -209,6 +216,7 @@ pub async fn service_verify_email(
 
 async fn complete_pending_email_signup(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     signup_data: PendingEmailSignupData,
 ) -> ServiceResult<Uuid> {
     let txn = db.begin().await?;
@@ -236,7 +244,19 @@ async fn complete_pending_email_signup(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::UserCreate,
+        Some(user.id),
+        ActionResourceType::User,
+        Some(user.id),
+        format!("Created account @{}", user.handle),
+        Some(json!({ "method": "email" })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     info!(user_id = %user.id, handle = %user.handle, "Pending signup completed");
 
@@ -0,0 +1,51 @@
+use crate::bridge::worker_client;
+use crate::state::WorkerClient;
+use job_queue::jobs::notification::BoardCommentFanOutJob;
+use tracing::warn;
+use uuid::Uuid;
+
+pub fn spawn_index_post(worker: &WorkerClient, post_id: Uuid) {
+    let worker = worker.clone();
+    tokio::spawn(async move {
+        if let Err(e) = worker_client::index_post(&worker, post_id).await {
+            warn!(post_id = %post_id, error = ?e, "Failed to queue post index job");
+        }
+    });
+}
+
+pub fn spawn_delete_post_from_index(worker: &WorkerClient, post_id: Uuid) {
+    let worker = worker.clone();
+    tokio::spawn(async move {
+        if let Err(e) = worker_client::delete_post_from_index(&worker, post_id).await {
+            warn!(post_id = %post_id, error = ?e, "Failed to queue post index delete job");
+        }
+    });
+}
+
+pub fn spawn_index_comment(worker: &WorkerClient, comment_id: Uuid) {
+    let worker = worker.clone();
+    tokio::spawn(async move {
+        if let Err(e) = worker_client::index_comment(&worker, comment_id).await {
+            warn!(comment_id = %comment_id, error = ?e, "Failed to queue comment index job");
+        }
+    });
+}
+
+pub fn spawn_delete_comment_from_index(worker: &WorkerClient, comment_id: Uuid) {
+    let worker = worker.clone();
+    tokio::spawn(async move {
+        if let Err(e) = worker_client::delete_comment_from_index(&worker, comment_id).await {
+            warn!(comment_id = %comment_id, error = ?e, "Failed to queue comment index delete job");
+        }
+    });
+}
+
+pub fn spawn_board_comment_fanout(worker: &WorkerClient, job: BoardCommentFanOutJob) {
+    let worker = worker.clone();
+    tokio::spawn(async move {
+        let comment_id = job.comment_id;
+        if let Err(e) = worker_client::fan_out_board_comment(&worker, job).await {
+            warn!(comment_id = %comment_id, error = ?e, "Failed to queue comment fan-out job");
+        }
+    });
+}
@@ -1,15 +1,22 @@
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::board::repository_create_board;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
 use dto::board::{CreateBoardRequest, CreateBoardResponse};
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 
 pub async fn service_create_board(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     payload: CreateBoardRequest,
     session: &SessionContext,
 ) -> ServiceResult<CreateBoardResponse> {
@@ -27,7 +34,19 @@ pub async fn service_create_board(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::BoardCreate,
+        Some(session.user_id),
+        ActionResourceType::Board,
+        Some(board.id),
+        format!("Created board \"{}\"", board.name),
+        Some(json!({ "slug": board.slug })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     info!(board_id = %board.id, "Board created");
 
@@ -1,9 +1,17 @@
+use crate::connection::R2RevisionClient;
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::board::repository_delete_board;
+use crate::repository::board::revisions::repository_find_board_revision_keys_by_board;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::revisions::delete_revision_bodies;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
 use dto::board::DeleteBoardResponse;
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use tracing::info;
@@ -11,6 +19,8 @@ use uuid::Uuid;
 
 pub async fn service_delete_board(
     db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    nats_client: &NatsClient,
     board_id: Uuid,
     session: &SessionContext,
 ) -> ServiceResult<DeleteBoardResponse> {
@@ -18,8 +28,24 @@ pub async fn service_delete_board(
     BoardPermission::ManageBoard.check(&ctx)?;
 
     let txn = db.begin().await?;
+    // Revision rows cascade with the board's posts; their bodies must be removed by hand.
+    let revision_keys = repository_find_board_revision_keys_by_board(&txn, board_id).await?;
     repository_delete_board(&txn, board_id).await?;
+
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::BoardDelete,
+        Some(session.user_id),
+        ActionResourceType::Board,
+        Some(board_id),
+        "Deleted board".to_string(),
+        None,
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
+    delete_revision_bodies(r2_revisions, &revision_keys).await;
 
     info!(board_id = %board_id, "Board deleted");
 
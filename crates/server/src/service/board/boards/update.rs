@@ -1,15 +1,22 @@
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::board::repository_update_board;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
 use dto::board::{UpdateBoardRequest, UpdateBoardResponse};
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 
 pub async fn service_update_board(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     payload: UpdateBoardRequest,
     session: &SessionContext,
 ) -> ServiceResult<UpdateBoardResponse> {
@@ -29,7 +36,19 @@ pub async fn service_update_board(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::BoardEdit,
+        Some(session.user_id),
+        ActionResourceType::Board,
+        Some(board.id),
+        format!("Edited board \"{}\"", board.name),
+        Some(json!({ "slug": board.slug })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     info!(board_id = %board.id, "Board updated");
 
@@ -1,7 +1,7 @@
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
-use crate::repository::actors::repository_find_actor_by_id;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::actors::repository_find_or_create_user_actor;
 use crate::repository::board::comments::{
     repository_create_board_comment, repository_get_board_comment_by_id,
@@ -12,13 +12,19 @@ use crate::repository::board::posts::{
 };
 use crate::repository::board::repository_get_board_by_id;
 use crate::repository::notification::NotificationTarget;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::{spawn_board_comment_fanout, spawn_index_comment};
 use crate::service::board::facts::load_board_facts;
-use crate::service::notification::{notify_mentions, service_notify_user};
+use crate::service::notification::notify_mentions;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::mentions::resolve_mentions;
 use crate::utils::session_helper::parse_attribution_ip;
+use constants::ActionLogAction;
 use dto::board::{CreateBoardCommentRequest, CreateBoardCommentResponse};
+use entity::common::ActionResourceType;
 use errors::errors::{Errors, ServiceResult};
+use job_queue::jobs::notification::BoardCommentFanOutJob;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde_json::json;
 use tracing::info;
@@ -26,6 +32,8 @@ use uuid::Uuid;
 
 pub async fn service_create_board_comment(
     db: &DatabaseConnection,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     payload: CreateBoardCommentRequest,
     session: &SessionContext,
     ip_address: &str,
@@ -74,12 +82,31 @@ pub async fn service_create_board_comment(
         repository_increment_comment_reply_count(&txn, root_id).await?;
     }
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::CommentCreate,
+        Some(session.user_id),
+        ActionResourceType::Comment,
+        Some(comment.id),
+        format!("Commented on post \"{}\"", post.title),
+        Some(json!({
+            "board_id": post.board_id,
+            "post_id": post.id,
+            "parent_comment_id": resolved_parent_id,
+        })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
+    spawn_index_comment(worker, comment.id);
 
     // Create mention notifications (best-effort). Deep-link targets the comment.
     let actor_ip = Some(parse_attribution_ip(ip_address)?);
     notify_mentions(
         db,
+        worker,
+        nats_client,
         mentioned_user_ids.clone(),
         Some(session.user_id),
         actor.id,
@@ -97,36 +124,28 @@ pub async fn service_create_board_comment(
     )
     .await;
 
-    // Alert the post author about the new comment (best-effort; skips
-    // self-comments and respects the author's action preference).
-    if let Some(post_author) = repository_find_actor_by_id(db, post.actor_id)
-        .await
-        .ok()
-        .flatten()
-        .and_then(|actor| actor.user_id)
-        && post_author != session.user_id
-        && !mentioned_user_ids.contains(&post_author)
-    {
-        let _ = service_notify_user(
-            db,
-            post_author,
-            Some(actor.id),
-            actor_ip,
-            entity::common::NotificationType::Board,
-            constants::NotificationAction::BoardCommentCreated,
-            NotificationTarget::BoardComment {
-                board_id: post.board_id,
-                post_id: post.id,
-                comment_id: comment.id,
-            },
-            json!({
+    // Everyone watching the post — the author by default — hears about the new
+    // comment through the worker fan-out, so this request does one publish instead
+    // of an insert per watcher. The commenter and the users just mentioned are
+    // skipped there; the mention already reached them.
+    let mut skip_user_ids = mentioned_user_ids;
+    skip_user_ids.push(session.user_id);
+    spawn_board_comment_fanout(
+        worker,
+        BoardCommentFanOutJob {
+            board_id: post.board_id,
+            post_id: post.id,
+            comment_id: comment.id,
+            actor_id: actor.id,
+            actor_ip: actor_ip.map(|ip| ip.ip()),
+            skip_user_ids,
+            additional_data: json!({
                 "board_name": board.name,
                 "board_slug": board.slug,
                 "post_title": post.title,
             }),
-        )
-        .await;
-    }
+        },
+    );
 
     info!(comment_id = %comment.id, post_id = %payload.post_id, "Board comment created");
 
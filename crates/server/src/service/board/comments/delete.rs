@@ -1,21 +1,34 @@
+use crate::connection::R2RevisionClient;
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::actors::repository_find_actors_by_ids;
 use crate::repository::board::comments::{
     repository_decrement_comment_reply_count, repository_delete_board_comment,
     repository_get_board_comment_by_id, repository_get_board_comment_by_id_for_update,
 };
 use crate::repository::board::posts::repository_decrement_post_comment_count;
+use crate::repository::board::revisions::repository_find_board_revision_keys_by_comment_thread;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_delete_comment_from_index;
+use crate::service::board::revisions::delete_revision_bodies;
+use crate::state::{NatsClient, WorkerClient};
+use constants::ActionLogAction;
 use dto::board::DeleteBoardCommentResponse;
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 use uuid::Uuid;
 
 pub async fn service_delete_board_comment(
     db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     comment_id: Uuid,
     session: &SessionContext,
 ) -> ServiceResult<DeleteBoardCommentResponse> {
@@ -57,8 +70,27 @@ pub async fn service_delete_board_comment(
         }
     }
 
+    // Revision rows of the comment and its replies cascade too; their bodies must be
+    // removed by hand after commit.
+    let revision_keys =
+        repository_find_board_revision_keys_by_comment_thread(&txn, comment_id).await?;
     repository_delete_board_comment(&txn, comment_id).await?;
+
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::CommentDelete,
+        Some(session.user_id),
+        ActionResourceType::Comment,
+        Some(comment_id),
+        "Deleted comment".to_string(),
+        Some(json!({ "post_id": locked.post_id })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
+    spawn_delete_comment_from_index(worker, comment_id);
+    delete_revision_bodies(r2_revisions, &revision_keys).await;
 
     info!(comment_id = %comment_id, "Board comment deleted");
 
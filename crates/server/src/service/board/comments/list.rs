@@ -12,6 +12,7 @@ use crate::service::actors::actor_response_map;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::board::facts::load_board_facts;
 use crate::service::board::mapper::{build_comment_response, resolve_viewer_actor_id};
+use crate::service::board::reactions::viewer_comment_reactions;
 use crate::service::cursor_pagination::{cursor_flags, reverse_if_older};
 use dto::board::{BoardCommentListResponse, BoardCommentResponse, GetBoardCommentsRequest};
 use dto::pagination::CursorDirection;
@@ -108,12 +109,22 @@ pub async fn service_list_board_comments(
         .collect();
     let authors = actor_response_map(db, &actor_ids).await?;
     let viewer_actor_id = resolve_viewer_actor_id(db, session).await?;
+    let comment_ids: Vec<_> = comments.iter().map(|comment| comment.id).collect();
+    let mut viewer_reactions = viewer_comment_reactions(db, viewer_actor_id, &comment_ids).await?;
 
     let data: Vec<BoardCommentResponse> = comments
         .into_iter()
         .map(|comment| {
             let author = authors.get(&comment.actor_id).cloned();
-            build_comment_response(&ctx, facts.clone(), comment, author, viewer_actor_id)
+            let reactions = viewer_reactions.remove(&comment.id).unwrap_or_default();
+            build_comment_response(
+                &ctx,
+                facts.clone(),
+                comment,
+                author,
+                viewer_actor_id,
+                reactions,
+            )
         })
         .collect();
 
@@ -1,9 +1,13 @@
 mod create;
 mod delete;
 mod list;
+mod moderation;
+mod reactions;
 mod update;
 
 pub use create::service_create_board_comment;
 pub use delete::service_delete_board_comment;
 pub use list::service_list_board_comments;
+pub use moderation::{service_hide_board_comment, service_unhide_board_comment};
+pub use reactions::{service_react_board_comment, service_unreact_board_comment};
 pub use update::service_update_board_comment;
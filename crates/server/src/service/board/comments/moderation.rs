@@ -0,0 +1,114 @@
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::actors::repository_find_or_create_user_actor;
+use crate::repository::board::comments::{
+    repository_get_board_comment_by_id, repository_set_board_comment_hidden,
+};
+use crate::repository::board::posts::repository_get_board_post_by_id;
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_index_comment;
+use crate::state::WorkerClient;
+use constants::ModerationAction;
+use dto::board::{BoardCommentModerationRequest, BoardCommentModerationResponse};
+use entity::common::ModerationResourceType;
+use errors::errors::ServiceResult;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// The board comment moderation operations, all gated by `BoardPermission::Moderate`.
+#[derive(Clone, Copy)]
+enum BoardCommentOp {
+    Hide,
+    Unhide,
+}
+
+impl BoardCommentOp {
+    fn action(self) -> ModerationAction {
+        match self {
+            BoardCommentOp::Hide => ModerationAction::BoardHideComment,
+            BoardCommentOp::Unhide => ModerationAction::BoardUnhideComment,
+        }
+    }
+}
+
+/// Shared implementation: gate on board moderation, flip the hidden state, log the action.
+async fn moderate_board_comment(
+    db: &DatabaseConnection,
+    op: BoardCommentOp,
+    payload: BoardCommentModerationRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardCommentModerationResponse> {
+    let comment = repository_get_board_comment_by_id(db, payload.comment_id).await?;
+    let post = repository_get_board_post_by_id(db, comment.post_id).await?;
+
+    let ctx = PermissionService::get_context(db, Some(session)).await?;
+    BoardPermission::Moderate.check(&ctx)?;
+
+    let txn = db.begin().await?;
+
+    let updated = match op {
+        // The reason doubles as the hidden reason shown to other moderators.
+        BoardCommentOp::Hide => {
+            let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+            repository_set_board_comment_hidden(
+                &txn,
+                payload.comment_id,
+                Some(actor.id),
+                Some(payload.reason.clone()),
+            )
+            .await?
+        }
+        BoardCommentOp::Unhide => {
+            repository_set_board_comment_hidden(&txn, payload.comment_id, None, None).await?
+        }
+    };
+
+    repository_create_moderation_log(
+        &txn,
+        op.action(),
+        Some(session.user_id),
+        ModerationResourceType::BoardComment,
+        Some(payload.comment_id),
+        payload.reason,
+        Some(json!({ "board_id": post.board_id, "post_id": post.id })),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(comment_id = %updated.id, action = %op.action(), "Board comment moderated");
+
+    Ok(BoardCommentModerationResponse {
+        comment_id: updated.id,
+        is_hidden: updated.hidden_at.is_some(),
+    })
+}
+
+/// Soft-hide a comment: the row and its replies stay in place, but
+/// non-moderators are served a tombstone until it is unhidden.
+pub async fn service_hide_board_comment(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    payload: BoardCommentModerationRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardCommentModerationResponse> {
+    let response = moderate_board_comment(db, BoardCommentOp::Hide, payload, session).await?;
+    // The index carries the hidden flag, so search drops (or restores) the hit.
+    spawn_index_comment(worker, response.comment_id);
+    Ok(response)
+}
+
+pub async fn service_unhide_board_comment(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    payload: BoardCommentModerationRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardCommentModerationResponse> {
+    let response = moderate_board_comment(db, BoardCommentOp::Unhide, payload, session).await?;
+    // The index carries the hidden flag, so search drops (or restores) the hit.
+    spawn_index_comment(worker, response.comment_id);
+    Ok(response)
+}
@@ -0,0 +1,108 @@
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::actors::repository_find_or_create_user_actor;
+use crate::repository::board::comments::{
+    repository_decrement_comment_reaction_count, repository_get_board_comment_by_id,
+    repository_increment_comment_reaction_count,
+};
+use crate::repository::board::posts::repository_get_board_post_by_id;
+use crate::repository::board::reactions::{
+    repository_create_board_reaction, repository_delete_board_reaction,
+};
+use crate::repository::board::repository_get_board_by_id;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::facts::load_board_facts;
+use crate::service::board::mapper::is_tombstoned;
+use crate::service::board::reactions::{
+    ensure_reaction_kind, reaction_counts_map, viewer_comment_reactions,
+};
+use dto::board::{BoardCommentReactionRequest, BoardCommentReactionResponse};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+
+/// Adds the caller's reaction of `kind` to a comment. Idempotent: reacting twice
+/// with the same kind leaves the counters untouched.
+pub async fn service_react_board_comment(
+    db: &DatabaseConnection,
+    payload: BoardCommentReactionRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardCommentReactionResponse> {
+    ensure_reaction_kind(&payload.kind)?;
+    set_board_comment_reaction(db, payload, session, true).await
+}
+
+/// Removes the caller's reaction of `kind` from a comment. Removing a reaction
+/// the caller never added is a no-op.
+pub async fn service_unreact_board_comment(
+    db: &DatabaseConnection,
+    payload: BoardCommentReactionRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardCommentReactionResponse> {
+    set_board_comment_reaction(db, payload, session, false).await
+}
+
+/// Shared implementation, mirroring the post reaction path.
+async fn set_board_comment_reaction(
+    db: &DatabaseConnection,
+    payload: BoardCommentReactionRequest,
+    session: &SessionContext,
+    react: bool,
+) -> ServiceResult<BoardCommentReactionResponse> {
+    let comment = repository_get_board_comment_by_id(db, payload.comment_id).await?;
+    let post = repository_get_board_post_by_id(db, comment.post_id).await?;
+    let board = repository_get_board_by_id(db, post.board_id).await?;
+
+    let ctx = PermissionService::get_context(db, Some(session)).await?;
+    let facts = load_board_facts(db, &board).await?;
+    BoardPermission::Write(facts).check(&ctx)?;
+
+    if react && is_tombstoned(&ctx, comment.hidden_at) {
+        return Err(Errors::BoardCommentNotFound);
+    }
+
+    let txn = db.begin().await?;
+    let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+
+    if react {
+        if repository_create_board_reaction(
+            &txn,
+            actor.id,
+            post.id,
+            Some(comment.id),
+            &payload.kind,
+        )
+        .await?
+        {
+            repository_increment_comment_reaction_count(&txn, comment.id, &payload.kind).await?;
+        }
+    } else if repository_delete_board_reaction(
+        &txn,
+        actor.id,
+        post.id,
+        Some(comment.id),
+        &payload.kind,
+    )
+    .await?
+    {
+        repository_decrement_comment_reaction_count(&txn, comment.id, &payload.kind).await?;
+    }
+
+    txn.commit().await?;
+
+    let comment = repository_get_board_comment_by_id(db, comment.id).await?;
+    let viewer_reactions = viewer_comment_reactions(db, Some(actor.id), &[comment.id])
+        .await?
+        .remove(&comment.id)
+        .unwrap_or_default();
+
+    info!(comment_id = %comment.id, kind = %payload.kind, react, "Board comment reaction updated");
+
+    Ok(BoardCommentReactionResponse {
+        comment_id: comment.id,
+        reaction_count: comment.reaction_count,
+        reaction_counts: reaction_counts_map(&comment.reaction_counts),
+        viewer_reactions,
+    })
+}
@@ -1,22 +1,32 @@
+use crate::connection::R2RevisionClient;
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::actors::{
     repository_find_actors_by_ids, repository_find_or_create_user_actor,
 };
 use crate::repository::board::comments::{
-    repository_get_board_comment_by_id, repository_update_board_comment,
+    repository_get_board_comment_by_id, repository_get_board_comment_by_id_for_update,
+    repository_update_board_comment,
 };
 use crate::repository::board::posts::repository_get_board_post_by_id;
 use crate::repository::board::repository_get_board_by_id;
+use crate::repository::board::revisions::repository_create_board_revision;
 use crate::repository::notification::NotificationTarget;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_index_comment;
 use crate::service::board::facts::load_board_facts;
+use crate::service::board::revisions::{discard_revision_body, store_revision_body};
 use crate::service::notification::notify_mentions;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::mentions::resolve_mentions;
 use crate::utils::session_helper::parse_attribution_ip;
+use constants::ActionLogAction;
 use dto::board::{UpdateBoardCommentRequest, UpdateBoardCommentResponse};
-use errors::errors::ServiceResult;
+use entity::common::ActionResourceType;
+use errors::errors::{Errors, ServiceResult};
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde_json::json;
 use std::collections::HashSet;
@@ -25,6 +35,9 @@ use uuid::Uuid;
 
 pub async fn service_update_board_comment(
     db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     payload: UpdateBoardCommentRequest,
     session: &SessionContext,
     ip_address: &str,
@@ -43,6 +56,12 @@ pub async fn service_update_board_comment(
     let facts = load_board_facts(db, &board).await?;
     BoardPermission::EditContent { is_owner, facts }.check(&ctx)?;
 
+    let txn = db.begin().await?;
+    // Re-read under a row lock: a concurrent edit may have landed since the
+    // permission check, and the revision must capture the body this edit
+    // actually replaces.
+    let comment = repository_get_board_comment_by_id_for_update(&txn, payload.comment_id).await?;
+
     // Notify only users newly mentioned by this edit — mentions present in the
     // new content but not the previous one — so re-saving a comment does not
     // spam every already-mentioned user again.
@@ -56,18 +75,65 @@ pub async fn service_update_board_comment(
         .collect();
     let mentioned_user_ids: Vec<Uuid> = new_mentions.difference(&old_mentions).copied().collect();
 
-    let txn = db.begin().await?;
-    let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+    // Snapshot the body this edit replaces, unless the edit is a no-op re-save.
+    let superseded = if payload.content != comment.content {
+        Some(store_revision_body(r2_revisions, comment.post_id, &comment.content).await?)
+    } else {
+        None
+    };
+    let superseded_key = superseded.as_ref().map(|s| s.storage_key.clone());
+
+    let result: Result<_, Errors> = async {
+        let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
 
-    let updated =
-        repository_update_board_comment(&txn, payload.comment_id, payload.content).await?;
+        if let Some(superseded) = superseded {
+            repository_create_board_revision(
+                &txn,
+                superseded.id,
+                comment.post_id,
+                Some(comment.id),
+                None,
+                superseded.storage_key,
+                superseded.content_length,
+                actor.id,
+            )
+            .await?;
+        }
 
-    txn.commit().await?;
+        let updated =
+            repository_update_board_comment(&txn, payload.comment_id, payload.content).await?;
+
+        let action_log = repository_create_action_log(
+            &txn,
+            ActionLogAction::CommentEdit,
+            Some(session.user_id),
+            ActionResourceType::Comment,
+            Some(updated.id),
+            format!("Edited comment on post \"{}\"", post.title),
+            Some(json!({ "board_id": post.board_id, "post_id": post.id })),
+        )
+        .await?;
+
+        txn.commit().await?;
+        Ok((actor, updated, action_log))
+    }
+    .await;
+    let (actor, updated, action_log) = match result {
+        Ok(committed) => committed,
+        Err(e) => {
+            discard_revision_body(r2_revisions, superseded_key).await;
+            return Err(e);
+        }
+    };
+    publish_action_log(nats_client, &action_log).await;
+    spawn_index_comment(worker, updated.id);
 
     // Notify only newly-mentioned users (best-effort). Deep-link targets the comment.
     let actor_ip = Some(parse_attribution_ip(ip_address)?);
     notify_mentions(
         db,
+        worker,
+        nats_client,
         mentioned_user_ids,
         Some(session.user_id),
         actor.id,
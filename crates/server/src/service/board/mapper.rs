@@ -4,9 +4,14 @@ use crate::permission::rule::Rule;
 use crate::repository::actors::repository_find_actor_by_user_id;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::board::permissions::build_board_permissions_response;
+use crate::service::board::reactions::reaction_counts_map;
+use chrono::{DateTime, Utc};
 use dto::actor::ActorResponse;
-use dto::board::{BoardCommentResponse, BoardPostResponse, BoardResponse};
+use dto::board::{
+    BoardCommentResponse, BoardPostResponse, BoardResponse, BoardRevisionSummaryResponse,
+};
 use entity::board_comments::Model as BoardCommentModel;
+use entity::board_post_revisions::Model as BoardPostRevisionModel;
 use entity::board_posts::Model as BoardPostModel;
 use entity::boards::Model as BoardModel;
 use errors::errors::ServiceResult;
@@ -35,6 +40,12 @@ where
         .map(|actor| actor.id))
 }
 
+/// Whether hidden content must be served to the caller as a tombstone: the row
+/// is hidden and the caller cannot moderate (moderators always see it in full).
+pub fn is_tombstoned(ctx: &UserContext, hidden_at: Option<DateTime<Utc>>) -> bool {
+    hidden_at.is_some() && !BoardPermission::Moderate.is_allowed(ctx)
+}
+
 /// Maps a board entity to its response, stamping the caller's capability flags.
 ///
 /// Board-level flags are computed by [`build_board_permissions_response`] so the
@@ -63,32 +74,50 @@ pub fn build_board_response(
 
 /// Maps a board post entity to its response, stamping the caller's capability flags.
 ///
-/// `facts` are the parent board's facts (needed for the author edit path) and
-/// `viewer_actor_id` is the caller's own actor id from [`resolve_viewer_actor_id`].
+/// `facts` are the parent board's facts (needed for the author edit path),
+/// `viewer_actor_id` is the caller's own actor id from [`resolve_viewer_actor_id`]
+/// and `viewer_reactions` the kinds that actor has reacted with.
 pub fn build_post_response(
     ctx: &UserContext,
     facts: BoardFacts,
     post: BoardPostModel,
     author: Option<ActorResponse>,
     viewer_actor_id: Option<Uuid>,
+    viewer_reactions: Vec<String>,
 ) -> BoardPostResponse {
     let is_owner = viewer_actor_id == Some(post.actor_id);
+    let tombstoned = is_tombstoned(ctx, post.hidden_at);
 
     BoardPostResponse {
         id: post.id,
         board_id: post.board_id,
         author_actor_id: post.actor_id,
         author,
-        title: post.title,
-        content: post.content,
+        title: if tombstoned {
+            String::new()
+        } else {
+            post.title
+        },
+        content: if tombstoned {
+            String::new()
+        } else {
+            post.content
+        },
+        rendered_content: None,
         is_pinned: post.pinned_position.is_some(),
         is_locked: post.is_locked,
         view_count: post.view_count,
         comment_count: post.comment_count,
+        reaction_count: post.reaction_count,
+        reaction_counts: reaction_counts_map(&post.reaction_counts),
+        viewer_reactions,
         can_edit: BoardPermission::EditContent { is_owner, facts }.is_allowed(ctx),
         can_delete: BoardPermission::DeleteContent { is_owner }.is_allowed(ctx),
         created_at: post.created_at,
         edited_at: post.edited_at,
+        is_hidden: post.hidden_at.is_some(),
+        hidden_at: post.hidden_at,
+        hidden_reason: if tombstoned { None } else { post.hidden_reason },
     }
 }
 
@@ -99,8 +128,10 @@ pub fn build_comment_response(
     comment: BoardCommentModel,
     author: Option<ActorResponse>,
     viewer_actor_id: Option<Uuid>,
+    viewer_reactions: Vec<String>,
 ) -> BoardCommentResponse {
     let is_owner = viewer_actor_id == Some(comment.actor_id);
+    let tombstoned = is_tombstoned(ctx, comment.hidden_at);
 
     BoardCommentResponse {
         id: comment.id,
@@ -108,11 +139,42 @@ pub fn build_comment_response(
         parent_comment_id: comment.parent_comment_id,
         author_actor_id: comment.actor_id,
         author,
-        content: comment.content,
+        content: if tombstoned {
+            String::new()
+        } else {
+            comment.content
+        },
         reply_count: comment.reply_count,
+        reaction_count: comment.reaction_count,
+        reaction_counts: reaction_counts_map(&comment.reaction_counts),
+        viewer_reactions,
         can_edit: BoardPermission::EditContent { is_owner, facts }.is_allowed(ctx),
         can_delete: BoardPermission::DeleteContent { is_owner }.is_allowed(ctx),
         created_at: comment.created_at,
         edited_at: comment.edited_at,
+        is_hidden: comment.hidden_at.is_some(),
+        hidden_at: comment.hidden_at,
+        hidden_reason: if tombstoned {
+            None
+        } else {
+            comment.hidden_reason
+        },
+    }
+}
+
+/// Maps a revision index row to its summary response.
+pub fn build_revision_summary_response(
+    revision: BoardPostRevisionModel,
+    actor: Option<ActorResponse>,
+) -> BoardRevisionSummaryResponse {
+    BoardRevisionSummaryResponse {
+        id: revision.id,
+        post_id: revision.post_id,
+        comment_id: revision.comment_id,
+        title: revision.title,
+        content_length: revision.content_length,
+        actor_id: revision.actor_id,
+        actor,
+        created_at: revision.created_at,
     }
 }
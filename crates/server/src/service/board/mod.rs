@@ -1,23 +1,35 @@
+mod background_jobs;
 mod boards;
 mod comments;
 mod facts;
 mod mapper;
 mod permissions;
 mod posts;
+mod reactions;
+mod rendering;
+mod revisions;
 
 pub use boards::{
     service_create_board, service_delete_board, service_get_board, service_get_board_by_slug,
     service_list_boards, service_update_board,
 };
 pub use comments::{
-    service_create_board_comment, service_delete_board_comment, service_list_board_comments,
-    service_update_board_comment,
+    service_create_board_comment, service_delete_board_comment, service_hide_board_comment,
+    service_list_board_comments, service_react_board_comment, service_unhide_board_comment,
+    service_unreact_board_comment, service_update_board_comment,
 };
-pub use facts::load_board_facts;
+pub use facts::{load_board_facts, load_board_facts_batch};
 pub use permissions::service_get_board_permissions;
 pub use posts::{
     service_create_board_post, service_delete_board_post, service_get_board_post,
-    service_list_board_posts, service_lock_board_post, service_pin_board_post,
-    service_reorder_board_pins, service_unlock_board_post, service_unpin_board_post,
-    service_update_board_post,
+    service_hide_board_post, service_list_board_posts, service_lock_board_post,
+    service_pin_board_post, service_react_board_post, service_reorder_board_pins,
+    service_unhide_board_post, service_unlock_board_post, service_unpin_board_post,
+    service_unreact_board_post, service_unwatch_board_post, service_update_board_post,
+    service_watch_board_post,
+};
+pub use rendering::service_parse_board;
+pub use revisions::{
+    service_diff_board_revisions, service_get_board_revision, service_list_board_comment_revisions,
+    service_list_board_post_revisions, service_restore_board_revision,
 };
@@ -1,16 +1,23 @@
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::actors::repository_find_or_create_user_actor;
 use crate::repository::board::posts::repository_create_board_post;
 use crate::repository::board::repository_get_board_by_id;
+use crate::repository::board::watchers::repository_create_board_post_watcher;
 use crate::repository::notification::NotificationTarget;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_index_post;
 use crate::service::board::facts::load_board_facts;
 use crate::service::notification::notify_mentions;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::mentions::resolve_mentions;
 use crate::utils::session_helper::parse_attribution_ip;
+use constants::ActionLogAction;
 use dto::board::{CreateBoardPostRequest, CreateBoardPostResponse};
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde_json::json;
@@ -19,6 +26,8 @@ use uuid::Uuid;
 
 pub async fn service_create_board_post(
     db: &DatabaseConnection,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     payload: CreateBoardPostRequest,
     session: &SessionContext,
     ip_address: &str,
@@ -44,12 +53,30 @@ pub async fn service_create_board_post(
     )
     .await?;
 
+    // Authors follow their own thread from the start; they can unwatch it later.
+    repository_create_board_post_watcher(&txn, post.id, session.user_id).await?;
+
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::PostCreate,
+        Some(session.user_id),
+        ActionResourceType::Post,
+        Some(post.id),
+        format!("Created post \"{}\"", post.title),
+        Some(json!({ "board_id": payload.board_id })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
+    spawn_index_post(worker, post.id);
 
     // Create mention notifications (best-effort)
     let actor_ip = Some(parse_attribution_ip(ip_address)?);
     notify_mentions(
         db,
+        worker,
+        nats_client,
         mentioned_user_ids,
         Some(session.user_id),
         actor.id,
@@ -1,20 +1,33 @@
+use crate::connection::R2RevisionClient;
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::actors::repository_find_actors_by_ids;
 use crate::repository::board::posts::{
     repository_delete_board_post, repository_get_board_post_by_id,
     repository_get_board_post_by_id_for_update,
 };
+use crate::repository::board::revisions::repository_find_board_revision_keys_by_post;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_delete_post_from_index;
+use crate::service::board::revisions::delete_revision_bodies;
+use crate::state::{NatsClient, WorkerClient};
+use constants::ActionLogAction;
 use dto::board::DeleteBoardPostResponse;
+use entity::common::ActionResourceType;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 use uuid::Uuid;
 
 pub async fn service_delete_board_post(
     db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     post_id: Uuid,
     session: &SessionContext,
 ) -> ServiceResult<DeleteBoardPostResponse> {
@@ -34,8 +47,25 @@ pub async fn service_delete_board_post(
     // loser re-reads the now-deleted row and returns NotFound. Comments cascade away
     // via the FK, so no counter maintenance is needed here.
     repository_get_board_post_by_id_for_update(&txn, post_id).await?;
+    // Revision rows cascade too; their bodies must be removed by hand after commit.
+    let revision_keys = repository_find_board_revision_keys_by_post(&txn, post_id).await?;
     repository_delete_board_post(&txn, post_id).await?;
+
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::PostDelete,
+        Some(session.user_id),
+        ActionResourceType::Post,
+        Some(post_id),
+        format!("Deleted post \"{}\"", post.title),
+        Some(json!({ "board_id": post.board_id })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
+    spawn_delete_post_from_index(worker, post_id);
+    delete_revision_bodies(r2_revisions, &revision_keys).await;
 
     info!(post_id = %post_id, "Board post deleted");
 
@@ -6,7 +6,9 @@ use crate::repository::board::repository_get_board_by_id;
 use crate::service::actors::actor_response_by_id;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::board::facts::load_board_facts;
-use crate::service::board::mapper::{build_post_response, resolve_viewer_actor_id};
+use crate::service::board::mapper::{build_post_response, is_tombstoned, resolve_viewer_actor_id};
+use crate::service::board::reactions::viewer_post_reactions;
+use crate::service::board::rendering::rendered_post_content;
 use dto::board::BoardPostResponse;
 use errors::errors::ServiceResult;
 use redis::aio::ConnectionManager as RedisClient;
@@ -34,14 +36,21 @@ pub async fn service_get_board_post(
 
     let author = actor_response_by_id(db, post.actor_id).await?;
     let viewer_actor_id = resolve_viewer_actor_id(db, session).await?;
+    let viewer_reactions = viewer_post_reactions(db, viewer_actor_id, &[post.id])
+        .await?
+        .remove(&post.id)
+        .unwrap_or_default();
+    // A tombstone has no body to render.
+    let rendered_content = if is_tombstoned(&ctx, post.hidden_at) {
+        None
+    } else {
+        Some(rendered_post_content(db, redis_cache, &post).await?)
+    };
 
-    Ok(build_post_response(
-        &ctx,
-        facts,
-        post,
-        author,
-        viewer_actor_id,
-    ))
+    let mut response =
+        build_post_response(&ctx, facts, post, author, viewer_actor_id, viewer_reactions);
+    response.rendered_content = rendered_content;
+    Ok(response)
 }
 
 /// Record a single view for `post_id`, deduplicated per viewer over a fixed
@@ -2,16 +2,25 @@ use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
 use crate::repository::board::posts::{
+    BoardPostCursor, repository_exists_newer_board_post, repository_exists_older_board_post,
     repository_find_board_posts, repository_find_pinned_board_posts,
+    repository_get_board_post_by_id,
 };
 use crate::repository::board::repository_get_board_by_id;
 use crate::service::actors::actor_response_map;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::board::facts::load_board_facts;
 use crate::service::board::mapper::{build_post_response, resolve_viewer_actor_id};
-use dto::board::{BoardPostListResponse, BoardPostResponse, GetBoardPostsRequest};
+use crate::service::board::reactions::viewer_post_reactions;
+use crate::service::cursor_pagination::{cursor_flags, reverse_if_newer};
+use chrono::{DateTime, Duration, Utc};
+use dto::board::{
+    BoardPostListResponse, BoardPostResponse, BoardPostSort, BoardPostTopWindow,
+    GetBoardPostsRequest,
+};
+use dto::pagination::CursorDirection;
 use entity::board_posts::Model as BoardPostModel;
-use errors::errors::ServiceResult;
+use errors::errors::{Errors, ServiceResult};
 use sea_orm::DatabaseConnection;
 use std::collections::HashSet;
 
@@ -26,23 +35,65 @@ pub async fn service_list_board_posts(
     let facts = load_board_facts(db, &board).await?;
     BoardPermission::View(facts.clone()).check(&ctx)?;
 
-    let page = payload.page;
-    let page_size = payload.page_size;
+    // Pins are read whole and served above every page; the paged query excludes
+    // them, so neither the cursor nor `has_newer`/`has_older` covers them.
+    let pinned = repository_find_pinned_board_posts(db, payload.board_id).await?;
+    let created_since = match payload.sort {
+        BoardPostSort::Top => top_window_start(payload.window),
+        _ => None,
+    };
+
+    // The cursor post must belong to this board; its current sort key is where
+    // the page resumes.
+    let cursor = match payload.cursor_id {
+        Some(cursor_id) => {
+            let cursor_post = repository_get_board_post_by_id(db, cursor_id).await?;
+            if cursor_post.board_id != payload.board_id {
+                return Err(Errors::BoardPostNotFound);
+            }
+            Some(BoardPostCursor::from(&cursor_post))
+        }
+        None => None,
+    };
+    let is_newer = cursor.is_some() && payload.cursor_direction == Some(CursorDirection::Newer);
 
-    let offset = (page as u64 - 1) * page_size as u64;
-    let limit = page_size as u64 + 1;
+    let mut posts = repository_find_board_posts(
+        db,
+        payload.board_id,
+        payload.sort,
+        created_since,
+        cursor,
+        payload.cursor_direction,
+        payload.limit,
+    )
+    .await?;
 
-    // Pins are read whole and served above every page, so they are paginated by
-    // neither `offset` nor `has_more` — the paged query excludes them entirely.
-    // Folding them into that one window instead is what used to strand them on
-    // page 1 and eat its slots.
-    let pinned = repository_find_pinned_board_posts(db, payload.board_id).await?;
-    let mut posts = repository_find_board_posts(db, payload.board_id, offset, limit).await?;
+    let (has_newer, has_older) = cursor_flags(
+        &posts,
+        is_newer,
+        |post| BoardPostCursor::from(post),
+        |cursor| {
+            repository_exists_newer_board_post(
+                db,
+                payload.board_id,
+                payload.sort,
+                created_since,
+                cursor,
+            )
+        },
+        |cursor| {
+            repository_exists_older_board_post(
+                db,
+                payload.board_id,
+                payload.sort,
+                created_since,
+                cursor,
+            )
+        },
+    )
+    .await?;
 
-    let has_more = posts.len() > page_size as usize;
-    if has_more {
-        posts.pop();
-    }
+    reverse_if_newer(&mut posts, is_newer);
 
     let actor_ids: Vec<_> = pinned
         .iter()
@@ -53,23 +104,48 @@ pub async fn service_list_board_posts(
         .collect();
     let authors = actor_response_map(db, &actor_ids).await?;
     let viewer_actor_id = resolve_viewer_actor_id(db, session).await?;
+    let post_ids: Vec<_> = pinned
+        .iter()
+        .chain(posts.iter())
+        .map(|post| post.id)
+        .collect();
+    let mut viewer_reactions = viewer_post_reactions(db, viewer_actor_id, &post_ids).await?;
 
     // The list view shows titles/metadata, not post bodies, so it does not render
     // or cache content (`rendered_content = None`); the detail read renders and
     // caches on demand.
-    let build = |post: BoardPostModel| {
+    let mut build = |post: BoardPostModel| {
         let author = authors.get(&post.actor_id).cloned();
-        build_post_response(&ctx, facts.clone(), post, author, viewer_actor_id)
+        let reactions = viewer_reactions.remove(&post.id).unwrap_or_default();
+        build_post_response(
+            &ctx,
+            facts.clone(),
+            post,
+            author,
+            viewer_actor_id,
+            reactions,
+        )
     };
 
-    let pinned_responses: Vec<BoardPostResponse> = pinned.into_iter().map(&build).collect();
-    let post_responses: Vec<BoardPostResponse> = posts.into_iter().map(&build).collect();
+    let pinned_responses: Vec<BoardPostResponse> = pinned.into_iter().map(&mut build).collect();
+    let post_responses: Vec<BoardPostResponse> = posts.into_iter().map(&mut build).collect();
 
     Ok(BoardPostListResponse {
         pinned: pinned_responses,
         posts: post_responses,
-        current_page: page,
-        page_size,
-        has_more,
+        has_newer,
+        has_older,
     })
 }
+
+/// Oldest `created_at` a `top` listing considers; `None` for the all-time window.
+fn top_window_start(window: BoardPostTopWindow) -> Option<DateTime<Utc>> {
+    let span = match window {
+        BoardPostTopWindow::Day => Duration::days(1),
+        BoardPostTopWindow::Week => Duration::weeks(1),
+        BoardPostTopWindow::Month => Duration::days(30),
+        BoardPostTopWindow::Year => Duration::days(365),
+        BoardPostTopWindow::All => return None,
+    };
+    Some(Utc::now() - span)
+}
@@ -3,16 +3,20 @@ mod delete;
 mod get;
 mod list;
 mod moderation;
+mod reactions;
 mod reorder_pins;
 mod update;
+mod watch;
 
 pub use create::service_create_board_post;
 pub use delete::service_delete_board_post;
 pub use get::service_get_board_post;
 pub use list::service_list_board_posts;
 pub use moderation::{
-    service_lock_board_post, service_pin_board_post, service_unlock_board_post,
-    service_unpin_board_post,
+    service_hide_board_post, service_lock_board_post, service_pin_board_post,
+    service_unhide_board_post, service_unlock_board_post, service_unpin_board_post,
 };
+pub use reactions::{service_react_board_post, service_unreact_board_post};
 pub use reorder_pins::service_reorder_board_pins;
 pub use update::service_update_board_post;
+pub use watch::{service_unwatch_board_post, service_watch_board_post};
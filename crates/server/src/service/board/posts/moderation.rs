@@ -1,12 +1,15 @@
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::actors::repository_find_or_create_user_actor;
 use crate::repository::board::posts::{
-    repository_get_board_post_by_id, repository_set_board_post_locked,
-    repository_set_board_post_pinned,
+    repository_get_board_post_by_id, repository_set_board_post_hidden,
+    repository_set_board_post_locked, repository_set_board_post_pinned,
 };
 use crate::repository::moderation::repository_create_moderation_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_index_post;
+use crate::state::WorkerClient;
 use constants::ModerationAction;
 use dto::board::{BoardPostModerationRequest, BoardPostModerationResponse};
 use entity::common::ModerationResourceType;
@@ -15,13 +18,15 @@ use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde_json::json;
 use tracing::info;
 
-/// The four board post moderation operations, all gated by `BoardPermission::Moderate`.
+/// The board post moderation operations, all gated by `BoardPermission::Moderate`.
 #[derive(Clone, Copy)]
 enum BoardPostOp {
     Pin,
     Unpin,
     Lock,
     Unlock,
+    Hide,
+    Unhide,
 }
 
 impl BoardPostOp {
@@ -31,6 +36,8 @@ impl BoardPostOp {
             BoardPostOp::Unpin => ModerationAction::BoardUnpin,
             BoardPostOp::Lock => ModerationAction::BoardLock,
             BoardPostOp::Unlock => ModerationAction::BoardUnlock,
+            BoardPostOp::Hide => ModerationAction::BoardHidePost,
+            BoardPostOp::Unhide => ModerationAction::BoardUnhidePost,
         }
     }
 }
@@ -60,6 +67,20 @@ async fn moderate_board_post(
         BoardPostOp::Unlock => {
             repository_set_board_post_locked(&txn, payload.post_id, false).await?
         }
+        // The reason doubles as the hidden reason shown to other moderators.
+        BoardPostOp::Hide => {
+            let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+            repository_set_board_post_hidden(
+                &txn,
+                payload.post_id,
+                Some(actor.id),
+                Some(payload.reason.clone()),
+            )
+            .await?
+        }
+        BoardPostOp::Unhide => {
+            repository_set_board_post_hidden(&txn, payload.post_id, None, None).await?
+        }
     };
 
     repository_create_moderation_log(
@@ -81,6 +102,7 @@ async fn moderate_board_post(
         post_id: updated.id,
         is_pinned: updated.pinned_position.is_some(),
         is_locked: updated.is_locked,
+        is_hidden: updated.hidden_at.is_some(),
     })
 }
 
@@ -115,3 +137,29 @@ pub async fn service_unlock_board_post(
 ) -> ServiceResult<BoardPostModerationResponse> {
     moderate_board_post(db, BoardPostOp::Unlock, payload, session).await
 }
+
+/// Soft-hide a post: the row, its comments and counters stay in place, but
+/// non-moderators are served a tombstone until it is unhidden.
+pub async fn service_hide_board_post(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    payload: BoardPostModerationRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardPostModerationResponse> {
+    let response = moderate_board_post(db, BoardPostOp::Hide, payload, session).await?;
+    // The index carries the hidden flag, so search drops (or restores) the hit.
+    spawn_index_post(worker, response.post_id);
+    Ok(response)
+}
+
+pub async fn service_unhide_board_post(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    payload: BoardPostModerationRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardPostModerationResponse> {
+    let response = moderate_board_post(db, BoardPostOp::Unhide, payload, session).await?;
+    // The index carries the hidden flag, so search drops (or restores) the hit.
+    spawn_index_post(worker, response.post_id);
+    Ok(response)
+}
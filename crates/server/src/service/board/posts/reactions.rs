@@ -0,0 +1,94 @@
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::actors::repository_find_or_create_user_actor;
+use crate::repository::board::posts::{
+    repository_decrement_post_reaction_count, repository_get_board_post_by_id,
+    repository_increment_post_reaction_count,
+};
+use crate::repository::board::reactions::{
+    repository_create_board_reaction, repository_delete_board_reaction,
+};
+use crate::repository::board::repository_get_board_by_id;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::facts::load_board_facts;
+use crate::service::board::mapper::is_tombstoned;
+use crate::service::board::reactions::{
+    ensure_reaction_kind, reaction_counts_map, viewer_post_reactions,
+};
+use dto::board::{BoardPostReactionRequest, BoardPostReactionResponse};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+
+/// Adds the caller's reaction of `kind` to a post. Idempotent: reacting twice with
+/// the same kind leaves the counters untouched.
+pub async fn service_react_board_post(
+    db: &DatabaseConnection,
+    payload: BoardPostReactionRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardPostReactionResponse> {
+    ensure_reaction_kind(&payload.kind)?;
+    set_board_post_reaction(db, payload, session, true).await
+}
+
+/// Removes the caller's reaction of `kind` from a post. Removing a reaction the
+/// caller never added is a no-op.
+pub async fn service_unreact_board_post(
+    db: &DatabaseConnection,
+    payload: BoardPostReactionRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardPostReactionResponse> {
+    set_board_post_reaction(db, payload, session, false).await
+}
+
+/// Shared implementation: reacting is participation, so it is held to the board's
+/// write bar (signed in, not banned). The counters move only when a row was
+/// actually inserted or deleted, in the same transaction as the row itself.
+async fn set_board_post_reaction(
+    db: &DatabaseConnection,
+    payload: BoardPostReactionRequest,
+    session: &SessionContext,
+    react: bool,
+) -> ServiceResult<BoardPostReactionResponse> {
+    let post = repository_get_board_post_by_id(db, payload.post_id).await?;
+    let board = repository_get_board_by_id(db, post.board_id).await?;
+
+    let ctx = PermissionService::get_context(db, Some(session)).await?;
+    let facts = load_board_facts(db, &board).await?;
+    BoardPermission::Write(facts).check(&ctx)?;
+
+    // A tombstone has nothing to react to; taking a reaction back stays allowed.
+    if react && is_tombstoned(&ctx, post.hidden_at) {
+        return Err(Errors::BoardPostNotFound);
+    }
+
+    let txn = db.begin().await?;
+    let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+
+    if react {
+        if repository_create_board_reaction(&txn, actor.id, post.id, None, &payload.kind).await? {
+            repository_increment_post_reaction_count(&txn, post.id, &payload.kind).await?;
+        }
+    } else if repository_delete_board_reaction(&txn, actor.id, post.id, None, &payload.kind).await?
+    {
+        repository_decrement_post_reaction_count(&txn, post.id, &payload.kind).await?;
+    }
+
+    txn.commit().await?;
+
+    let post = repository_get_board_post_by_id(db, post.id).await?;
+    let viewer_reactions = viewer_post_reactions(db, Some(actor.id), &[post.id])
+        .await?
+        .remove(&post.id)
+        .unwrap_or_default();
+
+    info!(post_id = %post.id, kind = %payload.kind, react, "Board post reaction updated");
+
+    Ok(BoardPostReactionResponse {
+        post_id: post.id,
+        reaction_count: post.reaction_count,
+        reaction_counts: reaction_counts_map(&post.reaction_counts),
+        viewer_reactions,
+    })
+}
@@ -1,21 +1,33 @@
+use crate::connection::R2RevisionClient;
 use crate::permission::PermissionService;
 use crate::permission::board::BoardPermission;
 use crate::permission::rule::Rule;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::actors::{
     repository_find_actors_by_ids, repository_find_or_create_user_actor,
 };
 use crate::repository::board::posts::{
-    repository_get_board_post_by_id, repository_update_board_post,
+    repository_get_board_post_by_id, repository_get_board_post_by_id_for_update,
+    repository_update_board_post,
 };
 use crate::repository::board::repository_get_board_by_id;
+use crate::repository::board::revisions::repository_create_board_revision;
 use crate::repository::notification::NotificationTarget;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::spawn_index_post;
 use crate::service::board::facts::load_board_facts;
+use crate::service::board::rendering::invalidate_rendered_post_content;
+use crate::service::board::revisions::{discard_revision_body, store_revision_body};
 use crate::service::notification::notify_mentions;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::mentions::resolve_mentions;
 use crate::utils::session_helper::parse_attribution_ip;
+use constants::ActionLogAction;
 use dto::board::{UpdateBoardPostRequest, UpdateBoardPostResponse};
-use errors::errors::ServiceResult;
+use entity::common::ActionResourceType;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use serde_json::json;
 use std::collections::HashSet;
@@ -24,6 +36,10 @@ use uuid::Uuid;
 
 pub async fn service_update_board_post(
     db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    redis_cache: &RedisClient,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     payload: UpdateBoardPostRequest,
     session: &SessionContext,
     ip_address: &str,
@@ -48,6 +64,12 @@ pub async fn service_update_board_post(
         BoardPermission::Moderate.check(&ctx)?;
     }
 
+    let txn = db.begin().await?;
+    // Re-read under a row lock: a concurrent edit may have landed since the
+    // permission check, and the revision must capture the body this edit
+    // actually replaces.
+    let post = repository_get_board_post_by_id_for_update(&txn, payload.post_id).await?;
+
     // Notify only users newly mentioned by this edit — mentions present in the
     // new content but not the previous one — so re-saving a post does not spam
     // every already-mentioned user again.
@@ -63,18 +85,72 @@ pub async fn service_update_board_post(
         vec![]
     };
 
-    let txn = db.begin().await?;
-    let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+    // Snapshot the body this edit replaces, unless the edit is a no-op re-save.
+    let title_changed = payload.title.as_ref().is_some_and(|t| *t != post.title);
+    let content_changed = payload.content.as_ref().is_some_and(|c| *c != post.content);
+    let superseded = if title_changed || content_changed {
+        Some(store_revision_body(r2_revisions, post.id, &post.content).await?)
+    } else {
+        None
+    };
+    let superseded_key = superseded.as_ref().map(|s| s.storage_key.clone());
+
+    let result: Result<_, Errors> = async {
+        let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+
+        if let Some(superseded) = superseded {
+            repository_create_board_revision(
+                &txn,
+                superseded.id,
+                post.id,
+                None,
+                Some(post.title.clone()),
+                superseded.storage_key,
+                superseded.content_length,
+                actor.id,
+            )
+            .await?;
+        }
 
-    let updated =
-        repository_update_board_post(&txn, payload.post_id, payload.title, payload.content).await?;
+        let updated =
+            repository_update_board_post(&txn, payload.post_id, payload.title, payload.content)
+                .await?;
 
-    txn.commit().await?;
+        let action_log = repository_create_action_log(
+            &txn,
+            ActionLogAction::PostEdit,
+            Some(session.user_id),
+            ActionResourceType::Post,
+            Some(updated.id),
+            format!("Edited post \"{}\"", updated.title),
+            Some(json!({ "board_id": updated.board_id })),
+        )
+        .await?;
+
+        txn.commit().await?;
+        Ok((actor, updated, action_log))
+    }
+    .await;
+    let (actor, updated, action_log) = match result {
+        Ok(committed) => committed,
+        Err(e) => {
+            discard_revision_body(r2_revisions, superseded_key).await;
+            return Err(e);
+        }
+    };
+    publish_action_log(nats_client, &action_log).await;
+    spawn_index_post(worker, updated.id);
+
+    if content_changed {
+        invalidate_rendered_post_content(redis_cache, updated.id).await;
+    }
 
     // Notify only newly-mentioned users (best-effort)
     let actor_ip = Some(parse_attribution_ip(ip_address)?);
     notify_mentions(
         db,
+        worker,
+        nats_client,
         mentioned_user_ids,
         Some(session.user_id),
         actor.id,
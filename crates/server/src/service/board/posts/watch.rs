@@ -0,0 +1,68 @@
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::board::posts::repository_get_board_post_by_id;
+use crate::repository::board::repository_get_board_by_id;
+use crate::repository::board::watchers::{
+    repository_create_board_post_watcher, repository_delete_board_post_watcher,
+};
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::facts::load_board_facts;
+use crate::service::board::mapper::is_tombstoned;
+use dto::board::{BoardPostWatchRequest, BoardPostWatchResponse};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use tracing::info;
+
+/// Starts watching a post: new comments on it reach the caller's inbox.
+/// Idempotent.
+pub async fn service_watch_board_post(
+    db: &DatabaseConnection,
+    payload: BoardPostWatchRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardPostWatchResponse> {
+    set_board_post_watch(db, payload, session, true).await
+}
+
+/// Stops watching a post. Unwatching a post the caller never watched is a no-op.
+pub async fn service_unwatch_board_post(
+    db: &DatabaseConnection,
+    payload: BoardPostWatchRequest,
+    session: &SessionContext,
+) -> ServiceResult<BoardPostWatchResponse> {
+    set_board_post_watch(db, payload, session, false).await
+}
+
+/// Shared implementation. Watching only subscribes to what the caller could
+/// already read, so it is held to the board's view bar, not the write bar — a
+/// banned user may still follow a thread.
+async fn set_board_post_watch(
+    db: &DatabaseConnection,
+    payload: BoardPostWatchRequest,
+    session: &SessionContext,
+    watch: bool,
+) -> ServiceResult<BoardPostWatchResponse> {
+    let post = repository_get_board_post_by_id(db, payload.post_id).await?;
+    let board = repository_get_board_by_id(db, post.board_id).await?;
+
+    let ctx = PermissionService::get_context(db, Some(session)).await?;
+    let facts = load_board_facts(db, &board).await?;
+    BoardPermission::View(facts).check(&ctx)?;
+
+    if watch && is_tombstoned(&ctx, post.hidden_at) {
+        return Err(Errors::BoardPostNotFound);
+    }
+
+    if watch {
+        repository_create_board_post_watcher(db, post.id, session.user_id).await?;
+    } else {
+        repository_delete_board_post_watcher(db, post.id, session.user_id).await?;
+    }
+
+    info!(post_id = %post.id, user_id = %session.user_id, watch, "Board post watch updated");
+
+    Ok(BoardPostWatchResponse {
+        post_id: post.id,
+        watching: watch,
+    })
+}
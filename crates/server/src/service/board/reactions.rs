@@ -0,0 +1,80 @@
+use crate::repository::board::reactions::{
+    repository_find_comment_reactions_by_actor, repository_find_post_reactions_by_actor,
+};
+use config::ServerConfig;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::ConnectionTrait;
+use sea_orm::prelude::Json;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+/// Rejects a reaction kind that is not in the configured `BOARD_REACTION_KINDS`.
+/// Only adding is gated: a kind dropped from the config can still be removed.
+pub fn ensure_reaction_kind(kind: &str) -> ServiceResult<()> {
+    if ServerConfig::get()
+        .board_reaction_kinds
+        .iter()
+        .any(|allowed| allowed == kind)
+    {
+        Ok(())
+    } else {
+        Err(Errors::BoardReactionKindInvalid)
+    }
+}
+
+/// Decodes a denormalized `reaction_counts` JSONB column (`{"kind": n, ...}`).
+pub fn reaction_counts_map(counts: &Json) -> BTreeMap<String, i32> {
+    counts
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .filter_map(|(kind, n)| Some((kind.clone(), i32::try_from(n.as_i64()?).ok()?)))
+                .filter(|(_, n)| *n > 0)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The viewer's own reaction kinds per post, keyed by post id. Empty for
+/// anonymous callers (`viewer_actor_id` from `resolve_viewer_actor_id`).
+pub async fn viewer_post_reactions<C>(
+    conn: &C,
+    viewer_actor_id: Option<Uuid>,
+    post_ids: &[Uuid],
+) -> ServiceResult<HashMap<Uuid, Vec<String>>>
+where
+    C: ConnectionTrait,
+{
+    let Some(actor_id) = viewer_actor_id else {
+        return Ok(HashMap::new());
+    };
+
+    let mut map: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for reaction in repository_find_post_reactions_by_actor(conn, actor_id, post_ids).await? {
+        map.entry(reaction.post_id).or_default().push(reaction.kind);
+    }
+    Ok(map)
+}
+
+/// The viewer's own reaction kinds per comment, keyed by comment id.
+pub async fn viewer_comment_reactions<C>(
+    conn: &C,
+    viewer_actor_id: Option<Uuid>,
+    comment_ids: &[Uuid],
+) -> ServiceResult<HashMap<Uuid, Vec<String>>>
+where
+    C: ConnectionTrait,
+{
+    let Some(actor_id) = viewer_actor_id else {
+        return Ok(HashMap::new());
+    };
+
+    let mut map: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for reaction in repository_find_comment_reactions_by_actor(conn, actor_id, comment_ids).await? {
+        if let Some(comment_id) = reaction.comment_id {
+            map.entry(comment_id).or_default().push(reaction.kind);
+        }
+    }
+    Ok(map)
+}
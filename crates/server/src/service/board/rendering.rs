@@ -0,0 +1,75 @@
+use crate::utils::markup::render_markup;
+use crate::utils::redis_cache::{delete_key, get_json_compressed, set_json_compressed};
+use chrono::{DateTime, Utc};
+use dto::board::{ParseBoardRequest, ParseBoardResponse};
+use entity::board_posts::Model as BoardPostModel;
+use errors::errors::ServiceResult;
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Cached render of a post body, stamped with the `edited_at` it was rendered
+/// from. A read that raced an edit (rendered the old body after the edit dropped
+/// the key) leaves a stale stamp behind, which the next read simply ignores.
+#[derive(Serialize, Deserialize)]
+struct CachedRenderedContent {
+    edited_at: Option<DateTime<Utc>>,
+    html: String,
+}
+
+/// Render markup for preview without saving anything.
+pub async fn service_parse_board(
+    db: &DatabaseConnection,
+    payload: ParseBoardRequest,
+) -> ServiceResult<ParseBoardResponse> {
+    let rendered_content = render_markup(db, &payload.content).await?;
+    Ok(ParseBoardResponse { rendered_content })
+}
+
+/// Returns the post's rendered body, rendering and caching it on a miss.
+///
+/// Cache failures are logged and fall back to rendering, never failing the read.
+pub(super) async fn rendered_post_content(
+    db: &DatabaseConnection,
+    redis_cache: &RedisClient,
+    post: &BoardPostModel,
+) -> ServiceResult<String> {
+    let key = constants::board_post_rendered_key(&post.id.to_string());
+
+    match get_json_compressed::<CachedRenderedContent>(redis_cache, &key).await {
+        Ok(Some(cached)) if cached.edited_at == post.edited_at => return Ok(cached.html),
+        Ok(_) => {}
+        Err(e) => {
+            tracing::warn!(error = ?e, post_id = %post.id, "rendered content cache read failed")
+        }
+    }
+
+    let html = render_markup(db, &post.content).await?;
+
+    let cached = CachedRenderedContent {
+        edited_at: post.edited_at,
+        html,
+    };
+    if let Err(e) = set_json_compressed(
+        redis_cache,
+        &key,
+        &cached,
+        constants::BOARD_POST_RENDERED_TTL_SECONDS,
+    )
+    .await
+    {
+        tracing::warn!(error = ?e, post_id = %post.id, "rendered content cache write failed");
+    }
+
+    Ok(cached.html)
+}
+
+/// Drop the cached render after the post body changed (best-effort; the
+/// `edited_at` stamp still guards against serving a stale entry).
+pub(super) async fn invalidate_rendered_post_content(redis_cache: &RedisClient, post_id: Uuid) {
+    let key = constants::board_post_rendered_key(&post_id.to_string());
+    if let Err(e) = delete_key(redis_cache, &key).await {
+        tracing::warn!(error = ?e, %post_id, "rendered content cache invalidation failed");
+    }
+}
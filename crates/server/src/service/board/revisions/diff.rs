@@ -0,0 +1,92 @@
+use super::get::authorize_revision_read;
+use super::storage::load_revision_body;
+use crate::connection::R2RevisionClient;
+use crate::repository::board::comments::repository_get_board_comment_by_id;
+use crate::repository::board::revisions::repository_get_board_revision_by_id;
+use crate::service::auth::session_types::SessionContext;
+use dto::board::{BoardRevisionDiffResponse, GetBoardRevisionDiffRequest};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use similar::{ChangeTag, TextDiff};
+
+pub async fn service_diff_board_revisions(
+    db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    payload: GetBoardRevisionDiffRequest,
+    session: Option<&SessionContext>,
+) -> ServiceResult<BoardRevisionDiffResponse> {
+    let from = repository_get_board_revision_by_id(db, payload.from_revision_id).await?;
+    let post = authorize_revision_read(db, from.post_id, from.comment_id, session).await?;
+
+    let from_content = load_revision_body(r2_revisions, &from.storage_key).await?;
+
+    let (to_title, to_content) = match payload.to_revision_id {
+        Some(to_revision_id) => {
+            let to = repository_get_board_revision_by_id(db, to_revision_id).await?;
+            if to.post_id != from.post_id || to.comment_id != from.comment_id {
+                return Err(Errors::BoardRevisionMismatch);
+            }
+            let content = load_revision_body(r2_revisions, &to.storage_key).await?;
+            (to.title, content)
+        }
+        // Against the live body.
+        None => match from.comment_id {
+            None => (Some(post.title), post.content),
+            Some(comment_id) => {
+                let comment = repository_get_board_comment_by_id(db, comment_id).await?;
+                (None, comment.content)
+            }
+        },
+    };
+
+    let (diff, additions, deletions) = line_diff(&from_content, &to_content);
+
+    Ok(BoardRevisionDiffResponse {
+        from_revision_id: from.id,
+        to_revision_id: payload.to_revision_id,
+        from_title: from.title,
+        to_title,
+        diff,
+        additions,
+        deletions,
+    })
+}
+
+/// Unified line diff of two bodies plus added/removed line counts.
+fn line_diff(old: &str, new: &str) -> (String, u32, u32) {
+    let diff = TextDiff::from_lines(old, new);
+
+    let (mut additions, mut deletions) = (0, 0);
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            ChangeTag::Insert => additions += 1,
+            ChangeTag::Delete => deletions += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+
+    let unified = diff.unified_diff().context_radius(3).to_string();
+
+    (unified, additions, deletions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::line_diff;
+
+    #[test]
+    fn identical_bodies_produce_an_empty_diff() {
+        let (diff, additions, deletions) = line_diff("a\nb\n", "a\nb\n");
+        assert!(diff.is_empty());
+        assert_eq!((additions, deletions), (0, 0));
+    }
+
+    #[test]
+    fn counts_changed_lines_on_each_side() {
+        let (diff, additions, deletions) = line_diff("a\nb\nc\n", "a\nB\nc\nd\n");
+        assert_eq!((additions, deletions), (2, 1));
+        assert!(diff.contains("-b\n"));
+        assert!(diff.contains("+B\n"));
+        assert!(diff.contains("+d\n"));
+    }
+}
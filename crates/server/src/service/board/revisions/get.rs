@@ -0,0 +1,67 @@
+use super::storage::load_revision_body;
+use crate::connection::R2RevisionClient;
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::board::comments::repository_get_board_comment_by_id;
+use crate::repository::board::posts::repository_get_board_post_by_id;
+use crate::repository::board::repository_get_board_by_id;
+use crate::repository::board::revisions::repository_get_board_revision_by_id;
+use crate::service::actors::actor_response_by_id;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::facts::load_board_facts;
+use crate::service::board::mapper::build_revision_summary_response;
+use dto::board::BoardRevisionResponse;
+use entity::board_posts::Model as BoardPostModel;
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+pub async fn service_get_board_revision(
+    db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    revision_id: Uuid,
+    session: Option<&SessionContext>,
+) -> ServiceResult<BoardRevisionResponse> {
+    let revision = repository_get_board_revision_by_id(db, revision_id).await?;
+    authorize_revision_read(db, revision.post_id, revision.comment_id, session).await?;
+
+    let content = load_revision_body(r2_revisions, &revision.storage_key).await?;
+    let actor = actor_response_by_id(db, revision.actor_id).await?;
+
+    Ok(BoardRevisionResponse {
+        revision: build_revision_summary_response(revision, actor),
+        content,
+    })
+}
+
+/// Revision history is as public as the thread it belongs to: anyone who may view
+/// the post's board may read its superseded bodies. Hidden content is the
+/// exception — its history would undo the tombstone, so only moderators may read
+/// the revisions of a hidden post, or of a hidden comment (`comment_id`).
+pub(super) async fn authorize_revision_read(
+    db: &DatabaseConnection,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    session: Option<&SessionContext>,
+) -> ServiceResult<BoardPostModel> {
+    let post = repository_get_board_post_by_id(db, post_id).await?;
+    let board = repository_get_board_by_id(db, post.board_id).await?;
+
+    let ctx = PermissionService::get_context(db, session).await?;
+    let facts = load_board_facts(db, &board).await?;
+    BoardPermission::View(facts).check(&ctx)?;
+
+    let comment_hidden = match comment_id {
+        Some(comment_id) => repository_get_board_comment_by_id(db, comment_id)
+            .await?
+            .hidden_at
+            .is_some(),
+        None => false,
+    };
+    if post.hidden_at.is_some() || comment_hidden {
+        BoardPermission::Moderate.check(&ctx)?;
+    }
+
+    Ok(post)
+}
@@ -0,0 +1,67 @@
+use super::get::authorize_revision_read;
+use crate::repository::board::comments::repository_get_board_comment_by_id;
+use crate::repository::board::revisions::repository_find_board_revisions;
+use crate::service::actors::actor_response_map;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::mapper::build_revision_summary_response;
+use dto::board::{
+    BoardRevisionListResponse, GetBoardCommentRevisionsRequest, GetBoardPostRevisionsRequest,
+};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+pub async fn service_list_board_post_revisions(
+    db: &DatabaseConnection,
+    payload: GetBoardPostRevisionsRequest,
+    session: Option<&SessionContext>,
+) -> ServiceResult<BoardRevisionListResponse> {
+    authorize_revision_read(db, payload.post_id, None, session).await?;
+
+    list_revisions(db, payload.post_id, None, payload.cursor_id, payload.limit).await
+}
+
+pub async fn service_list_board_comment_revisions(
+    db: &DatabaseConnection,
+    payload: GetBoardCommentRevisionsRequest,
+    session: Option<&SessionContext>,
+) -> ServiceResult<BoardRevisionListResponse> {
+    let comment = repository_get_board_comment_by_id(db, payload.comment_id).await?;
+    authorize_revision_read(db, comment.post_id, Some(comment.id), session).await?;
+
+    list_revisions(
+        db,
+        comment.post_id,
+        Some(comment.id),
+        payload.cursor_id,
+        payload.limit,
+    )
+    .await
+}
+
+async fn list_revisions(
+    db: &DatabaseConnection,
+    post_id: Uuid,
+    comment_id: Option<Uuid>,
+    cursor_id: Option<Uuid>,
+    limit: u64,
+) -> ServiceResult<BoardRevisionListResponse> {
+    // Fetch one extra row to learn whether an older page exists.
+    let mut revisions =
+        repository_find_board_revisions(db, post_id, comment_id, cursor_id, limit + 1).await?;
+    let has_older = revisions.len() as u64 > limit;
+    revisions.truncate(limit as usize);
+
+    let actor_ids: Vec<Uuid> = revisions.iter().map(|revision| revision.actor_id).collect();
+    let actors = actor_response_map(db, &actor_ids).await?;
+
+    let data = revisions
+        .into_iter()
+        .map(|revision| {
+            let actor = actors.get(&revision.actor_id).cloned();
+            build_revision_summary_response(revision, actor)
+        })
+        .collect();
+
+    Ok(BoardRevisionListResponse { data, has_older })
+}
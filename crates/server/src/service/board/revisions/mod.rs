@@ -0,0 +1,11 @@
+mod diff;
+mod get;
+mod list;
+mod restore;
+mod storage;
+
+pub use diff::service_diff_board_revisions;
+pub use get::service_get_board_revision;
+pub use list::{service_list_board_comment_revisions, service_list_board_post_revisions};
+pub use restore::service_restore_board_revision;
+pub(super) use storage::{delete_revision_bodies, discard_revision_body, store_revision_body};
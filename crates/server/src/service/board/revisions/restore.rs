@@ -0,0 +1,150 @@
+use super::storage::{discard_revision_body, load_revision_body, store_revision_body};
+use crate::connection::R2RevisionClient;
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::actors::repository_find_or_create_user_actor;
+use crate::repository::board::comments::{
+    repository_get_board_comment_by_id_for_update, repository_update_board_comment,
+};
+use crate::repository::board::posts::{
+    repository_get_board_post_by_id, repository_get_board_post_by_id_for_update,
+    repository_update_board_post,
+};
+use crate::repository::board::revisions::{
+    repository_create_board_revision, repository_get_board_revision_by_id,
+};
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::background_jobs::{spawn_index_comment, spawn_index_post};
+use crate::service::board::rendering::invalidate_rendered_post_content;
+use crate::state::WorkerClient;
+use constants::ModerationAction;
+use dto::board::{RestoreBoardRevisionRequest, RestoreBoardRevisionResponse};
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// Put an earlier revision's body (and, for posts, title) back in place.
+///
+/// Gated by `BoardPermission::Moderate` — the one case where a moderator rewrites
+/// content, and only ever to words the author (or a previous restore) already
+/// published. The body being replaced is snapshotted first, so every restore is
+/// itself reversible, and the restore is recorded in the moderation log.
+pub async fn service_restore_board_revision(
+    db: &DatabaseConnection,
+    r2_revisions: &R2RevisionClient,
+    redis_cache: &RedisClient,
+    worker: &WorkerClient,
+    payload: RestoreBoardRevisionRequest,
+    session: &SessionContext,
+) -> ServiceResult<RestoreBoardRevisionResponse> {
+    let revision = repository_get_board_revision_by_id(db, payload.revision_id).await?;
+    let post = repository_get_board_post_by_id(db, revision.post_id).await?;
+
+    let ctx = PermissionService::get_context(db, Some(session)).await?;
+    BoardPermission::Moderate.check(&ctx)?;
+
+    let restored_content = load_revision_body(r2_revisions, &revision.storage_key).await?;
+
+    let txn = db.begin().await?;
+    // Lock the row being restored and snapshot its body as it stands now, so a
+    // concurrent edit or restore is never lost from the history.
+    let (current_title, current_content) = match revision.comment_id {
+        Some(comment_id) => {
+            let comment = repository_get_board_comment_by_id_for_update(&txn, comment_id).await?;
+            (None, comment.content)
+        }
+        None => {
+            let post = repository_get_board_post_by_id_for_update(&txn, post.id).await?;
+            (Some(post.title), post.content)
+        }
+    };
+    let superseded = store_revision_body(r2_revisions, post.id, &current_content).await?;
+    let superseded_id = superseded.id;
+    let superseded_key = Some(superseded.storage_key.clone());
+
+    let result: Result<_, Errors> = async {
+        let actor = repository_find_or_create_user_actor(&txn, session.user_id).await?;
+
+        repository_create_board_revision(
+            &txn,
+            superseded.id,
+            post.id,
+            revision.comment_id,
+            current_title,
+            superseded.storage_key,
+            superseded.content_length,
+            actor.id,
+        )
+        .await?;
+
+        let (resource_type, resource_id) = match revision.comment_id {
+            Some(comment_id) => {
+                repository_update_board_comment(&txn, comment_id, restored_content).await?;
+                (ModerationResourceType::BoardComment, comment_id)
+            }
+            None => {
+                repository_update_board_post(
+                    &txn,
+                    post.id,
+                    revision.title.clone(),
+                    Some(restored_content),
+                )
+                .await?;
+                (ModerationResourceType::BoardPost, post.id)
+            }
+        };
+
+        repository_create_moderation_log(
+            &txn,
+            ModerationAction::BoardRestoreRevision,
+            Some(session.user_id),
+            resource_type,
+            Some(resource_id),
+            payload.reason,
+            Some(json!({
+                "board_id": post.board_id,
+                "post_id": post.id,
+                "revision_id": revision.id,
+                "superseded_revision_id": superseded.id,
+            })),
+        )
+        .await?;
+
+        txn.commit().await?;
+        Ok(resource_id)
+    }
+    .await;
+    let resource_id = match result {
+        Ok(resource_id) => resource_id,
+        Err(e) => {
+            discard_revision_body(r2_revisions, superseded_key).await;
+            return Err(e);
+        }
+    };
+
+    match revision.comment_id {
+        Some(comment_id) => spawn_index_comment(worker, comment_id),
+        None => {
+            invalidate_rendered_post_content(redis_cache, post.id).await;
+            spawn_index_post(worker, post.id);
+        }
+    }
+
+    info!(
+        revision_id = %revision.id,
+        %resource_id,
+        "Board revision restored"
+    );
+
+    Ok(RestoreBoardRevisionResponse {
+        post_id: post.id,
+        comment_id: revision.comment_id,
+        restored_revision_id: revision.id,
+        superseded_revision_id: superseded_id,
+    })
+}
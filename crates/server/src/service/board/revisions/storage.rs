@@ -0,0 +1,84 @@
+//! Revision bodies in the revisions bucket.
+//!
+//! A body is uploaded inside the edit transaction, after the edited row has been
+//! locked, so concurrent edits each snapshot the body they actually replace. If the
+//! transaction then fails, the object is deleted again; should that delete fail too,
+//! the object is merely unreachable, since the API only resolves bodies through
+//! `board_post_revisions`.
+
+use crate::connection::R2RevisionClient;
+use constants::board_revision_key;
+use errors::errors::{Errors, ServiceResult};
+use tracing::warn;
+use uuid::Uuid;
+
+/// A body uploaded to the revisions bucket, awaiting its index row.
+pub(crate) struct StoredRevisionBody {
+    pub id: Uuid,
+    pub storage_key: String,
+    pub content_length: i32,
+}
+
+/// Upload a body that is about to be replaced. The returned id must be used as
+/// the revision row's primary key, since the storage key embeds it.
+pub(crate) async fn store_revision_body(
+    r2_revisions: &R2RevisionClient,
+    post_id: Uuid,
+    content: &str,
+) -> ServiceResult<StoredRevisionBody> {
+    let id = Uuid::now_v7();
+    let storage_key = board_revision_key(&post_id.to_string(), &id.to_string());
+
+    r2_revisions
+        .upload_content(&storage_key, content)
+        .await
+        .map_err(|e| {
+            warn!(%post_id, storage_key, error = ?e, "Failed to upload revision to R2");
+            Errors::SysInternalError("Failed to store revision".to_string())
+        })?;
+
+    Ok(StoredRevisionBody {
+        id,
+        storage_key,
+        // Bodies are capped far below i32::MAX by request validation.
+        content_length: content.len() as i32,
+    })
+}
+
+/// Download a revision body.
+pub(super) async fn load_revision_body(
+    r2_revisions: &R2RevisionClient,
+    storage_key: &str,
+) -> ServiceResult<String> {
+    r2_revisions
+        .download_content(storage_key)
+        .await
+        .map_err(|e| {
+            warn!(storage_key, error = ?e, "Failed to download revision from R2");
+            Errors::SysInternalError("Failed to read revision from storage".to_string())
+        })
+}
+
+/// Delete the bodies of revisions whose rows were cascaded away. Best-effort: a
+/// failure only leaves an unreachable object behind, so it is logged, not returned.
+pub(crate) async fn delete_revision_bodies(
+    r2_revisions: &R2RevisionClient,
+    storage_keys: &[String],
+) {
+    for storage_key in storage_keys {
+        if let Err(e) = r2_revisions.delete(storage_key).await {
+            warn!(storage_key, error = ?e, "Failed to delete revision body from R2");
+        }
+    }
+}
+
+/// Delete a body uploaded for an edit whose transaction did not commit.
+/// Best-effort, like [`delete_revision_bodies`].
+pub(crate) async fn discard_revision_body(
+    r2_revisions: &R2RevisionClient,
+    storage_key: Option<String>,
+) {
+    if let Some(storage_key) = storage_key {
+        delete_revision_bodies(r2_revisions, &[storage_key]).await;
+    }
+}
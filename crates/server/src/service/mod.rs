@@ -9,5 +9,7 @@ pub mod groups;
 pub mod moderation;
 pub mod notification;
 pub mod oauth;
+pub mod report;
 pub mod search;
 pub mod user;
+pub mod webhooks;
@@ -0,0 +1,82 @@
+use crate::bridge::worker_client::fan_out_system_announcement;
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::notification::repository_create_system_announcement;
+use crate::service::auth::session_types::SessionContext;
+use crate::state::WorkerClient;
+use constants::ModerationAction;
+use dto::notification::{CreateSystemAnnouncementRequest, SystemAnnouncementResponse};
+use entity::common::ModerationResourceType;
+use errors::errors::ServiceResult;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// Broadcasts a system announcement to every active user's inbox.
+///
+/// # Role
+/// - Stores the announcement as one `system_announcement` event (title, body and
+///   link in `additional_data`) and records a `notification:announce` moderation
+///   log entry, in one transaction.
+/// - Queues the worker fan-out, which writes the deliveries in batches.
+///
+/// # Related
+/// - `repository_create_system_announcement`
+/// - `fan_out_system_announcement`
+///
+/// # Errors
+/// - Returns a DB/repository error if the insert fails.
+/// - Returns a worker queue error if the fan-out cannot be queued. The event is
+///   then left without deliveries and can be retracted.
+pub async fn service_create_system_announcement(
+    db: &DatabaseConnection,
+    worker: &WorkerClient,
+    session: &SessionContext,
+    payload: CreateSystemAnnouncementRequest,
+) -> ServiceResult<SystemAnnouncementResponse> {
+    let txn = db.begin().await?;
+
+    let event = repository_create_system_announcement(
+        &txn,
+        json!({
+            "title": payload.title,
+            "body": payload.body,
+            "link": payload.link,
+            "mandatory": payload.mandatory,
+        }),
+    )
+    .await?;
+
+    repository_create_moderation_log(
+        &txn,
+        ModerationAction::NotificationAnnounce,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(event.id),
+        payload.reason,
+        Some(json!({
+            "title": payload.title,
+            "mandatory": payload.mandatory,
+        })),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    fan_out_system_announcement(worker, event.id, payload.mandatory).await?;
+
+    info!(
+        event_id = %event.id,
+        mandatory = payload.mandatory,
+        actor_id = %session.user_id,
+        "System announcement queued"
+    );
+
+    Ok(SystemAnnouncementResponse {
+        id: event.id,
+        title: payload.title,
+        body: payload.body,
+        link: payload.link,
+        mandatory: payload.mandatory,
+        created_at: event.created_at,
+    })
+}
@@ -0,0 +1,9 @@
+//! System announcement service APIs (admin only).
+//!
+//! Covers broadcasting an announcement to every active user and retracting it.
+
+pub mod create;
+pub mod retract;
+
+pub use create::service_create_system_announcement;
+pub use retract::service_retract_system_announcement;
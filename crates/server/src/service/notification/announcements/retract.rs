@@ -0,0 +1,77 @@
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::notification::{
+    repository_find_system_announcement_for_update, repository_retract_system_announcement,
+};
+use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
+use constants::ModerationAction;
+use dto::notification::RetractSystemAnnouncementRequest;
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// Retracts a system announcement.
+///
+/// # Role
+/// - Locks the event, stamps it retracted (which stops a fan-out still running)
+///   and deletes every unread delivery. Copies users already read are kept.
+/// - Records a `notification:retract_announcement` moderation log entry.
+/// - Signals the open notification streams of the users who lost a copy.
+///
+/// Retracting again is harmless: it only sweeps unread copies left behind.
+///
+/// # Related
+/// - `repository_find_system_announcement_for_update`
+/// - `repository_retract_system_announcement`
+///
+/// # Errors
+/// - `Errors::NotFound` if no system announcement has this id
+/// - Returns a DB/repository error if the retraction fails.
+pub async fn service_retract_system_announcement(
+    db: &DatabaseConnection,
+    nats_client: &NatsClient,
+    session: &SessionContext,
+    payload: RetractSystemAnnouncementRequest,
+) -> ServiceResult<()> {
+    let txn = db.begin().await?;
+
+    repository_find_system_announcement_for_update(&txn, payload.id)
+        .await?
+        .ok_or(Errors::NotFound("Announcement not found".to_string()))?;
+
+    let removed = repository_retract_system_announcement(&txn, payload.id).await?;
+
+    repository_create_moderation_log(
+        &txn,
+        ModerationAction::NotificationRetractAnnouncement,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(payload.id),
+        payload.reason,
+        Some(json!({ "removed_deliveries": removed.len() })),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    for user_id in &removed {
+        publish_user_realtime_event(
+            nats_client,
+            *user_id,
+            &UserRealtimeEvent::NotificationsChanged,
+        )
+        .await;
+    }
+
+    info!(
+        event_id = %payload.id,
+        removed_deliveries = removed.len(),
+        actor_id = %session.user_id,
+        "System announcement retracted"
+    );
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use crate::repository::notification::repository_delete_notification;
 use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
 use errors::errors::{Errors, ServiceResult};
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
 use sea_orm::DatabaseConnection;
 use tracing::debug;
 use uuid::Uuid;
@@ -8,8 +10,9 @@ use uuid::Uuid;
 /// Deletes a single notification belonging to the currently logged-in user.
 ///
 /// # Role
-/// - Only deletes notifications owned by the user.
+/// - Only deletes notifications owned by the user; an unread group is deleted whole.
 /// - Returns NotFound if there is nothing to delete.
+/// - Signals the user's open notification streams of the change.
 ///
 /// # Related
 /// - `repository_delete_notification`
@@ -19,6 +22,7 @@ use uuid::Uuid;
 /// - Returns a DB/repository error if the deletion fails.
 pub async fn service_delete_notification(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     session: &SessionContext,
     notification_id: Uuid,
 ) -> ServiceResult<()> {
@@ -33,5 +37,12 @@ pub async fn service_delete_notification(
 
     debug!(user_id = %session.user_id, notification_id = %notification_id, "Notification deleted");
 
+    publish_user_realtime_event(
+        nats_client,
+        session.user_id,
+        &UserRealtimeEvent::NotificationsChanged,
+    )
+    .await;
+
     Ok(())
 }
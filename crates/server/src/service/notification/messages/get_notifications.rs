@@ -1,25 +1,31 @@
 use crate::repository::notification::{
-    NotificationFilter, repository_exists_newer_notification, repository_exists_older_notification,
-    repository_find_notifications_by_user_id_cursor,
+    NotificationFilter, NotificationGroupSummary, NotificationQueryResult,
+    repository_exists_newer_notification, repository_exists_older_notification,
+    repository_find_notification_group_summaries, repository_find_notifications_by_user_id_cursor,
 };
 use crate::service::actors::actor_response_map;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::cursor_pagination::{cursor_flags, reverse_if_newer};
 use constants::NotificationAction;
+use dto::actor::ActorResponse;
 use dto::notification::{GetNotificationsRequest, NotificationListResponse, NotificationResponse};
 use dto::pagination::CursorDirection;
 use errors::errors::ServiceResult;
 use sea_orm::DatabaseConnection;
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::str::FromStr;
 use tracing::warn;
+use uuid::Uuid;
+
+/// Recent distinct actors returned per notification group.
+const GROUP_RECENT_ACTORS: usize = 3;
 
 /// Retrieves the currently logged-in user's notifications with cursor-based pagination.
 ///
 /// # Role
 /// - Converts request conditions into a `NotificationFilter`.
-/// - Computes the cursor page and `has_newer`/`has_older`.
-/// - Maps repository models to API response DTOs.
+/// - Computes the cursor page and `has_newer`/`has_older`; an unread group is one entry.
+/// - Maps repository models to API response DTOs, with each group's size and actors.
 ///
 /// # Related
 /// - `repository_find_notifications_by_user_id_cursor`
@@ -67,50 +73,119 @@ pub async fn service_get_notifications(
 
     reverse_if_newer(&mut notifications, is_newer);
 
-    let actor_ids: Vec<_> = notifications
+    let data = notification_responses(db, session.user_id, notifications).await?;
+
+    Ok(NotificationListResponse {
+        data,
+        has_newer,
+        has_older,
+    })
+}
+
+/// Maps delivery rows to response DTOs. Unread rows get their group's size and
+/// recent actors; rows whose stored action no longer parses are dropped (logged).
+pub(super) async fn notification_responses(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    notifications: Vec<NotificationQueryResult>,
+) -> ServiceResult<Vec<NotificationResponse>> {
+    let unread_group_keys: Vec<String> = notifications
+        .iter()
+        .filter(|notification| !notification.is_read)
+        .map(|notification| notification.group_key.clone())
+        .collect();
+    let groups: HashMap<String, NotificationGroupSummary> =
+        repository_find_notification_group_summaries(db, user_id, unread_group_keys)
+            .await?
+            .into_iter()
+            .map(|group| (group.group_key.clone(), group))
+            .collect();
+
+    let recent_actor_ids: Vec<Vec<Uuid>> = notifications
+        .iter()
+        .map(|notification| {
+            let group = (!notification.is_read)
+                .then(|| groups.get(&notification.group_key))
+                .flatten();
+            let mut recent: Vec<Uuid> = Vec::with_capacity(GROUP_RECENT_ACTORS);
+            let candidates = match group.and_then(|group| group.actor_ids.as_ref()) {
+                Some(actor_ids) => actor_ids.clone(),
+                None => notification.actor_id.into_iter().collect(),
+            };
+            for actor_id in candidates {
+                if recent.len() == GROUP_RECENT_ACTORS {
+                    break;
+                }
+                if !recent.contains(&actor_id) {
+                    recent.push(actor_id);
+                }
+            }
+            recent
+        })
+        .collect();
+
+    let mut actor_ids: Vec<Uuid> = notifications
         .iter()
         .filter_map(|notification| notification.actor_id)
-        .collect::<HashSet<_>>()
-        .into_iter()
+        .chain(recent_actor_ids.iter().flatten().copied())
         .collect();
+    actor_ids.sort_unstable();
+    actor_ids.dedup();
     let actors = actor_response_map(db, &actor_ids).await?;
 
-    let data: Vec<NotificationResponse> = notifications
+    Ok(notifications
         .into_iter()
-        .filter_map(|notification| {
-            let action = match NotificationAction::from_str(&notification.action) {
-                Ok(a) => a,
-                Err(_) => {
-                    warn!(
-                        notification_id = %notification.id,
-                        action = %notification.action,
-                        "Failed to parse notification action, skipping"
-                    );
-                    return None;
-                }
-            };
-            Some(NotificationResponse {
-                id: notification.id,
-                actor_id: notification.actor_id,
-                actor: notification
-                    .actor_id
-                    .and_then(|id| actors.get(&id).cloned()),
-                notification_type: notification.notification_type,
-                action,
-                board_id: notification.board_id,
-                post_id: notification.post_id,
-                comment_id: notification.comment_id,
-                additional_data: notification.additional_data,
-                is_read: notification.is_read,
-                created_at: notification.created_at,
-                read_at: notification.read_at,
-            })
+        .zip(recent_actor_ids)
+        .filter_map(|(notification, recent_actor_ids)| {
+            let group = (!notification.is_read)
+                .then(|| groups.get(&notification.group_key))
+                .flatten();
+            notification_response(notification, group, &recent_actor_ids, &actors)
         })
-        .collect();
+        .collect())
+}
 
-    Ok(NotificationListResponse {
-        data,
-        has_newer,
-        has_older,
+/// Maps a delivery row to its response DTO, or `None` (logged) when the stored
+/// action no longer parses.
+fn notification_response(
+    notification: NotificationQueryResult,
+    group: Option<&NotificationGroupSummary>,
+    recent_actor_ids: &[Uuid],
+    actors: &HashMap<Uuid, ActorResponse>,
+) -> Option<NotificationResponse> {
+    let action = match NotificationAction::from_str(&notification.action) {
+        Ok(a) => a,
+        Err(_) => {
+            warn!(
+                notification_id = %notification.id,
+                action = %notification.action,
+                "Failed to parse notification action, skipping"
+            );
+            return None;
+        }
+    };
+    Some(NotificationResponse {
+        id: notification.id,
+        actor_id: notification.actor_id,
+        actor: notification
+            .actor_id
+            .and_then(|id| actors.get(&id).cloned()),
+        notification_type: notification.notification_type,
+        action,
+        board_id: notification.board_id,
+        post_id: notification.post_id,
+        comment_id: notification.comment_id,
+        additional_data: notification.additional_data,
+        is_read: notification.is_read,
+        created_at: notification.created_at,
+        read_at: notification.read_at,
+        group_size: group.map_or(1, |group| group.member_count as u64),
+        actor_count: group.map_or(notification.actor_id.is_some() as u64, |group| {
+            group.actor_count as u64
+        }),
+        recent_actors: recent_actor_ids
+            .iter()
+            .filter_map(|id| actors.get(id).cloned())
+            .collect(),
     })
 }
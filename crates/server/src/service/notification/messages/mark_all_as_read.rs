@@ -1,6 +1,8 @@
 use crate::repository::notification::repository_mark_all_notifications_as_read;
 use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
 use errors::errors::ServiceResult;
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
 use sea_orm::DatabaseConnection;
 use tracing::debug;
 
@@ -8,6 +10,7 @@ use tracing::debug;
 ///
 /// # Role
 /// Bulk-updates unread notifications and returns the number of affected rows.
+/// Signals the user's open notification streams when anything changed.
 ///
 /// # Related
 /// - `repository_mark_all_notifications_as_read`
@@ -16,11 +19,21 @@ use tracing::debug;
 /// - Returns a DB/repository error if the update fails.
 pub async fn service_mark_all_notifications_as_read(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     session: &SessionContext,
 ) -> ServiceResult<u64> {
     let result = repository_mark_all_notifications_as_read(db, session.user_id).await?;
 
     debug!(user_id = %session.user_id, count = result.rows_affected, "Notifications marked as read");
 
+    if result.rows_affected > 0 {
+        publish_user_realtime_event(
+            nats_client,
+            session.user_id,
+            &UserRealtimeEvent::NotificationsChanged,
+        )
+        .await;
+    }
+
     Ok(result.rows_affected)
 }
@@ -1,6 +1,8 @@
 use crate::repository::notification::repository_mark_notification_as_read;
 use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
 use errors::errors::{Errors, ServiceResult};
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
 use sea_orm::DatabaseConnection;
 use tracing::debug;
 use uuid::Uuid;
@@ -8,8 +10,10 @@ use uuid::Uuid;
 /// Marks a single notification of the currently logged-in user as read.
 ///
 /// # Role
-/// - Transitions the notification to read state with a single UPDATE.
+/// - Transitions the notification, and every other unread member of its group, to
+///   read state with a single UPDATE.
 /// - Returns NotFound if the target does not exist or is already read.
+/// - Signals the user's open notification streams of the new unread count.
 ///
 /// # Related
 /// - `repository_mark_notification_as_read`
@@ -19,6 +23,7 @@ use uuid::Uuid;
 /// - Returns a DB/repository error if the update fails.
 pub async fn service_mark_notification_as_read(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     session: &SessionContext,
     notification_id: Uuid,
 ) -> ServiceResult<()> {
@@ -34,5 +39,12 @@ pub async fn service_mark_notification_as_read(
 
     debug!(user_id = %session.user_id, notification_id = %notification_id, "Notification marked as read");
 
+    publish_user_realtime_event(
+        nats_client,
+        session.user_id,
+        &UserRealtimeEvent::NotificationsChanged,
+    )
+    .await;
+
     Ok(())
 }
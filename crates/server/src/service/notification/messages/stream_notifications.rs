@@ -0,0 +1,169 @@
+use super::get_notifications::notification_responses;
+use crate::repository::notification::{
+    repository_count_unread_notifications, repository_find_notification_by_event_id,
+};
+use crate::service::auth::session::SessionService;
+use crate::service::auth::session_types::SessionContext;
+use crate::state::NatsClient;
+use async_nats::Message;
+use axum::response::sse::Event;
+use dto::notification::UnreadCountResponse;
+use errors::errors::{Errors, ServiceResult};
+use futures::{Stream, StreamExt, future, stream};
+use job_queue::realtime::UserRealtimeEvent;
+use job_queue::subjects::realtime_user_subject;
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use std::convert::Infallible;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+/// How often an open stream re-checks its session when no signal arrives.
+const SESSION_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// What the stream reacts to: a realtime signal, or the periodic session re-check.
+enum StreamInput {
+    Signal(Message),
+    SessionCheck,
+}
+
+/// Creates the currently logged-in user's notification SSE stream.
+///
+/// # Role
+/// - Subscribes to the user's realtime NATS subject, so a signal published by
+///   any server replica or the worker reaches the replica holding the stream.
+/// - Emits the current `unread_count` first, then for each signal re-reads the
+///   data from the database: a `notification` event for a new delivery, followed
+///   by the new `unread_count`.
+/// - Re-checks the session on every signal and every [`SESSION_RECHECK_INTERVAL`],
+///   and ends the stream once it was logged out, revoked or expired.
+///
+/// # Related
+/// - `job_queue::realtime::publish_user_realtime_event`
+/// - `SessionService::is_session_active`
+/// - `repository_find_notification_by_event_id`
+/// - `repository_count_unread_notifications`
+///
+/// # Errors
+/// - `Errors::EventStreamSubscribeFailed` if the NATS subscription fails.
+/// - Returns a DB/repository error if the initial count fails.
+pub async fn service_stream_notifications(
+    db: DatabaseConnection,
+    redis: RedisClient,
+    nats_client: &NatsClient,
+    session: &SessionContext,
+) -> ServiceResult<impl Stream<Item = Result<Event, Infallible>> + use<>> {
+    let user_id = session.user_id;
+    let session_id = session.session_id.clone();
+
+    let subscriber = nats_client
+        .subscribe(realtime_user_subject(user_id))
+        .await
+        .map_err(|_| Errors::EventStreamSubscribeFailed)?;
+
+    // Counted after subscribing, so a change landing in between is still signalled.
+    let initial = unread_count_event(&db, user_id).await?;
+
+    let session_checks = stream::unfold((), |()| async {
+        tokio::time::sleep(SESSION_RECHECK_INTERVAL).await;
+        Some((StreamInput::SessionCheck, ()))
+    });
+    let inputs = stream::select(subscriber.map(StreamInput::Signal), session_checks);
+
+    let updates = inputs
+        .then(move |input| {
+            let db = db.clone();
+            let redis = redis.clone();
+            let session_id = session_id.clone();
+            async move {
+                if !session_still_active(&redis, &session_id, user_id).await {
+                    return None;
+                }
+                match input {
+                    StreamInput::Signal(message) => {
+                        Some(realtime_events(&db, user_id, &message.payload).await)
+                    }
+                    StreamInput::SessionCheck => Some(Vec::new()),
+                }
+            }
+        })
+        .take_while(|events| future::ready(events.is_some()))
+        .flat_map(|events| stream::iter(events.unwrap_or_default()));
+
+    Ok(stream::once(async move { initial }).chain(updates).map(Ok))
+}
+
+/// Whether the stream may keep delivering. A Redis failure ends the stream too: the
+/// client reconnects and is authenticated afresh.
+async fn session_still_active(redis: &RedisClient, session_id: &str, user_id: Uuid) -> bool {
+    match SessionService::is_session_active(redis, session_id, user_id).await {
+        Ok(active) => active,
+        Err(e) => {
+            warn!(user_id = %user_id, error = ?e, "Failed to re-check notification stream session");
+            false
+        }
+    }
+}
+
+/// SSE events for one realtime signal. Failures are logged and yield nothing
+/// rather than ending the stream.
+async fn realtime_events(db: &DatabaseConnection, user_id: Uuid, payload: &[u8]) -> Vec<Event> {
+    let signal = match serde_json::from_slice::<UserRealtimeEvent>(payload) {
+        Ok(signal) => signal,
+        Err(e) => {
+            warn!(user_id = %user_id, error = %e, "Failed to deserialize user realtime event");
+            return Vec::new();
+        }
+    };
+
+    let mut events = Vec::new();
+
+    if let UserRealtimeEvent::NotificationCreated { event_id } = signal {
+        match notification_event(db, user_id, event_id).await {
+            Ok(Some(event)) => events.push(event),
+            Ok(None) => {}
+            Err(e) => {
+                warn!(user_id = %user_id, event_id = %event_id, error = ?e, "Failed to load streamed notification")
+            }
+        }
+    }
+
+    match unread_count_event(db, user_id).await {
+        Ok(event) => events.push(event),
+        Err(e) => warn!(user_id = %user_id, error = ?e, "Failed to count unread notifications"),
+    }
+
+    events
+}
+
+async fn notification_event(
+    db: &DatabaseConnection,
+    user_id: Uuid,
+    event_id: Uuid,
+) -> ServiceResult<Option<Event>> {
+    let Some(notification) =
+        repository_find_notification_by_event_id(db, user_id, event_id).await?
+    else {
+        return Ok(None);
+    };
+
+    let notification = notification_responses(db, user_id, vec![notification])
+        .await?
+        .pop();
+
+    Ok(notification.map(|notification| {
+        Event::default()
+            .event("notification")
+            .id(notification.id.to_string())
+            .data(serde_json::to_string(&notification).unwrap_or_default())
+    }))
+}
+
+async fn unread_count_event(db: &DatabaseConnection, user_id: Uuid) -> ServiceResult<Event> {
+    let count = repository_count_unread_notifications(db, user_id).await?;
+
+    Ok(Event::default()
+        .event("unread_count")
+        .data(serde_json::to_string(&UnreadCountResponse { count }).unwrap_or_default()))
+}
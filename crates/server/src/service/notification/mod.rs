@@ -1,9 +1,12 @@
 //! Notification service layer.
 //!
-//! Provides user notification message APIs and per-user notification preference APIs.
+//! Provides user notification message APIs, per-user notification preference APIs,
+//! Web Push subscription APIs and admin system announcement APIs.
 
+pub mod announcements;
 pub mod messages;
 pub mod notify;
 pub mod preferences;
+pub mod push;
 
 pub use notify::{notify_mentions, service_notify_user};
@@ -1,13 +1,17 @@
+use crate::bridge::worker_client::{send_notification_email, send_notification_push};
 use crate::repository::notification::{
     NotificationTarget, repository_create_notification,
     repository_find_notification_action_preference,
 };
+use crate::state::{NatsClient, WorkerClient};
 use constants::NotificationAction;
 use entity::common::NotificationType;
 use errors::errors::Errors;
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
 use sea_orm::DatabaseConnection;
 use sea_orm::prelude::IpNetwork;
 use serde_json::Value as JsonValue;
+use tracing::warn;
 use uuid::Uuid;
 
 /// The single server-side entry point for creating a directly-targeted
@@ -21,10 +25,16 @@ use uuid::Uuid;
 /// notification unconditionally, so disabling e.g. `user_mentioned` had no
 /// effect on mentions created on the server.
 ///
+/// Once created, the recipient's open notification streams are signalled over
+/// their realtime subject and the email and push copies are queued (all
+/// best-effort; the worker drops each unless the recipient turned that channel on).
+///
 /// Returns `Ok(false)` when the recipient has the action disabled (nothing
 /// created), `Ok(true)` when a notification was created.
 pub async fn service_notify_user(
     db: &DatabaseConnection,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     recipient_user_id: Uuid,
     actor_id: Option<Uuid>,
     actor_ip: Option<IpNetwork>,
@@ -41,7 +51,7 @@ pub async fn service_notify_user(
         return Ok(false);
     }
 
-    repository_create_notification(
+    let event_id = repository_create_notification(
         db,
         recipient_user_id,
         actor_id,
@@ -53,6 +63,22 @@ pub async fn service_notify_user(
     )
     .await?;
 
+    if let Some(event_id) = event_id {
+        publish_user_realtime_event(
+            nats_client,
+            recipient_user_id,
+            &UserRealtimeEvent::NotificationCreated { event_id },
+        )
+        .await;
+
+        if let Err(e) = send_notification_email(worker, event_id, vec![recipient_user_id]).await {
+            warn!(event_id = %event_id, error = ?e, "Failed to queue notification email job");
+        }
+        if let Err(e) = send_notification_push(worker, event_id, vec![recipient_user_id]).await {
+            warn!(event_id = %event_id, error = ?e, "Failed to queue notification push job");
+        }
+    }
+
     Ok(true)
 }
 
@@ -63,6 +89,8 @@ pub async fn service_notify_user(
 /// propagated) and preference-checked via `service_notify_user`.
 pub async fn notify_mentions(
     db: &DatabaseConnection,
+    worker: &WorkerClient,
+    nats_client: &NatsClient,
     mentioned_user_ids: impl IntoIterator<Item = Uuid>,
     exclude_user_id: Option<Uuid>,
     actor_id: Uuid,
@@ -76,6 +104,8 @@ pub async fn notify_mentions(
         }
         let _ = service_notify_user(
             db,
+            worker,
+            nats_client,
             user_id,
             Some(actor_id),
             actor_ip,
@@ -1,13 +1,14 @@
 use crate::repository::notification::repository_find_notification_preferences_by_user_id;
 use crate::service::auth::session_types::SessionContext;
 use dto::notification::NotificationPreferenceResponse;
+use entity::common::NotificationDigestFrequency;
 use errors::errors::ServiceResult;
 use sea_orm::DatabaseConnection;
 
 /// Fetches the current user's per-channel notification preferences.
 ///
 /// # Role
-/// Returns defaults (email/push disabled) if the user has no saved preferences.
+/// Returns defaults (email/push disabled, no digest) if the user has no saved preferences.
 ///
 /// # Related
 /// - `repository_find_notification_preferences_by_user_id`
@@ -26,11 +27,13 @@ pub async fn service_get_notification_preferences(
         Some(prefs) => NotificationPreferenceResponse {
             email_enabled: prefs.email_enabled,
             push_enabled: prefs.push_enabled,
+            digest_frequency: prefs.digest_frequency,
             updated_at: prefs.updated_at,
         },
         None => NotificationPreferenceResponse {
             email_enabled: false, // Default: email disabled
             push_enabled: false,  // Default: push disabled
+            digest_frequency: NotificationDigestFrequency::Off,
             updated_at: chrono::Utc::now(),
         },
     };
@@ -1,15 +1,18 @@
 //! Notification preference service APIs.
 //!
-//! Covers global notification channel preferences and per-action preferences.
+//! Covers global notification channel preferences, per-action preferences, and
+//! the token-based email unsubscribe.
 
 pub mod get_action_preferences;
 pub mod get_preferences;
+pub mod unsubscribe_email;
 pub mod update_action_preference;
 pub mod update_action_preferences_bulk;
 pub mod update_preferences;
 
 pub use get_action_preferences::service_get_notification_action_preferences;
 pub use get_preferences::service_get_notification_preferences;
+pub use unsubscribe_email::service_unsubscribe_notification_email;
 pub use update_action_preference::service_update_notification_action_preference;
 pub use update_action_preferences_bulk::service_update_action_preferences_bulk;
 pub use update_preferences::service_update_notification_preferences;
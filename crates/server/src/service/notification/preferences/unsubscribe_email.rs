@@ -0,0 +1,33 @@
+use crate::repository::notification::repository_disable_email_by_unsubscribe_token;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use tracing::info;
+use uuid::Uuid;
+
+/// Turns off notification emails and digests for whoever holds the unsubscribe `token`.
+///
+/// # Role
+/// Backs the one-click unsubscribe link (and `List-Unsubscribe` header) in
+/// notification and digest emails, so no session is involved: the token alone identifies the
+/// preference row. Idempotent.
+///
+/// # Related
+/// - `repository_disable_email_by_unsubscribe_token`
+///
+/// # Errors
+/// - `Errors::NotFound` if no preference row holds the token
+/// - Returns a DB/repository error if the update fails.
+pub async fn service_unsubscribe_notification_email(
+    db: &DatabaseConnection,
+    token: Uuid,
+) -> ServiceResult<()> {
+    let rows_affected = repository_disable_email_by_unsubscribe_token(db, token).await?;
+
+    if rows_affected == 0 {
+        return Err(Errors::NotFound("Unsubscribe link is invalid".to_string()));
+    }
+
+    info!("Notification emails disabled via unsubscribe link");
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use crate::repository::notification::{
 };
 use crate::service::auth::session_types::SessionContext;
 use dto::notification::NotificationPreferenceResponse;
+use entity::common::NotificationDigestFrequency;
 use errors::errors::ServiceResult;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 
@@ -29,6 +30,7 @@ pub async fn service_update_notification_preferences(
     session: &SessionContext,
     email_enabled: Option<bool>,
     push_enabled: Option<bool>,
+    digest_frequency: Option<NotificationDigestFrequency>,
 ) -> ServiceResult<NotificationPreferenceResponse> {
     let txn = db.begin().await?;
 
@@ -43,6 +45,7 @@ pub async fn service_update_notification_preferences(
             session.user_id,
             email_enabled,
             push_enabled,
+            digest_frequency,
         )
         .await?
     } else {
@@ -52,6 +55,7 @@ pub async fn service_update_notification_preferences(
             session.user_id,
             email_enabled.unwrap_or(false), // Default: email enabled
             push_enabled.unwrap_or(false),  // Default: push disabled
+            digest_frequency.unwrap_or(NotificationDigestFrequency::Off),
         )
         .await?
     };
@@ -61,6 +65,7 @@ pub async fn service_update_notification_preferences(
     Ok(NotificationPreferenceResponse {
         email_enabled: preferences.email_enabled,
         push_enabled: preferences.push_enabled,
+        digest_frequency: preferences.digest_frequency,
         updated_at: preferences.updated_at,
     })
 }
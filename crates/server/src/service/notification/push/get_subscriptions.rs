@@ -0,0 +1,30 @@
+use crate::repository::notification::repository_find_push_subscriptions_by_user_id;
+use crate::service::auth::session_types::SessionContext;
+use dto::notification::{PushSubscriptionListResponse, PushSubscriptionResponse};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+
+/// Lists the current user's Web Push devices, newest first.
+///
+/// # Related
+/// - `repository_find_push_subscriptions_by_user_id`
+///
+/// # Errors
+/// - Returns a DB/repository error if the lookup fails.
+pub async fn service_get_push_subscriptions(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+) -> ServiceResult<PushSubscriptionListResponse> {
+    let subscriptions = repository_find_push_subscriptions_by_user_id(db, session.user_id).await?;
+
+    Ok(PushSubscriptionListResponse {
+        data: subscriptions
+            .into_iter()
+            .map(|subscription| PushSubscriptionResponse {
+                id: subscription.id,
+                endpoint: subscription.endpoint,
+                created_at: subscription.created_at,
+            })
+            .collect(),
+    })
+}
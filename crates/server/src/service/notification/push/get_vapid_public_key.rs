@@ -0,0 +1,13 @@
+use config::ServerConfig;
+use dto::notification::VapidPublicKeyResponse;
+
+/// Returns the VAPID public key browsers subscribe with.
+///
+/// # Role
+/// Reads `VAPID_PUBLIC_KEY`; `None` means Web Push is not configured, and
+/// clients should not offer it.
+pub fn service_get_vapid_public_key() -> VapidPublicKeyResponse {
+    VapidPublicKeyResponse {
+        public_key: ServerConfig::get().vapid_public_key.clone(),
+    }
+}
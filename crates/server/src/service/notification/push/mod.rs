@@ -0,0 +1,14 @@
+//! Web Push service APIs.
+//!
+//! Covers the VAPID public key lookup and registering/removing the caller's
+//! push subscriptions (one per device). Delivery itself is done by the worker.
+
+pub mod get_subscriptions;
+pub mod get_vapid_public_key;
+pub mod subscribe;
+pub mod unsubscribe;
+
+pub use get_subscriptions::service_get_push_subscriptions;
+pub use get_vapid_public_key::service_get_vapid_public_key;
+pub use subscribe::service_subscribe_push;
+pub use unsubscribe::service_unsubscribe_push;
@@ -0,0 +1,69 @@
+use crate::repository::notification::{
+    repository_delete_push_subscriptions_by_ids, repository_find_push_subscriptions_by_user_id,
+    repository_upsert_push_subscription,
+};
+use crate::service::auth::session_types::SessionContext;
+use config::ServerConfig;
+use dto::notification::{PushSubscriptionResponse, SubscribePushRequest};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::debug;
+
+/// Devices kept per user; registering one more evicts the oldest.
+const MAX_PUSH_SUBSCRIPTIONS_PER_USER: usize = 10;
+
+/// Registers the caller's device for Web Push.
+///
+/// # Role
+/// - Upserts the subscription on its endpoint (re-subscribing rotates the keys).
+/// - Keeps at most `MAX_PUSH_SUBSCRIPTIONS_PER_USER` devices, evicting the oldest,
+///   so one account cannot make the worker push to an unbounded endpoint list.
+///
+/// Pushes are only sent while the user's `push_enabled` preference is on.
+///
+/// # Related
+/// - `repository_upsert_push_subscription`
+///
+/// # Errors
+/// - `Errors::BadRequestError` if Web Push is not configured (no VAPID key)
+/// - Returns a DB/repository error if the upsert fails.
+pub async fn service_subscribe_push(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+    payload: SubscribePushRequest,
+) -> ServiceResult<PushSubscriptionResponse> {
+    if ServerConfig::get().vapid_public_key.is_none() {
+        return Err(Errors::BadRequestError(
+            "Web Push is not configured on this server".to_string(),
+        ));
+    }
+
+    let txn = db.begin().await?;
+
+    let subscription = repository_upsert_push_subscription(
+        &txn,
+        session.user_id,
+        payload.endpoint,
+        payload.keys.p256dh,
+        payload.keys.auth,
+    )
+    .await?;
+
+    let evicted: Vec<_> = repository_find_push_subscriptions_by_user_id(&txn, session.user_id)
+        .await?
+        .into_iter()
+        .skip(MAX_PUSH_SUBSCRIPTIONS_PER_USER)
+        .map(|subscription| subscription.id)
+        .collect();
+    repository_delete_push_subscriptions_by_ids(&txn, evicted).await?;
+
+    txn.commit().await?;
+
+    debug!(user_id = %session.user_id, subscription_id = %subscription.id, "Push subscription registered");
+
+    Ok(PushSubscriptionResponse {
+        id: subscription.id,
+        endpoint: subscription.endpoint,
+        created_at: subscription.created_at,
+    })
+}
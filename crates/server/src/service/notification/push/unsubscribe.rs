@@ -0,0 +1,31 @@
+use crate::repository::notification::repository_delete_push_subscription;
+use crate::service::auth::session_types::SessionContext;
+use dto::notification::UnsubscribePushRequest;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use tracing::debug;
+
+/// Removes one of the caller's Web Push devices.
+///
+/// # Related
+/// - `repository_delete_push_subscription`
+///
+/// # Errors
+/// - `Errors::NotFound` if the caller has no subscription with this endpoint
+/// - Returns a DB/repository error if the deletion fails.
+pub async fn service_unsubscribe_push(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+    payload: UnsubscribePushRequest,
+) -> ServiceResult<()> {
+    let rows_affected =
+        repository_delete_push_subscription(db, session.user_id, &payload.endpoint).await?;
+
+    if rows_affected == 0 {
+        return Err(Errors::NotFound("Push subscription not found".to_string()));
+    }
+
+    debug!(user_id = %session.user_id, "Push subscription removed");
+
+    Ok(())
+}
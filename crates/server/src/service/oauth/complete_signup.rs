@@ -1,22 +1,26 @@
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::oauth::create_oauth_connection::repository_create_oauth_connection;
 use crate::repository::oauth::create_oauth_user::repository_create_oauth_user;
 use crate::repository::oauth::find_user_by_oauth::repository_find_user_by_oauth;
 use crate::repository::user::find_by_email::repository_find_user_by_email;
 use crate::repository::user::find_by_handle::repository_find_user_by_handle;
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session::SessionService;
 use crate::service::auth::verify_email::{
     find_pending_email_signup_by_email, find_pending_email_signup_by_handle,
 };
 use crate::service::oauth::types::PendingSignupTokenState;
 use crate::service::user::utils::{spawn_index_user, spawn_oauth_profile_image};
-use crate::state::WorkerClient;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::crypto::token::hash_token;
 use crate::utils::redis_cache::set_json_with_ttl;
-use constants::{oauth_pending_key, oauth_pending_lock_key};
+use constants::{ActionLogAction, oauth_pending_key, oauth_pending_lock_key};
+use entity::common::ActionResourceType;
 use errors::errors::{Errors, ServiceResult};
 use redis::AsyncCommands;
 use redis::aio::ConnectionManager;
 use sea_orm::{ConnectionTrait, TransactionSession, TransactionTrait};
+use serde_json::json;
 use std::sync::LazyLock;
 use tracing::{info, warn};
 use uuid::Uuid;
@@ -31,6 +35,7 @@ pub async fn service_complete_signup<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     pending_token: &str,
     handle: &str,
     display_name: &str,
@@ -203,15 +208,27 @@ where
             )
             .await?;
 
+            let action_log = repository_create_action_log(
+                &txn,
+                ActionLogAction::UserCreate,
+                Some(new_user.id),
+                ActionResourceType::User,
+                Some(new_user.id),
+                format!("Created account @{}", new_user.handle),
+                Some(json!({ "method": "oauth", "provider": provider })),
+            )
+            .await?;
+
             txn.commit().await?;
-            Ok(new_user)
+            Ok((new_user, action_log))
         }
         .await;
 
-        let new_user = match create_result {
-            Ok(new_user) => new_user,
+        let (new_user, action_log) = match create_result {
+            Ok(created) => created,
             Err(err) => return Err(err),
         };
+        publish_action_log(nats_client, &action_log).await;
 
         // 5. Mark the token completed briefly so retries can issue a session.
         store_completed_signup_state(
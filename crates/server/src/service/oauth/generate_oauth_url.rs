@@ -29,6 +29,7 @@ pub async fn service_generate_oauth_url<P: OAuthProviderConfig>(
         flow,
         provider,
         anonymous_user_id: anonymous_user_id.to_string(),
+        nonce: None,
     };
     // Key by the hashed state (hash-at-rest, like every other short-lived
     // credential): the payload carries the plaintext PKCE verifier, so a Redis
@@ -2,6 +2,7 @@ use super::{GithubProvider, fetch_github_user_emails, fetch_github_user_info};
 use crate::service::oauth::provider::client::exchange_code;
 use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
 use crate::service::oauth::types::OAuthStateData;
+use crate::state::NatsClient;
 use crate::utils::crypto::token::hash_token;
 use crate::utils::redis_cache::get_json_and_delete;
 use constants::oauth_state_key;
@@ -19,6 +20,7 @@ use sea_orm::ConnectionTrait;
 pub async fn service_github_sign_in<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     http_client: &reqwest::Client,
     code: &str,
     state: &str,
@@ -72,6 +74,7 @@ where
     resolve_oauth_sign_in(
         conn,
         redis_conn,
+        nats_client,
         OAuthProvider::Github,
         &user_info.id.to_string(),
         email,
@@ -1,5 +1,6 @@
 use super::{fetch_github_user_emails, verify_github_token};
 use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
+use crate::state::NatsClient;
 use dto::oauth::internal::SignInResult;
 use entity::common::OAuthProvider;
 use errors::errors::{Errors, ServiceResult};
@@ -18,6 +19,7 @@ use sea_orm::ConnectionTrait;
 pub async fn service_github_token_sign_in<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     http_client: &reqwest::Client,
     access_token: &str,
     user_agent: Option<String>,
@@ -43,6 +45,7 @@ where
     resolve_oauth_sign_in(
         conn,
         redis_conn,
+        nats_client,
         OAuthProvider::Github,
         &user_info.id.to_string(),
         email,
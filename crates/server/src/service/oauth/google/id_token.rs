@@ -181,7 +181,7 @@ async fn fetch_google_jwks(http_client: &reqwest::Client) -> ServiceResult<(JwkS
     Ok((jwks, cache_ttl_seconds))
 }
 
-fn parse_cache_control_max_age(cache_control: &str) -> Option<u64> {
+pub(crate) fn parse_cache_control_max_age(cache_control: &str) -> Option<u64> {
     cache_control.split(',').find_map(|directive| {
         directive
             .trim()
@@ -1,23 +1,28 @@
 use super::{GoogleProvider, fetch_google_user_info};
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::oauth::create_oauth_connection::repository_create_oauth_connection;
 use crate::repository::oauth::find_oauth_connection::repository_find_oauth_connection;
 use crate::repository::oauth::find_user_by_oauth::repository_find_user_by_oauth;
+use crate::service::action_logs::publish_action_log;
 use crate::service::oauth::provider::client::exchange_code;
 use crate::service::oauth::types::OAuthStateData;
+use crate::state::NatsClient;
 use crate::utils::crypto::token::hash_token;
 use crate::utils::redis_cache::get_json_and_delete;
-use constants::oauth_state_key;
+use constants::{ActionLogAction, oauth_state_key};
 use dto::oauth::request::OAuthAuthorizeFlow;
-use entity::common::OAuthProvider;
+use entity::common::{ActionResourceType, OAuthProvider};
 use errors::errors::{Errors, ServiceResult};
 use redis::aio::ConnectionManager;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use uuid::Uuid;
 
 /// Links Google OAuth to an existing account.
 pub async fn service_link_google_oauth(
     db: &DatabaseConnection,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     http_client: &reqwest::Client,
     user_id: Uuid,
     code: &str,
@@ -77,7 +82,19 @@ pub async fn service_link_google_oauth(
     repository_create_oauth_connection(&txn, &user_id, OAuthProvider::Google, &user_info.id)
         .await?;
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::OAuthLink,
+        Some(user_id),
+        ActionResourceType::User,
+        Some(user_id),
+        "Linked Google account".to_string(),
+        Some(json!({ "provider": OAuthProvider::Google })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     Ok(())
 }
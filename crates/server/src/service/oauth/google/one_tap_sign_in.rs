@@ -1,5 +1,6 @@
 use super::id_token::verify_google_id_token;
 use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
+use crate::state::NatsClient;
 use crate::utils::crypto::token::hash_token;
 use crate::utils::redis_cache::get_json_and_delete;
 use dto::oauth::internal::SignInResult;
@@ -13,6 +14,7 @@ use tracing::debug;
 pub async fn service_google_one_tap_sign_in<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     http_client: &reqwest::Client,
     credential: &str,
     anonymous_user_id: &str,
@@ -49,6 +51,7 @@ where
     resolve_oauth_sign_in(
         conn,
         redis_conn,
+        nats_client,
         OAuthProvider::Google,
         &claims.sub,
         claims.email,
@@ -2,6 +2,7 @@ use super::{GoogleProvider, fetch_google_user_info};
 use crate::service::oauth::provider::client::exchange_code;
 use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
 use crate::service::oauth::types::OAuthStateData;
+use crate::state::NatsClient;
 use crate::utils::crypto::token::hash_token;
 use crate::utils::redis_cache::get_json_and_delete;
 use constants::oauth_state_key;
@@ -19,6 +20,7 @@ use sea_orm::ConnectionTrait;
 pub async fn service_google_sign_in<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     http_client: &reqwest::Client,
     code: &str,
     state: &str,
@@ -64,6 +66,7 @@ where
     resolve_oauth_sign_in(
         conn,
         redis_conn,
+        nats_client,
         OAuthProvider::Google,
         &user_info.id,
         user_info.email,
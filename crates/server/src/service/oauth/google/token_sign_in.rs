@@ -1,5 +1,6 @@
 use super::id_token::verify_google_id_token;
 use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
+use crate::state::NatsClient;
 use dto::oauth::internal::SignInResult;
 use entity::common::OAuthProvider;
 use errors::errors::ServiceResult;
@@ -16,6 +17,7 @@ use sea_orm::ConnectionTrait;
 pub async fn service_google_token_sign_in<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     http_client: &reqwest::Client,
     id_token: &str,
     user_agent: Option<String>,
@@ -29,6 +31,7 @@ where
     resolve_oauth_sign_in(
         conn,
         redis_conn,
+        nats_client,
         OAuthProvider::Google,
         &claims.sub,
         claims.email,
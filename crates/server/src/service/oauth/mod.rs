@@ -3,6 +3,7 @@ pub mod generate_oauth_url;
 pub mod github;
 pub mod google;
 pub mod list_connections;
+pub mod oidc;
 pub mod provider;
 pub mod resolve_sign_in;
 pub mod types;
@@ -0,0 +1,204 @@
+use super::OidcProvider;
+use super::discovery::OidcMetadata;
+use super::id_token::OidcIdTokenClaims;
+use errors::errors::{Errors, ServiceResult};
+use oauth2::basic::{
+    BasicErrorResponse, BasicRevocationErrorResponse, BasicTokenIntrospectionResponse,
+    BasicTokenType,
+};
+use oauth2::{
+    AuthType, AuthUrl, AuthorizationCode, Client, ClientId, ClientSecret, CsrfToken,
+    EndpointNotSet, EndpointSet, ExtraTokenFields, PkceCodeChallenge, PkceCodeVerifier,
+    RedirectUrl, Scope, StandardRevocableToken, StandardTokenResponse, TokenResponse, TokenUrl,
+};
+use oauth2_reqwest::ReqwestClient;
+use serde::{Deserialize, Serialize};
+
+/// The OIDC token response adds `id_token` to the OAuth 2.0 fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcIdTokenField {
+    pub id_token: Option<String>,
+}
+
+impl ExtraTokenFields for OidcIdTokenField {}
+
+type OidcTokenResponse = StandardTokenResponse<OidcIdTokenField, BasicTokenType>;
+
+type OidcClient = Client<
+    BasicErrorResponse,
+    OidcTokenResponse,
+    BasicTokenIntrospectionResponse,
+    StandardRevocableToken,
+    BasicRevocationErrorResponse,
+    EndpointSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointSet,
+>;
+
+/// Tokens returned by the provider's token endpoint.
+pub struct OidcTokens {
+    pub id_token: String,
+    pub access_token: String,
+}
+
+/// User info fields used when the ID token omits the email.
+#[derive(Debug, Deserialize)]
+struct OidcUserInfo {
+    sub: String,
+    email: Option<String>,
+    email_verified: Option<bool>,
+    picture: Option<String>,
+}
+
+fn build_client(provider: &OidcProvider, metadata: &OidcMetadata) -> ServiceResult<OidcClient> {
+    let config = provider.config;
+
+    let auth_url = AuthUrl::new(metadata.discovery.authorization_endpoint.clone())
+        .map_err(|_| Errors::OauthInvalidAuthUrl)?;
+    let token_url = TokenUrl::new(metadata.discovery.token_endpoint.clone())
+        .map_err(|_| Errors::OauthInvalidTokenUrl)?;
+    let redirect_url = RedirectUrl::new(config.redirect_uri.clone())
+        .map_err(|_| Errors::OauthInvalidRedirectUrl)?;
+
+    // `client_secret_basic` is the OIDC default; fall back to the request body only for a
+    // provider that advertises `client_secret_post` alone.
+    let methods = &metadata.discovery.token_endpoint_auth_methods_supported;
+    let auth_type = if methods.iter().any(|m| m == "client_secret_post")
+        && !methods.iter().any(|m| m == "client_secret_basic")
+    {
+        AuthType::RequestBody
+    } else {
+        AuthType::BasicAuth
+    };
+
+    Ok(Client::new(ClientId::new(config.client_id.clone()))
+        .set_client_secret(ClientSecret::new(config.client_secret.clone()))
+        .set_auth_uri(auth_url)
+        .set_token_uri(token_url)
+        .set_redirect_uri(redirect_url)
+        .set_auth_type(auth_type))
+}
+
+/// Builds the authorization URL with PKCE (S256) and a `nonce`.
+/// Returns: (auth_url, pkce_verifier)
+pub fn generate_oidc_auth_url(
+    provider: &OidcProvider,
+    metadata: &OidcMetadata,
+    state: String,
+    nonce: &str,
+) -> ServiceResult<(String, String)> {
+    let client = build_client(provider, metadata)?;
+    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+
+    let mut auth_request = client.authorize_url(|| CsrfToken::new(state));
+    for scope in &provider.config.scopes {
+        auth_request = auth_request.add_scope(Scope::new(scope.clone()));
+    }
+    let (auth_url, _csrf_token) = auth_request
+        .add_extra_param("nonce", nonce)
+        .set_pkce_challenge(pkce_challenge)
+        .url();
+
+    Ok((auth_url.to_string(), pkce_verifier.secret().clone()))
+}
+
+/// Exchanges an authorization code for the ID token and access token.
+///
+/// # Errors
+/// - `Errors::OauthTokenExchangeFailed` if the exchange fails or no ID token is returned
+pub async fn exchange_oidc_code(
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    metadata: &OidcMetadata,
+    code: &str,
+    pkce_verifier: &str,
+) -> ServiceResult<OidcTokens> {
+    let client = build_client(provider, metadata)?;
+
+    let oauth_http = ReqwestClient::from(http_client.clone());
+    let token_result = client
+        .exchange_code(AuthorizationCode::new(code.to_string()))
+        .set_pkce_verifier(PkceCodeVerifier::new(pkce_verifier.to_string()))
+        .request_async(&oauth_http)
+        .await
+        .map_err(|_| Errors::OauthTokenExchangeFailed)?;
+
+    let id_token = token_result
+        .extra_fields()
+        .id_token
+        .clone()
+        .ok_or(Errors::OauthTokenExchangeFailed)?;
+
+    Ok(OidcTokens {
+        id_token,
+        access_token: token_result.access_token().secret().clone(),
+    })
+}
+
+/// Fills in email (and picture) from the userinfo endpoint when the ID token carries none.
+///
+/// Some IdPs keep the ID token minimal and serve profile claims from userinfo only. The
+/// response must describe the same subject as the verified ID token.
+pub async fn complete_claims_from_userinfo(
+    http_client: &reqwest::Client,
+    metadata: &OidcMetadata,
+    access_token: &str,
+    mut claims: OidcIdTokenClaims,
+) -> ServiceResult<OidcIdTokenClaims> {
+    if claims.email.is_some() {
+        return Ok(claims);
+    }
+    let Some(userinfo_endpoint) = metadata.discovery.userinfo_endpoint.as_deref() else {
+        return Ok(claims);
+    };
+
+    let response = http_client
+        .get(userinfo_endpoint)
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .map_err(|_| Errors::OauthUserInfoFetchFailed)?;
+    if !response.status().is_success() {
+        return Err(Errors::OauthUserInfoFetchFailed);
+    }
+    let response_text = response
+        .text()
+        .await
+        .map_err(|_| Errors::OauthUserInfoFetchFailed)?;
+    let user_info = serde_json::from_str::<OidcUserInfo>(&response_text)
+        .map_err(|_| Errors::OauthUserInfoParseFailed(response_text))?;
+
+    // OIDC Core §5.3.2: the userinfo `sub` must match the ID token's, or it is discarded.
+    if user_info.sub != claims.sub {
+        return Err(Errors::OidcInvalidIdToken);
+    }
+
+    claims.email = user_info.email;
+    claims.email_verified = user_info.email_verified;
+    claims.picture = claims.picture.or(user_info.picture);
+    Ok(claims)
+}
+
+/// Returns the identity's email if the provider vouches for it.
+///
+/// `email_verified: true` is required; an IdP that never sends the claim (e.g. Azure AD, whose
+/// tenant owns the addresses) can be trusted explicitly with `OIDC_<SLUG>_TRUST_EMAIL`.
+///
+/// # Errors
+/// - `Errors::OauthEmailNotVerified` if there is no email or it is not verified
+pub fn verified_email(
+    provider: &OidcProvider,
+    claims: &OidcIdTokenClaims,
+) -> ServiceResult<String> {
+    let verified = match claims.email_verified {
+        Some(verified) => verified,
+        None => provider.config.trust_email,
+    };
+
+    match &claims.email {
+        Some(email) if verified && !email.is_empty() => Ok(email.clone()),
+        _ => Err(Errors::OauthEmailNotVerified),
+    }
+}
@@ -0,0 +1,165 @@
+use super::OidcProvider;
+use crate::service::oauth::google::id_token::parse_cache_control_max_age;
+use errors::errors::{Errors, ServiceResult};
+use jsonwebtoken::jwk::JwkSet;
+use reqwest::header::{CACHE_CONTROL, HeaderValue};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
+use tracing::debug;
+
+const DEFAULT_METADATA_CACHE_TTL_SECONDS: u64 = 300;
+/// Minimum spacing between *forced* refreshes per provider (the unknown-`kid` retry path), as for
+/// Google: `kid` is attacker-controlled, so it must not drive unbounded fetches to the IdP.
+const FORCED_REFRESH_MIN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Discovery document and signing keys, cached per provider slug.
+static OIDC_METADATA_CACHE: LazyLock<RwLock<HashMap<String, CachedOidcMetadata>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+static OIDC_METADATA_REFRESH_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+static OIDC_FORCED_REFRESH_AT: LazyLock<Mutex<HashMap<String, Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The parts of `/.well-known/openid-configuration` the sign-in flows use.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcDiscovery {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: Option<String>,
+    pub jwks_uri: String,
+    #[serde(default)]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+}
+
+/// A provider's discovery document together with its current JWKS.
+#[derive(Debug, Clone)]
+pub struct OidcMetadata {
+    pub discovery: OidcDiscovery,
+    pub jwks: JwkSet,
+}
+
+#[derive(Debug, Clone)]
+struct CachedOidcMetadata {
+    metadata: OidcMetadata,
+    expires_at: Instant,
+}
+
+/// Returns the provider's metadata and whether it came from the cache.
+///
+/// Both documents are refreshed together, on the JWKS `Cache-Control: max-age` (default five
+/// minutes). `force_refresh` is for a token signed with an unknown `kid` (key rotation) and is
+/// throttled per provider.
+///
+/// # Errors
+/// - `Errors::OidcDiscoveryFailed` if the discovery document cannot be fetched, parsed, or names
+///   a different issuer than configured
+/// - `Errors::OidcJwksFetchFailed` if the JWKS cannot be fetched or parsed
+pub async fn get_oidc_metadata(
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    force_refresh: bool,
+) -> ServiceResult<(OidcMetadata, bool)> {
+    let slug = provider.config.slug.as_str();
+
+    if !force_refresh && let Some(metadata) = cached_metadata(slug).await {
+        return Ok((metadata, true));
+    }
+
+    let _refresh_guard = OIDC_METADATA_REFRESH_LOCK.lock().await;
+
+    if force_refresh {
+        let mut forced_at = OIDC_FORCED_REFRESH_AT.lock().await;
+        let within_window = matches!(
+            forced_at.get(slug),
+            Some(at) if Instant::now() < *at + FORCED_REFRESH_MIN_INTERVAL
+        );
+        if within_window && let Some(cached) = OIDC_METADATA_CACHE.read().await.get(slug) {
+            return Ok((cached.metadata.clone(), true));
+        }
+        forced_at.insert(slug.to_string(), Instant::now());
+    } else if let Some(metadata) = cached_metadata(slug).await {
+        // Another request refreshed while we waited for the lock.
+        return Ok((metadata, true));
+    }
+
+    let (metadata, cache_ttl_seconds) = fetch_oidc_metadata(http_client, provider).await?;
+
+    OIDC_METADATA_CACHE.write().await.insert(
+        slug.to_string(),
+        CachedOidcMetadata {
+            metadata: metadata.clone(),
+            expires_at: Instant::now() + Duration::from_secs(cache_ttl_seconds),
+        },
+    );
+
+    Ok((metadata, false))
+}
+
+async fn cached_metadata(slug: &str) -> Option<OidcMetadata> {
+    let cache = OIDC_METADATA_CACHE.read().await;
+    cache
+        .get(slug)
+        .filter(|cached| Instant::now() < cached.expires_at)
+        .map(|cached| cached.metadata.clone())
+}
+
+async fn fetch_oidc_metadata(
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+) -> ServiceResult<(OidcMetadata, u64)> {
+    let issuer = provider.config.issuer.as_str();
+    let discovery_url = format!(
+        "{}/.well-known/openid-configuration",
+        issuer.trim_end_matches('/')
+    );
+
+    let response = http_client
+        .get(&discovery_url)
+        .send()
+        .await
+        .map_err(|_| Errors::OidcDiscoveryFailed)?;
+    if !response.status().is_success() {
+        return Err(Errors::OidcDiscoveryFailed);
+    }
+    let discovery = response
+        .json::<OidcDiscovery>()
+        .await
+        .map_err(|_| Errors::OidcDiscoveryFailed)?;
+
+    // OpenID Connect Discovery §4.3: the document must name the issuer it was fetched for.
+    // Tokens are later pinned to this value, so a mismatch is a misconfiguration, not a retry.
+    if discovery.issuer != issuer {
+        debug!(
+            configured = %issuer,
+            discovered = %discovery.issuer,
+            "OIDC discovery issuer mismatch"
+        );
+        return Err(Errors::OidcDiscoveryFailed);
+    }
+
+    let response = http_client
+        .get(&discovery.jwks_uri)
+        .send()
+        .await
+        .map_err(|_| Errors::OidcJwksFetchFailed)?;
+    if !response.status().is_success() {
+        return Err(Errors::OidcJwksFetchFailed);
+    }
+
+    let cache_ttl_seconds = response
+        .headers()
+        .get(CACHE_CONTROL)
+        .and_then(|value: &HeaderValue| value.to_str().ok())
+        .and_then(parse_cache_control_max_age)
+        .unwrap_or(DEFAULT_METADATA_CACHE_TTL_SECONDS);
+
+    let jwks = response
+        .json::<JwkSet>()
+        .await
+        .map_err(|_| Errors::OidcJwksFetchFailed)?;
+
+    Ok((OidcMetadata { discovery, jwks }, cache_ttl_seconds))
+}
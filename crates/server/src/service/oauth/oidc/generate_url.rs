@@ -0,0 +1,47 @@
+use super::OidcProvider;
+use super::client::generate_oidc_auth_url;
+use super::discovery::get_oidc_metadata;
+use crate::service::oauth::types::OAuthStateData;
+use crate::utils::crypto::token::{generate_secure_token, hash_token};
+use crate::utils::redis_cache::store_json_for_token_with_ttl;
+use dto::oauth::request::OAuthAuthorizeFlow;
+use dto::oauth::response::OAuthUrlResponse;
+use errors::errors::ServiceResult;
+use redis::aio::ConnectionManager;
+
+/// Generates an OIDC authorization URL and stores the state in Redis.
+///
+/// Same state handling as `service_generate_oauth_url`, plus a single-use `nonce` kept with
+/// the state: the ID token must echo it, binding the token to this authorization request.
+pub async fn service_generate_oidc_oauth_url(
+    redis_conn: &ConnectionManager,
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    anonymous_user_id: &str,
+    flow: OAuthAuthorizeFlow,
+) -> ServiceResult<OAuthUrlResponse> {
+    let (metadata, _) = get_oidc_metadata(http_client, provider, false).await?;
+
+    let state = generate_secure_token();
+    let nonce = generate_secure_token();
+    let (auth_url, pkce_verifier) =
+        generate_oidc_auth_url(provider, &metadata, state.clone(), &nonce)?;
+
+    let state_data = OAuthStateData {
+        pkce_verifier,
+        flow,
+        provider: provider.provider.clone(),
+        anonymous_user_id: anonymous_user_id.to_string(),
+        nonce: Some(nonce),
+    };
+    store_json_for_token_with_ttl(
+        redis_conn,
+        &state,
+        |token| constants::oauth_state_key(&hash_token(token)),
+        &state_data,
+        constants::OAUTH_STATE_TTL_SECONDS,
+    )
+    .await?;
+
+    Ok(OAuthUrlResponse { auth_url })
+}
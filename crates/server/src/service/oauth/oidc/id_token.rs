@@ -0,0 +1,116 @@
+use super::OidcProvider;
+use super::discovery::get_oidc_metadata;
+use errors::errors::{Errors, ServiceResult};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use serde::Deserialize;
+use tracing::debug;
+
+/// Asymmetric algorithms accepted for ID tokens. HMAC (`HS*`, keyed with the client secret) is
+/// excluded: a leaked secret would otherwise let anyone mint identities.
+const ALLOWED_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+/// Verified claims from an OIDC ID token.
+#[derive(Debug, Deserialize)]
+pub struct OidcIdTokenClaims {
+    pub sub: String,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub picture: Option<String>,
+    pub nonce: Option<String>,
+}
+
+/// Verify an OIDC ID token against the provider's discovered JWKS: signature, issuer (pinned to
+/// the discovery document), audience (our client id), and expiry.
+///
+/// `expected_nonce` is the nonce stored with the OAuth state for redirect flows; the token must
+/// echo it. The native-app token flow passes `None`, relying on audience + signature + expiry
+/// like the Google token flow.
+///
+/// # Errors
+/// - `Errors::OidcInvalidIdToken` for any verification failure
+/// - `Errors::OidcDiscoveryFailed` / `Errors::OidcJwksFetchFailed` if metadata is unavailable
+pub async fn verify_oidc_id_token(
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    id_token: &str,
+    expected_nonce: Option<&str>,
+) -> ServiceResult<OidcIdTokenClaims> {
+    let header = decode_header(id_token).map_err(|e| {
+        debug!(error = %e, "Failed to decode OIDC ID token header");
+        Errors::OidcInvalidIdToken
+    })?;
+    if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+        debug!(alg = ?header.alg, "OIDC ID token signed with a disallowed algorithm");
+        return Err(Errors::OidcInvalidIdToken);
+    }
+
+    let (metadata, from_cache) = get_oidc_metadata(http_client, provider, false).await?;
+    let (metadata, jwk) = match find_signing_key(&metadata.jwks, header.kid.as_deref()) {
+        Some(jwk) => {
+            let jwk = jwk.clone();
+            (metadata, jwk)
+        }
+        // The IdP can rotate keys before our cached JWKS expires; retry once with a refresh.
+        None if from_cache => {
+            let (refreshed, _) = get_oidc_metadata(http_client, provider, true).await?;
+            let jwk = find_signing_key(&refreshed.jwks, header.kid.as_deref())
+                .cloned()
+                .ok_or_else(|| {
+                    debug!(kid = ?header.kid, "kid not found in refreshed OIDC JWKS");
+                    Errors::OidcInvalidIdToken
+                })?;
+            (refreshed, jwk)
+        }
+        None => {
+            debug!(kid = ?header.kid, "kid not found in freshly fetched OIDC JWKS");
+            return Err(Errors::OidcInvalidIdToken);
+        }
+    };
+
+    let decoding_key = DecodingKey::from_jwk(&jwk).map_err(|e| {
+        debug!(error = %e, "Failed to build decoding key from OIDC JWK");
+        Errors::OidcInvalidIdToken
+    })?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+    validation.validate_nbf = true;
+    validation.set_issuer(&[metadata.discovery.issuer.as_str()]);
+    validation.set_audience(&[provider.config.client_id.as_str()]);
+
+    let claims = decode::<OidcIdTokenClaims>(id_token, &decoding_key, &validation)
+        .map_err(|e| {
+            debug!(error = %e, "OIDC ID token validation failed");
+            Errors::OidcInvalidIdToken
+        })?
+        .claims;
+
+    if let Some(expected) = expected_nonce
+        && claims.nonce.as_deref() != Some(expected)
+    {
+        debug!("OIDC ID token nonce does not match the authorization request");
+        return Err(Errors::OidcInvalidIdToken);
+    }
+
+    Ok(claims)
+}
+
+/// Picks the JWK for a token: by `kid`, or the only key when the token names none.
+fn find_signing_key<'a>(jwks: &'a JwkSet, kid: Option<&str>) -> Option<&'a Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+}
@@ -0,0 +1,108 @@
+use super::OidcProvider;
+use super::client::{complete_claims_from_userinfo, exchange_oidc_code, verified_email};
+use super::discovery::get_oidc_metadata;
+use super::id_token::verify_oidc_id_token;
+use crate::repository::action_logs::repository_create_action_log;
+use crate::repository::oauth::create_oauth_connection::repository_create_oauth_connection;
+use crate::repository::oauth::find_oauth_connection::repository_find_oauth_connection;
+use crate::repository::oauth::find_user_by_oauth::repository_find_user_by_oauth;
+use crate::service::action_logs::publish_action_log;
+use crate::service::oauth::types::OAuthStateData;
+use crate::state::NatsClient;
+use crate::utils::crypto::token::hash_token;
+use crate::utils::redis_cache::get_json_and_delete;
+use constants::{ActionLogAction, oauth_state_key};
+use dto::oauth::request::OAuthAuthorizeFlow;
+use entity::common::ActionResourceType;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use uuid::Uuid;
+
+/// Links an OIDC provider to an existing account.
+pub async fn service_link_oidc_oauth(
+    db: &DatabaseConnection,
+    redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    user_id: Uuid,
+    code: &str,
+    state: &str,
+    anonymous_user_id: &str,
+) -> ServiceResult<()> {
+    // 1. Validate state and retrieve PKCE verifier + nonce from Redis (single-use via get_del)
+    let state_key = oauth_state_key(&hash_token(state));
+    let state_data: OAuthStateData = get_json_and_delete(
+        redis_conn,
+        &state_key,
+        || Errors::OauthInvalidState,
+        |_| Errors::OauthInvalidState,
+    )
+    .await?;
+
+    if state_data.provider != provider.provider
+        || state_data.flow != OAuthAuthorizeFlow::Link
+        || state_data.anonymous_user_id != anonymous_user_id
+    {
+        return Err(Errors::OauthInvalidState);
+    }
+    let nonce = state_data.nonce.ok_or(Errors::OauthInvalidState)?;
+
+    // 2. Exchange the authorization code, then verify the ID token (signature, iss, aud, nonce)
+    let (metadata, _) = get_oidc_metadata(http_client, provider, false).await?;
+    let tokens = exchange_oidc_code(
+        http_client,
+        provider,
+        &metadata,
+        code,
+        &state_data.pkce_verifier,
+    )
+    .await?;
+    let claims =
+        verify_oidc_id_token(http_client, provider, &tokens.id_token, Some(&nonce)).await?;
+    let claims =
+        complete_claims_from_userinfo(http_client, &metadata, &tokens.access_token, claims).await?;
+
+    // 3. Same email requirement as sign-in, so a linked identity can always sign in later
+    verified_email(provider, &claims)?;
+
+    let txn = db.begin().await?;
+
+    // 4. Check if already linked to another account
+    if repository_find_user_by_oauth(&txn, provider.provider.clone(), &claims.sub)
+        .await?
+        .is_some()
+    {
+        return Err(Errors::OauthAccountAlreadyLinked);
+    }
+
+    // 5. Check if this provider is already linked to the current user
+    if repository_find_oauth_connection(&txn, user_id, provider.provider.clone())
+        .await?
+        .is_some()
+    {
+        return Err(Errors::OauthAccountAlreadyLinked);
+    }
+
+    // 6. Create OAuth connection
+    repository_create_oauth_connection(&txn, &user_id, provider.provider.clone(), &claims.sub)
+        .await?;
+
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::OAuthLink,
+        Some(user_id),
+        ActionResourceType::User,
+        Some(user_id),
+        format!("Linked {:?} account", provider.provider),
+        Some(json!({ "provider": provider.provider })),
+    )
+    .await?;
+
+    txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+pub mod client;
+pub mod discovery;
+pub mod generate_url;
+pub mod id_token;
+pub mod link;
+pub mod provider;
+pub mod sign_in;
+pub mod token_sign_in;
+
+pub use generate_url::service_generate_oidc_oauth_url;
+pub use link::service_link_oidc_oauth;
+pub use provider::{OidcProvider, oidc_provider};
+pub use sign_in::service_oidc_sign_in;
+pub use token_sign_in::service_oidc_token_sign_in;
@@ -0,0 +1,43 @@
+use config::{OidcProviderConfig, ServerConfig};
+use entity::common::OAuthProvider;
+use errors::errors::{Errors, ServiceResult};
+
+/// A configured OpenID Connect provider, resolved from the `{provider}` route segment.
+///
+/// Unlike Google and GitHub (compile-time [`OAuthProviderConfig`] impls), OIDC providers are
+/// listed in `OIDC_PROVIDERS` and discover their endpoints at runtime, so one set of flows
+/// serves all of them.
+///
+/// [`OAuthProviderConfig`]: crate::service::oauth::provider::config::OAuthProviderConfig
+#[derive(Debug, Clone)]
+pub struct OidcProvider {
+    /// `oauth_provider` value the provider's identities are stored under.
+    pub provider: OAuthProvider,
+    pub config: &'static OidcProviderConfig,
+}
+
+/// Maps a route slug to its `oauth_provider` value. Google and GitHub have dedicated routes
+/// and are deliberately absent.
+fn provider_for_slug(slug: &str) -> Option<OAuthProvider> {
+    match slug {
+        "discord" => Some(OAuthProvider::Discord),
+        "microsoft" => Some(OAuthProvider::Microsoft),
+        "sso" => Some(OAuthProvider::Sso),
+        _ => None,
+    }
+}
+
+/// Resolves a configured OIDC provider by slug.
+///
+/// # Errors
+/// - `Errors::OidcProviderNotConfigured` if the slug is unknown or not enabled in `OIDC_PROVIDERS`
+pub fn oidc_provider(slug: &str) -> ServiceResult<OidcProvider> {
+    let provider = provider_for_slug(slug).ok_or(Errors::OidcProviderNotConfigured)?;
+    let config = ServerConfig::get()
+        .oidc_providers
+        .iter()
+        .find(|p| p.slug == slug)
+        .ok_or(Errors::OidcProviderNotConfigured)?;
+
+    Ok(OidcProvider { provider, config })
+}
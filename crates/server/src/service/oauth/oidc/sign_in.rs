@@ -0,0 +1,86 @@
+use super::OidcProvider;
+use super::client::{complete_claims_from_userinfo, exchange_oidc_code, verified_email};
+use super::discovery::get_oidc_metadata;
+use super::id_token::verify_oidc_id_token;
+use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
+use crate::service::oauth::types::OAuthStateData;
+use crate::state::NatsClient;
+use crate::utils::crypto::token::hash_token;
+use crate::utils::redis_cache::get_json_and_delete;
+use constants::oauth_state_key;
+use dto::oauth::internal::SignInResult;
+use dto::oauth::request::OAuthAuthorizeFlow;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager;
+use sea_orm::ConnectionTrait;
+
+/// Handles OIDC sign-in (authorization-code flow).
+///
+/// - Existing user: creates a session and returns Success
+/// - New user: returns PendingSignup (requires complete-signup to finish registration)
+pub async fn service_oidc_sign_in<C>(
+    conn: &C,
+    redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    code: &str,
+    state: &str,
+    anonymous_user_id: &str,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+) -> ServiceResult<SignInResult>
+where
+    C: ConnectionTrait,
+{
+    // 1. Validate state and retrieve PKCE verifier + nonce from Redis (single-use via get_del)
+    let state_key = oauth_state_key(&hash_token(state));
+    let state_data: OAuthStateData = get_json_and_delete(
+        redis_conn,
+        &state_key,
+        || Errors::OauthInvalidState,
+        |_| Errors::OauthInvalidState,
+    )
+    .await?;
+
+    if state_data.provider != provider.provider
+        || state_data.flow != OAuthAuthorizeFlow::Login
+        || state_data.anonymous_user_id != anonymous_user_id
+    {
+        return Err(Errors::OauthInvalidState);
+    }
+    let nonce = state_data.nonce.ok_or(Errors::OauthInvalidState)?;
+
+    // 2. Exchange the authorization code, then verify the ID token (signature, iss, aud, nonce)
+    let (metadata, _) = get_oidc_metadata(http_client, provider, false).await?;
+    let tokens = exchange_oidc_code(
+        http_client,
+        provider,
+        &metadata,
+        code,
+        &state_data.pkce_verifier,
+    )
+    .await?;
+    let claims =
+        verify_oidc_id_token(http_client, provider, &tokens.id_token, Some(&nonce)).await?;
+    let claims =
+        complete_claims_from_userinfo(http_client, &metadata, &tokens.access_token, claims).await?;
+
+    // 3. Require an email the provider vouches for
+    let email = verified_email(provider, &claims)?;
+
+    // 4. Resolve common sign-in flow (existing user → session, new user → pending signup)
+    resolve_oauth_sign_in(
+        conn,
+        redis_conn,
+        nats_client,
+        provider.provider.clone(),
+        &claims.sub,
+        email,
+        claims.picture,
+        Some(anonymous_user_id),
+        user_agent,
+        ip_address,
+    )
+    .await
+}
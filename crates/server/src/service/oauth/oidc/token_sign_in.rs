@@ -0,0 +1,48 @@
+use super::OidcProvider;
+use super::client::verified_email;
+use super::id_token::verify_oidc_id_token;
+use crate::service::oauth::resolve_sign_in::resolve_oauth_sign_in;
+use crate::state::NatsClient;
+use dto::oauth::internal::SignInResult;
+use errors::errors::ServiceResult;
+use redis::aio::ConnectionManager;
+use sea_orm::ConnectionTrait;
+
+/// Native-app OIDC sign-in via a provider ID token (allauth `provider/token` pattern).
+///
+/// The app runs its own authorization (with PKCE) against the IdP and submits the resulting ID
+/// token. As with Google, there is no server-issued state or nonce to check: the token's `aud`
+/// (= our client id) + signature + expiry are the protection (see [`verify_oidc_id_token`]).
+/// The email must be present in the token itself, since there is no access token to query
+/// userinfo with.
+pub async fn service_oidc_token_sign_in<C>(
+    conn: &C,
+    redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
+    http_client: &reqwest::Client,
+    provider: &OidcProvider,
+    id_token: &str,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+) -> ServiceResult<SignInResult>
+where
+    C: ConnectionTrait,
+{
+    let claims = verify_oidc_id_token(http_client, provider, id_token, None).await?;
+    let email = verified_email(provider, &claims)?;
+
+    resolve_oauth_sign_in(
+        conn,
+        redis_conn,
+        nats_client,
+        provider.provider.clone(),
+        &claims.sub,
+        email,
+        claims.picture,
+        // Native app: no browser cookie jar → no anonymous-context binding on the pending token.
+        None,
+        user_agent,
+        ip_address,
+    )
+    .await
+}
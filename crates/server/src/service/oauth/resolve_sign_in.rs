@@ -1,23 +1,27 @@
 use crate::repository::oauth::find_user_by_oauth::repository_find_user_by_oauth;
 use crate::repository::user::find_by_email::repository_find_user_by_email;
+use crate::service::action_logs::record_action_log;
 use crate::service::auth::session::SessionService;
 use crate::service::auth::verify_email::find_pending_email_signup_by_email;
 use crate::service::oauth::types::{PendingSignupData, PendingSignupTokenState};
+use crate::state::NatsClient;
 use crate::utils::crypto::token::{generate_secure_token, hash_token};
 use crate::utils::redis_cache::issue_token_and_store_json_with_ttl;
 use config::ServerConfig;
-use constants::oauth_pending_key;
+use constants::{ActionLogAction, oauth_pending_key};
 use dto::oauth::internal::SignInResult;
-use entity::common::OAuthProvider;
+use entity::common::{ActionResourceType, OAuthProvider};
 use errors::errors::{Errors, ServiceResult};
 use redis::aio::ConnectionManager;
 use sea_orm::ConnectionTrait;
+use serde_json::json;
 
 /// Shared OAuth sign-in handling after provider authentication.
 ///
 /// Each provider sign-in (authorization code / one-tap) only performs provider-specific token
 /// verification and user-info extraction, then delegates the common flow here:
-/// - If a connected account exists, create a session and return `Success`.
+/// - If a connected account exists, create a session, record an `auth:oauth_login` action log,
+///   and return `Success`.
 /// - Otherwise reject email collisions (existing account or pending email/password signup) and
 ///   issue a pending-signup token, returning `PendingSignup`.
 ///
@@ -26,6 +30,7 @@ use sea_orm::ConnectionTrait;
 pub async fn resolve_oauth_sign_in<C>(
     conn: &C,
     redis_conn: &ConnectionManager,
+    nats_client: &NatsClient,
     provider: OAuthProvider,
     provider_user_id: &str,
     email: String,
@@ -52,6 +57,18 @@ where
         )
         .await?;
 
+        record_action_log(
+            conn,
+            nats_client,
+            ActionLogAction::AuthOAuthLogin,
+            Some(existing_user.id),
+            ActionResourceType::User,
+            Some(existing_user.id),
+            format!("Signed in with {:?}", provider),
+            Some(json!({ "provider": provider })),
+        )
+        .await;
+
         return Ok(SignInResult::Success(raw_token));
     }
 
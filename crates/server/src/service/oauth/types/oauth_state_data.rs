@@ -13,4 +13,7 @@ pub struct OAuthStateData {
     pub provider: OAuthProvider,
     /// Browser binding value from anonymous cookie.
     pub anonymous_user_id: String,
+    /// OIDC nonce the ID token must echo (`None` for Google/GitHub code flows).
+    #[serde(default)]
+    pub nonce: Option<String>,
 }
@@ -1,9 +1,14 @@
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::oauth::delete_oauth_connection::repository_delete_oauth_connection;
 use crate::repository::oauth::list_oauth_connections::repository_list_oauth_connections_by_user_id;
 use crate::repository::user::get_by_id::repository_get_user_by_id_for_update;
-use entity::common::OAuthProvider;
+use crate::service::action_logs::publish_action_log;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
+use entity::common::{ActionResourceType, OAuthProvider};
 use errors::errors::{Errors, ServiceResult};
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 use uuid::Uuid;
 
@@ -13,6 +18,7 @@ use uuid::Uuid;
 /// - If the account has no password and only one OAuth connection left, unlink is denied.
 pub async fn service_unlink_oauth(
     db: &DatabaseConnection,
+    nats_client: &NatsClient,
     user_id: Uuid,
     provider: OAuthProvider,
 ) -> ServiceResult<()> {
@@ -35,7 +41,19 @@ pub async fn service_unlink_oauth(
 
     repository_delete_oauth_connection(&txn, user_id, provider.clone()).await?;
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::OAuthUnlink,
+        Some(user_id),
+        ActionResourceType::User,
+        Some(user_id),
+        format!("Unlinked {:?} account", provider),
+        Some(json!({ "provider": provider })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     info!(user_id = %user_id, provider = ?provider, "OAuth connection unlinked");
 
@@ -0,0 +1,102 @@
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::actors::repository_find_actor_by_id;
+use crate::repository::board::comments::repository_get_board_comment_by_id;
+use crate::repository::board::posts::repository_get_board_post_by_id;
+use crate::repository::board::repository_get_board_by_id;
+use crate::repository::report::{repository_create_report, repository_find_open_report};
+use crate::repository::user::repository_get_user_by_id;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::load_board_facts;
+use dto::report::{CreateReportRequest, ReportResponse};
+use entity::common::ReportTargetType;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+use uuid::Uuid;
+
+/// Files a report against a board post, comment or user profile.
+///
+/// # Role
+/// - The target must exist and, for board content, be visible to the reporter.
+/// - A reporter holds at most one open report per target.
+///
+/// # Errors
+/// - `Errors::ReportCannotReportSelf` when reporting one's own content or profile.
+/// - `Errors::ReportAlreadyOpen` when the reporter already has an open report on the target.
+pub async fn service_create_report(
+    db: &DatabaseConnection,
+    payload: CreateReportRequest,
+    session: &SessionContext,
+) -> ServiceResult<ReportResponse> {
+    let owner_user_id = resolve_target_owner(db, &payload, session).await?;
+    if owner_user_id == Some(session.user_id) {
+        return Err(Errors::ReportCannotReportSelf);
+    }
+
+    let txn = db.begin().await?;
+
+    // Fast path for the common case; a concurrent duplicate that slips past it
+    // is caught by the insert as `ReportAlreadyOpen`.
+    if repository_find_open_report(
+        &txn,
+        session.user_id,
+        payload.target_type.clone(),
+        payload.target_id,
+    )
+    .await?
+    .is_some()
+    {
+        return Err(Errors::ReportAlreadyOpen);
+    }
+
+    let report = repository_create_report(
+        &txn,
+        session.user_id,
+        payload.target_type,
+        payload.target_id,
+        payload.reason,
+        payload.description,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(report_id = %report.id, target_id = %report.target_id, reporter_id = %session.user_id, "Report filed");
+
+    Ok(ReportResponse::from(report))
+}
+
+/// Checks the target exists (and is visible to the reporter) and returns the
+/// user who owns it, if any.
+async fn resolve_target_owner(
+    db: &DatabaseConnection,
+    payload: &CreateReportRequest,
+    session: &SessionContext,
+) -> ServiceResult<Option<Uuid>> {
+    let (board_id, actor_id) = match payload.target_type {
+        ReportTargetType::User => {
+            let user = repository_get_user_by_id(db, payload.target_id).await?;
+            return Ok(Some(user.id));
+        }
+        ReportTargetType::BoardPost => {
+            let post = repository_get_board_post_by_id(db, payload.target_id).await?;
+            (post.board_id, post.actor_id)
+        }
+        ReportTargetType::BoardComment => {
+            let comment = repository_get_board_comment_by_id(db, payload.target_id).await?;
+            let post = repository_get_board_post_by_id(db, comment.post_id).await?;
+            (post.board_id, comment.actor_id)
+        }
+    };
+
+    let board = repository_get_board_by_id(db, board_id).await?;
+    let ctx = PermissionService::get_context(db, Some(session)).await?;
+    let facts = load_board_facts(db, &board).await?;
+    BoardPermission::View(facts).check(&ctx)?;
+
+    Ok(repository_find_actor_by_id(db, actor_id)
+        .await?
+        .and_then(|actor| actor.user_id))
+}
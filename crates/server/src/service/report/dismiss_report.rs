@@ -0,0 +1,58 @@
+use super::moderation_resource_type;
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::report::{repository_close_report, repository_get_report_by_id_for_update};
+use crate::service::auth::session_types::SessionContext;
+use constants::ModerationAction;
+use dto::report::{DismissReportRequest, ReportResponse};
+use entity::common::ReportStatus;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// Closes an open report without action. The dismissal itself is recorded as a
+/// `report:dismiss` moderation log entry on the reported target, which the
+/// report then links to.
+///
+/// # Errors
+/// - `Errors::ReportAlreadyClosed` if the report was already resolved or dismissed.
+pub async fn service_dismiss_report(
+    db: &DatabaseConnection,
+    payload: DismissReportRequest,
+    session: &SessionContext,
+) -> ServiceResult<ReportResponse> {
+    let txn = db.begin().await?;
+
+    let report = repository_get_report_by_id_for_update(&txn, payload.report_id).await?;
+    if report.status != ReportStatus::Open {
+        return Err(Errors::ReportAlreadyClosed);
+    }
+
+    let resource_type = moderation_resource_type(&report.target_type);
+    let log = repository_create_moderation_log(
+        &txn,
+        ModerationAction::ReportDismiss,
+        Some(session.user_id),
+        resource_type,
+        Some(report.target_id),
+        payload.reason.clone(),
+        Some(json!({ "report_id": report.id })),
+    )
+    .await?;
+
+    let report = repository_close_report(
+        &txn,
+        report,
+        ReportStatus::Dismissed,
+        session.user_id,
+        Some(payload.reason),
+        log.id,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(report_id = %report.id, actor_id = %session.user_id, "Report dismissed");
+
+    Ok(ReportResponse::from(report))
+}
@@ -0,0 +1,111 @@
+use crate::repository::report::{
+    ReportFilter, repository_count_reports_by_reporters, repository_count_reports_by_targets,
+    repository_exists_newer_report, repository_exists_older_report, repository_find_reports,
+};
+use crate::service::cursor_pagination::{cursor_flags, reverse_if_newer};
+use dto::pagination::CursorDirection;
+use dto::report::{ListReportsRequest, ListReportsResponse, ReportListItem};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Lists reports for the moderator queue, newest first.
+///
+/// # Role
+/// - Each row carries the target's open/total report counts and the reporter's
+///   total/dismissed counts, so piled-up targets and repeat reporters stand out.
+pub async fn service_list_reports(
+    conn: &DatabaseConnection,
+    payload: ListReportsRequest,
+) -> ServiceResult<ListReportsResponse> {
+    let is_newer = payload.cursor_direction == Some(CursorDirection::Newer);
+
+    let filter = ReportFilter {
+        status: payload.status,
+        target_type: payload.target_type,
+        target_id: payload.target_id,
+        reporter_id: payload.reporter_id,
+        reason: payload.reason,
+    };
+
+    let mut reports = repository_find_reports(
+        conn,
+        &filter,
+        payload.cursor_id,
+        payload.cursor_direction,
+        payload.limit,
+    )
+    .await?;
+
+    let (has_newer, has_older) = cursor_flags(
+        &reports,
+        is_newer,
+        |report| report.id,
+        |cursor| repository_exists_newer_report(conn, &filter, cursor),
+        |cursor| repository_exists_older_report(conn, &filter, cursor),
+    )
+    .await?;
+
+    reverse_if_newer(&mut reports, is_newer);
+
+    let target_ids: Vec<Uuid> = reports.iter().map(|report| report.target_id).collect();
+    let target_counts = repository_count_reports_by_targets(conn, &target_ids).await?;
+
+    let reporter_ids: Vec<Uuid> = reports
+        .iter()
+        .filter_map(|report| report.reporter_id)
+        .collect();
+    let reporter_counts: HashMap<Uuid, (i64, i64)> =
+        repository_count_reports_by_reporters(conn, &reporter_ids)
+            .await?
+            .into_iter()
+            .map(|counts| {
+                (
+                    counts.reporter_id,
+                    (counts.total_count, counts.dismissed_count),
+                )
+            })
+            .collect();
+
+    let data = reports
+        .into_iter()
+        .map(|report| {
+            let (target_open_reports, target_total_reports) = target_counts
+                .iter()
+                .find(|counts| {
+                    counts.target_id == report.target_id && counts.target_type == report.target_type
+                })
+                .map_or((0, 0), |counts| (counts.open_count, counts.total_count));
+            let (reporter_total_reports, reporter_dismissed_reports) = report
+                .reporter_id
+                .and_then(|reporter_id| reporter_counts.get(&reporter_id).copied())
+                .unwrap_or((0, 0));
+
+            ReportListItem {
+                id: report.id,
+                reporter_id: report.reporter_id,
+                target_type: report.target_type,
+                target_id: report.target_id,
+                reason: report.reason,
+                description: report.description,
+                status: report.status,
+                resolved_by: report.resolved_by,
+                resolved_at: report.resolved_at,
+                resolution_note: report.resolution_note,
+                moderation_log_id: report.moderation_log_id,
+                created_at: report.created_at,
+                target_open_reports,
+                target_total_reports,
+                reporter_total_reports,
+                reporter_dismissed_reports,
+            }
+        })
+        .collect();
+
+    Ok(ListReportsResponse {
+        data,
+        has_newer,
+        has_older,
+    })
+}
@@ -0,0 +1,22 @@
+//! User content reports and the moderator report queue.
+
+mod create_report;
+mod dismiss_report;
+mod list_reports;
+mod resolve_report;
+
+pub use create_report::service_create_report;
+pub use dismiss_report::service_dismiss_report;
+pub use list_reports::service_list_reports;
+pub use resolve_report::service_resolve_report;
+
+use entity::common::{ModerationResourceType, ReportTargetType};
+
+/// The moderation log resource type that covers a report target.
+fn moderation_resource_type(target_type: &ReportTargetType) -> ModerationResourceType {
+    match target_type {
+        ReportTargetType::BoardPost => ModerationResourceType::BoardPost,
+        ReportTargetType::BoardComment => ModerationResourceType::BoardComment,
+        ReportTargetType::User => ModerationResourceType::User,
+    }
+}
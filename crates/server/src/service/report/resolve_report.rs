@@ -0,0 +1,52 @@
+use super::moderation_resource_type;
+use crate::repository::moderation::repository_find_moderation_log_by_id;
+use crate::repository::report::{repository_close_report, repository_get_report_by_id_for_update};
+use crate::service::auth::session_types::SessionContext;
+use dto::report::{ReportResponse, ResolveReportRequest};
+use entity::common::ReportStatus;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+
+/// Closes an open report as resolved, linking the moderation log entry of the
+/// action the moderator took on the reported target (hide, ban, ...).
+///
+/// # Errors
+/// - `Errors::ReportAlreadyClosed` if the report was already resolved or dismissed.
+/// - `Errors::ReportModerationLogMismatch` if the log entry is missing or acts on another resource.
+pub async fn service_resolve_report(
+    db: &DatabaseConnection,
+    payload: ResolveReportRequest,
+    session: &SessionContext,
+) -> ServiceResult<ReportResponse> {
+    let txn = db.begin().await?;
+
+    let report = repository_get_report_by_id_for_update(&txn, payload.report_id).await?;
+    if report.status != ReportStatus::Open {
+        return Err(Errors::ReportAlreadyClosed);
+    }
+
+    let log = repository_find_moderation_log_by_id(&txn, payload.moderation_log_id)
+        .await?
+        .ok_or(Errors::ReportModerationLogMismatch)?;
+    let expected_resource_type = moderation_resource_type(&report.target_type);
+    if log.resource_type != expected_resource_type || log.resource_id != Some(report.target_id) {
+        return Err(Errors::ReportModerationLogMismatch);
+    }
+
+    let report = repository_close_report(
+        &txn,
+        report,
+        ReportStatus::Resolved,
+        session.user_id,
+        payload.note,
+        log.id,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(report_id = %report.id, moderation_log_id = %log.id, actor_id = %session.user_id, "Report resolved");
+
+    Ok(ReportResponse::from(report))
+}
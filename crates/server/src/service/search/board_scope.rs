@@ -0,0 +1,49 @@
+use crate::permission::PermissionService;
+use crate::permission::board::BoardPermission;
+use crate::permission::rule::Rule;
+use crate::repository::board::repository_find_boards;
+use crate::service::auth::session_types::SessionContext;
+use crate::service::board::load_board_facts_batch;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+/// Meilisearch filter limiting board content hits to what the caller may see.
+///
+/// Board disablement lives in the DB, not the index, so the filter lists the
+/// viewable boards explicitly; this also drops stale hits from deleted boards.
+/// Hidden rows are only kept for moderators. `None` means no board is visible
+/// and the search can be skipped.
+pub(super) async fn board_content_filter(
+    db: &DatabaseConnection,
+    session: Option<&SessionContext>,
+    board_id: Option<Uuid>,
+) -> ServiceResult<Option<String>> {
+    let ctx = PermissionService::get_context(db, session).await?;
+
+    let boards = repository_find_boards(db).await?;
+    let visible_ids: Vec<Uuid> = load_board_facts_batch(db, boards)
+        .await?
+        .into_iter()
+        .filter(|(_, facts)| BoardPermission::View(facts.clone()).is_allowed(&ctx))
+        .map(|(board, _)| board.id)
+        .filter(|id| board_id.is_none_or(|wanted| wanted == *id))
+        .collect();
+
+    if visible_ids.is_empty() {
+        // A specific board that is missing or not viewable reads as not found,
+        // matching the board routes.
+        return match board_id {
+            Some(_) => Err(Errors::BoardNotFound),
+            None => Ok(None),
+        };
+    }
+
+    let quoted: Vec<String> = visible_ids.iter().map(|id| format!("\"{id}\"")).collect();
+    let mut filter = format!("board_id IN [{}]", quoted.join(", "));
+    if !BoardPermission::Moderate.is_allowed(&ctx) {
+        filter.push_str(" AND is_hidden = false");
+    }
+
+    Ok(Some(filter))
+}
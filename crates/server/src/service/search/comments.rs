@@ -0,0 +1,86 @@
+use super::board_scope::board_content_filter;
+use super::snippet::{hit_snippet, parse_timestamp};
+use crate::connection::MeilisearchClient;
+use crate::service::auth::session_types::SessionContext;
+use dto::search::{CommentSearchItem, SearchCommentsRequest, SearchCommentsResponse};
+use errors::errors::{Errors, ServiceResult};
+use meilisearch_sdk::search::Selectors;
+use sea_orm::DatabaseConnection;
+use search_index::comments::{COMMENTS_INDEX, SearchComment};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+pub async fn service_search_comments(
+    db: &DatabaseConnection,
+    client: &MeilisearchClient,
+    request: &SearchCommentsRequest,
+    session: Option<&SessionContext>,
+) -> ServiceResult<SearchCommentsResponse> {
+    let query = request.query.as_deref().unwrap_or("");
+    info!(
+        "Searching comments: query='{}', board_id={:?}, post_id={:?}, page={}, page_size={}",
+        query, request.board_id, request.post_id, request.page, request.page_size
+    );
+
+    let mut response = SearchCommentsResponse {
+        comments: Vec::new(),
+        page: request.page,
+        page_size: request.page_size,
+        total_hits: 0,
+        total_pages: 0,
+    };
+
+    let Some(mut filter) = board_content_filter(db, session, request.board_id).await? else {
+        return Ok(response);
+    };
+    if let Some(post_id) = request.post_id {
+        filter.push_str(&format!(" AND post_id = \"{post_id}\""));
+    }
+
+    // Build and execute search query using page/hitsPerPage mode for exact total_hits
+    let index = client.get_client().index(COMMENTS_INDEX);
+    let mut search_query = index.search();
+
+    search_query.with_query(query);
+    search_query.with_filter(&filter);
+    search_query.with_attributes_to_crop(Selectors::Some(&[("content", None)]));
+    search_query.with_page(request.page as usize);
+    search_query.with_hits_per_page(request.page_size as usize);
+
+    let results = search_query.execute::<SearchComment>().await.map_err(|e| {
+        tracing::error!("MeiliSearch comment search failed: {}", e);
+        Errors::MeiliSearchQueryFailed
+    })?;
+
+    response.total_hits = results.total_hits.unwrap_or(0) as u64;
+    response.total_pages = results.total_pages.unwrap_or(0) as u32;
+
+    response.comments = results
+        .hits
+        .into_iter()
+        .filter_map(|hit| {
+            let snippet = hit_snippet(hit.formatted_result.as_ref(), &hit.result.content);
+            let comment = hit.result;
+            match (
+                Uuid::parse_str(&comment.id),
+                Uuid::parse_str(&comment.board_id),
+                Uuid::parse_str(&comment.post_id),
+            ) {
+                (Ok(id), Ok(board_id), Ok(post_id)) => Some(CommentSearchItem {
+                    id,
+                    board_id,
+                    post_id,
+                    snippet,
+                    created_at: parse_timestamp(comment.created_at),
+                    is_hidden: comment.is_hidden,
+                }),
+                _ => {
+                    warn!("Invalid UUID in comment search index: '{}'", comment.id);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    Ok(response)
+}
@@ -1,3 +1,11 @@
+mod board_scope;
+pub mod comments;
+pub mod posts;
+pub mod reindex;
+mod snippet;
 pub mod users;
 
+pub use comments::*;
+pub use posts::*;
+pub use reindex::*;
 pub use users::*;
@@ -0,0 +1,79 @@
+use super::board_scope::board_content_filter;
+use super::snippet::{hit_snippet, parse_timestamp};
+use crate::connection::MeilisearchClient;
+use crate::service::auth::session_types::SessionContext;
+use dto::search::{PostSearchItem, SearchPostsRequest, SearchPostsResponse};
+use errors::errors::{Errors, ServiceResult};
+use meilisearch_sdk::search::Selectors;
+use sea_orm::DatabaseConnection;
+use search_index::posts::{POSTS_INDEX, SearchPost};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+pub async fn service_search_posts(
+    db: &DatabaseConnection,
+    client: &MeilisearchClient,
+    request: &SearchPostsRequest,
+    session: Option<&SessionContext>,
+) -> ServiceResult<SearchPostsResponse> {
+    let query = request.query.as_deref().unwrap_or("");
+    info!(
+        "Searching posts: query='{}', board_id={:?}, page={}, page_size={}",
+        query, request.board_id, request.page, request.page_size
+    );
+
+    let mut response = SearchPostsResponse {
+        posts: Vec::new(),
+        page: request.page,
+        page_size: request.page_size,
+        total_hits: 0,
+        total_pages: 0,
+    };
+
+    let Some(filter) = board_content_filter(db, session, request.board_id).await? else {
+        return Ok(response);
+    };
+
+    // Build and execute search query using page/hitsPerPage mode for exact total_hits
+    let index = client.get_client().index(POSTS_INDEX);
+    let mut search_query = index.search();
+
+    search_query.with_query(query);
+    search_query.with_filter(&filter);
+    search_query.with_attributes_to_crop(Selectors::Some(&[("content", None)]));
+    search_query.with_page(request.page as usize);
+    search_query.with_hits_per_page(request.page_size as usize);
+
+    let results = search_query.execute::<SearchPost>().await.map_err(|e| {
+        tracing::error!("MeiliSearch post search failed: {}", e);
+        Errors::MeiliSearchQueryFailed
+    })?;
+
+    response.total_hits = results.total_hits.unwrap_or(0) as u64;
+    response.total_pages = results.total_pages.unwrap_or(0) as u32;
+
+    response.posts = results
+        .hits
+        .into_iter()
+        .filter_map(|hit| {
+            let snippet = hit_snippet(hit.formatted_result.as_ref(), &hit.result.content);
+            let post = hit.result;
+            match (Uuid::parse_str(&post.id), Uuid::parse_str(&post.board_id)) {
+                (Ok(id), Ok(board_id)) => Some(PostSearchItem {
+                    id,
+                    board_id,
+                    title: post.title,
+                    snippet,
+                    created_at: parse_timestamp(post.created_at),
+                    is_hidden: post.is_hidden,
+                }),
+                _ => {
+                    warn!("Invalid UUID in post search index: '{}'", post.id);
+                    None
+                }
+            }
+        })
+        .collect();
+
+    Ok(response)
+}
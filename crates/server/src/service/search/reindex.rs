@@ -0,0 +1,223 @@
+use crate::bridge::worker_client::{
+    start_reindex_comments, start_reindex_posts, start_reindex_users,
+};
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::service::auth::session_types::SessionContext;
+use crate::state::WorkerClient;
+use crate::utils::redis_cache::{get_json, set_json_nx_with_ttl, set_json_with_ttl};
+use crate::utils::redis_keys::reindex_active_key;
+use chrono::{DateTime, Utc};
+use constants::ModerationAction;
+use dto::search::{
+    SearchReindexStatus, SearchReindexStatusResponse, SearchReindexTarget,
+    StartSearchReindexRequest, StartSearchReindexResponse,
+};
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use job_queue::jobs::reindex::{
+    REINDEX_PROGRESS_TTL_SECONDS, ReindexProgress, ReindexStatus, ReindexTarget,
+    reindex_progress_key,
+};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use tracing::info;
+use uuid::Uuid;
+
+/// A run still marked running whose progress has not moved for this long is
+/// presumed dead (e.g. its worker died before recording a terminal status), so
+/// it no longer blocks a new run. Comfortably above the reindex consumers'
+/// 15-minute handler timeout.
+const REINDEX_STALE_AFTER_SECONDS: i64 = 60 * 60;
+
+/// Whether `progress` belongs to a run that is still going, so a new run for
+/// the same index must wait. Completed and failed runs never block.
+fn blocks_new_run(progress: &ReindexProgress, now: i64) -> bool {
+    progress.status == ReindexStatus::Running
+        && now - progress.updated_at < REINDEX_STALE_AFTER_SECONDS
+}
+
+fn job_target(target: SearchReindexTarget) -> ReindexTarget {
+    match target {
+        SearchReindexTarget::Users => ReindexTarget::Users,
+        SearchReindexTarget::Posts => ReindexTarget::Posts,
+        SearchReindexTarget::Comments => ReindexTarget::Comments,
+    }
+}
+
+fn target_name(target: SearchReindexTarget) -> &'static str {
+    match target {
+        SearchReindexTarget::Users => "users",
+        SearchReindexTarget::Posts => "posts",
+        SearchReindexTarget::Comments => "comments",
+    }
+}
+
+/// Starts a full rebuild of one search index and records a `search:reindex`
+/// moderation log entry. The worker reports per-batch progress under the
+/// returned `reindex_id`.
+///
+/// # Errors
+/// - `Errors::ReindexAlreadyRunning` if a run for the same index is still
+///   making progress.
+pub async fn service_start_search_reindex(
+    db: &DatabaseConnection,
+    redis_cache: &RedisClient,
+    worker: &WorkerClient,
+    payload: StartSearchReindexRequest,
+    session: &SessionContext,
+) -> ServiceResult<StartSearchReindexResponse> {
+    let active_key = reindex_active_key(target_name(payload.target));
+    let now = Utc::now().timestamp();
+
+    // Best-effort guard: two runs would race each other's temp index and swap.
+    if let Some(active_id) = get_json::<Uuid>(redis_cache, &active_key).await? {
+        let progress =
+            get_json::<ReindexProgress>(redis_cache, &reindex_progress_key(active_id)).await?;
+        if progress.is_some_and(|p| blocks_new_run(&p, now)) {
+            return Err(Errors::ReindexAlreadyRunning);
+        }
+    }
+
+    let reindex_id = match payload.target {
+        SearchReindexTarget::Users => start_reindex_users(worker, payload.batch_size).await?,
+        SearchReindexTarget::Posts => start_reindex_posts(worker, payload.batch_size).await?,
+        SearchReindexTarget::Comments => start_reindex_comments(worker, payload.batch_size).await?,
+    };
+
+    set_json_with_ttl(
+        redis_cache,
+        &active_key,
+        &reindex_id,
+        REINDEX_PROGRESS_TTL_SECONDS,
+    )
+    .await?;
+
+    // NX: the worker may already have recorded the first batch.
+    let progress = ReindexProgress {
+        reindex_id,
+        target: job_target(payload.target),
+        status: ReindexStatus::Running,
+        batch_number: 0,
+        processed_count: 0,
+        total_count: None,
+        error: None,
+        started_at: now,
+        updated_at: now,
+    };
+    set_json_nx_with_ttl(
+        redis_cache,
+        &reindex_progress_key(reindex_id),
+        &progress,
+        REINDEX_PROGRESS_TTL_SECONDS,
+    )
+    .await?;
+
+    repository_create_moderation_log(
+        db,
+        ModerationAction::SearchReindex,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(reindex_id),
+        payload.reason,
+        Some(json!({
+            "target": target_name(payload.target),
+            "batch_size": payload.batch_size,
+        })),
+    )
+    .await?;
+
+    info!(
+        %reindex_id,
+        target = target_name(payload.target),
+        actor_id = %session.user_id,
+        "Search reindex started"
+    );
+
+    Ok(StartSearchReindexResponse { reindex_id })
+}
+
+/// Reports the progress the worker last recorded for a reindex run.
+///
+/// # Errors
+/// - `Errors::ReindexNotFound` if the id is unknown or its record expired.
+pub async fn service_get_search_reindex_status(
+    redis_cache: &RedisClient,
+    reindex_id: Uuid,
+) -> ServiceResult<SearchReindexStatusResponse> {
+    let progress = get_json::<ReindexProgress>(redis_cache, &reindex_progress_key(reindex_id))
+        .await?
+        .ok_or(Errors::ReindexNotFound)?;
+
+    Ok(SearchReindexStatusResponse {
+        reindex_id: progress.reindex_id,
+        target: match progress.target {
+            ReindexTarget::Users => SearchReindexTarget::Users,
+            ReindexTarget::Posts => SearchReindexTarget::Posts,
+            ReindexTarget::Comments => SearchReindexTarget::Comments,
+        },
+        status: match progress.status {
+            ReindexStatus::Running => SearchReindexStatus::Running,
+            ReindexStatus::Completed => SearchReindexStatus::Completed,
+            ReindexStatus::Failed => SearchReindexStatus::Failed,
+        },
+        batch_number: progress.batch_number,
+        processed_count: progress.processed_count,
+        total_count: progress.total_count,
+        error: progress.error,
+        started_at: DateTime::from_timestamp(progress.started_at, 0).unwrap_or_default(),
+        updated_at: DateTime::from_timestamp(progress.updated_at, 0).unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(status: ReindexStatus, updated_at: i64) -> ReindexProgress {
+        ReindexProgress {
+            reindex_id: Uuid::nil(),
+            target: ReindexTarget::Posts,
+            status,
+            batch_number: 3,
+            processed_count: 300,
+            total_count: Some(1_000),
+            error: None,
+            started_at: 0,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn recent_running_run_blocks() {
+        assert!(blocks_new_run(
+            &progress(ReindexStatus::Running, 1_000),
+            1_060
+        ));
+    }
+
+    #[test]
+    fn stale_running_run_does_not_block() {
+        let now = 1_000 + REINDEX_STALE_AFTER_SECONDS;
+        assert!(!blocks_new_run(
+            &progress(ReindexStatus::Running, 1_000),
+            now
+        ));
+    }
+
+    #[test]
+    fn failed_run_can_be_restarted_right_away() {
+        assert!(!blocks_new_run(
+            &progress(ReindexStatus::Failed, 1_000),
+            1_000
+        ));
+    }
+
+    #[test]
+    fn completed_run_does_not_block() {
+        assert!(!blocks_new_run(
+            &progress(ReindexStatus::Completed, 1_000),
+            1_000
+        ));
+    }
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+/// The cropped `content` Meilisearch returns in `_formatted`, falling back to
+/// the raw content when the hit carries no formatted copy.
+pub(super) fn hit_snippet(formatted: Option<&Map<String, Value>>, content: &str) -> String {
+    formatted
+        .and_then(|f| f.get("content"))
+        .and_then(Value::as_str)
+        .unwrap_or(content)
+        .to_string()
+}
+
+/// Index timestamps are unix seconds; an out-of-range value maps to the epoch.
+pub(super) fn parse_timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_default()
+}
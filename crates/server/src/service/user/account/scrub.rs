@@ -1,5 +1,6 @@
 use crate::repository::notification::{
     repository_delete_all_notifications_for_user,
+    repository_delete_all_push_subscriptions_for_user,
     repository_delete_notification_action_preferences_for_user,
     repository_delete_notification_preferences_for_user,
 };
@@ -26,6 +27,7 @@ use uuid::Uuid;
 /// - Notification inbox and channel/action preferences: private data
 /// - Roles: revoke admin/mod privileges from the deactivated account
 /// - Notification inbox and channel/action preferences: private data
+/// - Web Push subscriptions: stop pushing to the account's devices
 pub async fn scrub_user_account<C>(conn: &C, user_id: Uuid) -> Result<(), Errors>
 where
     C: ConnectionTrait,
@@ -61,6 +63,7 @@ where
     repository_delete_notification_preferences_for_user(conn, user_id).await?;
     repository_delete_notification_action_preferences_for_user(conn, user_id).await?;
     repository_delete_all_notifications_for_user(conn, user_id).await?;
+    repository_delete_all_push_subscriptions_for_user(conn, user_id).await?;
 
     Ok(())
 }
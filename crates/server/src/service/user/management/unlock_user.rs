@@ -0,0 +1,68 @@
+use crate::permission::PermissionService;
+use crate::repository::auth_events::AUTH_EVENT_ACCOUNT_UNLOCKED;
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::user::repository_get_user_by_id;
+use crate::service::auth::audit::record_auth_event;
+use crate::service::auth::login_throttle::clear_login_failures;
+use crate::service::auth::session_types::SessionContext;
+use constants::ModerationAction;
+use dto::user::response::UnlockUserResponse;
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use redis::aio::ConnectionManager as RedisClient;
+use sea_orm::DatabaseConnection;
+use tracing::info;
+use uuid::Uuid;
+
+/// Lifts a login lockout early.
+///
+/// Clears the account's failed-login counter and any active delay or lock. The per-IP counters
+/// are left to expire on their own.
+///
+/// # Permissions
+/// - Only Admin can unlock users
+/// - Cannot unlock another Admin
+///
+/// # Errors
+/// - Returns `Errors::UserNotLocked` if the account has no failed logins on record
+pub async fn service_unlock_user(
+    db: &DatabaseConnection,
+    redis: &RedisClient,
+    target_user_id: Uuid,
+    reason: String,
+    session: &SessionContext,
+) -> ServiceResult<UnlockUserResponse> {
+    PermissionService::require_admin_for_target(db, Some(session), target_user_id).await?;
+
+    let user = repository_get_user_by_id(db, target_user_id).await?;
+
+    if !clear_login_failures(redis, &user.email).await? {
+        return Err(Errors::UserNotLocked);
+    }
+
+    repository_create_moderation_log(
+        db,
+        ModerationAction::UserUnlock,
+        Some(session.user_id),
+        ModerationResourceType::User,
+        Some(target_user_id),
+        reason,
+        None,
+    )
+    .await?;
+    record_auth_event(
+        db,
+        Some(target_user_id),
+        AUTH_EVENT_ACCOUNT_UNLOCKED,
+        None,
+        None,
+        None,
+    )
+    .await;
+
+    info!(target_user_id = %target_user_id, actor_id = %session.user_id, "User login unlocked");
+
+    Ok(UnlockUserResponse {
+        user_id: target_user_id,
+    })
+}
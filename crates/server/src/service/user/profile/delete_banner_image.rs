@@ -1,11 +1,17 @@
 use crate::connection::r2_assets_conn::R2AssetsClient;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::user::{
     UserUpdateParams, repository_get_user_by_id, repository_update_user,
 };
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::blob_cleanup::delete_user_image_blob_if_unreferenced;
+use crate::state::NatsClient;
+use constants::ActionLogAction;
+use entity::common::ActionResourceType;
 use errors::errors::Errors;
 use sea_orm::DatabaseConnection;
+use serde_json::json;
 use tracing::info;
 
 /// Deletes the current user's banner image.
@@ -25,6 +31,7 @@ use tracing::info;
 pub async fn service_delete_banner_image(
     db: &DatabaseConnection,
     r2_assets: &R2AssetsClient,
+    nats_client: &NatsClient,
     session: &SessionContext,
 ) -> Result<(), Errors> {
     let user = repository_get_user_by_id(db, session.user_id).await?;
@@ -44,6 +51,18 @@ pub async fn service_delete_banner_image(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        db,
+        ActionLogAction::UserEdit,
+        Some(session.user_id),
+        ActionResourceType::User,
+        Some(session.user_id),
+        "Removed banner image".to_string(),
+        Some(json!({ "fields": ["banner_image"] })),
+    )
+    .await?;
+    publish_action_log(nats_client, &action_log).await;
+
     // R2 delete is best effort. Content-addressed image may be shared with other users,
     // so only delete the object when nothing references it anymore.
     delete_user_image_blob_if_unreferenced(db, r2_assets, &storage_key).await;
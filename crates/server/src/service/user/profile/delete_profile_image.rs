@@ -1,13 +1,18 @@
 use crate::connection::r2_assets_conn::R2AssetsClient;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::user::{
     UserUpdateParams, repository_get_user_by_id, repository_update_user,
 };
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::blob_cleanup::delete_user_image_blob_if_unreferenced;
 use crate::service::user::utils::spawn_index_user;
-use crate::state::WorkerClient;
+use crate::state::{NatsClient, WorkerClient};
+use constants::ActionLogAction;
+use entity::common::ActionResourceType;
 use errors::errors::Errors;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 
 /// Deletes the current user's profile image.
@@ -29,6 +34,7 @@ pub async fn service_delete_profile_image(
     db: &DatabaseConnection,
     r2_assets: &R2AssetsClient,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     session: &SessionContext,
 ) -> Result<(), Errors> {
     let txn = db.begin().await?;
@@ -50,7 +56,19 @@ pub async fn service_delete_profile_image(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::UserEdit,
+        Some(session.user_id),
+        ActionResourceType::User,
+        Some(session.user_id),
+        "Removed profile image".to_string(),
+        Some(json!({ "fields": ["profile_image"] })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     // R2 delete happens outside the transaction (best effort). Content-addressed image may be shared with
     // other users, so only delete the object when nothing references it anymore.
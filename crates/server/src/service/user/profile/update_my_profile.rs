@@ -1,16 +1,21 @@
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::user::user_roles::repository_find_user_roles;
 use crate::repository::user::{
     UserUpdateParams, repository_find_user_by_handle, repository_update_user,
 };
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::user::bans::find_active_user_ban;
 use crate::service::user::utils::spawn_index_user;
-use crate::state::WorkerClient;
+use crate::state::{NatsClient, WorkerClient};
 use crate::utils::r2_url::build_r2_public_url;
+use constants::ActionLogAction;
 use dto::user::UserResponse;
 use dto::user::request::UpdateMyProfileRequest;
+use entity::common::ActionResourceType;
 use errors::errors::Errors;
 use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
 use tracing::info;
 
 /// Updates the currently logged-in user's profile information.
@@ -19,6 +24,7 @@ use tracing::info;
 /// - Updates the handle, display name, and bio.
 /// - Checks for duplicates when the handle changes.
 /// - Returns the latest profile response including role names.
+/// - Records a `user:edit` action log and publishes it after commit.
 /// - Triggers a search index refresh after commit.
 ///
 /// # Related
@@ -33,6 +39,7 @@ use tracing::info;
 pub async fn service_update_my_profile(
     db: &DatabaseConnection,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     session: &SessionContext,
     request: UpdateMyProfileRequest,
 ) -> Result<UserResponse, Errors> {
@@ -45,6 +52,15 @@ pub async fn service_update_my_profile(
         return Err(Errors::UserHandleAlreadyExists);
     }
 
+    let changed_fields: Vec<&str> = [
+        ("handle", request.handle.is_some()),
+        ("display_name", request.display_name.is_some()),
+        ("bio", request.bio.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(field, changed)| changed.then_some(field))
+    .collect();
+
     let params = UserUpdateParams {
         handle: request.handle,
         display_name: request.display_name,
@@ -56,7 +72,19 @@ pub async fn service_update_my_profile(
     let roles = repository_find_user_roles(&txn, session.user_id).await?;
     let is_banned = find_active_user_ban(&txn, session.user_id).await?.is_some();
 
+    let action_log = repository_create_action_log(
+        &txn,
+        ActionLogAction::UserEdit,
+        Some(session.user_id),
+        ActionResourceType::User,
+        Some(session.user_id),
+        "Updated profile".to_string(),
+        Some(json!({ "fields": changed_fields })),
+    )
+    .await?;
+
     txn.commit().await?;
+    publish_action_log(nats_client, &action_log).await;
 
     info!(user_id = %session.user_id, "Profile updated");
 
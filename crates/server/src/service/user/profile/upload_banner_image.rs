@@ -1,13 +1,18 @@
 use crate::connection::r2_assets_conn::R2AssetsClient;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::user::{UserUpdateParams, repository_update_user};
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::user::profile::upload_profile_image::prepare_user_image;
-use constants::{BANNER_IMAGE_MAX_SIZE, user_image_key};
+use crate::state::NatsClient;
+use constants::{ActionLogAction, BANNER_IMAGE_MAX_SIZE, user_image_key};
 use dto::user::UploadUserImageRequest;
 use dto::user::UploadUserImageResponse;
+use entity::common::ActionResourceType;
 use errors::errors::Errors;
 use reqwest::Client as HttpClient;
 use sea_orm::DatabaseConnection;
+use serde_json::json;
 use tracing::{info, warn};
 
 /// Uploads my banner image.
@@ -27,6 +32,7 @@ pub async fn service_upload_banner_image(
     db: &DatabaseConnection,
     http_client: &HttpClient,
     r2_assets: &R2AssetsClient,
+    nats_client: &NatsClient,
     session: &SessionContext,
     payload: UploadUserImageRequest,
 ) -> Result<UploadUserImageResponse, Errors> {
@@ -53,6 +59,18 @@ pub async fn service_upload_banner_image(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        db,
+        ActionLogAction::UserEdit,
+        Some(session.user_id),
+        ActionResourceType::User,
+        Some(session.user_id),
+        "Updated banner image".to_string(),
+        Some(json!({ "fields": ["banner_image"] })),
+    )
+    .await?;
+    publish_action_log(nats_client, &action_log).await;
+
     let image_url = r2_assets.get_public_url(&storage_key);
 
     info!(user_id = %session.user_id, storage_key = %storage_key, "Banner image uploaded");
@@ -1,15 +1,19 @@
 use crate::bridge::media_processor_client::process_media;
 use crate::connection::r2_assets_conn::R2AssetsClient;
+use crate::repository::action_logs::repository_create_action_log;
 use crate::repository::user::{UserUpdateParams, repository_update_user};
+use crate::service::action_logs::publish_action_log;
 use crate::service::auth::session_types::SessionContext;
 use crate::service::user::utils::spawn_index_user;
-use crate::state::WorkerClient;
-use constants::{PROFILE_IMAGE_MAX_SIZE, user_image_key};
+use crate::state::{NatsClient, WorkerClient};
+use constants::{ActionLogAction, PROFILE_IMAGE_MAX_SIZE, user_image_key};
 use dto::user::UploadUserImageRequest;
 use dto::user::UploadUserImageResponse;
+use entity::common::ActionResourceType;
 use errors::errors::Errors;
 use reqwest::Client as HttpClient;
 use sea_orm::DatabaseConnection;
+use serde_json::json;
 use tracing::{info, warn};
 
 /// Uploads my profile image.
@@ -32,6 +36,7 @@ pub async fn service_upload_profile_image(
     http_client: &HttpClient,
     r2_assets: &R2AssetsClient,
     worker: &WorkerClient,
+    nats_client: &NatsClient,
     session: &SessionContext,
     payload: UploadUserImageRequest,
 ) -> Result<UploadUserImageResponse, Errors> {
@@ -58,6 +63,18 @@ pub async fn service_upload_profile_image(
     )
     .await?;
 
+    let action_log = repository_create_action_log(
+        db,
+        ActionLogAction::UserEdit,
+        Some(session.user_id),
+        ActionResourceType::User,
+        Some(session.user_id),
+        "Updated profile image".to_string(),
+        Some(json!({ "fields": ["profile_image"] })),
+    )
+    .await?;
+    publish_action_log(nats_client, &action_log).await;
+
     let image_url = r2_assets.get_public_url(&storage_key);
 
     // Index the user in MeiliSearch (reflect profile image change)
@@ -0,0 +1,70 @@
+use super::generate_webhook_secret;
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::webhooks::repository_create_webhook_endpoint;
+use crate::service::auth::session_types::SessionContext;
+use constants::ModerationAction;
+use dto::webhooks::{CreateWebhookEndpointRequest, WebhookEndpointSecretResponse};
+use entity::common::ModerationResourceType;
+use errors::errors::ServiceResult;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// Registers a webhook endpoint.
+///
+/// # Role
+/// - Stores the endpoint with a freshly generated signing secret and records a
+///   `webhook:create` moderation log entry, in one transaction.
+/// - Returns the secret; it is not shown again.
+///
+/// # Related
+/// - `repository_create_webhook_endpoint`
+///
+/// # Errors
+/// - Returns a DB/repository error if the insert fails.
+pub async fn service_create_webhook_endpoint(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+    payload: CreateWebhookEndpointRequest,
+) -> ServiceResult<WebhookEndpointSecretResponse> {
+    let secret = generate_webhook_secret();
+
+    let txn = db.begin().await?;
+
+    let endpoint = repository_create_webhook_endpoint(
+        &txn,
+        payload.url,
+        payload.description.unwrap_or_default(),
+        payload.events,
+        secret.clone(),
+        session.user_id,
+    )
+    .await?;
+
+    repository_create_moderation_log(
+        &txn,
+        ModerationAction::WebhookCreate,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(endpoint.id),
+        payload.reason,
+        Some(json!({
+            "url": endpoint.url,
+            "events": endpoint.events,
+        })),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(
+        endpoint_id = %endpoint.id,
+        actor_id = %session.user_id,
+        "Webhook endpoint created"
+    );
+
+    Ok(WebhookEndpointSecretResponse {
+        endpoint: endpoint.into(),
+        secret,
+    })
+}
@@ -0,0 +1,55 @@
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::webhooks::repository_delete_webhook_endpoint;
+use crate::service::auth::session_types::SessionContext;
+use constants::ModerationAction;
+use dto::webhooks::DeleteWebhookEndpointRequest;
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+
+/// Deletes a webhook endpoint together with its delivery log.
+///
+/// # Role
+/// - Deletes the endpoint and records a `webhook:delete` moderation log entry, in
+///   one transaction. Deliveries still queued for it are dropped.
+///
+/// # Related
+/// - `repository_delete_webhook_endpoint`
+///
+/// # Errors
+/// - `Errors::WebhookEndpointNotFound` if no endpoint has this id
+/// - Returns a DB/repository error if the deletion fails.
+pub async fn service_delete_webhook_endpoint(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+    payload: DeleteWebhookEndpointRequest,
+) -> ServiceResult<()> {
+    let txn = db.begin().await?;
+
+    let deleted = repository_delete_webhook_endpoint(&txn, payload.endpoint_id).await?;
+    if deleted == 0 {
+        return Err(Errors::WebhookEndpointNotFound);
+    }
+
+    repository_create_moderation_log(
+        &txn,
+        ModerationAction::WebhookDelete,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(payload.endpoint_id),
+        payload.reason,
+        None,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(
+        endpoint_id = %payload.endpoint_id,
+        actor_id = %session.user_id,
+        "Webhook endpoint deleted"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::repository::webhooks::{
+    repository_exists_newer_webhook_delivery, repository_exists_older_webhook_delivery,
+    repository_find_webhook_deliveries, repository_find_webhook_endpoint_by_id,
+};
+use dto::pagination::CursorDirection;
+use dto::webhooks::{
+    ListWebhookDeliveriesRequest, WebhookDeliveryListResponse, WebhookDeliveryResponse,
+};
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+
+/// Lists one endpoint's delivery log with cursor pagination, newest first.
+///
+/// # Errors
+/// - `Errors::WebhookEndpointNotFound` if no endpoint has this id
+/// - Returns a DB/repository error if a query fails.
+pub async fn service_list_webhook_deliveries(
+    conn: &DatabaseConnection,
+    payload: ListWebhookDeliveriesRequest,
+) -> ServiceResult<WebhookDeliveryListResponse> {
+    let endpoint_id = payload.endpoint_id;
+    let is_newer = payload.cursor_direction == Some(CursorDirection::Newer);
+
+    repository_find_webhook_endpoint_by_id(conn, endpoint_id)
+        .await?
+        .ok_or(Errors::WebhookEndpointNotFound)?;
+
+    let mut deliveries = repository_find_webhook_deliveries(
+        conn,
+        endpoint_id,
+        payload.cursor_id,
+        payload.cursor_direction,
+        payload.limit,
+    )
+    .await?;
+
+    let (has_newer, has_older) = if deliveries.is_empty() {
+        (false, false)
+    } else {
+        let first_id = deliveries.first().unwrap().id;
+        let last_id = deliveries.last().unwrap().id;
+        let (newest, oldest) = if is_newer {
+            (last_id, first_id)
+        } else {
+            (first_id, last_id)
+        };
+        (
+            repository_exists_newer_webhook_delivery(conn, endpoint_id, newest).await?,
+            repository_exists_older_webhook_delivery(conn, endpoint_id, oldest).await?,
+        )
+    };
+
+    if is_newer {
+        deliveries.reverse();
+    }
+
+    let data = deliveries
+        .into_iter()
+        .map(WebhookDeliveryResponse::from)
+        .collect();
+
+    Ok(WebhookDeliveryListResponse {
+        data,
+        has_newer,
+        has_older,
+    })
+}
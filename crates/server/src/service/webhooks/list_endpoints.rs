@@ -0,0 +1,20 @@
+use crate::repository::webhooks::repository_find_webhook_endpoints;
+use dto::webhooks::{WebhookEndpointListResponse, WebhookEndpointResponse};
+use errors::errors::ServiceResult;
+use sea_orm::DatabaseConnection;
+
+/// Lists every webhook endpoint, newest first, without their secrets.
+///
+/// # Errors
+/// - Returns a DB/repository error if the query fails.
+pub async fn service_list_webhook_endpoints(
+    conn: &DatabaseConnection,
+) -> ServiceResult<WebhookEndpointListResponse> {
+    let endpoints = repository_find_webhook_endpoints(conn)
+        .await?
+        .into_iter()
+        .map(WebhookEndpointResponse::from)
+        .collect();
+
+    Ok(WebhookEndpointListResponse { endpoints })
+}
@@ -0,0 +1,27 @@
+//! Webhook service APIs (admin only).
+//!
+//! Covers managing webhook endpoints, browsing their delivery log and
+//! redelivering a logged event. Every change is moderation-logged.
+
+mod create_endpoint;
+mod delete_endpoint;
+mod list_deliveries;
+mod list_endpoints;
+mod redeliver;
+mod rotate_secret;
+mod update_endpoint;
+
+pub use create_endpoint::service_create_webhook_endpoint;
+pub use delete_endpoint::service_delete_webhook_endpoint;
+pub use list_deliveries::service_list_webhook_deliveries;
+pub use list_endpoints::service_list_webhook_endpoints;
+pub use redeliver::service_redeliver_webhook;
+pub use rotate_secret::service_rotate_webhook_secret;
+pub use update_endpoint::service_update_webhook_endpoint;
+
+use crate::utils::crypto::token::generate_secure_token;
+
+/// Generates a signing secret. The prefix makes leaked secrets easy to scan for.
+fn generate_webhook_secret() -> String {
+    format!("whsec_{}", generate_secure_token())
+}
@@ -0,0 +1,39 @@
+use crate::repository::webhooks::{
+    repository_find_webhook_delivery_by_id, repository_requeue_webhook_delivery,
+};
+use dto::webhooks::RedeliverWebhookRequest;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::DatabaseConnection;
+use tracing::info;
+
+/// Sends a logged delivery again.
+///
+/// # Role
+/// - Puts the delivery back in the outbox; the worker's dispatcher picks it up
+///   within seconds and sends the original payload with a fresh signature.
+/// - Works for any status. Receivers should dedupe on the payload `id`.
+///
+/// # Related
+/// - `repository_requeue_webhook_delivery`
+///
+/// # Errors
+/// - `Errors::WebhookDeliveryNotFound` if no delivery has this id
+/// - Returns a DB/repository error if the update fails.
+pub async fn service_redeliver_webhook(
+    conn: &DatabaseConnection,
+    payload: RedeliverWebhookRequest,
+) -> ServiceResult<()> {
+    let delivery = repository_find_webhook_delivery_by_id(conn, payload.delivery_id)
+        .await?
+        .ok_or(Errors::WebhookDeliveryNotFound)?;
+
+    repository_requeue_webhook_delivery(conn, delivery.id).await?;
+
+    info!(
+        delivery_id = %delivery.id,
+        endpoint_id = %delivery.endpoint_id,
+        "Webhook delivery requeued"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use super::generate_webhook_secret;
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::webhooks::{
+    WebhookEndpointUpdate, repository_find_webhook_endpoint_by_id,
+    repository_update_webhook_endpoint,
+};
+use crate::service::auth::session_types::SessionContext;
+use constants::ModerationAction;
+use dto::webhooks::{RotateWebhookSecretRequest, WebhookEndpointSecretResponse};
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use tracing::info;
+
+/// Replaces a webhook endpoint's signing secret.
+///
+/// # Role
+/// - Stores a new secret and records a `webhook:rotate_secret` moderation log
+///   entry, in one transaction. Every attempt from then on, including retries of
+///   older deliveries, is signed with the new secret.
+/// - Returns the new secret; it is not shown again.
+///
+/// # Related
+/// - `repository_update_webhook_endpoint`
+///
+/// # Errors
+/// - `Errors::WebhookEndpointNotFound` if no endpoint has this id
+/// - Returns a DB/repository error if the update fails.
+pub async fn service_rotate_webhook_secret(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+    payload: RotateWebhookSecretRequest,
+) -> ServiceResult<WebhookEndpointSecretResponse> {
+    let secret = generate_webhook_secret();
+
+    let txn = db.begin().await?;
+
+    let endpoint = repository_find_webhook_endpoint_by_id(&txn, payload.endpoint_id)
+        .await?
+        .ok_or(Errors::WebhookEndpointNotFound)?;
+
+    let endpoint = repository_update_webhook_endpoint(
+        &txn,
+        endpoint,
+        WebhookEndpointUpdate {
+            secret: Some(secret.clone()),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    repository_create_moderation_log(
+        &txn,
+        ModerationAction::WebhookRotateSecret,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(endpoint.id),
+        payload.reason,
+        None,
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(
+        endpoint_id = %endpoint.id,
+        actor_id = %session.user_id,
+        "Webhook secret rotated"
+    );
+
+    Ok(WebhookEndpointSecretResponse {
+        endpoint: endpoint.into(),
+        secret,
+    })
+}
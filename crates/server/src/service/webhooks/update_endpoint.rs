@@ -0,0 +1,80 @@
+use crate::repository::moderation::repository_create_moderation_log;
+use crate::repository::webhooks::{
+    WebhookEndpointUpdate, repository_find_webhook_endpoint_by_id,
+    repository_update_webhook_endpoint,
+};
+use crate::service::auth::session_types::SessionContext;
+use constants::ModerationAction;
+use dto::webhooks::{UpdateWebhookEndpointRequest, WebhookEndpointResponse};
+use entity::common::ModerationResourceType;
+use errors::errors::{Errors, ServiceResult};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use serde_json::json;
+use tracing::info;
+
+/// Changes a webhook endpoint's URL, description, subscribed events or active flag.
+///
+/// # Role
+/// - Applies the given fields and records a `webhook:update` moderation log entry
+///   listing what changed, in one transaction.
+/// - Pausing an endpoint stops new deliveries; queued ones are marked failed when
+///   the worker picks them up.
+///
+/// # Related
+/// - `repository_update_webhook_endpoint`
+///
+/// # Errors
+/// - `Errors::WebhookEndpointNotFound` if no endpoint has this id
+/// - Returns a DB/repository error if the update fails.
+pub async fn service_update_webhook_endpoint(
+    db: &DatabaseConnection,
+    session: &SessionContext,
+    payload: UpdateWebhookEndpointRequest,
+) -> ServiceResult<WebhookEndpointResponse> {
+    let txn = db.begin().await?;
+
+    let endpoint = repository_find_webhook_endpoint_by_id(&txn, payload.endpoint_id)
+        .await?
+        .ok_or(Errors::WebhookEndpointNotFound)?;
+
+    let metadata = json!({
+        "url": payload.url,
+        "description": payload.description,
+        "events": payload.events,
+        "is_active": payload.is_active,
+    });
+
+    let endpoint = repository_update_webhook_endpoint(
+        &txn,
+        endpoint,
+        WebhookEndpointUpdate {
+            url: payload.url,
+            description: payload.description,
+            events: payload.events,
+            is_active: payload.is_active,
+            secret: None,
+        },
+    )
+    .await?;
+
+    repository_create_moderation_log(
+        &txn,
+        ModerationAction::WebhookUpdate,
+        Some(session.user_id),
+        ModerationResourceType::System,
+        Some(endpoint.id),
+        payload.reason,
+        Some(metadata),
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    info!(
+        endpoint_id = %endpoint.id,
+        actor_id = %session.user_id,
+        "Webhook endpoint updated"
+    );
+
+    Ok(endpoint.into())
+}
@@ -2,7 +2,7 @@ use redis::aio::ConnectionManager as RedisClient;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
-use crate::connection::{MeilisearchClient, R2AssetsClient};
+use crate::connection::{MeilisearchClient, R2AssetsClient, R2RevisionClient};
 use dto::action_logs::ActionLogResponse;
 use reqwest::Client as HttpClient;
 use sea_orm::DatabaseConnection as PostgresqlClient;
@@ -21,6 +21,8 @@ pub struct AppState {
     /// Database connection (via PgDog connection pooler)
     pub db: PostgresqlClient,
     pub r2_assets: R2AssetsClient,
+    /// Private bucket holding board post/comment revision bodies
+    pub r2_revisions: R2RevisionClient,
     /// Redis for sessions, tokens, rate-limiting (persistent, AOF)
     pub redis_session: RedisClient,
     /// Redis for document cache (volatile, LRU eviction)
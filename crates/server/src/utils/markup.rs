@@ -0,0 +1,193 @@
+//! Markdown rendering for user-authored board content.
+//!
+//! Posts and comments are stored as raw Markdown and rendered to HTML on read:
+//! CommonMark plus tables, strikethrough and task lists, with fenced code
+//! blocks keeping their `language-*` class for client-side highlighting.
+//! `@handle` mentions of existing users become profile links (mentions inside
+//! code, links and image alt text are left alone). The generated HTML always
+//! goes through `ammonia`, so raw HTML in the source can never inject scripts,
+//! event handlers or `javascript:` URLs.
+
+use crate::repository::user::repository_find_users_by_handles;
+use crate::utils::mentions::{extract_mention_handles, find_mention_spans};
+use ammonia::Builder;
+use errors::errors::Errors;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, TextMergeStream, html};
+use sea_orm::ConnectionTrait;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+/// Upper bound on distinct handles looked up per render — keeps a pathological
+/// body from turning into a huge `IN` query. Mentions past the cap render as
+/// plain text.
+const MAX_LINKED_MENTIONS: usize = 100;
+
+/// Profile link prefix for autolinked mentions (`/@{handle}`).
+const MENTION_HREF_PREFIX: &str = "/@";
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::default();
+    builder
+        .add_tags(&["input"])
+        .add_tag_attributes("a", &["class"])
+        .add_tag_attributes("code", &["class"])
+        .add_tag_attributes("input", &["type", "checked", "disabled"])
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            ("a", "class") => (value == "mention").then_some(Cow::Borrowed(value)),
+            ("code", "class") => is_language_class(value).then_some(Cow::Borrowed(value)),
+            ("input", "type") => (value == "checkbox").then_some(Cow::Borrowed(value)),
+            _ => Some(Cow::Borrowed(value)),
+        });
+    builder
+});
+
+/// Renders `content` to sanitized HTML, linking mentions of existing users.
+pub async fn render_markup<C>(conn: &C, content: &str) -> Result<String, Errors>
+where
+    C: ConnectionTrait,
+{
+    let mut handles = extract_mention_handles(content);
+    handles.truncate(MAX_LINKED_MENTIONS);
+
+    let known_handles: HashSet<String> = repository_find_users_by_handles(conn, &handles)
+        .await?
+        .into_iter()
+        .map(|user| user.handle)
+        .collect();
+
+    Ok(render_html(content, &known_handles))
+}
+
+/// Renders `content` to sanitized HTML, linking only mentions in `known_handles`.
+fn render_html(content: &str, known_handles: &HashSet<String>) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    // Merge adjacent text events so a handle split across events (e.g. at `_`)
+    // is still seen as one token.
+    let parser = TextMergeStream::new(Parser::new_ext(content, options));
+
+    let mut events = Vec::new();
+    let mut in_code_block = false;
+    let mut link_depth = 0usize;
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => {
+                in_code_block = true;
+                events.push(event);
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                events.push(event);
+            }
+            Event::Start(Tag::Link { .. } | Tag::Image { .. }) => {
+                link_depth += 1;
+                events.push(event);
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                link_depth = link_depth.saturating_sub(1);
+                events.push(event);
+            }
+            Event::Text(text) if !in_code_block && link_depth == 0 => {
+                push_text_with_mentions(&mut events, &text, known_handles);
+            }
+            other => events.push(other),
+        }
+    }
+
+    let mut unsafe_html = String::with_capacity(content.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    SANITIZER.clean(&unsafe_html).to_string()
+}
+
+/// Splits a text run around `@handle` tokens, emitting a profile link for each
+/// known handle and plain (escaped) text for everything else.
+fn push_text_with_mentions<'a>(
+    events: &mut Vec<Event<'a>>,
+    text: &str,
+    known_handles: &HashSet<String>,
+) {
+    let mut last = 0;
+    for span in find_mention_spans(text) {
+        let handle = &text[span.start + 1..span.end];
+        if !known_handles.contains(handle) {
+            continue;
+        }
+        if span.start > last {
+            events.push(Event::Text(text[last..span.start].to_string().into()));
+        }
+        // Handles are `[A-Za-z0-9_]+`, so they need no HTML escaping.
+        events.push(Event::InlineHtml(
+            format!(
+                r#"<a href="{}{}" class="mention">@{}</a>"#,
+                MENTION_HREF_PREFIX, handle, handle
+            )
+            .into(),
+        ));
+        last = span.end;
+    }
+    if last < text.len() {
+        events.push(Event::Text(text[last..].to_string().into()));
+    }
+}
+
+/// Whether `value` is a fenced-code language class such as `language-rust`.
+fn is_language_class(value: &str) -> bool {
+    value.strip_prefix("language-").is_some_and(|lang| {
+        !lang.is_empty()
+            && lang
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+' | '#'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_html;
+    use std::collections::HashSet;
+
+    fn known(handles: &[&str]) -> HashSet<String> {
+        handles.iter().map(|h| h.to_string()).collect()
+    }
+
+    #[test]
+    fn links_known_mentions_only() {
+        let html = render_html("hi @alice and @ghost", &known(&["alice"]));
+        assert!(
+            html.contains(
+                r#"<a href="/@alice" class="mention" rel="noopener noreferrer">@alice</a>"#
+            )
+        );
+        assert!(html.contains("@ghost"));
+        assert!(!html.contains("/@ghost"));
+    }
+
+    #[test]
+    fn leaves_mentions_in_code_and_links_alone() {
+        let html = render_html(
+            "`@alice`\n\n```\n@alice\n```\n\n[@alice](https://example.com)",
+            &known(&["alice"]),
+        );
+        assert!(!html.contains("/@alice"));
+    }
+
+    #[test]
+    fn keeps_code_block_language() {
+        let html = render_html("```rust\nfn main() {}\n```", &HashSet::new());
+        assert!(html.contains(r#"<code class="language-rust">"#));
+    }
+
+    #[test]
+    fn strips_scripts_handlers_and_spoofed_classes() {
+        let html = render_html(
+            "<script>alert(1)</script><a class=\"evil\" href=\"javascript:x\" onclick=\"x\">y</a>",
+            &HashSet::new(),
+        );
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("onclick"));
+        assert!(!html.contains("javascript:"));
+        assert!(!html.contains("evil"));
+    }
+}
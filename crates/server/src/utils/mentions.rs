@@ -1,26 +1,29 @@
 //! Lightweight @handle mention extraction for user-authored content.
 //!
-//! Content is stored raw (no markup pipeline), so mentions are parsed
-//! directly from the text: `@` followed by a handle-shaped token. Handles that
-//! don't resolve to a user are simply ignored.
+//! Content is stored raw, so mentions are parsed directly from the text: `@`
+//! followed by a handle-shaped token. Handles that don't resolve to a user are
+//! simply ignored. The markup renderer (`utils::markup`) reuses the same
+//! scanner to autolink mentions.
 
 use crate::repository::user::repository_find_user_by_handle;
 use errors::errors::Errors;
 use sea_orm::ConnectionTrait;
 use std::collections::BTreeSet;
+use std::ops::Range;
 use uuid::Uuid;
 
 /// Upper bound on resolved mentions per content body — keeps a pathological
 /// post from turning into a notification broadcast.
 const MAX_MENTIONS: usize = 10;
 
-/// Extracts candidate handles from `@handle` tokens in the content.
+/// Locates `@handle` tokens in `text`, returning the byte range of each token
+/// (including the leading `@`).
 ///
 /// A candidate must look like a valid handle (alphanumeric/underscore, no
 /// leading/trailing underscore) and be delimited by a non-handle character.
-fn extract_mention_handles(content: &str) -> Vec<String> {
-    let mut handles = BTreeSet::new();
-    let bytes = content.as_bytes();
+pub(crate) fn find_mention_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let bytes = text.as_bytes();
     let is_handle_char = |c: u8| c.is_ascii_alphanumeric() || c == b'_';
 
     let mut i = 0;
@@ -32,9 +35,9 @@ fn extract_mention_handles(content: &str) -> Vec<String> {
                 end += 1;
             }
             if end > start {
-                let handle = &content[start..end];
+                let handle = &text[start..end];
                 if !handle.starts_with('_') && !handle.ends_with('_') {
-                    handles.insert(handle.to_string());
+                    spans.push(i..end);
                 }
             }
             i = end;
@@ -43,6 +46,16 @@ fn extract_mention_handles(content: &str) -> Vec<String> {
         }
     }
 
+    spans
+}
+
+/// Extracts candidate handles from `@handle` tokens in the content
+/// (deduplicated, sorted).
+pub(crate) fn extract_mention_handles(content: &str) -> Vec<String> {
+    let handles: BTreeSet<String> = find_mention_spans(content)
+        .into_iter()
+        .map(|span| content[span.start + 1..span.end].to_string())
+        .collect();
     handles.into_iter().collect()
 }
 
@@ -66,7 +79,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::extract_mention_handles;
+    use super::{extract_mention_handles, find_mention_spans};
 
     #[test]
     fn extracts_delimited_handles() {
@@ -79,4 +92,12 @@ mod tests {
         let handles = extract_mention_handles("@alice @alice @_bad @bad_ @");
         assert_eq!(handles, vec!["alice"]);
     }
+
+    #[test]
+    fn spans_cover_the_at_sign() {
+        let text = "hi @alice!";
+        let spans = find_mention_spans(text);
+        assert_eq!(spans, vec![3..9]);
+        assert_eq!(&text[spans[0].clone()], "@alice");
+    }
 }
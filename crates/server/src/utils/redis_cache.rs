@@ -172,6 +172,47 @@ pub async fn set_json_nx_with_ttl<T: Serialize>(
     Ok(matches!(result, Some(v) if v == "OK"))
 }
 
+/// Store a JSON value for at least `ttl_seconds`, never shortening the TTL of an existing key.
+///
+/// `SET NX EX` and `EXPIRE GT` in one transaction: a missing key is created, an existing one only
+/// has its expiry pushed out, so concurrent writers with different TTLs settle on the longest.
+pub async fn set_json_with_min_ttl<T: Serialize>(
+    redis_client: &RedisClient,
+    key: &str,
+    value: &T,
+    ttl_seconds: u64,
+) -> Result<(), Errors> {
+    let json = serde_json::to_string(value).map_err(|e| {
+        Errors::SysInternalError(format!(
+            "JSON serialization failed for Redis key '{}': {}",
+            key, e
+        ))
+    })?;
+
+    let mut conn = redis_client.clone();
+    redis::pipe()
+        .atomic()
+        .cmd("SET")
+        .arg(key)
+        .arg(json)
+        .arg("NX")
+        .arg("EX")
+        .arg(ttl_seconds)
+        .ignore()
+        .cmd("EXPIRE")
+        .arg(key)
+        .arg(ttl_seconds)
+        .arg("GT")
+        .ignore()
+        .query_async::<()>(&mut conn)
+        .await
+        .map_err(|e| {
+            Errors::SysInternalError(format!("Redis write failed for key '{}': {}", key, e))
+        })?;
+
+    Ok(())
+}
+
 /// Atomically increment a hash field by `delta` (HINCRBY). Returns the new value.
 pub async fn hincr_by(
     redis_client: &RedisClient,
@@ -189,6 +230,38 @@ pub async fn hincr_by(
     Ok(value)
 }
 
+/// Atomically increment a counter (INCR), starting its TTL on the first increment only.
+/// Returns the new value.
+pub async fn incr_with_ttl(
+    redis_client: &RedisClient,
+    key: &str,
+    ttl_seconds: u64,
+) -> Result<i64, Errors> {
+    let mut conn = redis_client.clone();
+    let (value,): (i64,) = redis::pipe()
+        .atomic()
+        .incr(key, 1)
+        .cmd("EXPIRE")
+        .arg(key)
+        .arg(ttl_seconds)
+        .arg("NX")
+        .ignore()
+        .query_async(&mut conn)
+        .await
+        .map_err(|e| {
+            Errors::SysInternalError(format!("Redis INCR failed for key '{}': {}", key, e))
+        })?;
+    Ok(value)
+}
+
+/// Check whether a key exists.
+pub async fn key_exists(redis_client: &RedisClient, key: &str) -> Result<bool, Errors> {
+    let mut conn = redis_client.clone();
+    conn.exists(key).await.map_err(|e| {
+        Errors::SysInternalError(format!("Redis EXISTS failed for key '{}': {}", key, e))
+    })
+}
+
 /// Get the remaining TTL of a key in seconds. Returns None if key doesn't exist.
 pub async fn get_ttl_seconds(redis_client: &RedisClient, key: &str) -> Result<Option<u64>, Errors> {
     let mut conn = redis_client.clone();
@@ -26,3 +26,12 @@ pub fn oauth_pending_key(token: &str) -> String {
 pub fn oauth_pending_lock_key(token: &str) -> String {
     format!("{}{}", OAUTH_PENDING_LOCK_KEY_PREFIX, token)
 }
+
+/// Active search reindex key prefix (stores the running reindex id)
+/// Format: reindex:active:{target}
+pub const REINDEX_ACTIVE_KEY_PREFIX: &str = "reindex:active:";
+
+/// Build active search reindex key.
+pub fn reindex_active_key(target: &str) -> String {
+    format!("{}{}", REINDEX_ACTIVE_KEY_PREFIX, target)
+}
@@ -1,7 +1,9 @@
 mod mail;
 mod media_processor;
 mod meilisearch;
+mod web_push;
 
 pub use mail::create_mailer;
 pub use media_processor::process_media;
 pub use meilisearch::create_meili_client;
+pub use web_push::{PushOutcome, PushTarget, VapidKey, send_web_push};
@@ -0,0 +1,445 @@
+//! Web Push delivery: `aes128gcm` payload encryption (RFC 8291) sent to a push
+//! service endpoint (RFC 8030) with VAPID authentication (RFC 8292).
+//!
+//! The subscription keys and the VAPID key are base64url, the form browsers hand
+//! out (`PushSubscription.toJSON()`) and the form `applicationServerKey` accepts.
+
+use aes_gcm::Aes128Gcm;
+use aes_gcm::Nonce;
+use aes_gcm::aead::{Aead, KeyInit};
+use anyhow::{Context, anyhow, bail};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use hkdf::Hkdf;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::{PublicKey, SecretKey};
+use rand::RngExt;
+use reqwest::{Client as HttpClient, StatusCode, Url};
+use sha2::Sha256;
+
+/// Record size advertised in the `aes128gcm` header. The payload is sent as a
+/// single record, so it only has to be larger than the payload.
+const RECORD_SIZE: u32 = 4096;
+
+/// Largest plaintext that fits the 4096-byte body push services accept: the
+/// 86-byte header, the 16-byte tag and the 1-byte padding delimiter come off.
+pub const MAX_PUSH_PAYLOAD_LEN: usize = 4096 - 86 - 16 - 1;
+
+/// How long a VAPID token stays valid (RFC 8292 caps it at 24 hours).
+const VAPID_TOKEN_LIFETIME_SECS: i64 = 12 * 60 * 60;
+
+/// The application server's VAPID identity.
+pub struct VapidKey {
+    signing_key: SigningKey,
+    /// Uncompressed public key, base64url — the `k=` parameter.
+    public_key: String,
+    /// Contact for the push service operator (`mailto:` or `https:` URL).
+    subject: String,
+}
+
+impl VapidKey {
+    /// Parse a base64url-encoded 32-byte P-256 private key.
+    pub fn from_base64url(private_key: &str, subject: impl Into<String>) -> anyhow::Result<Self> {
+        let bytes = decode_base64url(private_key).context("VAPID private key is not base64url")?;
+        let signing_key =
+            SigningKey::from_slice(&bytes).map_err(|_| anyhow!("invalid VAPID private key"))?;
+        let public_key = URL_SAFE_NO_PAD.encode(
+            signing_key
+                .verifying_key()
+                .to_encoded_point(false)
+                .as_bytes(),
+        );
+
+        Ok(Self {
+            signing_key,
+            public_key,
+            subject: subject.into(),
+        })
+    }
+
+    /// The public key browsers pass as `applicationServerKey`.
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    /// `Authorization` header value for a request to `endpoint`: a signed ES256
+    /// JWT scoped to the endpoint's origin, plus the public key.
+    fn authorization(&self, endpoint: &Url) -> anyhow::Result<String> {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"typ":"JWT","alg":"ES256"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&serde_json::json!({
+            "aud": endpoint.origin().ascii_serialization(),
+            "exp": chrono::Utc::now().timestamp() + VAPID_TOKEN_LIFETIME_SECS,
+            "sub": self.subject,
+        }))?);
+
+        let signing_input = format!("{header}.{claims}");
+        let signature: Signature = self.signing_key.sign(signing_input.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+        Ok(format!(
+            "vapid t={signing_input}.{signature}, k={}",
+            self.public_key
+        ))
+    }
+}
+
+/// One browser subscription, as stored.
+pub struct PushTarget<'a> {
+    pub endpoint: &'a str,
+    /// The user agent's P-256 public key (uncompressed), base64url.
+    pub p256dh: &'a str,
+    /// The 16-byte authentication secret, base64url.
+    pub auth: &'a str,
+}
+
+/// What the push service made of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushOutcome {
+    Delivered,
+    /// 404/410: the subscription expired or was revoked; stop using it.
+    Gone,
+}
+
+/// Encrypt `payload` for `target` and post it to the push service.
+///
+/// `ttl_secs` is how long the service may hold the message for an offline
+/// device. Any other status than success or 404/410 is returned as an error.
+pub async fn send_web_push(
+    http_client: &HttpClient,
+    vapid: &VapidKey,
+    target: &PushTarget<'_>,
+    payload: &[u8],
+    ttl_secs: u32,
+) -> anyhow::Result<PushOutcome> {
+    let endpoint = Url::parse(target.endpoint).context("invalid push endpoint")?;
+    let body = encrypt_payload(target, payload)?;
+
+    let response = http_client
+        .post(endpoint.clone())
+        .header("Authorization", vapid.authorization(&endpoint)?)
+        .header("TTL", ttl_secs)
+        .header("Urgency", "normal")
+        .header("Content-Encoding", "aes128gcm")
+        .header("Content-Type", "application/octet-stream")
+        .body(body)
+        .send()
+        .await
+        .context("push service request failed")?;
+
+    match response.status() {
+        status if status.is_success() => Ok(PushOutcome::Delivered),
+        StatusCode::NOT_FOUND | StatusCode::GONE => Ok(PushOutcome::Gone),
+        status => {
+            let detail = response.text().await.unwrap_or_default();
+            Err(anyhow!("push service rejected message: {status} {detail}"))
+        }
+    }
+}
+
+/// Encrypt `payload` for the subscription with a fresh sender key and salt.
+fn encrypt_payload(target: &PushTarget<'_>, payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let ua_public = PublicKey::from_sec1_bytes(
+        &decode_base64url(target.p256dh).context("p256dh is not base64url")?,
+    )
+    .map_err(|_| anyhow!("p256dh is not a P-256 public key"))?;
+    let auth_secret = decode_base64url(target.auth).context("auth is not base64url")?;
+    if auth_secret.len() != 16 {
+        bail!("auth secret must be 16 bytes");
+    }
+
+    let as_secret = loop {
+        let bytes: [u8; 32] = rand::rng().random();
+        // Only zero and values past the curve order are rejected: practically never.
+        if let Ok(secret) = SecretKey::from_slice(&bytes) {
+            break secret;
+        }
+    };
+    let salt: [u8; 16] = rand::rng().random();
+
+    encrypt_with(&as_secret, salt, &ua_public, &auth_secret, payload)
+}
+
+/// RFC 8291 §3–4: derive the content key from an ECDH between the sender key and
+/// the user agent key, then seal `payload` as one `aes128gcm` record.
+fn encrypt_with(
+    as_secret: &SecretKey,
+    salt: [u8; 16],
+    ua_public: &PublicKey,
+    auth_secret: &[u8],
+    payload: &[u8],
+) -> anyhow::Result<Vec<u8>> {
+    if payload.len() > MAX_PUSH_PAYLOAD_LEN {
+        bail!(
+            "push payload too large: {} bytes (max: {MAX_PUSH_PAYLOAD_LEN})",
+            payload.len()
+        );
+    }
+
+    let ua_public_bytes = ua_public.to_encoded_point(false);
+    let as_public_bytes = as_secret.public_key().to_encoded_point(false);
+
+    let shared = p256::ecdh::diffie_hellman(as_secret.to_nonzero_scalar(), ua_public.as_affine());
+
+    // IKM = HKDF(auth_secret, ecdh_secret, "WebPush: info" || 0 || ua_public || as_public)
+    let mut key_info = b"WebPush: info\0".to_vec();
+    key_info.extend_from_slice(ua_public_bytes.as_bytes());
+    key_info.extend_from_slice(as_public_bytes.as_bytes());
+    let mut ikm = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(auth_secret), shared.raw_secret_bytes())
+        .expand(&key_info, &mut ikm)
+        .map_err(|_| anyhow!("HKDF expand failed"))?;
+
+    let (cek, nonce) = content_key_and_nonce(&salt, &ikm)?;
+    let nonce = Nonce::try_from(&nonce[..]).map_err(|_| anyhow!("invalid nonce"))?;
+
+    // Single (last) record: plaintext followed by the 0x02 delimiter, no padding.
+    let mut record = Vec::with_capacity(payload.len() + 1);
+    record.extend_from_slice(payload);
+    record.push(2);
+    let ciphertext = Aes128Gcm::new_from_slice(&cek)
+        .map_err(|_| anyhow!("invalid content encryption key"))?
+        .encrypt(&nonce, record.as_slice())
+        .map_err(|_| anyhow!("payload encryption failed"))?;
+
+    // Header: salt(16) || rs(4) || idlen(1) || keyid (the sender public key).
+    let key_id = as_public_bytes.as_bytes();
+    let mut body = Vec::with_capacity(21 + key_id.len() + ciphertext.len());
+    body.extend_from_slice(&salt);
+    body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+    body.push(key_id.len() as u8);
+    body.extend_from_slice(key_id);
+    body.extend_from_slice(&ciphertext);
+    Ok(body)
+}
+
+/// RFC 8188 §2.2–2.3: the content encryption key and the nonce of the first record.
+fn content_key_and_nonce(salt: &[u8], ikm: &[u8]) -> anyhow::Result<([u8; 16], [u8; 12])> {
+    let hkdf = Hkdf::<Sha256>::new(Some(salt), ikm);
+    let mut cek = [0u8; 16];
+    let mut nonce = [0u8; 12];
+    hkdf.expand(b"Content-Encoding: aes128gcm\0", &mut cek)
+        .and_then(|_| hkdf.expand(b"Content-Encoding: nonce\0", &mut nonce))
+        .map_err(|_| anyhow!("HKDF expand failed"))?;
+    Ok((cek, nonce))
+}
+
+/// Browsers emit unpadded base64url; tolerate padding anyway.
+fn decode_base64url(value: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    URL_SAFE_NO_PAD.decode(value.trim_end_matches('='))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::VerifyingKey;
+    use p256::ecdsa::signature::Verifier;
+    use std::collections::HashMap;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// A request as the stand-in push service received it.
+    struct Received {
+        headers: HashMap<String, String>,
+        body: Vec<u8>,
+    }
+
+    /// Local stand-in for a push service: accepts one request, answers `status`,
+    /// and hands the request back. Returns the endpoint URL to subscribe with.
+    async fn stand_in_push_service(status: u16) -> (String, tokio::task::JoinHandle<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}/push/abc", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 4096];
+            let header_end = loop {
+                let n = socket.read(&mut chunk).await.unwrap();
+                buf.extend_from_slice(&chunk[..n]);
+                if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break pos + 4;
+                }
+            };
+
+            let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+            let headers: HashMap<String, String> = head
+                .lines()
+                .skip(1)
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+                .collect();
+            let length: usize = headers["content-length"].parse().unwrap();
+            while buf.len() < header_end + length {
+                let n = socket.read(&mut chunk).await.unwrap();
+                buf.extend_from_slice(&chunk[..n]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 {status} Stand-in\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+
+            Received {
+                headers,
+                body: buf[header_end..header_end + length].to_vec(),
+            }
+        });
+
+        (endpoint, handle)
+    }
+
+    fn random_secret() -> SecretKey {
+        loop {
+            let bytes: [u8; 32] = rand::rng().random();
+            if let Ok(secret) = SecretKey::from_slice(&bytes) {
+                return secret;
+            }
+        }
+    }
+
+    /// The user agent side of RFC 8291: recover the plaintext from a push body.
+    fn decrypt(ua_secret: &SecretKey, auth_secret: &[u8], body: &[u8]) -> Vec<u8> {
+        let (salt, rest) = body.split_at(16);
+        let record_size = u32::from_be_bytes(rest[..4].try_into().unwrap());
+        assert_eq!(record_size, RECORD_SIZE);
+        let key_id_len = rest[4] as usize;
+        let (key_id, ciphertext) = rest[5..].split_at(key_id_len);
+
+        let as_public = PublicKey::from_sec1_bytes(key_id).unwrap();
+        let shared =
+            p256::ecdh::diffie_hellman(ua_secret.to_nonzero_scalar(), as_public.as_affine());
+        let mut key_info = b"WebPush: info\0".to_vec();
+        key_info.extend_from_slice(ua_secret.public_key().to_encoded_point(false).as_bytes());
+        key_info.extend_from_slice(key_id);
+        let mut ikm = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(auth_secret), shared.raw_secret_bytes())
+            .expand(&key_info, &mut ikm)
+            .unwrap();
+
+        let (cek, nonce) = content_key_and_nonce(salt, &ikm).unwrap();
+        let mut record = Aes128Gcm::new_from_slice(&cek)
+            .unwrap()
+            .decrypt(&Nonce::try_from(&nonce[..]).unwrap(), ciphertext)
+            .unwrap();
+        assert_eq!(record.pop(), Some(2), "last-record delimiter");
+        record
+    }
+
+    #[tokio::test]
+    async fn delivers_payload_the_subscriber_can_decrypt() {
+        let ua_secret = random_secret();
+        let auth_secret: [u8; 16] = rand::rng().random();
+        let p256dh =
+            URL_SAFE_NO_PAD.encode(ua_secret.public_key().to_encoded_point(false).as_bytes());
+        let auth = URL_SAFE_NO_PAD.encode(auth_secret);
+
+        let vapid_secret = random_secret();
+        let vapid = VapidKey::from_base64url(
+            &URL_SAFE_NO_PAD.encode(vapid_secret.to_bytes()),
+            "mailto:ops@example.com",
+        )
+        .unwrap();
+
+        let (endpoint, received) = stand_in_push_service(201).await;
+        let target = PushTarget {
+            endpoint: &endpoint,
+            p256dh: &p256dh,
+            auth: &auth,
+        };
+        let payload = br#"{"title":"AxumKit","body":"Alice commented"}"#;
+
+        let outcome = send_web_push(&HttpClient::new(), &vapid, &target, payload, 60)
+            .await
+            .unwrap();
+        assert_eq!(outcome, PushOutcome::Delivered);
+
+        let received = received.await.unwrap();
+        assert_eq!(received.headers["content-encoding"], "aes128gcm");
+        assert_eq!(received.headers["ttl"], "60");
+        assert_eq!(decrypt(&ua_secret, &auth_secret, &received.body), payload);
+
+        // The VAPID token is signed by the advertised key and scoped to the origin.
+        let authorization = &received.headers["authorization"];
+        let (token, key) = authorization
+            .strip_prefix("vapid t=")
+            .and_then(|rest| rest.split_once(", k="))
+            .unwrap();
+        assert_eq!(key, vapid.public_key());
+        let (signing_input, signature) = token.rsplit_once('.').unwrap();
+        let verifying_key =
+            VerifyingKey::from_sec1_bytes(&URL_SAFE_NO_PAD.decode(key).unwrap()).unwrap();
+        let signature = Signature::from_slice(&URL_SAFE_NO_PAD.decode(signature).unwrap()).unwrap();
+        verifying_key
+            .verify(signing_input.as_bytes(), &signature)
+            .unwrap();
+        let claims: serde_json::Value = serde_json::from_slice(
+            &URL_SAFE_NO_PAD
+                .decode(signing_input.split_once('.').unwrap().1)
+                .unwrap(),
+        )
+        .unwrap();
+        let origin = Url::parse(&endpoint)
+            .unwrap()
+            .origin()
+            .ascii_serialization();
+        assert_eq!(claims["aud"], origin);
+        assert_eq!(claims["sub"], "mailto:ops@example.com");
+    }
+
+    #[tokio::test]
+    async fn expired_subscription_reports_gone() {
+        let ua_secret = random_secret();
+        let p256dh =
+            URL_SAFE_NO_PAD.encode(ua_secret.public_key().to_encoded_point(false).as_bytes());
+        let auth = URL_SAFE_NO_PAD.encode([7u8; 16]);
+        let vapid = VapidKey::from_base64url(
+            &URL_SAFE_NO_PAD.encode(random_secret().to_bytes()),
+            "mailto:a@b.c",
+        )
+        .unwrap();
+
+        for status in [404, 410] {
+            let (endpoint, received) = stand_in_push_service(status).await;
+            let target = PushTarget {
+                endpoint: &endpoint,
+                p256dh: &p256dh,
+                auth: &auth,
+            };
+            let outcome = send_web_push(&HttpClient::new(), &vapid, &target, b"{}", 60)
+                .await
+                .unwrap();
+            assert_eq!(outcome, PushOutcome::Gone);
+            received.await.unwrap();
+        }
+
+        let (endpoint, received) = stand_in_push_service(500).await;
+        let target = PushTarget {
+            endpoint: &endpoint,
+            p256dh: &p256dh,
+            auth: &auth,
+        };
+        assert!(
+            send_web_push(&HttpClient::new(), &vapid, &target, b"{}", 60)
+                .await
+                .is_err()
+        );
+        received.await.unwrap();
+    }
+
+    #[test]
+    fn rejects_oversized_payload() {
+        let ua_public = random_secret().public_key();
+        let payload = vec![0u8; MAX_PUSH_PAYLOAD_LEN];
+        let body = encrypt_with(&random_secret(), [0; 16], &ua_public, &[1; 16], &payload).unwrap();
+        assert_eq!(
+            body.len(),
+            4096,
+            "a full payload fills the whole 4096-byte body"
+        );
+
+        let payload = vec![0u8; MAX_PUSH_PAYLOAD_LEN + 1];
+        assert!(encrypt_with(&random_secret(), [0; 16], &ua_public, &[1; 16], &payload).is_err());
+    }
+}
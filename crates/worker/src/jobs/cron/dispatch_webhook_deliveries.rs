@@ -0,0 +1,103 @@
+use crate::DbPool;
+use crate::nats::JetStreamContext;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::WEBHOOK_DELIVERY_SUBJECT;
+use chrono::{DateTime, Duration, Utc};
+use entity::common::WebhookDeliveryStatus;
+use entity::webhook_deliveries::{
+    Column as WebhookDeliveryColumn, Entity as WebhookDeliveryEntity,
+};
+use job_queue::jobs::webhook::WebhookDeliveryJob;
+use sea_orm::sea_query::{Expr, LockBehavior, LockType, Query};
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+/// Deliveries claimed per run.
+const DISPATCH_BATCH_SIZE: u64 = 100;
+
+/// A claimed delivery still `pending` after this long is claimed again: its job
+/// was never published (the worker died or lost NATS after the claim) or never
+/// reached the consumer. Far above normal queue lag, so live jobs are not doubled.
+const STALE_CLAIM_AFTER_MINUTES: i64 = 10;
+
+/// Publish a delivery job for each webhook delivery still in the outbox.
+///
+/// Deliveries are written in the same transaction as the log entry they report,
+/// so nothing reaches a receiver for a rolled-back change. This relay claims
+/// undispatched rows by stamping `dispatched_at` (`FOR UPDATE SKIP LOCKED`, so
+/// overlapping runs on several workers split the rows) and publishes one job per
+/// row. Rows whose publish fails are released for the next run, and claims
+/// still `pending` after [`STALE_CLAIM_AFTER_MINUTES`] are taken again, so a
+/// claim lost between the commit and the publish is not stranded.
+pub async fn run_dispatch_webhook_deliveries(db: &DbPool, jetstream: &JetStreamContext) {
+    let now = Utc::now();
+    let stale_before = now - Duration::minutes(STALE_CLAIM_AFTER_MINUTES);
+    let claimed: Vec<Uuid> = match WebhookDeliveryEntity::update_many()
+        .col_expr(WebhookDeliveryColumn::DispatchedAt, Expr::value(now))
+        .filter(
+            WebhookDeliveryColumn::Id.in_subquery(
+                Query::select()
+                    .column(WebhookDeliveryColumn::Id)
+                    .from(WebhookDeliveryEntity)
+                    .cond_where(
+                        Condition::any()
+                            .add(WebhookDeliveryColumn::DispatchedAt.is_null())
+                            .add(
+                                Condition::all()
+                                    .add(
+                                        WebhookDeliveryColumn::Status
+                                            .eq(WebhookDeliveryStatus::Pending),
+                                    )
+                                    .add(WebhookDeliveryColumn::DispatchedAt.lt(stale_before)),
+                            ),
+                    )
+                    .order_by(WebhookDeliveryColumn::Id, sea_orm::Order::Asc)
+                    .limit(DISPATCH_BATCH_SIZE)
+                    .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+                    .to_owned(),
+            ),
+        )
+        .exec_with_returning(db.as_ref())
+        .await
+    {
+        Ok(rows) => rows.into_iter().map(|row| row.id).collect(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to claim webhook deliveries");
+            return;
+        }
+    };
+
+    if claimed.is_empty() {
+        return;
+    }
+
+    let mut failed = Vec::new();
+    for delivery_id in &claimed {
+        let job = WebhookDeliveryJob {
+            delivery_id: *delivery_id,
+        };
+        if let Err(e) = publish_job(jetstream, WEBHOOK_DELIVERY_SUBJECT, &job).await {
+            tracing::warn!(%delivery_id, error = %e, "Failed to queue webhook delivery");
+            failed.push(*delivery_id);
+        }
+    }
+
+    if !failed.is_empty()
+        && let Err(e) = WebhookDeliveryEntity::update_many()
+            .col_expr(
+                WebhookDeliveryColumn::DispatchedAt,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            )
+            .filter(WebhookDeliveryColumn::Id.is_in(failed.clone()))
+            .exec(db.as_ref())
+            .await
+    {
+        tracing::error!(error = %e, "Failed to release webhook deliveries");
+    }
+
+    tracing::info!(
+        dispatched = claimed.len() - failed.len(),
+        released = failed.len(),
+        "Dispatched webhook deliveries"
+    );
+}
@@ -1,13 +1,17 @@
 mod cleanup;
 mod cleanup_expired_roles;
 mod cleanup_old_notifications;
+mod dispatch_webhook_deliveries;
 mod expiry;
 mod flush_board_view_counts;
+mod refresh_board_hot_scores;
+mod send_notification_digests;
 pub mod sitemap;
 
 use crate::CacheClient;
 use crate::DbPool;
 use crate::LockClient;
+use crate::nats::JetStreamContext;
 use chrono_tz::Tz;
 use config::WorkerConfig;
 use redis::Script;
@@ -33,6 +37,20 @@ const SITEMAP_SCHEDULE: &str = "0 0 3 * * 0";
 /// minute costs one empty Redis roundtrip.
 const FLUSH_BOARD_VIEW_COUNTS_SCHEDULE: &str = "0 * * * * *";
 
+/// Board hot-score refresh schedule: every 5 minutes (at second 30, clear of the
+/// view-count flush so a refresh sees the freshest counts).
+const REFRESH_BOARD_HOT_SCORES_SCHEDULE: &str = "30 */5 * * * *";
+
+/// Notification digest schedule: 8:00 AM every day. Weekly digests go out on
+/// the Monday run.
+const NOTIFICATION_DIGEST_SCHEDULE: &str = "0 0 8 * * *";
+
+/// Webhook outbox relay schedule: every 5 seconds.
+///
+/// Sets how long a committed event waits before its deliveries are queued. An
+/// idle run is one UPDATE that matches nothing on the partial index.
+const DISPATCH_WEBHOOK_DELIVERIES_SCHEDULE: &str = "*/5 * * * * *";
+
 /// Distributed lock TTL for cron jobs (seconds).
 const CRON_LOCK_TTL_SECONDS: u64 = 60 * 30; // 30 minutes
 /// Heartbeat interval for lock extension (seconds).
@@ -40,6 +58,8 @@ const CRON_LOCK_HEARTBEAT_SECONDS: u64 = 60 * 10; // 10 minutes
 
 const CLEANUP_LOCK_KEY: &str = "cron:lock:cleanup";
 const SITEMAP_LOCK_KEY: &str = "cron:lock:sitemap";
+const NOTIFICATION_DIGEST_LOCK_KEY: &str = "cron:lock:notification_digest";
+const REFRESH_BOARD_HOT_SCORES_LOCK_KEY: &str = "cron:lock:refresh_board_hot_scores";
 
 static RELEASE_LOCK_SCRIPT: LazyLock<Script> =
     LazyLock::new(|| Script::new(include_str!("lua/release_lock.lua")));
@@ -51,6 +71,7 @@ pub async fn start_scheduler(
     db_pool: DbPool,
     lock_client: LockClient,
     cache_client: CacheClient,
+    jetstream: JetStreamContext,
     r2_assets: R2AssetsClient,
     config: &'static WorkerConfig,
 ) -> Result<JobScheduler, JobSchedulerError> {
@@ -77,18 +98,54 @@ pub async fn start_scheduler(
         timezone = %timezone,
         "Registering sitemap cron job"
     );
-    let sitemap_job =
-        create_sitemap_job(db_pool.clone(), lock_client, r2_assets, config, timezone)?;
+    let sitemap_job = create_sitemap_job(
+        db_pool.clone(),
+        lock_client.clone(),
+        r2_assets,
+        config,
+        timezone,
+    )?;
     sched.add(sitemap_job).await?;
 
+    tracing::info!(
+        schedule = NOTIFICATION_DIGEST_SCHEDULE,
+        timezone = %timezone,
+        "Registering notification digest cron job"
+    );
+    let digest_job = create_notification_digest_job(
+        db_pool.clone(),
+        lock_client.clone(),
+        jetstream.clone(),
+        timezone,
+    )?;
+    sched.add(digest_job).await?;
+
     tracing::info!(
         schedule = FLUSH_BOARD_VIEW_COUNTS_SCHEDULE,
         timezone = %timezone,
         "Registering board view-count flush cron job"
     );
-    let flush_views_job = create_flush_board_view_counts_job(db_pool, cache_client, timezone)?;
+    let flush_views_job =
+        create_flush_board_view_counts_job(db_pool.clone(), cache_client, timezone)?;
     sched.add(flush_views_job).await?;
 
+    tracing::info!(
+        schedule = REFRESH_BOARD_HOT_SCORES_SCHEDULE,
+        timezone = %timezone,
+        "Registering board hot-score refresh cron job"
+    );
+    let hot_scores_job =
+        create_refresh_board_hot_scores_job(db_pool.clone(), lock_client, timezone)?;
+    sched.add(hot_scores_job).await?;
+
+    tracing::info!(
+        schedule = DISPATCH_WEBHOOK_DELIVERIES_SCHEDULE,
+        timezone = %timezone,
+        "Registering webhook delivery dispatch cron job"
+    );
+    let webhook_job = create_dispatch_webhook_deliveries_job(db_pool, jetstream, timezone)?;
+    sched.add(webhook_job).await?;
+
     sched.start().await?;
 
     Ok(sched)
@@ -147,6 +204,41 @@ fn create_sitemap_job(
         .build()
 }
 
+fn create_notification_digest_job(
+    db_pool: DbPool,
+    lock_client: LockClient,
+    jetstream: JetStreamContext,
+    timezone: Tz,
+) -> Result<Job, JobSchedulerError> {
+    let db = Arc::clone(&db_pool);
+    let lock = lock_client.clone();
+
+    JobBuilder::new()
+        .with_timezone(timezone)
+        .with_cron_job_type()
+        .with_schedule(NOTIFICATION_DIGEST_SCHEDULE)?
+        .with_run_async(Box::new(move |_uuid, _lock| {
+            let db = Arc::clone(&db);
+            let lock = lock.clone();
+            let jetstream = Arc::clone(&jetstream);
+            Box::pin(async move {
+                run_with_cron_lock(
+                    lock,
+                    NOTIFICATION_DIGEST_LOCK_KEY,
+                    "notification_digest",
+                    || async move {
+                        send_notification_digests::run_send_notification_digests(
+                            &db, &jetstream, timezone,
+                        )
+                        .await;
+                    },
+                )
+                .await;
+            })
+        }))
+        .build()
+}
+
 async fn run_with_cron_lock<F, Fut>(
     lock_client: LockClient,
     lock_key: &'static str,
@@ -303,3 +395,56 @@ fn create_flush_board_view_counts_job(
         }))
         .build()
 }
+
+fn create_refresh_board_hot_scores_job(
+    db_pool: DbPool,
+    lock_client: LockClient,
+    timezone: Tz,
+) -> Result<Job, JobSchedulerError> {
+    let db = Arc::clone(&db_pool);
+    let lock = lock_client.clone();
+
+    JobBuilder::new()
+        .with_timezone(timezone)
+        .with_cron_job_type()
+        .with_schedule(REFRESH_BOARD_HOT_SCORES_SCHEDULE)?
+        .with_run_async(Box::new(move |_uuid, _lock| {
+            let db = Arc::clone(&db);
+            let lock = lock.clone();
+            Box::pin(async move {
+                run_with_cron_lock(
+                    lock,
+                    REFRESH_BOARD_HOT_SCORES_LOCK_KEY,
+                    "refresh_board_hot_scores",
+                    || async move {
+                        refresh_board_hot_scores::run_refresh_board_hot_scores(&db).await;
+                    },
+                )
+                .await;
+            })
+        }))
+        .build()
+}
+
+fn create_dispatch_webhook_deliveries_job(
+    db_pool: DbPool,
+    jetstream: JetStreamContext,
+    timezone: Tz,
+) -> Result<Job, JobSchedulerError> {
+    let db = Arc::clone(&db_pool);
+
+    JobBuilder::new()
+        .with_timezone(timezone)
+        .with_cron_job_type()
+        .with_schedule(DISPATCH_WEBHOOK_DELIVERIES_SCHEDULE)?
+        .with_run_async(Box::new(move |_uuid, _lock| {
+            let db = Arc::clone(&db);
+            let jetstream = Arc::clone(&jetstream);
+            Box::pin(async move {
+                // No cron lock: rows are claimed with SKIP LOCKED, so overlapping runs
+                // on several instances split the outbox instead of double-sending.
+                dispatch_webhook_deliveries::run_dispatch_webhook_deliveries(&db, &jetstream).await;
+            })
+        }))
+        .build()
+}
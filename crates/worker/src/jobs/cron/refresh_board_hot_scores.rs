@@ -0,0 +1,60 @@
+use crate::DbPool;
+use chrono::{Duration, Utc};
+use entity::board_posts::{Column as PostColumn, Entity as PostEntity};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+/// Posts older than this stop being rescored and drop to a hot score of 0, so the
+/// refresh only ever touches a bounded, recent slice of `board_posts`.
+const HOT_SCORE_HORIZON_DAYS: i64 = 7;
+
+/// Decay exponent: the score is divided by `(age_hours + 2) ^ gravity`, so a
+/// higher gravity sinks older posts faster.
+const HOT_SCORE_GRAVITY: f64 = 1.5;
+
+/// Recompute `board_posts.hot_score` for the `sort=hot` listing.
+///
+/// Engagement is reactions plus comments (weighted double, as they cost more than
+/// a click) plus the log of views, divided by a time decay. The decay depends on
+/// "now", so scores go stale even without new activity and are recomputed for
+/// every post inside the horizon on each run; posts that aged out are zeroed once.
+/// Runs under the cron lock, so only one worker instance refreshes at a time.
+pub async fn run_refresh_board_hot_scores(db: &DbPool) {
+    match refresh(db).await {
+        Ok((0, 0)) => {}
+        Ok((rescored, expired)) => tracing::info!(
+            rescored_posts = rescored,
+            expired_posts = expired,
+            "Refreshed board hot scores"
+        ),
+        Err(e) => tracing::error!(error = %e, "Failed to refresh board hot scores"),
+    }
+}
+
+async fn refresh(db: &DbPool) -> Result<(u64, u64), anyhow::Error> {
+    let horizon = Utc::now() - Duration::days(HOT_SCORE_HORIZON_DAYS);
+
+    let rescored = PostEntity::update_many()
+        .filter(PostColumn::CreatedAt.gte(horizon))
+        .col_expr(
+            PostColumn::HotScore,
+            Expr::cust_with_values(
+                "(reaction_count + 2 * comment_count + ln(1 + view_count::float8)) \
+                 / power(extract(epoch FROM now() - created_at)::float8 / 3600 + 2, $1)",
+                [HOT_SCORE_GRAVITY],
+            ),
+        )
+        .exec(db.as_ref())
+        .await?
+        .rows_affected;
+
+    let expired = PostEntity::update_many()
+        .filter(PostColumn::CreatedAt.lt(horizon))
+        .filter(PostColumn::HotScore.ne(0.0))
+        .col_expr(PostColumn::HotScore, Expr::value(0.0))
+        .exec(db.as_ref())
+        .await?
+        .rows_affected;
+
+    Ok((rescored, expired))
+}
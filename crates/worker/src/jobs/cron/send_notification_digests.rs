@@ -0,0 +1,225 @@
+use crate::DbPool;
+use crate::jobs::notification::email::describe_notification;
+use crate::nats::JetStreamContext;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::EMAIL_SUBJECT;
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use chrono_tz::Tz;
+use entity::actors::{Column as ActorColumn, Entity as ActorEntity, Relation as ActorRelation};
+use entity::common::NotificationDigestFrequency;
+use entity::notification_deliveries::{
+    Column as NotificationDeliveryColumn, Entity as NotificationDeliveryEntity,
+    Relation as NotificationDeliveryRelation,
+};
+use entity::notification_events::Column as NotificationEventColumn;
+use entity::notification_preferences::{
+    Column as NotificationPreferenceColumn, Entity as NotificationPreferenceEntity,
+    Relation as NotificationPreferenceRelation,
+};
+use entity::users::Column as UserColumn;
+use job_queue::jobs::email::{EmailTemplate, SendEmailJob};
+use sea_orm::prelude::Json;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Weekly digests go out on this weekday's run (in `CRON_TIMEZONE`).
+const WEEKLY_DIGEST_WEEKDAY: Weekday = Weekday::Mon;
+
+/// Subscribers loaded per page.
+const DIGEST_BATCH_SIZE: u64 = 500;
+
+/// Notifications listed in one digest; the rest are only counted.
+const DIGEST_MAX_ITEMS: u64 = 20;
+
+/// A digest subscriber row: preferences joined with the user.
+type Subscriber = (
+    Uuid,
+    String,
+    String,
+    Uuid,
+    NotificationDigestFrequency,
+    Option<DateTime<Utc>>,
+);
+
+/// Email each due digest subscriber a summary of their unread notifications.
+///
+/// Daily subscribers are due on every run, weekly ones on the
+/// `WEEKLY_DIGEST_WEEKDAY` run. A digest covers the unread deliveries created
+/// since the user's previous digest (or one period back for the first one), and
+/// users with nothing new get no email. `last_digest_sent_at` is moved to the
+/// run's start after each send, so a rerun finds nothing left to send.
+pub async fn run_send_notification_digests(
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+    timezone: Tz,
+) {
+    match send_digests(db, jetstream, timezone).await {
+        Ok(0) => {}
+        Ok(sent) => tracing::info!(sent, "Queued notification digests"),
+        Err(e) => tracing::error!(error = %e, "Failed to send notification digests"),
+    }
+}
+
+async fn send_digests(
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+    timezone: Tz,
+) -> Result<usize, anyhow::Error> {
+    let now = Utc::now();
+
+    let mut frequencies = vec![NotificationDigestFrequency::Daily];
+    if now.with_timezone(&timezone).weekday() == WEEKLY_DIGEST_WEEKDAY {
+        frequencies.push(NotificationDigestFrequency::Weekly);
+    }
+
+    let mut sent = 0usize;
+    let mut after: Option<Uuid> = None;
+    loop {
+        let mut query = NotificationPreferenceEntity::find()
+            .join(
+                JoinType::InnerJoin,
+                NotificationPreferenceRelation::User.def(),
+            )
+            .filter(NotificationPreferenceColumn::DigestFrequency.is_in(frequencies.clone()))
+            .filter(UserColumn::DeletedAt.is_null());
+        if let Some(after) = after {
+            query = query.filter(NotificationPreferenceColumn::UserId.gt(after));
+        }
+
+        let subscribers: Vec<Subscriber> = query
+            .select_only()
+            .column(NotificationPreferenceColumn::UserId)
+            .column(UserColumn::Email)
+            .column(UserColumn::DisplayName)
+            .column(NotificationPreferenceColumn::EmailUnsubscribeToken)
+            .column(NotificationPreferenceColumn::DigestFrequency)
+            .column(NotificationPreferenceColumn::LastDigestSentAt)
+            .order_by_asc(NotificationPreferenceColumn::UserId)
+            .limit(DIGEST_BATCH_SIZE)
+            .into_tuple()
+            .all(db.as_ref())
+            .await?;
+
+        let Some(last) = subscribers.last() else {
+            break;
+        };
+        after = Some(last.0);
+        let page_len = subscribers.len() as u64;
+
+        for subscriber in subscribers {
+            let user_id = subscriber.0;
+            match send_digest(db, jetstream, subscriber, now).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                // Left for the next run: last_digest_sent_at was not moved.
+                Err(e) => {
+                    tracing::warn!(user_id = %user_id, error = %e, "Failed to send notification digest")
+                }
+            }
+        }
+
+        if page_len < DIGEST_BATCH_SIZE {
+            break;
+        }
+    }
+
+    Ok(sent)
+}
+
+/// Queue one subscriber's digest; `false` when there was nothing unread to send.
+async fn send_digest(
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+    (user_id, email, display_name, unsubscribe_token, frequency, last_sent_at): Subscriber,
+    now: DateTime<Utc>,
+) -> Result<bool, anyhow::Error> {
+    let (period, period_days) = match frequency {
+        NotificationDigestFrequency::Weekly => ("weekly", 7),
+        _ => ("daily", 1),
+    };
+    let since = last_sent_at.unwrap_or(now - Duration::days(period_days));
+
+    let unread = NotificationDeliveryEntity::find()
+        .join(
+            JoinType::InnerJoin,
+            NotificationDeliveryRelation::Event.def(),
+        )
+        .filter(NotificationDeliveryColumn::UserId.eq(user_id))
+        .filter(NotificationDeliveryColumn::IsRead.eq(false))
+        .filter(NotificationDeliveryColumn::CreatedAt.gt(since))
+        .filter(NotificationDeliveryColumn::CreatedAt.lte(now));
+
+    let total = unread.clone().count(db.as_ref()).await?;
+    if total == 0 {
+        return Ok(false);
+    }
+
+    let events: Vec<(String, Option<Uuid>, Option<Json>)> = unread
+        .select_only()
+        .column(NotificationEventColumn::Action)
+        .column(NotificationEventColumn::ActorId)
+        .column(NotificationEventColumn::AdditionalData)
+        .order_by_desc(NotificationDeliveryColumn::CreatedAt)
+        .limit(DIGEST_MAX_ITEMS)
+        .into_tuple()
+        .all(db.as_ref())
+        .await?;
+
+    let actor_ids: Vec<Uuid> = events.iter().filter_map(|(_, actor, _)| *actor).collect();
+    let actor_names: HashMap<Uuid, String> = if actor_ids.is_empty() {
+        HashMap::new()
+    } else {
+        ActorEntity::find()
+            .join(JoinType::InnerJoin, ActorRelation::User.def())
+            .filter(ActorColumn::Id.is_in(actor_ids))
+            .select_only()
+            .column(ActorColumn::Id)
+            .column(UserColumn::DisplayName)
+            .into_tuple::<(Uuid, String)>()
+            .all(db.as_ref())
+            .await?
+            .into_iter()
+            .collect()
+    };
+
+    let items = events
+        .iter()
+        .map(|(action, actor_id, additional_data)| {
+            let actor_name = actor_id.and_then(|id| actor_names.get(&id));
+            describe_notification(
+                action,
+                actor_name.map(String::as_str),
+                additional_data.as_ref(),
+            )
+        })
+        .collect();
+
+    let email_job = SendEmailJob {
+        to: email,
+        subject: format!("Your {period} notification digest: {total} unread"),
+        template: EmailTemplate::NotificationDigest {
+            username: display_name,
+            period: period.to_string(),
+            items,
+            total,
+            unsubscribe_token,
+        },
+    };
+    publish_job(jetstream, EMAIL_SUBJECT, &email_job).await?;
+
+    NotificationPreferenceEntity::update_many()
+        .filter(NotificationPreferenceColumn::UserId.eq(user_id))
+        .col_expr(
+            NotificationPreferenceColumn::LastDigestSentAt,
+            Expr::value(now),
+        )
+        .exec(db.as_ref())
+        .await?;
+
+    Ok(true)
+}
@@ -4,8 +4,10 @@ use crate::nats::consumer::NatsConsumer;
 use crate::nats::streams::{EMAIL_CONSUMER, EMAIL_STREAM};
 use config::WorkerConfig;
 pub use job_queue::jobs::email::{EmailTemplate, SendEmailJob};
-use lettre::message::{Mailbox, header::ContentType};
+use lettre::message::header::{ContentType, HeaderName, HeaderValue};
+use lettre::message::{Mailbox, MessageBuilder};
 use lettre::{AsyncTransport, Message};
+use uuid::Uuid;
 
 async fn handle_send_email(job: SendEmailJob, mailer: &Mailer) -> Result<(), anyhow::Error> {
     tracing::info!(subject = %job.subject, "Processing email job");
@@ -19,12 +21,21 @@ async fn handle_send_email(job: SendEmailJob, mailer: &Mailer) -> Result<(), any
         format!("{} <{}>", config.emails_from_name, config.emails_from_email).parse()?;
     let to_mailbox: Mailbox = job.to.parse()?;
 
-    let message = Message::builder()
+    let mut builder = Message::builder()
         .from(from_mailbox)
         .to(to_mailbox)
         .subject(&job.subject)
-        .header(ContentType::TEXT_HTML)
-        .body(html_content)?;
+        .header(ContentType::TEXT_HTML);
+    if let EmailTemplate::Notification {
+        unsubscribe_token, ..
+    }
+    | EmailTemplate::NotificationDigest {
+        unsubscribe_token, ..
+    } = &job.template
+    {
+        builder = with_list_unsubscribe(builder, *unsubscribe_token);
+    }
+    let message = builder.body(html_content)?;
 
     mailer.send(message).await?;
 
@@ -133,16 +144,108 @@ fn render_template(template: &EmailTemplate) -> Result<String, anyhow::Error> {
             )
             .map_err(|e| anyhow::anyhow!("Template error: {}", e))?
         }
+        EmailTemplate::MagicLink {
+            username,
+            token,
+            valid_minutes,
+        } => {
+            let sign_in_link = format!(
+                "{}{}?token={}",
+                config.frontend_host,
+                config.frontend_path_magic_link,
+                urlencoding::encode(token)
+            );
+            crate::templates::render_magic_link(
+                &config.project_name,
+                username,
+                &sign_in_link,
+                *valid_minutes,
+            )
+            .map_err(|e| anyhow::anyhow!("Template error: {}", e))?
+        }
         EmailTemplate::SecurityAlert { username, event } => {
             crate::templates::render_security_alert(&config.project_name, username, event)
                 .map_err(|e| anyhow::anyhow!("Template error: {}", e))?
         }
+        EmailTemplate::Notification {
+            username,
+            summary,
+            unsubscribe_token,
+        } => {
+            let link = format!(
+                "{}{}",
+                config.frontend_host, config.frontend_path_notifications
+            );
+            crate::templates::render_notification(
+                &config.project_name,
+                username,
+                summary,
+                &link,
+                &unsubscribe_page_link(*unsubscribe_token),
+            )
+            .map_err(|e| anyhow::anyhow!("Template error: {}", e))?
+        }
+        EmailTemplate::NotificationDigest {
+            username,
+            period,
+            items,
+            total,
+            unsubscribe_token,
+        } => {
+            let link = format!(
+                "{}{}",
+                config.frontend_host, config.frontend_path_notifications
+            );
+            crate::templates::render_notification_digest(
+                &config.project_name,
+                username,
+                period,
+                items,
+                *total,
+                &link,
+                &unsubscribe_page_link(*unsubscribe_token),
+            )
+            .map_err(|e| anyhow::anyhow!("Template error: {}", e))?
+        }
         EmailTemplate::Custom { html_content } => html_content.clone(),
     };
 
     Ok(html)
 }
 
+/// Frontend page that confirms the unsubscribe (the link in the email body).
+fn unsubscribe_page_link(token: Uuid) -> String {
+    let config = WorkerConfig::get();
+    format!(
+        "{}{}?token={}",
+        config.frontend_host, config.frontend_path_unsubscribe_email, token
+    )
+}
+
+/// Adds RFC 2369 `List-Unsubscribe` so mail clients offer their own unsubscribe
+/// button. RFC 8058 one-click needs a URL that accepts the client's POST, i.e. the
+/// API itself (`PUBLIC_API_URL`); without it the header points at the frontend page.
+fn with_list_unsubscribe(builder: MessageBuilder, token: Uuid) -> MessageBuilder {
+    let config = WorkerConfig::get();
+    let list_unsubscribe = HeaderName::new_from_ascii_str("List-Unsubscribe");
+
+    match &config.public_api_url {
+        Some(api_url) => builder
+            .raw_header(HeaderValue::new(
+                list_unsubscribe,
+                format!("<{api_url}/v0/notifications/email/unsubscribe?token={token}>"),
+            ))
+            .raw_header(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                "List-Unsubscribe=One-Click".to_string(),
+            )),
+        None => builder.raw_header(HeaderValue::new(
+            list_unsubscribe,
+            format!("<{}>", unsubscribe_page_link(token)),
+        )),
+    }
+}
+
 /// Run the email consumer
 pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
     let mailer = ctx.mailer.clone();
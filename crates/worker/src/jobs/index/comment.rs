@@ -0,0 +1,146 @@
+use crate::jobs::WorkerContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::streams::{INDEX_COMMENT_CONSUMER, INDEX_COMMENT_STREAM};
+use crate::{DbPool, SearchClient};
+use entity::{board_comments, board_posts};
+use meilisearch_sdk::documents::DocumentDeletionQuery;
+use sea_orm::EntityTrait;
+use uuid::Uuid;
+
+pub use job_queue::jobs::index::comment::{CommentIndexAction, IndexCommentJob};
+
+pub use search_index::comments::{COMMENTS_INDEX, SearchComment};
+
+/// Build a [`SearchComment`] from a board comment and the board of its post.
+pub fn build_comment_search_json(comment: &board_comments::Model, board_id: Uuid) -> SearchComment {
+    SearchComment {
+        id: comment.id.to_string(),
+        board_id: board_id.to_string(),
+        post_id: comment.post_id.to_string(),
+        thread_id: comment.parent_comment_id.unwrap_or(comment.id).to_string(),
+        actor_id: comment.actor_id.to_string(),
+        content: comment.content.clone(),
+        created_at: comment.created_at.timestamp(),
+        is_hidden: comment.hidden_at.is_some(),
+    }
+}
+
+/// MeiliSearch index settings for board comments
+fn comment_index_settings() -> meilisearch_sdk::settings::Settings {
+    meilisearch_sdk::settings::Settings::new()
+        .with_searchable_attributes(["content"])
+        .with_filterable_attributes(["board_id", "post_id", "thread_id", "is_hidden"])
+        .with_sortable_attributes(["created_at"])
+        .with_ranking_rules([
+            "words",
+            "typo",
+            "proximity",
+            "attribute",
+            "sort",
+            "exactness",
+        ])
+}
+
+async fn handle_index_comment(
+    job: IndexCommentJob,
+    client: &SearchClient,
+    db: &DbPool,
+) -> Result<(), anyhow::Error> {
+    tracing::info!(
+        "Processing comment index job: comment_id={}, action={:?}",
+        job.comment_id,
+        job.action
+    );
+
+    let index = client.index(COMMENTS_INDEX);
+
+    // Ensure index exists and settings are applied
+    ensure_index_settings(client).await?;
+
+    let found = match job.action {
+        CommentIndexAction::Index => board_comments::Entity::find_by_id(job.comment_id)
+            .find_also_related(board_posts::Entity)
+            .one(db.as_ref())
+            .await?
+            .and_then(|(comment, post)| post.map(|post| (comment, post.board_id))),
+        CommentIndexAction::Delete => None,
+    };
+
+    match found {
+        Some((comment, board_id)) => {
+            let search_comment = build_comment_search_json(&comment, board_id);
+
+            // Add to index (upsert)
+            index.add_documents(&[search_comment], Some("id")).await?;
+            tracing::info!("Comment {} indexed successfully", job.comment_id);
+        }
+        // Deleted, or deleted again before an index job for it ran. Replies
+        // cascade with a top-level comment, so drop the whole thread.
+        None => {
+            index.delete_document(&job.comment_id.to_string()).await?;
+            let filter = format!("thread_id = \"{}\"", job.comment_id);
+            index
+                .delete_documents_with(DocumentDeletionQuery::new(&index).with_filter(&filter))
+                .await?;
+            tracing::info!("Comment {} deleted from index", job.comment_id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure index exists with proper settings
+pub async fn ensure_index_settings(
+    client: &meilisearch_sdk::client::Client,
+) -> Result<(), anyhow::Error> {
+    ensure_index_settings_for(client, COMMENTS_INDEX).await
+}
+
+/// Ensure the given index uid exists with comment index settings
+/// (used by reindex to prepare its temp index)
+pub async fn ensure_index_settings_for(
+    client: &meilisearch_sdk::client::Client,
+    index_uid: &str,
+) -> Result<(), anyhow::Error> {
+    let index = client.index(index_uid);
+
+    match index.get_stats().await {
+        Ok(_) => Ok(()),
+        Err(meilisearch_sdk::errors::Error::Meilisearch(ref e))
+            if e.error_code == meilisearch_sdk::errors::ErrorCode::IndexNotFound =>
+        {
+            tracing::info!(index = index_uid, "Creating comments index");
+            let task = client.create_index(index_uid, Some("id")).await?;
+            task.wait_for_completion(client, None, None).await?;
+
+            tracing::info!(index = index_uid, "Applying comments index settings");
+            let index = client.index(index_uid);
+            let task = index.set_settings(&comment_index_settings()).await?;
+            task.wait_for_completion(client, None, None).await?;
+
+            Ok(())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Run the comment index consumer
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let meili_client = ctx.meili_client.clone();
+    let db_pool = ctx.db_pool.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        INDEX_COMMENT_STREAM,
+        INDEX_COMMENT_CONSUMER,
+        2, // concurrency
+    );
+
+    consumer
+        .run::<IndexCommentJob, _, _>(move |job| {
+            let client = meili_client.clone();
+            let db = db_pool.clone();
+            async move { handle_index_comment(job, &client, &db).await }
+        })
+        .await
+}
@@ -0,0 +1,148 @@
+use crate::jobs::WorkerContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::streams::{INDEX_POST_CONSUMER, INDEX_POST_STREAM};
+use crate::{DbPool, SearchClient};
+use entity::board_posts;
+use meilisearch_sdk::documents::DocumentDeletionQuery;
+use sea_orm::EntityTrait;
+use search_index::comments::COMMENTS_INDEX;
+
+pub use job_queue::jobs::index::post::{IndexPostJob, PostIndexAction};
+
+pub use search_index::posts::{POSTS_INDEX, SearchPost};
+
+/// Build a [`SearchPost`] from a board post model.
+pub fn build_post_search_json(post: &board_posts::Model) -> SearchPost {
+    SearchPost {
+        id: post.id.to_string(),
+        board_id: post.board_id.to_string(),
+        actor_id: post.actor_id.to_string(),
+        title: post.title.clone(),
+        content: post.content.clone(),
+        created_at: post.created_at.timestamp(),
+        is_hidden: post.hidden_at.is_some(),
+    }
+}
+
+/// MeiliSearch index settings for board posts
+fn post_index_settings() -> meilisearch_sdk::settings::Settings {
+    meilisearch_sdk::settings::Settings::new()
+        .with_searchable_attributes(["title", "content"])
+        .with_filterable_attributes(["board_id", "is_hidden"])
+        .with_sortable_attributes(["created_at"])
+        .with_ranking_rules([
+            "words",
+            "typo",
+            "proximity",
+            "attribute",
+            "sort",
+            "exactness",
+        ])
+}
+
+async fn handle_index_post(
+    job: IndexPostJob,
+    client: &SearchClient,
+    db: &DbPool,
+) -> Result<(), anyhow::Error> {
+    tracing::info!(
+        "Processing post index job: post_id={}, action={:?}",
+        job.post_id,
+        job.action
+    );
+
+    let index = client.index(POSTS_INDEX);
+
+    // Ensure index exists and settings are applied (the comments index too,
+    // since a delete filters it by post)
+    ensure_index_settings(client).await?;
+    super::comment::ensure_index_settings(client).await?;
+
+    let post = match job.action {
+        PostIndexAction::Index => {
+            board_posts::Entity::find_by_id(job.post_id)
+                .one(db.as_ref())
+                .await?
+        }
+        PostIndexAction::Delete => None,
+    };
+
+    match post {
+        Some(post) => {
+            let search_post = build_post_search_json(&post);
+
+            // Add to index (upsert)
+            index.add_documents(&[search_post], Some("id")).await?;
+            tracing::info!("Post {} indexed successfully", job.post_id);
+        }
+        // Deleted, or deleted again before an index job for it ran. Its
+        // comments cascade with it, so drop them from their index as well.
+        None => {
+            index.delete_document(&job.post_id.to_string()).await?;
+            let comments = client.index(COMMENTS_INDEX);
+            let filter = format!("post_id = \"{}\"", job.post_id);
+            comments
+                .delete_documents_with(DocumentDeletionQuery::new(&comments).with_filter(&filter))
+                .await?;
+            tracing::info!("Post {} deleted from index", job.post_id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure index exists with proper settings
+pub async fn ensure_index_settings(
+    client: &meilisearch_sdk::client::Client,
+) -> Result<(), anyhow::Error> {
+    ensure_index_settings_for(client, POSTS_INDEX).await
+}
+
+/// Ensure the given index uid exists with post index settings
+/// (used by reindex to prepare its temp index)
+pub async fn ensure_index_settings_for(
+    client: &meilisearch_sdk::client::Client,
+    index_uid: &str,
+) -> Result<(), anyhow::Error> {
+    let index = client.index(index_uid);
+
+    match index.get_stats().await {
+        Ok(_) => Ok(()),
+        Err(meilisearch_sdk::errors::Error::Meilisearch(ref e))
+            if e.error_code == meilisearch_sdk::errors::ErrorCode::IndexNotFound =>
+        {
+            tracing::info!(index = index_uid, "Creating posts index");
+            let task = client.create_index(index_uid, Some("id")).await?;
+            task.wait_for_completion(client, None, None).await?;
+
+            tracing::info!(index = index_uid, "Applying posts index settings");
+            let index = client.index(index_uid);
+            let task = index.set_settings(&post_index_settings()).await?;
+            task.wait_for_completion(client, None, None).await?;
+
+            Ok(())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Run the post index consumer
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let meili_client = ctx.meili_client.clone();
+    let db_pool = ctx.db_pool.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        INDEX_POST_STREAM,
+        INDEX_POST_CONSUMER,
+        2, // concurrency
+    );
+
+    consumer
+        .run::<IndexPostJob, _, _>(move |job| {
+            let client = meili_client.clone();
+            let db = db_pool.clone();
+            async move { handle_index_post(job, &client, &db).await }
+        })
+        .await
+}
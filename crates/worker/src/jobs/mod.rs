@@ -1,8 +1,10 @@
 pub mod cron;
 pub mod email;
 pub mod index;
+pub mod notification;
 pub mod oauth;
 pub mod reindex;
+pub mod webhook;
 
 // Re-exports for backward compatibility with server
 pub use index::user as user_index;
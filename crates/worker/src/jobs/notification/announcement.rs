@@ -0,0 +1,152 @@
+use crate::DbPool;
+use crate::jobs::WorkerContext;
+use crate::nats::JetStreamContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::{
+    NOTIFICATION_ANNOUNCEMENT_CONSUMER, NOTIFICATION_ANNOUNCEMENT_STREAM,
+    NOTIFICATION_EMAIL_SUBJECT, NOTIFICATION_PUSH_SUBJECT,
+};
+use constants::NotificationAction;
+use entity::notification_events::{
+    Column as NotificationEventColumn, Entity as NotificationEventEntity,
+};
+use entity::users::{Column as UserColumn, Entity as UserEntity};
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
+use notification_repository::{
+    filter_recipients_by_action_preference, insert_notification_deliveries,
+};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait};
+use uuid::Uuid;
+
+pub use job_queue::jobs::notification::{
+    NotificationEmailJob, NotificationPushJob, SystemAnnouncementFanOutJob,
+};
+
+/// Users paged, preference-checked and delivered per transaction.
+const ANNOUNCEMENT_BATCH_SIZE: usize = 1000;
+
+/// Deliver a system announcement to every active (not deleted) user.
+///
+/// Unlike the watcher fan-out, the audience is the whole user table, so each
+/// batch commits on its own. Before writing, a batch takes a share lock on the
+/// event row and stops if it was deleted or retracted: the retract takes the
+/// same row for update, so once it commits no later batch can slip a delivery
+/// past its cleanup. Deliveries are unique per (user, event), which makes a
+/// redelivered job resume by re-walking the users and skipping those already
+/// served; only users newly delivered to in this attempt are signalled, emailed
+/// and pushed. Opt-outs of `system_announcement` are honoured unless the
+/// announcement is mandatory.
+async fn handle_system_announcement_fanout(
+    job: SystemAnnouncementFanOutJob,
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+) -> Result<(), anyhow::Error> {
+    let nats_client = jetstream.client();
+    let realtime_event = UserRealtimeEvent::NotificationCreated {
+        event_id: job.event_id,
+    };
+
+    let mut delivered = 0u64;
+    let mut after: Option<Uuid> = None;
+    loop {
+        let mut query = UserEntity::find()
+            .filter(UserColumn::DeletedAt.is_null())
+            .select_only()
+            .column(UserColumn::Id)
+            .order_by_asc(UserColumn::Id)
+            .limit(ANNOUNCEMENT_BATCH_SIZE as u64);
+        if let Some(after) = after {
+            query = query.filter(UserColumn::Id.gt(after));
+        }
+
+        let batch: Vec<Uuid> = query.into_tuple().all(db.as_ref()).await?;
+        let Some(last) = batch.last().copied() else {
+            break;
+        };
+        let full = batch.len() == ANNOUNCEMENT_BATCH_SIZE;
+
+        let txn = db.begin().await?;
+        let event = NotificationEventEntity::find_by_id(job.event_id)
+            .filter(NotificationEventColumn::RetractedAt.is_null())
+            .lock_shared()
+            .one(&txn)
+            .await?;
+        let Some(event) = event else {
+            txn.rollback().await?;
+            tracing::info!(event_id = %job.event_id, delivered, "System announcement retracted; fan-out stopped");
+            return Ok(());
+        };
+
+        let recipients = if job.mandatory {
+            batch
+        } else {
+            filter_recipients_by_action_preference(
+                &txn,
+                batch,
+                NotificationAction::SystemAnnouncement,
+                ANNOUNCEMENT_BATCH_SIZE,
+            )
+            .await?
+        };
+        // Users served by an earlier attempt conflict and are left out, so a
+        // retried batch does not email or push them a second time.
+        let inserted =
+            insert_notification_deliveries(&txn, &event, &recipients, ANNOUNCEMENT_BATCH_SIZE)
+                .await?;
+        txn.commit().await?;
+        delivered += inserted.len() as u64;
+
+        if !inserted.is_empty() {
+            // Best-effort, as in the comment fan-out: the batch is already committed.
+            for user_id in &inserted {
+                publish_user_realtime_event(&nats_client, *user_id, &realtime_event).await;
+            }
+            let email_job = NotificationEmailJob {
+                event_id: job.event_id,
+                user_ids: inserted.clone(),
+            };
+            if let Err(e) = publish_job(jetstream, NOTIFICATION_EMAIL_SUBJECT, &email_job).await {
+                tracing::warn!(event_id = %job.event_id, error = %e, "Failed to queue notification email job");
+            }
+            let push_job = NotificationPushJob {
+                event_id: job.event_id,
+                user_ids: inserted,
+            };
+            if let Err(e) = publish_job(jetstream, NOTIFICATION_PUSH_SUBJECT, &push_job).await {
+                tracing::warn!(event_id = %job.event_id, error = %e, "Failed to queue notification push job");
+            }
+        }
+
+        if !full {
+            break;
+        }
+        after = Some(last);
+    }
+
+    tracing::info!(event_id = %job.event_id, delivered, "Fanned out system announcement");
+
+    Ok(())
+}
+
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let db_pool = ctx.db_pool.clone();
+    let jetstream = ctx.jetstream.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        NOTIFICATION_ANNOUNCEMENT_STREAM,
+        NOTIFICATION_ANNOUNCEMENT_CONSUMER,
+        1, // concurrency
+    )
+    // One run walks the whole user table; a retry resumes past delivered users.
+    .with_handler_timeout(std::time::Duration::from_secs(900));
+
+    consumer
+        .run::<SystemAnnouncementFanOutJob, _, _>(move |job| {
+            let db = db_pool.clone();
+            let jetstream = jetstream.clone();
+            async move { handle_system_announcement_fanout(job, &db, &jetstream).await }
+        })
+        .await
+}
@@ -0,0 +1,157 @@
+use crate::DbPool;
+use crate::jobs::WorkerContext;
+use crate::nats::JetStreamContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::{
+    NOTIFICATION_EMAIL_SUBJECT, NOTIFICATION_FANOUT_CONSUMER, NOTIFICATION_FANOUT_STREAM,
+    NOTIFICATION_PUSH_SUBJECT,
+};
+use constants::NotificationAction;
+use entity::board_post_watchers::{Column as WatcherColumn, Entity as WatcherEntity};
+use entity::common::NotificationType;
+use job_queue::realtime::{UserRealtimeEvent, publish_user_realtime_event};
+use notification_repository::{
+    NotificationEventInsertSpec, NotificationTarget, filter_recipients_by_action_preference,
+    insert_notification_event_deliveries,
+};
+use sea_orm::prelude::IpNetwork;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+pub use job_queue::jobs::notification::{
+    BoardCommentFanOutJob, NotificationEmailJob, NotificationPushJob,
+};
+
+/// Rows read, preference-checked and inserted per round trip.
+const FANOUT_BATCH_SIZE: usize = 500;
+
+/// Deliver a `board_comment_created` notification to every watcher of the post.
+///
+/// Watchers are paged by `user_id` so a heavily watched post never loads in one
+/// query, and the recipients the server already notified directly (the
+/// commenter, mentioned users) are skipped. The event and every delivery batch
+/// are written in one transaction: a failed run leaves nothing behind for the
+/// redelivery to duplicate. Once committed, each recipient's open notification
+/// streams are signalled on their realtime subject and the email and push copies
+/// are queued, all best-effort: failing the job now would only re-run a committed fan-out.
+async fn handle_board_comment_fanout(
+    job: BoardCommentFanOutJob,
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+) -> Result<(), anyhow::Error> {
+    let skip: HashSet<Uuid> = job.skip_user_ids.iter().copied().collect();
+
+    let mut recipients = Vec::new();
+    let mut after: Option<Uuid> = None;
+    loop {
+        let mut query = WatcherEntity::find()
+            .filter(WatcherColumn::PostId.eq(job.post_id))
+            .select_only()
+            .column(WatcherColumn::UserId)
+            .order_by_asc(WatcherColumn::UserId)
+            .limit(FANOUT_BATCH_SIZE as u64);
+        if let Some(after) = after {
+            query = query.filter(WatcherColumn::UserId.gt(after));
+        }
+
+        let batch: Vec<Uuid> = query.into_tuple().all(db.as_ref()).await?;
+        let Some(last) = batch.last().copied() else {
+            break;
+        };
+        let full = batch.len() == FANOUT_BATCH_SIZE;
+
+        let candidates = batch
+            .into_iter()
+            .filter(|user_id| !skip.contains(user_id))
+            .collect();
+        recipients.extend(
+            filter_recipients_by_action_preference(
+                db.as_ref(),
+                candidates,
+                NotificationAction::BoardCommentCreated,
+                FANOUT_BATCH_SIZE,
+            )
+            .await?,
+        );
+
+        if !full {
+            break;
+        }
+        after = Some(last);
+    }
+
+    let spec = NotificationEventInsertSpec {
+        actor_id: Some(job.actor_id),
+        actor_ip: job.actor_ip.map(IpNetwork::from),
+        notification_type: NotificationType::Board,
+        action: NotificationAction::BoardCommentCreated,
+        target: NotificationTarget::BoardComment {
+            board_id: job.board_id,
+            post_id: job.post_id,
+            comment_id: job.comment_id,
+        },
+        additional_data: job.additional_data,
+    };
+
+    let txn = db.begin().await?;
+    let event_id =
+        insert_notification_event_deliveries(&txn, &recipients, spec, FANOUT_BATCH_SIZE).await?;
+    txn.commit().await?;
+
+    if let Some(event_id) = event_id {
+        let nats_client = jetstream.client();
+        let event = UserRealtimeEvent::NotificationCreated { event_id };
+        for user_id in &recipients {
+            publish_user_realtime_event(&nats_client, *user_id, &event).await;
+        }
+
+        let delivered = recipients.len();
+        let email_job = NotificationEmailJob {
+            event_id,
+            user_ids: recipients.clone(),
+        };
+        if let Err(e) = publish_job(jetstream, NOTIFICATION_EMAIL_SUBJECT, &email_job).await {
+            tracing::warn!(event_id = %event_id, error = %e, "Failed to queue notification email job");
+        }
+        let push_job = NotificationPushJob {
+            event_id,
+            user_ids: recipients,
+        };
+        if let Err(e) = publish_job(jetstream, NOTIFICATION_PUSH_SUBJECT, &push_job).await {
+            tracing::warn!(event_id = %event_id, error = %e, "Failed to queue notification push job");
+        }
+
+        tracing::info!(
+            comment_id = %job.comment_id,
+            post_id = %job.post_id,
+            delivered,
+            "Fanned out board comment notification to watchers"
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let db_pool = ctx.db_pool.clone();
+    let jetstream = ctx.jetstream.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        NOTIFICATION_FANOUT_STREAM,
+        NOTIFICATION_FANOUT_CONSUMER,
+        4, // concurrency
+    )
+    // A run that committed but failed to ack would otherwise notify everyone twice.
+    .with_dedup(ctx.lock_client.clone());
+
+    consumer
+        .run::<BoardCommentFanOutJob, _, _>(move |job| {
+            let db = db_pool.clone();
+            let jetstream = jetstream.clone();
+            async move { handle_board_comment_fanout(job, &db, &jetstream).await }
+        })
+        .await
+}
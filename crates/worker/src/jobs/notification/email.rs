@@ -0,0 +1,224 @@
+use crate::jobs::WorkerContext;
+use crate::nats::JetStreamContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::{EMAIL_SUBJECT, NOTIFICATION_EMAIL_CONSUMER, NOTIFICATION_EMAIL_STREAM};
+use crate::{CacheClient, DbPool};
+use constants::NotificationAction;
+use entity::actors::Entity as ActorEntity;
+use entity::notification_events::{
+    Column as NotificationEventColumn, Entity as NotificationEventEntity,
+    Model as NotificationEventModel,
+};
+use entity::notification_preferences::{
+    Column as NotificationPreferenceColumn, Entity as NotificationPreferenceEntity,
+    Relation as NotificationPreferenceRelation,
+};
+use entity::users::{Column as UserColumn, Entity as UserEntity};
+use job_queue::jobs::email::{EmailTemplate, SendEmailJob};
+use sea_orm::prelude::Json;
+use sea_orm::{ColumnTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait};
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub use job_queue::jobs::notification::NotificationEmailJob;
+
+/// Seconds during which a user gets at most one notification email per thread
+/// (post). Anything after the first stays in the inbox only. Events without a
+/// post (announcements) each get their own slot, so they are never throttled by
+/// one another.
+const NOTIFICATION_EMAIL_THROTTLE_SECONDS: u64 = 15 * 60;
+
+/// Email one notification event to the recipients who turned email on.
+///
+/// Per-action opt-outs were already applied when the deliveries were written, so
+/// `email_enabled` is the only check left. Each recipient is throttled per thread
+/// in Redis, then handed to the email stream as a `Notification` template that
+/// carries their unsubscribe token. An event deleted in the meantime (retention
+/// cleanup) or a retracted announcement sends nothing.
+async fn handle_notification_email(
+    job: NotificationEmailJob,
+    db: &DbPool,
+    cache_client: &CacheClient,
+    jetstream: &JetStreamContext,
+) -> Result<(), anyhow::Error> {
+    if job.user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let Some(event) = NotificationEventEntity::find_by_id(job.event_id)
+        .filter(NotificationEventColumn::RetractedAt.is_null())
+        .one(db.as_ref())
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let recipients: Vec<(Uuid, String, String, Uuid)> = NotificationPreferenceEntity::find()
+        .join(
+            JoinType::InnerJoin,
+            NotificationPreferenceRelation::User.def(),
+        )
+        .filter(NotificationPreferenceColumn::UserId.is_in(job.user_ids.clone()))
+        .filter(NotificationPreferenceColumn::EmailEnabled.eq(true))
+        .filter(UserColumn::DeletedAt.is_null())
+        .select_only()
+        .column(NotificationPreferenceColumn::UserId)
+        .column(UserColumn::Email)
+        .column(UserColumn::DisplayName)
+        .column(NotificationPreferenceColumn::EmailUnsubscribeToken)
+        .into_tuple()
+        .all(db.as_ref())
+        .await?;
+
+    if recipients.is_empty() {
+        return Ok(());
+    }
+
+    let summary = notification_summary(db, &event).await?;
+
+    let mut sent = 0usize;
+    for (user_id, email, display_name, unsubscribe_token) in recipients {
+        let slot_key = email_slot_key(user_id, &event);
+        if !claim_email_slot(cache_client, &slot_key).await? {
+            continue;
+        }
+
+        let email_job = SendEmailJob {
+            to: email,
+            subject: summary.clone(),
+            template: EmailTemplate::Notification {
+                username: display_name,
+                summary: summary.clone(),
+                unsubscribe_token,
+            },
+        };
+        if let Err(e) = publish_job(jetstream, EMAIL_SUBJECT, &email_job).await {
+            // Give the slot back so the redelivered job emails this user.
+            release_email_slot(cache_client, &slot_key).await;
+            return Err(e);
+        }
+        sent += 1;
+    }
+
+    if sent > 0 {
+        tracing::info!(event_id = %event.id, sent, "Queued notification emails");
+    }
+
+    Ok(())
+}
+
+/// Throttle key for `user_id` and the event's thread: the post, or the event
+/// itself when it has none.
+fn email_slot_key(user_id: Uuid, event: &NotificationEventModel) -> String {
+    match event.post_id {
+        Some(post_id) => format!("notification_email:throttle:{user_id}:{post_id}"),
+        None => format!("notification_email:throttle:{user_id}:event:{}", event.id),
+    }
+}
+
+/// Claim an email slot: `SET NX EX`, so only the first notification of a burst
+/// gets through until the key expires.
+async fn claim_email_slot(
+    cache_client: &CacheClient,
+    key: &str,
+) -> Result<bool, redis::RedisError> {
+    let mut conn = cache_client.as_ref().clone();
+    let result: Option<String> = redis::cmd("SET")
+        .arg(key)
+        .arg(1)
+        .arg("NX")
+        .arg("EX")
+        .arg(NOTIFICATION_EMAIL_THROTTLE_SECONDS)
+        .query_async(&mut conn)
+        .await?;
+
+    Ok(result.is_some())
+}
+
+/// Release a claimed slot whose email was never queued. Best-effort: a failure
+/// only throttles that user until the key expires.
+async fn release_email_slot(cache_client: &CacheClient, key: &str) {
+    let mut conn = cache_client.as_ref().clone();
+    let result: Result<(), redis::RedisError> =
+        redis::cmd("DEL").arg(key).query_async(&mut conn).await;
+    if let Err(e) = result {
+        tracing::warn!(key, error = %e, "Failed to release notification email slot");
+    }
+}
+
+/// One line describing the event, used as both subject and headline.
+async fn notification_summary(
+    db: &DbPool,
+    event: &NotificationEventModel,
+) -> Result<String, anyhow::Error> {
+    let actor_name = match event.actor_id {
+        Some(actor_id) => ActorEntity::find_by_id(actor_id)
+            .find_also_related(UserEntity)
+            .one(db.as_ref())
+            .await?
+            .and_then(|(_, user)| user)
+            .map(|user| user.display_name),
+        None => None,
+    };
+
+    Ok(describe_notification(
+        &event.action,
+        actor_name.as_deref(),
+        event.additional_data.as_ref(),
+    ))
+}
+
+/// Render a notification as one line of text ("Alice commented on “Title”").
+///
+/// `actor_name` is the actor's display name when they still have a user account;
+/// `additional_data` carries the `post_title` snapshot taken at creation, or an
+/// announcement's `title`.
+pub(crate) fn describe_notification(
+    action: &str,
+    actor_name: Option<&str>,
+    additional_data: Option<&Json>,
+) -> String {
+    let actor_name = actor_name.unwrap_or("Someone");
+    let post_title = additional_data
+        .and_then(|data| data["post_title"].as_str())
+        .unwrap_or("a post");
+
+    match NotificationAction::from_str(action) {
+        Ok(NotificationAction::BoardCommentCreated) => {
+            format!("{actor_name} commented on \u{201c}{post_title}\u{201d}")
+        }
+        Ok(NotificationAction::UserMentioned) => {
+            format!("{actor_name} mentioned you in \u{201c}{post_title}\u{201d}")
+        }
+        Ok(NotificationAction::SystemAnnouncement) => {
+            match additional_data.and_then(|data| data["title"].as_str()) {
+                Some(title) => format!("Announcement: {title}"),
+                None => "New announcement".to_string(),
+            }
+        }
+        _ => "You have a new notification".to_string(),
+    }
+}
+
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let db_pool = ctx.db_pool.clone();
+    let cache_client = ctx.cache_client.clone();
+    let jetstream = ctx.jetstream.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        NOTIFICATION_EMAIL_STREAM,
+        NOTIFICATION_EMAIL_CONSUMER,
+        2, // concurrency
+    );
+
+    consumer
+        .run::<NotificationEmailJob, _, _>(move |job| {
+            let db = db_pool.clone();
+            let cache_client = cache_client.clone();
+            let jetstream = jetstream.clone();
+            async move { handle_notification_email(job, &db, &cache_client, &jetstream).await }
+        })
+        .await
+}
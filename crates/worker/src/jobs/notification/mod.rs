@@ -0,0 +1,9 @@
+pub mod announcement;
+pub mod comment_fanout;
+pub mod email;
+pub mod push;
+
+pub use announcement::SystemAnnouncementFanOutJob;
+pub use comment_fanout::BoardCommentFanOutJob;
+pub use email::NotificationEmailJob;
+pub use push::NotificationPushJob;
@@ -0,0 +1,198 @@
+use crate::DbPool;
+use crate::clients::{PushOutcome, PushTarget, VapidKey, send_web_push};
+use crate::jobs::WorkerContext;
+use crate::jobs::notification::email::describe_notification;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::streams::{NOTIFICATION_PUSH_CONSUMER, NOTIFICATION_PUSH_STREAM};
+use config::WorkerConfig;
+use entity::actors::Entity as ActorEntity;
+use entity::notification_events::{
+    Column as NotificationEventColumn, Entity as NotificationEventEntity,
+};
+use entity::notification_preferences::{
+    Column as NotificationPreferenceColumn, Entity as NotificationPreferenceEntity,
+    Relation as NotificationPreferenceRelation,
+};
+use entity::push_subscriptions::{
+    Column as PushSubscriptionColumn, Entity as PushSubscriptionEntity,
+};
+use entity::users::{Column as UserColumn, Entity as UserEntity};
+use futures::StreamExt;
+use reqwest::Client as HttpClient;
+use sea_orm::{ColumnTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+pub use job_queue::jobs::notification::NotificationPushJob;
+
+/// How long a push service may hold a notification for an offline device.
+const PUSH_TTL_SECS: u32 = 24 * 60 * 60;
+
+/// Push requests in flight at once within one job.
+const PUSH_CONCURRENCY: usize = 8;
+
+/// Push one notification event to every device of the recipients who turned
+/// push on.
+///
+/// Like email, per-action opt-outs were applied when the deliveries were
+/// written, so `push_enabled` is the only check left. Each subscription gets
+/// the same small JSON payload (`title`, `body`, `url`, `event_id`, `action`),
+/// encrypted for that device. A subscription the push service reports gone
+/// (404/410) is deleted. Other failures are only logged: retrying the job would
+/// push again to the devices that already got it.
+async fn handle_notification_push(
+    job: NotificationPushJob,
+    db: &DbPool,
+    http_client: &HttpClient,
+    vapid: &VapidKey,
+) -> Result<(), anyhow::Error> {
+    if job.user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let Some(event) = NotificationEventEntity::find_by_id(job.event_id)
+        .filter(NotificationEventColumn::RetractedAt.is_null())
+        .one(db.as_ref())
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let recipients: Vec<Uuid> = NotificationPreferenceEntity::find()
+        .join(
+            JoinType::InnerJoin,
+            NotificationPreferenceRelation::User.def(),
+        )
+        .filter(NotificationPreferenceColumn::UserId.is_in(job.user_ids))
+        .filter(NotificationPreferenceColumn::PushEnabled.eq(true))
+        .filter(UserColumn::DeletedAt.is_null())
+        .select_only()
+        .column(NotificationPreferenceColumn::UserId)
+        .into_tuple()
+        .all(db.as_ref())
+        .await?;
+
+    if recipients.is_empty() {
+        return Ok(());
+    }
+
+    let subscriptions = PushSubscriptionEntity::find()
+        .filter(PushSubscriptionColumn::UserId.is_in(recipients))
+        .all(db.as_ref())
+        .await?;
+
+    if subscriptions.is_empty() {
+        return Ok(());
+    }
+
+    let actor_name = match event.actor_id {
+        Some(actor_id) => ActorEntity::find_by_id(actor_id)
+            .find_also_related(UserEntity)
+            .one(db.as_ref())
+            .await?
+            .and_then(|(_, user)| user)
+            .map(|user| user.display_name),
+        None => None,
+    };
+
+    let config = WorkerConfig::get();
+    let payload = serde_json::to_vec(&serde_json::json!({
+        "title": config.project_name,
+        "body": describe_notification(
+            &event.action,
+            actor_name.as_deref(),
+            event.additional_data.as_ref(),
+        ),
+        "url": format!("{}{}", config.frontend_host, config.frontend_path_notifications),
+        "event_id": event.id,
+        "action": event.action,
+    }))?;
+
+    let results: Vec<(Uuid, anyhow::Result<PushOutcome>)> =
+        futures::stream::iter(subscriptions.into_iter().map(|subscription| {
+            let payload = &payload;
+            async move {
+                let target = PushTarget {
+                    endpoint: &subscription.endpoint,
+                    p256dh: &subscription.p256dh,
+                    auth: &subscription.auth,
+                };
+                let outcome =
+                    send_web_push(http_client, vapid, &target, payload, PUSH_TTL_SECS).await;
+                (subscription.id, outcome)
+            }
+        }))
+        .buffer_unordered(PUSH_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut delivered = 0usize;
+    let mut gone = Vec::new();
+    for (subscription_id, outcome) in results {
+        match outcome {
+            Ok(PushOutcome::Delivered) => delivered += 1,
+            Ok(PushOutcome::Gone) => gone.push(subscription_id),
+            Err(e) => {
+                tracing::warn!(event_id = %event.id, %subscription_id, error = %e, "Failed to send web push");
+            }
+        }
+    }
+
+    if !gone.is_empty() {
+        PushSubscriptionEntity::delete_many()
+            .filter(PushSubscriptionColumn::Id.is_in(gone.clone()))
+            .exec(db.as_ref())
+            .await?;
+    }
+
+    tracing::info!(
+        event_id = %event.id,
+        delivered,
+        pruned = gone.len(),
+        "Sent web push notifications"
+    );
+
+    Ok(())
+}
+
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    // Without a VAPID key the jobs are still consumed, so the stream does not
+    // back up, but nothing is sent.
+    let vapid = match &ctx.config.vapid_private_key {
+        Some(private_key) => Some(Arc::new(VapidKey::from_base64url(
+            private_key,
+            ctx.config.vapid_subject.clone(),
+        )?)),
+        None => {
+            tracing::info!("VAPID_PRIVATE_KEY is not set; web push is disabled");
+            None
+        }
+    };
+    let http_client = HttpClient::builder()
+        .user_agent("axumkit-worker/1.0")
+        .timeout(Duration::from_secs(15))
+        .build()?;
+    let db_pool = ctx.db_pool.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        NOTIFICATION_PUSH_STREAM,
+        NOTIFICATION_PUSH_CONSUMER,
+        2, // concurrency
+    );
+
+    consumer
+        .run::<NotificationPushJob, _, _>(move |job| {
+            let db = db_pool.clone();
+            let http_client = http_client.clone();
+            let vapid = vapid.clone();
+            async move {
+                match vapid {
+                    Some(vapid) => handle_notification_push(job, &db, &http_client, &vapid).await,
+                    None => Ok(()),
+                }
+            }
+        })
+        .await
+}
@@ -0,0 +1,192 @@
+use super::common::{
+    REINDEX_HANDLER_TIMEOUT, promote_reindexed_index, record_reindex_progress, reindex_temp_uid,
+    run_reindex_batch,
+};
+use super::{ReindexCommentsJob, ReindexJobBase};
+use crate::jobs::WorkerContext;
+use crate::jobs::index::comment::{
+    COMMENTS_INDEX, build_comment_search_json, ensure_index_settings_for,
+};
+use crate::nats::JetStreamContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::{
+    REINDEX_COMMENTS_CONSUMER, REINDEX_COMMENTS_STREAM, REINDEX_COMMENTS_SUBJECT,
+};
+use crate::{CacheClient, DbPool, SearchClient};
+use entity::{board_comments, board_posts};
+use job_queue::jobs::reindex::{ReindexStatus, ReindexTarget};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// Handle a batch reindex job for board comments
+async fn handle_reindex_comments(
+    job: ReindexCommentsJob,
+    client: &SearchClient,
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+    cache: &CacheClient,
+) -> Result<(), anyhow::Error> {
+    tracing::info!(
+        reindex_id = %job.base.reindex_id,
+        batch_number = job.base.batch_number,
+        after_id = ?job.base.after_id,
+        batch_size = job.base.batch_size,
+        "Processing comment reindex batch"
+    );
+
+    // Build into a temp index and swap at the end so search stays available
+    // and untouched on failure during reindex.
+    let temp_uid = reindex_temp_uid(COMMENTS_INDEX);
+
+    // Progress is recorded against this batch; the first one also counts the total.
+    let mut base = job.base.clone();
+
+    // First batch: prepare the temp index and clear leftovers from an aborted run.
+    if job.base.after_id.is_none() {
+        ensure_index_settings_for(client, &temp_uid).await?;
+        client.index(&temp_uid).delete_all_documents().await?;
+
+        let total = board_comments::Entity::find().count(db.as_ref()).await?;
+        tracing::info!(
+            reindex_id = %job.base.reindex_id,
+            total_comments = total,
+            "Starting comment reindex"
+        );
+        base.total_count = Some(total);
+    }
+
+    // Fetch batch of comments
+    let comments_batch =
+        fetch_comments_batch(db.as_ref(), job.base.after_id, job.base.batch_size).await?;
+
+    if comments_batch.is_empty() {
+        promote_reindexed_index(client, COMMENTS_INDEX).await?;
+        record_reindex_progress(
+            cache,
+            ReindexTarget::Comments,
+            &base,
+            base.processed_count,
+            ReindexStatus::Completed,
+            None,
+        )
+        .await;
+        tracing::info!(
+            reindex_id = %job.base.reindex_id,
+            total_batches = job.base.batch_number,
+            "Comment reindex completed"
+        );
+        return Ok(());
+    }
+
+    // Build search documents
+    let search_docs: Vec<_> = comments_batch
+        .iter()
+        .map(|(comment, board_id)| build_comment_search_json(comment, *board_id))
+        .collect();
+
+    // Index batch to MeiliSearch temp index.
+    let index = client.index(&temp_uid);
+    index.add_documents(&search_docs, Some("id")).await?;
+
+    let processed_count = comments_batch.len();
+    let last_id = comments_batch
+        .last()
+        .map(|(comment, _)| comment.id)
+        .ok_or_else(|| anyhow::anyhow!("comments_batch unexpectedly empty"))?;
+
+    tracing::info!(
+        reindex_id = %job.base.reindex_id,
+        batch_number = job.base.batch_number,
+        processed = processed_count,
+        last_id = %last_id,
+        "Batch processed"
+    );
+
+    let processed_total = base.processed_count + processed_count as u64;
+    record_reindex_progress(
+        cache,
+        ReindexTarget::Comments,
+        &base,
+        processed_total,
+        ReindexStatus::Running,
+        None,
+    )
+    .await;
+
+    // Self-enqueue next batch via NATS
+    let next_job = ReindexCommentsJob {
+        base: ReindexJobBase {
+            after_id: Some(last_id),
+            batch_size: job.base.batch_size,
+            reindex_id: job.base.reindex_id,
+            batch_number: job.base.batch_number + 1,
+            processed_count: processed_total,
+            total_count: base.total_count,
+        },
+    };
+
+    publish_job(jetstream, REINDEX_COMMENTS_SUBJECT, &next_job).await?;
+
+    Ok(())
+}
+
+/// Fetch a batch of comments, each paired with its post's board id, using
+/// UUID v7 cursor pagination
+async fn fetch_comments_batch(
+    db: &sea_orm::DatabaseConnection,
+    after_id: Option<Uuid>,
+    batch_size: u32,
+) -> Result<Vec<(board_comments::Model, Uuid)>, anyhow::Error> {
+    let mut query = board_comments::Entity::find()
+        .find_also_related(board_posts::Entity)
+        .order_by_asc(board_comments::Column::Id);
+
+    if let Some(cursor) = after_id {
+        query = query.filter(board_comments::Column::Id.gt(cursor));
+    }
+
+    let comments = query
+        .limit(batch_size as u64)
+        .all(db)
+        .await?
+        .into_iter()
+        // The post FK cascades, so a comment always has its post.
+        .filter_map(|(comment, post)| post.map(|post| (comment, post.board_id)))
+        .collect();
+
+    Ok(comments)
+}
+
+/// Run the reindex comments consumer
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let meili_client = ctx.meili_client.clone();
+    let db_pool = ctx.db_pool.clone();
+    let jetstream = ctx.jetstream.clone();
+    let cache_client = ctx.cache_client.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        REINDEX_COMMENTS_STREAM,
+        REINDEX_COMMENTS_CONSUMER,
+        1, // concurrency
+    )
+    .with_handler_timeout(REINDEX_HANDLER_TIMEOUT)
+    // Dedup on stream sequence so a redelivered (lost-ack) batch does not
+    // re-enqueue its successor and fork the reindex chain.
+    .with_dedup(ctx.lock_client.clone());
+
+    consumer
+        .run_with_delivery::<ReindexCommentsJob, _, _>(move |job, delivery| {
+            let client = meili_client.clone();
+            let db = db_pool.clone();
+            let js = jetstream.clone();
+            let cache = cache_client.clone();
+            async move {
+                let base = job.base.clone();
+                let batch = handle_reindex_comments(job, &client, &db, &js, &cache);
+                run_reindex_batch(&cache, ReindexTarget::Comments, &base, delivery, batch).await
+            }
+        })
+        .await
+}
@@ -1,7 +1,18 @@
 // Common utilities for reindex jobs.
 
-use crate::SearchClient;
+use super::ReindexJobBase;
+use crate::nats::consumer::{Delivery, panic_message};
+use crate::{CacheClient, SearchClient};
+use chrono::Utc;
+use futures::FutureExt;
+use job_queue::jobs::reindex::{
+    REINDEX_PROGRESS_TTL_SECONDS, ReindexProgress, ReindexStatus, ReindexTarget,
+    reindex_progress_key,
+};
 use meilisearch_sdk::client::SwapIndexes;
+use redis::AsyncCommands;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::time::Duration;
 
 /// Polling interval while waiting for the swap task
@@ -9,6 +20,13 @@ const SWAP_WAIT_INTERVAL: Duration = Duration::from_secs(1);
 /// Max time to wait for the swap task (it queues behind all add_documents tasks)
 const SWAP_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
 
+/// Consumer handler timeout for reindex batches, which re-index many records
+/// and can legitimately outlast the default.
+pub const REINDEX_HANDLER_TIMEOUT: Duration = Duration::from_secs(900);
+/// A batch is cut off here, ahead of the consumer's timeout, so that a hung
+/// batch still gets its failure recorded.
+const REINDEX_BATCH_TIMEOUT: Duration = Duration::from_secs(870);
+
 /// Temp index uid used to build a reindex before swapping it into place
 pub fn reindex_temp_uid(index_uid: &str) -> String {
     format!("{index_uid}_reindex")
@@ -69,3 +87,107 @@ pub async fn promote_reindexed_index(
         }
     }
 }
+
+/// Record a reindex run's progress for the admin status endpoint.
+///
+/// `base` is the batch that just ran (with `total_count` filled in once known)
+/// and `processed_count` the running total after it. Best-effort: a Redis
+/// failure only leaves the status stale, it never fails the batch.
+pub async fn record_reindex_progress(
+    cache: &CacheClient,
+    target: ReindexTarget,
+    base: &ReindexJobBase,
+    processed_count: u64,
+    status: ReindexStatus,
+    error: Option<String>,
+) {
+    let key = reindex_progress_key(base.reindex_id);
+    let mut conn = (**cache).clone();
+    let now = Utc::now().timestamp();
+
+    // Keep the start time the server recorded when it queued the run.
+    let started_at = conn
+        .get::<_, Option<String>>(&key)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|raw| serde_json::from_str::<ReindexProgress>(&raw).ok())
+        .map_or(now, |progress| progress.started_at);
+
+    let progress = ReindexProgress {
+        reindex_id: base.reindex_id,
+        target,
+        status,
+        batch_number: base.batch_number,
+        processed_count,
+        total_count: base.total_count,
+        error,
+        started_at,
+        updated_at: now,
+    };
+
+    let payload = match serde_json::to_string(&progress) {
+        Ok(payload) => payload,
+        Err(e) => {
+            tracing::warn!(reindex_id = %base.reindex_id, error = %e, "Failed to serialize reindex progress");
+            return;
+        }
+    };
+    if let Err(e) = conn
+        .set_ex::<_, _, ()>(&key, payload, REINDEX_PROGRESS_TTL_SECONDS)
+        .await
+    {
+        tracing::warn!(reindex_id = %base.reindex_id, error = %e, "Failed to record reindex progress");
+    }
+}
+
+/// Run one reindex batch, recording a failed attempt in the run's progress.
+///
+/// The batch is bounded and panic-guarded here rather than only by the
+/// consumer, so a timeout or panic is recorded like any other error. A failure
+/// on the final delivery marks the run `Failed`; earlier ones keep it `Running`
+/// with the error while NATS retries.
+pub async fn run_reindex_batch<F>(
+    cache: &CacheClient,
+    target: ReindexTarget,
+    base: &ReindexJobBase,
+    delivery: Delivery,
+    batch: F,
+) -> Result<(), anyhow::Error>
+where
+    F: Future<Output = Result<(), anyhow::Error>>,
+{
+    let result = match tokio::time::timeout(
+        REINDEX_BATCH_TIMEOUT,
+        AssertUnwindSafe(batch).catch_unwind(),
+    )
+    .await
+    {
+        Ok(Ok(result)) => result,
+        Ok(Err(panic)) => Err(anyhow::anyhow!(
+            "reindex batch panicked: {}",
+            panic_message(panic)
+        )),
+        Err(_) => Err(anyhow::anyhow!(
+            "reindex batch timed out after {REINDEX_BATCH_TIMEOUT:?}"
+        )),
+    };
+
+    if let Err(e) = &result {
+        let status = if delivery.is_final {
+            ReindexStatus::Failed
+        } else {
+            ReindexStatus::Running
+        };
+        record_reindex_progress(
+            cache,
+            target,
+            base,
+            base.processed_count,
+            status,
+            Some(e.to_string()),
+        )
+        .await;
+    }
+    result
+}
@@ -1,6 +1,11 @@
+pub mod comments;
 pub mod common;
+pub mod posts;
 pub mod users;
 
 // Reindex job payloads and their pure constructors live in the shared job_queue
 // contract; the batch-processing consumers below are worker-only.
-pub use job_queue::jobs::reindex::{ReindexJobBase, ReindexUsersJob, create_reindex_users_job};
+pub use job_queue::jobs::reindex::{
+    ReindexCommentsJob, ReindexJobBase, ReindexPostsJob, ReindexUsersJob,
+    create_reindex_comments_job, create_reindex_posts_job, create_reindex_users_job,
+};
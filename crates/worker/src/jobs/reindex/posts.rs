@@ -0,0 +1,175 @@
+use super::common::{
+    REINDEX_HANDLER_TIMEOUT, promote_reindexed_index, record_reindex_progress, reindex_temp_uid,
+    run_reindex_batch,
+};
+use super::{ReindexJobBase, ReindexPostsJob};
+use crate::jobs::WorkerContext;
+use crate::jobs::index::post::{POSTS_INDEX, build_post_search_json, ensure_index_settings_for};
+use crate::nats::JetStreamContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::publisher::publish_job;
+use crate::nats::streams::{REINDEX_POSTS_CONSUMER, REINDEX_POSTS_STREAM, REINDEX_POSTS_SUBJECT};
+use crate::{CacheClient, DbPool, SearchClient};
+use entity::board_posts;
+use job_queue::jobs::reindex::{ReindexStatus, ReindexTarget};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
+use uuid::Uuid;
+
+/// Handle a batch reindex job for board posts
+async fn handle_reindex_posts(
+    job: ReindexPostsJob,
+    client: &SearchClient,
+    db: &DbPool,
+    jetstream: &JetStreamContext,
+    cache: &CacheClient,
+) -> Result<(), anyhow::Error> {
+    tracing::info!(
+        reindex_id = %job.base.reindex_id,
+        batch_number = job.base.batch_number,
+        after_id = ?job.base.after_id,
+        batch_size = job.base.batch_size,
+        "Processing post reindex batch"
+    );
+
+    // Build into a temp index and swap at the end so search stays available
+    // and untouched on failure during reindex.
+    let temp_uid = reindex_temp_uid(POSTS_INDEX);
+
+    // Progress is recorded against this batch; the first one also counts the total.
+    let mut base = job.base.clone();
+
+    // First batch: prepare the temp index and clear leftovers from an aborted run.
+    if job.base.after_id.is_none() {
+        ensure_index_settings_for(client, &temp_uid).await?;
+        client.index(&temp_uid).delete_all_documents().await?;
+
+        let total = board_posts::Entity::find().count(db.as_ref()).await?;
+        tracing::info!(
+            reindex_id = %job.base.reindex_id,
+            total_posts = total,
+            "Starting post reindex"
+        );
+        base.total_count = Some(total);
+    }
+
+    // Fetch batch of posts
+    let posts_batch =
+        fetch_posts_batch(db.as_ref(), job.base.after_id, job.base.batch_size).await?;
+
+    if posts_batch.is_empty() {
+        promote_reindexed_index(client, POSTS_INDEX).await?;
+        record_reindex_progress(
+            cache,
+            ReindexTarget::Posts,
+            &base,
+            base.processed_count,
+            ReindexStatus::Completed,
+            None,
+        )
+        .await;
+        tracing::info!(
+            reindex_id = %job.base.reindex_id,
+            total_batches = job.base.batch_number,
+            "Post reindex completed"
+        );
+        return Ok(());
+    }
+
+    // Build search documents
+    let search_docs: Vec<_> = posts_batch.iter().map(build_post_search_json).collect();
+
+    // Index batch to MeiliSearch temp index.
+    let index = client.index(&temp_uid);
+    index.add_documents(&search_docs, Some("id")).await?;
+
+    let processed_count = posts_batch.len();
+    let last_id = posts_batch
+        .last()
+        .map(|u| u.id)
+        .ok_or_else(|| anyhow::anyhow!("posts_batch unexpectedly empty"))?;
+
+    tracing::info!(
+        reindex_id = %job.base.reindex_id,
+        batch_number = job.base.batch_number,
+        processed = processed_count,
+        last_id = %last_id,
+        "Batch processed"
+    );
+
+    let processed_total = base.processed_count + processed_count as u64;
+    record_reindex_progress(
+        cache,
+        ReindexTarget::Posts,
+        &base,
+        processed_total,
+        ReindexStatus::Running,
+        None,
+    )
+    .await;
+
+    // Self-enqueue next batch via NATS
+    let next_job = ReindexPostsJob {
+        base: ReindexJobBase {
+            after_id: Some(last_id),
+            batch_size: job.base.batch_size,
+            reindex_id: job.base.reindex_id,
+            batch_number: job.base.batch_number + 1,
+            processed_count: processed_total,
+            total_count: base.total_count,
+        },
+    };
+
+    publish_job(jetstream, REINDEX_POSTS_SUBJECT, &next_job).await?;
+
+    Ok(())
+}
+
+/// Fetch a batch of posts using UUID v7 cursor pagination
+async fn fetch_posts_batch(
+    db: &sea_orm::DatabaseConnection,
+    after_id: Option<Uuid>,
+    batch_size: u32,
+) -> Result<Vec<board_posts::Model>, anyhow::Error> {
+    let mut query = board_posts::Entity::find().order_by_asc(board_posts::Column::Id);
+
+    if let Some(cursor) = after_id {
+        query = query.filter(board_posts::Column::Id.gt(cursor));
+    }
+
+    let posts = query.limit(batch_size as u64).all(db).await?;
+
+    Ok(posts)
+}
+
+/// Run the reindex posts consumer
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    let meili_client = ctx.meili_client.clone();
+    let db_pool = ctx.db_pool.clone();
+    let jetstream = ctx.jetstream.clone();
+    let cache_client = ctx.cache_client.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        REINDEX_POSTS_STREAM,
+        REINDEX_POSTS_CONSUMER,
+        1, // concurrency
+    )
+    .with_handler_timeout(REINDEX_HANDLER_TIMEOUT)
+    // Dedup on stream sequence so a redelivered (lost-ack) batch does not
+    // re-enqueue its successor and fork the reindex chain.
+    .with_dedup(ctx.lock_client.clone());
+
+    consumer
+        .run_with_delivery::<ReindexPostsJob, _, _>(move |job, delivery| {
+            let client = meili_client.clone();
+            let db = db_pool.clone();
+            let js = jetstream.clone();
+            let cache = cache_client.clone();
+            async move {
+                let base = job.base.clone();
+                let batch = handle_reindex_posts(job, &client, &db, &js, &cache);
+                run_reindex_batch(&cache, ReindexTarget::Posts, &base, delivery, batch).await
+            }
+        })
+        .await
+}
@@ -1,4 +1,7 @@
-use super::common::{promote_reindexed_index, reindex_temp_uid};
+use super::common::{
+    REINDEX_HANDLER_TIMEOUT, promote_reindexed_index, record_reindex_progress, reindex_temp_uid,
+    run_reindex_batch,
+};
 use super::{ReindexJobBase, ReindexUsersJob};
 use crate::jobs::WorkerContext;
 use crate::jobs::index::user::{USERS_INDEX, build_user_search_json, ensure_index_settings_for};
@@ -6,8 +9,9 @@ use crate::nats::JetStreamContext;
 use crate::nats::consumer::NatsConsumer;
 use crate::nats::publisher::publish_job;
 use crate::nats::streams::{REINDEX_USERS_CONSUMER, REINDEX_USERS_STREAM, REINDEX_USERS_SUBJECT};
-use crate::{DbPool, SearchClient};
+use crate::{CacheClient, DbPool, SearchClient};
 use entity::users;
+use job_queue::jobs::reindex::{ReindexStatus, ReindexTarget};
 use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
 use uuid::Uuid;
 
@@ -17,6 +21,7 @@ async fn handle_reindex_users(
     client: &SearchClient,
     db: &DbPool,
     jetstream: &JetStreamContext,
+    cache: &CacheClient,
 ) -> Result<(), anyhow::Error> {
     tracing::info!(
         reindex_id = %job.base.reindex_id,
@@ -30,6 +35,9 @@ async fn handle_reindex_users(
     // and untouched on failure during reindex.
     let temp_uid = reindex_temp_uid(USERS_INDEX);
 
+    // Progress is recorded against this batch; the first one also counts the total.
+    let mut base = job.base.clone();
+
     // First batch: prepare the temp index and clear leftovers from an aborted run.
     if job.base.after_id.is_none() {
         ensure_index_settings_for(client, &temp_uid).await?;
@@ -41,6 +49,7 @@ async fn handle_reindex_users(
             total_users = total,
             "Starting user reindex"
         );
+        base.total_count = Some(total);
     }
 
     // Fetch batch of users
@@ -49,6 +58,15 @@ async fn handle_reindex_users(
 
     if users_batch.is_empty() {
         promote_reindexed_index(client, USERS_INDEX).await?;
+        record_reindex_progress(
+            cache,
+            ReindexTarget::Users,
+            &base,
+            base.processed_count,
+            ReindexStatus::Completed,
+            None,
+        )
+        .await;
         tracing::info!(
             reindex_id = %job.base.reindex_id,
             total_batches = job.base.batch_number,
@@ -78,6 +96,17 @@ async fn handle_reindex_users(
         "Batch processed"
     );
 
+    let processed_total = base.processed_count + processed_count as u64;
+    record_reindex_progress(
+        cache,
+        ReindexTarget::Users,
+        &base,
+        processed_total,
+        ReindexStatus::Running,
+        None,
+    )
+    .await;
+
     // Self-enqueue next batch via NATS
     let next_job = ReindexUsersJob {
         base: ReindexJobBase {
@@ -85,6 +114,8 @@ async fn handle_reindex_users(
             batch_size: job.base.batch_size,
             reindex_id: job.base.reindex_id,
             batch_number: job.base.batch_number + 1,
+            processed_count: processed_total,
+            total_count: base.total_count,
         },
     };
 
@@ -115,6 +146,7 @@ pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
     let meili_client = ctx.meili_client.clone();
     let db_pool = ctx.db_pool.clone();
     let jetstream = ctx.jetstream.clone();
+    let cache_client = ctx.cache_client.clone();
 
     let consumer = NatsConsumer::new(
         ctx.jetstream.clone(),
@@ -122,19 +154,22 @@ pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
         REINDEX_USERS_CONSUMER,
         1, // concurrency
     )
-    // A reindex batch re-indexes many records and can legitimately outlast the
-    // default handler timeout.
-    .with_handler_timeout(std::time::Duration::from_secs(900))
+    .with_handler_timeout(REINDEX_HANDLER_TIMEOUT)
     // Dedup on stream sequence so a redelivered (lost-ack) batch does not
     // re-enqueue its successor and fork the reindex chain.
     .with_dedup(ctx.lock_client.clone());
 
     consumer
-        .run::<ReindexUsersJob, _, _>(move |job| {
+        .run_with_delivery::<ReindexUsersJob, _, _>(move |job, delivery| {
             let client = meili_client.clone();
             let db = db_pool.clone();
             let js = jetstream.clone();
-            async move { handle_reindex_users(job, &client, &db, &js).await }
+            let cache = cache_client.clone();
+            async move {
+                let base = job.base.clone();
+                let batch = handle_reindex_users(job, &client, &db, &js, &cache);
+                run_reindex_batch(&cache, ReindexTarget::Users, &base, delivery, batch).await
+            }
         })
         .await
 }
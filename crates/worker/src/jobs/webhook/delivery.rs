@@ -0,0 +1,206 @@
+use crate::DbPool;
+use crate::jobs::WorkerContext;
+use crate::nats::consumer::NatsConsumer;
+use crate::nats::streams::{WEBHOOK_DELIVERY_CONSUMER, WEBHOOK_DELIVERY_STREAM};
+use anyhow::anyhow;
+use auth_core::keyed_hash::hmac_sha256_hex;
+use chrono::Utc;
+use entity::common::WebhookDeliveryStatus;
+use entity::webhook_deliveries::{
+    Column as WebhookDeliveryColumn, Entity as WebhookDeliveryEntity,
+};
+use entity::webhook_endpoints::Entity as WebhookEndpointEntity;
+use reqwest::Client as HttpClient;
+use reqwest::redirect::Policy;
+use sea_orm::sea_query::{Expr, ExprTrait};
+use sea_orm::{ActiveEnum, ColumnTrait, EntityTrait, QueryFilter};
+use std::time::Duration;
+use uuid::Uuid;
+
+pub use job_queue::jobs::webhook::WebhookDeliveryJob;
+
+/// How long an endpoint may take to answer one attempt.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest response-body excerpt kept in `last_error`.
+const ERROR_BODY_LIMIT: usize = 500;
+
+/// POST one logged delivery to its endpoint.
+///
+/// The body is the stored payload, signed as `t={unix},v1={hex}` in
+/// `X-AxumKit-Signature`, where the hex is HMAC-SHA256 over `"{t}.{body}"` with
+/// the endpoint's current secret. Every attempt is recorded on the row. A
+/// non-2xx answer or transport error returns `Err`, so the consumer retries with
+/// its backoff and dead-letters the job once the attempts run out. A delivery
+/// whose endpoint was paused is marked failed without sending.
+async fn handle_webhook_delivery(
+    job: WebhookDeliveryJob,
+    db: &DbPool,
+    http_client: &HttpClient,
+) -> Result<(), anyhow::Error> {
+    let Some((delivery, Some(endpoint))) = WebhookDeliveryEntity::find_by_id(job.delivery_id)
+        .find_also_related(WebhookEndpointEntity)
+        .one(db.as_ref())
+        .await?
+    else {
+        // Deleted along with its endpoint
+        return Ok(());
+    };
+
+    if !endpoint.is_active {
+        record_attempt(
+            db,
+            delivery.id,
+            WebhookDeliveryStatus::Failed,
+            None,
+            Some("endpoint is paused".to_string()),
+            false,
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let body = serde_json::to_string(&delivery.payload)?;
+    let timestamp = Utc::now().timestamp();
+    let signature = hmac_sha256_hex(
+        endpoint.secret.as_bytes(),
+        format!("{timestamp}.{body}").as_bytes(),
+    );
+
+    let result = http_client
+        .post(&endpoint.url)
+        .header("Content-Type", "application/json")
+        .header("X-AxumKit-Webhook-Id", delivery.log_id.to_string())
+        .header("X-AxumKit-Webhook-Event", &delivery.event)
+        .header(
+            "X-AxumKit-Signature",
+            format!("t={timestamp},v1={signature}"),
+        )
+        .body(body)
+        .send()
+        .await;
+
+    let (status_code, error) = match result {
+        Ok(response) if response.status().is_success() => {
+            (Some(response.status().as_u16() as i32), None)
+        }
+        Ok(response) => {
+            let status = response.status();
+            let mut text = response.text().await.unwrap_or_default();
+            if text.len() > ERROR_BODY_LIMIT {
+                let mut end = ERROR_BODY_LIMIT;
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                text.truncate(end);
+            }
+            (
+                Some(status.as_u16() as i32),
+                Some(format!("HTTP {status}: {text}")),
+            )
+        }
+        Err(e) => (None, Some(e.to_string())),
+    };
+
+    match error {
+        None => {
+            record_attempt(
+                db,
+                delivery.id,
+                WebhookDeliveryStatus::Succeeded,
+                status_code,
+                None,
+                true,
+            )
+            .await?;
+            tracing::info!(
+                delivery_id = %delivery.id,
+                endpoint_id = %endpoint.id,
+                event = %delivery.event,
+                "Webhook delivered"
+            );
+            Ok(())
+        }
+        Some(error) => {
+            record_attempt(
+                db,
+                delivery.id,
+                WebhookDeliveryStatus::Failed,
+                status_code,
+                Some(error.clone()),
+                true,
+            )
+            .await?;
+            Err(anyhow!("webhook delivery {} failed: {error}", delivery.id))
+        }
+    }
+}
+
+/// Store the outcome of an attempt. `sent` counts it towards `attempts`.
+async fn record_attempt(
+    db: &DbPool,
+    delivery_id: Uuid,
+    status: WebhookDeliveryStatus,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    sent: bool,
+) -> Result<(), anyhow::Error> {
+    let delivered_at = (status == WebhookDeliveryStatus::Succeeded).then(Utc::now);
+    let attempts_increment = if sent { 1 } else { 0 };
+
+    WebhookDeliveryEntity::update_many()
+        .col_expr(WebhookDeliveryColumn::Status, ActiveEnum::as_enum(&status))
+        .col_expr(
+            WebhookDeliveryColumn::Attempts,
+            Expr::col(WebhookDeliveryColumn::Attempts).add(attempts_increment),
+        )
+        .col_expr(
+            WebhookDeliveryColumn::ResponseStatus,
+            Expr::value(response_status),
+        )
+        .col_expr(WebhookDeliveryColumn::LastError, Expr::value(last_error))
+        .col_expr(
+            WebhookDeliveryColumn::DeliveredAt,
+            Expr::value(delivered_at),
+        )
+        .filter(WebhookDeliveryColumn::Id.eq(delivery_id))
+        .exec(db.as_ref())
+        .await?;
+
+    Ok(())
+}
+
+pub async fn run_consumer(ctx: WorkerContext) -> anyhow::Result<()> {
+    // Receivers are admin-registered URLs; redirects are not followed so a
+    // delivery only ever reaches the URL that was configured.
+    let http_client = HttpClient::builder()
+        .user_agent("axumkit-webhooks/1.0")
+        .timeout(WEBHOOK_TIMEOUT)
+        .redirect(Policy::none())
+        .build()?;
+    let db_pool = ctx.db_pool.clone();
+
+    let consumer = NatsConsumer::new(
+        ctx.jetstream.clone(),
+        WEBHOOK_DELIVERY_STREAM,
+        WEBHOOK_DELIVERY_CONSUMER,
+        8, // concurrency
+    )
+    // Outages on the receiving side tend to last minutes to hours, so retries
+    // back off further than the default.
+    .with_backoff(vec![
+        Duration::from_secs(10),
+        Duration::from_secs(60),
+        Duration::from_secs(5 * 60),
+        Duration::from_secs(30 * 60),
+        Duration::from_secs(2 * 60 * 60),
+    ]);
+
+    consumer
+        .run::<WebhookDeliveryJob, _, _>(move |job| {
+            let db = db_pool.clone();
+            let http_client = http_client.clone();
+            async move { handle_webhook_delivery(job, &db, &http_client).await }
+        })
+        .await
+}
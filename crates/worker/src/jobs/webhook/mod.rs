@@ -0,0 +1,3 @@
+pub mod delivery;
+
+pub use delivery::WebhookDeliveryJob;
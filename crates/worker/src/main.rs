@@ -22,15 +22,33 @@ enum ConsumerKind {
     Email,
     IndexUser,
     ReindexUsers,
+    IndexPost,
+    ReindexPosts,
+    IndexComment,
+    ReindexComments,
     OAuthProfileImage,
+    NotificationFanOut,
+    NotificationEmail,
+    NotificationAnnouncement,
+    NotificationPush,
+    WebhookDelivery,
 }
 
 impl ConsumerKind {
-    const ALL: [Self; 4] = [
+    const ALL: [Self; 13] = [
         Self::Email,
         Self::IndexUser,
         Self::ReindexUsers,
+        Self::IndexPost,
+        Self::ReindexPosts,
+        Self::IndexComment,
+        Self::ReindexComments,
         Self::OAuthProfileImage,
+        Self::NotificationFanOut,
+        Self::NotificationEmail,
+        Self::NotificationAnnouncement,
+        Self::NotificationPush,
+        Self::WebhookDelivery,
     ];
 
     fn name(self) -> &'static str {
@@ -38,7 +56,16 @@ impl ConsumerKind {
             Self::Email => "email",
             Self::IndexUser => "index_user",
             Self::ReindexUsers => "reindex_users",
+            Self::IndexPost => "index_post",
+            Self::ReindexPosts => "reindex_posts",
+            Self::IndexComment => "index_comment",
+            Self::ReindexComments => "reindex_comments",
             Self::OAuthProfileImage => "oauth_profile_image",
+            Self::NotificationFanOut => "notification_fanout",
+            Self::NotificationEmail => "notification_email",
+            Self::NotificationAnnouncement => "notification_announcement",
+            Self::NotificationPush => "notification_push",
+            Self::WebhookDelivery => "webhook_delivery",
         }
     }
 }
@@ -88,7 +115,20 @@ async fn run_consumer(kind: ConsumerKind, ctx: WorkerContext) -> Result<()> {
         ConsumerKind::Email => jobs::email::run_consumer(ctx).await,
         ConsumerKind::IndexUser => jobs::index::user::run_consumer(ctx).await,
         ConsumerKind::ReindexUsers => jobs::reindex::users::run_consumer(ctx).await,
+        ConsumerKind::IndexPost => jobs::index::post::run_consumer(ctx).await,
+        ConsumerKind::ReindexPosts => jobs::reindex::posts::run_consumer(ctx).await,
+        ConsumerKind::IndexComment => jobs::index::comment::run_consumer(ctx).await,
+        ConsumerKind::ReindexComments => jobs::reindex::comments::run_consumer(ctx).await,
         ConsumerKind::OAuthProfileImage => jobs::oauth::run_consumer(ctx).await,
+        ConsumerKind::NotificationFanOut => {
+            jobs::notification::comment_fanout::run_consumer(ctx).await
+        }
+        ConsumerKind::NotificationEmail => jobs::notification::email::run_consumer(ctx).await,
+        ConsumerKind::NotificationAnnouncement => {
+            jobs::notification::announcement::run_consumer(ctx).await
+        }
+        ConsumerKind::NotificationPush => jobs::notification::push::run_consumer(ctx).await,
+        ConsumerKind::WebhookDelivery => jobs::webhook::delivery::run_consumer(ctx).await,
     }
 }
 
@@ -189,6 +229,7 @@ async fn main() -> Result<()> {
         ctx.db_pool.clone(),
         lock_client,
         ctx.cache_client.clone(),
+        ctx.jetstream.clone(),
         ctx.r2_assets.clone(),
         config,
     )
@@ -56,6 +56,15 @@ fn default_backoff() -> Vec<Duration> {
     ]
 }
 
+/// Where a message stands in its retry budget, passed to `run_with_delivery` handlers.
+#[derive(Debug, Clone, Copy)]
+pub struct Delivery {
+    /// 1-based delivery count (JetStream's `delivered`).
+    pub attempt: i64,
+    /// A failure of this attempt is dead-lettered rather than retried.
+    pub is_final: bool,
+}
+
 /// Generic NATS consumer for job processing
 pub struct NatsConsumer {
     jetstream: Arc<JetStream>,
@@ -118,6 +127,18 @@ impl NatsConsumer {
         T: DeserializeOwned + Send + 'static,
         F: Fn(T) -> Fut + Clone + Send + Sync + 'static,
         Fut: Future<Output = Result<(), anyhow::Error>> + Send,
+    {
+        self.run_with_delivery(move |job, _delivery| handler(job))
+            .await
+    }
+
+    /// Like [`run`](Self::run), but the handler also sees the [`Delivery`], so it
+    /// can tell a retried failure from the last one before the DLQ.
+    pub async fn run_with_delivery<T, F, Fut>(self, handler: F) -> anyhow::Result<()>
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(T, Delivery) -> Fut + Clone + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), anyhow::Error>> + Send,
     {
         let stream = self.jetstream.get_stream(&self.stream_name).await?;
 
@@ -252,8 +273,13 @@ impl NatsConsumer {
                 // are not redelivered mid-processing. The whole run is bounded by
                 // `handler_timeout` so a hung external call can't pin the message
                 // (and its concurrency slot) forever; expiry is treated as failure.
+                let attempt = msg.info().map(|info| info.delivered).unwrap_or(0);
+                let delivery = Delivery {
+                    attempt,
+                    is_final: attempt >= max_deliver,
+                };
                 let run_handler = async {
-                    let mut handler_future = pin!(handler(job));
+                    let mut handler_future = pin!(handler(job, delivery));
                     let mut heartbeat = tokio::time::interval(ACK_WAIT / 2);
                     heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
                     heartbeat.tick().await; // first tick fires immediately; skip it
@@ -394,7 +420,7 @@ async fn publish_to_dlq(
 }
 
 /// Extracts a readable message from a caught panic payload.
-fn panic_message(payload: Box<dyn Any + Send>) -> String {
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
     if let Some(message) = payload.downcast_ref::<&str>() {
         (*message).to_string()
     } else if let Some(message) = payload.downcast_ref::<String>() {
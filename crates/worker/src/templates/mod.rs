@@ -1,6 +1,6 @@
 //! Email template rendering using MJML and minijinja
 
-use minijinja::{Environment, context};
+use minijinja::{AutoEscape, Environment, context};
 use mrml::prelude::render::RenderOptions;
 use std::sync::LazyLock;
 
@@ -10,7 +10,10 @@ const RESET_PASSWORD_MJML: &str = include_str!("reset_password.mjml");
 const EMAIL_CHANGE_MJML: &str = include_str!("email_change.mjml");
 const ACCOUNT_DELETION_MJML: &str = include_str!("account_deletion.mjml");
 const DEVICE_VERIFICATION_MJML: &str = include_str!("device_verification.mjml");
+const MAGIC_LINK_MJML: &str = include_str!("magic_link.mjml");
 const SECURITY_ALERT_MJML: &str = include_str!("security_alert.mjml");
+const NOTIFICATION_MJML: &str = include_str!("notification.mjml");
+const NOTIFICATION_DIGEST_MJML: &str = include_str!("notification_digest.mjml");
 
 /// Pre-rendered HTML templates (MJML → HTML conversion cached)
 static EMAIL_VERIFICATION_HTML: LazyLock<String> = LazyLock::new(|| {
@@ -33,10 +36,21 @@ static DEVICE_VERIFICATION_HTML: LazyLock<String> = LazyLock::new(|| {
     mjml_to_html(DEVICE_VERIFICATION_MJML).expect("Failed to parse device verification template")
 });
 
+static MAGIC_LINK_HTML: LazyLock<String> =
+    LazyLock::new(|| mjml_to_html(MAGIC_LINK_MJML).expect("Failed to parse magic link template"));
+
 static SECURITY_ALERT_HTML: LazyLock<String> = LazyLock::new(|| {
     mjml_to_html(SECURITY_ALERT_MJML).expect("Failed to parse security alert template")
 });
 
+static NOTIFICATION_HTML: LazyLock<String> = LazyLock::new(|| {
+    mjml_to_html(NOTIFICATION_MJML).expect("Failed to parse notification template")
+});
+
+static NOTIFICATION_DIGEST_HTML: LazyLock<String> = LazyLock::new(|| {
+    mjml_to_html(NOTIFICATION_DIGEST_MJML).expect("Failed to parse notification digest template")
+});
+
 /// Render email verification template
 pub fn render_email_verification(
     project_name: &str,
@@ -129,6 +143,24 @@ pub fn render_device_verification(
     )
 }
 
+/// Render magic-link sign-in template
+pub fn render_magic_link(
+    project_name: &str,
+    username: &str,
+    sign_in_link: &str,
+    valid_minutes: u64,
+) -> Result<String, TemplateError> {
+    render_with_context(
+        &MAGIC_LINK_HTML,
+        context! {
+            project_name => project_name,
+            username => username,
+            sign_in_link => sign_in_link,
+            valid_minutes => valid_minutes,
+        },
+    )
+}
+
 /// Render a security-alert notification (no link; pure "this happened on your account").
 pub fn render_security_alert(
     project_name: &str,
@@ -145,6 +177,50 @@ pub fn render_security_alert(
     )
 }
 
+/// Render a notification email (one in-app notification, with an unsubscribe link)
+pub fn render_notification(
+    project_name: &str,
+    username: &str,
+    summary: &str,
+    link: &str,
+    unsubscribe_link: &str,
+) -> Result<String, TemplateError> {
+    render_with_context(
+        &NOTIFICATION_HTML,
+        context! {
+            project_name => project_name,
+            username => username,
+            summary => summary,
+            link => link,
+            unsubscribe_link => unsubscribe_link,
+        },
+    )
+}
+
+/// Render a notification digest (unread notification lines, with an unsubscribe link)
+pub fn render_notification_digest(
+    project_name: &str,
+    username: &str,
+    period: &str,
+    items: &[String],
+    total: u64,
+    link: &str,
+    unsubscribe_link: &str,
+) -> Result<String, TemplateError> {
+    render_with_context(
+        &NOTIFICATION_DIGEST_HTML,
+        context! {
+            project_name => project_name,
+            username => username,
+            period => period,
+            items => items,
+            total => total,
+            link => link,
+            unsubscribe_link => unsubscribe_link,
+        },
+    )
+}
+
 /// Convert MJML to HTML
 fn mjml_to_html(mjml: &str) -> Result<String, TemplateError> {
     let root = mrml::parse(mjml).map_err(|e| TemplateError::MjmlParse(e.to_string()))?;
@@ -155,8 +231,12 @@ fn mjml_to_html(mjml: &str) -> Result<String, TemplateError> {
 }
 
 /// Render HTML template with minijinja context
+///
+/// Values are HTML-escaped: minijinja only auto-escapes `.html`-named templates by default, and
+/// some values (display names, post titles) come from other users.
 fn render_with_context(html: &str, ctx: minijinja::Value) -> Result<String, TemplateError> {
     let mut env = Environment::new();
+    env.set_auto_escape_callback(|_| AutoEscape::Html);
     env.add_template("email", html)
         .map_err(|e| TemplateError::Jinja(e.to_string()))?;
     let template = env
@@ -185,3 +265,49 @@ impl std::fmt::Display for TemplateError {
 }
 
 impl std::error::Error for TemplateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MALICIOUS_TITLE: &str = r#"<a href="https://evil.example">click</a>"#;
+
+    #[test]
+    fn notification_summary_is_escaped() {
+        let html = render_notification(
+            "AxumKit",
+            "alice",
+            &format!("bob commented on \"{MALICIOUS_TITLE}\""),
+            "https://example.com/notifications",
+            "https://example.com/unsubscribe?token=abc",
+        )
+        .unwrap();
+
+        assert!(!html.contains(MALICIOUS_TITLE));
+        assert!(!html.contains("https://evil.example\""));
+        assert!(html.contains("&lt;a href="));
+    }
+
+    #[test]
+    fn notification_digest_items_are_escaped() {
+        let items = vec![
+            format!("bob commented on \"{MALICIOUS_TITLE}\""),
+            "carol reacted to your post".to_string(),
+        ];
+        let html = render_notification_digest(
+            "AxumKit",
+            "alice",
+            "daily",
+            &items,
+            3,
+            "https://example.com/notifications",
+            "https://example.com/unsubscribe?token=abc",
+        )
+        .unwrap();
+
+        assert!(!html.contains(MALICIOUS_TITLE));
+        assert!(html.contains("&lt;a href="));
+        assert!(html.contains("carol reacted to your post"));
+        assert!(html.contains("and 1 more"));
+    }
+}